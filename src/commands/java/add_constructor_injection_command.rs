@@ -0,0 +1,35 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::add_constructor_injection_response::AddConstructorInjectionResponse,
+    services::add_constructor_injection_service::run,
+  },
+  common::{response::Response, validators::directory_validator::validate_file_path_within_base},
+};
+
+pub fn execute(
+  cwd: &Path,
+  b64_source_code: &str,
+  file_path: &Path,
+  dependency_type: &str,
+  dependency_package_name: Option<&str>,
+  field_name: Option<&str>,
+) -> Response<AddConstructorInjectionResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("add-constructor-injection");
+  // Path containment validation: ensure file path is within the cwd
+  let file_path_str = file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("File path must be within working directory: {}", error_msg),
+    );
+  }
+
+  match run(cwd, b64_source_code, file_path, dependency_type, dependency_package_name, field_name) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,53 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::add_nested_class_response::AddNestedClassResponse,
+    services::add_nested_class_service::{NestedTypeModifiers, run},
+    treesitter::types::java_file_type::JavaFileType,
+  },
+  common::{
+    response::Response, utils::build_file_util::detect_java_version,
+    validators::directory_validator::validate_file_path_within_base,
+  },
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute(
+  cwd: &Path,
+  b64_source_code: &str,
+  file_path: &Path,
+  outer_type_name: Option<&str>,
+  nested_type_name: &str,
+  nested_type: &JavaFileType,
+  modifiers: &NestedTypeModifiers,
+  target_java_version: Option<u32>,
+) -> Response<AddNestedClassResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("add-nested-class");
+  // Path containment validation: ensure file path is within the cwd
+  let file_path_str = file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("File path must be within working directory: {}", error_msg),
+    );
+  }
+
+  // Without an explicit target the version stated in the build file applies
+  let target_java_version = target_java_version.or_else(|| detect_java_version(cwd));
+  match run(
+    cwd,
+    b64_source_code,
+    file_path,
+    outer_type_name,
+    nested_type_name,
+    nested_type,
+    modifiers,
+    target_java_version,
+  ) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,57 @@
+//! In-memory transforms for Rust callers.
+//!
+//! These functions take plain Java source and return the transformed source. They never read or
+//! write files; base64 encoding is only used at the CLI boundary to survive shell quoting. The
+//! CLI commands run the very same transforms before saving the result.
+
+use crate::commands::java::services::{
+  create_jpa_entity_basic_field_service, create_jpa_entity_enum_field_service,
+  create_jpa_entity_id_field_service,
+};
+use crate::commands::java::treesitter::types::basic_field_config::BasicFieldConfig;
+use crate::commands::java::treesitter::types::enum_field_config::EnumFieldConfig;
+use crate::commands::java::treesitter::types::id_field_config::IdFieldConfig;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+
+fn transform_source(
+  source_code: &str,
+  transform: impl FnOnce(&mut TSFile) -> Result<(), String>,
+) -> Result<String, String> {
+  let mut ts_file = TSFile::from_source_code(source_code, SupportedLanguage::Java);
+  if ts_file.tree.is_none() {
+    return Err("Unable to parse Java source code".to_string());
+  }
+  transform(&mut ts_file)?;
+  Ok(ts_file.source_code)
+}
+
+/// Adds a basic field, with its annotations, accessors and imports, to the entity source.
+pub fn create_jpa_entity_basic_field(
+  source_code: &str,
+  field_config: &BasicFieldConfig,
+) -> Result<String, String> {
+  transform_source(source_code, |ts_file| {
+    create_jpa_entity_basic_field_service::transform(ts_file, field_config)
+  })
+}
+
+/// Adds an enum field, with its annotations, accessors and imports, to the entity source.
+pub fn create_jpa_entity_enum_field(
+  source_code: &str,
+  field_config: &EnumFieldConfig,
+) -> Result<String, String> {
+  transform_source(source_code, |ts_file| {
+    create_jpa_entity_enum_field_service::transform(ts_file, field_config)
+  })
+}
+
+/// Adds an id field, with its annotations, accessors and imports, to the entity source.
+pub fn create_jpa_entity_id_field(
+  source_code: &str,
+  field_config: &IdFieldConfig,
+) -> Result<String, String> {
+  transform_source(source_code, |ts_file| {
+    create_jpa_entity_id_field_service::transform(ts_file, field_config)
+  })
+}
@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::check_entity_compiles_against_repository_response::CheckEntityCompilesAgainstRepositoryResponse,
+    services::check_entity_compiles_against_repository_service::run,
+  },
+  common::{response::Response, validators::directory_validator::validate_file_path_within_base},
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_path: &Path,
+  repository_file_path: &Path,
+) -> Response<CheckEntityCompilesAgainstRepositoryResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("check-entity-compiles-against-repository");
+  // Path containment validation: ensure both file paths are within the cwd
+  for (label, file_path) in [("Entity", entity_file_path), ("Repository", repository_file_path)] {
+    let file_path_str = file_path.display().to_string();
+    if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+      return Response::error(
+        cmd_name,
+        cwd_string,
+        format!("{} file path must be within working directory: {}", label, error_msg),
+      );
+    }
+  }
+
+  match run(cwd, entity_file_path, repository_file_path) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
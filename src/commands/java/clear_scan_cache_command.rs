@@ -0,0 +1,18 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::clear_scan_cache_response::ClearScanCacheResponse,
+    services::clear_scan_cache_service::run,
+  },
+  common::response::Response,
+};
+
+pub fn execute(cwd: &Path, all_projects: bool) -> Response<ClearScanCacheResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("clear-scan-cache");
+  match run(cwd, all_projects) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
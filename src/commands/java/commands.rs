@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use clap::Subcommand;
+use clap::{ArgAction, Subcommand};
 
 #[cfg(feature = "ui")]
 use crate::common::ui::runner::run_ui_command;
@@ -12,27 +12,63 @@ use crate::commands::java::ui::{
   create_jpa_entity::CreateJpaEntityForm, create_jpa_repository::CreateJpaRepositoryForm,
 };
 
+#[cfg(feature = "debug-tools")]
+use crate::commands::java::get_parse_tree_command;
+
 use crate::commands::java::{
-  create_java_file_command, create_jpa_entity_basic_field_command, create_jpa_entity_command,
+  add_attribute_override_command, add_constructor_injection_command, add_nested_class_command,
+  add_repository_query_method_command, add_secondary_table_command, add_size_validation_command,
+  add_unique_constraint_command, check_entity_compiles_against_repository_command,
+  clear_scan_cache_command, convert_to_lombok_builder_command, create_java_file_command,
+  create_jpa_embeddable_command, create_jpa_entity_basic_field_command, create_jpa_entity_command,
   create_jpa_entity_enum_field_command, create_jpa_entity_id_field_command,
-  create_jpa_many_to_one_relationship_command, create_jpa_one_to_one_relationship_command,
-  create_jpa_repository_command, get_all_jpa_entities_command, get_all_jpa_mapped_superclasses,
-  get_all_packages_command, get_java_basic_types_command, get_java_files_command,
-  get_jpa_entity_info_command,
+  create_jpa_entity_version_field_command, create_jpa_many_to_one_relationship_command,
+  create_jpa_one_to_one_relationship_command, create_jpa_repository_command,
+  delete_jpa_entity_field_command, emit_camel_to_snake_column_report_command,
+  ensure_no_arg_constructor_command, generate_audit_entity_command,
+  generate_controller_test_command, generate_entity_diagram_command,
+  generate_entity_listener_command, generate_factory_method_command,
+  generate_liquibase_changelog_command, generate_projection_interface_command,
+  generate_query_by_example_command, generate_query_dsl_metadata_command, generate_schema_command,
+  generate_test_data_builder_command, get_all_jpa_entities_command,
+  get_all_jpa_mapped_superclasses, get_all_packages_command, get_annotations_command,
+  get_class_metrics_command, get_column_mapping_command, get_constructor_info_command,
+  get_effective_column_type_command, get_entity_by_table_name_command, get_entity_graph_command,
+  get_file_diagnostics_command, get_id_field_info_command, get_java_basic_types_command,
+  get_java_files_command, get_jpa_entities_info_command, get_jpa_entity_info_command,
+  get_method_callers_command, get_relationship_inverse_command, get_relationship_targets_command,
+  get_superclass_fields_command, get_type_definition_location_command,
+  invalidate_parse_cache_command, list_commands_command, make_entity_immutable_command,
+  merge_packages_command, migrate_to_jakarta_command, move_method_command,
+  regenerate_accessors_command, remove_annotation_command, remove_duplicate_imports_command,
+  remove_relationship_command, rename_column_command, rename_java_class_command,
+  rename_java_field_command, replace_annotation_argument_command, resolve_import_for_type_command,
+  services::add_nested_class_service::NestedTypeModifiers,
+  set_column_name_command, set_default_fetch_command,
   treesitter::types::{
     basic_field_config::BasicFieldConfig, cascade_type::CascadeType,
     collection_type::CollectionType, enum_field_config::EnumFieldConfig, fetch_type::FetchType,
-    id_field_config::IdFieldConfig, java_basic_types::JavaBasicType, java_enum_type::JavaEnumType,
+    finding_severity::FindingSeverity, id_field_config::IdFieldConfig,
+    java_basic_types::JavaBasicType, java_enum_type::JavaEnumType,
     java_field_temporal::JavaFieldTemporal, java_field_time_zone_storage::JavaFieldTimeZoneStorage,
-    java_file_type::JavaFileType, java_id_generation::JavaIdGeneration,
-    java_id_generation_type::JavaIdGenerationType,
+    java_file_template::JavaFileTemplate, java_file_type::JavaFileType,
+    java_id_generation::JavaIdGeneration, java_id_generation_type::JavaIdGenerationType,
     java_source_directory_type::JavaSourceDirectoryType,
-    many_to_one_field_config::ManyToOneFieldConfig, mapping_type::MappingType,
-    one_to_one_field_config::OneToOneFieldConfig, other_type::OtherType,
+    java_visibility_modifier::JavaVisibilityModifier,
+    lifecycle_callback_type::LifecycleCallbackType, many_to_one_field_config::ManyToOneFieldConfig,
+    mapping_type::MappingType, member_access_config::MemberAccessConfig,
+    namespace_mapping::NamespaceMapping, one_to_one_field_config::OneToOneFieldConfig,
+    other_type::OtherType, relationship_kind::RelationshipKind,
+    reserved_word_check::ReservedWordCheck, sql_dialect::SqlDialect,
+    version_field_config::VersionFieldConfig,
   },
+  validate_entity_command, validate_project_command,
   validators::{
     java_class_name_validator::validate_java_class_name,
+    java_identifier_validator::validate_java_identifier,
+    java_type_validator::validate_java_field_type, java_version_validator::validate_java_version,
     package_name_validator::validate_package_name,
+    sql_identifier_validator::validate_sql_identifier,
   },
 };
 
@@ -41,18 +77,21 @@ use crate::common::validators::directory_validator::validate_directory_unrestric
 #[derive(Subcommand)]
 pub enum JavaCommands {
   // ============ UI Commands (with --features ui) ============
+  /// Create a Java file from an interactive form
   #[cfg(feature = "ui")]
   #[command(name = "create-java-file-ui")]
   CreateJavaFileUi {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
   },
+  /// Create a JPA entity from an interactive form
   #[cfg(feature = "ui")]
   #[command(name = "create-jpa-entity-ui")]
   CreateJpaEntityUi {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
   },
+  /// Add a field to a JPA entity from an interactive form
   #[cfg(feature = "ui")]
   #[command(name = "create-jpa-entity-basic-field-ui")]
   CreateJpaEntityBasicFieldUi {
@@ -65,6 +104,7 @@ pub enum JavaCommands {
     #[arg(long, required = true)]
     entity_file_path: PathBuf,
   },
+  /// Add a relationship to a JPA entity from an interactive form
   #[cfg(feature = "ui")]
   #[command(name = "create-jpa-one-to-one-relationship-ui")]
   CreateJpaOneToOneRelationshipUi {
@@ -77,6 +117,7 @@ pub enum JavaCommands {
     #[arg(long, required = true)]
     entity_file_path: PathBuf,
   },
+  /// Create a JPA repository from an interactive form
   #[cfg(feature = "ui")]
   #[command(name = "create-jpa-repository-ui")]
   CreateJpaRepositoryUi {
@@ -91,14 +132,26 @@ pub enum JavaCommands {
   },
 
   // ============ CLI Commands (always available) ============
+  /// List the JPA entities of the project
   GetAllJPAEntities {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
+
+    #[arg(long)]
+    timeout_ms: Option<u64>,
+
+    #[arg(long)]
+    include_commented: bool,
   },
+  /// List the mapped superclasses of the project
   GetAllJPAMappedSuperclasses {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
+
+    #[arg(long)]
+    timeout_ms: Option<u64>,
   },
+  /// Describe a JPA entity, its id and its fields
   GetJPAEntityInfo {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
@@ -109,6 +162,18 @@ pub enum JavaCommands {
     #[arg(long, required = false)]
     b64_source_code: Option<String>,
   },
+  /// List the fields an entity inherits from its mapped superclasses
+  GetSuperclassFields {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    entity_file_path: Option<PathBuf>,
+
+    #[arg(long, required = false)]
+    b64_source_code: Option<String>,
+  },
+  /// List the packages of the project
   GetAllPackages {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
@@ -116,17 +181,26 @@ pub enum JavaCommands {
     #[arg(long, default_value = "main")]
     source_directory: JavaSourceDirectoryType,
   },
+  /// List the Java types available for entity fields
   GetJavaBasicTypes {
     #[arg(long, default_value = "all-types")]
     basic_type_kind: JavaBasicType,
   },
+  /// List the Java files of a given type in the project
   GetJavaFiles {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
 
     #[arg(long, required = true)]
     file_type: JavaFileType,
+
+    #[arg(long)]
+    modified_since: Option<u64>,
+
+    #[arg(long)]
+    timeout_ms: Option<u64>,
   },
+  /// Create a class, interface, enum, record or annotation file
   CreateJavaFile {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
@@ -142,7 +216,14 @@ pub enum JavaCommands {
 
     #[arg(long, default_value = "main")]
     source_directory: JavaSourceDirectoryType,
+
+    #[arg(long, value_parser = validate_java_version, required = false)]
+    target_java_version: Option<u32>,
+
+    #[arg(long)]
+    template: Option<JavaFileTemplate>,
   },
+  /// Create a JPA entity
   CreateJPAEntity {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
@@ -158,7 +239,23 @@ pub enum JavaCommands {
 
     #[arg(long, required = false)]
     superclass_package_name: Option<String>,
+
+    #[arg(long)]
+    no_annotations: bool,
+
+    #[arg(long, required = false)]
+    dialect: Option<SqlDialect>,
+
+    #[arg(long)]
+    quote_reserved: bool,
+
+    #[arg(long)]
+    fail_on_duplicate_name: bool,
+
+    #[arg(long)]
+    serializable: bool,
   },
+  /// Create the Spring Data repository of a JPA entity
   CreateJPARepository {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
@@ -172,6 +269,7 @@ pub enum JavaCommands {
     #[arg(long, required = false)]
     b64_superclass_source: Option<String>,
   },
+  /// Add a basic field to a JPA entity
   CreateJPAEntityBasicField {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
@@ -185,7 +283,7 @@ pub enum JavaCommands {
     #[arg(long, required = true)]
     field_name: String,
 
-    #[arg(long, required = true)]
+    #[arg(long, value_parser = validate_java_field_type, required = true)]
     field_type: String,
 
     #[arg(long, required = false)]
@@ -200,6 +298,12 @@ pub enum JavaCommands {
     #[arg(long, required = false)]
     field_scale: Option<u16>,
 
+    #[arg(long, default_value_t = 19)]
+    money_precision: u16,
+
+    #[arg(long, default_value_t = 2)]
+    money_scale: u16,
+
     #[arg(long, required = false)]
     field_temporal: Option<JavaFieldTemporal>,
 
@@ -214,7 +318,35 @@ pub enum JavaCommands {
 
     #[arg(long)]
     field_large_object: bool,
+
+    #[arg(long, default_value_t = true, action = ArgAction::Set)]
+    field_optional: bool,
+
+    #[arg(long, required = false)]
+    field_fetch_type: Option<FetchType>,
+
+    #[arg(long)]
+    sync_length: bool,
+
+    #[arg(long)]
+    explicit_string_length: bool,
+
+    #[arg(long, default_value = "private")]
+    field_visibility: JavaVisibilityModifier,
+
+    #[arg(long)]
+    generate_accessors: bool,
+
+    #[arg(long, default_value = "public")]
+    accessor_visibility: JavaVisibilityModifier,
+
+    #[arg(long, required = false)]
+    dialect: Option<SqlDialect>,
+
+    #[arg(long)]
+    quote_reserved: bool,
   },
+  /// Add an id field to a JPA entity
   CreateJPAEntityIdField {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
@@ -254,7 +386,17 @@ pub enum JavaCommands {
 
     #[arg(long)]
     field_nullable: bool,
+
+    #[arg(long, default_value = "private")]
+    field_visibility: JavaVisibilityModifier,
+
+    #[arg(long)]
+    generate_accessors: bool,
+
+    #[arg(long, default_value = "public")]
+    accessor_visibility: JavaVisibilityModifier,
   },
+  /// Add an enum field to a JPA entity
   CreateJPAEntityEnumField {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
@@ -285,7 +427,20 @@ pub enum JavaCommands {
 
     #[arg(long)]
     field_unique: bool,
+
+    #[arg(long)]
+    validate_enum_type: bool,
+
+    #[arg(long, default_value = "private")]
+    field_visibility: JavaVisibilityModifier,
+
+    #[arg(long)]
+    generate_accessors: bool,
+
+    #[arg(long, default_value = "public")]
+    accessor_visibility: JavaVisibilityModifier,
   },
+  /// Add a one-to-one relationship between two JPA entities
   CreateJPAOneToOneRelationship {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
@@ -302,24 +457,48 @@ pub enum JavaCommands {
     #[arg(long, required = true)]
     inverse_side_field_name: String,
 
-    #[arg(long, required = true)]
-    inverse_field_type: String,
+    #[arg(long, required_unless_present = "config_json", conflicts_with = "config_json")]
+    inverse_field_type: Option<String>,
 
-    #[arg(long, required = false)]
+    #[arg(long, required = false, conflicts_with = "config_json")]
     mapping_type: Option<MappingType>,
 
-    #[arg(long, required = false)]
+    #[arg(long, required = false, conflicts_with = "config_json")]
     owning_side_cascades: Vec<CascadeType>,
 
-    #[arg(long, required = false)]
+    #[arg(long, required = false, conflicts_with = "config_json")]
     inverse_side_cascades: Vec<CascadeType>,
 
-    #[arg(long, required = false)]
+    #[arg(long, required = false, conflicts_with = "config_json")]
     owning_side_other: Vec<OtherType>,
 
-    #[arg(long, required = false)]
+    #[arg(long, required = false, conflicts_with = "config_json")]
     inverse_side_other: Vec<OtherType>,
+
+    #[arg(
+      long,
+      value_parser = validate_sql_identifier,
+      conflicts_with_all = ["config_json", "no_foreign_key_constraint"]
+    )]
+    foreign_key_name: Option<String>,
+
+    #[arg(long, conflicts_with = "config_json")]
+    no_foreign_key_constraint: bool,
+
+    #[arg(long, conflicts_with = "config_json")]
+    required: bool,
+
+    #[arg(long, conflicts_with = "config_json")]
+    insertable: Option<bool>,
+
+    #[arg(long, conflicts_with = "config_json")]
+    updatable: Option<bool>,
+
+    #[arg(long, value_parser = OneToOneFieldConfig::from_json)]
+    config_json: Option<OneToOneFieldConfig>,
   },
+
+  /// Add a many-to-one relationship between two JPA entities
   CreateJPAManyToOneRelationship {
     #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
     cwd: PathBuf,
@@ -336,223 +515,2091 @@ pub enum JavaCommands {
     #[arg(long, required = true)]
     inverse_side_field_name: String,
 
+    #[arg(long, required_unless_present = "config_json", conflicts_with = "config_json")]
+    inverse_field_type: Option<String>,
+
+    #[arg(long, required_unless_present = "config_json", conflicts_with = "config_json")]
+    fetch_type: Option<FetchType>,
+
+    #[arg(long, required_unless_present = "config_json", conflicts_with = "config_json")]
+    collection_type: Option<CollectionType>,
+
+    #[arg(long, required = false, conflicts_with = "config_json")]
+    mapping_type: Option<MappingType>,
+
+    #[arg(long, required = false, conflicts_with = "config_json")]
+    owning_side_cascades: Vec<CascadeType>,
+
+    #[arg(long, required = false, conflicts_with = "config_json")]
+    inverse_side_cascades: Vec<CascadeType>,
+
+    #[arg(long, required = false, conflicts_with = "config_json")]
+    owning_side_other: Vec<OtherType>,
+
+    #[arg(long, required = false, conflicts_with = "config_json")]
+    inverse_side_other: Vec<OtherType>,
+
+    #[arg(
+      long,
+      value_parser = validate_sql_identifier,
+      conflicts_with_all = ["config_json", "no_foreign_key_constraint"]
+    )]
+    foreign_key_name: Option<String>,
+
+    #[arg(long, conflicts_with = "config_json")]
+    no_foreign_key_constraint: bool,
+
+    #[arg(long, conflicts_with = "config_json")]
+    required: bool,
+
+    #[arg(long, conflicts_with = "config_json")]
+    insertable: Option<bool>,
+
+    #[arg(long, conflicts_with = "config_json")]
+    updatable: Option<bool>,
+
+    #[arg(long, required = false, conflicts_with = "config_json")]
+    map_key_type: Option<String>,
+
+    #[arg(long, value_parser = validate_sql_identifier, conflicts_with = "config_json")]
+    map_key_column: Option<String>,
+
+    #[arg(long, value_parser = ManyToOneFieldConfig::from_json)]
+    config_json: Option<ManyToOneFieldConfig>,
+  },
+
+  /// Override the column of an inherited or embedded attribute
+  AddAttributeOverride {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
     #[arg(long, required = true)]
-    inverse_field_type: String,
+    entity_file_path: PathBuf,
 
     #[arg(long, required = true)]
-    fetch_type: FetchType,
+    field_name: String,
+
+    #[arg(long, required = true)]
+    attribute_name: String,
 
     #[arg(long, required = true)]
-    collection_type: CollectionType,
+    column_name: String,
+  },
+  /// Export the DDL of the project's entities
+  GenerateSchema {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, default_value = "postgresql")]
+    dialect: SqlDialect,
+
+    #[arg(long)]
+    timeout_ms: Option<u64>,
+  },
+  /// Report mapping problems in a JPA entity
+  ValidateEntity {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
 
     #[arg(long, required = false)]
-    mapping_type: Option<MappingType>,
+    entity_file_path: Option<PathBuf>,
 
     #[arg(long, required = false)]
-    owning_side_cascades: Vec<CascadeType>,
+    b64_source_code: Option<String>,
 
     #[arg(long, required = false)]
-    inverse_side_cascades: Vec<CascadeType>,
+    dialect: Option<SqlDialect>,
 
     #[arg(long, required = false)]
-    owning_side_other: Vec<OtherType>,
+    dynamic_update_threshold: Option<usize>,
+  },
+  /// Add the no-arg constructor JPA requires when it is missing
+  EnsureNoArgConstructor {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+  },
+  /// Generate a Spring Data projection interface for an entity
+  GenerateProjectionInterface {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, value_delimiter = ',', required = true)]
+    field_names: Vec<String>,
+
+    #[arg(long, value_parser = validate_package_name, required = false)]
+    package_name: Option<String>,
+
+    #[arg(long, value_parser = validate_java_class_name, required = false)]
+    projection_name: Option<String>,
+  },
+  /// Check an entity against the query methods of its repository
+  CheckEntityCompilesAgainstRepository {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    repository_file_path: PathBuf,
+  },
+  /// Map a JPA entity to a secondary table
+  AddSecondaryTable {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, value_parser = validate_sql_identifier, required = true)]
+    table_name: String,
+
+    #[arg(long, value_parser = validate_sql_identifier)]
+    pk_join_column: Option<String>,
+
+    #[arg(long, value_delimiter = ',')]
+    field_names: Vec<String>,
+  },
+  /// Describe the column each field of an entity maps to
+  GetColumnMapping {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
 
     #[arg(long, required = false)]
-    inverse_side_other: Vec<OtherType>,
+    entity_file_path: Option<PathBuf>,
+
+    #[arg(long, required = false)]
+    b64_source_code: Option<String>,
   },
-}
+  /// Generate a Liquibase changelog for the project's entities
+  GenerateLiquibaseChangelog {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
 
-impl JavaCommands {
-  pub fn execute(&self) -> Result<String, Box<dyn std::error::Error>> {
-    match self {
-      // ============ UI Commands ============
-      #[cfg(feature = "ui")]
-      JavaCommands::CreateJavaFileUi { cwd } => {
-        let form = CreateJavaFileForm::new(cwd.clone());
-        run_ui_command(form)?;
-        Ok(String::new()) // UI commands don't return JSON
-      }
-      #[cfg(feature = "ui")]
-      JavaCommands::CreateJpaEntityUi { cwd } => {
-        let form = CreateJpaEntityForm::new(cwd.clone());
-        run_ui_command(form)?;
-        Ok(String::new())
-      }
-      #[cfg(feature = "ui")]
-      JavaCommands::CreateJpaEntityBasicFieldUi { cwd, entity_file_b64_src, entity_file_path } => {
-        let form = CreateEntityFieldForm::new(
-          cwd.clone(),
-          entity_file_b64_src.clone(),
-          entity_file_path.clone(),
-        );
-        run_ui_command(form)?;
-        Ok(String::new())
-      }
-      #[cfg(feature = "ui")]
-      JavaCommands::CreateJpaOneToOneRelationshipUi {
-        cwd,
-        entity_file_b64_src,
-        entity_file_path,
-      } => {
-        let form = CreateEntityRelationshipForm::new(
-          cwd.clone(),
-          entity_file_b64_src.clone(),
-          entity_file_path.clone(),
-        );
-        run_ui_command(form)?;
-        Ok(String::new())
-      }
-      #[cfg(feature = "ui")]
-      JavaCommands::CreateJpaRepositoryUi { cwd, entity_file_b64_src, entity_file_path } => {
-        let form = CreateJpaRepositoryForm::new(
-          cwd.clone(),
-          entity_file_b64_src.clone(),
-          entity_file_path.clone(),
-        );
-        run_ui_command(form)?;
-        Ok(String::new())
-      }
+    #[arg(long, required = false)]
+    entity_file_path: Option<PathBuf>,
 
-      // ============ CLI Commands ============
-      JavaCommands::GetAllJPAEntities { cwd } => {
-        let response = get_all_jpa_entities_command::execute(cwd.as_path());
-        response.to_json_pretty().map_err(|e| e.into())
-      }
-      JavaCommands::GetAllJPAMappedSuperclasses { cwd } => {
-        let response = get_all_jpa_mapped_superclasses::execute(cwd.as_path());
-        response.to_json_pretty().map_err(|e| e.into())
-      }
-      JavaCommands::GetJPAEntityInfo { cwd, entity_file_path, b64_source_code } => {
-        let response = get_jpa_entity_info_command::execute(
-          cwd.as_path(),
-          entity_file_path.as_deref(),
-          b64_source_code.as_deref(),
-        );
-        response.to_json_pretty().map_err(|e| e.into())
-      }
-      JavaCommands::GetAllPackages { cwd, source_directory } => {
-        let response = get_all_packages_command::execute(cwd.as_path(), source_directory);
-        response.to_json_pretty().map_err(|e| e.into())
-      }
-      JavaCommands::GetJavaBasicTypes { basic_type_kind } => {
-        let response = get_java_basic_types_command::execute(basic_type_kind);
-        response.to_json_pretty().map_err(|e| e.into())
-      }
-      JavaCommands::GetJavaFiles { cwd, file_type } => {
-        let response = get_java_files_command::execute(cwd.as_path(), file_type);
-        response.to_json_pretty().map_err(|e| e.into())
-      }
-      JavaCommands::CreateJavaFile {
-        cwd,
-        package_name,
-        file_name,
-        file_type,
-        source_directory,
-      } => {
+    #[arg(long, required = false)]
+    b64_source_code: Option<String>,
+
+    #[arg(long, default_value = "postgresql")]
+    dialect: SqlDialect,
+
+    #[arg(long, default_value = "src/main/resources/db/changelog/changes")]
+    changelog_directory: PathBuf,
+
+    #[arg(long, required = false)]
+    author: Option<String>,
+
+    #[arg(long, required = false)]
+    master_changelog_file_path: Option<PathBuf>,
+  },
+  /// List the annotations of a class and its members
+  GetAnnotations {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    file_path: Option<PathBuf>,
+
+    #[arg(long, required = false)]
+    b64_source_code: Option<String>,
+
+    #[arg(long, required = false)]
+    type_name: Option<String>,
+  },
+  /// Rewrite javax namespaces to jakarta
+  MigrateToJakarta {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, default_value = "all")]
+    source_directory: JavaSourceDirectoryType,
+
+    #[arg(long = "mapping", value_delimiter = ',', value_parser = NamespaceMapping::parse)]
+    mappings: Vec<NamespaceMapping>,
+  },
+  /// Add a @Size constraint to an entity field
+  AddSizeValidation {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    field_name: String,
+
+    #[arg(long, required_unless_present = "max")]
+    min: Option<u32>,
+
+    #[arg(long, required_unless_present = "min")]
+    max: Option<u32>,
+
+    #[arg(long)]
+    sync_length: bool,
+  },
+  // ============ Debug Commands (with --features debug-tools) ============
+  /// Print the tree-sitter parse tree of a Java file
+  #[cfg(feature = "debug-tools")]
+  #[command(name = "get-parse-tree", hide = true)]
+  GetParseTree {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    file_path: Option<PathBuf>,
+
+    #[arg(long, required = false)]
+    b64_source_code: Option<String>,
+
+    #[arg(long, required = false)]
+    line: Option<usize>,
+  },
+  /// Regenerate the getters and setters of an entity
+  RegenerateAccessors {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long = "fields", value_delimiter = ',')]
+    field_names: Vec<String>,
+
+    #[arg(long, default_value = "public")]
+    accessor_visibility: JavaVisibilityModifier,
+  },
+  /// Mark a JPA entity read-only with @Immutable
+  MakeEntityImmutable {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long)]
+    remove_setters: bool,
+  },
+  /// Add a nested class, interface, enum or record to a type
+  AddNestedClass {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    b64_source_code: String,
+
+    #[arg(long, required = true)]
+    file_path: PathBuf,
+
+    #[arg(long)]
+    outer_type_name: Option<String>,
+
+    #[arg(long, value_parser = validate_java_class_name, required = true)]
+    nested_type_name: String,
+
+    #[arg(long, default_value = "class")]
+    nested_type: JavaFileType,
+
+    #[arg(long, default_value = "public")]
+    visibility: JavaVisibilityModifier,
+
+    #[arg(long = "static")]
+    is_static: bool,
+
+    #[arg(long = "final")]
+    is_final: bool,
+
+    #[arg(long = "abstract")]
+    is_abstract: bool,
+
+    #[arg(long, value_parser = validate_java_version, required = false)]
+    target_java_version: Option<u32>,
+  },
+  /// Replace the value of an annotation argument
+  ReplaceAnnotationArgument {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    b64_source_code: String,
+
+    #[arg(long, required = true)]
+    file_path: PathBuf,
+
+    #[arg(long)]
+    type_name: Option<String>,
+
+    #[arg(long)]
+    field_name: Option<String>,
+
+    #[arg(long, required = true)]
+    annotation_name: String,
+
+    #[arg(long, required = true)]
+    key: String,
+
+    #[arg(long, required = true, allow_hyphen_values = true)]
+    value: String,
+  },
+  /// Annotate a class for QueryDSL metadata generation
+  GenerateQueryDslMetadata {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    b64_source_code: String,
+
+    #[arg(long, required = true)]
+    file_path: PathBuf,
+  },
+  /// Remove duplicate imports from a Java file
+  RemoveDuplicateImports {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    b64_source_code: String,
+
+    #[arg(long, required = true)]
+    file_path: PathBuf,
+  },
+  /// List the entities an entity has relationships with
+  GetRelationshipTargets {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    entity_file_path: Option<PathBuf>,
+
+    #[arg(long, required = false)]
+    b64_source_code: Option<String>,
+  },
+  /// Add a unique constraint to the table of an entity
+  AddUniqueConstraint {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, value_delimiter = ',', required = true)]
+    field_names: Vec<String>,
+
+    #[arg(long, value_parser = validate_sql_identifier)]
+    constraint_name: Option<String>,
+  },
+  /// Clear the cached project scan
+  ClearScanCache {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+    #[arg(long)]
+    #[arg(long, default_value_t = false)]
+    all_projects: bool,
+  },
+  /// Generate a factory method taking the required fields of a class
+  GenerateFactoryMethod {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    b64_source_code: String,
+
+    #[arg(long, required = true)]
+    file_path: PathBuf,
+
+    #[arg(long, default_value = "of")]
+    method_name: String,
+  },
+  /// Set the fetch type of an entity's relationships
+  SetDefaultFetch {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    fetch_type: FetchType,
+
+    #[arg(long, value_delimiter = ',')]
+    relationship_kinds: Vec<RelationshipKind>,
+  },
+  /// Report size and complexity metrics of a class
+  GetClassMetrics {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    file_path: Option<PathBuf>,
+
+    #[arg(long, required = false)]
+    b64_source_code: Option<String>,
+
+    #[arg(long, required = false)]
+    type_name: Option<String>,
+  },
+  /// Inject a dependency through the constructor of a class
+  AddConstructorInjection {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    b64_source_code: String,
+
+    #[arg(long, required = true)]
+    file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    dependency_type: String,
+
+    #[arg(long, required = false)]
+    dependency_package_name: Option<String>,
+
+    #[arg(long, required = false)]
+    field_name: Option<String>,
+  },
+  /// Render the entities of a package as a PlantUML diagram
+  GenerateEntityDiagram {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    package_name: Option<String>,
+
+    #[arg(long, required = false)]
+    output_file_path: Option<PathBuf>,
+
+    #[arg(long)]
+    timeout_ms: Option<u64>,
+  },
+  /// Set the column name of an entity field
+  SetColumnName {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    field_name: String,
+
+    #[arg(long, value_parser = validate_sql_identifier, required = true)]
+    column_name: String,
+
+    #[arg(long)]
+    quote_mixed_case: bool,
+  },
+  /// Describe several JPA entities at once
+  GetJPAEntitiesInfo {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, value_delimiter = ',')]
+    entity_file_paths: Vec<PathBuf>,
+
+    #[arg(long)]
+    all_entities: bool,
+
+    #[arg(long)]
+    timeout_ms: Option<u64>,
+  },
+  /// Describe the id field of a JPA entity
+  GetIdFieldInfo {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    entity_file_path: Option<PathBuf>,
+
+    #[arg(long, required = false)]
+    b64_source_code: Option<String>,
+  },
+  /// Add a derived query method to a repository
+  AddRepositoryQueryMethod {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    b64_source_code: String,
+
+    #[arg(long, required = true)]
+    file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    method_name: String,
+
+    #[arg(long)]
+    pageable: bool,
+
+    #[arg(long)]
+    sort: bool,
+  },
+  /// List the call sites of a method across the project
+  GetMethodCallers {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    line: usize,
+
+    #[arg(long)]
+    timeout_ms: Option<u64>,
+  },
+  /// Remove an annotation from a class or member
+  RemoveAnnotation {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    b64_source_code: String,
+
+    #[arg(long, required = true)]
+    file_path: PathBuf,
+
+    #[arg(long)]
+    type_name: Option<String>,
+
+    #[arg(long)]
+    field_name: Option<String>,
+
+    #[arg(long, required = true)]
+    annotation_name: String,
+
+    #[arg(long)]
+    remove_import: bool,
+  },
+  /// Describe the constructors of a class
+  GetConstructorInfo {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    file_path: Option<PathBuf>,
+
+    #[arg(long, required = false)]
+    b64_source_code: Option<String>,
+
+    #[arg(long, required = false)]
+    type_name: Option<String>,
+  },
+  /// Generate an entity listener with lifecycle callbacks
+  GenerateEntityListener {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, value_delimiter = ',', required = true)]
+    callbacks: Vec<LifecycleCallbackType>,
+
+    #[arg(long, value_parser = validate_package_name, required = false)]
+    package_name: Option<String>,
+
+    #[arg(long, value_parser = validate_java_class_name, required = false)]
+    listener_name: Option<String>,
+
+    #[arg(long)]
+    entity_parameter: bool,
+  },
+  /// Resolve the SQL column type of an entity field
+  GetEffectiveColumnType {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    entity_file_path: Option<PathBuf>,
+
+    #[arg(long, required = false)]
+    b64_source_code: Option<String>,
+
+    #[arg(long, required = true)]
+    field_name: String,
+
+    #[arg(long, default_value = "postgresql")]
+    dialect: SqlDialect,
+  },
+  /// Rename the column of an entity field
+  RenameColumn {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    field_name: String,
+
+    #[arg(long, value_parser = validate_sql_identifier, required = true)]
+    column_name: String,
+  },
+  /// Report mapping problems across every entity of the project
+  ValidateProject {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    dialect: Option<SqlDialect>,
+
+    #[arg(long, required = false)]
+    fail_on: Option<FindingSeverity>,
+  },
+  /// Drop the cached parse trees of the project
+  InvalidateParseCache {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    file_path: Option<PathBuf>,
+  },
+  /// Generate a test data builder for an entity
+  GenerateTestDataBuilder {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, value_parser = validate_package_name, required = false)]
+    package_name: Option<String>,
+
+    #[arg(long, value_parser = validate_java_class_name, required = false)]
+    builder_name: Option<String>,
+
+    #[arg(long)]
+    include_relations: bool,
+  },
+  /// Move the files of one package into another
+  MergePackages {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, value_parser = validate_package_name, required = true)]
+    source_package: String,
+
+    #[arg(long, value_parser = validate_package_name, required = true)]
+    destination_package: String,
+  },
+  /// Find the file declaring a type
+  GetTypeDefinitionLocation {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    line: usize,
+
+    #[arg(long, required = true)]
+    column: usize,
+  },
+  /// Generate a MockMvc test for a controller
+  GenerateControllerTest {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    controller_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    controller_file_path: PathBuf,
+
+    #[arg(long, value_parser = validate_package_name, required = false)]
+    package_name: Option<String>,
+
+    #[arg(long, value_parser = validate_java_class_name, required = false)]
+    test_name: Option<String>,
+  },
+  /// Remove a relationship field and its inverse side
+  RemoveRelationship {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    field_name: String,
+
+    #[arg(long)]
+    owning_only: bool,
+  },
+  /// Report syntax errors in a Java file
+  GetFileDiagnostics {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = false)]
+    file_path: Option<PathBuf>,
+
+    #[arg(long, required = false)]
+    b64_source_code: Option<String>,
+  },
+  /// Create a JPA embeddable class
+  CreateJPAEmbeddable {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, value_parser = validate_package_name, required = true)]
+    package_name: String,
+
+    #[arg(long, value_parser = validate_java_class_name, required = true)]
+    file_name: String,
+
+    #[arg(long = "field-json", value_parser = BasicFieldConfig::from_json)]
+    fields: Vec<BasicFieldConfig>,
+
+    #[arg(long)]
+    composite_key: bool,
+
+    #[arg(long, required = false)]
+    serializable: Option<bool>,
+  },
+  /// Replace a hand-written builder with Lombok's @Builder
+  ConvertToLombokBuilder {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    b64_source_code: String,
+
+    #[arg(long, required = true)]
+    file_path: PathBuf,
+  },
+  /// Find the entity mapped to a table
+  GetEntityByTableName {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    table_name: String,
+  },
+  /// Generate a helper building a query-by-example probe of an entity
+  GenerateQueryByExample {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, value_parser = validate_package_name, required = false)]
+    package_name: Option<String>,
+
+    #[arg(long, value_parser = validate_java_class_name, required = false)]
+    helper_name: Option<String>,
+
+    #[arg(long)]
+    include_id: bool,
+
+    #[arg(long)]
+    include_relations: bool,
+  },
+  /// Find the inverse side of a relationship field
+  GetRelationshipInverse {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    field_name: String,
+  },
+  /// Add an optimistic locking version field to a JPA entity
+  CreateJPAEntityVersionField {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, default_value = "version")]
+    field_name: String,
+
+    #[arg(long, default_value = "Long")]
+    field_type: String,
+
+    #[arg(long, value_parser = validate_sql_identifier, required = false)]
+    column_name: Option<String>,
+
+    #[arg(long)]
+    column_nullable: bool,
+
+    #[arg(long, default_value = "private")]
+    field_visibility: JavaVisibilityModifier,
+
+    #[arg(long)]
+    generate_accessors: bool,
+
+    #[arg(long, default_value = "public")]
+    accessor_visibility: JavaVisibilityModifier,
+  },
+  /// Generate a mapped superclass holding auditing fields
+  GenerateAuditEntity {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, value_parser = validate_package_name, required = true)]
+    package_name: String,
+
+    #[arg(long, value_parser = validate_java_class_name, default_value = "Auditable")]
+    class_name: String,
+
+    #[arg(long, default_value = "Instant")]
+    timestamp_type: String,
+
+    #[arg(long, value_parser = validate_java_field_type, default_value = "String")]
+    auditor_type: String,
+
+    #[arg(long, value_parser = validate_package_name, required = false)]
+    auditor_type_package_name: Option<String>,
+  },
+  /// Report fields whose columns do not follow snake_case
+  EmitCamelToSnakeColumnReport {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long)]
+    implicit_names_as_is: bool,
+
+    #[arg(long)]
+    apply: bool,
+  },
+  /// Suggest or declare named entity graphs for lazy associations
+  GetEntityGraph {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long)]
+    graph_name: Option<String>,
+
+    #[arg(long, value_delimiter = ',')]
+    attribute_nodes: Vec<String>,
+  },
+  /// List the available commands and their arguments
+  ListCommands,
+  /// Move a method to another class and update its callers
+  MoveMethod {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    source_file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    target_file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    method_name: String,
+
+    #[arg(long, default_value_t = false)]
+    include_private_helpers: bool,
+
+    #[arg(long)]
+    timeout_ms: Option<u64>,
+  },
+  /// Rename a class, its file and its references
+  RenameJavaClass {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    file_path: PathBuf,
+
+    #[arg(long, value_parser = validate_java_identifier, required = true)]
+    new_name: String,
+
+    #[arg(long)]
+    timeout_ms: Option<u64>,
+  },
+  /// Rename a field, its accessors and their calls across the project
+  RenameJavaField {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    line: usize,
+
+    #[arg(long, required = true)]
+    column: usize,
+
+    #[arg(long, required = true)]
+    new_name: String,
+
+    #[arg(long)]
+    timeout_ms: Option<u64>,
+  },
+  /// Find the import for a type name
+  ResolveImportForType {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    type_name: String,
+
+    #[arg(long)]
+    timeout_ms: Option<u64>,
+  },
+  /// Delete an entity field and its accessors, warning about the references left behind
+  DeleteJPAEntityField {
+    #[arg(long, value_parser = validate_directory_unrestricted, required = true)]
+    cwd: PathBuf,
+
+    #[arg(long, required = true)]
+    entity_file_b64_src: String,
+
+    #[arg(long, required = true)]
+    entity_file_path: PathBuf,
+
+    #[arg(long, required = true)]
+    field_name: String,
+
+    #[arg(long)]
+    timeout_ms: Option<u64>,
+  },
+}
+
+impl JavaCommands {
+  pub fn execute(&self) -> Result<String, Box<dyn std::error::Error>> {
+    match self {
+      // ============ UI Commands ============
+      #[cfg(feature = "ui")]
+      JavaCommands::CreateJavaFileUi { cwd } => {
+        let form = CreateJavaFileForm::new(cwd.clone());
+        run_ui_command(form)?;
+        Ok(String::new()) // UI commands don't return JSON
+      }
+      #[cfg(feature = "ui")]
+      JavaCommands::CreateJpaEntityUi { cwd } => {
+        let form = CreateJpaEntityForm::new(cwd.clone());
+        run_ui_command(form)?;
+        Ok(String::new())
+      }
+      #[cfg(feature = "ui")]
+      JavaCommands::CreateJpaEntityBasicFieldUi { cwd, entity_file_b64_src, entity_file_path } => {
+        let form = CreateEntityFieldForm::new(
+          cwd.clone(),
+          entity_file_b64_src.clone(),
+          entity_file_path.clone(),
+        );
+        run_ui_command(form)?;
+        Ok(String::new())
+      }
+      #[cfg(feature = "ui")]
+      JavaCommands::CreateJpaOneToOneRelationshipUi {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+      } => {
+        let form = CreateEntityRelationshipForm::new(
+          cwd.clone(),
+          entity_file_b64_src.clone(),
+          entity_file_path.clone(),
+        );
+        run_ui_command(form)?;
+        Ok(String::new())
+      }
+      #[cfg(feature = "ui")]
+      JavaCommands::CreateJpaRepositoryUi { cwd, entity_file_b64_src, entity_file_path } => {
+        let form = CreateJpaRepositoryForm::new(
+          cwd.clone(),
+          entity_file_b64_src.clone(),
+          entity_file_path.clone(),
+        );
+        run_ui_command(form)?;
+        Ok(String::new())
+      }
+
+      // ============ CLI Commands ============
+      JavaCommands::GetAllJPAEntities { cwd, timeout_ms, include_commented } => {
+        let response =
+          get_all_jpa_entities_command::execute(cwd.as_path(), *timeout_ms, *include_commented);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetAllJPAMappedSuperclasses { cwd, timeout_ms } => {
+        let response = get_all_jpa_mapped_superclasses::execute(cwd.as_path(), *timeout_ms);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetJPAEntityInfo { cwd, entity_file_path, b64_source_code } => {
+        let response = get_jpa_entity_info_command::execute(
+          cwd.as_path(),
+          entity_file_path.as_deref(),
+          b64_source_code.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetSuperclassFields { cwd, entity_file_path, b64_source_code } => {
+        let response = get_superclass_fields_command::execute(
+          cwd.as_path(),
+          entity_file_path.as_deref(),
+          b64_source_code.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetAllPackages { cwd, source_directory } => {
+        let response = get_all_packages_command::execute(cwd.as_path(), source_directory);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetJavaBasicTypes { basic_type_kind } => {
+        let response = get_java_basic_types_command::execute(basic_type_kind);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetJavaFiles { cwd, file_type, modified_since, timeout_ms } => {
+        let response =
+          get_java_files_command::execute(cwd.as_path(), file_type, *modified_since, *timeout_ms);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::CreateJavaFile {
+        cwd,
+        package_name,
+        file_name,
+        file_type,
+        source_directory,
+        target_java_version,
+        template,
+      } => {
         let response = create_java_file_command::execute(
           cwd.as_path(),
-          package_name,
-          file_name,
-          file_type,
-          source_directory,
+          package_name,
+          file_name,
+          file_type,
+          source_directory,
+          *target_java_version,
+          template.as_ref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::CreateJPAEntity {
+        cwd,
+        package_name,
+        file_name,
+        superclass_type,
+        superclass_package_name,
+        no_annotations,
+        dialect,
+        quote_reserved,
+        fail_on_duplicate_name,
+        serializable,
+      } => {
+        let reserved_word_check =
+          ReservedWordCheck { dialect: dialect.clone(), quote_reserved: *quote_reserved };
+        let response = create_jpa_entity_command::execute(
+          cwd.as_path(),
+          package_name,
+          file_name,
+          superclass_type.as_deref(),
+          superclass_package_name.as_deref(),
+          !*no_annotations,
+          &reserved_word_check,
+          *fail_on_duplicate_name,
+          *serializable,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::CreateJPARepository {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        b64_superclass_source,
+      } => {
+        let response = create_jpa_repository_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          b64_superclass_source.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::CreateJPAEntityBasicField {
+        cwd,
+        entity_file_path,
+        entity_file_b64_src,
+        field_name,
+        field_type,
+        field_type_package_name,
+        field_length,
+        field_precision,
+        field_scale,
+        money_precision,
+        money_scale,
+        field_temporal,
+        field_timezone_storage,
+        field_unique,
+        field_nullable,
+        field_large_object,
+        field_optional,
+        field_fetch_type,
+        sync_length,
+        explicit_string_length,
+        field_visibility,
+        generate_accessors,
+        accessor_visibility,
+        dialect,
+        quote_reserved,
+      } => {
+        let field_config = BasicFieldConfig {
+          field_name: field_name.clone(),
+          field_type: field_type.clone(),
+          field_type_package_name: field_type_package_name.clone(),
+          field_length: *field_length,
+          field_precision: *field_precision,
+          field_scale: *field_scale,
+          money_precision: Some(*money_precision),
+          money_scale: Some(*money_scale),
+          field_temporal: field_temporal.clone(),
+          field_timezone_storage: field_timezone_storage.clone(),
+          field_unique: *field_unique,
+          field_nullable: *field_nullable,
+          field_large_object: *field_large_object,
+          field_optional: *field_optional,
+          field_fetch_type: field_fetch_type.clone(),
+          sync_length: *sync_length,
+          explicit_string_length: *explicit_string_length,
+          access_config: MemberAccessConfig {
+            field_visibility: *field_visibility,
+            generate_accessors: *generate_accessors,
+            accessor_visibility: *accessor_visibility,
+          },
+          reserved_word_check: ReservedWordCheck {
+            dialect: dialect.clone(),
+            quote_reserved: *quote_reserved,
+          },
+        };
+        let response = create_jpa_entity_basic_field_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          &field_config,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::CreateJPAEntityIdField {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        field_name,
+        field_type,
+        field_type_package_name,
+        field_id_generation,
+        field_id_generation_type,
+        field_generator_name,
+        field_sequence_name,
+        field_initial_value,
+        field_allocation_size,
+        field_nullable,
+        field_visibility,
+        generate_accessors,
+        accessor_visibility,
+      } => {
+        let field_config = IdFieldConfig {
+          field_name: field_name.clone(),
+          field_type: field_type.clone(),
+          field_type_package_name: field_type_package_name.clone(),
+          field_id_generation: field_id_generation.clone(),
+          field_id_generation_type: field_id_generation_type.clone(),
+          field_generator_name: field_generator_name.clone(),
+          field_sequence_name: field_sequence_name.clone(),
+          field_initial_value: *field_initial_value,
+          field_allocation_size: *field_allocation_size,
+          field_nullable: *field_nullable,
+          access_config: MemberAccessConfig {
+            field_visibility: *field_visibility,
+            generate_accessors: *generate_accessors,
+            accessor_visibility: *accessor_visibility,
+          },
+        };
+        let response = create_jpa_entity_id_field_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_config,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::CreateJPAEntityEnumField {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        field_name,
+        enum_type,
+        enum_package_name,
+        enum_type_storage,
+        field_length,
+        field_nullable,
+        field_unique,
+        validate_enum_type,
+        field_visibility,
+        generate_accessors,
+        accessor_visibility,
+      } => {
+        let field_config = EnumFieldConfig {
+          field_name: field_name.clone(),
+          enum_type: enum_type.clone(),
+          enum_package_name: enum_package_name.clone(),
+          enum_type_storage: enum_type_storage.clone(),
+          field_length: *field_length,
+          field_nullable: *field_nullable,
+          field_unique: *field_unique,
+          validate_enum_type: *validate_enum_type,
+          access_config: MemberAccessConfig {
+            field_visibility: *field_visibility,
+            generate_accessors: *generate_accessors,
+            accessor_visibility: *accessor_visibility,
+          },
+        };
+        let response = create_jpa_entity_enum_field_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_config,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::CreateJPAOneToOneRelationship {
+        cwd,
+        owning_side_entity_file_b64_src,
+        owning_side_entity_file_path,
+        owning_side_field_name,
+        inverse_side_field_name,
+        inverse_field_type,
+        mapping_type,
+        owning_side_cascades,
+        inverse_side_cascades,
+        owning_side_other,
+        inverse_side_other,
+        foreign_key_name,
+        no_foreign_key_constraint,
+        required,
+        insertable,
+        updatable,
+        config_json,
+      } => {
+        let config = match config_json {
+          Some(config) => config.clone(),
+          None => OneToOneFieldConfig {
+            inverse_field_type: inverse_field_type
+              .clone()
+              .ok_or("--inverse-field-type is required without --config-json")?,
+            mapping_type: mapping_type.clone(),
+            owning_side_cascades: owning_side_cascades.clone(),
+            inverse_side_cascades: inverse_side_cascades.clone(),
+            owning_side_other: owning_side_other.clone(),
+            inverse_side_other: inverse_side_other.clone(),
+            foreign_key_name: foreign_key_name.clone(),
+            no_foreign_key_constraint: *no_foreign_key_constraint,
+            required: *required,
+            insertable: *insertable,
+            updatable: *updatable,
+          },
+        };
+        let response = create_jpa_one_to_one_relationship_command::execute(
+          cwd.as_path(),
+          owning_side_entity_file_b64_src,
+          owning_side_entity_file_path.as_path(),
+          owning_side_field_name.clone(),
+          inverse_side_field_name.clone(),
+          config,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::CreateJPAManyToOneRelationship {
+        cwd,
+        owning_side_entity_file_b64_src,
+        owning_side_entity_file_path,
+        owning_side_field_name,
+        inverse_side_field_name,
+        inverse_field_type,
+        fetch_type,
+        collection_type,
+        mapping_type,
+        owning_side_cascades,
+        inverse_side_cascades,
+        owning_side_other,
+        inverse_side_other,
+        foreign_key_name,
+        no_foreign_key_constraint,
+        required,
+        insertable,
+        updatable,
+        map_key_type,
+        map_key_column,
+        config_json,
+      } => {
+        let config = match config_json {
+          Some(config) => config.clone(),
+          None => ManyToOneFieldConfig {
+            inverse_field_type: inverse_field_type
+              .clone()
+              .ok_or("--inverse-field-type is required without --config-json")?,
+            fetch_type: fetch_type
+              .clone()
+              .ok_or("--fetch-type is required without --config-json")?,
+            collection_type: collection_type
+              .clone()
+              .ok_or("--collection-type is required without --config-json")?,
+            mapping_type: mapping_type.clone(),
+            owning_side_cascades: owning_side_cascades.clone(),
+            inverse_side_cascades: inverse_side_cascades.clone(),
+            owning_side_other: owning_side_other.clone(),
+            inverse_side_other: inverse_side_other.clone(),
+            foreign_key_name: foreign_key_name.clone(),
+            no_foreign_key_constraint: *no_foreign_key_constraint,
+            required: *required,
+            insertable: *insertable,
+            updatable: *updatable,
+            map_key_type: map_key_type.clone(),
+            map_key_column: map_key_column.clone(),
+          },
+        };
+        let response = create_jpa_many_to_one_relationship_command::execute(
+          cwd.as_path(),
+          owning_side_entity_file_b64_src,
+          owning_side_entity_file_path.as_path(),
+          owning_side_field_name.clone(),
+          inverse_side_field_name.clone(),
+          config,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::AddAttributeOverride {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        field_name,
+        attribute_name,
+        column_name,
+      } => {
+        let response = add_attribute_override_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_name,
+          attribute_name,
+          column_name,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GenerateSchema { cwd, dialect, timeout_ms } => {
+        let response = generate_schema_command::execute(cwd.as_path(), dialect, *timeout_ms);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::ValidateEntity {
+        cwd,
+        entity_file_path,
+        b64_source_code,
+        dialect,
+        dynamic_update_threshold,
+      } => {
+        let response = validate_entity_command::execute(
+          cwd.as_path(),
+          entity_file_path.as_deref(),
+          b64_source_code.as_deref(),
+          dialect.clone(),
+          *dynamic_update_threshold,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::EnsureNoArgConstructor { cwd, entity_file_b64_src, entity_file_path } => {
+        let response = ensure_no_arg_constructor_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GenerateProjectionInterface {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        field_names,
+        package_name,
+        projection_name,
+      } => {
+        let response = generate_projection_interface_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_names,
+          package_name.as_deref(),
+          projection_name.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::CheckEntityCompilesAgainstRepository {
+        cwd,
+        entity_file_path,
+        repository_file_path,
+      } => {
+        let response = check_entity_compiles_against_repository_command::execute(
+          cwd.as_path(),
+          entity_file_path.as_path(),
+          repository_file_path.as_path(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::AddSecondaryTable {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        table_name,
+        pk_join_column,
+        field_names,
+      } => {
+        let response = add_secondary_table_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          table_name,
+          pk_join_column.as_deref(),
+          field_names,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetColumnMapping { cwd, entity_file_path, b64_source_code } => {
+        let response = get_column_mapping_command::execute(
+          cwd.as_path(),
+          entity_file_path.as_deref(),
+          b64_source_code.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GenerateLiquibaseChangelog {
+        cwd,
+        entity_file_path,
+        b64_source_code,
+        dialect,
+        changelog_directory,
+        author,
+        master_changelog_file_path,
+      } => {
+        let response = generate_liquibase_changelog_command::execute(
+          cwd.as_path(),
+          entity_file_path.as_deref(),
+          b64_source_code.as_deref(),
+          dialect,
+          changelog_directory.as_path(),
+          author.as_deref(),
+          master_changelog_file_path.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetAnnotations { cwd, file_path, b64_source_code, type_name } => {
+        let response = get_annotations_command::execute(
+          cwd.as_path(),
+          file_path.as_deref(),
+          b64_source_code.as_deref(),
+          type_name.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::MigrateToJakarta { cwd, source_directory, mappings } => {
+        let response =
+          migrate_to_jakarta_command::execute(cwd.as_path(), source_directory, mappings);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::AddSizeValidation {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        field_name,
+        min,
+        max,
+        sync_length,
+      } => {
+        let response = add_size_validation_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_name,
+          *min,
+          *max,
+          *sync_length,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      #[cfg(feature = "debug-tools")]
+      JavaCommands::GetParseTree { cwd, file_path, b64_source_code, line } => {
+        let response = get_parse_tree_command::execute(
+          cwd.as_path(),
+          file_path.as_deref(),
+          b64_source_code.as_deref(),
+          *line,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::RegenerateAccessors {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        field_names,
+        accessor_visibility,
+      } => {
+        let response = regenerate_accessors_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_names,
+          *accessor_visibility,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::MakeEntityImmutable {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        remove_setters,
+      } => {
+        let response = make_entity_immutable_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          *remove_setters,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::AddNestedClass {
+        cwd,
+        b64_source_code,
+        file_path,
+        outer_type_name,
+        nested_type_name,
+        nested_type,
+        visibility,
+        is_static,
+        is_final,
+        is_abstract,
+        target_java_version,
+      } => {
+        let modifiers = NestedTypeModifiers {
+          visibility_modifier: *visibility,
+          is_static: *is_static,
+          is_final: *is_final,
+          is_abstract: *is_abstract,
+        };
+        let response = add_nested_class_command::execute(
+          cwd.as_path(),
+          b64_source_code,
+          file_path.as_path(),
+          outer_type_name.as_deref(),
+          nested_type_name,
+          nested_type,
+          &modifiers,
+          *target_java_version,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::ReplaceAnnotationArgument {
+        cwd,
+        b64_source_code,
+        file_path,
+        type_name,
+        field_name,
+        annotation_name,
+        key,
+        value,
+      } => {
+        let response = replace_annotation_argument_command::execute(
+          cwd.as_path(),
+          b64_source_code,
+          file_path.as_path(),
+          type_name.as_deref(),
+          field_name.as_deref(),
+          annotation_name,
+          key,
+          value,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GenerateQueryDslMetadata { cwd, b64_source_code, file_path } => {
+        let response = generate_query_dsl_metadata_command::execute(
+          cwd.as_path(),
+          b64_source_code,
+          file_path.as_path(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::RemoveDuplicateImports { cwd, b64_source_code, file_path } => {
+        let response = remove_duplicate_imports_command::execute(
+          cwd.as_path(),
+          b64_source_code,
+          file_path.as_path(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetRelationshipTargets { cwd, entity_file_path, b64_source_code } => {
+        let response = get_relationship_targets_command::execute(
+          cwd.as_path(),
+          entity_file_path.as_deref(),
+          b64_source_code.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::AddUniqueConstraint {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        field_names,
+        constraint_name,
+      } => {
+        let response = add_unique_constraint_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_names,
+          constraint_name.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::ClearScanCache { cwd, all_projects } => {
+        let response = clear_scan_cache_command::execute(cwd.as_path(), *all_projects);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GenerateFactoryMethod { cwd, b64_source_code, file_path, method_name } => {
+        let response = generate_factory_method_command::execute(
+          cwd.as_path(),
+          b64_source_code,
+          file_path.as_path(),
+          method_name,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::SetDefaultFetch {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        fetch_type,
+        relationship_kinds,
+      } => {
+        let response = set_default_fetch_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          fetch_type,
+          relationship_kinds,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetClassMetrics { cwd, file_path, b64_source_code, type_name } => {
+        let response = get_class_metrics_command::execute(
+          cwd.as_path(),
+          file_path.as_deref(),
+          b64_source_code.as_deref(),
+          type_name.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::AddConstructorInjection {
+        cwd,
+        b64_source_code,
+        file_path,
+        dependency_type,
+        dependency_package_name,
+        field_name,
+      } => {
+        let response = add_constructor_injection_command::execute(
+          cwd.as_path(),
+          b64_source_code,
+          file_path.as_path(),
+          dependency_type,
+          dependency_package_name.as_deref(),
+          field_name.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GenerateEntityDiagram { cwd, package_name, output_file_path, timeout_ms } => {
+        let response = generate_entity_diagram_command::execute(
+          cwd.as_path(),
+          package_name.as_deref(),
+          output_file_path.as_deref(),
+          *timeout_ms,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::SetColumnName {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        field_name,
+        column_name,
+        quote_mixed_case,
+      } => {
+        let response = set_column_name_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_name,
+          column_name,
+          *quote_mixed_case,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetJPAEntitiesInfo { cwd, entity_file_paths, all_entities, timeout_ms } => {
+        let response = get_jpa_entities_info_command::execute(
+          cwd.as_path(),
+          entity_file_paths,
+          *all_entities,
+          *timeout_ms,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetIdFieldInfo { cwd, entity_file_path, b64_source_code } => {
+        let response = get_id_field_info_command::execute(
+          cwd.as_path(),
+          entity_file_path.as_deref(),
+          b64_source_code.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::AddRepositoryQueryMethod {
+        cwd,
+        b64_source_code,
+        file_path,
+        method_name,
+        pageable,
+        sort,
+      } => {
+        let response = add_repository_query_method_command::execute(
+          cwd.as_path(),
+          b64_source_code,
+          file_path.as_path(),
+          method_name,
+          *pageable,
+          *sort,
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
-      JavaCommands::CreateJPAEntity {
+      JavaCommands::GetMethodCallers { cwd, file_path, line, timeout_ms } => {
+        let response = get_method_callers_command::execute(
+          cwd.as_path(),
+          file_path.as_path(),
+          *line,
+          *timeout_ms,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::RemoveAnnotation {
+        cwd,
+        b64_source_code,
+        file_path,
+        type_name,
+        field_name,
+        annotation_name,
+        remove_import,
+      } => {
+        let response = remove_annotation_command::execute(
+          cwd.as_path(),
+          b64_source_code,
+          file_path.as_path(),
+          type_name.as_deref(),
+          field_name.as_deref(),
+          annotation_name,
+          *remove_import,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetConstructorInfo { cwd, file_path, b64_source_code, type_name } => {
+        let response = get_constructor_info_command::execute(
+          cwd.as_path(),
+          file_path.as_deref(),
+          b64_source_code.as_deref(),
+          type_name.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GenerateEntityListener {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        callbacks,
+        package_name,
+        listener_name,
+        entity_parameter,
+      } => {
+        let response = generate_entity_listener_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          callbacks,
+          package_name.as_deref(),
+          listener_name.as_deref(),
+          *entity_parameter,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetEffectiveColumnType {
+        cwd,
+        entity_file_path,
+        b64_source_code,
+        field_name,
+        dialect,
+      } => {
+        let response = get_effective_column_type_command::execute(
+          cwd.as_path(),
+          entity_file_path.as_deref(),
+          b64_source_code.as_deref(),
+          field_name,
+          dialect,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::RenameColumn {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        field_name,
+        column_name,
+      } => {
+        let response = rename_column_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_name,
+          column_name,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::ValidateProject { cwd, dialect, fail_on } => {
+        let response = validate_project_command::execute(cwd.as_path(), dialect.clone(), *fail_on);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::InvalidateParseCache { cwd, file_path } => {
+        let response = invalidate_parse_cache_command::execute(cwd.as_path(), file_path.as_deref());
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GenerateTestDataBuilder {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        package_name,
+        builder_name,
+        include_relations,
+      } => {
+        let response = generate_test_data_builder_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          package_name.as_deref(),
+          builder_name.as_deref(),
+          *include_relations,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::MergePackages { cwd, source_package, destination_package } => {
+        let response =
+          merge_packages_command::execute(cwd.as_path(), source_package, destination_package);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetTypeDefinitionLocation { cwd, file_path, line, column } => {
+        let response = get_type_definition_location_command::execute(
+          cwd.as_path(),
+          file_path.as_path(),
+          *line,
+          *column,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GenerateControllerTest {
+        cwd,
+        controller_file_b64_src,
+        controller_file_path,
+        package_name,
+        test_name,
+      } => {
+        let response = generate_controller_test_command::execute(
+          cwd.as_path(),
+          controller_file_b64_src,
+          controller_file_path.as_path(),
+          package_name.as_deref(),
+          test_name.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::RemoveRelationship {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        field_name,
+        owning_only,
+      } => {
+        let response = remove_relationship_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_name,
+          *owning_only,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetFileDiagnostics { cwd, file_path, b64_source_code } => {
+        let response = get_file_diagnostics_command::execute(
+          cwd.as_path(),
+          file_path.as_deref(),
+          b64_source_code.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::CreateJPAEmbeddable {
         cwd,
         package_name,
         file_name,
-        superclass_type,
-        superclass_package_name,
+        fields,
+        composite_key,
+        serializable,
       } => {
-        let response = create_jpa_entity_command::execute(
+        let response = create_jpa_embeddable_command::execute(
           cwd.as_path(),
           package_name,
           file_name,
-          superclass_type.as_deref(),
-          superclass_package_name.as_deref(),
+          fields,
+          *composite_key,
+          *serializable,
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
-      JavaCommands::CreateJPARepository {
+      JavaCommands::ConvertToLombokBuilder { cwd, b64_source_code, file_path } => {
+        let response = convert_to_lombok_builder_command::execute(
+          cwd.as_path(),
+          b64_source_code,
+          file_path.as_path(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetEntityByTableName { cwd, table_name } => {
+        let response = get_entity_by_table_name_command::execute(cwd.as_path(), table_name);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GenerateQueryByExample {
         cwd,
         entity_file_b64_src,
         entity_file_path,
-        b64_superclass_source,
+        package_name,
+        helper_name,
+        include_id,
+        include_relations,
       } => {
-        let response = create_jpa_repository_command::execute(
+        let response = generate_query_by_example_command::execute(
           cwd.as_path(),
           entity_file_b64_src,
           entity_file_path.as_path(),
-          b64_superclass_source.as_deref(),
+          package_name.as_deref(),
+          helper_name.as_deref(),
+          *include_id,
+          *include_relations,
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
-      JavaCommands::CreateJPAEntityBasicField {
+      JavaCommands::GetRelationshipInverse {
         cwd,
-        entity_file_path,
         entity_file_b64_src,
+        entity_file_path,
         field_name,
-        field_type,
-        field_type_package_name,
-        field_length,
-        field_precision,
-        field_scale,
-        field_temporal,
-        field_timezone_storage,
-        field_unique,
-        field_nullable,
-        field_large_object,
       } => {
-        let field_config = BasicFieldConfig {
-          field_name: field_name.clone(),
-          field_type: field_type.clone(),
-          field_type_package_name: field_type_package_name.clone(),
-          field_length: *field_length,
-          field_precision: *field_precision,
-          field_scale: *field_scale,
-          field_temporal: field_temporal.clone(),
-          field_timezone_storage: field_timezone_storage.clone(),
-          field_unique: *field_unique,
-          field_nullable: *field_nullable,
-          field_large_object: *field_large_object,
-        };
-        let response = create_jpa_entity_basic_field_command::execute(
+        let response = get_relationship_inverse_command::execute(
           cwd.as_path(),
           entity_file_b64_src,
           entity_file_path.as_path(),
-          &field_config,
+          field_name,
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
-      JavaCommands::CreateJPAEntityIdField {
+      JavaCommands::CreateJPAEntityVersionField {
         cwd,
         entity_file_b64_src,
         entity_file_path,
         field_name,
         field_type,
-        field_type_package_name,
-        field_id_generation,
-        field_id_generation_type,
-        field_generator_name,
-        field_sequence_name,
-        field_initial_value,
-        field_allocation_size,
-        field_nullable,
+        column_name,
+        column_nullable,
+        field_visibility,
+        generate_accessors,
+        accessor_visibility,
       } => {
-        let field_config = IdFieldConfig {
+        let field_config = VersionFieldConfig {
           field_name: field_name.clone(),
           field_type: field_type.clone(),
-          field_type_package_name: field_type_package_name.clone(),
-          field_id_generation: field_id_generation.clone(),
-          field_id_generation_type: field_id_generation_type.clone(),
-          field_generator_name: field_generator_name.clone(),
-          field_sequence_name: field_sequence_name.clone(),
-          field_initial_value: *field_initial_value,
-          field_allocation_size: *field_allocation_size,
-          field_nullable: *field_nullable,
+          column_name: column_name.clone(),
+          column_nullable: *column_nullable,
+          access_config: MemberAccessConfig {
+            field_visibility: *field_visibility,
+            generate_accessors: *generate_accessors,
+            accessor_visibility: *accessor_visibility,
+          },
         };
-        let response = create_jpa_entity_id_field_command::execute(
+        let response = create_jpa_entity_version_field_command::execute(
           cwd.as_path(),
           entity_file_b64_src,
           entity_file_path.as_path(),
@@ -560,98 +2607,117 @@ impl JavaCommands {
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
-      JavaCommands::CreateJPAEntityEnumField {
+      JavaCommands::GenerateAuditEntity {
+        cwd,
+        package_name,
+        class_name,
+        timestamp_type,
+        auditor_type,
+        auditor_type_package_name,
+      } => {
+        let response = generate_audit_entity_command::execute(
+          cwd.as_path(),
+          package_name,
+          class_name,
+          timestamp_type,
+          auditor_type,
+          auditor_type_package_name.as_deref(),
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::EmitCamelToSnakeColumnReport { cwd, implicit_names_as_is, apply } => {
+        let response = emit_camel_to_snake_column_report_command::execute(
+          cwd.as_path(),
+          *implicit_names_as_is,
+          *apply,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::GetEntityGraph {
         cwd,
         entity_file_b64_src,
         entity_file_path,
-        field_name,
-        enum_type,
-        enum_package_name,
-        enum_type_storage,
-        field_length,
-        field_nullable,
-        field_unique,
+        graph_name,
+        attribute_nodes,
       } => {
-        let field_config = EnumFieldConfig {
-          field_name: field_name.clone(),
-          enum_type: enum_type.clone(),
-          enum_package_name: enum_package_name.clone(),
-          enum_type_storage: enum_type_storage.clone(),
-          field_length: *field_length,
-          field_nullable: *field_nullable,
-          field_unique: *field_unique,
-        };
-        let response = create_jpa_entity_enum_field_command::execute(
+        let response = get_entity_graph_command::execute(
           cwd.as_path(),
           entity_file_b64_src,
           entity_file_path.as_path(),
-          field_config,
+          graph_name.as_deref(),
+          attribute_nodes,
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
-      JavaCommands::CreateJPAOneToOneRelationship {
+      JavaCommands::ListCommands => {
+        let response = list_commands_command::execute();
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::MoveMethod {
         cwd,
-        owning_side_entity_file_b64_src,
-        owning_side_entity_file_path,
-        owning_side_field_name,
-        inverse_side_field_name,
-        inverse_field_type,
-        mapping_type,
-        owning_side_cascades,
-        inverse_side_cascades,
-        owning_side_other,
-        inverse_side_other,
+        source_file_path,
+        target_file_path,
+        method_name,
+        include_private_helpers,
+        timeout_ms,
       } => {
-        let config = OneToOneFieldConfig {
-          inverse_field_type: inverse_field_type.clone(),
-          mapping_type: mapping_type.clone(),
-          owning_side_cascades: owning_side_cascades.clone(),
-          inverse_side_cascades: inverse_side_cascades.clone(),
-          owning_side_other: owning_side_other.clone(),
-          inverse_side_other: inverse_side_other.clone(),
-        };
-        let response = create_jpa_one_to_one_relationship_command::execute(
+        let response = move_method_command::execute(
           cwd.as_path(),
-          owning_side_entity_file_b64_src,
-          owning_side_entity_file_path.as_path(),
-          owning_side_field_name.clone(),
-          inverse_side_field_name.clone(),
-          config,
+          source_file_path.as_path(),
+          target_file_path.as_path(),
+          method_name,
+          *include_private_helpers,
+          *timeout_ms,
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
-      JavaCommands::CreateJPAManyToOneRelationship {
+      JavaCommands::RenameJavaClass { cwd, file_path, new_name, timeout_ms } => {
+        let response = rename_java_class_command::execute(
+          cwd.as_path(),
+          file_path.as_path(),
+          new_name,
+          *timeout_ms,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::RenameJavaField {
         cwd,
-        owning_side_entity_file_b64_src,
-        owning_side_entity_file_path,
-        owning_side_field_name,
-        inverse_side_field_name,
-        inverse_field_type,
-        fetch_type,
-        collection_type,
-        mapping_type,
-        owning_side_cascades,
-        inverse_side_cascades,
-        owning_side_other,
-        inverse_side_other,
+        entity_file_b64_src,
+        entity_file_path,
+        line,
+        column,
+        new_name,
+        timeout_ms,
       } => {
-        let config = ManyToOneFieldConfig {
-          inverse_field_type: inverse_field_type.clone(),
-          fetch_type: fetch_type.clone(),
-          collection_type: collection_type.clone(),
-          mapping_type: mapping_type.clone(),
-          owning_side_cascades: owning_side_cascades.clone(),
-          inverse_side_cascades: inverse_side_cascades.clone(),
-          owning_side_other: owning_side_other.clone(),
-          inverse_side_other: inverse_side_other.clone(),
-        };
-        let response = create_jpa_many_to_one_relationship_command::execute(
+        let response = rename_java_field_command::execute(
           cwd.as_path(),
-          owning_side_entity_file_b64_src,
-          owning_side_entity_file_path.as_path(),
-          owning_side_field_name.clone(),
-          inverse_side_field_name.clone(),
-          config,
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          *line,
+          *column,
+          new_name,
+          *timeout_ms,
+        );
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::ResolveImportForType { cwd, type_name, timeout_ms } => {
+        let response =
+          resolve_import_for_type_command::execute(cwd.as_path(), type_name, *timeout_ms);
+        response.to_json_pretty().map_err(|e| e.into())
+      }
+      JavaCommands::DeleteJPAEntityField {
+        cwd,
+        entity_file_b64_src,
+        entity_file_path,
+        field_name,
+        timeout_ms,
+      } => {
+        let response = delete_jpa_entity_field_command::execute(
+          cwd.as_path(),
+          entity_file_b64_src,
+          entity_file_path.as_path(),
+          field_name,
+          *timeout_ms,
         );
         response.to_json_pretty().map_err(|e| e.into())
       }
@@ -2,13 +2,17 @@ use std::path::Path;
 
 use crate::{
   commands::java::{
-    responses::file_response::FileResponse,
+    responses::create_java_file_response::CreateJavaFileResponse,
     services::create_java_file_service::run,
     treesitter::types::{
-      java_file_type::JavaFileType, java_source_directory_type::JavaSourceDirectoryType,
+      java_file_template::JavaFileTemplate, java_file_type::JavaFileType,
+      java_source_directory_type::JavaSourceDirectoryType,
     },
   },
-  common::{response::Response, utils::case_util},
+  common::{
+    response::Response,
+    utils::{build_file_util::detect_java_version, case_util},
+  },
 };
 
 pub fn execute(
@@ -17,12 +21,28 @@ pub fn execute(
   file_name: &str,
   file_type: &JavaFileType,
   source_directory: &JavaSourceDirectoryType,
-) -> Response<FileResponse> {
+  target_java_version: Option<u32>,
+  template: Option<&JavaFileTemplate>,
+) -> Response<CreateJavaFileResponse> {
   let normalized_file_name = case_util::to_pascal_case(file_name);
   let cwd_string = cwd.display().to_string();
   let cmd_name = String::from("create-java-file");
-  match run(cwd, package_name, &normalized_file_name, file_type, source_directory) {
-    Ok(response) => Response::success(cmd_name, cwd_string, response),
+  // Without an explicit target the version stated in the build file applies
+  let target_java_version = target_java_version.or_else(|| detect_java_version(cwd));
+  match run(
+    cwd,
+    package_name,
+    &normalized_file_name,
+    file_type,
+    source_directory,
+    target_java_version,
+    template,
+  ) {
+    Ok(file) => Response::success(
+      cmd_name,
+      cwd_string,
+      CreateJavaFileResponse { file, template: template.copied() },
+    ),
     Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
   }
 }
@@ -0,0 +1,24 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::responses::create_jpa_embeddable_response::CreateJPAEmbeddableResponse,
+  commands::java::services::create_jpa_embeddable_service::run,
+  commands::java::treesitter::types::basic_field_config::BasicFieldConfig,
+  common::response::Response,
+};
+
+pub fn execute(
+  cwd: &Path,
+  package_name: &str,
+  file_name: &str,
+  fields: &[BasicFieldConfig],
+  composite_key: bool,
+  serializable: Option<bool>,
+) -> Response<CreateJPAEmbeddableResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("create-jpa-embeddable");
+  match run(cwd, package_name, file_name, fields, composite_key, serializable) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -1,20 +1,37 @@
 use std::path::Path;
 
 use crate::{
-  commands::java::responses::file_response::FileResponse,
-  commands::java::services::create_jpa_entity_service::run, common::response::Response,
+  commands::java::responses::create_jpa_entity_response::CreateJPAEntityResponse,
+  commands::java::services::create_jpa_entity_service::run,
+  commands::java::treesitter::types::reserved_word_check::ReservedWordCheck,
+  common::response::Response,
 };
 
+#[allow(clippy::too_many_arguments)]
 pub fn execute(
   cwd: &Path,
   package_name: &str,
   file_name: &str,
   superclass_type: Option<&str>,
   superclass_package_name: Option<&str>,
-) -> Response<FileResponse> {
+  emit_annotations: bool,
+  reserved_word_check: &ReservedWordCheck,
+  fail_on_duplicate_name: bool,
+  serializable: bool,
+) -> Response<CreateJPAEntityResponse> {
   let cwd_string = cwd.display().to_string();
   let cmd_name = String::from("create-jpa-entity");
-  match run(cwd, package_name, file_name, superclass_type, superclass_package_name) {
+  match run(
+    cwd,
+    package_name,
+    file_name,
+    superclass_type,
+    superclass_package_name,
+    emit_annotations,
+    reserved_word_check,
+    fail_on_duplicate_name,
+    serializable,
+  ) {
     Ok(response) => Response::success(cmd_name, cwd_string, response),
     Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
   }
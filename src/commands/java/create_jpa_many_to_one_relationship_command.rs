@@ -2,7 +2,7 @@ use std::path::Path;
 
 use crate::{
   commands::java::{
-    responses::get_files_response::GetFilesResponse,
+    responses::multi_file_response::MultiFileResponse,
     services::create_jpa_many_to_one_relationship_service,
     treesitter::types::many_to_one_field_config::ManyToOneFieldConfig,
   },
@@ -16,7 +16,7 @@ pub fn execute(
   owning_side_field_name: String,
   inverse_side_field_name: String,
   config: ManyToOneFieldConfig,
-) -> Response<GetFilesResponse> {
+) -> Response<MultiFileResponse> {
   let cwd_string = cwd.display().to_string();
   let cmd_name = String::from("create-jpa-many-to-one-relationship");
   // Path containment validation: ensure owning side entity file path is within the cwd
@@ -37,11 +37,7 @@ pub fn execute(
     &inverse_side_field_name,
     &config,
   ) {
-    Ok(files) => {
-      let files_count = files.len();
-      let response = GetFilesResponse { files, files_count };
-      Response::success(cmd_name, cwd_string, response)
-    }
+    Ok(files) => Response::success(cmd_name, cwd_string, MultiFileResponse::new(files)),
     Err(e) => Response::error(cmd_name, cwd_string, e.to_string()),
   }
 }
@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::file_response::FileResponse, services::delete_jpa_entity_field_service,
+  },
+  common::{
+    response::Response, utils::scan_deadline::ScanDeadline,
+    validators::directory_validator::validate_file_path_within_base,
+  },
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  timeout_ms: Option<u64>,
+) -> Response<FileResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("delete-jpa-entity-field");
+  // Path containment validation: ensure entity file path is within the cwd
+  let file_path_str = entity_file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("Entity file path must be within working directory: {}", error_msg),
+    );
+  }
+
+  let deadline = ScanDeadline::new(timeout_ms);
+  match delete_jpa_entity_field_service::run(
+    cwd,
+    entity_file_b64_src,
+    entity_file_path,
+    field_name,
+    &deadline,
+  ) {
+    Ok(file) => Response::success(cmd_name, cwd_string, file),
+    Err(e) => Response::error(cmd_name, cwd_string, e),
+  }
+}
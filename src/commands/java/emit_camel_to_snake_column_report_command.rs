@@ -0,0 +1,20 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::responses::emit_camel_to_snake_column_report_response::EmitCamelToSnakeColumnReportResponse,
+  commands::java::services::emit_camel_to_snake_column_report_service::run,
+  common::response::Response,
+};
+
+pub fn execute(
+  cwd: &Path,
+  implicit_names_as_is: bool,
+  apply: bool,
+) -> Response<EmitCamelToSnakeColumnReportResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("emit-camel-to-snake-column-report");
+  match run(cwd, implicit_names_as_is, apply) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,23 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::responses::generate_audit_entity_response::GenerateAuditEntityResponse,
+  commands::java::services::generate_audit_entity_service::run, common::response::Response,
+};
+
+pub fn execute(
+  cwd: &Path,
+  package_name: &str,
+  class_name: &str,
+  timestamp_type: &str,
+  auditor_type: &str,
+  auditor_type_package_name: Option<&str>,
+) -> Response<GenerateAuditEntityResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("generate-audit-entity");
+  match run(cwd, package_name, class_name, timestamp_type, auditor_type, auditor_type_package_name)
+  {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::generate_controller_test_response::GenerateControllerTestResponse,
+    services::generate_controller_test_service::run,
+  },
+  common::{
+    response::Response, utils::case_util,
+    validators::directory_validator::validate_file_path_within_base,
+  },
+};
+
+pub fn execute(
+  cwd: &Path,
+  controller_file_b64_src: &str,
+  controller_file_path: &Path,
+  package_name: Option<&str>,
+  test_name: Option<&str>,
+) -> Response<GenerateControllerTestResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("generate-controller-test");
+  // Path containment validation: ensure controller file path is within the cwd
+  let file_path_str = controller_file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("Controller file path must be within working directory: {}", error_msg),
+    );
+  }
+  let normalized_test_name = test_name.map(case_util::to_pascal_case);
+
+  match run(cwd, controller_file_b64_src, package_name, normalized_test_name.as_deref()) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::generate_entity_diagram_response::GenerateEntityDiagramResponse,
+    services::generate_entity_diagram_service::run,
+  },
+  common::{
+    response::Response, utils::scan_deadline::ScanDeadline,
+    validators::directory_validator::validate_file_path_within_base,
+  },
+};
+
+pub fn execute(
+  cwd: &Path,
+  package_name: Option<&str>,
+  output_file_path: Option<&Path>,
+  timeout_ms: Option<u64>,
+) -> Response<GenerateEntityDiagramResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("generate-entity-diagram");
+  // Path containment validation: ensure output file path (if provided) is within the cwd
+  if let Some(output_file_path) = output_file_path {
+    let output_file_path_str = output_file_path.display().to_string();
+    if let Err(error_msg) = validate_file_path_within_base(&output_file_path_str, cwd) {
+      return Response::error(
+        cmd_name,
+        cwd_string,
+        format!("Output file path must be within working directory: {}", error_msg),
+      );
+    }
+  }
+
+  match run(cwd, package_name, output_file_path, &ScanDeadline::new(timeout_ms)) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::generate_entity_listener_response::GenerateEntityListenerResponse,
+    services::generate_entity_listener_service::run,
+    treesitter::types::lifecycle_callback_type::LifecycleCallbackType,
+  },
+  common::{
+    response::Response, utils::case_util,
+    validators::directory_validator::validate_file_path_within_base,
+  },
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  callbacks: &[LifecycleCallbackType],
+  package_name: Option<&str>,
+  listener_name: Option<&str>,
+  entity_parameter: bool,
+) -> Response<GenerateEntityListenerResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("generate-entity-listener");
+  // Path containment validation: ensure entity file path is within the cwd
+  let file_path_str = entity_file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("Entity file path must be within working directory: {}", error_msg),
+    );
+  }
+  let normalized_listener_name = listener_name.map(case_util::to_pascal_case);
+
+  match run(
+    cwd,
+    entity_file_b64_src,
+    entity_file_path,
+    callbacks,
+    package_name,
+    normalized_listener_name.as_deref(),
+    entity_parameter,
+  ) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
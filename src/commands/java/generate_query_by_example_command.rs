@@ -0,0 +1,47 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::generate_query_by_example_response::GenerateQueryByExampleResponse,
+    services::generate_query_by_example_service::run,
+  },
+  common::{
+    response::Response, utils::case_util,
+    validators::directory_validator::validate_file_path_within_base,
+  },
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  package_name: Option<&str>,
+  helper_name: Option<&str>,
+  include_id: bool,
+  include_relations: bool,
+) -> Response<GenerateQueryByExampleResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("generate-query-by-example");
+  // Path containment validation: ensure entity file path is within the cwd
+  let file_path_str = entity_file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("Entity file path must be within working directory: {}", error_msg),
+    );
+  }
+  let normalized_helper_name = helper_name.map(case_util::to_pascal_case);
+
+  match run(
+    cwd,
+    entity_file_b64_src,
+    package_name,
+    normalized_helper_name.as_deref(),
+    include_id,
+    include_relations,
+  ) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,22 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::generate_schema_response::GenerateSchemaResponse,
+    services::generate_schema_service::run, treesitter::types::sql_dialect::SqlDialect,
+  },
+  common::{response::Response, utils::scan_deadline::ScanDeadline},
+};
+
+pub fn execute(
+  cwd: &Path,
+  dialect: &SqlDialect,
+  timeout_ms: Option<u64>,
+) -> Response<GenerateSchemaResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("generate-schema");
+  match run(cwd, dialect, &ScanDeadline::new(timeout_ms)) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
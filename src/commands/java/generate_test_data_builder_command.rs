@@ -0,0 +1,45 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::generate_test_data_builder_response::GenerateTestDataBuilderResponse,
+    services::generate_test_data_builder_service::run,
+  },
+  common::{
+    response::Response, utils::case_util,
+    validators::directory_validator::validate_file_path_within_base,
+  },
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  package_name: Option<&str>,
+  builder_name: Option<&str>,
+  include_relations: bool,
+) -> Response<GenerateTestDataBuilderResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("generate-test-data-builder");
+  // Path containment validation: ensure entity file path is within the cwd
+  let file_path_str = entity_file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("Entity file path must be within working directory: {}", error_msg),
+    );
+  }
+  let normalized_builder_name = builder_name.map(case_util::to_pascal_case);
+
+  match run(
+    cwd,
+    entity_file_b64_src,
+    package_name,
+    normalized_builder_name.as_deref(),
+    include_relations,
+  ) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
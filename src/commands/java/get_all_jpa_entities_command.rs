@@ -2,16 +2,29 @@ use std::path::Path;
 
 use crate::{
   commands::java::responses::get_files_response::GetFilesResponse,
-  commands::java::services::get_all_jpa_entities_service::run, common::response::Response,
+  commands::java::services::get_all_jpa_entities_service::run,
+  common::{response::Response, utils::scan_deadline::ScanDeadline},
 };
 
-pub fn execute(cwd: &Path) -> Response<GetFilesResponse> {
+pub fn execute(
+  cwd: &Path,
+  timeout_ms: Option<u64>,
+  include_commented: bool,
+) -> Response<GetFilesResponse> {
   let cwd_string = cwd.display().to_string();
   let cmd_name = String::from("get-all-jpa-entities");
-  match run(cwd) {
+  let deadline = ScanDeadline::new(timeout_ms);
+  match run(cwd, &deadline, include_commented) {
     Ok(files) => {
       let files_count = files.len();
-      let response = GetFilesResponse { files, files_count };
+      let response = GetFilesResponse {
+        files,
+        files_count,
+        truncated: deadline.is_truncated(),
+        processed_files_count: Some(deadline.processed_files_count()),
+        modified_times_ms: None,
+        skipped_files: deadline.skipped_files(),
+      };
       Response::success(cmd_name, cwd_string, response)
     }
     Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
@@ -4,16 +4,24 @@ use crate::{
   commands::java::{
     responses::get_files_response::GetFilesResponse, services::get_all_jpa_mapped_superclasses::run,
   },
-  common::response::Response,
+  common::{response::Response, utils::scan_deadline::ScanDeadline},
 };
 
-pub fn execute(cwd: &Path) -> Response<GetFilesResponse> {
+pub fn execute(cwd: &Path, timeout_ms: Option<u64>) -> Response<GetFilesResponse> {
   let cwd_string = cwd.display().to_string();
   let cmd_name = String::from("get-all-jpa-mapped-superclasses");
-  match run(cwd) {
+  let deadline = ScanDeadline::new(timeout_ms);
+  match run(cwd, &deadline) {
     Ok(files) => {
       let files_count = files.len();
-      let response = GetFilesResponse { files, files_count };
+      let response = GetFilesResponse {
+        files,
+        files_count,
+        truncated: deadline.is_truncated(),
+        processed_files_count: Some(deadline.processed_files_count()),
+        modified_times_ms: None,
+        skipped_files: deadline.skipped_files(),
+      };
       Response::success(cmd_name, cwd_string, response)
     }
     Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
@@ -0,0 +1,35 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::get_annotations_response::GetAnnotationsResponse,
+    services::get_annotations_service::run,
+  },
+  common::{response::Response, validators::directory_validator::validate_file_path_within_base},
+};
+
+pub fn execute(
+  cwd: &Path,
+  file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  type_name: Option<&str>,
+) -> Response<GetAnnotationsResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("get-annotations");
+  // Path containment validation: ensure file path (if provided) is within the cwd
+  if let Some(file_path) = file_path {
+    let file_path_str = file_path.display().to_string();
+    if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+      return Response::error(
+        cmd_name,
+        cwd_string,
+        format!("File path must be within working directory: {}", error_msg),
+      );
+    }
+  }
+
+  match run(file_path, b64_source_code, type_name, cwd) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
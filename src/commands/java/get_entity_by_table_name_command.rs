@@ -0,0 +1,19 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::get_entity_by_table_name_response::GetEntityByTableNameResponse,
+    services::get_entity_by_table_name_service::run,
+  },
+  common::response::Response,
+};
+
+pub fn execute(cwd: &Path, table_name: &str) -> Response<GetEntityByTableNameResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("get-entity-by-table-name");
+
+  match run(cwd, table_name) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::get_entity_graph_response::GetEntityGraphResponse,
+    services::get_entity_graph_service::run,
+  },
+  common::{response::Response, validators::directory_validator::validate_file_path_within_base},
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  graph_name: Option<&str>,
+  attribute_nodes: &[String],
+) -> Response<GetEntityGraphResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("get-entity-graph");
+  // Path containment validation: ensure entity file path is within the cwd
+  let file_path_str = entity_file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("Entity file path must be within working directory: {}", error_msg),
+    );
+  }
+
+  match run(cwd, entity_file_b64_src, entity_file_path, graph_name, attribute_nodes) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -3,16 +3,30 @@ use std::path::Path;
 use crate::{
   commands::java::responses::get_files_response::GetFilesResponse,
   commands::java::services::get_java_files_service::run,
-  commands::java::treesitter::types::java_file_type::JavaFileType, common::response::Response,
+  commands::java::treesitter::types::java_file_type::JavaFileType,
+  common::{response::Response, utils::scan_deadline::ScanDeadline},
 };
 
-pub fn execute(cwd: &Path, file_type: &JavaFileType) -> Response<GetFilesResponse> {
+pub fn execute(
+  cwd: &Path,
+  file_type: &JavaFileType,
+  modified_since_ms: Option<u64>,
+  timeout_ms: Option<u64>,
+) -> Response<GetFilesResponse> {
   let cwd_string = cwd.display().to_string();
   let cmd_name = String::from("get-java-files");
-  match run(cwd, file_type) {
-    Ok(files) => {
+  let deadline = ScanDeadline::new(timeout_ms);
+  match run(cwd, file_type, modified_since_ms, &deadline) {
+    Ok((files, modified_times_ms)) => {
       let files_count = files.len();
-      let response = GetFilesResponse { files, files_count };
+      let response = GetFilesResponse {
+        files,
+        files_count,
+        truncated: deadline.is_truncated(),
+        processed_files_count: Some(deadline.processed_files_count()),
+        modified_times_ms: Some(modified_times_ms),
+        skipped_files: deadline.skipped_files(),
+      };
       Response::success(cmd_name, cwd_string, response)
     }
     Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
@@ -0,0 +1,43 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+  commands::java::{
+    responses::get_jpa_entities_info_response::GetJpaEntitiesInfoResponse,
+    services::get_jpa_entities_info_service::run,
+  },
+  common::{response::Response, utils::scan_deadline::ScanDeadline},
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_paths: &[PathBuf],
+  all_entities: bool,
+  timeout_ms: Option<u64>,
+) -> Response<GetJpaEntitiesInfoResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("get-jpa-entities-info");
+  // Path containment is validated per file so a single bad path does not fail the whole batch
+  let entity_file_paths = match (entity_file_paths.is_empty(), all_entities) {
+    (true, true) => None,
+    (false, false) => Some(entity_file_paths),
+    (true, false) => {
+      return Response::error(
+        cmd_name,
+        cwd_string,
+        "Either --entity-file-paths or --all-entities must be provided".to_string(),
+      );
+    }
+    (false, true) => {
+      return Response::error(
+        cmd_name,
+        cwd_string,
+        "--entity-file-paths and --all-entities cannot be used together".to_string(),
+      );
+    }
+  };
+  let deadline = ScanDeadline::new(timeout_ms);
+  match run(cwd, entity_file_paths, &deadline) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
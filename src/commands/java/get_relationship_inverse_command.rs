@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::get_relationship_inverse_response::GetRelationshipInverseResponse,
+    services::get_relationship_inverse_service,
+  },
+  common::{response::Response, validators::directory_validator::validate_file_path_within_base},
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+) -> Response<GetRelationshipInverseResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("get-relationship-inverse");
+  // Path containment validation: ensure entity file path is within the cwd
+  let file_path_str = entity_file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("Entity file path must be within working directory: {}", error_msg),
+    );
+  }
+
+  match get_relationship_inverse_service::run(
+    cwd,
+    entity_file_b64_src,
+    entity_file_path,
+    field_name,
+  ) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(e) => Response::error(cmd_name, cwd_string, e),
+  }
+}
@@ -0,0 +1,33 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::get_type_definition_location_response::GetTypeDefinitionLocationResponse,
+    services::get_type_definition_location_service::run,
+  },
+  common::{response::Response, validators::directory_validator::validate_file_path_within_base},
+};
+
+pub fn execute(
+  cwd: &Path,
+  file_path: &Path,
+  line: usize,
+  column: usize,
+) -> Response<GetTypeDefinitionLocationResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("get-type-definition-location");
+  // Path containment validation: ensure file path is within the cwd
+  let file_path_str = file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("File path must be within working directory: {}", error_msg),
+    );
+  }
+
+  match run(cwd, file_path, line, column) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
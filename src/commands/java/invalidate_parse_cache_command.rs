@@ -0,0 +1,29 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::invalidate_parse_cache_response::InvalidateParseCacheResponse,
+    services::invalidate_parse_cache_service::run,
+  },
+  common::{response::Response, validators::directory_validator::validate_file_path_within_base},
+};
+
+pub fn execute(cwd: &Path, file_path: Option<&Path>) -> Response<InvalidateParseCacheResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("invalidate-parse-cache");
+  // Path containment validation: ensure the file path (if provided) is within the cwd
+  if let Some(file_path) = file_path {
+    let file_path_str = file_path.display().to_string();
+    if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+      return Response::error(
+        cmd_name,
+        cwd_string,
+        format!("File path must be within working directory: {}", error_msg),
+      );
+    }
+  }
+  match run(file_path) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
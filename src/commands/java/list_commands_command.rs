@@ -0,0 +1,21 @@
+use std::env;
+
+use clap::{Command, Subcommand};
+
+use crate::{
+  commands::java::{
+    JavaCommands, responses::list_commands_response::ListCommandsResponse,
+    services::list_commands_service::run,
+  },
+  common::response::Response,
+};
+
+pub fn execute() -> Response<ListCommandsResponse> {
+  let cwd_string = env::current_dir().map(|cwd| cwd.display().to_string()).unwrap_or_default();
+  let cmd_name = String::from("list-commands");
+  let java_command = JavaCommands::augment_subcommands(Command::new("java"));
+  match run(&java_command) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
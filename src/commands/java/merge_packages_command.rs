@@ -0,0 +1,22 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::merge_packages_response::MergePackagesResponse,
+    services::merge_packages_service::run,
+  },
+  common::response::Response,
+};
+
+pub fn execute(
+  cwd: &Path,
+  source_package: &str,
+  destination_package: &str,
+) -> Response<MergePackagesResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("merge-packages");
+  match run(cwd, source_package, destination_package) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
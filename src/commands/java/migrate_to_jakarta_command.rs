@@ -0,0 +1,25 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::migrate_to_jakarta_response::MigrateToJakartaResponse,
+    services::migrate_to_jakarta_service::run,
+    treesitter::types::{
+      java_source_directory_type::JavaSourceDirectoryType, namespace_mapping::NamespaceMapping,
+    },
+  },
+  common::response::Response,
+};
+
+pub fn execute(
+  cwd: &Path,
+  source_directory: &JavaSourceDirectoryType,
+  additional_mappings: &[NamespaceMapping],
+) -> Response<MigrateToJakartaResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("migrate-to-jakarta");
+  match run(cwd, source_directory, additional_mappings) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
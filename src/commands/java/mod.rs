@@ -1,20 +1,83 @@
 // Command modules
+pub mod add_attribute_override_command;
+pub mod add_constructor_injection_command;
+pub mod add_nested_class_command;
+pub mod add_repository_query_method_command;
+pub mod add_secondary_table_command;
+pub mod add_size_validation_command;
+pub mod add_unique_constraint_command;
+pub mod check_entity_compiles_against_repository_command;
+pub mod clear_scan_cache_command;
+pub mod convert_to_lombok_builder_command;
 pub mod create_java_file_command;
+pub mod create_jpa_embeddable_command;
 pub mod create_jpa_entity_basic_field_command;
 pub mod create_jpa_entity_command;
 pub mod create_jpa_entity_enum_field_command;
 pub mod create_jpa_entity_id_field_command;
+pub mod create_jpa_entity_version_field_command;
 pub mod create_jpa_many_to_one_relationship_command;
 pub mod create_jpa_one_to_one_relationship_command;
 pub mod create_jpa_repository_command;
+pub mod delete_jpa_entity_field_command;
+pub mod emit_camel_to_snake_column_report_command;
+pub mod ensure_no_arg_constructor_command;
+pub mod generate_audit_entity_command;
+pub mod generate_controller_test_command;
+pub mod generate_entity_diagram_command;
+pub mod generate_entity_listener_command;
+pub mod generate_factory_method_command;
+pub mod generate_liquibase_changelog_command;
+pub mod generate_projection_interface_command;
+pub mod generate_query_by_example_command;
+pub mod generate_query_dsl_metadata_command;
+pub mod generate_schema_command;
+pub mod generate_test_data_builder_command;
 pub mod get_all_jpa_entities_command;
 pub mod get_all_jpa_mapped_superclasses;
 pub mod get_all_packages_command;
+pub mod get_annotations_command;
+pub mod get_class_metrics_command;
+pub mod get_column_mapping_command;
+pub mod get_constructor_info_command;
+pub mod get_effective_column_type_command;
+pub mod get_entity_by_table_name_command;
+pub mod get_entity_graph_command;
+pub mod get_file_diagnostics_command;
+pub mod get_id_field_info_command;
 pub mod get_java_basic_types_command;
 pub mod get_java_files_command;
+pub mod get_jpa_entities_info_command;
 pub mod get_jpa_entity_info_command;
+pub mod get_method_callers_command;
+#[cfg(feature = "debug-tools")]
+pub mod get_parse_tree_command;
+pub mod get_relationship_inverse_command;
+pub mod get_relationship_targets_command;
+pub mod get_superclass_fields_command;
+pub mod get_type_definition_location_command;
+pub mod invalidate_parse_cache_command;
+pub mod list_commands_command;
+pub mod make_entity_immutable_command;
+pub mod merge_packages_command;
+pub mod migrate_to_jakarta_command;
+pub mod move_method_command;
+pub mod regenerate_accessors_command;
+pub mod remove_annotation_command;
+pub mod remove_duplicate_imports_command;
+pub mod remove_relationship_command;
+pub mod rename_column_command;
+pub mod rename_java_class_command;
+pub mod rename_java_field_command;
+pub mod replace_annotation_argument_command;
+pub mod resolve_import_for_type_command;
+pub mod set_column_name_command;
+pub mod set_default_fetch_command;
+pub mod validate_entity_command;
+pub mod validate_project_command;
 
 // Supporting modules
+pub mod api;
 pub mod commands;
 pub mod responses;
 pub mod services;
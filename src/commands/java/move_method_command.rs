@@ -0,0 +1,46 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::move_method_response::MoveMethodResponse, services::move_method_service::run,
+  },
+  common::{
+    response::Response, utils::scan_deadline::ScanDeadline,
+    validators::directory_validator::validate_file_path_within_base,
+  },
+};
+
+pub fn execute(
+  cwd: &Path,
+  source_file_path: &Path,
+  target_file_path: &Path,
+  method_name: &str,
+  include_private_helpers: bool,
+  timeout_ms: Option<u64>,
+) -> Response<MoveMethodResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("move-method");
+  // Path containment validation: ensure both file paths are within the cwd
+  for file_path in [source_file_path, target_file_path] {
+    let file_path_str = file_path.display().to_string();
+    if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+      return Response::error(
+        cmd_name,
+        cwd_string,
+        format!("File path must be within working directory: {}", error_msg),
+      );
+    }
+  }
+
+  match run(
+    cwd,
+    source_file_path,
+    target_file_path,
+    method_name,
+    include_private_helpers,
+    &ScanDeadline::new(timeout_ms),
+  ) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,32 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::remove_duplicate_imports_response::RemoveDuplicateImportsResponse,
+    services::remove_duplicate_imports_service::run,
+  },
+  common::{response::Response, validators::directory_validator::validate_file_path_within_base},
+};
+
+pub fn execute(
+  cwd: &Path,
+  b64_source_code: &str,
+  file_path: &Path,
+) -> Response<RemoveDuplicateImportsResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("remove-duplicate-imports");
+  // Path containment validation: ensure file path is within the cwd
+  let file_path_str = file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("File path must be within working directory: {}", error_msg),
+    );
+  }
+
+  match run(cwd, b64_source_code, file_path) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,39 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::multi_file_response::MultiFileResponse, services::remove_relationship_service,
+  },
+  common::{response::Response, validators::directory_validator::validate_file_path_within_base},
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  owning_only: bool,
+) -> Response<MultiFileResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("remove-relationship");
+  // Path containment validation: ensure entity file path is within the cwd
+  let file_path_str = entity_file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("Entity file path must be within working directory: {}", error_msg),
+    );
+  }
+
+  match remove_relationship_service::run(
+    cwd,
+    entity_file_b64_src,
+    entity_file_path,
+    field_name,
+    owning_only,
+  ) {
+    Ok(files) => Response::success(cmd_name, cwd_string, MultiFileResponse::new(files)),
+    Err(e) => Response::error(cmd_name, cwd_string, e),
+  }
+}
@@ -0,0 +1,35 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::rename_response::RenameResponse, services::rename_java_class_service::run,
+  },
+  common::{
+    response::Response, utils::scan_deadline::ScanDeadline,
+    validators::directory_validator::validate_file_path_within_base,
+  },
+};
+
+pub fn execute(
+  cwd: &Path,
+  file_path: &Path,
+  new_name: &str,
+  timeout_ms: Option<u64>,
+) -> Response<RenameResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("rename-java-class");
+  // Path containment validation: ensure file path is within the cwd
+  let file_path_str = file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("File path must be within working directory: {}", error_msg),
+    );
+  }
+
+  match run(cwd, file_path, new_name, &ScanDeadline::new(timeout_ms)) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
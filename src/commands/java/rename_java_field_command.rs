@@ -0,0 +1,40 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::rename_java_field_response::RenameJavaFieldResponse,
+    services::rename_java_field_service::run,
+  },
+  common::{
+    response::Response, utils::scan_deadline::ScanDeadline,
+    validators::directory_validator::validate_file_path_within_base,
+  },
+};
+
+pub fn execute(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  line: usize,
+  column: usize,
+  new_name: &str,
+  timeout_ms: Option<u64>,
+) -> Response<RenameJavaFieldResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("rename-java-field");
+  // Path containment validation: ensure entity file path is within the cwd
+  let file_path_str = entity_file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("Entity file path must be within working directory: {}", error_msg),
+    );
+  }
+
+  let deadline = ScanDeadline::new(timeout_ms);
+  match run(cwd, entity_file_b64_src, entity_file_path, line, column, new_name, &deadline) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::replace_annotation_argument_response::ReplaceAnnotationArgumentResponse,
+    services::replace_annotation_argument_service::run,
+  },
+  common::{response::Response, validators::directory_validator::validate_file_path_within_base},
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute(
+  cwd: &Path,
+  b64_source_code: &str,
+  file_path: &Path,
+  type_name: Option<&str>,
+  field_name: Option<&str>,
+  annotation_name: &str,
+  key: &str,
+  value: &str,
+) -> Response<ReplaceAnnotationArgumentResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("replace-annotation-argument");
+  // Path containment validation: ensure file path is within the cwd
+  let file_path_str = file_path.display().to_string();
+  if let Err(error_msg) = validate_file_path_within_base(&file_path_str, cwd) {
+    return Response::error(
+      cmd_name,
+      cwd_string,
+      format!("File path must be within working directory: {}", error_msg),
+    );
+  }
+
+  match run(cwd, b64_source_code, file_path, type_name, field_name, annotation_name, key, value) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
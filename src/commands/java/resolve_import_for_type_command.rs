@@ -0,0 +1,23 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::resolve_import_for_type_response::ResolveImportForTypeResponse,
+    services::resolve_import_for_type_service::run,
+  },
+  common::{response::Response, utils::scan_deadline::ScanDeadline},
+};
+
+pub fn execute(
+  cwd: &Path,
+  type_name: &str,
+  timeout_ms: Option<u64>,
+) -> Response<ResolveImportForTypeResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("resolve-import-for-type");
+
+  match run(cwd, type_name, &ScanDeadline::new(timeout_ms)) {
+    Ok(response) => Response::success(cmd_name, cwd_string, response),
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
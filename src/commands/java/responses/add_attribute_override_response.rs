@@ -0,0 +1,16 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttributeOverrideResponse {
+  pub attribute_name: String,
+  pub column_name: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddAttributeOverrideResponse {
+  pub entity_file_path: String,
+  pub field_name: String,
+  pub overrides: Vec<AttributeOverrideResponse>,
+}
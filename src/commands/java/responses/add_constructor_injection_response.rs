@@ -0,0 +1,13 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddConstructorInjectionResponse {
+  pub file_path: String,
+  pub class_name: String,
+  pub field_type: String,
+  pub field_name: String,
+  pub field_added: bool,
+  pub constructor_parameter_added: bool,
+  pub constructor_created: bool,
+}
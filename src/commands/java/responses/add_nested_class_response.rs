@@ -0,0 +1,15 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddNestedClassResponse {
+  pub file_path: String,
+  pub outer_type_name: String,
+  pub nested_type_name: String,
+  pub nested_type_kind: String,
+  /// Range of the inserted declaration, bytes are 0-based and lines 1-based
+  pub start_byte: usize,
+  pub end_byte: usize,
+  pub start_line: usize,
+  pub end_line: usize,
+}
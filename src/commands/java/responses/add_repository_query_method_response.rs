@@ -0,0 +1,16 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddRepositoryQueryMethodResponse {
+  pub file_path: String,
+  pub repository_type: String,
+  pub entity_type: String,
+  /// Declaration added to the repository, without the trailing `;`
+  pub method_signature: String,
+  pub return_type: String,
+  /// Entity properties referenced by the method name, nested ones in dotted form (`author.name`)
+  pub property_paths: Vec<String>,
+  /// Fully qualified names of the imports added to the repository
+  pub imports_added: Vec<String>,
+}
@@ -0,0 +1,18 @@
+use serde::Serialize;
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SecondaryTableResponse {
+  pub name: String,
+  pub pk_join_column: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddSecondaryTableResponse {
+  pub entity_file_path: String,
+  pub secondary_table: SecondaryTableResponse,
+  /// All secondary tables of the entity after the change, in declaration order
+  pub secondary_tables: Vec<SecondaryTableResponse>,
+  pub reassigned_fields: Vec<String>,
+}
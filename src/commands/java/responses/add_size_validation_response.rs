@@ -0,0 +1,15 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddSizeValidationResponse {
+  pub entity_file_path: String,
+  pub field_name: String,
+  pub size_min: Option<u32>,
+  pub size_max: Option<u32>,
+  /// Length of the column after the change, `None` when the field isn't mapped to a column
+  pub column_length: Option<u32>,
+  pub length_synced: bool,
+  /// Conflicts between `@Size` and `@Column(length)` left in place
+  pub warnings: Vec<String>,
+}
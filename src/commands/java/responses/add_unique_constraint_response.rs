@@ -0,0 +1,14 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddUniqueConstraintResponse {
+  pub entity_file_path: String,
+  pub entity_type: String,
+  /// The `@UniqueConstraint` added to `@Table(uniqueConstraints = ...)`
+  pub unique_constraint: String,
+  pub column_names: Vec<String>,
+  /// Fields whose column-level `unique = true` was removed
+  pub demoted_fields: Vec<String>,
+  pub table_annotation_added: bool,
+}
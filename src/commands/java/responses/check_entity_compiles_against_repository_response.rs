@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryMismatchResponse {
+  pub code: String,
+  pub message: String,
+  pub expected: Option<String>,
+  pub actual: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckEntityCompilesAgainstRepositoryResponse {
+  pub entity_type: String,
+  pub entity_id_type: Option<String>,
+  pub repository_type: String,
+  pub repository_entity_type: Option<String>,
+  pub repository_id_type: Option<String>,
+  pub passed: bool,
+  pub mismatches: Vec<RepositoryMismatchResponse>,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClearScanCacheResponse {
+  /// Whether the caches of every project were cleared instead of only the one of `cwd`
+  pub all_projects: bool,
+  pub cleared_files_count: usize,
+}
@@ -0,0 +1,13 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertToLombokBuilderResponse {
+  pub file_path: String,
+  pub file_type: String,
+  /// Builder members removed from the class, e.g. `Builder`, `builder()` and `Book(Builder)`
+  pub removed_members: Vec<String>,
+  /// False when the class was already annotated with `@Builder`
+  pub annotation_added: bool,
+  pub warnings: Vec<String>,
+}
@@ -0,0 +1,15 @@
+use serde::Serialize;
+
+use crate::commands::java::{
+  responses::file_response::FileResponse, treesitter::types::java_file_template::JavaFileTemplate,
+};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateJavaFileResponse {
+  #[serde(flatten)]
+  pub file: FileResponse,
+  /// Template the body was seeded with, unset for the empty declaration
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub template: Option<JavaFileTemplate>,
+}
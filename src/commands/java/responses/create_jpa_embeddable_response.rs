@@ -0,0 +1,21 @@
+use serde::Serialize;
+
+use crate::commands::java::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbeddableFieldResponse {
+  pub field_name: String,
+  pub field_type: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateJPAEmbeddableResponse {
+  #[serde(flatten)]
+  pub file: FileResponse,
+  /// Whether `implements Serializable` and a `serialVersionUID` were added
+  pub serializable_added: bool,
+  /// Basic fields the embeddable was created with, in declaration order
+  pub fields: Vec<EmbeddableFieldResponse>,
+}
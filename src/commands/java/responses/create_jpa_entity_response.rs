@@ -0,0 +1,16 @@
+use serde::Serialize;
+
+use crate::commands::java::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateJPAEntityResponse {
+  #[serde(flatten)]
+  pub file: FileResponse,
+  /// Whether `@Entity`/`@Table` were added, `false` when a plain class was requested
+  pub annotations_emitted: bool,
+  /// Files of the project already declaring a class with the entity name, in any package
+  pub duplicate_class_paths: Vec<String>,
+  /// Whether `implements Serializable` and a `serialVersionUID` were added
+  pub serializable_added: bool,
+}
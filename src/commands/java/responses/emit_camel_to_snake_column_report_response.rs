@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnNameSuggestionResponse {
+  pub field: String,
+  pub current_column: String,
+  pub suggested_column: String,
+  /// Whether the current name comes from `@Column(name = ...)` rather than the field name
+  pub has_explicit_name: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityColumnReportResponse {
+  pub entity_type: String,
+  pub entity_path: String,
+  pub columns: Vec<ColumnNameSuggestionResponse>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmitCamelToSnakeColumnReportResponse {
+  /// Mapped classes with at least one column to rename
+  pub entities: Vec<EntityColumnReportResponse>,
+  pub columns_count: usize,
+  /// Whether the suggested names were written to the files
+  pub applied: bool,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnsureNoArgConstructorResponse {
+  pub entity_file_path: String,
+  pub entity_type: String,
+  pub constructor_added: bool,
+}
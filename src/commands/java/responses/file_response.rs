@@ -6,4 +6,10 @@ pub struct FileResponse {
   pub file_type: String,
   pub file_package_name: String,
   pub file_path: String,
+  /// SHA-256 of the content written by the command, so clients can detect later modifications
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub content_hash: Option<String>,
+  /// Non-fatal problems found while generating the file
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub warnings: Vec<String>,
 }
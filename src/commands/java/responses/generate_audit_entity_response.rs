@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+use crate::commands::java::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditFieldResponse {
+  pub field_name: String,
+  pub field_type: String,
+  /// Spring Data annotation filling the field, e.g. `CreatedDate`
+  pub annotation: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateAuditEntityResponse {
+  /// The created base class, whose type and package can be given to `create-jpa-entity` as the
+  /// superclass
+  #[serde(flatten)]
+  pub file: FileResponse,
+  pub fields: Vec<AuditFieldResponse>,
+}
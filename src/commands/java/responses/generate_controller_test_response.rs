@@ -0,0 +1,27 @@
+use serde::Serialize;
+
+use crate::commands::java::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ControllerTestEndpointResponse {
+  /// Controller method handling the endpoint
+  pub handler_method: String,
+  pub http_method: String,
+  /// Request path, joined with the controller `@RequestMapping` path
+  pub path: String,
+  pub test_method: String,
+  /// Status matcher the test asserts, e.g. `isCreated`
+  pub expected_status: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateControllerTestResponse {
+  pub test: FileResponse,
+  pub endpoints: Vec<ControllerTestEndpointResponse>,
+  /// Services replaced by `@MockBean` mocks
+  pub mocked_services: Vec<String>,
+  /// Path variables without a sample value
+  pub warnings: Vec<String>,
+}
@@ -0,0 +1,16 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateEntityDiagramResponse {
+  /// PlantUML source of the class diagram
+  pub diagram: String,
+  pub entities: Vec<String>,
+  pub entities_count: usize,
+  pub relationships_count: usize,
+  /// File the diagram was written to, when one was requested
+  pub output_file_path: Option<String>,
+  /// Set when the scan hit its timeout, the diagram then only covers the files parsed until then
+  pub truncated: bool,
+  pub processed_files_count: usize,
+}
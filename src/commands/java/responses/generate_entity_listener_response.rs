@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+use crate::commands::java::responses::multi_file_response::MultiFileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityListenerCallbackResponse {
+  pub annotation: String,
+  pub method_name: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateEntityListenerResponse {
+  /// The created listener followed by the updated entity
+  #[serde(flatten)]
+  pub written_files: MultiFileResponse,
+  pub callbacks: Vec<EntityListenerCallbackResponse>,
+  /// `@EntityListeners` annotation of the entity after registering the listener.
+  pub annotation: String,
+  /// False when the entity already had an `@EntityListeners` annotation the listener was merged
+  /// into.
+  pub annotation_added: bool,
+}
@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FactoryParameterResponse {
+  pub parameter_type: String,
+  pub parameter_name: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateFactoryMethodResponse {
+  pub file_path: String,
+  pub class_name: String,
+  pub method_name: String,
+  /// Signature of the generated method, e.g. `public static Book of(String title)`
+  pub signature: String,
+  /// Required fields taken as parameters, in declaration order
+  pub parameters: Vec<FactoryParameterResponse>,
+  /// Whether a non-public no-arg constructor was added for the factory to call
+  pub constructor_added: bool,
+}
@@ -0,0 +1,13 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateLiquibaseChangelogResponse {
+  pub changeset_file_path: String,
+  pub changeset_id: String,
+  pub author: String,
+  pub dialect: String,
+  pub table_name: String,
+  /// Master changelog the changeset was included in, when one was given
+  pub master_changelog_file_path: Option<String>,
+}
@@ -0,0 +1,18 @@
+use serde::Serialize;
+
+use crate::commands::java::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectionMethodResponse {
+  pub field_name: String,
+  pub method_name: String,
+  pub return_type: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateProjectionInterfaceResponse {
+  pub projection: FileResponse,
+  pub methods: Vec<ProjectionMethodResponse>,
+}
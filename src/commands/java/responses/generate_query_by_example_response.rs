@@ -0,0 +1,23 @@
+use serde::Serialize;
+
+use crate::commands::java::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProbeFieldResponse {
+  pub field_name: String,
+  /// Parameter type of the field, primitives boxed so they can be left null
+  pub parameter_type: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateQueryByExampleResponse {
+  pub helper: FileResponse,
+  pub method_name: String,
+  /// Source of the generated method
+  pub method: String,
+  pub probe_fields: Vec<ProbeFieldResponse>,
+  /// Fields left out of the probe because the entity has no setter for them
+  pub warnings: Vec<String>,
+}
@@ -0,0 +1,23 @@
+use serde::Serialize;
+
+/// What makes the QueryDSL annotation processor generate the `Q`-class.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum QueryDslCoverage {
+  /// `@Entity`, `@MappedSuperclass` or `@Embeddable`, picked up by `JPAAnnotationProcessor`
+  JpaAnnotationProcessor,
+  /// `@QueryEntity`, `@QuerySupertype` or `@QueryEmbeddable`, picked up by
+  /// `QuerydslAnnotationProcessor`
+  QueryDslAnnotation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateQueryDslMetadataResponse {
+  pub file_path: String,
+  pub type_name: String,
+  /// Fully qualified name of the generated `Q`-class
+  pub query_type_name: String,
+  pub coverage: QueryDslCoverage,
+  pub annotation_added: bool,
+}
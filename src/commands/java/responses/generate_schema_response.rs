@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedEntityResponse {
+  pub entity_type: String,
+  pub file_path: Option<String>,
+  pub reasons: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateSchemaResponse {
+  pub dialect: String,
+  pub script: String,
+  pub tables: Vec<String>,
+  pub tables_count: usize,
+  pub skipped_entities: Vec<SkippedEntityResponse>,
+  /// Set when the scan hit its timeout, the script then only covers the files parsed until then
+  pub truncated: bool,
+  pub processed_files_count: usize,
+}
@@ -0,0 +1,23 @@
+use serde::Serialize;
+
+use crate::commands::java::responses::file_response::FileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestDataFieldResponse {
+  pub field_name: String,
+  pub field_type: String,
+  /// Java expression the builder initializes the field with
+  pub default_value: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateTestDataBuilderResponse {
+  pub builder: FileResponse,
+  /// Static factory method starting a builder, e.g. `aUser`
+  pub factory_method: String,
+  pub fields: Vec<TestDataFieldResponse>,
+  /// Required fields left unpopulated and setters the entity lacks
+  pub warnings: Vec<String>,
+}
@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AnnotationForm {
+  /// `@Entity`
+  Marker,
+  /// `@Table("users")`
+  SingleValue,
+  /// `@Table(name = "users")`
+  Normal,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum AnnotationValueResponse {
+  /// Any expression (literal, enum constant, class literal, constant reference) as written
+  Expression {
+    text: String,
+  },
+  Array {
+    elements: Vec<AnnotationValueResponse>,
+  },
+  Annotation(Box<AnnotationResponse>),
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotationArgumentResponse {
+  /// `value` for the single-value form
+  pub key: String,
+  pub value: AnnotationValueResponse,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotationResponse {
+  pub name: String,
+  pub fully_qualified_name: Option<String>,
+  pub form: AnnotationForm,
+  pub arguments: Vec<AnnotationArgumentResponse>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAnnotationsResponse {
+  pub type_name: String,
+  pub type_kind: String,
+  pub annotations: Vec<AnnotationResponse>,
+  pub annotations_count: usize,
+}
@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+/// Metrics of a single type declaration. Members of nested types are not counted, their metrics
+/// are reported when the nested type itself is requested.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetClassMetricsResponse {
+  pub type_name: String,
+  pub type_kind: String,
+  /// Number of declared fields; `private int a, b;` counts as two. Enum constants are not fields
+  pub field_count: usize,
+  /// Number of declared methods, constructors excluded
+  pub method_count: usize,
+  /// Number of declared constructors, including compact record constructors
+  pub constructor_count: usize,
+  /// Lines of the declaration, from its first annotation or modifier to its closing brace,
+  /// holding anything other than whitespace and comments
+  pub lines_of_code: usize,
+  /// Number of fields annotated with `@ManyToOne`, `@OneToMany`, `@OneToOne` or `@ManyToMany`
+  pub relationship_count: usize,
+  /// Sum of the complexity of every method and constructor. The complexity of one is 1 plus its
+  /// branch points: `if`, loops, `catch`, `case` labels, `?:` and every `&&`/`||` operator.
+  /// Lambdas and anonymous classes count towards the enclosing method
+  pub cyclomatic_complexity: usize,
+  /// Highest complexity of a single method or constructor, 0 without any
+  pub max_method_complexity: usize,
+  /// Name of the method or constructor with the highest complexity, the first one on ties
+  pub most_complex_method: Option<String>,
+}
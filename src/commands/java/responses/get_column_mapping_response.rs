@@ -0,0 +1,25 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldColumnResponse {
+  /// Field path from the entity, embedded fields are dotted (`address.street`)
+  pub field_name: String,
+  pub column_name: String,
+  /// False when the column name comes from the naming strategy default
+  pub has_explicit_name: bool,
+  pub is_id: bool,
+  pub is_join_column: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetColumnMappingResponse {
+  pub entity_type: String,
+  pub table_name: String,
+  pub has_explicit_table_name: bool,
+  pub schema: Option<String>,
+  pub catalog: Option<String>,
+  pub columns: Vec<FieldColumnResponse>,
+  pub columns_count: usize,
+}
@@ -0,0 +1,38 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConstructorParameterResponse {
+  /// Declared type, the element type for a varargs parameter
+  pub parameter_type: String,
+  pub parameter_name: String,
+  pub is_varargs: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConstructorInfoResponse {
+  /// Signature without annotations and body, e.g. `protected Order(Long id, String... tags)`
+  pub signature: String,
+  /// `public`, `protected`, `private` or `package-private`
+  pub visibility: String,
+  /// Modifier keywords as declared, annotations excluded
+  pub modifiers: Vec<String>,
+  pub parameters: Vec<ConstructorParameterResponse>,
+  /// 1-based line of the constructor declaration
+  pub line: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetConstructorInfoResponse {
+  pub type_name: String,
+  /// Explicitly declared constructors, in declaration order
+  pub constructors: Vec<ConstructorInfoResponse>,
+  pub constructors_count: usize,
+  /// Whether the class can be instantiated without arguments, as JPA requires: it declares a
+  /// no-arg constructor, declares none at all, or is annotated with Lombok's `@NoArgsConstructor`
+  pub has_no_arg: bool,
+  /// The class declares no constructor, the compiler adds the default no-arg one
+  pub has_implicit_default_constructor: bool,
+}
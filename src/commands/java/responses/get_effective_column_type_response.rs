@@ -0,0 +1,26 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetEffectiveColumnTypeResponse {
+  pub entity_type: String,
+  /// Field path from the entity, embedded fields are dotted (`address.street`)
+  pub field_name: String,
+  pub field_type: String,
+  pub column_name: String,
+  pub dialect: String,
+  pub sql_type: String,
+  pub is_join_column: bool,
+  /// Entity and column a join column references, its type is the type of that column
+  pub referenced_entity: Option<String>,
+  pub referenced_column: Option<String>,
+  /// Mapping inputs the type was resolved from, for a join column those of the referenced column
+  pub length: Option<u32>,
+  pub precision: Option<u32>,
+  pub scale: Option<u32>,
+  pub is_lob: bool,
+  /// `STRING` or `ORDINAL`, enum fields without `@Enumerated` are ordinal
+  pub enum_type: Option<String>,
+  pub temporal: Option<String>,
+  pub column_definition: Option<String>,
+}
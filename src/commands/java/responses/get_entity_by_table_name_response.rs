@@ -0,0 +1,23 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableEntityResponse {
+  pub entity_type: String,
+  pub entity_package_name: Option<String>,
+  pub entity_path: Option<String>,
+  /// Entity whose `@Table` or class name gives the table, a superclass under single-table
+  /// inheritance
+  pub table_owner_type: String,
+  /// Whether the table is named by `@Table(name = ...)` rather than the naming strategy default
+  pub has_explicit_table_name: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetEntityByTableNameResponse {
+  pub table_name: String,
+  pub found: bool,
+  /// Entities mapped to the table, several when they share it through single-table inheritance
+  pub entities: Vec<TableEntityResponse>,
+}
@@ -0,0 +1,21 @@
+use serde::Serialize;
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityGraphResponse {
+  pub name: String,
+  pub attribute_nodes: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetEntityGraphResponse {
+  pub entity_type: String,
+  pub entity_file_path: String,
+  /// The `@NamedEntityGraph` written to the entity, unset when no attribute nodes were given
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub generated_graph: Option<EntityGraphResponse>,
+  /// Graphs loading each lazy association of the entity, and all of them together
+  pub suggestions: Vec<EntityGraphResponse>,
+  pub warnings: Vec<String>,
+}
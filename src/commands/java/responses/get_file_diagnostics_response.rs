@@ -0,0 +1,20 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileDiagnosticResponse {
+  /// `syntax_error` for unparsable text, `missing_token` for a token the parser had to insert
+  pub kind: String,
+  pub message: String,
+  pub start_line: usize,
+  pub start_column: usize,
+  pub end_line: usize,
+  pub end_column: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFileDiagnosticsResponse {
+  /// Empty when the file parses cleanly
+  pub diagnostics: Vec<FileDiagnosticResponse>,
+}
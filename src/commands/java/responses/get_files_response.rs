@@ -1,10 +1,25 @@
+use std::collections::BTreeMap;
+
 use serde::Serialize;
 
 use crate::commands::java::responses::file_response::FileResponse;
+use crate::common::utils::scan_deadline::SkippedFile;
 
-#[derive(Serialize)]
+#[derive(Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct GetFilesResponse {
   pub files: Vec<FileResponse>,
   pub files_count: usize,
+  /// Set when a project scan hit its timeout, `files` then only holds the matches found so far
+  pub truncated: bool,
+  /// Number of files scanned, only reported by project scans
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub processed_files_count: Option<usize>,
+  /// Modification time of each listed file in milliseconds since the Unix epoch, keyed by path.
+  /// Only reported by `get-java-files`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub modified_times_ms: Option<BTreeMap<String, u64>>,
+  /// Files the scan left out, e.g. because they aren't valid UTF-8
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub skipped_files: Vec<SkippedFile>,
 }
@@ -0,0 +1,27 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdFieldInfoResponse {
+  pub field_name: String,
+  pub field_type: String,
+  /// `@GeneratedValue` strategy (`IDENTITY`, `SEQUENCE`, ...), `AUTO` when none is given
+  pub generation_strategy: Option<String>,
+  pub generator_name: Option<String>,
+  pub sequence_name: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetIdFieldInfoResponse {
+  pub entity_type: String,
+  /// Class declaring the id fields, a mapped superclass when the id is inherited
+  pub declaring_class: String,
+  pub is_inherited: bool,
+  pub id_fields: Vec<IdFieldInfoResponse>,
+  /// True for an `@EmbeddedId`, an `@IdClass` or several `@Id` fields
+  pub is_composite: bool,
+  pub is_embedded_id: bool,
+  /// Simple name of the `@IdClass`
+  pub id_class_type: Option<String>,
+}
@@ -0,0 +1,27 @@
+use serde::Serialize;
+
+use crate::commands::java::responses::get_jpa_entity_info_response::GetJpaEntityInfoResponse;
+
+/// Outcome for a single file of the batch, mirroring the `succeed`/`data`/`errorReason` shape of
+/// the single-file command.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JpaEntityInfoResultResponse {
+  pub file_path: String,
+  pub succeed: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub data: Option<GetJpaEntityInfoResponse>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub error_reason: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetJpaEntitiesInfoResponse {
+  /// One result per requested file, in request order, or per entity found in the project
+  pub entities: Vec<JpaEntityInfoResultResponse>,
+  pub succeeded_count: usize,
+  pub failed_count: usize,
+  pub truncated: bool,
+  pub processed_files_count: usize,
+}
@@ -0,0 +1,32 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodCallSiteResponse {
+  pub file_path: String,
+  /// 1-based line of the invocation
+  pub line: usize,
+  /// 1-based column of the invoked method name
+  pub column: usize,
+  /// Method or constructor containing the call, `None` for field initializers and static blocks
+  pub enclosing_method: Option<String>,
+  pub enclosing_type: Option<String>,
+  /// Receiver expression as written (`repository`, `this`), `None` for an unqualified call
+  pub receiver: Option<String>,
+  /// Whether the receiver type was resolved to the declaring type or one of its subtypes. Calls
+  /// on receivers whose type can't be resolved from the source are reported unresolved
+  pub receiver_resolved: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMethodCallersResponse {
+  pub method_name: String,
+  pub declaring_type: String,
+  pub parameters_count: usize,
+  /// Call sites ordered by file path, then by position
+  pub call_sites: Vec<MethodCallSiteResponse>,
+  pub call_sites_count: usize,
+  pub truncated: bool,
+  pub processed_files_count: usize,
+}
@@ -0,0 +1,25 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParseErrorNodeResponse {
+  /// `ERROR` for unparsable text, `MISSING <kind>` for a token the parser had to insert
+  pub kind: String,
+  pub start_line: usize,
+  pub start_column: usize,
+  pub end_line: usize,
+  pub end_column: usize,
+  pub text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetParseTreeResponse {
+  pub s_expression: String,
+  pub node_kind: String,
+  pub start_line: usize,
+  pub end_line: usize,
+  pub has_errors: bool,
+  /// Error and missing nodes of the whole file, not only of the returned subtree
+  pub error_nodes: Vec<ParseErrorNodeResponse>,
+}
@@ -0,0 +1,32 @@
+use serde::Serialize;
+
+use crate::commands::java::treesitter::types::{
+  inverse_resolution::InverseResolution, relationship_kind::RelationshipKind,
+};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InverseFieldResponse {
+  pub field_name: String,
+  pub field_type: String,
+  pub relationship_kind: RelationshipKind,
+  pub resolved_by: InverseResolution,
+  pub file_path: String,
+  /// 1-based line of the inverse field's name
+  pub line: usize,
+  /// 1-based column of the inverse field's name
+  pub column: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRelationshipInverseResponse {
+  pub entity_type: String,
+  pub field_name: String,
+  pub target_type: String,
+  pub target_file_path: String,
+  /// `false` when the target entity has no field mapping the other side
+  pub bidirectional: bool,
+  pub inverse: Option<InverseFieldResponse>,
+  pub warnings: Vec<String>,
+}
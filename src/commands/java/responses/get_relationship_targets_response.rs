@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+use crate::commands::java::treesitter::types::{
+  entity_side::EntitySide, relationship_kind::RelationshipKind,
+};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelationshipTargetResponse {
+  pub field_name: String,
+  pub field_type: String,
+  pub target_type: String,
+  /// `None` when the target can't be resolved through the imports or the project scan
+  pub target_fully_qualified_name: Option<String>,
+  /// Path of the target entity's file, when it's part of the project
+  pub target_file_path: Option<String>,
+  pub relationship_kind: RelationshipKind,
+  pub side: EntitySide,
+  pub mapped_by: Option<String>,
+  pub is_collection: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRelationshipTargetsResponse {
+  pub entity_type: String,
+  pub relationships: Vec<RelationshipTargetResponse>,
+  pub relationships_count: usize,
+}
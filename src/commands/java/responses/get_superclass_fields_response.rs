@@ -0,0 +1,21 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuperclassFieldResponse {
+  pub field_name: String,
+  pub field_type: String,
+  pub declaring_class: String,
+  pub is_id: bool,
+  pub is_inherited: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSuperclassFieldsResponse {
+  pub class_type: String,
+  pub superclass_chain: Vec<String>,
+  pub id_field_type: Option<String>,
+  pub fields: Vec<SuperclassFieldResponse>,
+  pub fields_count: usize,
+}
@@ -0,0 +1,26 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeDefinitionLocationResponse {
+  pub fully_qualified_name: String,
+  pub file_path: String,
+  /// 1-based line of the declared type name
+  pub line: usize,
+  /// 1-based column of the declared type name
+  pub column: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTypeDefinitionLocationResponse {
+  /// Type reference at the position, as written up to the referenced segment
+  pub type_name: String,
+  /// Fully qualified name of the type, `None` when it can't be told apart from the candidates
+  pub fully_qualified_name: Option<String>,
+  /// Whether the type is declared outside of the project, e.g. in a library or the JDK
+  pub is_external: bool,
+  /// Declarations of the type in the project, several when same-named types can't be told apart
+  pub locations: Vec<TypeDefinitionLocationResponse>,
+  pub ambiguous: bool,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidateParseCacheResponse {
+  /// File whose cached parse was dropped, `None` when every cached parse was dropped
+  pub file_path: Option<String>,
+  pub invalidated_files_count: usize,
+}
@@ -0,0 +1,38 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandArgumentResponse {
+  /// Long flag without the leading dashes, e.g. `entity-file-path`
+  pub name: String,
+  /// One of `string`, `integer`, `number`, `boolean`, `path` or `enum`
+  pub value_type: String,
+  pub required: bool,
+  /// Set when the argument can be repeated or takes a comma-separated list
+  pub multiple: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub default_value: Option<String>,
+  /// Accepted values of an `enum` argument
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub possible_values: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandInfoResponse {
+  pub name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  /// Interactive form, only available in builds with the `ui` feature
+  pub requires_ui_feature: bool,
+  pub arguments: Vec<CommandArgumentResponse>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListCommandsResponse {
+  pub commands: Vec<CommandInfoResponse>,
+  pub commands_count: usize,
+  /// Whether this build includes the `ui` feature commands
+  pub ui_feature_enabled: bool,
+}
@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MakeEntityImmutableResponse {
+  pub entity_file_path: String,
+  pub entity_type: String,
+  /// False when the entity was already annotated with `@Immutable`
+  pub annotation_added: bool,
+  pub removed_setters: Vec<String>,
+  pub warnings: Vec<String>,
+}
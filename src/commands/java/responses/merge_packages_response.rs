@@ -0,0 +1,25 @@
+use serde::Serialize;
+
+use crate::common::utils::scan_deadline::SkippedFile;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MovedFileResponse {
+  pub type_name: String,
+  pub old_file_path: String,
+  pub new_file_path: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergePackagesResponse {
+  pub moved_files: Vec<MovedFileResponse>,
+  pub moved_files_count: usize,
+  /// Imports of the moved types, single-type, static and on-demand, rewritten across the project
+  pub updated_imports_count: usize,
+  /// Files other than the moved ones whose imports or qualified references were rewritten
+  pub updated_files_count: usize,
+  /// Files left out because they aren't valid UTF-8, none of them references the source package
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub skipped_files: Vec<SkippedFile>,
+}
@@ -0,0 +1,23 @@
+use serde::Serialize;
+
+use crate::common::utils::scan_deadline::SkippedFile;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigratedFileResponse {
+  pub file_path: String,
+  pub rewritten_references_count: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrateToJakartaResponse {
+  pub migrated_files: Vec<MigratedFileResponse>,
+  pub migrated_files_count: usize,
+  pub rewritten_references_count: usize,
+  /// Files without any reference to a migrated namespace, left untouched
+  pub skipped_files_count: usize,
+  /// Files left out because they aren't valid UTF-8
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub skipped_files: Vec<SkippedFile>,
+}
@@ -1,10 +1,72 @@
+pub mod add_attribute_override_response;
+pub mod add_constructor_injection_response;
+pub mod add_nested_class_response;
+pub mod add_repository_query_method_response;
+pub mod add_secondary_table_response;
+pub mod add_size_validation_response;
+pub mod add_unique_constraint_response;
 pub mod basic_java_type_response;
+pub mod check_entity_compiles_against_repository_response;
+pub mod clear_scan_cache_response;
+pub mod convert_to_lombok_builder_response;
 pub mod create_entity_field_response;
+pub mod create_java_file_response;
+pub mod create_jpa_embeddable_response;
+pub mod create_jpa_entity_response;
 pub mod create_jpa_one_to_one_relationship_response;
 pub mod create_jpa_repository_response;
 pub mod create_many_to_one_relationship_response;
+pub mod emit_camel_to_snake_column_report_response;
+pub mod ensure_no_arg_constructor_response;
 pub mod file_response;
+pub mod generate_audit_entity_response;
+pub mod generate_controller_test_response;
+pub mod generate_entity_diagram_response;
+pub mod generate_entity_listener_response;
+pub mod generate_factory_method_response;
+pub mod generate_liquibase_changelog_response;
+pub mod generate_projection_interface_response;
+pub mod generate_query_by_example_response;
+pub mod generate_query_dsl_metadata_response;
+pub mod generate_schema_response;
+pub mod generate_test_data_builder_response;
+pub mod get_annotations_response;
+pub mod get_class_metrics_response;
+pub mod get_column_mapping_response;
+pub mod get_constructor_info_response;
+pub mod get_effective_column_type_response;
+pub mod get_entity_by_table_name_response;
+pub mod get_entity_graph_response;
+pub mod get_file_diagnostics_response;
 pub mod get_files_response;
+pub mod get_id_field_info_response;
+pub mod get_jpa_entities_info_response;
 pub mod get_jpa_entity_info_response;
+pub mod get_method_callers_response;
 pub mod get_packages_response;
+#[cfg(feature = "debug-tools")]
+pub mod get_parse_tree_response;
+pub mod get_relationship_inverse_response;
+pub mod get_relationship_targets_response;
+pub mod get_superclass_fields_response;
+pub mod get_type_definition_location_response;
+pub mod invalidate_parse_cache_response;
+pub mod list_commands_response;
+pub mod make_entity_immutable_response;
+pub mod merge_packages_response;
+pub mod migrate_to_jakarta_response;
+pub mod move_method_response;
+pub mod multi_file_response;
 pub mod package_response;
+pub mod regenerate_accessors_response;
+pub mod remove_annotation_response;
+pub mod remove_duplicate_imports_response;
+pub mod rename_column_response;
+pub mod rename_java_field_response;
+pub mod rename_response;
+pub mod replace_annotation_argument_response;
+pub mod resolve_import_for_type_response;
+pub mod set_column_name_response;
+pub mod set_default_fetch_response;
+pub mod validate_entity_response;
+pub mod validate_project_response;
@@ -0,0 +1,17 @@
+use serde::Serialize;
+
+use crate::commands::java::responses::multi_file_response::MultiFileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveMethodResponse {
+  /// The source class, the target class, then every other file whose call sites were updated
+  #[serde(flatten)]
+  pub written_files: MultiFileResponse,
+  pub method_name: String,
+  pub is_static: bool,
+  /// Private helpers only the method used, moved along with it
+  pub moved_helpers: Vec<String>,
+  pub updated_call_sites_count: usize,
+  pub warnings: Vec<String>,
+}
@@ -0,0 +1,19 @@
+use serde::Serialize;
+
+use crate::commands::java::responses::file_response::FileResponse;
+
+/// Result of a command writing more than one file, e.g. both sides of a relationship. Every file
+/// created or modified is listed, in the order it was written, so clients can reload all of them.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiFileResponse {
+  pub files: Vec<FileResponse>,
+  pub files_count: usize,
+}
+
+impl MultiFileResponse {
+  pub fn new(files: Vec<FileResponse>) -> Self {
+    let files_count = files.len();
+    Self { files, files_count }
+  }
+}
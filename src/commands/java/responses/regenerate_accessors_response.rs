@@ -0,0 +1,13 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegenerateAccessorsResponse {
+  pub entity_file_path: String,
+  pub entity_type: String,
+  /// Accessors rewritten in place, including those whose custom body was kept
+  pub replaced_accessors: Vec<String>,
+  /// Accessors whose body holds a `// custom` marker, only their signature was rewritten
+  pub custom_accessors: Vec<String>,
+  pub created_accessors: Vec<String>,
+}
@@ -0,0 +1,17 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveAnnotationResponse {
+  pub file_path: String,
+  /// Name of the class or field the annotation was removed from
+  pub target_name: String,
+  pub annotation_name: String,
+  pub annotation_removed: bool,
+  /// Source text of the removed annotation, arguments included
+  pub removed_annotation: String,
+  /// True when the import of the annotation was removed as well, which only happens when it was
+  /// requested and the annotation is no longer used in the file
+  pub import_removed: bool,
+  pub removed_import: Option<String>,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveDuplicateImportsResponse {
+  pub file_path: String,
+  /// Removed import declarations, as they were written in the file
+  pub removed_imports: Vec<String>,
+}
@@ -0,0 +1,20 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameColumnResponse {
+  pub entity_file_path: String,
+  pub entity_type: String,
+  /// Java field, left unchanged
+  pub field_name: String,
+  /// Column the field mapped to before, the default name when the annotation didn't set one
+  pub old_column_name: String,
+  pub new_column_name: String,
+  /// Set when the column is the foreign key of a relationship, renamed through `@JoinColumn`
+  pub is_join_column: bool,
+  /// Whether the `@Column` or `@JoinColumn` annotation was added to the field
+  pub annotation_added: bool,
+  /// Whether the existing database schema must be migrated to match the new mapping
+  pub migration_required: bool,
+  pub warnings: Vec<String>,
+}
@@ -0,0 +1,30 @@
+use serde::Serialize;
+
+use crate::commands::java::responses::rename_response::RenamedFileResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenamedAccessorResponse {
+  pub old_name: String,
+  pub new_name: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameJavaFieldResponse {
+  pub entity_file_path: String,
+  pub class_name: String,
+  pub old_name: String,
+  pub new_name: String,
+  /// Whether a getter of the field was found in the class and renamed with it
+  pub getter_renamed: bool,
+  /// Whether a setter of the field was found in the class and renamed with it
+  pub setter_renamed: bool,
+  pub renamed_accessors: Vec<RenamedAccessorResponse>,
+  /// Declaration, references and accessor names rewritten in the file
+  pub edits_count: usize,
+  /// Other files of the project whose accessor calls were renamed
+  pub updated_files: Vec<RenamedFileResponse>,
+  /// Accessor calls whose receiver type couldn't be resolved, left unchanged
+  pub warnings: Vec<String>,
+}
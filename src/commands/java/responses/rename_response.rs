@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenamedFileResponse {
+  pub file_path: String,
+  /// Path the file had before the rename, set only when the file itself was renamed
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub previous_file_path: Option<String>,
+  pub edits_count: usize,
+}
+
+/// Result of a project-wide rename. Every file containing an edited reference is listed.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameResponse {
+  pub old_name: String,
+  pub new_name: String,
+  pub files: Vec<RenamedFileResponse>,
+  pub files_count: usize,
+  pub edits_count: usize,
+}
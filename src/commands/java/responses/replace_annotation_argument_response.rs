@@ -0,0 +1,15 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaceAnnotationArgumentResponse {
+  pub file_path: String,
+  /// Name of the annotated class or field
+  pub target_name: String,
+  pub annotation_name: String,
+  pub key: String,
+  /// False when the key was already present and its value was replaced
+  pub argument_added: bool,
+  pub annotation_before: String,
+  pub annotation_after: String,
+}
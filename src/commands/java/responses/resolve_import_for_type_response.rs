@@ -0,0 +1,35 @@
+use serde::Serialize;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ImportCandidateSource {
+  Project,
+  Jdk,
+  Library,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportCandidateResponse {
+  pub fully_qualified_name: String,
+  pub package_name: String,
+  pub source: ImportCandidateSource,
+  /// `import` statement to add, unset for `java.lang` types which are always in scope
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub import_statement: Option<String>,
+  /// File declaring the type, set for project types
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub file_path: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveImportForTypeResponse {
+  pub type_name: String,
+  /// Best match first: project types, then JDK types, then library types
+  pub candidates: Vec<ImportCandidateResponse>,
+  pub candidates_count: usize,
+  /// True when more than one type matches the name
+  pub is_ambiguous: bool,
+  pub truncated: bool,
+}
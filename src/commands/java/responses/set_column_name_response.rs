@@ -0,0 +1,14 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetColumnNameResponse {
+  pub entity_file_path: String,
+  pub entity_type: String,
+  pub field_name: String,
+  /// Column the field mapped to before, the default name when `@Column` didn't set one
+  pub previous_column_name: String,
+  pub column_name: String,
+  pub column_annotation_added: bool,
+  pub warnings: Vec<String>,
+}
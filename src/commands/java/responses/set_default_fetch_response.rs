@@ -0,0 +1,14 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDefaultFetchResponse {
+  pub entity_file_path: String,
+  pub entity_type: String,
+  /// Fetch type the relationships were set to, e.g. `LAZY`
+  pub fetch_type: String,
+  /// Relationship fields whose fetch type changed, in declaration order
+  pub changed_fields: Vec<String>,
+  pub changed_fields_count: usize,
+  pub warnings: Vec<String>,
+}
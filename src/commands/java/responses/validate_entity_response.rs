@@ -0,0 +1,21 @@
+use serde::Serialize;
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityFindingResponse {
+  pub severity: String,
+  pub code: String,
+  pub message: String,
+  pub field_name: Option<String>,
+  pub suggestion: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateEntityResponse {
+  pub entity_type: String,
+  pub is_valid: bool,
+  pub findings: Vec<EntityFindingResponse>,
+  pub errors_count: usize,
+  pub warnings_count: usize,
+}
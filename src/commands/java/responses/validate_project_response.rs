@@ -0,0 +1,27 @@
+use serde::Serialize;
+
+use crate::commands::java::responses::validate_entity_response::EntityFindingResponse;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileFindingsResponse {
+  pub file_path: String,
+  /// Entity or repository declared by the file
+  pub type_name: String,
+  pub findings: Vec<EntityFindingResponse>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateProjectResponse {
+  /// Files with at least one finding, sorted by path
+  pub files: Vec<FileFindingsResponse>,
+  pub entities_count: usize,
+  pub repositories_count: usize,
+  pub errors_count: usize,
+  pub warnings_count: usize,
+  /// Lowest severity failing the validation, from `--fail-on`
+  pub fail_on: Option<String>,
+  /// Whether no finding reaches the `fail_on` severity, always true without it
+  pub passed: bool,
+}
@@ -0,0 +1,219 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::add_attribute_override_response::{
+  AddAttributeOverrideResponse, AttributeOverrideResponse,
+};
+use crate::commands::java::services::get_superclass_fields_service::collect_persistent_fields;
+use crate::commands::java::treesitter::services::annotation_service::{
+  add_annotation, find_annotation_node_by_name, get_all_annotation_nodes, remove_annotation,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+use crate::commands::java::treesitter::services::field_declaration_service::find_field_declaration_node_by_name;
+use crate::commands::java::treesitter::services::import_declaration_service::add_import;
+use crate::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::find_file_by_class_name;
+
+fn find_field_node<'a>(ts_file: &'a TSFile, field_name: &str) -> Result<Node<'a>, String> {
+  let class_node =
+    get_public_class_node(ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  find_field_declaration_node_by_name(ts_file, field_name, class_node)
+    .ok_or_else(|| format!("Field '{}' not found in entity", field_name))
+}
+
+fn get_simple_type_name(ts_file: &TSFile, field_node: Node) -> Option<String> {
+  let type_text =
+    field_node.child_by_field_name("type").and_then(|n| ts_file.get_text_from_node(&n))?;
+  let without_generics = type_text.split('<').next().unwrap_or(type_text);
+  without_generics.rsplit('.').next().map(|s| s.trim().to_string())
+}
+
+/// Reads a string argument of the annotation's own argument list. For a nested annotation value
+/// such as `column = @Column(name = "x")` its `name` is returned instead.
+fn get_annotation_string_argument(
+  ts_file: &TSFile,
+  annotation_node: Node,
+  key: &str,
+) -> Option<String> {
+  let arguments_node = annotation_node.child_by_field_name("arguments")?;
+  let mut cursor = arguments_node.walk();
+  let pairs: Vec<Node> = arguments_node.named_children(&mut cursor).collect();
+  for pair in pairs {
+    if pair.kind() != "element_value_pair" {
+      continue;
+    }
+    let key_text = pair.child_by_field_name("key").and_then(|n| ts_file.get_text_from_node(&n));
+    if key_text != Some(key) {
+      continue;
+    }
+    let value_node = pair.child_by_field_name("value")?;
+    if value_node.kind() == "string_literal" {
+      return ts_file.get_text_from_node(&value_node).map(|t| t.trim_matches('"').to_string());
+    }
+    if value_node.kind() == "annotation" {
+      return get_annotation_string_argument(ts_file, value_node, "name");
+    }
+    return None;
+  }
+  None
+}
+
+fn collect_existing_overrides(
+  ts_file: &TSFile,
+  field_node: Node,
+) -> Vec<AttributeOverrideResponse> {
+  let mut overrides = Vec::new();
+  for annotation_node in get_all_annotation_nodes(ts_file, field_node) {
+    let name =
+      annotation_node.child_by_field_name("name").and_then(|n| ts_file.get_text_from_node(&n));
+    if name != Some("AttributeOverride") {
+      continue;
+    }
+    let attribute_name = get_annotation_string_argument(ts_file, annotation_node, "name");
+    let column_name = get_annotation_string_argument(ts_file, annotation_node, "column");
+    if let (Some(attribute_name), Some(column_name)) = (attribute_name, column_name) {
+      overrides.push(AttributeOverrideResponse { attribute_name, column_name });
+    }
+  }
+  overrides
+}
+
+/// Checks that `attribute_name` (optionally a dotted path into nested embeddables) is a persistent
+/// attribute of the `@Embeddable` class `embeddable_type`.
+fn validate_attribute_exists(
+  cwd: &Path,
+  embeddable_type: &str,
+  attribute_name: &str,
+) -> Result<(), String> {
+  let mut current_type = embeddable_type.to_string();
+  for segment in attribute_name.split('.') {
+    let embeddable_ts_file =
+      find_file_by_class_name(cwd, &JavaSourceDirectoryType::Main, &current_type)
+        .ok_or_else(|| format!("Unable to find embeddable class '{}'", current_type))?;
+    let embeddable_class_node = get_public_class_node(&embeddable_ts_file)
+      .ok_or_else(|| format!("Unable to get public class node of '{}'", current_type))?;
+    if find_annotation_node_by_name(&embeddable_ts_file, embeddable_class_node, "Embeddable")
+      .is_none()
+    {
+      return Err(format!("Class '{}' is not annotated with @Embeddable", current_type));
+    }
+    let fields =
+      collect_persistent_fields(&embeddable_ts_file, embeddable_class_node, &current_type, false);
+    let field = fields.iter().find(|f| f.field_name == segment).ok_or_else(|| {
+      format!("Attribute '{}' does not exist on embeddable '{}'", segment, current_type)
+    })?;
+    current_type = field.field_type.split('<').next().unwrap_or(&field.field_type).to_string();
+  }
+  Ok(())
+}
+
+fn build_override_text(attribute_override: &AttributeOverrideResponse) -> String {
+  format!(
+    "@AttributeOverride(name = \"{}\", column = @Column(name = \"{}\"))",
+    attribute_override.attribute_name, attribute_override.column_name
+  )
+}
+
+fn build_overrides_annotation(
+  overrides: &[AttributeOverrideResponse],
+  indentation: &str,
+) -> String {
+  if overrides.len() == 1 {
+    return build_override_text(&overrides[0]);
+  }
+  let entries = overrides
+    .iter()
+    .map(|o| format!("{}  {}", indentation, build_override_text(o)))
+    .collect::<Vec<_>>()
+    .join(",\n");
+  format!("@AttributeOverrides({{\n{}\n{}}})", entries, indentation)
+}
+
+fn remove_existing_overrides(ts_file: &mut TSFile, field_name: &str) -> Result<(), String> {
+  for annotation_name in ["AttributeOverrides", "AttributeOverride"] {
+    loop {
+      let annotation_start_byte = {
+        let field_node = find_field_node(ts_file, field_name)?;
+        match find_annotation_node_by_name(ts_file, field_node, annotation_name) {
+          Some(node) => node.start_byte(),
+          None => break,
+        }
+      };
+      if !remove_annotation(ts_file, annotation_start_byte) {
+        return Err(format!("Unable to remove @{} annotation", annotation_name));
+      }
+    }
+  }
+  Ok(())
+}
+
+fn add_imports(ts_file: &mut TSFile, overrides_count: usize) {
+  let import_position = ImportInsertionPosition::BeforeFirstImport;
+  add_import(ts_file, &import_position, "jakarta.persistence", "AttributeOverride");
+  add_import(ts_file, &import_position, "jakarta.persistence", "Column");
+  if overrides_count > 1 {
+    add_import(ts_file, &import_position, "jakarta.persistence", "AttributeOverrides");
+  }
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  attribute_name: &str,
+  column_name: &str,
+) -> Result<AddAttributeOverrideResponse, String> {
+  // Step 1: Parse entity file
+  let mut entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  // Step 2: Find the embedded field and collect its current overrides
+  let (embeddable_type, indentation, mut overrides) = {
+    let field_node = find_field_node(&entity_ts_file, field_name)?;
+    if find_annotation_node_by_name(&entity_ts_file, field_node, "Embedded").is_none() {
+      return Err(format!("Field '{}' is not annotated with @Embedded", field_name));
+    }
+    let embeddable_type = get_simple_type_name(&entity_ts_file, field_node)
+      .ok_or_else(|| format!("Unable to get the type of field '{}'", field_name))?;
+    let indentation = entity_ts_file.get_line_indentation(field_node.start_byte());
+    (embeddable_type, indentation, collect_existing_overrides(&entity_ts_file, field_node))
+  };
+  // Step 3: Validate the overridden attribute exists on the embeddable
+  validate_attribute_exists(cwd, &embeddable_type, attribute_name)?;
+  // Step 4: Insert or update the override
+  match overrides.iter_mut().find(|o| o.attribute_name == attribute_name) {
+    Some(existing) => existing.column_name = column_name.to_string(),
+    None => overrides.push(AttributeOverrideResponse {
+      attribute_name: attribute_name.to_string(),
+      column_name: column_name.to_string(),
+    }),
+  }
+  // Step 5: Replace the existing override annotations with the merged ones
+  remove_existing_overrides(&mut entity_ts_file, field_name)?;
+  let field_start_byte = find_field_node(&entity_ts_file, field_name)?.start_byte();
+  let annotation_text = build_overrides_annotation(&overrides, &indentation);
+  add_annotation(
+    &mut entity_ts_file,
+    field_start_byte,
+    &AnnotationInsertionPosition::AboveScopeDeclaration,
+    &annotation_text,
+  )
+  .ok_or_else(|| "Unable to add @AttributeOverride annotation".to_string())?;
+  // Step 6: Add imports
+  add_imports(&mut entity_ts_file, overrides.len());
+  // Step 7: Save file with working directory validation
+  entity_ts_file
+    .save_to_existing_file(entity_file_path, cwd)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 8: Build and return response
+  Ok(AddAttributeOverrideResponse {
+    entity_file_path: entity_file_path.display().to_string(),
+    field_name: field_name.to_string(),
+    overrides,
+  })
+}
@@ -0,0 +1,163 @@
+use std::path::Path;
+
+use crate::commands::java::responses::add_constructor_injection_response::AddConstructorInjectionResponse;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::constructor_declaration_service::{
+  AddConstructorDeclarationParams, add_constructor_declaration, add_constructor_parameter,
+  get_all_constructor_declaration_nodes,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::get_simple_type_name;
+use crate::commands::java::treesitter::services::field_declaration_service::{
+  AddFieldDeclarationParams, add_field_declaration, find_field_declaration_node_by_name,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::add_import;
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::java_basic_types::FieldInsertionPosition;
+use crate::commands::java::treesitter::types::java_field_modifier::JavaFieldModifier;
+use crate::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::case_util::{self, CaseType};
+
+/// The class receiving the dependency and the field already declared for it, if any.
+struct InjectionTarget {
+  class_name: String,
+  class_start_byte: usize,
+  existing_field_type: Option<String>,
+}
+
+fn validate_field_name(field_name: &str) -> Result<(), String> {
+  let starts_correctly =
+    field_name.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$');
+  if !starts_correctly || !field_name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '$') {
+    return Err(format!("'{}' is not a valid field name", field_name));
+  }
+  Ok(())
+}
+
+fn find_injection_target(ts_file: &TSFile, field_name: &str) -> Result<InjectionTarget, String> {
+  let class_node =
+    get_public_class_node(ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  let class_name = get_class_declaration_name_node(ts_file, class_node)
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+    .map(|name| name.to_string())
+    .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+  let constructors = get_all_constructor_declaration_nodes(ts_file, class_node);
+  if constructors.len() > 1 {
+    return Err(format!(
+      "Class '{}' declares {} constructors, unable to choose the one receiving '{}'",
+      class_name,
+      constructors.len(),
+      field_name
+    ));
+  }
+  let existing_field_type = find_field_declaration_node_by_name(ts_file, field_name, class_node)
+    .and_then(|field_node| field_node.child_by_field_name("type"))
+    .and_then(|type_node| ts_file.get_text_from_node(&type_node))
+    .map(|field_type| field_type.to_string());
+  Ok(InjectionTarget { class_name, class_start_byte: class_node.start_byte(), existing_field_type })
+}
+
+pub fn run(
+  cwd: &Path,
+  b64_source_code: &str,
+  file_path: &Path,
+  dependency_type: &str,
+  dependency_package_name: Option<&str>,
+  field_name: Option<&str>,
+) -> Result<AddConstructorInjectionResponse, String> {
+  // Step 1: Resolve the field name and parse the file
+  let dependency_type = dependency_type.trim();
+  let simple_type_name = get_simple_type_name(dependency_type);
+  if simple_type_name.is_empty() {
+    return Err("Dependency type must not be empty".to_string());
+  }
+  let field_name = match field_name {
+    Some(field_name) => field_name.to_string(),
+    None => case_util::auto_convert_case(&simple_type_name, CaseType::Camel),
+  };
+  validate_field_name(&field_name)?;
+  let mut ts_file = TSFile::from_base64_source_code(b64_source_code, SupportedLanguage::Java);
+  // Step 2: Find the class and the field already declared, rejecting several constructors
+  let target = find_injection_target(&ts_file, &field_name)?;
+  if let Some(existing_field_type) = &target.existing_field_type
+    && get_simple_type_name(existing_field_type) != simple_type_name
+  {
+    return Err(format!(
+      "Class '{}' already declares a field '{}' of type '{}'",
+      target.class_name, field_name, existing_field_type
+    ));
+  }
+  // Step 3: Add the final field after the last one
+  let field_added = target.existing_field_type.is_none();
+  if field_added {
+    let params = AddFieldDeclarationParams {
+      insertion_position: FieldInsertionPosition::AfterLastField,
+      visibility_modifier: JavaVisibilityModifier::Private,
+      field_modifiers: vec![JavaFieldModifier::Final],
+      field_type: dependency_type,
+      field_name: &field_name,
+      field_initialization: None,
+    };
+    add_field_declaration(&mut ts_file, target.class_start_byte, params, |_| ())
+      .ok_or_else(|| format!("Unable to add the field '{}'", field_name))?;
+  }
+  // Step 4: Add the constructor parameter, or a constructor when the class has none
+  let assignment = format!("this.{} = {};", field_name, field_name);
+  let constructor_start_byte = get_public_class_node(&ts_file)
+    .and_then(|class_node| {
+      get_all_constructor_declaration_nodes(&ts_file, class_node).first().copied()
+    })
+    .map(|constructor| constructor.start_byte());
+  let (constructor_parameter_added, constructor_created) = match constructor_start_byte {
+    Some(constructor_start_byte) => {
+      let added = add_constructor_parameter(
+        &mut ts_file,
+        constructor_start_byte,
+        dependency_type,
+        &field_name,
+        &assignment,
+      )
+      .ok_or_else(|| "Unable to add the constructor parameter".to_string())?;
+      (added, false)
+    }
+    None => {
+      let params = AddConstructorDeclarationParams {
+        visibility_modifier: JavaVisibilityModifier::Public,
+        parameters: vec![(dependency_type, field_name.as_str())],
+        body_statements: vec![assignment],
+      };
+      add_constructor_declaration(&mut ts_file, target.class_start_byte, params)
+        .ok_or_else(|| "Unable to add the constructor".to_string())?;
+      (true, true)
+    }
+  };
+  // Step 5: Import the dependency type unless it lives in the same package
+  if let Some(package_name) = dependency_package_name
+    && get_package_name(&ts_file).as_deref() != Some(package_name)
+  {
+    add_import(
+      &mut ts_file,
+      &ImportInsertionPosition::AfterLastImport,
+      package_name,
+      &simple_type_name,
+    );
+  }
+  // Step 6: Save file with working directory validation
+  ts_file
+    .save_to_existing_file(file_path, cwd)
+    .map_err(|e| format!("Unable to save file: {}", e))?;
+  // Step 7: Build and return response
+  Ok(AddConstructorInjectionResponse {
+    file_path: file_path.display().to_string(),
+    class_name: target.class_name,
+    field_type: dependency_type.to_string(),
+    field_name,
+    field_added,
+    constructor_parameter_added,
+    constructor_created,
+  })
+}
@@ -0,0 +1,154 @@
+use std::path::Path;
+
+use crate::commands::java::responses::add_nested_class_response::AddNestedClassResponse;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  add_member_at_end_of_type_body, find_type_declaration_node, get_nested_type_declaration_names,
+  get_type_declaration_kind, get_type_declaration_name,
+};
+use crate::commands::java::treesitter::types::java_file_type::JavaFileType;
+use crate::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+use crate::commands::java::validators::java_version_validator::validate_java_feature_support;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::case_util;
+
+pub struct NestedTypeModifiers {
+  pub visibility_modifier: JavaVisibilityModifier,
+  /// Only applies to classes, the other nested types are implicitly static
+  pub is_static: bool,
+  pub is_final: bool,
+  pub is_abstract: bool,
+}
+
+fn get_type_keyword(nested_type: &JavaFileType) -> &'static str {
+  match nested_type {
+    JavaFileType::Class => "class",
+    JavaFileType::Interface => "interface",
+    JavaFileType::Enum => "enum",
+    JavaFileType::Record => "record",
+    JavaFileType::Annotation => "@interface",
+  }
+}
+
+fn validate_modifiers(
+  nested_type: &JavaFileType,
+  modifiers: &NestedTypeModifiers,
+  outer_type_kind: &str,
+) -> Result<(), String> {
+  if modifiers.is_final && modifiers.is_abstract {
+    return Err("A nested class cannot be both final and abstract".to_string());
+  }
+  if (modifiers.is_final || modifiers.is_abstract) && *nested_type != JavaFileType::Class {
+    return Err(format!(
+      "Only nested classes can be final or abstract, not a nested {}",
+      get_type_keyword(nested_type)
+    ));
+  }
+  let is_interface_member = matches!(outer_type_kind, "interface" | "annotation");
+  if is_interface_member
+    && matches!(
+      modifiers.visibility_modifier,
+      JavaVisibilityModifier::Private | JavaVisibilityModifier::Protected
+    )
+  {
+    return Err(format!("Types nested in an {} are implicitly public", outer_type_kind));
+  }
+  Ok(())
+}
+
+fn build_nested_type_text(
+  nested_type: &JavaFileType,
+  nested_type_name: &str,
+  modifiers: &NestedTypeModifiers,
+) -> String {
+  let mut keywords = Vec::new();
+  if modifiers.visibility_modifier.has_keyword() {
+    keywords.push(modifiers.visibility_modifier.keyword());
+  }
+  if *nested_type == JavaFileType::Class {
+    if modifiers.is_static {
+      keywords.push("static");
+    }
+    if modifiers.is_abstract {
+      keywords.push("abstract");
+    }
+    if modifiers.is_final {
+      keywords.push("final");
+    }
+  }
+  keywords.push(get_type_keyword(nested_type));
+  let parameters = match nested_type {
+    JavaFileType::Record => "()",
+    _ => "",
+  };
+  format!("{} {}{} {{}}", keywords.join(" "), nested_type_name, parameters)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+  cwd: &Path,
+  b64_source_code: &str,
+  file_path: &Path,
+  outer_type_name: Option<&str>,
+  nested_type_name: &str,
+  nested_type: &JavaFileType,
+  modifiers: &NestedTypeModifiers,
+  target_java_version: Option<u32>,
+) -> Result<AddNestedClassResponse, String> {
+  // Step 1: Check the target Java version supports the nested type, then parse the file
+  if let Some(feature) = nested_type.get_language_feature() {
+    validate_java_feature_support(&feature, target_java_version)?;
+  }
+  let mut ts_file = TSFile::from_base64_source_code(b64_source_code, SupportedLanguage::Java);
+  let nested_type_name = case_util::to_pascal_case(nested_type_name);
+  // Step 2: Find the outer type and check the nested name is free
+  let (outer_type_name, outer_start_byte) = {
+    let outer_node =
+      find_type_declaration_node(&ts_file, outer_type_name).ok_or_else(
+        || match outer_type_name {
+          Some(outer_type_name) => format!("Type '{}' not found in file", outer_type_name),
+          None => "No type declaration found in file".to_string(),
+        },
+      )?;
+    let outer_type_kind = get_type_declaration_kind(outer_node).unwrap_or_default();
+    validate_modifiers(nested_type, modifiers, outer_type_kind)?;
+    let name = get_type_declaration_name(&ts_file, outer_node)
+      .ok_or_else(|| "Couldn't get the outer type name from the tree".to_string())?;
+    if get_nested_type_declaration_names(&ts_file, outer_node).contains(&nested_type_name.as_str())
+    {
+      return Err(format!("Type '{}' already declares '{}'", name, nested_type_name));
+    }
+    // A nested type cannot share the name of any type enclosing it
+    let mut enclosing_node = Some(outer_node);
+    while let Some(node) = enclosing_node {
+      if get_type_declaration_kind(node).is_some()
+        && get_type_declaration_name(&ts_file, node) == Some(nested_type_name.as_str())
+      {
+        return Err(format!("'{}' is already the name of an enclosing type", nested_type_name));
+      }
+      enclosing_node = node.parent();
+    }
+    (name.to_string(), outer_node.start_byte())
+  };
+  // Step 3: Insert the nested type at the end of the outer type body
+  let nested_type_text = build_nested_type_text(nested_type, &nested_type_name, modifiers);
+  let (start_byte, end_byte) =
+    add_member_at_end_of_type_body(&mut ts_file, outer_start_byte, &nested_type_text)
+      .ok_or_else(|| "Unable to insert the nested type".to_string())?;
+  // Step 4: Save file with working directory validation
+  ts_file
+    .save_to_existing_file(file_path, cwd)
+    .map_err(|e| format!("Unable to save file: {}", e))?;
+  // Step 5: Build and return response
+  let line_of = |byte: usize| ts_file.source_code[..byte].matches('\n').count() + 1;
+  Ok(AddNestedClassResponse {
+    file_path: file_path.display().to_string(),
+    outer_type_name,
+    nested_type_name,
+    nested_type_kind: get_type_keyword(nested_type).trim_start_matches('@').to_string(),
+    start_byte,
+    end_byte,
+    start_line: line_of(start_byte),
+    end_line: line_of(end_byte),
+  })
+}
@@ -0,0 +1,571 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::add_repository_query_method_response::AddRepositoryQueryMethodResponse;
+use crate::commands::java::services::get_superclass_fields_service::collect_persistent_fields;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_superclass_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::{
+  get_element_type_name, get_simple_type_name,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, find_imported_class_package, get_all_import_declaration_nodes,
+};
+use crate::commands::java::treesitter::services::interface_declaration_service::{
+  add_interface_method_declaration, get_interface_extended_type_nodes,
+  get_interface_method_declaration_nodes, get_interface_name_node, get_public_interface_node,
+};
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::find_file_by_class_name;
+
+const SPRING_DATA_DOMAIN_PACKAGE: &str = "org.springframework.data.domain";
+
+const SUBJECT_PREFIXES: [&str; 8] =
+  ["find", "read", "get", "query", "search", "stream", "count", "exists"];
+
+/// Number of method arguments bound by a predicate keyword.
+#[derive(Clone, Copy, PartialEq)]
+enum ArgumentKind {
+  None,
+  Single,
+  Pair,
+  Collection,
+}
+
+const PREDICATE_KEYWORDS: [(&str, ArgumentKind); 39] = [
+  ("IsNotNull", ArgumentKind::None),
+  ("NotNull", ArgumentKind::None),
+  ("IsNull", ArgumentKind::None),
+  ("Null", ArgumentKind::None),
+  ("IsTrue", ArgumentKind::None),
+  ("True", ArgumentKind::None),
+  ("IsFalse", ArgumentKind::None),
+  ("False", ArgumentKind::None),
+  ("IsNotEmpty", ArgumentKind::None),
+  ("NotEmpty", ArgumentKind::None),
+  ("IsEmpty", ArgumentKind::None),
+  ("Empty", ArgumentKind::None),
+  ("IsBetween", ArgumentKind::Pair),
+  ("Between", ArgumentKind::Pair),
+  ("IsNotIn", ArgumentKind::Collection),
+  ("NotIn", ArgumentKind::Collection),
+  ("IsIn", ArgumentKind::Collection),
+  ("In", ArgumentKind::Collection),
+  ("GreaterThanEqual", ArgumentKind::Single),
+  ("GreaterThan", ArgumentKind::Single),
+  ("LessThanEqual", ArgumentKind::Single),
+  ("LessThan", ArgumentKind::Single),
+  ("IsAfter", ArgumentKind::Single),
+  ("After", ArgumentKind::Single),
+  ("IsBefore", ArgumentKind::Single),
+  ("Before", ArgumentKind::Single),
+  ("NotLike", ArgumentKind::Single),
+  ("Like", ArgumentKind::Single),
+  ("StartingWith", ArgumentKind::Single),
+  ("StartsWith", ArgumentKind::Single),
+  ("EndingWith", ArgumentKind::Single),
+  ("EndsWith", ArgumentKind::Single),
+  ("NotContaining", ArgumentKind::Single),
+  ("Containing", ArgumentKind::Single),
+  ("Contains", ArgumentKind::Single),
+  ("IsNot", ArgumentKind::Single),
+  ("Not", ArgumentKind::Single),
+  ("Is", ArgumentKind::Single),
+  ("Equals", ArgumentKind::Single),
+];
+
+/// A persistent field of a class of the project, with the package its type is imported from.
+struct ClassField {
+  name: String,
+  field_type: String,
+  type_package: Option<String>,
+}
+
+/// A property path of the method name resolved against the entity, e.g. `author.name`.
+struct ResolvedProperty {
+  path: String,
+  field_name: String,
+  field_type: String,
+  type_package: Option<String>,
+}
+
+fn is_java_lang_or_primitive(simple_type_name: &str) -> bool {
+  matches!(
+    simple_type_name,
+    "String"
+      | "Integer"
+      | "Long"
+      | "Short"
+      | "Byte"
+      | "Character"
+      | "Boolean"
+      | "Double"
+      | "Float"
+      | "Object"
+      | "int"
+      | "long"
+      | "short"
+      | "byte"
+      | "char"
+      | "boolean"
+      | "double"
+      | "float"
+  )
+}
+
+/// Package of a type used in `ts_file`: its single-type import, otherwise the package of the file
+/// itself. `None` for `java.lang` and primitive types, and when a wildcard import makes the
+/// package ambiguous.
+fn resolve_type_package(ts_file: &TSFile, simple_type_name: &str) -> Option<String> {
+  if is_java_lang_or_primitive(simple_type_name) {
+    return None;
+  }
+  if let Some(package_name) = find_imported_class_package(ts_file, simple_type_name) {
+    return Some(package_name);
+  }
+  let has_wildcard_import = get_all_import_declaration_nodes(ts_file).iter().any(|import_node| {
+    ts_file.get_text_from_node(import_node).is_some_and(|text| {
+      let text = text.trim_end_matches(';').trim();
+      text.ends_with(".*") && !text.starts_with("import static")
+    })
+  });
+  if has_wildcard_import { None } else { get_package_name(ts_file) }
+}
+
+/// Collects the persistent fields of a class of the project and of its superclasses.
+fn load_class_fields(cwd: &Path, class_name: &str) -> Option<Vec<ClassField>> {
+  let mut fields = Vec::new();
+  let mut visited = HashSet::new();
+  let mut current_class_name = class_name.to_string();
+  let mut is_declaring_class = true;
+  while visited.insert(current_class_name.clone()) {
+    let Some(ts_file) =
+      find_file_by_class_name(cwd, &JavaSourceDirectoryType::Main, &current_class_name)
+    else {
+      // The requested class itself must be part of the project, its superclasses may not
+      return if is_declaring_class { None } else { Some(fields) };
+    };
+    let class_node = get_public_class_node(&ts_file)?;
+    for field in collect_persistent_fields(&ts_file, class_node, &current_class_name, false) {
+      let type_package = resolve_type_package(
+        &ts_file,
+        &get_simple_type_name(&get_element_type_name(&field.field_type)),
+      );
+      fields.push(ClassField {
+        name: field.field_name,
+        field_type: field.field_type,
+        type_package,
+      });
+    }
+    let Some(superclass_name) = get_class_superclass_name_node(&ts_file, class_node)
+      .and_then(|node| ts_file.get_text_from_node(&node))
+    else {
+      break;
+    };
+    current_class_name = superclass_name.to_string();
+    is_declaring_class = false;
+  }
+  Some(fields)
+}
+
+/// Lowercases the first letter the way `java.beans.Introspector` does, keeping names starting
+/// with two capitals (`URL`) unchanged.
+fn decapitalize(segment: &str) -> String {
+  let mut chars = segment.chars();
+  match (chars.next(), chars.next()) {
+    (Some(first), Some(second)) if first.is_uppercase() && second.is_uppercase() => {
+      segment.to_string()
+    }
+    (Some(first), _) => first.to_lowercase().chain(segment.chars().skip(1)).collect(),
+    _ => String::new(),
+  }
+}
+
+/// Resolves a property segment of the method name (`AuthorName`) against the fields of a class,
+/// traversing into related classes of the project for nested paths (`author.name`). An `_` in
+/// the segment forces the traversal point.
+fn resolve_property(cwd: &Path, fields: &[ClassField], segment: &str) -> Option<ResolvedProperty> {
+  let (head_segment, forced_tail) = match segment.split_once('_') {
+    Some((head, tail)) => (head, Some(tail)),
+    None => (segment, None),
+  };
+  if forced_tail.is_none()
+    && let Some(field) = fields.iter().find(|field| field.name == decapitalize(segment))
+  {
+    return Some(ResolvedProperty {
+      path: field.name.clone(),
+      field_name: field.name.clone(),
+      field_type: field.field_type.clone(),
+      type_package: field.type_package.clone(),
+    });
+  }
+  // Longest field name first, like Spring Data does
+  let split_indexes: Vec<usize> = match forced_tail {
+    Some(_) => vec![head_segment.len()],
+    None => {
+      segment.char_indices().filter(|(_, c)| c.is_uppercase()).map(|(i, _)| i).rev().collect()
+    }
+  };
+  split_indexes.into_iter().filter(|index| *index > 0).find_map(|index| {
+    let field = fields.iter().find(|field| field.name == decapitalize(&segment[..index]))?;
+    let tail = forced_tail.unwrap_or(&segment[index..]);
+    let target_fields = load_class_fields(cwd, &get_element_type_name(&field.field_type))?;
+    let nested = resolve_property(cwd, &target_fields, tail)?;
+    Some(ResolvedProperty { path: format!("{}.{}", field.name, nested.path), ..nested })
+  })
+}
+
+fn is_predicate_boundary(text: &str, index: usize, keyword: &str) -> bool {
+  text[index + keyword.len()..].chars().next().is_some_and(|c| c.is_uppercase())
+}
+
+/// Splits `text` on `And`/`Or` keywords followed by an uppercase letter.
+fn split_predicate(text: &str) -> Vec<&str> {
+  let mut parts = Vec::new();
+  let mut start = 0;
+  let mut index = 0;
+  while index < text.len() {
+    let keyword = ["And", "Or"].into_iter().find(|keyword| {
+      text[index..].starts_with(keyword) && is_predicate_boundary(text, index, keyword)
+    });
+    match keyword {
+      Some(keyword) if index > start => {
+        parts.push(&text[start..index]);
+        index += keyword.len();
+        start = index;
+      }
+      _ => index += text[index..].chars().next().map_or(1, |c| c.len_utf8()),
+    }
+  }
+  parts.push(&text[start..]);
+  parts
+}
+
+fn pluralize(name: &str) -> String {
+  if name.ends_with('s') || name.ends_with('x') {
+    format!("{}es", name)
+  } else if name.ends_with('y') && !name.ends_with("ey") && !name.ends_with("ay") {
+    format!("{}ies", &name[..name.len() - 1])
+  } else {
+    format!("{}s", name)
+  }
+}
+
+fn box_primitive(type_text: &str) -> &str {
+  match type_text {
+    "long" => "Long",
+    "int" => "Integer",
+    "short" => "Short",
+    "byte" => "Byte",
+    "char" => "Character",
+    "boolean" => "Boolean",
+    "double" => "Double",
+    "float" => "Float",
+    _ => type_text,
+  }
+}
+
+/// Parameter type bound to a property: the element type for collection fields, so
+/// `findByTagsContaining(Tag tag)` compares against a single element.
+fn get_parameter_type(property: &ResolvedProperty) -> String {
+  match property.field_type.contains('<') {
+    true => get_element_type_name(&property.field_type),
+    false => property.field_type.clone(),
+  }
+}
+
+fn unique_name(name: String, used_names: &mut HashSet<String>) -> String {
+  let mut candidate = name.clone();
+  let mut suffix = 2;
+  while !used_names.insert(candidate.clone()) {
+    candidate = format!("{}{}", name, suffix);
+    suffix += 1;
+  }
+  candidate
+}
+
+/// Method parameters and property paths parsed from the predicate of a derived query name.
+struct ParsedPredicate {
+  parameters: Vec<(String, String)>,
+  property_paths: Vec<String>,
+  type_imports: Vec<(String, String)>,
+}
+
+fn parse_predicate(
+  cwd: &Path,
+  entity_fields: &[ClassField],
+  method_name: &str,
+  predicate: &str,
+) -> Result<ParsedPredicate, String> {
+  let mut parsed = ParsedPredicate {
+    parameters: Vec::new(),
+    property_paths: Vec::new(),
+    type_imports: Vec::new(),
+  };
+  let mut used_names = HashSet::new();
+  let predicate = predicate.strip_suffix("AllIgnoreCase").unwrap_or(predicate);
+  for part in split_predicate(predicate) {
+    let part =
+      part.strip_suffix("IgnoreCase").or_else(|| part.strip_suffix("IgnoringCase")).unwrap_or(part);
+    let keyword = PREDICATE_KEYWORDS
+      .iter()
+      .filter(|(keyword, _)| part.ends_with(keyword) && part.len() > keyword.len())
+      .max_by_key(|(keyword, _)| keyword.len());
+    // A property ending like a keyword (`checkIn`) is taken as a whole when the rest doesn't
+    // resolve
+    let resolved = keyword
+      .and_then(|(keyword, argument_kind)| {
+        resolve_property(cwd, entity_fields, &part[..part.len() - keyword.len()])
+          .map(|property| (property, *argument_kind))
+      })
+      .or_else(|| {
+        resolve_property(cwd, entity_fields, part).map(|property| (property, ArgumentKind::Single))
+      });
+    let Some((property, argument_kind)) = resolved else {
+      return Err(format!(
+        "Property '{}' of method '{}' does not match any field of the entity",
+        decapitalize(part),
+        method_name
+      ));
+    };
+    let parameter_type = get_parameter_type(&property);
+    if argument_kind != ArgumentKind::None
+      && let Some(package_name) = &property.type_package
+    {
+      parsed.type_imports.push((package_name.clone(), get_simple_type_name(&parameter_type)));
+    }
+    let name = property.field_name.clone();
+    match argument_kind {
+      ArgumentKind::None => {}
+      ArgumentKind::Single => {
+        parsed.parameters.push((parameter_type, unique_name(name, &mut used_names)));
+      }
+      ArgumentKind::Pair => {
+        parsed
+          .parameters
+          .push((parameter_type.clone(), unique_name(format!("{}From", name), &mut used_names)));
+        parsed
+          .parameters
+          .push((parameter_type, unique_name(format!("{}To", name), &mut used_names)));
+      }
+      ArgumentKind::Collection => {
+        parsed.type_imports.push(("java.util".to_string(), "Collection".to_string()));
+        parsed.parameters.push((
+          format!("Collection<{}>", box_primitive(&parameter_type)),
+          unique_name(pluralize(&name), &mut used_names),
+        ));
+      }
+    }
+    parsed.property_paths.push(property.path);
+  }
+  Ok(parsed)
+}
+
+/// Validates the properties of an `OrderBy` clause (`CreatedAtDescTitle`).
+fn parse_order_by(
+  cwd: &Path,
+  entity_fields: &[ClassField],
+  method_name: &str,
+  order_by: &str,
+) -> Result<Vec<String>, String> {
+  let mut property_paths = Vec::new();
+  let mut rest = order_by;
+  while !rest.is_empty() {
+    let direction_end = ["Asc", "Desc"].iter().find_map(|direction| {
+      rest.match_indices(direction).find_map(|(index, _)| {
+        let end = index + direction.len();
+        (index > 0 && (end == rest.len() || rest[end..].starts_with(char::is_uppercase)))
+          .then_some((index, end))
+      })
+    });
+    let (segment, next) = match direction_end {
+      Some((index, end)) => (&rest[..index], &rest[end..]),
+      None => (rest, ""),
+    };
+    let property = resolve_property(cwd, entity_fields, segment).ok_or_else(|| {
+      format!(
+        "Sort property '{}' of method '{}' does not match any field of the entity",
+        decapitalize(segment),
+        method_name
+      )
+    })?;
+    property_paths.push(property.path);
+    rest = next;
+  }
+  Ok(property_paths)
+}
+
+/// Finds the entity type of the `Repository<Entity, Id>`-style supertype of the repository.
+pub fn find_repository_entity_type(ts_file: &TSFile, interface_node: Node) -> Option<String> {
+  get_interface_extended_type_nodes(ts_file, interface_node).into_iter().find_map(|extended_type| {
+    if extended_type.kind() != "generic_type" {
+      return None;
+    }
+    let name = ts_file.get_text_from_node(&extended_type.named_child(0)?)?;
+    let mut cursor = extended_type.walk();
+    let arguments_node =
+      extended_type.named_children(&mut cursor).find(|child| child.kind() == "type_arguments")?;
+    let entity_type_node = arguments_node.named_child(0)?;
+    name
+      .ends_with("Repository")
+      .then(|| ts_file.get_text_from_node(&entity_type_node).map(get_simple_type_name))
+      .flatten()
+  })
+}
+
+pub fn run(
+  cwd: &Path,
+  b64_source_code: &str,
+  file_path: &Path,
+  method_name: &str,
+  pageable: bool,
+  sort: bool,
+) -> Result<AddRepositoryQueryMethodResponse, String> {
+  // Step 1: Split the method name into its subject, predicate and sort clause
+  let method_name = method_name.trim();
+  let prefix = SUBJECT_PREFIXES
+    .into_iter()
+    .find(|prefix| method_name.starts_with(prefix))
+    .ok_or_else(|| {
+      format!("Method '{}' must start with one of: {}", method_name, SUBJECT_PREFIXES.join(", "))
+    })?;
+  let by_index = method_name[prefix.len()..]
+    .match_indices("By")
+    .map(|(index, _)| prefix.len() + index)
+    .find(|index| is_predicate_boundary(method_name, *index, "By"))
+    .ok_or_else(|| {
+      format!(
+        "Method '{}' must declare its criteria after 'By', e.g. '{}ByName'",
+        method_name, prefix
+      )
+    })?;
+  let subject = &method_name[prefix.len()..by_index];
+  let criteria = &method_name[by_index + 2..];
+  let (predicate, order_by) = match criteria.find("OrderBy") {
+    Some(index) => (&criteria[..index], Some(&criteria[index + "OrderBy".len()..])),
+    None => (criteria, None),
+  };
+  // Step 2: Check the paging options against the kind of query
+  let is_projection = prefix == "count" || prefix == "exists";
+  if is_projection && (pageable || sort) {
+    return Err(format!("'{}' queries can't take a Pageable or Sort parameter", prefix));
+  }
+  if pageable && sort {
+    return Err("A Pageable already carries its Sort, use either --pageable or --sort".to_string());
+  }
+  // Step 3: Find the repository interface and its entity
+  let mut ts_file = TSFile::from_base64_source_code(b64_source_code, SupportedLanguage::Java);
+  let interface_node = get_public_interface_node(&ts_file)
+    .ok_or_else(|| "Unable to get public interface node of the repository".to_string())?;
+  let repository_type = get_interface_name_node(&ts_file, interface_node)
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .map(|name| name.to_string())
+    .ok_or_else(|| "Couldn't get the repository name from the tree".to_string())?;
+  let entity_type = find_repository_entity_type(&ts_file, interface_node).ok_or_else(|| {
+    format!(
+      "'{}' does not extend a Spring Data repository with entity and id generics",
+      repository_type
+    )
+  })?;
+  // Step 4: Resolve the properties of the method name against the entity fields
+  let entity_fields = load_class_fields(cwd, &entity_type)
+    .ok_or_else(|| format!("Entity '{}' not found in the project", entity_type))?;
+  let mut parsed = parse_predicate(cwd, &entity_fields, method_name, predicate)?;
+  if let Some(order_by) = order_by {
+    parsed.property_paths.extend(parse_order_by(cwd, &entity_fields, method_name, order_by)?);
+  }
+  // Step 5: Build the signature
+  let is_single_result = matches!(subject, "First" | "Top" | "First1" | "Top1");
+  let (return_type, mut imports) = match prefix {
+    "count" => ("long".to_string(), Vec::new()),
+    "exists" => ("boolean".to_string(), Vec::new()),
+    "stream" => (format!("Stream<{}>", entity_type), vec![("java.util.stream", "Stream")]),
+    _ if pageable => (format!("Page<{}>", entity_type), vec![(SPRING_DATA_DOMAIN_PACKAGE, "Page")]),
+    _ if is_single_result => {
+      (format!("Optional<{}>", entity_type), vec![("java.util", "Optional")])
+    }
+    _ => (format!("List<{}>", entity_type), vec![("java.util", "List")]),
+  };
+  let mut parameters = parsed.parameters;
+  if pageable {
+    parameters.push(("Pageable".to_string(), "pageable".to_string()));
+    imports.push((SPRING_DATA_DOMAIN_PACKAGE, "Pageable"));
+  }
+  if sort {
+    parameters.push(("Sort".to_string(), "sort".to_string()));
+    imports.push((SPRING_DATA_DOMAIN_PACKAGE, "Sort"));
+  }
+  let method_signature = format!(
+    "{} {}({})",
+    return_type,
+    method_name,
+    parameters
+      .iter()
+      .map(|(parameter_type, parameter_name)| format!("{} {}", parameter_type, parameter_name))
+      .collect::<Vec<_>>()
+      .join(", ")
+  );
+  // Step 6: Add the declaration unless the repository already declares it
+  let parameters_count = parameters.len();
+  let is_declared =
+    get_interface_method_declaration_nodes(&ts_file, interface_node).into_iter().any(|method| {
+      let name =
+        method.child_by_field_name("name").and_then(|node| ts_file.get_text_from_node(&node));
+      let declared_parameters_count = method.child_by_field_name("parameters").map_or(0, |node| {
+        let mut cursor = node.walk();
+        node.named_children(&mut cursor).filter(|child| child.kind() == "formal_parameter").count()
+      });
+      name == Some(method_name) && declared_parameters_count == parameters_count
+    });
+  if is_declared {
+    return Err(format!(
+      "Repository '{}' already declares '{}' with {} parameter(s)",
+      repository_type, method_name, parameters_count
+    ));
+  }
+  let interface_start_byte = interface_node.start_byte();
+  add_interface_method_declaration(
+    &mut ts_file,
+    interface_start_byte,
+    &format!("{};", method_signature),
+  )
+  .ok_or_else(|| format!("Unable to add method '{}'", method_name))?;
+  // Step 7: Import the return, paging and parameter types
+  let repository_package_name = get_package_name(&ts_file);
+  let mut imports_added = Vec::new();
+  let type_imports = parsed
+    .type_imports
+    .iter()
+    .map(|(package_name, class_name)| (package_name.as_str(), class_name.as_str()));
+  for (package_name, class_name) in imports.into_iter().chain(type_imports) {
+    if repository_package_name.as_deref() == Some(package_name) {
+      continue;
+    }
+    if add_import(&mut ts_file, &ImportInsertionPosition::AfterLastImport, package_name, class_name)
+      .is_some()
+    {
+      imports_added.push(format!("{}.{}", package_name, class_name));
+    }
+  }
+  // Step 8: Save file with working directory validation
+  ts_file
+    .save_to_existing_file(file_path, cwd)
+    .map_err(|e| format!("Unable to save file: {}", e))?;
+  // Step 9: Build and return response
+  Ok(AddRepositoryQueryMethodResponse {
+    file_path: file_path.display().to_string(),
+    repository_type,
+    entity_type,
+    method_signature,
+    return_type,
+    property_paths: parsed.property_paths,
+    imports_added,
+  })
+}
@@ -0,0 +1,277 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::add_secondary_table_response::{
+  AddSecondaryTableResponse, SecondaryTableResponse,
+};
+use crate::commands::java::treesitter::services::annotation_service::{
+  add_annotation, find_declaration_annotation_node_by_name,
+  find_direct_annotation_value_node_by_key, remove_annotation, set_annotation_argument,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::get_required_public_class_node;
+use crate::commands::java::treesitter::services::entity_mapping_service::get_class_mapping;
+use crate::commands::java::treesitter::services::field_declaration_service::find_field_declaration_node_by_name;
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, get_persistence_package,
+};
+use crate::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
+use crate::commands::java::treesitter::types::entity_mapping::MappedClassKind;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::string_literal_util::{from_java_string_literal, to_java_string_literal};
+
+/// A secondary table of the entity together with the source text of its `@SecondaryTable`
+/// annotation. Existing entries keep their original text so that attributes this command doesn't
+/// manage (e.g. `foreignKey`, `uniqueConstraints`) are preserved.
+struct SecondaryTableEntry {
+  table: SecondaryTableResponse,
+  annotation_text: String,
+}
+
+fn get_string_argument(ts_file: &TSFile, annotation_node: Node, key: &str) -> Option<String> {
+  let value_node = find_direct_annotation_value_node_by_key(ts_file, annotation_node, key)?;
+  ts_file.get_text_from_node(&value_node).map(from_java_string_literal)
+}
+
+/// Reads the first `@PrimaryKeyJoinColumn` name of a `pkJoinColumns` value, which may be a single
+/// annotation or an array of annotations.
+fn get_pk_join_column_name(ts_file: &TSFile, secondary_table_node: Node) -> Option<String> {
+  let value_node =
+    find_direct_annotation_value_node_by_key(ts_file, secondary_table_node, "pkJoinColumns")?;
+  let join_column_node = if value_node.kind() == "annotation" {
+    value_node
+  } else {
+    let mut cursor = value_node.walk();
+    value_node.named_children(&mut cursor).find(|child| child.kind() == "annotation")?
+  };
+  get_string_argument(ts_file, join_column_node, "name")
+}
+
+fn build_entry(ts_file: &TSFile, secondary_table_node: Node) -> Option<SecondaryTableEntry> {
+  Some(SecondaryTableEntry {
+    table: SecondaryTableResponse {
+      name: get_string_argument(ts_file, secondary_table_node, "name")?,
+      pk_join_column: get_pk_join_column_name(ts_file, secondary_table_node),
+    },
+    annotation_text: ts_file.get_text_from_node(&secondary_table_node)?.to_string(),
+  })
+}
+
+fn collect_existing_entries(ts_file: &TSFile, class_node: Node) -> Vec<SecondaryTableEntry> {
+  let mut entries = Vec::new();
+  if let Some(annotation_node) =
+    find_declaration_annotation_node_by_name(ts_file, class_node, "SecondaryTable")
+  {
+    entries.extend(build_entry(ts_file, annotation_node));
+  }
+  if let Some(value_node) =
+    find_declaration_annotation_node_by_name(ts_file, class_node, "SecondaryTables")
+      .and_then(|node| find_direct_annotation_value_node_by_key(ts_file, node, "value"))
+  {
+    if value_node.kind() == "annotation" {
+      entries.extend(build_entry(ts_file, value_node));
+    } else {
+      let mut cursor = value_node.walk();
+      let nested: Vec<Node> = value_node
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "annotation")
+        .collect();
+      entries.extend(nested.into_iter().filter_map(|node| build_entry(ts_file, node)));
+    }
+  }
+  entries
+}
+
+fn build_secondary_table_text(table: &SecondaryTableResponse) -> String {
+  match &table.pk_join_column {
+    Some(pk_join_column) => format!(
+      "@SecondaryTable(name = {}, pkJoinColumns = @PrimaryKeyJoinColumn(name = {}))",
+      to_java_string_literal(&table.name),
+      to_java_string_literal(pk_join_column)
+    ),
+    None => format!("@SecondaryTable(name = {})", to_java_string_literal(&table.name)),
+  }
+}
+
+fn build_secondary_tables_annotation(entries: &[SecondaryTableEntry], indentation: &str) -> String {
+  if entries.len() == 1 {
+    return entries[0].annotation_text.clone();
+  }
+  let nested = entries
+    .iter()
+    .map(|entry| format!("{}  {}", indentation, entry.annotation_text))
+    .collect::<Vec<_>>()
+    .join(",\n");
+  format!("@SecondaryTables({{\n{}\n{}}})", nested, indentation)
+}
+
+/// Checks every field is a basic column declared by the entity itself. Identifiers, relationships
+/// and embedded values can't be moved to a secondary table.
+fn validate_fields(ts_file: &TSFile, field_names: &[String]) -> Result<(), String> {
+  let class_node = get_required_public_class_node(ts_file)?;
+  let mapping = get_class_mapping(ts_file, class_node)
+    .filter(|mapping| mapping.kind == MappedClassKind::Entity)
+    .ok_or_else(|| "Class is not annotated with @Entity".to_string())?;
+  for field_name in field_names {
+    match mapping.columns.iter().find(|column| &column.field_name == field_name) {
+      Some(column) if column.is_id => {
+        return Err(format!(
+          "Field '{}' is an identifier and must stay in the primary table",
+          field_name
+        ));
+      }
+      Some(_) => {}
+      None if find_field_declaration_node_by_name(ts_file, field_name, class_node).is_some() => {
+        return Err(format!(
+          "Field '{}' is not a basic column and can't be mapped to a secondary table",
+          field_name
+        ));
+      }
+      None => {
+        return Err(format!("Field '{}' not found in entity '{}'", field_name, mapping.class_name));
+      }
+    }
+  }
+  Ok(())
+}
+
+fn remove_existing_secondary_tables(ts_file: &mut TSFile) -> Result<(), String> {
+  for annotation_name in ["SecondaryTables", "SecondaryTable"] {
+    loop {
+      let annotation_start_byte = {
+        let class_node = get_required_public_class_node(ts_file)?;
+        match find_declaration_annotation_node_by_name(ts_file, class_node, annotation_name) {
+          Some(node) => node.start_byte(),
+          None => break,
+        }
+      };
+      if !remove_annotation(ts_file, annotation_start_byte) {
+        return Err(format!("Unable to remove @{} annotation", annotation_name));
+      }
+    }
+  }
+  Ok(())
+}
+
+/// Sets `table` on the field's `@Column`, adding a `@Column` when the field has none.
+fn assign_field_to_table(ts_file: &mut TSFile, field_name: &str, table_name: &str) -> bool {
+  let table_literal = to_java_string_literal(table_name);
+  let (field_start_byte, column_start_byte) = {
+    let Ok(class_node) = get_required_public_class_node(ts_file) else {
+      return false;
+    };
+    let Some(field_node) = find_field_declaration_node_by_name(ts_file, field_name, class_node)
+    else {
+      return false;
+    };
+    let column_node = find_declaration_annotation_node_by_name(ts_file, field_node, "Column");
+    (field_node.start_byte(), column_node.map(|node| node.start_byte()))
+  };
+  match column_start_byte {
+    Some(column_start_byte) => {
+      set_annotation_argument(ts_file, column_start_byte, "table", &table_literal)
+    }
+    None => add_annotation(
+      ts_file,
+      field_start_byte,
+      &AnnotationInsertionPosition::AboveScopeDeclaration,
+      &format!("@Column(table = {})", table_literal),
+    )
+    .is_some(),
+  }
+}
+
+fn add_imports(ts_file: &mut TSFile, entries: &[SecondaryTableEntry], has_reassigned_fields: bool) {
+  let import_position = ImportInsertionPosition::BeforeFirstImport;
+  let persistence_package = get_persistence_package(ts_file);
+  add_import(ts_file, &import_position, persistence_package, "SecondaryTable");
+  if entries.len() > 1 {
+    add_import(ts_file, &import_position, persistence_package, "SecondaryTables");
+  }
+  if entries.iter().any(|entry| entry.annotation_text.contains("@PrimaryKeyJoinColumn")) {
+    add_import(ts_file, &import_position, persistence_package, "PrimaryKeyJoinColumn");
+  }
+  if has_reassigned_fields {
+    add_import(ts_file, &import_position, persistence_package, "Column");
+  }
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  table_name: &str,
+  pk_join_column: Option<&str>,
+  field_names: &[String],
+) -> Result<AddSecondaryTableResponse, String> {
+  // Step 1: Parse entity file
+  let mut entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  // Step 2: Validate the fields to move to the secondary table
+  let mut reassigned_fields: Vec<String> = Vec::new();
+  for field_name in field_names {
+    if !reassigned_fields.contains(field_name) {
+      reassigned_fields.push(field_name.clone());
+    }
+  }
+  validate_fields(&entity_ts_file, &reassigned_fields)?;
+  // Step 3: Merge the new secondary table with the existing ones
+  let secondary_table = SecondaryTableResponse {
+    name: table_name.to_string(),
+    pk_join_column: pk_join_column.map(|name| name.to_string()),
+  };
+  let new_entry = SecondaryTableEntry {
+    annotation_text: build_secondary_table_text(&secondary_table),
+    table: secondary_table.clone(),
+  };
+  let (mut entries, indentation) = {
+    let class_node = get_required_public_class_node(&entity_ts_file)?;
+    (
+      collect_existing_entries(&entity_ts_file, class_node),
+      entity_ts_file.get_line_indentation(class_node.start_byte()),
+    )
+  };
+  // An existing table is only rewritten when a new join column is given, otherwise it's kept as is
+  let secondary_table = match entries.iter_mut().find(|entry| entry.table.name == table_name) {
+    Some(existing) if pk_join_column.is_none() => existing.table.clone(),
+    Some(existing) => {
+      *existing = new_entry;
+      secondary_table
+    }
+    None => {
+      entries.push(new_entry);
+      secondary_table
+    }
+  };
+  // Step 4: Replace the existing secondary table annotations with the merged ones
+  remove_existing_secondary_tables(&mut entity_ts_file)?;
+  let class_start_byte = get_required_public_class_node(&entity_ts_file)?.start_byte();
+  add_annotation(
+    &mut entity_ts_file,
+    class_start_byte,
+    &AnnotationInsertionPosition::AboveScopeDeclaration,
+    &build_secondary_tables_annotation(&entries, &indentation),
+  )
+  .ok_or_else(|| "Unable to add @SecondaryTable annotation".to_string())?;
+  // Step 5: Map the fields to the secondary table
+  for field_name in &reassigned_fields {
+    if !assign_field_to_table(&mut entity_ts_file, field_name, table_name) {
+      return Err(format!("Unable to set the table of field '{}'", field_name));
+    }
+  }
+  // Step 6: Add imports
+  add_imports(&mut entity_ts_file, &entries, !reassigned_fields.is_empty());
+  // Step 7: Save file with working directory validation
+  entity_ts_file
+    .save_to_existing_file(entity_file_path, cwd)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 8: Build and return response
+  Ok(AddSecondaryTableResponse {
+    entity_file_path: entity_file_path.display().to_string(),
+    secondary_table,
+    secondary_tables: entries.into_iter().map(|entry| entry.table).collect(),
+    reassigned_fields,
+  })
+}
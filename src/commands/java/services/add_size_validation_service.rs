@@ -0,0 +1,179 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::add_size_validation_response::AddSizeValidationResponse;
+use crate::commands::java::treesitter::services::annotation_service::{
+  add_annotation, find_declaration_annotation_node_by_name, set_annotation_argument,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+use crate::commands::java::treesitter::services::entity_mapping_service::{
+  get_class_mapping, get_simple_type_name,
+};
+use crate::commands::java::treesitter::services::field_declaration_service::find_field_declaration_node_by_name;
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, get_persistence_package,
+};
+use crate::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
+use crate::commands::java::treesitter::types::entity_mapping::ColumnMapping;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+
+/// Column length JPA uses when `@Column(length)` is omitted.
+const DEFAULT_COLUMN_LENGTH: u32 = 255;
+
+fn find_field_node<'a>(ts_file: &'a TSFile, field_name: &str) -> Result<Node<'a>, String> {
+  let class_node = get_public_class_node(ts_file)
+    .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
+  find_field_declaration_node_by_name(ts_file, field_name, class_node)
+    .ok_or_else(|| format!("Field '{}' not found in class", field_name))
+}
+
+fn find_column_mapping(ts_file: &TSFile, field_name: &str) -> Option<ColumnMapping> {
+  let class_node = get_public_class_node(ts_file)?;
+  get_class_mapping(ts_file, class_node)?
+    .columns
+    .into_iter()
+    .find(|column| column.field_name == field_name)
+}
+
+/// The length only applies to character and binary columns, where it defaults to 255.
+fn get_effective_column_length(column: &ColumnMapping) -> Option<u32> {
+  let has_length =
+    matches!(get_simple_type_name(&column.field_type).as_str(), "String" | "char[]" | "byte[]");
+  match has_length && !column.is_lob && column.column_definition.is_none() {
+    true => Some(column.length.unwrap_or(DEFAULT_COLUMN_LENGTH)),
+    false => None,
+  }
+}
+
+/// Sets `key` on the field's annotation, adding the annotation when the field doesn't carry it.
+fn set_field_annotation_argument(
+  ts_file: &mut TSFile,
+  field_name: &str,
+  annotation_name: &str,
+  key: &str,
+  value: &str,
+) -> Result<(), String> {
+  let (field_start_byte, annotation_start_byte) = {
+    let field_node = find_field_node(ts_file, field_name)?;
+    let annotation_node =
+      find_declaration_annotation_node_by_name(ts_file, field_node, annotation_name);
+    (field_node.start_byte(), annotation_node.map(|node| node.start_byte()))
+  };
+  let updated = match annotation_start_byte {
+    Some(annotation_start_byte) => {
+      set_annotation_argument(ts_file, annotation_start_byte, key, value)
+    }
+    None => add_annotation(
+      ts_file,
+      field_start_byte,
+      &AnnotationInsertionPosition::AboveScopeDeclaration,
+      &format!("@{}({} = {})", annotation_name, key, value),
+    )
+    .is_some(),
+  };
+  match updated {
+    true => Ok(()),
+    false => Err(format!("Unable to set '{}' on @{}", key, annotation_name)),
+  }
+}
+
+/// Bean Validation moved to `jakarta.validation` together with JPA, follow the file's namespace.
+fn get_validation_package(ts_file: &TSFile) -> &'static str {
+  match get_persistence_package(ts_file) {
+    "javax.persistence" => "javax.validation.constraints",
+    _ => "jakarta.validation.constraints",
+  }
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  size_min: Option<u32>,
+  size_max: Option<u32>,
+  sync_length: bool,
+) -> Result<AddSizeValidationResponse, String> {
+  // Step 1: Validate the bounds
+  if size_min.is_none() && size_max.is_none() {
+    return Err("At least one of min or max is required".to_string());
+  }
+  if let (Some(min), Some(max)) = (size_min, size_max)
+    && min > max
+  {
+    return Err(format!("Min ({}) cannot be greater than max ({})", min, max));
+  }
+  // Step 2: Parse entity file and read the existing column mapping of the field
+  let mut entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  find_field_node(&entity_ts_file, field_name)?;
+  let column = find_column_mapping(&entity_ts_file, field_name);
+  let validation_package = get_validation_package(&entity_ts_file);
+  // Step 3: Add or update @Size
+  if let Some(min) = size_min {
+    set_field_annotation_argument(
+      &mut entity_ts_file,
+      field_name,
+      "Size",
+      "min",
+      &min.to_string(),
+    )?;
+  }
+  if let Some(max) = size_max {
+    set_field_annotation_argument(
+      &mut entity_ts_file,
+      field_name,
+      "Size",
+      "max",
+      &max.to_string(),
+    )?;
+  }
+  // Step 4: Sync @Column(length) with the max size, or report the mismatch
+  let mut warnings = Vec::new();
+  let mut column_length = column.as_ref().and_then(get_effective_column_length);
+  let mut length_synced = false;
+  match (size_max, column_length) {
+    (Some(max), Some(length)) if max != length && sync_length => {
+      set_field_annotation_argument(
+        &mut entity_ts_file,
+        field_name,
+        "Column",
+        "length",
+        &max.to_string(),
+      )?;
+      column_length = Some(max);
+      length_synced = true;
+    }
+    (Some(max), Some(length)) if max != length => warnings.push(format!(
+      "@Size(max = {}) conflicts with the column length {} of field '{}'",
+      max, length, field_name
+    )),
+    (Some(_), None) if sync_length => warnings
+      .push(format!("Field '{}' has no character column length to sync with @Size", field_name)),
+    _ => {}
+  }
+  // Step 5: Add imports
+  let import_position = ImportInsertionPosition::BeforeFirstImport;
+  add_import(&mut entity_ts_file, &import_position, validation_package, "Size");
+  if length_synced {
+    let persistence_package = get_persistence_package(&entity_ts_file);
+    add_import(&mut entity_ts_file, &import_position, persistence_package, "Column");
+  }
+  // Step 6: Save file with working directory validation
+  entity_ts_file
+    .save_to_existing_file(entity_file_path, cwd)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 7: Build and return response
+  Ok(AddSizeValidationResponse {
+    entity_file_path: entity_file_path.display().to_string(),
+    field_name: field_name.to_string(),
+    size_min,
+    size_max,
+    column_length,
+    length_synced,
+    warnings,
+  })
+}
@@ -0,0 +1,257 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::add_unique_constraint_response::AddUniqueConstraintResponse;
+use crate::commands::java::treesitter::services::annotation_service::{
+  add_annotation, find_declaration_annotation_node_by_name,
+  find_direct_annotation_value_node_by_key, remove_annotation_argument, set_annotation_argument,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::get_required_public_class_node;
+use crate::commands::java::treesitter::services::entity_mapping_service::get_class_mapping;
+use crate::commands::java::treesitter::services::field_declaration_service::find_field_declaration_node_by_name;
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, get_persistence_package,
+};
+use crate::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
+use crate::commands::java::treesitter::types::entity_mapping::MappedClassKind;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::string_literal_util::to_java_string_literal;
+
+/// A field of the constraint with the annotation declaring its column.
+struct ConstraintColumn {
+  field_name: String,
+  column_name: String,
+  /// `Column` for basic columns, `JoinColumn` for foreign keys
+  annotation_name: &'static str,
+}
+
+/// Resolves the column of every field. Fields must be columns or foreign keys declared by the
+/// entity itself, identifiers are already unique on their own.
+fn resolve_columns(
+  ts_file: &TSFile,
+  field_names: &[String],
+) -> Result<(String, Vec<ConstraintColumn>), String> {
+  let class_node = get_required_public_class_node(ts_file)?;
+  let mapping = get_class_mapping(ts_file, class_node)
+    .filter(|mapping| mapping.kind == MappedClassKind::Entity)
+    .ok_or_else(|| "Class is not annotated with @Entity".to_string())?;
+  let mut columns: Vec<ConstraintColumn> = Vec::new();
+  for field_name in field_names {
+    if columns.iter().any(|column| &column.field_name == field_name) {
+      continue;
+    }
+    let column = match mapping.columns.iter().find(|column| &column.field_name == field_name) {
+      Some(column) if column.is_id => {
+        return Err(format!("Field '{}' is an identifier and already unique", field_name));
+      }
+      Some(column) => ConstraintColumn {
+        field_name: field_name.clone(),
+        column_name: column.column_name.clone(),
+        annotation_name: "Column",
+      },
+      None => match mapping.join_columns.iter().find(|column| &column.field_name == field_name) {
+        Some(join_column) => ConstraintColumn {
+          field_name: field_name.clone(),
+          column_name: join_column.column_name.clone(),
+          annotation_name: "JoinColumn",
+        },
+        None if find_field_declaration_node_by_name(ts_file, field_name, class_node).is_some() => {
+          return Err(format!("Field '{}' is not mapped to a column", field_name));
+        }
+        None => {
+          return Err(format!(
+            "Field '{}' not found in entity '{}'",
+            field_name, mapping.class_name
+          ));
+        }
+      },
+    };
+    columns.push(column);
+  }
+  if columns.is_empty() {
+    return Err("At least one field is required".to_string());
+  }
+  Ok((mapping.class_name, columns))
+}
+
+fn get_string_values(ts_file: &TSFile, value_node: Node) -> Vec<String> {
+  let value_nodes = if value_node.kind() == "element_value_array_initializer" {
+    let mut cursor = value_node.walk();
+    value_node.named_children(&mut cursor).collect()
+  } else {
+    vec![value_node]
+  };
+  value_nodes
+    .iter()
+    .filter(|node| node.kind() == "string_literal")
+    .filter_map(|node| ts_file.get_text_from_node(node))
+    .map(|text| text.trim_matches('"').to_string())
+    .collect()
+}
+
+/// Returns the source text of the existing `@UniqueConstraint` entries, failing when one of them
+/// already covers the same set of columns.
+fn collect_existing_constraints(
+  ts_file: &TSFile,
+  table_node: Node,
+  column_names: &[String],
+) -> Result<Vec<String>, String> {
+  let Some(value_node) =
+    find_direct_annotation_value_node_by_key(ts_file, table_node, "uniqueConstraints")
+  else {
+    return Ok(Vec::new());
+  };
+  let constraint_nodes: Vec<Node> = if value_node.kind() == "element_value_array_initializer" {
+    let mut cursor = value_node.walk();
+    value_node.named_children(&mut cursor).filter(|child| child.kind() == "annotation").collect()
+  } else {
+    vec![value_node]
+  };
+  let mut sorted_column_names = column_names.to_vec();
+  sorted_column_names.sort();
+  let mut constraints = Vec::new();
+  for constraint_node in constraint_nodes {
+    let mut existing_column_names =
+      find_direct_annotation_value_node_by_key(ts_file, constraint_node, "columnNames")
+        .map(|node| get_string_values(ts_file, node))
+        .unwrap_or_default();
+    existing_column_names.sort();
+    if existing_column_names == sorted_column_names {
+      return Err(format!("A unique constraint on ({}) already exists", column_names.join(", ")));
+    }
+    constraints.extend(ts_file.get_text_from_node(&constraint_node).map(|text| text.to_string()));
+  }
+  Ok(constraints)
+}
+
+fn build_unique_constraint_text(constraint_name: Option<&str>, column_names: &[String]) -> String {
+  let column_names = column_names
+    .iter()
+    .map(|column_name| to_java_string_literal(column_name))
+    .collect::<Vec<_>>()
+    .join(", ");
+  match constraint_name {
+    Some(constraint_name) => format!(
+      "@UniqueConstraint(name = {}, columnNames = {{{}}})",
+      to_java_string_literal(constraint_name),
+      column_names
+    ),
+    None => format!("@UniqueConstraint(columnNames = {{{}}})", column_names),
+  }
+}
+
+/// Removes `unique = true` from the column annotations, returning the demoted fields. Fields are
+/// processed from the bottom of the file so the positions of the others stay valid.
+fn demote_unique_columns(ts_file: &mut TSFile, columns: &[ConstraintColumn]) -> Vec<String> {
+  let mut unique_annotations: Vec<(usize, String)> = {
+    let Ok(class_node) = get_required_public_class_node(ts_file) else {
+      return Vec::new();
+    };
+    columns
+      .iter()
+      .filter_map(|column| {
+        let field_node =
+          find_field_declaration_node_by_name(ts_file, &column.field_name, class_node)?;
+        let annotation_node =
+          find_declaration_annotation_node_by_name(ts_file, field_node, column.annotation_name)?;
+        let unique_value =
+          find_direct_annotation_value_node_by_key(ts_file, annotation_node, "unique")?;
+        (ts_file.get_text_from_node(&unique_value)?.trim() == "true")
+          .then(|| (annotation_node.start_byte(), column.field_name.clone()))
+      })
+      .collect()
+  };
+  unique_annotations
+    .sort_by_key(|(annotation_start_byte, _)| std::cmp::Reverse(*annotation_start_byte));
+  let mut demoted_fields: Vec<String> = unique_annotations
+    .into_iter()
+    .filter(|(annotation_start_byte, _)| {
+      remove_annotation_argument(ts_file, *annotation_start_byte, "unique")
+    })
+    .map(|(_, field_name)| field_name)
+    .collect();
+  demoted_fields
+    .sort_by_key(|field_name| columns.iter().position(|column| &column.field_name == field_name));
+  demoted_fields
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_names: &[String],
+  constraint_name: Option<&str>,
+) -> Result<AddUniqueConstraintResponse, String> {
+  // Step 1: Parse entity file
+  let mut entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  // Step 2: Validate the fields and resolve their columns
+  let (entity_type, columns) = resolve_columns(&entity_ts_file, field_names)?;
+  let column_names: Vec<String> = columns.iter().map(|column| column.column_name.clone()).collect();
+  // Step 3: Merge the new constraint with the existing ones
+  let unique_constraint = build_unique_constraint_text(constraint_name, &column_names);
+  let mut constraints = {
+    let class_node = get_required_public_class_node(&entity_ts_file)?;
+    match find_declaration_annotation_node_by_name(&entity_ts_file, class_node, "Table") {
+      Some(table_node) => collect_existing_constraints(&entity_ts_file, table_node, &column_names)?,
+      None => Vec::new(),
+    }
+  };
+  constraints.push(unique_constraint.clone());
+  let unique_constraints = if constraints.len() == 1 {
+    unique_constraint.clone()
+  } else {
+    format!("{{{}}}", constraints.join(", "))
+  };
+  // Step 4: Remove the column-level uniqueness, the fields are below the class annotations
+  let demoted_fields = demote_unique_columns(&mut entity_ts_file, &columns);
+  // Step 5: Set the constraints on @Table, adding the annotation when missing
+  let (class_start_byte, table_start_byte) = {
+    let class_node = get_required_public_class_node(&entity_ts_file)?;
+    let table_node = find_declaration_annotation_node_by_name(&entity_ts_file, class_node, "Table");
+    (class_node.start_byte(), table_node.map(|node| node.start_byte()))
+  };
+  let table_annotation_added = table_start_byte.is_none();
+  match table_start_byte {
+    Some(table_start_byte) => {
+      if !set_annotation_argument(
+        &mut entity_ts_file,
+        table_start_byte,
+        "uniqueConstraints",
+        &unique_constraints,
+      ) {
+        return Err("Unable to set the unique constraints of @Table".to_string());
+      }
+    }
+    None => {
+      add_annotation(
+        &mut entity_ts_file,
+        class_start_byte,
+        &AnnotationInsertionPosition::AboveScopeDeclaration,
+        &format!("@Table(uniqueConstraints = {})", unique_constraints),
+      )
+      .ok_or_else(|| "Unable to add @Table annotation".to_string())?;
+    }
+  }
+  // Step 6: Add imports
+  let import_position = ImportInsertionPosition::BeforeFirstImport;
+  let persistence_package = get_persistence_package(&entity_ts_file);
+  add_import(&mut entity_ts_file, &import_position, persistence_package, "UniqueConstraint");
+  add_import(&mut entity_ts_file, &import_position, persistence_package, "Table");
+  // Step 7: Save file with working directory validation
+  entity_ts_file
+    .save_to_existing_file(entity_file_path, cwd)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 8: Build and return response
+  Ok(AddUniqueConstraintResponse {
+    entity_file_path: entity_file_path.display().to_string(),
+    entity_type,
+    unique_constraint,
+    column_names,
+    demoted_fields,
+    table_annotation_added,
+  })
+}
@@ -0,0 +1,279 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::check_entity_compiles_against_repository_response::{
+  CheckEntityCompilesAgainstRepositoryResponse, RepositoryMismatchResponse,
+};
+use crate::commands::java::treesitter::services::annotation_service::find_annotation_node_by_name;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::get_own_field_declaration_nodes;
+use crate::commands::java::treesitter::services::import_declaration_service::resolve_fully_qualified_type_name;
+use crate::commands::java::treesitter::services::interface_declaration_service::{
+  get_interface_extended_type_nodes, get_interface_name_node, get_public_interface_node,
+};
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::find_file_by_class_name;
+
+/// A type as written in a file together with its fully qualified name, when it can be resolved.
+#[derive(Clone)]
+struct ResolvedType {
+  simple_name: String,
+  qualified_name: Option<String>,
+}
+
+impl ResolvedType {
+  fn from_file(ts_file: &TSFile, type_text: &str) -> Self {
+    let type_text = box_primitive(type_text.split('<').next().unwrap_or(type_text).trim());
+    let simple_name = type_text.rsplit('.').next().unwrap_or(type_text).to_string();
+    Self { simple_name, qualified_name: resolve_fully_qualified_type_name(ts_file, type_text) }
+  }
+
+  fn display_name(&self) -> String {
+    self.qualified_name.clone().unwrap_or_else(|| self.simple_name.clone())
+  }
+
+  /// Compares fully qualified names when both sides are known, simple names otherwise.
+  fn matches(&self, other: &ResolvedType) -> bool {
+    match (&self.qualified_name, &other.qualified_name) {
+      (Some(a), Some(b)) => a == b,
+      _ => self.simple_name == other.simple_name,
+    }
+  }
+}
+
+fn box_primitive(type_text: &str) -> &str {
+  match type_text {
+    "long" => "Long",
+    "int" => "Integer",
+    "short" => "Short",
+    "byte" => "Byte",
+    "char" => "Character",
+    "boolean" => "Boolean",
+    "double" => "Double",
+    "float" => "Float",
+    _ => type_text,
+  }
+}
+
+fn get_name(ts_file: &TSFile, node: Option<Node>) -> Option<String> {
+  node.and_then(|n| ts_file.get_text_from_node(&n)).map(|name| name.to_string())
+}
+
+fn get_type_argument_nodes(type_node: Node) -> Vec<Node> {
+  let mut cursor = type_node.walk();
+  let Some(arguments_node) =
+    type_node.named_children(&mut cursor).find(|child| child.kind() == "type_arguments")
+  else {
+    return Vec::new();
+  };
+  let mut arguments_cursor = arguments_node.walk();
+  arguments_node.named_children(&mut arguments_cursor).collect()
+}
+
+fn get_type_parameter_names(ts_file: &TSFile, class_node: Node) -> Vec<String> {
+  let Some(type_parameters_node) = class_node.child_by_field_name("type_parameters") else {
+    return Vec::new();
+  };
+  let mut cursor = type_parameters_node.walk();
+  type_parameters_node
+    .named_children(&mut cursor)
+    .filter_map(|type_parameter| {
+      let mut parameter_cursor = type_parameter.walk();
+      let name_node = type_parameter
+        .named_children(&mut parameter_cursor)
+        .find(|child| child.kind() == "type_identifier");
+      get_name(ts_file, name_node)
+    })
+    .collect()
+}
+
+fn find_own_id_field_type(ts_file: &TSFile, class_node: Node) -> Option<String> {
+  get_own_field_declaration_nodes(ts_file, class_node).into_iter().find_map(|field_node| {
+    let is_id = find_annotation_node_by_name(ts_file, field_node, "Id").is_some()
+      || find_annotation_node_by_name(ts_file, field_node, "EmbeddedId").is_some();
+    if !is_id {
+      return None;
+    }
+    get_name(ts_file, field_node.child_by_field_name("type"))
+  })
+}
+
+/// Finds the entity id type, walking up the superclass chain and substituting type arguments,
+/// so `User extends BaseEntity<UUID>` with `@Id private ID id;` in `BaseEntity<ID>` resolves to
+/// `UUID`.
+fn resolve_entity_id_type(cwd: &Path, entity_ts_file: &TSFile) -> Option<ResolvedType> {
+  let mut bindings: HashMap<String, ResolvedType> = HashMap::new();
+  let mut visited = HashSet::new();
+  let mut current_file: Option<TSFile> = None;
+  loop {
+    let ts_file = current_file.as_ref().unwrap_or(entity_ts_file);
+    let class_node = get_public_class_node(ts_file)?;
+    let class_name = get_name(ts_file, get_class_declaration_name_node(ts_file, class_node))?;
+    if !visited.insert(class_name) {
+      return None;
+    }
+    if let Some(id_type) = find_own_id_field_type(ts_file, class_node) {
+      return Some(
+        bindings
+          .get(&id_type)
+          .cloned()
+          .unwrap_or_else(|| ResolvedType::from_file(ts_file, &id_type)),
+      );
+    }
+    let superclass_node = class_node.child_by_field_name("superclass")?.named_child(0)?;
+    let superclass_name = match superclass_node.kind() {
+      "generic_type" => get_name(ts_file, superclass_node.named_child(0))?,
+      _ => get_name(ts_file, Some(superclass_node))?,
+    };
+    let type_arguments: Vec<ResolvedType> = get_type_argument_nodes(superclass_node)
+      .into_iter()
+      .filter_map(|argument| {
+        let argument_text = get_name(ts_file, Some(argument))?;
+        Some(
+          bindings
+            .get(&argument_text)
+            .cloned()
+            .unwrap_or_else(|| ResolvedType::from_file(ts_file, &argument_text)),
+        )
+      })
+      .collect();
+    let superclass_file =
+      find_file_by_class_name(cwd, &JavaSourceDirectoryType::Main, &superclass_name)?;
+    let superclass_node = get_public_class_node(&superclass_file)?;
+    bindings = get_type_parameter_names(&superclass_file, superclass_node)
+      .into_iter()
+      .zip(type_arguments)
+      .collect();
+    current_file = Some(superclass_file);
+  }
+}
+
+/// Finds the `Repository<Entity, Id>`-style supertype of the repository interface.
+fn find_repository_type_arguments(
+  repository_ts_file: &TSFile,
+  interface_node: Node,
+) -> Option<(ResolvedType, ResolvedType)> {
+  for extended_type in get_interface_extended_type_nodes(repository_ts_file, interface_node) {
+    if extended_type.kind() != "generic_type" {
+      continue;
+    }
+    let name = get_name(repository_ts_file, extended_type.named_child(0))?;
+    let arguments = get_type_argument_nodes(extended_type);
+    if !name.ends_with("Repository") || arguments.len() != 2 {
+      continue;
+    }
+    let entity_text = get_name(repository_ts_file, Some(arguments[0]))?;
+    let id_text = get_name(repository_ts_file, Some(arguments[1]))?;
+    return Some((
+      ResolvedType::from_file(repository_ts_file, &entity_text),
+      ResolvedType::from_file(repository_ts_file, &id_text),
+    ));
+  }
+  None
+}
+
+fn mismatch(
+  code: &str,
+  message: String,
+  expected: Option<&ResolvedType>,
+  actual: Option<&ResolvedType>,
+) -> RepositoryMismatchResponse {
+  RepositoryMismatchResponse {
+    code: code.to_string(),
+    message,
+    expected: expected.map(|t| t.display_name()),
+    actual: actual.map(|t| t.display_name()),
+  }
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_path: &Path,
+  repository_file_path: &Path,
+) -> Result<CheckEntityCompilesAgainstRepositoryResponse, String> {
+  // Step 1: Parse both files
+  let entity_ts_file = TSFile::from_file(entity_file_path, cwd, SupportedLanguage::Java)
+    .map_err(|e| format!("Unable to parse JPA Entity file: {}", e))?;
+  let repository_ts_file = TSFile::from_file(repository_file_path, cwd, SupportedLanguage::Java)
+    .map_err(|e| format!("Unable to parse repository file: {}", e))?;
+  // Step 2: Resolve the entity type and its id type
+  let class_node = get_public_class_node(&entity_ts_file)
+    .ok_or_else(|| "Unable to get public class node".to_string())?;
+  if find_annotation_node_by_name(&entity_ts_file, class_node, "Entity").is_none() {
+    return Err("Class is not annotated with @Entity".to_string());
+  }
+  let entity_name =
+    get_name(&entity_ts_file, get_class_declaration_name_node(&entity_ts_file, class_node))
+      .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+  let entity_type = ResolvedType::from_file(&entity_ts_file, &entity_name);
+  let entity_id_type = resolve_entity_id_type(cwd, &entity_ts_file);
+  // Step 3: Resolve the repository generics
+  let interface_node = get_public_interface_node(&repository_ts_file)
+    .ok_or_else(|| "Unable to get public interface node of the repository".to_string())?;
+  let repository_type =
+    get_name(&repository_ts_file, get_interface_name_node(&repository_ts_file, interface_node))
+      .ok_or_else(|| "Couldn't get the repository name from the tree".to_string())?;
+  let repository_arguments = find_repository_type_arguments(&repository_ts_file, interface_node);
+  // Step 4: Compare
+  let mut mismatches = Vec::new();
+  match &repository_arguments {
+    None => mismatches.push(mismatch(
+      "NOT_A_REPOSITORY",
+      format!(
+        "'{}' does not extend a Spring Data repository with entity and id generics",
+        repository_type
+      ),
+      None,
+      None,
+    )),
+    Some((repository_entity_type, repository_id_type)) => {
+      if !repository_entity_type.matches(&entity_type) {
+        mismatches.push(mismatch(
+          "ENTITY_TYPE_MISMATCH",
+          format!(
+            "Repository '{}' is typed with entity '{}' but the entity is '{}'",
+            repository_type, repository_entity_type.simple_name, entity_type.simple_name
+          ),
+          Some(&entity_type),
+          Some(repository_entity_type),
+        ));
+      }
+      match &entity_id_type {
+        None => mismatches.push(mismatch(
+          "NO_ID_FOUND",
+          format!("Unable to find the id field of entity '{}'", entity_type.simple_name),
+          None,
+          Some(repository_id_type),
+        )),
+        Some(entity_id_type) if !repository_id_type.matches(entity_id_type) => {
+          mismatches.push(mismatch(
+            "ID_TYPE_MISMATCH",
+            format!(
+              "Repository '{}' is typed with id '{}' but the entity id is '{}'",
+              repository_type, repository_id_type.simple_name, entity_id_type.simple_name
+            ),
+            Some(entity_id_type),
+            Some(repository_id_type),
+          ));
+        }
+        _ => {}
+      }
+    }
+  }
+  // Step 5: Build response
+  Ok(CheckEntityCompilesAgainstRepositoryResponse {
+    entity_type: entity_type.display_name(),
+    entity_id_type: entity_id_type.map(|t| t.display_name()),
+    repository_type,
+    repository_entity_type: repository_arguments.as_ref().map(|(e, _)| e.display_name()),
+    repository_id_type: repository_arguments.as_ref().map(|(_, id)| id.display_name()),
+    passed: mismatches.is_empty(),
+    mismatches,
+  })
+}
@@ -0,0 +1,11 @@
+use std::path::Path;
+
+use crate::commands::java::responses::clear_scan_cache_response::ClearScanCacheResponse;
+use crate::commands::java::services::generate_schema_service::clear_project_mappings_cache;
+
+/// Drops the cached project scan of `cwd`, or of every project when `all_projects` is set, so the
+/// next scan parses every file again.
+pub fn run(cwd: &Path, all_projects: bool) -> Result<ClearScanCacheResponse, String> {
+  let cleared_files_count = clear_project_mappings_cache((!all_projects).then_some(cwd));
+  Ok(ClearScanCacheResponse { all_projects, cleared_files_count })
+}
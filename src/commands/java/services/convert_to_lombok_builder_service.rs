@@ -0,0 +1,97 @@
+use std::path::Path;
+
+use crate::commands::java::responses::convert_to_lombok_builder_response::ConvertToLombokBuilderResponse;
+use crate::commands::java::treesitter::services::annotation_service::{
+  add_annotation, find_annotation_node_by_name,
+};
+use crate::commands::java::treesitter::services::builder_declaration_service::find_builder_members;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node, remove_member,
+};
+use crate::commands::java::treesitter::services::constructor_declaration_service::get_all_constructor_declaration_nodes;
+use crate::commands::java::treesitter::services::import_declaration_service::add_import;
+use crate::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+
+/// Replaces a hand-written builder with Lombok's `@Builder`. Members with custom logic are
+/// removed as well, since Lombok generates its own builder, and reported as warnings.
+pub fn run(
+  cwd: &Path,
+  b64_source_code: &str,
+  file_path: &Path,
+) -> Result<ConvertToLombokBuilderResponse, String> {
+  // Step 1: Parse file
+  let mut ts_file = TSFile::from_base64_source_code(b64_source_code, SupportedLanguage::Java);
+  // Step 2: Find the builder scaffolding
+  let (file_type, mut builder_members, has_other_constructors, annotation_added) = {
+    let class_node = get_public_class_node(&ts_file)
+      .ok_or_else(|| "Unable to get public class node".to_string())?;
+    let file_type = get_class_declaration_name_node(&ts_file, class_node)
+      .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+      .map(|name| name.to_string())
+      .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+    let builder_members = find_builder_members(&ts_file, class_node);
+    if builder_members.is_empty() {
+      return Err(format!("Class '{}' has no hand-written static Builder class", file_type));
+    }
+    let has_other_constructors = get_all_constructor_declaration_nodes(&ts_file, class_node)
+      .iter()
+      .any(|constructor| builder_members.iter().all(|m| m.start_byte != constructor.start_byte()))
+      && find_annotation_node_by_name(&ts_file, class_node, "AllArgsConstructor").is_none();
+    let annotation_added = find_annotation_node_by_name(&ts_file, class_node, "Builder").is_none();
+    (file_type, builder_members, has_other_constructors, annotation_added)
+  };
+  let mut warnings: Vec<String> = builder_members
+    .iter()
+    .flat_map(|member| &member.custom_logic)
+    .map(|name| format!("'{}' has custom logic that Lombok's @Builder can't preserve", name))
+    .collect();
+  warnings.push(format!(
+    "Lombok names the builder class '{}Builder', references to '{}.Builder' must be updated",
+    file_type, file_type
+  ));
+  if has_other_constructors {
+    warnings.push(format!(
+      "'{}' declares other constructors, so @Builder needs an all-args constructor, e.g. with \
+       @AllArgsConstructor",
+      file_type
+    ));
+  }
+  // Step 3: Remove the members from the last one, keeping the earlier positions valid
+  builder_members.sort_by_key(|member| std::cmp::Reverse(member.start_byte));
+  for member in &builder_members {
+    if !remove_member(&mut ts_file, member.start_byte) {
+      return Err(format!("Unable to remove '{}'", member.name));
+    }
+  }
+  let removed_members: Vec<String> =
+    builder_members.into_iter().rev().map(|member| member.name).collect();
+  // Step 4: Annotate the class and import @Builder
+  if annotation_added {
+    let class_start_byte = get_public_class_node(&ts_file)
+      .map(|class_node| class_node.start_byte())
+      .ok_or_else(|| "Unable to get public class node".to_string())?;
+    add_annotation(
+      &mut ts_file,
+      class_start_byte,
+      &AnnotationInsertionPosition::AboveScopeDeclaration,
+      "@Builder",
+    )
+    .ok_or_else(|| "Unable to add @Builder annotation".to_string())?;
+    add_import(&mut ts_file, &ImportInsertionPosition::BeforeFirstImport, "lombok", "Builder");
+  }
+  // Step 5: Save file with working directory validation
+  ts_file
+    .save_to_existing_file(file_path, cwd)
+    .map_err(|e| format!("Unable to save file: {}", e))?;
+  // Step 6: Build and return response
+  Ok(ConvertToLombokBuilderResponse {
+    file_path: file_path.display().to_string(),
+    file_type,
+    removed_members,
+    annotation_added,
+    warnings,
+  })
+}
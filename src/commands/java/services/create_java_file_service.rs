@@ -3,23 +3,40 @@ use std::path::Path;
 use crate::{
   commands::java::{
     responses::file_response::FileResponse,
-    treesitter::types::{
-      java_file_type::JavaFileType, java_source_directory_type::JavaSourceDirectoryType,
+    treesitter::{
+      services::{
+        annotation_service::add_annotation, class_declaration_service::get_public_class_node,
+        import_declaration_service::add_import,
+      },
+      types::{
+        annotation_types::AnnotationInsertionPosition, import_types::ImportInsertionPosition,
+        java_file_template::JavaFileTemplate, java_file_type::JavaFileType,
+        java_source_directory_type::JavaSourceDirectoryType,
+      },
     },
+    validators::java_version_validator::validate_java_feature_support,
   },
   common::{
     supported_language::SupportedLanguage,
     ts_file::TSFile,
-    utils::{case_util, path_security_util::PathSecurityValidator},
+    utils::{
+      case_util, file_header, hash_util::get_content_hash,
+      path_security_util::PathSecurityValidator,
+    },
   },
 };
 
+/// Source of a new file, preceded by the configured header (`--header-file`) if any.
 pub fn generate_file_template(
   file_type: &JavaFileType,
   package_name: &str,
   file_name: &str,
 ) -> String {
-  file_type.get_source_content(package_name, file_name)
+  let source_content = file_type.get_source_content(package_name, file_name);
+  match file_header::render_header(&correct_java_file_name(file_name)) {
+    Some(header) => format!("{}\n{}", header, source_content),
+    None => source_content,
+  }
 }
 
 pub fn create_ts_file(file_template: &str) -> TSFile {
@@ -57,6 +74,36 @@ pub fn build_save_path(
     .map_err(|e| format!("Path security validation failed: {}", e))
 }
 
+/// Seeds the empty class declaration with the template's annotations, members and imports.
+fn apply_template(ts_file: &mut TSFile, template: &JavaFileTemplate) -> Result<(), String> {
+  let mut imports = Vec::new();
+  if let Some((members, member_imports)) = template.get_members() {
+    let (body_start_byte, body_end_byte) = get_public_class_node(ts_file)
+      .and_then(|class_node| class_node.child_by_field_name("body"))
+      .map(|body_node| (body_node.start_byte(), body_node.end_byte()))
+      .ok_or_else(|| "Unable to get class body node".to_string())?;
+    ts_file.replace_text_by_range(body_start_byte, body_end_byte, &format!("{{\n{}\n}}", members));
+    imports.extend(member_imports);
+  }
+  for (package_name, annotation) in template.get_annotations() {
+    let class_start_byte = get_public_class_node(ts_file)
+      .map(|class_node| class_node.start_byte())
+      .ok_or_else(|| "No public class found in file".to_string())?;
+    add_annotation(
+      ts_file,
+      class_start_byte,
+      &AnnotationInsertionPosition::AboveScopeDeclaration,
+      &format!("@{}", annotation),
+    )
+    .ok_or_else(|| format!("Failed to add @{} annotation", annotation))?;
+    imports.push((package_name, annotation));
+  }
+  for (package_name, class_name) in imports {
+    add_import(ts_file, &ImportInsertionPosition::AfterLastImport, package_name, class_name);
+  }
+  Ok(())
+}
+
 fn save_ts_file(
   ts_file: &mut TSFile,
   save_path: &std::path::Path,
@@ -73,7 +120,14 @@ fn build_file_response(ts_file: &TSFile, package_name: &str) -> Result<FileRespo
     .map(|p| p.to_string_lossy().to_string())
     .ok_or("Failed to get file path")?;
   let file_package_name = package_name.to_string();
-  Ok(FileResponse { file_type: file_type_str, file_path, file_package_name })
+  let content_hash = Some(get_content_hash(&ts_file.source_code));
+  Ok(FileResponse {
+    file_type: file_type_str,
+    file_path,
+    file_package_name,
+    content_hash,
+    warnings: Vec::new(),
+  })
 }
 
 pub fn run(
@@ -82,11 +136,26 @@ pub fn run(
   file_name: &str,
   file_type: &JavaFileType,
   source_directory: &JavaSourceDirectoryType,
+  target_java_version: Option<u32>,
+  template: Option<&JavaFileTemplate>,
 ) -> Result<FileResponse, String> {
-  // Step 1: Generate file template
+  // Step 1: Check the target Java version supports the type, then generate file template
+  if let Some(feature) = file_type.get_language_feature() {
+    validate_java_feature_support(&feature, target_java_version)?;
+  }
+  if let Some(template) = template.filter(|template| template.get_file_type() != *file_type) {
+    return Err(format!(
+      "Template '{}' only applies to {} files",
+      template.get_name(),
+      format!("{:?}", template.get_file_type()).to_lowercase()
+    ));
+  }
   let file_template = generate_file_template(file_type, package_name, file_name);
-  // Step 2: Create TSFile
+  // Step 2: Create TSFile, seeded by the template if any
   let mut ts_file = create_ts_file(&file_template);
+  if let Some(template) = template {
+    apply_template(&mut ts_file, template)?;
+  }
   // Step 3: Correct file name
   let corrected_file_name = correct_java_file_name(file_name);
   // Step 4: Build save path with security validation
@@ -97,5 +166,11 @@ pub fn run(
   }
   save_ts_file(&mut ts_file, &save_path, cwd)?;
   // Step 6: Build response
-  build_file_response(&ts_file, package_name)
+  let mut file_response = build_file_response(&ts_file, package_name)?;
+  if template == Some(&JavaFileTemplate::JunitTest)
+    && *source_directory != JavaSourceDirectoryType::Test
+  {
+    file_response.warnings.push("JUnit tests belong in the test source directory".to_string());
+  }
+  Ok(file_response)
 }
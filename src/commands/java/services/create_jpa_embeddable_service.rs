@@ -0,0 +1,119 @@
+use std::path::Path;
+
+use crate::commands::java::responses::create_jpa_embeddable_response::{
+  CreateJPAEmbeddableResponse, EmbeddableFieldResponse,
+};
+use crate::commands::java::responses::file_response::FileResponse;
+use crate::commands::java::services::create_java_file_service::{
+  build_save_path, correct_java_file_name, create_ts_file, generate_file_template,
+};
+use crate::commands::java::services::create_jpa_entity_basic_field_service;
+use crate::commands::java::treesitter::services::annotation_service::add_annotation;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  add_serializable_interface, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, get_persistence_package,
+};
+use crate::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
+use crate::commands::java::treesitter::types::basic_field_config::BasicFieldConfig;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::java_file_type::JavaFileType;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::case_util::{self, CaseType};
+use crate::common::utils::hash_util::get_content_hash;
+
+fn validate_fields(fields: &[BasicFieldConfig]) -> Result<(), String> {
+  for (index, field) in fields.iter().enumerate() {
+    if fields[..index].iter().any(|other| other.field_name == field.field_name) {
+      return Err(format!("Field '{}' is given more than once", field.field_name));
+    }
+  }
+  Ok(())
+}
+
+fn add_embeddable_annotation(ts_file: &mut TSFile) -> Result<(), String> {
+  let persistence_package = get_persistence_package(ts_file);
+  add_import(ts_file, &ImportInsertionPosition::AfterLastImport, persistence_package, "Embeddable")
+    .ok_or_else(|| format!("Failed to add import for {}.Embeddable", persistence_package))?;
+  let class_byte_position = get_public_class_node(ts_file)
+    .map(|class_node| class_node.start_byte())
+    .ok_or_else(|| "No public class found in file".to_string())?;
+  add_annotation(
+    ts_file,
+    class_byte_position,
+    &AnnotationInsertionPosition::AboveScopeDeclaration,
+    "@Embeddable",
+  )
+  .map(|_| ())
+  .ok_or_else(|| "Failed to add @Embeddable annotation".to_string())
+}
+
+/// Creates an `@Embeddable` class with the given basic fields. A `composite_key` class, used as an
+/// `@EmbeddedId`, implements `Serializable` unless `serializable` says otherwise, since JPA
+/// requires primary key classes to be serializable.
+pub fn run(
+  cwd: &Path,
+  package_name: &str,
+  file_name: &str,
+  fields: &[BasicFieldConfig],
+  composite_key: bool,
+  serializable: Option<bool>,
+) -> Result<CreateJPAEmbeddableResponse, String> {
+  // Step 1: Validate the fields and the save path before creating anything
+  validate_fields(fields)?;
+  let class_name = case_util::to_pascal_case(file_name);
+  let save_path = build_save_path(
+    &JavaSourceDirectoryType::Main,
+    cwd,
+    package_name,
+    &correct_java_file_name(&class_name),
+  )?;
+  if save_path.exists() {
+    return Err(format!("File already exists: {}", save_path.display()));
+  }
+  // Step 2: Create the class annotated with @Embeddable
+  let mut ts_file =
+    create_ts_file(&generate_file_template(&JavaFileType::Class, package_name, &class_name));
+  add_embeddable_annotation(&mut ts_file)?;
+  let mut warnings = Vec::new();
+  let serializable_added = match serializable.unwrap_or(composite_key) {
+    true => add_serializable_interface(&mut ts_file)
+      .ok_or_else(|| "Failed to implement Serializable".to_string())?,
+    false if composite_key => {
+      warnings.push(format!(
+        "Composite key class '{}' doesn't implement Serializable, which JPA requires",
+        class_name
+      ));
+      false
+    }
+    false => false,
+  };
+  // Step 3: Add the basic fields with the same insertion as entity fields, which never emits @Id
+  for field in fields {
+    create_jpa_entity_basic_field_service::transform(&mut ts_file, field)?;
+    let column_name = case_util::auto_convert_case(&field.field_name, CaseType::Snake);
+    warnings.extend(field.reserved_word_check.get_warning("Column", &column_name));
+  }
+  // Step 4: Save file
+  ts_file.save_as(&save_path, cwd).map_err(|e| format!("Failed to save file: {}", e))?;
+  // Step 5: Build and return response
+  Ok(CreateJPAEmbeddableResponse {
+    file: FileResponse {
+      file_type: class_name,
+      file_package_name: package_name.to_string(),
+      file_path: save_path.display().to_string(),
+      content_hash: Some(get_content_hash(&ts_file.source_code)),
+      warnings,
+    },
+    serializable_added,
+    fields: fields
+      .iter()
+      .map(|field| EmbeddableFieldResponse {
+        field_name: field.field_name.clone(),
+        field_type: field.field_type.clone(),
+      })
+      .collect(),
+  })
+}
@@ -3,26 +3,31 @@ use crate::commands::java::treesitter::services::class_declaration_service::get_
 use crate::commands::java::treesitter::services::field_declaration_service::{
   AddFieldDeclarationParams, add_field_declaration,
 };
-use crate::commands::java::treesitter::services::import_declaration_service::add_import;
+use crate::commands::java::treesitter::services::import_declaration_service::add_imports;
+use crate::commands::java::treesitter::services::method_declaration_service::add_accessor_methods;
 use crate::commands::java::treesitter::services::package_declaration_service::{
   get_package_class_scope_node, get_package_declaration_node,
 };
 use crate::commands::java::treesitter::types::basic_field_config::BasicFieldConfig;
-use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::fetch_type::FetchType;
 use crate::commands::java::treesitter::types::java_basic_types::FieldInsertionPosition;
 use crate::commands::java::treesitter::types::java_field_temporal::JavaFieldTemporal;
 use crate::commands::java::treesitter::types::java_field_time_zone_storage::JavaFieldTimeZoneStorage;
-use crate::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+use crate::commands::java::validators::java_type_validator::validate_java_field_type;
 use crate::common::supported_language::SupportedLanguage;
 use crate::common::ts_file::TSFile;
 use crate::common::utils::case_util::{self, CaseType};
+use crate::common::utils::hash_util::get_content_hash;
+use crate::common::utils::parse_cache;
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
 struct ProcessedFieldConfig {
+  pub should_add_basic_annotation: bool,
   pub should_add_timezone_storage_annotation: bool,
   pub should_add_temporal_annotation: bool,
   pub should_add_lob_annotation: bool,
+  pub should_add_size_annotation: bool,
 }
 
 fn add_to_import_map(
@@ -35,13 +40,6 @@ fn add_to_import_map(
   }
 }
 
-fn add_imports(ts_file: &mut TSFile, import_map: &HashMap<String, String>) {
-  let import_position = ImportInsertionPosition::BeforeFirstImport;
-  for (class_name, package_name) in import_map {
-    add_import(ts_file, &import_position, package_name, class_name);
-  }
-}
-
 fn process_imports(
   import_map: &mut HashMap<String, String>,
   processed_field_config: &ProcessedFieldConfig,
@@ -51,6 +49,12 @@ fn process_imports(
     add_to_import_map(import_map, package_name, &field_config.field_type);
   };
   add_to_import_map(import_map, "jakarta.persistence", "Column");
+  if processed_field_config.should_add_basic_annotation {
+    add_to_import_map(import_map, "jakarta.persistence", "Basic");
+    if is_lazy_fetch(field_config) {
+      add_to_import_map(import_map, "jakarta.persistence", "FetchType");
+    }
+  }
   if processed_field_config.should_add_timezone_storage_annotation {
     add_to_import_map(import_map, "org.hibernate.annotations", "TimeZoneStorage");
     add_to_import_map(import_map, "org.hibernate.annotations", "TimeZoneStorageType");
@@ -62,6 +66,33 @@ fn process_imports(
   if processed_field_config.should_add_lob_annotation {
     add_to_import_map(import_map, "jakarta.persistence", "Lob");
   }
+  if processed_field_config.should_add_size_annotation {
+    add_to_import_map(import_map, "jakarta.validation.constraints", "Size");
+  }
+}
+
+fn is_lazy_fetch(field_config: &BasicFieldConfig) -> bool {
+  field_config.field_fetch_type == Some(FetchType::Lazy)
+}
+
+fn is_big_decimal(field_config: &BasicFieldConfig) -> bool {
+  field_config.field_type == "BigDecimal"
+    && field_config.field_type_package_name.as_deref() == Some("java.math")
+}
+
+fn is_string_type(field_config: &BasicFieldConfig) -> bool {
+  matches!(field_config.field_type.as_str(), "String" | "java.lang.String" | "char[]")
+}
+
+/// `@Column(length = ...)` value: a length other than the default 255, or 255 for string fields
+/// when `explicit_string_length` is set.
+fn get_column_length(field_config: &BasicFieldConfig) -> Option<u16> {
+  match field_config.field_length {
+    Some(255) | None if field_config.explicit_string_length && is_string_type(field_config) => {
+      Some(255)
+    }
+    length => length.filter(|&length| length != 255),
+  }
 }
 
 fn process_field_config(field_config: &BasicFieldConfig) -> ProcessedFieldConfig {
@@ -97,10 +128,16 @@ fn process_field_config(field_config: &BasicFieldConfig) -> ProcessedFieldConfig
   {
     should_add_lob_annotation = true;
   }
+  // @Basic only carries non-default attributes, a bare @Basic adds nothing
+  let should_add_basic_annotation = !field_config.field_optional || is_lazy_fetch(field_config);
+  // A `@Size` mirroring the column length keeps validation and persistence constraints in sync
+  let should_add_size_annotation = field_config.sync_length && field_config.field_length.is_some();
   ProcessedFieldConfig {
+    should_add_basic_annotation,
     should_add_timezone_storage_annotation,
     should_add_temporal_annotation,
     should_add_lob_annotation,
+    should_add_size_annotation,
   }
 }
 
@@ -119,7 +156,7 @@ fn add_field_and_annotations(
   let public_class_node_start_byte = public_class_node.start_byte();
   let params = AddFieldDeclarationParams {
     insertion_position: FieldInsertionPosition::EndOfClassBody,
-    visibility_modifier: JavaVisibilityModifier::Private,
+    visibility_modifier: field_config.access_config.field_visibility,
     field_modifiers: vec![],
     field_type: &field_config.field_type,
     field_name: &field_name_camel_case,
@@ -129,10 +166,19 @@ fn add_field_and_annotations(
     field_config.field_timezone_storage.clone().unwrap_or(JavaFieldTimeZoneStorage::Auto);
   let temporal_type = field_config.field_temporal.clone().unwrap_or(JavaFieldTemporal::Timestamp);
   add_field_declaration(ts_file, public_class_node_start_byte, params, |builder| {
+    if processed_field_config.should_add_basic_annotation {
+      builder.add_annotation("@Basic")?;
+      if is_lazy_fetch(field_config) {
+        builder.with_argument("@Basic", "fetch", "FetchType.LAZY")?;
+      }
+      if !field_config.field_optional {
+        builder.with_argument("@Basic", "optional", "false")?;
+      }
+    }
     builder.add_annotation("@Column")?.with_argument(
       "@Column",
       "name",
-      &format!("\"{}\"", &column_name_snake_case),
+      &format!("\"{}\"", field_config.reserved_word_check.resolve_name(&column_name_snake_case)),
     )?;
     if field_config.field_unique {
       builder.with_argument("@Column", "unique", "true")?;
@@ -144,14 +190,17 @@ fn add_field_and_annotations(
     } else {
       builder.with_argument("@Column", "nullable", "false")?;
     }
-    if field_config.field_type == "BigDecimal"
-      && field_config.field_type_package_name.as_deref() == Some("java.math")
-    {
-      if let Some(precision) = field_config.field_precision.filter(|&p| p != 19) {
+    // Add length if specified and different from default (255), or the default itself when asked
+    // to make it explicit on string fields
+    if let Some(length) = get_column_length(field_config) {
+      builder.with_argument("@Column", "length", &length.to_string())?;
+    }
+    // Money defaults only fill in the dimensions the field doesn't set itself
+    if is_big_decimal(field_config) {
+      if let Some(precision) = field_config.field_precision.or(field_config.money_precision) {
         builder.with_argument("@Column", "precision", &precision.to_string())?;
       }
-
-      if let Some(scale) = field_config.field_scale.filter(|&s| s != 2) {
+      if let Some(scale) = field_config.field_scale.or(field_config.money_scale) {
         builder.with_argument("@Column", "scale", &scale.to_string())?;
       }
     }
@@ -174,6 +223,11 @@ fn add_field_and_annotations(
     if processed_field_config.should_add_lob_annotation {
       builder.add_annotation("@Lob")?;
     }
+    if processed_field_config.should_add_size_annotation
+      && let Some(length) = field_config.field_length
+    {
+      builder.add_annotation("@Size")?.with_argument("@Size", "max", &length.to_string())?;
+    }
     builder.build()
   })
   .ok_or_else(|| "Unable to add new field to the JPA Entity".to_string())?
@@ -181,7 +235,25 @@ fn add_field_and_annotations(
   Ok(())
 }
 
-fn build_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
+fn add_accessors(ts_file: &mut TSFile, field_config: &BasicFieldConfig) -> Result<(), String> {
+  if !field_config.access_config.generate_accessors {
+    return Ok(());
+  }
+  let public_class_node_start_byte = get_public_class_node(ts_file)
+    .ok_or_else(|| "Unable to get public class node from Entity".to_string())?
+    .start_byte();
+  add_accessor_methods(
+    ts_file,
+    public_class_node_start_byte,
+    &field_config.field_type,
+    &case_util::auto_convert_case(&field_config.field_name, CaseType::Camel),
+    field_config.access_config.accessor_visibility,
+  )
+  .ok_or_else(|| "Unable to add accessors to the JPA Entity".to_string())?;
+  Ok(())
+}
+
+fn build_file_response(ts_file: &TSFile, warnings: Vec<String>) -> Result<FileResponse, String> {
   let file_type = ts_file.get_file_name_without_ext().unwrap_or_default();
   let file_path = ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default();
   let file_package_node = get_package_declaration_node(ts_file)
@@ -191,31 +263,53 @@ fn build_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
     .and_then(|node| ts_file.get_text_from_node(&node))
     .unwrap_or("")
     .to_string();
-  Ok(FileResponse { file_type, file_package_name, file_path })
+  let content_hash = Some(get_content_hash(&ts_file.source_code));
+  Ok(FileResponse { file_type, file_package_name, file_path, content_hash, warnings })
 }
 
-pub fn run(
-  entity_file_b64_src: &str,
-  entity_file_path: &Path,
+/// Adds the basic field, its accessors and imports to the parsed entity, without touching disk.
+pub fn transform(
+  entity_ts_file: &mut TSFile,
   field_config: &BasicFieldConfig,
-  cwd: &Path,
-) -> Result<FileResponse, String> {
-  // Step 1: Process field config
+) -> Result<(), String> {
+  // Step 1: Validate the field type and member access configuration
+  validate_java_field_type(&field_config.field_type)?;
+  field_config.access_config.validate()?;
+  // Step 2: Process field config
   let processed_field_config = process_field_config(field_config);
-  // Step 2: Parse entity file
-  let mut entity_ts_file =
-    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
   // Step 3: Process imports
   let mut import_map: HashMap<String, String> = HashMap::new();
   process_imports(&mut import_map, &processed_field_config, field_config);
   // Step 4: Add field and annotations
-  add_field_and_annotations(&mut entity_ts_file, field_config, &processed_field_config)?;
-  // Step 5: Add imports
-  add_imports(&mut entity_ts_file, &import_map);
-  // Step 6: Save file with working directory validation
+  add_field_and_annotations(entity_ts_file, field_config, &processed_field_config)?;
+  // Step 5: Add accessors when requested
+  add_accessors(entity_ts_file, field_config)?;
+  // Step 6: Add imports
+  add_imports(entity_ts_file, &import_map);
+  Ok(())
+}
+
+pub fn run(
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_config: &BasicFieldConfig,
+  cwd: &Path,
+) -> Result<FileResponse, String> {
+  // Step 1: Parse entity file
+  let mut entity_ts_file = parse_cache::parse_base64_source_code(
+    entity_file_b64_src,
+    entity_file_path,
+    SupportedLanguage::Java,
+  );
+  // Step 2: Add the field to the entity
+  transform(&mut entity_ts_file, field_config)?;
+  // Step 3: Save file with working directory validation
   entity_ts_file
     .save_to_existing_file(entity_file_path, cwd)
     .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
-  // Step 7: Build and return response
-  build_file_response(&entity_ts_file)
+  parse_cache::store(entity_file_path, &entity_ts_file);
+  // Step 4: Build and return response, warning about a reserved column name
+  let column_name = case_util::auto_convert_case(&field_config.field_name, CaseType::Snake);
+  let warnings = field_config.reserved_word_check.get_warning("Column", &column_name);
+  build_file_response(&entity_ts_file, warnings.into_iter().collect())
 }
@@ -1,20 +1,24 @@
 use crate::commands::java::responses::file_response::FileResponse;
-use crate::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  find_type_declaration_node, get_public_class_node, get_type_declaration_kind,
+};
 use crate::commands::java::treesitter::services::field_declaration_service::{
   AddFieldDeclarationParams, add_field_declaration,
 };
-use crate::commands::java::treesitter::services::import_declaration_service::add_import;
+use crate::commands::java::treesitter::services::import_declaration_service::add_imports;
+use crate::commands::java::treesitter::services::method_declaration_service::add_accessor_methods;
 use crate::commands::java::treesitter::services::package_declaration_service::{
   get_package_class_scope_node, get_package_declaration_node,
 };
 use crate::commands::java::treesitter::types::enum_field_config::EnumFieldConfig;
-use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
 use crate::commands::java::treesitter::types::java_basic_types::FieldInsertionPosition;
 use crate::commands::java::treesitter::types::java_enum_type::JavaEnumType;
-use crate::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
 use crate::common::supported_language::SupportedLanguage;
 use crate::common::ts_file::TSFile;
 use crate::common::utils::case_util::{self, CaseType};
+use crate::common::utils::hash_util::get_content_hash;
+use crate::common::utils::parse_cache;
 use std::collections::HashMap;
 use std::path::Path;
 
@@ -28,13 +32,6 @@ fn add_to_import_map(
   }
 }
 
-fn add_imports(ts_file: &mut TSFile, import_map: &HashMap<String, String>) {
-  let import_position = ImportInsertionPosition::BeforeFirstImport;
-  for (class_name, package_name) in import_map {
-    add_import(ts_file, &import_position, package_name, class_name);
-  }
-}
-
 fn add_field_and_annotations(
   ts_file: &mut TSFile,
   field_config: &EnumFieldConfig,
@@ -53,7 +50,7 @@ fn add_field_and_annotations(
   add_to_import_map(import_map, &field_config.enum_package_name, &field_config.enum_type);
   let params = AddFieldDeclarationParams {
     insertion_position: FieldInsertionPosition::EndOfClassBody,
-    visibility_modifier: JavaVisibilityModifier::Private,
+    visibility_modifier: field_config.access_config.field_visibility,
     field_modifiers: vec![],
     field_type: &field_config.enum_type,
     field_name: &field_config.field_name,
@@ -96,6 +93,51 @@ fn add_field_and_annotations(
   Ok(())
 }
 
+fn add_accessors(ts_file: &mut TSFile, field_config: &EnumFieldConfig) -> Result<(), String> {
+  if !field_config.access_config.generate_accessors {
+    return Ok(());
+  }
+  let public_class_node_start_byte = get_public_class_node(ts_file)
+    .ok_or_else(|| "Unable to get public class node from Entity".to_string())?
+    .start_byte();
+  add_accessor_methods(
+    ts_file,
+    public_class_node_start_byte,
+    &field_config.enum_type,
+    &field_config.field_name,
+    field_config.access_config.accessor_visibility,
+  )
+  .ok_or_else(|| "Unable to add accessors to the JPA Entity".to_string())?;
+  Ok(())
+}
+
+/// Checks that the enum is declared as an `enum` when its package lives in the project's main
+/// sources. Enums of other packages (dependencies, the JDK) are trusted as given. Nested enums are
+/// looked up in the file of their outermost type (`Order.Status` in `Order.java`).
+fn validate_enum_type(cwd: &Path, field_config: &EnumFieldConfig) -> Result<(), String> {
+  let enum_type = field_config.enum_type.trim();
+  let qualified_name = format!("{}.{}", field_config.enum_package_name, enum_type);
+  let package_dir =
+    JavaSourceDirectoryType::Main.get_full_path(cwd, &field_config.enum_package_name);
+  if field_config.enum_package_name.trim().is_empty() || !package_dir.is_dir() {
+    return Ok(());
+  }
+  let outer_type_name = enum_type.split('.').next().unwrap_or(enum_type);
+  let enum_file_path = package_dir.join(format!("{}.java", outer_type_name));
+  let enum_ts_file = TSFile::from_file(&enum_file_path, cwd, SupportedLanguage::Java)
+    .map_err(|_| format!("NOT_AN_ENUM: Enum '{}' not found in the project", qualified_name))?;
+  let simple_name = enum_type.rsplit('.').next().unwrap_or(enum_type);
+  match find_type_declaration_node(&enum_ts_file, Some(simple_name)) {
+    Some(type_node) if type_node.kind() == "enum_declaration" => Ok(()),
+    Some(type_node) => Err(format!(
+      "NOT_AN_ENUM: '{}' is declared as '{}', not as 'enum'",
+      qualified_name,
+      get_type_declaration_kind(type_node).unwrap_or_default()
+    )),
+    None => Err(format!("NOT_AN_ENUM: Enum '{}' not found in the project", qualified_name)),
+  }
+}
+
 fn build_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
   let file_type = ts_file.get_file_name_without_ext().unwrap_or_default();
   let file_path = ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default();
@@ -106,7 +148,26 @@ fn build_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
     .and_then(|node| ts_file.get_text_from_node(&node))
     .unwrap_or("")
     .to_string();
-  Ok(FileResponse { file_type, file_package_name, file_path })
+  let content_hash = Some(get_content_hash(&ts_file.source_code));
+  Ok(FileResponse { file_type, file_package_name, file_path, content_hash, warnings: Vec::new() })
+}
+
+/// Adds the enum field, its accessors and imports to the parsed entity, without touching disk.
+pub fn transform(
+  entity_ts_file: &mut TSFile,
+  field_config: &EnumFieldConfig,
+) -> Result<(), String> {
+  // Step 1: Validate member access configuration
+  field_config.access_config.validate()?;
+  // Step 2: Prepare import map for required imports
+  let mut import_map = HashMap::new();
+  // Step 3: Add field and annotations to the entity
+  add_field_and_annotations(entity_ts_file, field_config, &mut import_map)?;
+  // Step 4: Add accessors when requested
+  add_accessors(entity_ts_file, field_config)?;
+  // Step 5: Add all required imports to the file
+  add_imports(entity_ts_file, &import_map);
+  Ok(())
 }
 
 pub fn run(
@@ -115,19 +176,23 @@ pub fn run(
   entity_file_path: &Path,
   field_config: EnumFieldConfig,
 ) -> Result<FileResponse, String> {
-  // Step 1: Parse the entity file
-  let mut entity_ts_file =
-    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
-  // Step 2: Prepare import map for required imports
-  let mut import_map = HashMap::new();
-  // Step 3: Add field and annotations to the entity
-  add_field_and_annotations(&mut entity_ts_file, &field_config, &mut import_map)?;
-  // Step 4: Add all required imports to the file
-  add_imports(&mut entity_ts_file, &import_map);
-  // Step 5: Write the modified file back to disk
+  // Step 1: Check the enum type resolves to an enum of the project when requested
+  if field_config.validate_enum_type {
+    validate_enum_type(cwd, &field_config)?;
+  }
+  // Step 2: Parse the entity file
+  let mut entity_ts_file = parse_cache::parse_base64_source_code(
+    entity_file_b64_src,
+    entity_file_path,
+    SupportedLanguage::Java,
+  );
+  // Step 3: Add the field to the entity
+  transform(&mut entity_ts_file, &field_config)?;
+  // Step 4: Write the modified file back to disk
   entity_ts_file
     .save_as(entity_file_path, cwd)
     .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
-  // Step 6: Build and return response
+  parse_cache::store(entity_file_path, &entity_ts_file);
+  // Step 5: Build and return response
   build_file_response(&entity_ts_file)
 }
@@ -1,20 +1,30 @@
 use std::path::Path;
 
+use crate::commands::java::responses::create_jpa_entity_response::CreateJPAEntityResponse;
 use crate::commands::java::responses::file_response::FileResponse;
 use crate::commands::java::services::create_java_file_service::{
   build_save_path, correct_java_file_name, create_ts_file, generate_file_template,
 };
+use crate::commands::java::services::get_entity_by_table_name_service;
 use crate::commands::java::treesitter::services::annotation_service;
 use crate::commands::java::treesitter::services::class_declaration_service::{
-  get_class_declaration_name_node, get_public_class_node,
+  add_serializable_interface, get_class_declaration_name_node, get_public_class_node,
 };
+use crate::commands::java::treesitter::services::entity_mapping_service::get_simple_type_name;
 use crate::commands::java::treesitter::services::import_declaration_service::{self, add_import};
+use crate::commands::java::treesitter::services::package_declaration_service::{
+  get_package_declaration_node, get_package_scope_node,
+};
 use crate::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
 use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
 use crate::commands::java::treesitter::types::java_file_type::JavaFileType;
 use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::commands::java::treesitter::types::reserved_word_check::ReservedWordCheck;
+use crate::commands::java::validators::java_type_validator::validate_java_field_type;
 use crate::common::ts_file::TSFile;
 use crate::common::utils::case_util;
+use crate::common::utils::hash_util::get_content_hash;
+use crate::common::utils::path_util::{find_file_by_class_name, get_all_java_file_paths};
 
 fn add_jpa_imports(ts_file: &mut TSFile) -> Result<(), String> {
   let entity_import_result = import_declaration_service::add_import(
@@ -38,7 +48,11 @@ fn add_jpa_imports(ts_file: &mut TSFile) -> Result<(), String> {
   Ok(())
 }
 
-fn build_file_response(ts_file: &TSFile, package_name: &str) -> Result<FileResponse, String> {
+fn build_file_response(
+  ts_file: &TSFile,
+  package_name: &str,
+  warnings: Vec<String>,
+) -> Result<FileResponse, String> {
   let file_type_str =
     ts_file.get_file_name_without_ext().ok_or("Failed to get file type string")?;
   let file_path = ts_file
@@ -46,7 +60,14 @@ fn build_file_response(ts_file: &TSFile, package_name: &str) -> Result<FileRespo
     .map(|p| p.to_string_lossy().to_string())
     .ok_or("Failed to get file path")?;
   let file_package_name = package_name.to_string();
-  Ok(FileResponse { file_type: file_type_str, file_path, file_package_name })
+  let content_hash = Some(get_content_hash(&ts_file.source_code));
+  Ok(FileResponse {
+    file_type: file_type_str,
+    file_path,
+    file_package_name,
+    content_hash,
+    warnings,
+  })
 }
 
 fn create_java_file_and_get_response(
@@ -84,7 +105,15 @@ fn add_table_annotation(ts_file: &mut TSFile, class_byte_position: usize) -> Res
   if result.is_none() { Err("Failed to add @Table annotation".to_string()) } else { Ok(()) }
 }
 
-fn add_table_name_argument(ts_file: &mut TSFile, class_name: &str) -> Result<(), String> {
+/// Adds the snake_case table name, quoted when it is a reserved word and quoting was requested.
+///
+/// # Returns
+/// A warning when the table name is a reserved word
+fn add_table_name_argument(
+  ts_file: &mut TSFile,
+  class_name: &str,
+  reserved_word_check: &ReservedWordCheck,
+) -> Result<Option<String>, String> {
   let class_node =
     crate::commands::java::treesitter::services::class_declaration_service::get_public_class_node(
       ts_file,
@@ -94,7 +123,7 @@ fn add_table_name_argument(ts_file: &mut TSFile, class_name: &str) -> Result<(),
     .ok_or("@Table annotation not found".to_string())?;
   let table_byte_position = table_node.start_byte();
   let table_name = case_util::to_snake_case(class_name);
-  let table_name_value = format!("\"{}\"", table_name);
+  let table_name_value = format!("\"{}\"", reserved_word_check.resolve_name(&table_name));
   let result = annotation_service::add_annotation_argument(
     ts_file,
     table_byte_position,
@@ -104,35 +133,118 @@ fn add_table_name_argument(ts_file: &mut TSFile, class_name: &str) -> Result<(),
   if result.is_none() {
     Err("Failed to add argument to @Table annotation".to_string())
   } else {
-    Ok(())
+    Ok(reserved_word_check.get_warning("Table", &table_name))
+  }
+}
+
+/// Number of top-level type arguments of a type (`BaseEntity<Map<K, V>, Long>` -> 2).
+fn count_type_arguments(type_text: &str) -> usize {
+  let (Some(start), Some(end)) = (type_text.find('<'), type_text.rfind('>')) else {
+    return 0;
+  };
+  let mut depth = 0;
+  let mut count = 1;
+  for c in type_text[start + 1..end].chars() {
+    match c {
+      '<' => depth += 1,
+      '>' => depth -= 1,
+      ',' if depth == 0 => count += 1,
+      _ => {}
+    }
   }
+  count
 }
 
+/// Checks the superclass when it is declared in the project: it must be mapped for its fields to
+/// be inherited (only when `require_mapped`), and take as many type arguments as `superclass_type`
+/// provides. Superclasses outside the project are trusted.
+fn check_project_superclass(
+  cwd: &Path,
+  superclass_type: &str,
+  superclass_package_name: &str,
+  require_mapped: bool,
+) -> Vec<String> {
+  let superclass_name = get_simple_type_name(superclass_type);
+  let Some(superclass_ts_file) =
+    find_file_by_class_name(cwd, &JavaSourceDirectoryType::Main, &superclass_name)
+  else {
+    return Vec::new();
+  };
+  let declared_package = get_package_declaration_node(&superclass_ts_file)
+    .and_then(|node| get_package_scope_node(&superclass_ts_file, node))
+    .and_then(|node| superclass_ts_file.get_text_from_node(&node));
+  let Some(class_node) = get_public_class_node(&superclass_ts_file)
+    .filter(|_| declared_package == Some(superclass_package_name))
+  else {
+    return Vec::new();
+  };
+  let mut warnings = Vec::new();
+  let is_mapped = ["MappedSuperclass", "Entity"].iter().any(|annotation| {
+    annotation_service::find_annotation_node_by_name(&superclass_ts_file, class_node, annotation)
+      .is_some()
+  });
+  if require_mapped && !is_mapped {
+    warnings.push(format!(
+      "Superclass '{}' is not annotated with @MappedSuperclass or @Entity, its fields won't be persisted",
+      superclass_name
+    ));
+  }
+  let type_parameters_count = class_node.child_by_field_name("type_parameters").map_or(0, |node| {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).filter(|child| child.kind() == "type_parameter").count()
+  });
+  let type_arguments_count = count_type_arguments(superclass_type);
+  if type_parameters_count != type_arguments_count {
+    warnings.push(format!(
+      "Superclass '{}' declares {} type parameter(s) but {} type argument(s) were given",
+      superclass_name, type_parameters_count, type_arguments_count
+    ));
+  }
+  warnings
+}
+
+/// Makes the entity extend the superclass, which may be generic (`BaseEntity<Long>`), and imports
+/// it unless it lives in the entity package.
+///
+/// # Returns
+/// Warnings about a superclass of the project that can't be inherited from as given
 fn add_superclass_heritage(
   ts_file: &mut TSFile,
+  cwd: &Path,
+  package_name: &str,
   superclass_type_opt: Option<&str>,
   superclass_package_name_opt: Option<&str>,
-) -> Result<(), String> {
-  if superclass_type_opt.is_some() != superclass_package_name_opt.is_some() {
-    return Err("Both superclass type and it's package name are necessary".to_string());
+  require_mapped: bool,
+) -> Result<Vec<String>, String> {
+  let (superclass_type, superclass_package_name) =
+    match (superclass_type_opt, superclass_package_name_opt) {
+      (Some(superclass_type), Some(superclass_package_name)) => {
+        (superclass_type.trim(), superclass_package_name.trim())
+      }
+      (None, None) => return Ok(Vec::new()),
+      _ => return Err("Both superclass type and it's package name are necessary".to_string()),
+    };
+  validate_java_field_type(superclass_type)?;
+  if superclass_type.ends_with(']') {
+    return Err(format!("'{}' is an array type and can't be a superclass", superclass_type));
   }
-  if superclass_type_opt.is_some() && superclass_package_name_opt.is_some() {
-    let superclass_type = superclass_type_opt.ok_or("Superclass type not provided".to_string())?;
-    let superclass_package_name =
-      superclass_package_name_opt.ok_or("Superclass package name not provided".to_string())?;
-    let class_declaration_node = get_public_class_node(ts_file)
-      .ok_or("Unable to get public class declaration from JPA Entity".to_string())?;
-    let class_name_node = get_class_declaration_name_node(ts_file, class_declaration_node)
-      .ok_or("Unable to get public class name node from JPA Entity".to_string())?;
-    ts_file.insert_text(class_name_node.end_byte(), &format!(" extends {}", superclass_type));
+  let warnings =
+    check_project_superclass(cwd, superclass_type, superclass_package_name, require_mapped);
+  let class_declaration_node = get_public_class_node(ts_file)
+    .ok_or("Unable to get public class declaration from JPA Entity".to_string())?;
+  let class_name_node = get_class_declaration_name_node(ts_file, class_declaration_node)
+    .ok_or("Unable to get public class name node from JPA Entity".to_string())?;
+  ts_file.insert_text(class_name_node.end_byte(), &format!(" extends {}", superclass_type));
+  let is_qualified = superclass_type.split('<').next().is_some_and(|raw| raw.contains('.'));
+  if !is_qualified && superclass_package_name != package_name {
     add_import(
       ts_file,
       &ImportInsertionPosition::AfterLastImport,
       superclass_package_name,
-      superclass_type,
+      &get_simple_type_name(superclass_type),
     );
   }
-  Ok(())
+  Ok(warnings)
 }
 
 fn save_ts_file(
@@ -147,33 +259,107 @@ fn save_ts_file(
   ts_file.save_as(&save_path, cwd).map_err(|e| format!("Failed to save file: {}", e))
 }
 
+/// Files of the project declaring a class with the same simple name, in any package.
+fn find_duplicate_class_paths(cwd: &Path, class_name: &str) -> Vec<String> {
+  let mut paths: Vec<String> = get_all_java_file_paths(cwd, &JavaSourceDirectoryType::Main)
+    .into_iter()
+    .filter(|path| path.file_stem().is_some_and(|stem| stem.to_string_lossy() == class_name))
+    .map(|path| path.display().to_string())
+    .collect();
+  paths.sort();
+  paths
+}
+
+/// Fails when an entity of the project is already mapped to the table the new entity would get,
+/// including entities sharing it through single-table inheritance.
+fn check_table_name_collision(cwd: &Path, class_name: &str) -> Result<(), String> {
+  let table_name = case_util::to_snake_case(class_name);
+  let existing = get_entity_by_table_name_service::run(cwd, &table_name)?;
+  match existing.entities.first() {
+    Some(entity) => Err(format!(
+      "Table '{}' is already mapped by entity '{}'{}",
+      table_name,
+      entity.entity_type,
+      entity.entity_path.as_ref().map(|path| format!(" in {}", path)).unwrap_or_default()
+    )),
+    None => Ok(()),
+  }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn run(
   cwd: &Path,
   package_name: &str,
   file_name: &str,
   superclass_type: Option<&str>,
   superclass_package_name: Option<&str>,
-) -> Result<FileResponse, String> {
+  emit_annotations: bool,
+  reserved_word_check: &ReservedWordCheck,
+  fail_on_duplicate_name: bool,
+  serializable: bool,
+) -> Result<CreateJPAEntityResponse, String> {
   // Normalize the class name to PascalCase
   let normalized_class_name = case_util::to_pascal_case(file_name);
-  // Step 1: Create the Java file
+  // Step 1: Check the class name and table name aren't taken elsewhere in the project
+  let duplicate_class_paths = find_duplicate_class_paths(cwd, &normalized_class_name);
+  if fail_on_duplicate_name && !duplicate_class_paths.is_empty() {
+    return Err(format!(
+      "A class named '{}' already exists: {}",
+      normalized_class_name,
+      duplicate_class_paths.join(", ")
+    ));
+  }
+  if emit_annotations {
+    check_table_name_collision(cwd, &normalized_class_name)?;
+  }
+  // Step 2: Create the Java file
   let mut ts_file = create_java_file_and_get_response(package_name, &normalized_class_name)?;
-  // Step 2: Add required imports for JPA annotations
-  add_jpa_imports(&mut ts_file)?;
-  // Step 3: Get the public class node byte position after imports are added
-  let class_byte_position = get_class_byte_position(&ts_file)?;
-  // Step 4: Add @Entity annotation above the class declaration
-  add_entity_annotation(&mut ts_file, class_byte_position)?;
-  // Step 5: Get the updated class node position after annotation insertion
-  let updated_class_position = get_class_byte_position(&ts_file)?;
-  // Step 6: Add @Table annotation above the class declaration
-  add_table_annotation(&mut ts_file, updated_class_position)?;
-  // Step 7: Add table name argument with snake_case conversion
-  add_table_name_argument(&mut ts_file, &normalized_class_name)?;
-  // Step 8: Add superclass heritage
-  add_superclass_heritage(&mut ts_file, superclass_type, superclass_package_name)?;
-  // Step 9: Save the updated TSFile to disk
+  let mut warnings: Vec<String> = duplicate_class_paths
+    .iter()
+    .map(|path| format!("A class named '{}' already exists in {}", normalized_class_name, path))
+    .collect();
+  // Without annotations the class is left as a plain POJO
+  if emit_annotations {
+    // Step 3: Add required imports for JPA annotations
+    add_jpa_imports(&mut ts_file)?;
+    // Step 4: Get the public class node byte position after imports are added
+    let class_byte_position = get_class_byte_position(&ts_file)?;
+    // Step 5: Add @Entity annotation above the class declaration
+    add_entity_annotation(&mut ts_file, class_byte_position)?;
+    // Step 6: Get the updated class node position after annotation insertion
+    let updated_class_position = get_class_byte_position(&ts_file)?;
+    // Step 7: Add @Table annotation above the class declaration
+    add_table_annotation(&mut ts_file, updated_class_position)?;
+    // Step 8: Add table name argument with snake_case conversion
+    warnings.extend(add_table_name_argument(
+      &mut ts_file,
+      &normalized_class_name,
+      reserved_word_check,
+    )?);
+  }
+  // Step 9: Add superclass heritage
+  warnings.extend(add_superclass_heritage(
+    &mut ts_file,
+    cwd,
+    package_name,
+    superclass_type,
+    superclass_package_name,
+    emit_annotations,
+  )?);
+  // Step 10: Implement Serializable when requested
+  let serializable_added = match serializable {
+    true => add_serializable_interface(&mut ts_file)
+      .ok_or_else(|| "Failed to implement Serializable".to_string())?,
+    false => false,
+  };
+  // Step 11: Save the updated TSFile to disk
   save_ts_file(&mut ts_file, cwd, file_name, package_name)?;
-  // Step 10: Build and return the final file response
-  build_file_response(&ts_file, package_name)
+  // Step 12: Build and return the final file response
+  let file = build_file_response(&ts_file, package_name, warnings)?;
+  Ok(CreateJPAEntityResponse {
+    file,
+    annotations_emitted: emit_annotations,
+    duplicate_class_paths,
+    serializable_added,
+  })
 }
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::commands::java::responses::file_response::FileResponse;
+use crate::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+use crate::commands::java::treesitter::services::entity_mapping_service::get_class_mapping;
+use crate::commands::java::treesitter::services::field_declaration_service::{
+  AddFieldDeclarationParams, add_field_declaration,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_imports, get_persistence_package,
+};
+use crate::commands::java::treesitter::services::method_declaration_service::add_accessor_methods;
+use crate::commands::java::treesitter::services::package_declaration_service::{
+  get_package_class_scope_node, get_package_declaration_node,
+};
+use crate::commands::java::treesitter::types::java_basic_types::FieldInsertionPosition;
+use crate::commands::java::treesitter::types::version_field_config::VersionFieldConfig;
+use crate::commands::java::validators::sql_identifier_validator::validate_sql_identifier;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::case_util::{self, CaseType};
+use crate::common::utils::hash_util::get_content_hash;
+use crate::common::utils::parse_cache;
+use crate::common::utils::string_literal_util::to_java_string_literal;
+
+/// Types JPA providers can increment for optimistic locking, with the package to import.
+const VERSION_TYPES: [(&str, Option<&str>); 9] = [
+  ("int", None),
+  ("Integer", None),
+  ("short", None),
+  ("Short", None),
+  ("long", None),
+  ("Long", None),
+  ("Timestamp", Some("java.sql")),
+  ("Instant", Some("java.time")),
+  ("LocalDateTime", Some("java.time")),
+];
+
+/// Rejects a type that can't hold a version, and a second `@Version` in the entity.
+fn validate_version_field(
+  ts_file: &TSFile,
+  field_config: &VersionFieldConfig,
+) -> Result<(), String> {
+  if !VERSION_TYPES.iter().any(|(type_name, _)| *type_name == field_config.field_type) {
+    let supported: Vec<&str> = VERSION_TYPES.iter().map(|(type_name, _)| *type_name).collect();
+    return Err(format!(
+      "Type '{}' can't be used for @Version, use one of: {}",
+      field_config.field_type,
+      supported.join(", ")
+    ));
+  }
+  let class_node = get_public_class_node(ts_file)
+    .ok_or_else(|| "Unable to get public class node from Entity".to_string())?;
+  let existing_version = get_class_mapping(ts_file, class_node)
+    .and_then(|mapping| mapping.columns.into_iter().find(|column| column.is_version));
+  if let Some(column) = existing_version {
+    return Err(format!("Entity already has a @Version field: '{}'", column.field_name));
+  }
+  Ok(())
+}
+
+fn add_field_and_annotations(
+  ts_file: &mut TSFile,
+  field_config: &VersionFieldConfig,
+  column_name: &str,
+) -> Result<(), String> {
+  let public_class_node_start_byte = get_public_class_node(ts_file)
+    .ok_or_else(|| "Unable to get public class node from Entity".to_string())?
+    .start_byte();
+  let params = AddFieldDeclarationParams {
+    insertion_position: FieldInsertionPosition::EndOfClassBody,
+    visibility_modifier: field_config.access_config.field_visibility,
+    field_modifiers: vec![],
+    field_type: &field_config.field_type,
+    field_name: &field_config.field_name,
+    field_initialization: None,
+  };
+  add_field_declaration(ts_file, public_class_node_start_byte, params, |builder| {
+    builder.add_annotation("@Version")?;
+    builder.add_annotation("@Column")?.with_argument(
+      "@Column",
+      "name",
+      &to_java_string_literal(column_name),
+    )?;
+    builder.with_argument(
+      "@Column",
+      "nullable",
+      if field_config.column_nullable { "true" } else { "false" },
+    )?;
+    builder.build()
+  })
+  .ok_or_else(|| "Unable to add new field to the JPA Entity".to_string())?
+  .map_err(|e| format!("Unable to add annotations: {}", e))?;
+  Ok(())
+}
+
+fn add_accessors(ts_file: &mut TSFile, field_config: &VersionFieldConfig) -> Result<(), String> {
+  if !field_config.access_config.generate_accessors {
+    return Ok(());
+  }
+  let public_class_node_start_byte = get_public_class_node(ts_file)
+    .ok_or_else(|| "Unable to get public class node from Entity".to_string())?
+    .start_byte();
+  add_accessor_methods(
+    ts_file,
+    public_class_node_start_byte,
+    &field_config.field_type,
+    &field_config.field_name,
+    field_config.access_config.accessor_visibility,
+  )
+  .ok_or_else(|| "Unable to add accessors to the JPA Entity".to_string())?;
+  Ok(())
+}
+
+fn build_file_response(ts_file: &TSFile, warnings: Vec<String>) -> Result<FileResponse, String> {
+  let file_type = ts_file.get_file_name_without_ext().unwrap_or_default();
+  let file_path = ts_file.file_path().map(|p| p.to_string_lossy().to_string()).unwrap_or_default();
+  let file_package_node = get_package_declaration_node(ts_file)
+    .ok_or_else(|| "Unable to get JPA Entity's package node".to_string())?;
+  let file_package_name = get_package_class_scope_node(ts_file, file_package_node)
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .unwrap_or("")
+    .to_string();
+  let content_hash = Some(get_content_hash(&ts_file.source_code));
+  Ok(FileResponse { file_type, file_package_name, file_path, content_hash, warnings })
+}
+
+/// Adds the `@Version` field used for optimistic locking, mapped through `@Column` so it can
+/// target an existing column.
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_config: VersionFieldConfig,
+) -> Result<FileResponse, String> {
+  // Step 1: Validate the configuration and parse the entity file
+  field_config.access_config.validate()?;
+  let column_name = match &field_config.column_name {
+    Some(column_name) => validate_sql_identifier(column_name)?,
+    None => case_util::auto_convert_case(&field_config.field_name, CaseType::Snake),
+  };
+  let mut entity_ts_file = parse_cache::parse_base64_source_code(
+    entity_file_b64_src,
+    entity_file_path,
+    SupportedLanguage::Java,
+  );
+  validate_version_field(&entity_ts_file, &field_config)?;
+  let mut warnings = Vec::new();
+  if field_config.column_nullable {
+    warnings.push(format!(
+      "Version column '{}' is nullable, rows with a null version aren't protected by optimistic \
+       locking",
+      column_name
+    ));
+  }
+  // Step 2: Add the field, its accessors and imports
+  add_field_and_annotations(&mut entity_ts_file, &field_config, &column_name)?;
+  add_accessors(&mut entity_ts_file, &field_config)?;
+  let persistence_package = get_persistence_package(&entity_ts_file);
+  let mut import_map = HashMap::new();
+  import_map.insert("Version".to_string(), persistence_package.to_string());
+  import_map.insert("Column".to_string(), persistence_package.to_string());
+  if let Some((type_name, Some(package_name))) =
+    VERSION_TYPES.iter().find(|(type_name, _)| *type_name == field_config.field_type)
+  {
+    import_map.insert(type_name.to_string(), package_name.to_string());
+  }
+  add_imports(&mut entity_ts_file, &import_map);
+  // Step 3: Write the modified file back to disk
+  entity_ts_file
+    .save_as(entity_file_path, cwd)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  parse_cache::store(entity_file_path, &entity_ts_file);
+  // Step 4: Build and return response
+  build_file_response(&entity_ts_file, warnings)
+}
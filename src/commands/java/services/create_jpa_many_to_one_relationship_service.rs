@@ -1,27 +1,35 @@
 use crate::commands::java::responses::file_response::FileResponse;
+use crate::commands::java::treesitter::services::annotation_service::find_annotation_node_by_name;
 use crate::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+use crate::commands::java::treesitter::services::entity_mapping_service::is_column_mapped;
+use crate::commands::java::treesitter::services::enum_declaration_service::get_public_enum_node;
 use crate::commands::java::treesitter::services::field_declaration_service::{
   AddFieldDeclarationParams, add_field_declaration,
 };
-use crate::commands::java::treesitter::services::import_declaration_service::add_import;
+use crate::commands::java::treesitter::services::import_declaration_service::add_imports;
 use crate::commands::java::treesitter::services::package_declaration_service::{
-  get_package_declaration_node, get_package_scope_node,
+  get_package_declaration_node, get_package_name, get_package_scope_node,
 };
 use crate::commands::java::treesitter::types::annotation_config::AnnotationConfig;
 use crate::commands::java::treesitter::types::cascade_type::CascadeType;
+use crate::commands::java::treesitter::types::collection_type::CollectionType;
 use crate::commands::java::treesitter::types::entity_side::EntitySide;
 use crate::commands::java::treesitter::types::fetch_type::FetchType;
-use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
-use crate::commands::java::treesitter::types::java_basic_types::FieldInsertionPosition;
+use crate::commands::java::treesitter::types::java_basic_types::{
+  FieldInsertionPosition, JavaBasicType,
+};
 use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
 use crate::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
 use crate::commands::java::treesitter::types::many_to_one_field_config::ManyToOneFieldConfig;
+use crate::commands::java::treesitter::types::map_key_config::{MapKeyConfig, MapKeyKind};
 use crate::commands::java::treesitter::types::mapping_type::MappingType;
 use crate::commands::java::treesitter::types::other_type::OtherType;
+use crate::commands::java::treesitter::types::relationship_kind::RelationshipKind;
 use crate::common::supported_language::SupportedLanguage;
 use crate::common::ts_file::TSFile;
 use crate::common::utils::case_util::{self, CaseType};
-use crate::common::utils::path_util::parse_all_files;
+use crate::common::utils::hash_util::get_content_hash;
+use crate::common::utils::path_util::{find_file_by_class_name, parse_all_files};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
@@ -35,13 +43,6 @@ fn add_to_import_map(
   }
 }
 
-fn add_imports(ts_file: &mut TSFile, import_map: &HashMap<String, String>) {
-  let import_position = ImportInsertionPosition::BeforeFirstImport;
-  for (class_name, package_name) in import_map {
-    add_import(ts_file, &import_position, package_name, class_name);
-  }
-}
-
 fn find_inverse_entity(cwd: &Path, class_name: &str) -> Result<PathBuf, String> {
   let ts_files = parse_all_files(cwd, &JavaSourceDirectoryType::Main);
   for ts_file in ts_files {
@@ -99,10 +100,11 @@ fn build_annotation_config(
   field_config: &ManyToOneFieldConfig,
   side: &EntitySide,
   mapped_by_field_name: &Option<String>,
+  map_key: Option<&MapKeyConfig>,
 ) -> AnnotationConfig {
   let (cascades, other_options) = match side {
     EntitySide::Owning => {
-      (field_config.owning_side_cascades.clone(), field_config.owning_side_other.clone())
+      (field_config.owning_side_cascades.clone(), field_config.get_owning_side_other())
     }
     EntitySide::Inverse => {
       (field_config.inverse_side_cascades.clone(), field_config.inverse_side_other.clone())
@@ -119,6 +121,11 @@ fn build_annotation_config(
     field_config.fetch_type.clone(),
     field_config.collection_type.clone(),
   )
+  .with_foreign_key(
+    field_config.get_foreign_key().ok().flatten().filter(|_| is_owning_side || is_unidirectional),
+  )
+  .with_join_column_access(field_config.get_join_column_access())
+  .with_map_key(map_key.filter(|_| !is_owning_side).cloned())
 }
 
 fn build_import_map(
@@ -141,6 +148,16 @@ fn build_import_map(
     );
   }
 
+  // Add the map key annotations and key type imports if needed
+  if let Some(ref map_key) = annotation_config.map_key {
+    for class_name in map_key.get_imported_classes() {
+      add_to_import_map(&mut import_map, "jakarta.persistence", class_name);
+    }
+    if let Some(ref key_type_package) = map_key.key_type_package {
+      add_to_import_map(&mut import_map, key_type_package, &map_key.key_type);
+    }
+  }
+
   // Add FetchType import if needed
   if let Some(fetch_type) = annotation_config.get_fetch_type()
     && *fetch_type != FetchType::None
@@ -151,6 +168,11 @@ fn build_import_map(
   // Add JoinColumn import if needed
   if annotation_config.needs_join_column {
     add_to_import_map(&mut import_map, "jakarta.persistence", "JoinColumn");
+    if let Some(ref foreign_key) = annotation_config.foreign_key {
+      for class_name in foreign_key.get_imported_classes() {
+        add_to_import_map(&mut import_map, "jakarta.persistence", class_name);
+      }
+    }
   }
 
   // Add CascadeType import if needed
@@ -185,6 +207,8 @@ fn add_relationship_field_and_annotations(
   let public_class_node_start_byte = public_class_node.start_byte();
   let field_type = if annotation_config.is_owning_side {
     target_entity_type.to_string()
+  } else if let Some(ref map_key) = annotation_config.map_key {
+    format!("Map<{}, {}>", map_key.key_type, target_entity_type)
   } else {
     format!(
       "{}<{}>",
@@ -232,6 +256,19 @@ fn add_relationship_field_and_annotations(
       if annotation_config.other_options.contains(&OtherType::OrphanRemoval) {
         builder.with_argument("@OneToMany", "orphanRemoval", "true")?;
       }
+      if let Some(ref map_key) = annotation_config.map_key {
+        if map_key.kind == MapKeyKind::Enum {
+          builder.add_annotation("@MapKeyEnumerated")?;
+          builder.with_value("@MapKeyEnumerated", "EnumType.STRING")?;
+        }
+        let column_annotation = map_key.get_column_annotation();
+        builder.add_annotation(column_annotation)?;
+        builder.with_argument(
+          column_annotation,
+          "name",
+          &format!("\"{}\"", map_key.column_name),
+        )?;
+      }
     }
     if annotation_config.needs_join_column {
       builder.add_annotation("@JoinColumn")?;
@@ -247,6 +284,12 @@ fn add_relationship_field_and_annotations(
       if annotation_config.other_options.contains(&OtherType::Unique) {
         builder.with_argument("@JoinColumn", "unique", "true")?;
       }
+      if let Some(ref foreign_key) = annotation_config.foreign_key {
+        builder.with_argument("@JoinColumn", "foreignKey", &foreign_key.get_annotation_value())?;
+      }
+      for (key, value) in annotation_config.join_column_access.get_arguments() {
+        builder.with_argument("@JoinColumn", key, &value)?;
+      }
     }
     builder.build()
   })
@@ -255,6 +298,24 @@ fn add_relationship_field_and_annotations(
   Ok(())
 }
 
+/// Warns when the join column is left out of inserts or updates while no other field maps it.
+fn get_unwritable_join_column_warning(
+  ts_file: &TSFile,
+  annotation_config: &AnnotationConfig,
+  field_name: &str,
+) -> Option<String> {
+  let join_column_access = &annotation_config.join_column_access;
+  if !annotation_config.needs_join_column || !join_column_access.restricts_writes() {
+    return None;
+  }
+  let column_name = format!("{}_id", case_util::auto_convert_case(field_name, CaseType::Snake));
+  let class_node = get_public_class_node(ts_file)?;
+  if is_column_mapped(ts_file, class_node, &column_name) {
+    return None;
+  }
+  Some(join_column_access.get_unwritable_warning(&column_name))
+}
+
 fn save_entity_file(
   ts_file: &mut TSFile,
   save_path: Option<&Path>,
@@ -284,13 +345,85 @@ fn build_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
     .and_then(|node| ts_file.get_text_from_node(&node))
     .unwrap_or("")
     .to_string();
-  Ok(FileResponse { file_type, file_package_name, file_path })
+  let content_hash = Some(get_content_hash(&ts_file.source_code));
+  Ok(FileResponse { file_type, file_package_name, file_path, content_hash, warnings: Vec::new() })
 }
 
 fn is_bidirectional_mapping(field_config: &ManyToOneFieldConfig) -> bool {
   field_config.mapping_type != Some(MappingType::UnidirectionalJoinColumn)
 }
 
+fn validate_field_config(field_config: &ManyToOneFieldConfig) -> Result<(), String> {
+  RelationshipKind::ManyToOne.validate_options(
+    &EntitySide::Owning,
+    &field_config.owning_side_cascades,
+    &field_config.get_owning_side_other(),
+  )?;
+  if is_bidirectional_mapping(field_config) {
+    RelationshipKind::OneToMany.validate_options(
+      &EntitySide::Inverse,
+      &field_config.inverse_side_cascades,
+      &field_config.inverse_side_other,
+    )?;
+  }
+  field_config.get_foreign_key()?;
+  Ok(())
+}
+
+/// Classifies the key type of a `map` collection as a basic type, or an entity or enum declared in
+/// the project, and resolves its key column.
+///
+/// # Returns
+/// `None` when the collection isn't a map
+fn resolve_map_key(
+  cwd: &Path,
+  field_config: &ManyToOneFieldConfig,
+  field_name: &str,
+) -> Result<Option<MapKeyConfig>, String> {
+  if field_config.collection_type != CollectionType::Map {
+    if field_config.map_key_type.is_some() || field_config.map_key_column.is_some() {
+      return Err("Map key options require the map collection type".to_string());
+    }
+    return Ok(None);
+  }
+  let key_type = field_config
+    .map_key_type
+    .as_deref()
+    .ok_or_else(|| "A map key type is required for map collections".to_string())?;
+  let basic_type =
+    JavaBasicType::AllTypes.get_all_types().into_iter().find(|basic| basic.name == key_type);
+  let (kind, key_type_package) = match basic_type {
+    Some(basic) => {
+      (MapKeyKind::Basic, basic.package_path.filter(|package_name| package_name != "java.lang"))
+    }
+    None => {
+      let not_found = || {
+        format!(
+          "Map key type '{}' is neither a basic type nor an entity or enum of the project",
+          key_type
+        )
+      };
+      let key_ts_file = find_file_by_class_name(cwd, &JavaSourceDirectoryType::Main, key_type)
+        .ok_or_else(not_found)?;
+      let is_entity = get_public_class_node(&key_ts_file).is_some_and(|class_node| {
+        find_annotation_node_by_name(&key_ts_file, class_node, "Entity").is_some()
+      });
+      let is_enum = get_public_enum_node(&key_ts_file).is_some();
+      let kind = match (is_entity, is_enum) {
+        (true, _) => MapKeyKind::Entity,
+        (false, true) => MapKeyKind::Enum,
+        (false, false) => return Err(not_found()),
+      };
+      (kind, get_package_name(&key_ts_file))
+    }
+  };
+  let column_name = field_config.map_key_column.clone().unwrap_or_else(|| match kind {
+    MapKeyKind::Entity => format!("{}_id", case_util::auto_convert_case(key_type, CaseType::Snake)),
+    _ => format!("{}_key", case_util::auto_convert_case(field_name, CaseType::Snake)),
+  });
+  Ok(Some(MapKeyConfig { key_type: key_type.to_string(), key_type_package, kind, column_name }))
+}
+
 struct ProcessEntitySideParams<'a> {
   entity_file_b64_src: Option<&'a str>,
   entity_file_path: Option<&'a Path>,
@@ -302,6 +435,7 @@ struct ProcessEntitySideParams<'a> {
   field_config: &'a ManyToOneFieldConfig,
   side: EntitySide,
   mapped_by_field_name: Option<&'a str>,
+  map_key: Option<&'a MapKeyConfig>,
 }
 
 fn process_owning_side_entity(
@@ -324,9 +458,11 @@ fn process_owning_side_entity(
     field_config,
     side: EntitySide::Owning,
     mapped_by_field_name: None,
+    map_key: None,
   })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn process_inverse_side_entity(
   cwd: &Path,
   entity_file_path: &Path,
@@ -335,6 +471,7 @@ fn process_inverse_side_entity(
   target_entity_file_path: &Path,
   field_config: &ManyToOneFieldConfig,
   mapped_by_field_name: &str,
+  map_key: Option<&MapKeyConfig>,
 ) -> Result<FileResponse, String> {
   process_entity_side(ProcessEntitySideParams {
     entity_file_b64_src: None,
@@ -347,6 +484,7 @@ fn process_inverse_side_entity(
     field_config,
     side: EntitySide::Inverse,
     mapped_by_field_name: Some(mapped_by_field_name),
+    map_key,
   })
 }
 
@@ -360,6 +498,7 @@ fn process_entity_side(params: ProcessEntitySideParams) -> Result<FileResponse,
     params.field_config,
     &params.side,
     &params.mapped_by_field_name.map(String::from),
+    params.map_key,
   );
   // Step 3: Build import map
   let import_map = build_import_map(
@@ -368,7 +507,12 @@ fn process_entity_side(params: ProcessEntitySideParams) -> Result<FileResponse,
     &annotation_config,
     cwd,
   )?;
-  // Step 4: Add relationship field and annotations
+  // Step 4: Add relationship field and annotations, after checking another field writes a
+  // read-only join column
+  let warnings: Vec<String> =
+    get_unwritable_join_column_warning(&entity_ts_file, &annotation_config, params.field_name)
+      .into_iter()
+      .collect();
   add_relationship_field_and_annotations(
     &mut entity_ts_file,
     params.field_name,
@@ -380,7 +524,9 @@ fn process_entity_side(params: ProcessEntitySideParams) -> Result<FileResponse,
   // Step 6: Save file
   save_entity_file(&mut entity_ts_file, params.save_path, params.cwd)?;
   // Step 7: Build and return response
-  build_file_response(&entity_ts_file)
+  let mut file_response = build_file_response(&entity_ts_file)?;
+  file_response.warnings = warnings;
+  Ok(file_response)
 }
 
 pub fn run(
@@ -391,12 +537,15 @@ pub fn run(
   inverse_side_field_name: &str,
   field_config: &ManyToOneFieldConfig,
 ) -> Result<Vec<FileResponse>, String> {
-  // Step 1: Find inverse entity by class name
+  // Step 1: Validate the relationship options, the foreign key constraint and the map key
+  validate_field_config(field_config)?;
+  let map_key = resolve_map_key(cwd, field_config, inverse_side_field_name)?;
+  // Step 2: Find inverse entity by class name
   let inverse_entity_file_path = find_inverse_entity(cwd, &field_config.inverse_field_type)?;
-  // Step 2: Extract owning entity class name for inverse side
+  // Step 3: Extract owning entity class name for inverse side
   let owning_entity_class_name =
     extract_owning_entity_class_name(owning_side_entity_file_path, cwd)?;
-  // Step 3: Process owning side entity (ManyToOne side)
+  // Step 4: Process owning side entity (ManyToOne side)
   let owning_response = process_owning_side_entity(
     cwd,
     owning_side_entity_file_b64_src,
@@ -407,7 +556,7 @@ pub fn run(
     field_config,
   )?;
   let mut responses = vec![owning_response];
-  // Step 4: Process inverse side entity (OneToMany side) if bidirectional
+  // Step 5: Process inverse side entity (OneToMany side) if bidirectional
   if is_bidirectional_mapping(field_config) {
     let inverse_response = process_inverse_side_entity(
       cwd,
@@ -417,6 +566,7 @@ pub fn run(
       owning_side_entity_file_path,
       field_config,
       owning_side_field_name,
+      map_key.as_ref(),
     )?;
     responses.push(inverse_response);
   }
@@ -1,25 +1,27 @@
 use crate::commands::java::responses::file_response::FileResponse;
 use crate::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+use crate::commands::java::treesitter::services::entity_mapping_service::is_column_mapped;
 use crate::commands::java::treesitter::services::field_declaration_service::{
   AddFieldDeclarationParams, add_field_declaration,
 };
-use crate::commands::java::treesitter::services::import_declaration_service::add_import;
+use crate::commands::java::treesitter::services::import_declaration_service::add_imports;
 use crate::commands::java::treesitter::services::package_declaration_service::{
   get_package_declaration_node, get_package_scope_node,
 };
 use crate::commands::java::treesitter::types::annotation_config::AnnotationConfig;
 use crate::commands::java::treesitter::types::cascade_type::CascadeType;
 use crate::commands::java::treesitter::types::entity_side::EntitySide;
-use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
 use crate::commands::java::treesitter::types::java_basic_types::FieldInsertionPosition;
 use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
 use crate::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
 use crate::commands::java::treesitter::types::mapping_type::MappingType;
 use crate::commands::java::treesitter::types::one_to_one_field_config::OneToOneFieldConfig;
 use crate::commands::java::treesitter::types::other_type::OtherType;
+use crate::commands::java::treesitter::types::relationship_kind::RelationshipKind;
 use crate::common::supported_language::SupportedLanguage;
 use crate::common::ts_file::TSFile;
 use crate::common::utils::case_util::{self, CaseType};
+use crate::common::utils::hash_util::get_content_hash;
 use crate::common::utils::path_util::parse_all_files;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -47,13 +49,6 @@ fn add_to_import_map(
   }
 }
 
-fn add_imports(ts_file: &mut TSFile, import_map: &HashMap<String, String>) {
-  let import_position = ImportInsertionPosition::BeforeFirstImport;
-  for (class_name, package_name) in import_map {
-    add_import(ts_file, &import_position, package_name, class_name);
-  }
-}
-
 fn find_inverse_entity(cwd: &Path, class_name: &str) -> Result<PathBuf, String> {
   let ts_files = parse_all_files(cwd, &JavaSourceDirectoryType::Main);
   for ts_file in ts_files {
@@ -114,7 +109,7 @@ fn build_annotation_config(
 ) -> AnnotationConfig {
   let (cascades, other_options) = match side {
     EntitySide::Owning => {
-      (field_config.owning_side_cascades.clone(), field_config.owning_side_other.clone())
+      (field_config.owning_side_cascades.clone(), field_config.get_owning_side_other())
     }
     EntitySide::Inverse => {
       (field_config.inverse_side_cascades.clone(), field_config.inverse_side_other.clone())
@@ -129,6 +124,10 @@ fn build_annotation_config(
     if is_owning_side || is_unidirectional { None } else { mapped_by_field_name.clone() },
     is_owning_side || is_unidirectional,
   )
+  .with_foreign_key(
+    field_config.get_foreign_key().ok().flatten().filter(|_| is_owning_side || is_unidirectional),
+  )
+  .with_join_column_access(field_config.get_join_column_access())
 }
 
 fn build_import_map(
@@ -144,6 +143,11 @@ fn build_import_map(
   add_to_import_map(&mut import_map, "jakarta.persistence", "OneToOne");
   if annotation_config.needs_join_column {
     add_to_import_map(&mut import_map, "jakarta.persistence", "JoinColumn");
+    if let Some(ref foreign_key) = annotation_config.foreign_key {
+      for class_name in foreign_key.get_imported_classes() {
+        add_to_import_map(&mut import_map, "jakarta.persistence", class_name);
+      }
+    }
   }
   if !annotation_config.cascades.is_empty() {
     add_to_import_map(&mut import_map, "jakarta.persistence", "CascadeType");
@@ -220,6 +224,12 @@ fn add_relationship_field_and_annotations(
       if annotation_config.other_options.contains(&OtherType::Unique) {
         builder.with_argument("@JoinColumn", "unique", "true")?;
       }
+      if let Some(ref foreign_key) = annotation_config.foreign_key {
+        builder.with_argument("@JoinColumn", "foreignKey", &foreign_key.get_annotation_value())?;
+      }
+      for (key, value) in annotation_config.join_column_access.get_arguments() {
+        builder.with_argument("@JoinColumn", key, &value)?;
+      }
     }
     builder.build()
   })
@@ -228,6 +238,24 @@ fn add_relationship_field_and_annotations(
   Ok(())
 }
 
+/// Warns when the join column is left out of inserts or updates while no other field maps it.
+fn get_unwritable_join_column_warning(
+  ts_file: &TSFile,
+  annotation_config: &AnnotationConfig,
+  field_name: &str,
+) -> Option<String> {
+  let join_column_access = &annotation_config.join_column_access;
+  if !annotation_config.needs_join_column || !join_column_access.restricts_writes() {
+    return None;
+  }
+  let column_name = format!("{}_id", case_util::auto_convert_case(field_name, CaseType::Snake));
+  let class_node = get_public_class_node(ts_file)?;
+  if is_column_mapped(ts_file, class_node, &column_name) {
+    return None;
+  }
+  Some(join_column_access.get_unwritable_warning(&column_name))
+}
+
 fn save_entity_file(
   ts_file: &mut TSFile,
   save_path: Option<&Path>,
@@ -257,13 +285,31 @@ fn build_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
     .and_then(|node| ts_file.get_text_from_node(&node))
     .unwrap_or("")
     .to_string();
-  Ok(FileResponse { file_type, file_package_name, file_path })
+  let content_hash = Some(get_content_hash(&ts_file.source_code));
+  Ok(FileResponse { file_type, file_package_name, file_path, content_hash, warnings: Vec::new() })
 }
 
 fn is_bidirectional_mapping(field_config: &OneToOneFieldConfig) -> bool {
   field_config.mapping_type != Some(MappingType::UnidirectionalJoinColumn)
 }
 
+fn validate_field_config(field_config: &OneToOneFieldConfig) -> Result<(), String> {
+  RelationshipKind::OneToOne.validate_options(
+    &EntitySide::Owning,
+    &field_config.owning_side_cascades,
+    &field_config.get_owning_side_other(),
+  )?;
+  if is_bidirectional_mapping(field_config) {
+    RelationshipKind::OneToOne.validate_options(
+      &EntitySide::Inverse,
+      &field_config.inverse_side_cascades,
+      &field_config.inverse_side_other,
+    )?;
+  }
+  field_config.get_foreign_key()?;
+  Ok(())
+}
+
 fn process_owning_side_entity(
   cwd: &Path,
   entity_file_b64_src: &str,
@@ -328,7 +374,12 @@ fn process_entity_side(params: ProcessEntitySideParams) -> Result<FileResponse,
     params.target_entity_file_path,
     cwd,
   )?;
-  // Step 4: Add relationship field and annotations
+  // Step 4: Add relationship field and annotations, after checking another field writes a
+  // read-only join column
+  let warnings: Vec<String> =
+    get_unwritable_join_column_warning(&entity_ts_file, &annotation_config, params.field_name)
+      .into_iter()
+      .collect();
   add_relationship_field_and_annotations(
     &mut entity_ts_file,
     params.field_name,
@@ -340,7 +391,9 @@ fn process_entity_side(params: ProcessEntitySideParams) -> Result<FileResponse,
   // Step 6: Save file
   save_entity_file(&mut entity_ts_file, params.save_path, params.cwd)?;
   // Step 7: Build and return response
-  build_file_response(&entity_ts_file)
+  let mut file_response = build_file_response(&entity_ts_file)?;
+  file_response.warnings = warnings;
+  Ok(file_response)
 }
 
 pub fn run(
@@ -351,11 +404,13 @@ pub fn run(
   inverse_side_field_name: &str,
   field_config: &OneToOneFieldConfig,
 ) -> Result<Vec<FileResponse>, String> {
-  // Step 1: Find inverse entity by class name
+  // Step 1: Validate the relationship options and the foreign key constraint
+  validate_field_config(field_config)?;
+  // Step 2: Find inverse entity by class name
   let inverse_entity_file_path = find_inverse_entity(cwd, &field_config.inverse_field_type)?;
-  // Step 2: Extract owning entity class name for inverse side
+  // Step 3: Extract owning entity class name for inverse side
   let owning_entity_class_name = extract_owning_entity_class_name(owning_side_entity_file_path)?;
-  // Step 3: Process owning side entity
+  // Step 4: Process owning side entity
   let owning_response = process_owning_side_entity(
     cwd,
     owning_side_entity_file_b64_src,
@@ -366,7 +421,7 @@ pub fn run(
     field_config,
   )?;
   let mut responses = vec![owning_response];
-  // Step 4: Process inverse side entity (if bidirectional)
+  // Step 5: Process inverse side entity (if bidirectional)
   if is_bidirectional_mapping(field_config) {
     let inverse_response = process_inverse_side_entity(
       cwd,
@@ -379,6 +434,6 @@ pub fn run(
     )?;
     responses.push(inverse_response);
   }
-  // Step 5: Return processed file responses
+  // Step 6: Return processed file responses
   Ok(responses)
 }
@@ -17,6 +17,7 @@ use crate::commands::java::treesitter::types::java_file_type::JavaFileType;
 use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
 use crate::common::supported_language::SupportedLanguage;
 use crate::common::ts_file::TSFile;
+use crate::common::utils::hash_util::get_content_hash;
 
 fn create_repository_file(
   cwd: &Path,
@@ -52,6 +53,8 @@ fn create_repository_file(
     &repository_file_name,
     &repository_file_type,
     &repository_source_dir_type,
+    None,
+    None,
   )?;
   Ok(create_java_file_response)
 }
@@ -78,7 +81,14 @@ fn create_file_response(ts_file: &TSFile) -> Result<FileResponse, String> {
     })
     .and_then(|scope_node| ts_file.get_text_from_node(&scope_node).map(|s| s.to_string()))
     .unwrap_or_default();
-  Ok(FileResponse { file_path, file_type, file_package_name: package_name })
+  let content_hash = Some(get_content_hash(&ts_file.source_code));
+  Ok(FileResponse {
+    file_path,
+    file_type,
+    file_package_name: package_name,
+    content_hash,
+    warnings: Vec::new(),
+  })
 }
 
 fn create_and_extend_jpa_repository(
@@ -0,0 +1,225 @@
+use std::cmp::Reverse;
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::file_response::FileResponse;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_public_class_node, get_type_declaration_name,
+};
+use crate::commands::java::treesitter::services::field_declaration_service::{
+  find_field_declaration_node_by_name, get_leftover_references_warning, remove_field_declaration,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  remove_unused_import, resolve_fully_qualified_type_name,
+};
+use crate::commands::java::treesitter::services::method_declaration_service::{
+  get_getter_name, get_method_declaration_name, get_own_method_declaration_nodes, get_setter_name,
+  remove_method_declaration,
+};
+use crate::commands::java::treesitter::services::method_invocation_service::{
+  get_method_invocation_arguments_count, get_method_invocation_nodes_by_name,
+  resolve_identifier_type,
+};
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::hash_util::get_content_hash;
+use crate::common::utils::path_util::{is_same_file, parse_all_files_within_deadline};
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+/// Getter or setter removed along with the field.
+struct RemovedAccessor {
+  start_byte: usize,
+  name: String,
+  parameters_count: usize,
+}
+
+fn get_parameters_count(method_node: Node) -> usize {
+  method_node.child_by_field_name("parameters").map_or(0, |parameters| {
+    let mut cursor = parameters.walk();
+    parameters
+      .named_children(&mut cursor)
+      .filter(|child| matches!(child.kind(), "formal_parameter" | "spread_parameter"))
+      .count()
+  })
+}
+
+/// Getter and setter of the field, last first so removing one leaves the position of the other
+/// valid.
+fn find_accessors(
+  ts_file: &TSFile,
+  class_node: Node,
+  field_name: &str,
+  field_type: &str,
+) -> Vec<RemovedAccessor> {
+  let accessors = [(get_getter_name(field_name, field_type), 0), (get_setter_name(field_name), 1)];
+  let method_nodes = get_own_method_declaration_nodes(ts_file, class_node);
+  let mut removed_accessors: Vec<RemovedAccessor> = accessors
+    .into_iter()
+    .filter_map(|(name, parameters_count)| {
+      let method_node = method_nodes.iter().find(|node| {
+        get_method_declaration_name(ts_file, **node) == Some(name.as_str())
+          && get_parameters_count(**node) == parameters_count
+      })?;
+      Some(RemovedAccessor { start_byte: method_node.start_byte(), name, parameters_count })
+    })
+    .collect();
+  removed_accessors.sort_by_key(|accessor| Reverse(accessor.start_byte));
+  removed_accessors
+}
+
+/// Warns about the calls to the removed accessors left in a file: calls without receiver or on
+/// `this` in the entity itself, calls on a variable declared with the entity type elsewhere.
+fn get_leftover_accessor_call_warnings(
+  ts_file: &TSFile,
+  class_name: &str,
+  accessors: &[RemovedAccessor],
+  file_label: &str,
+  is_entity_file: bool,
+) -> Vec<String> {
+  let mut warnings = Vec::new();
+  for accessor in accessors {
+    for invocation in get_method_invocation_nodes_by_name(ts_file, &accessor.name) {
+      if get_method_invocation_arguments_count(invocation) != accessor.parameters_count {
+        continue;
+      }
+      let is_entity_call = match invocation.child_by_field_name("object") {
+        None => is_entity_file,
+        Some(object) if object.kind() == "this" => is_entity_file,
+        Some(object) if object.kind() == "identifier" => ts_file
+          .get_text_from_node(&object)
+          .and_then(|identifier| resolve_identifier_type(ts_file, object, identifier))
+          .is_some_and(|receiver_type| receiver_type == class_name),
+        Some(_) => false,
+      };
+      if is_entity_call {
+        warnings.push(format!(
+          "Accessor '{}' was removed but is still called in {} at line {}, update it before \
+           compiling",
+          accessor.name,
+          file_label,
+          invocation.start_position().row + 1
+        ));
+      }
+    }
+  }
+  warnings
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  deadline: &ScanDeadline,
+) -> Result<FileResponse, String> {
+  // Step 1: Parse entity file
+  let mut ts_file = TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  // Step 2: Find the field and its accessors in the public class
+  let (class_start_byte, file_type, accessors) = {
+    let class_node = get_public_class_node(&ts_file)
+      .ok_or_else(|| "Unable to get public class node".to_string())?;
+    let class_name = get_type_declaration_name(&ts_file, class_node).unwrap_or_default();
+    let field_node = find_field_declaration_node_by_name(&ts_file, field_name, class_node)
+      .ok_or_else(|| format!("Field '{}' not found in class '{}'", field_name, class_name))?;
+    let mut cursor = field_node.walk();
+    let declarators_count = field_node
+      .named_children(&mut cursor)
+      .filter(|child| child.kind() == "variable_declarator")
+      .count();
+    if declarators_count != 1 {
+      return Err(format!(
+        "Field '{}' shares its declaration with other fields, split it before deleting",
+        field_name
+      ));
+    }
+    let field_type = field_node
+      .child_by_field_name("type")
+      .and_then(|type_node| ts_file.get_text_from_node(&type_node))
+      .unwrap_or_default();
+    (
+      class_node.start_byte(),
+      class_name.to_string(),
+      find_accessors(&ts_file, class_node, field_name, field_type),
+    )
+  };
+  // Step 3: Remove the getter and setter, then the field with its annotations
+  for accessor in &accessors {
+    remove_method_declaration(&mut ts_file, accessor.start_byte);
+  }
+  let removed_text = remove_field_declaration(&mut ts_file, class_start_byte, field_name)
+    .ok_or_else(|| format!("Unable to remove field '{}'", field_name))?;
+  // Step 4: Remove the imports of the types and annotations the field was the last to use
+  let mut names: Vec<&str> = removed_text
+    .split(|c: char| !c.is_alphanumeric() && c != '_')
+    .filter(|word| word.starts_with(|c: char| c.is_ascii_uppercase()))
+    .collect();
+  names.sort();
+  names.dedup();
+  for name in names {
+    remove_unused_import(&mut ts_file, name);
+  }
+  // Step 5: Warn about the references to the field and its accessors left in the entity
+  let entity_file_label = entity_file_path
+    .file_name()
+    .map(|file_name| file_name.to_string_lossy().to_string())
+    .unwrap_or_default();
+  let mut warnings: Vec<String> =
+    get_leftover_references_warning(&ts_file, field_name).into_iter().collect();
+  warnings.extend(get_leftover_accessor_call_warnings(
+    &ts_file,
+    &file_type,
+    &accessors,
+    &entity_file_label,
+    true,
+  ));
+  // Step 6: Warn about the accessor calls left in the other files of the project
+  let file_package_name = get_package_name(&ts_file).unwrap_or_default();
+  if !accessors.is_empty() {
+    let class_fqn =
+      format!("{}.{}", file_package_name, file_type).trim_start_matches('.').to_string();
+    for other_ts_file in
+      parse_all_files_within_deadline(cwd, &JavaSourceDirectoryType::All, deadline)
+    {
+      let Some(file_path) = other_ts_file.file_path() else {
+        continue;
+      };
+      // Files where the simple name is another class can't call the accessors
+      if is_same_file(file_path, entity_file_path)
+        || resolve_fully_qualified_type_name(&other_ts_file, &file_type)
+          .is_some_and(|fqn| fqn != class_fqn)
+      {
+        continue;
+      }
+      let file_label = file_path.strip_prefix(cwd).unwrap_or(file_path).display().to_string();
+      warnings.extend(get_leftover_accessor_call_warnings(
+        &other_ts_file,
+        &file_type,
+        &accessors,
+        &file_label,
+        false,
+      ));
+    }
+    if deadline.is_truncated() {
+      warnings.push(format!(
+        "Timed out scanning the project for calls to the accessors of '{}', other calls may \
+         remain",
+        field_name
+      ));
+    }
+  }
+  // Step 7: Save file with working directory validation
+  ts_file
+    .save_to_existing_file(entity_file_path, cwd)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 8: Build and return response
+  Ok(FileResponse {
+    file_type,
+    file_package_name,
+    file_path: entity_file_path.display().to_string(),
+    content_hash: Some(get_content_hash(&ts_file.source_code)),
+    warnings,
+  })
+}
@@ -0,0 +1,132 @@
+use std::fs;
+use std::path::Path;
+
+use crate::commands::java::responses::emit_camel_to_snake_column_report_response::{
+  ColumnNameSuggestionResponse, EmitCamelToSnakeColumnReportResponse, EntityColumnReportResponse,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+use crate::commands::java::treesitter::services::entity_mapping_service::get_class_mapping;
+use crate::commands::java::treesitter::services::field_declaration_service::set_field_annotation_argument;
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, get_persistence_package,
+};
+use crate::commands::java::treesitter::types::entity_mapping::EntityMapping;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::case_util::to_snake_case;
+use crate::common::utils::path_util::get_all_java_file_paths;
+use crate::common::utils::progress;
+
+/// Columns of the class whose name isn't snake_case, with the snake_case form.
+///
+/// Implicit names are derived from the field by Spring Boot's naming strategy, which already
+/// produces snake_case, unless the project maps them verbatim. Quoted names are kept as written
+/// on purpose and never reported.
+fn collect_suggestions(
+  mapping: &EntityMapping,
+  implicit_names_as_is: bool,
+) -> Vec<ColumnNameSuggestionResponse> {
+  mapping
+    .columns
+    .iter()
+    .filter_map(|column| {
+      let current_column = match (column.has_explicit_name, implicit_names_as_is) {
+        (true, _) => column.column_name.clone(),
+        (false, true) => column.field_name.clone(),
+        (false, false) => return None,
+      };
+      // The annotation value holds quoted names with their escapes, `\"Order\"`
+      if current_column.starts_with("\\\"") || current_column.starts_with('"') {
+        return None;
+      }
+      let suggested_column = to_snake_case(&current_column);
+      (suggested_column != current_column).then(|| ColumnNameSuggestionResponse {
+        field: column.field_name.clone(),
+        current_column,
+        suggested_column,
+        has_explicit_name: column.has_explicit_name,
+      })
+    })
+    .collect()
+}
+
+/// Names the suggested columns explicitly through `@Column(name = ...)`.
+fn apply_suggestions(
+  ts_file: &mut TSFile,
+  suggestions: &[ColumnNameSuggestionResponse],
+) -> Result<(), String> {
+  let class_start_byte = get_public_class_node(ts_file)
+    .map(|class_node| class_node.start_byte())
+    .ok_or_else(|| "Unable to get public class node".to_string())?;
+  let mut column_annotation_added = false;
+  for suggestion in suggestions {
+    column_annotation_added |= set_field_annotation_argument(
+      ts_file,
+      class_start_byte,
+      &suggestion.field,
+      "Column",
+      "name",
+      &format!("\"{}\"", suggestion.suggested_column),
+    )
+    .ok_or_else(|| format!("Unable to set the column name of field '{}'", suggestion.field))?;
+  }
+  if column_annotation_added {
+    let persistence_package = get_persistence_package(ts_file);
+    add_import(ts_file, &ImportInsertionPosition::BeforeFirstImport, persistence_package, "Column");
+  }
+  Ok(())
+}
+
+/// Reports the mapped classes' columns that aren't snake_case, and renames them with `apply`.
+pub fn run(
+  cwd: &Path,
+  implicit_names_as_is: bool,
+  apply: bool,
+) -> Result<EmitCamelToSnakeColumnReportResponse, String> {
+  let mut entities = Vec::new();
+  let file_paths = get_all_java_file_paths(cwd, &JavaSourceDirectoryType::Main);
+  for (index, file_path) in file_paths.iter().enumerate() {
+    progress::report("checking", index + 1, file_paths.len());
+    // Step 1: Skip files that can't map columns before parsing
+    let Ok(source_code) = fs::read_to_string(file_path) else {
+      continue;
+    };
+    let annotations = ["@Entity", "@MappedSuperclass", "@Embeddable"];
+    if !annotations.iter().any(|annotation| source_code.contains(annotation)) {
+      continue;
+    }
+    let Ok(mut ts_file) = TSFile::from_file(file_path, cwd, SupportedLanguage::Java) else {
+      continue;
+    };
+    // Step 2: Resolve the columns and collect the ones to rename
+    let Some(mapping) = get_public_class_node(&ts_file)
+      .and_then(|class_node| get_class_mapping(&ts_file, class_node))
+    else {
+      continue;
+    };
+    let columns = collect_suggestions(&mapping, implicit_names_as_is);
+    if columns.is_empty() {
+      continue;
+    }
+    // Step 3: Rename the columns when requested
+    if apply {
+      apply_suggestions(&mut ts_file, &columns)?;
+      ts_file
+        .save_to_existing_file(file_path, cwd)
+        .map_err(|e| format!("Unable to save '{}': {}", file_path.display(), e))?;
+    }
+    entities.push(EntityColumnReportResponse {
+      entity_type: mapping.class_name,
+      entity_path: file_path.display().to_string(),
+      columns,
+    });
+  }
+  // Step 4: Build response
+  Ok(EmitCamelToSnakeColumnReportResponse {
+    columns_count: entities.iter().map(|entity| entity.columns.len()).sum(),
+    entities,
+    applied: apply,
+  })
+}
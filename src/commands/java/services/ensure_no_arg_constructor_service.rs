@@ -0,0 +1,61 @@
+use std::path::Path;
+
+use crate::commands::java::responses::ensure_no_arg_constructor_response::EnsureNoArgConstructorResponse;
+use crate::commands::java::treesitter::services::annotation_service::find_annotation_node_by_name;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::constructor_declaration_service::{
+  AddConstructorDeclarationParams, add_constructor_declaration, has_no_arg_constructor,
+};
+use crate::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+) -> Result<EnsureNoArgConstructorResponse, String> {
+  // Step 1: Parse entity file
+  let mut entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  // Step 2: Inspect the class constructors
+  let (entity_type, class_start_byte, needs_constructor) = {
+    let class_node = get_public_class_node(&entity_ts_file)
+      .ok_or_else(|| "Unable to get public class node".to_string())?;
+    let is_jpa_class = ["Entity", "MappedSuperclass", "Embeddable"].iter().any(|annotation| {
+      find_annotation_node_by_name(&entity_ts_file, class_node, annotation).is_some()
+    });
+    if !is_jpa_class {
+      return Err(
+        "Class is not annotated with @Entity, @MappedSuperclass or @Embeddable".to_string(),
+      );
+    }
+    let entity_type = get_class_declaration_name_node(&entity_ts_file, class_node)
+      .and_then(|name_node| entity_ts_file.get_text_from_node(&name_node))
+      .map(|name| name.to_string())
+      .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+    (entity_type, class_node.start_byte(), !has_no_arg_constructor(&entity_ts_file, class_node))
+  };
+  // Step 3: Insert the protected no-arg constructor when missing
+  if needs_constructor {
+    let params = AddConstructorDeclarationParams {
+      visibility_modifier: JavaVisibilityModifier::Protected,
+      parameters: Vec::new(),
+      body_statements: Vec::new(),
+    };
+    add_constructor_declaration(&mut entity_ts_file, class_start_byte, params)
+      .ok_or_else(|| "Unable to add no-arg constructor".to_string())?;
+    // Step 4: Save file with working directory validation
+    entity_ts_file
+      .save_to_existing_file(entity_file_path, cwd)
+      .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  }
+  // Step 5: Build and return response
+  Ok(EnsureNoArgConstructorResponse {
+    entity_file_path: entity_file_path.display().to_string(),
+    entity_type,
+    constructor_added: needs_constructor,
+  })
+}
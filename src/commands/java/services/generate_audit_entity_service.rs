@@ -0,0 +1,237 @@
+use std::fs;
+use std::path::Path;
+
+use crate::commands::java::responses::file_response::FileResponse;
+use crate::commands::java::responses::generate_audit_entity_response::{
+  AuditFieldResponse, GenerateAuditEntityResponse,
+};
+use crate::commands::java::services::create_java_file_service::{
+  build_save_path, correct_java_file_name, create_ts_file, generate_file_template,
+};
+use crate::commands::java::treesitter::services::annotation_service::add_annotation;
+use crate::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+use crate::commands::java::treesitter::services::import_declaration_service::add_import;
+use crate::commands::java::treesitter::services::method_declaration_service::get_getter_name;
+use crate::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::java_file_type::JavaFileType;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::commands::java::validators::java_class_name_validator::validate_java_class_name;
+use crate::commands::java::validators::java_type_validator::validate_java_field_type;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::hash_util::get_content_hash;
+use crate::common::utils::path_util::get_all_java_file_paths;
+
+const SPRING_DATA_ANNOTATION_PACKAGE: &str = "org.springframework.data.annotation";
+
+/// `java.time` types Spring Data can set from the auditing clock.
+const TIMESTAMP_TYPES: [&str; 4] = ["Instant", "LocalDateTime", "OffsetDateTime", "ZonedDateTime"];
+
+/// Audit field of the base class.
+struct AuditField {
+  field_name: &'static str,
+  column_name: &'static str,
+  annotation: &'static str,
+  /// Creation fields are written once, on insert
+  updatable: bool,
+  is_auditor: bool,
+}
+
+const AUDIT_FIELDS: [AuditField; 4] = [
+  AuditField {
+    field_name: "createdAt",
+    column_name: "created_at",
+    annotation: "CreatedDate",
+    updatable: false,
+    is_auditor: false,
+  },
+  AuditField {
+    field_name: "updatedAt",
+    column_name: "updated_at",
+    annotation: "LastModifiedDate",
+    updatable: true,
+    is_auditor: false,
+  },
+  AuditField {
+    field_name: "createdBy",
+    column_name: "created_by",
+    annotation: "CreatedBy",
+    updatable: false,
+    is_auditor: true,
+  },
+  AuditField {
+    field_name: "updatedBy",
+    column_name: "updated_by",
+    annotation: "LastModifiedBy",
+    updatable: true,
+    is_auditor: true,
+  },
+];
+
+/// Fields and read-only accessors of the base class, the auditing listener sets the values.
+fn build_class_body(timestamp_type: &str, auditor_type: &str) -> String {
+  let mut members = Vec::new();
+  for field in &AUDIT_FIELDS {
+    let field_type = if field.is_auditor { auditor_type } else { timestamp_type };
+    let column_arguments = match field.updatable {
+      true => format!("name = \"{}\"", field.column_name),
+      false => format!("name = \"{}\", updatable = false", field.column_name),
+    };
+    members.push(format!(
+      "  @{}\n  @Column({})\n  private {} {};",
+      field.annotation, column_arguments, field_type, field.field_name
+    ));
+  }
+  for field in &AUDIT_FIELDS {
+    let field_type = if field.is_auditor { auditor_type } else { timestamp_type };
+    members.push(format!(
+      "  public {} {}() {{\n    return {};\n  }}",
+      field_type,
+      get_getter_name(field.field_name, field_type),
+      field.field_name
+    ));
+  }
+  format!("{{\n{}\n}}", members.join("\n\n"))
+}
+
+fn add_class_annotations(ts_file: &mut TSFile) -> Result<(), String> {
+  for annotation in ["@MappedSuperclass", "@EntityListeners(AuditingEntityListener.class)"] {
+    let class_byte_position = get_public_class_node(ts_file)
+      .map(|class_node| class_node.start_byte())
+      .ok_or_else(|| "No public class found in file".to_string())?;
+    add_annotation(
+      ts_file,
+      class_byte_position,
+      &AnnotationInsertionPosition::AboveScopeDeclaration,
+      annotation,
+    )
+    .ok_or_else(|| format!("Failed to add {} annotation", annotation))?;
+  }
+  Ok(())
+}
+
+/// Makes the class abstract and replaces its body with the audit fields.
+fn write_class_body(ts_file: &mut TSFile, body: &str) -> Result<(), String> {
+  let (modifiers_end_byte, body_start_byte, body_end_byte) = {
+    let class_node =
+      get_public_class_node(ts_file).ok_or_else(|| "No public class found in file".to_string())?;
+    let mut cursor = class_node.walk();
+    let modifiers_node = class_node
+      .children(&mut cursor)
+      .find(|child| child.kind() == "modifiers")
+      .ok_or_else(|| "Unable to get class modifiers node".to_string())?;
+    let body_node = class_node
+      .child_by_field_name("body")
+      .ok_or_else(|| "Unable to get class body node".to_string())?;
+    (modifiers_node.end_byte(), body_node.start_byte(), body_node.end_byte())
+  };
+  ts_file.replace_text_by_range(body_start_byte, body_end_byte, &format!("{}\n", body));
+  ts_file.insert_text(modifiers_end_byte, " abstract");
+  Ok(())
+}
+
+fn add_imports(
+  ts_file: &mut TSFile,
+  timestamp_type: &str,
+  auditor_type: &str,
+  auditor_type_package_name: Option<&str>,
+) {
+  let mut imports: Vec<(&str, &str)> = vec![
+    ("jakarta.persistence", "Column"),
+    ("jakarta.persistence", "EntityListeners"),
+    ("jakarta.persistence", "MappedSuperclass"),
+    ("java.time", timestamp_type),
+  ];
+  if let Some(package_name) = auditor_type_package_name {
+    imports.push((package_name, auditor_type));
+  }
+  for field in &AUDIT_FIELDS {
+    imports.push((SPRING_DATA_ANNOTATION_PACKAGE, field.annotation));
+  }
+  imports.push(("org.springframework.data.jpa.domain.support", "AuditingEntityListener"));
+  for (package_name, class_name) in imports {
+    add_import(ts_file, &ImportInsertionPosition::AfterLastImport, package_name, class_name);
+  }
+}
+
+/// Warns about the configuration auditing needs and the project doesn't declare yet.
+fn get_setup_warnings(cwd: &Path, auditor_type: &str) -> Vec<String> {
+  let sources: Vec<String> = get_all_java_file_paths(cwd, &JavaSourceDirectoryType::Main)
+    .into_iter()
+    .filter_map(|path| fs::read_to_string(path).ok())
+    .collect();
+  let mut warnings = Vec::new();
+  if !sources.iter().any(|source| source.contains("@EnableJpaAuditing")) {
+    warnings.push(
+      "Auditing stays inactive until a configuration class is annotated with @EnableJpaAuditing"
+        .to_string(),
+    );
+  }
+  if !sources.iter().any(|source| source.contains("AuditorAware")) {
+    warnings.push(format!(
+      "createdBy and updatedBy stay null until an AuditorAware<{}> bean is declared",
+      auditor_type
+    ));
+  }
+  warnings
+}
+
+/// Creates a `@MappedSuperclass` holding Spring Data JPA auditing fields, which new entities can
+/// extend through `create-jpa-entity --superclass-type`.
+pub fn run(
+  cwd: &Path,
+  package_name: &str,
+  class_name: &str,
+  timestamp_type: &str,
+  auditor_type: &str,
+  auditor_type_package_name: Option<&str>,
+) -> Result<GenerateAuditEntityResponse, String> {
+  // Step 1: Validate the types and the save path before creating anything
+  validate_java_class_name(class_name)?;
+  if !TIMESTAMP_TYPES.contains(&timestamp_type) {
+    return Err(format!(
+      "Type '{}' can't hold audit timestamps, use one of: {}",
+      timestamp_type,
+      TIMESTAMP_TYPES.join(", ")
+    ));
+  }
+  validate_java_field_type(auditor_type)?;
+  let save_path = build_save_path(
+    &JavaSourceDirectoryType::Main,
+    cwd,
+    package_name,
+    &correct_java_file_name(class_name),
+  )?;
+  if save_path.exists() {
+    return Err(format!("File already exists: {}", save_path.display()));
+  }
+  // Step 2: Create the abstract class with the audit fields
+  let mut ts_file =
+    create_ts_file(&generate_file_template(&JavaFileType::Class, package_name, class_name));
+  write_class_body(&mut ts_file, &build_class_body(timestamp_type, auditor_type))?;
+  add_class_annotations(&mut ts_file)?;
+  add_imports(&mut ts_file, timestamp_type, auditor_type, auditor_type_package_name);
+  // Step 3: Save file
+  ts_file.save_as(&save_path, cwd).map_err(|e| format!("Failed to save file: {}", e))?;
+  // Step 4: Build and return response
+  Ok(GenerateAuditEntityResponse {
+    file: FileResponse {
+      file_type: class_name.to_string(),
+      file_package_name: package_name.to_string(),
+      file_path: save_path.display().to_string(),
+      content_hash: Some(get_content_hash(&ts_file.source_code)),
+      warnings: get_setup_warnings(cwd, auditor_type),
+    },
+    fields: AUDIT_FIELDS
+      .iter()
+      .map(|field| AuditFieldResponse {
+        field_name: field.field_name.to_string(),
+        field_type: match field.is_auditor {
+          true => auditor_type.to_string(),
+          false => timestamp_type.to_string(),
+        },
+        annotation: field.annotation.to_string(),
+      })
+      .collect(),
+  })
+}
@@ -0,0 +1,508 @@
+use std::path::{Path, PathBuf};
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::file_response::FileResponse;
+use crate::commands::java::responses::generate_controller_test_response::{
+  ControllerTestEndpointResponse, GenerateControllerTestResponse,
+};
+use crate::commands::java::services::create_java_file_service;
+use crate::commands::java::treesitter::services::annotation_service::{
+  find_declaration_annotation_node_by_name, find_direct_annotation_value_node_by_key,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::{
+  get_own_field_declaration_nodes, get_simple_type_name,
+};
+use crate::commands::java::treesitter::services::field_declaration_service::get_field_declaration_name_node;
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, find_imported_class_package,
+};
+use crate::commands::java::treesitter::services::method_declaration_service::{
+  get_method_declaration_name, get_own_method_declaration_nodes,
+};
+use crate::commands::java::treesitter::services::method_invocation_service::get_method_invocation_arguments_count;
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::java_file_type::JavaFileType;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::case_util;
+use crate::common::utils::hash_util::get_content_hash;
+
+const MAPPING_ANNOTATIONS: [(&str, &str); 5] = [
+  ("GetMapping", "GET"),
+  ("PostMapping", "POST"),
+  ("PutMapping", "PUT"),
+  ("DeleteMapping", "DELETE"),
+  ("PatchMapping", "PATCH"),
+];
+
+const TEST_IMPORTS: [(&str, &str); 7] = [
+  ("org.junit.jupiter.api", "Test"),
+  ("org.springframework.beans.factory.annotation", "Autowired"),
+  ("org.springframework.boot.test.autoconfigure.web.servlet", "WebMvcTest"),
+  ("org.springframework.boot.test.mock.mockito", "MockBean"),
+  ("org.springframework.test.web.servlet", "MockMvc"),
+  ("org.springframework.test.web.servlet.request", "MockMvcRequestBuilders"),
+  ("org.springframework.test.web.servlet.result", "MockMvcResultMatchers"),
+];
+
+/// Service field of the controller, mocked in the test.
+struct MockedService {
+  field_name: String,
+  type_name: String,
+}
+
+/// Service method an endpoint calls, verified after the request.
+struct ServiceCall {
+  field_name: String,
+  method_name: String,
+  arguments_count: usize,
+}
+
+/// Request mapping method of the controller and the test exercising it.
+struct Endpoint {
+  handler_method: String,
+  http_method: String,
+  path: String,
+  path_variable_values: Vec<String>,
+  has_request_body: bool,
+  expected_status: String,
+  service_calls: Vec<ServiceCall>,
+}
+
+/// First string literal in the node, without quotes. Picks the first path of a
+/// `{"/a", "/b"}` array.
+fn get_first_string_literal(ts_file: &TSFile, node: Node) -> Option<String> {
+  let mut pending = vec![node];
+  while let Some(current) = pending.pop() {
+    if current.kind() == "string_literal" {
+      return ts_file.get_text_from_node(&current).map(|text| text.trim_matches('"').to_string());
+    }
+    let mut cursor = current.walk();
+    let mut children: Vec<Node> = current.named_children(&mut cursor).collect();
+    children.reverse();
+    pending.extend(children);
+  }
+  None
+}
+
+fn get_annotation_text_value(ts_file: &TSFile, annotation_node: Node, keys: &[&str]) -> String {
+  keys
+    .iter()
+    .find_map(|key| find_direct_annotation_value_node_by_key(ts_file, annotation_node, key))
+    .and_then(|value_node| get_first_string_literal(ts_file, value_node))
+    .unwrap_or_default()
+}
+
+/// Last segment of a constant argument, `CREATED` for `HttpStatus.CREATED`.
+fn get_annotation_constant_value(
+  ts_file: &TSFile,
+  annotation_node: Node,
+  keys: &[&str],
+) -> Option<String> {
+  let value_node = keys
+    .iter()
+    .find_map(|key| find_direct_annotation_value_node_by_key(ts_file, annotation_node, key))?;
+  let text = ts_file.get_text_from_node(&value_node)?;
+  let first_value = text.trim_matches(|c| c == '{' || c == '}').split(',').next()?.trim();
+  first_value.rsplit('.').next().map(|value| value.to_string())
+}
+
+fn join_paths(base_path: &str, path: &str) -> String {
+  let segments: Vec<&str> =
+    [base_path, path].iter().map(|part| part.trim_matches('/')).filter(|p| !p.is_empty()).collect();
+  format!("/{}", segments.join("/"))
+}
+
+/// Names of the `{name}` and `{name:regex}` variables of a path, in order.
+fn get_path_variable_names(path: &str) -> Vec<String> {
+  path
+    .split('{')
+    .skip(1)
+    .filter_map(|part| part.split('}').next())
+    .map(|variable| variable.split(':').next().unwrap_or(variable).trim().to_string())
+    .collect()
+}
+
+/// Sample value of a path variable type, with the `java.*` class it needs imported.
+fn get_path_variable_value(simple_type: &str) -> Option<(&'static str, Option<(&str, &str)>)> {
+  let value = match simple_type {
+    "long" | "Long" => "1L",
+    "int" | "Integer" | "short" | "Short" | "byte" | "Byte" => "1",
+    "String" => "\"1\"",
+    "UUID" => return Some(("UUID.randomUUID()", Some(("java.util", "UUID")))),
+    _ => return None,
+  };
+  Some((value, None))
+}
+
+fn get_formal_parameter_nodes(method_node: Node) -> Vec<Node> {
+  let Some(parameters_node) = method_node.child_by_field_name("parameters") else {
+    return Vec::new();
+  };
+  let mut cursor = parameters_node.walk();
+  parameters_node
+    .named_children(&mut cursor)
+    .filter(|child| child.kind() == "formal_parameter")
+    .collect()
+}
+
+/// `isCreated` for `CREATED`, following the `StatusResultMatchers` method names.
+fn get_status_matcher(status: &str) -> String {
+  format!("is{}", case_util::to_pascal_case(&status.to_lowercase()))
+}
+
+/// Calls made in the method body on one of the mocked service fields, either directly or through
+/// `this`.
+fn collect_service_calls(
+  ts_file: &TSFile,
+  method_node: Node,
+  services: &[MockedService],
+) -> Vec<ServiceCall> {
+  let Some(body_node) = method_node.child_by_field_name("body") else {
+    return Vec::new();
+  };
+  let mut calls: Vec<ServiceCall> = Vec::new();
+  let mut pending = vec![body_node];
+  while let Some(node) = pending.pop() {
+    if node.kind() == "method_invocation"
+      && let Some(object_node) = node.child_by_field_name("object")
+    {
+      let object_name = match object_node.kind() {
+        "field_access" => object_node.child_by_field_name("field"),
+        _ => Some(object_node),
+      }
+      .and_then(|name_node| ts_file.get_text_from_node(&name_node));
+      let method_name =
+        node.child_by_field_name("name").and_then(|name| ts_file.get_text_from_node(&name));
+      if let (Some(object_name), Some(method_name)) = (object_name, method_name)
+        && services.iter().any(|service| service.field_name == object_name)
+        && !calls
+          .iter()
+          .any(|call| call.field_name == object_name && call.method_name == method_name)
+      {
+        calls.push(ServiceCall {
+          field_name: object_name.to_string(),
+          method_name: method_name.to_string(),
+          arguments_count: get_method_invocation_arguments_count(node),
+        });
+      }
+    }
+    let mut cursor = node.walk();
+    let mut children: Vec<Node> = node.named_children(&mut cursor).collect();
+    children.reverse();
+    pending.extend(children);
+  }
+  calls
+}
+
+/// Fields of the controller whose type is a `*Service`.
+fn collect_mocked_services(ts_file: &TSFile, class_node: Node) -> Vec<MockedService> {
+  get_own_field_declaration_nodes(ts_file, class_node)
+    .into_iter()
+    .filter_map(|field_node| {
+      let type_name = field_node
+        .child_by_field_name("type")
+        .and_then(|node| ts_file.get_text_from_node(&node))
+        .map(get_simple_type_name)?;
+      let field_name = get_field_declaration_name_node(ts_file, field_node)
+        .and_then(|node| ts_file.get_text_from_node(&node))?;
+      type_name
+        .ends_with("Service")
+        .then(|| MockedService { field_name: field_name.to_string(), type_name })
+    })
+    .collect()
+}
+
+/// Reads the request mapping methods of the controller. Path variables get a sample value per
+/// their type, and unknown types fall back to `"1"` with a warning.
+fn collect_endpoints(
+  ts_file: &TSFile,
+  class_node: Node,
+  base_path: &str,
+  services: &[MockedService],
+  imports: &mut Vec<(String, String)>,
+  warnings: &mut Vec<String>,
+) -> Vec<Endpoint> {
+  let mut endpoints = Vec::new();
+  for method_node in get_own_method_declaration_nodes(ts_file, class_node) {
+    let mapping = MAPPING_ANNOTATIONS.iter().find_map(|(name, http_method)| {
+      find_declaration_annotation_node_by_name(ts_file, method_node, name)
+        .map(|annotation| (annotation, http_method.to_string()))
+    });
+    let mapping = mapping.or_else(|| {
+      let annotation =
+        find_declaration_annotation_node_by_name(ts_file, method_node, "RequestMapping")?;
+      let http_method = get_annotation_constant_value(ts_file, annotation, &["method"])
+        .unwrap_or_else(|| "GET".to_string());
+      Some((annotation, http_method))
+    });
+    let (Some((annotation_node, http_method)), Some(handler_method)) =
+      (mapping, get_method_declaration_name(ts_file, method_node))
+    else {
+      continue;
+    };
+    let path = join_paths(
+      base_path,
+      &get_annotation_text_value(ts_file, annotation_node, &["value", "path"]),
+    );
+    let parameters = get_formal_parameter_nodes(method_node);
+    let mut path_variables: Vec<(String, String)> = Vec::new();
+    for parameter_node in &parameters {
+      let Some(path_variable_node) =
+        find_declaration_annotation_node_by_name(ts_file, *parameter_node, "PathVariable")
+      else {
+        continue;
+      };
+      let parameter_name = parameter_node
+        .child_by_field_name("name")
+        .and_then(|node| ts_file.get_text_from_node(&node))
+        .unwrap_or_default();
+      let variable_name =
+        get_annotation_text_value(ts_file, path_variable_node, &["value", "name"]);
+      let parameter_type = parameter_node
+        .child_by_field_name("type")
+        .and_then(|node| ts_file.get_text_from_node(&node))
+        .unwrap_or_default();
+      let variable_name = if variable_name.is_empty() { parameter_name } else { &variable_name };
+      path_variables.push((variable_name.to_string(), parameter_type.to_string()));
+    }
+    let path_variable_values = get_path_variable_names(&path)
+      .into_iter()
+      .map(|variable_name| {
+        let parameter_type = path_variables
+          .iter()
+          .find(|(name, _)| *name == variable_name)
+          .map(|(_, parameter_type)| parameter_type.as_str())
+          .unwrap_or_default();
+        match get_path_variable_value(&get_simple_type_name(parameter_type)) {
+          Some((value, import)) => {
+            if let Some((package_name, class_name)) = import {
+              let import = (package_name.to_string(), class_name.to_string());
+              if !imports.contains(&import) {
+                imports.push(import);
+              }
+            }
+            value.to_string()
+          }
+          None => {
+            warnings.push(format!(
+              "Path variable '{}' of '{}' has no sample value, the test passes \"1\"",
+              variable_name, handler_method
+            ));
+            "\"1\"".to_string()
+          }
+        }
+      })
+      .collect();
+    let has_request_body = parameters.iter().any(|parameter_node| {
+      find_declaration_annotation_node_by_name(ts_file, *parameter_node, "RequestBody").is_some()
+    });
+    let expected_status =
+      find_declaration_annotation_node_by_name(ts_file, method_node, "ResponseStatus")
+        .and_then(|annotation| {
+          get_annotation_constant_value(ts_file, annotation, &["value", "code"])
+        })
+        .unwrap_or_else(|| "OK".to_string());
+    endpoints.push(Endpoint {
+      handler_method: handler_method.to_string(),
+      http_method,
+      path,
+      path_variable_values,
+      has_request_body,
+      expected_status: get_status_matcher(&expected_status),
+      service_calls: collect_service_calls(ts_file, method_node, services),
+    });
+  }
+  endpoints
+}
+
+fn get_test_method_name(endpoint: &Endpoint) -> String {
+  format!("{}Returns{}", endpoint.handler_method, endpoint.expected_status.trim_start_matches("is"))
+}
+
+fn build_test_method(endpoint: &Endpoint) -> String {
+  let mut request_arguments = vec![format!("\"{}\"", endpoint.path)];
+  request_arguments.extend(endpoint.path_variable_values.iter().cloned());
+  let mut request = format!(
+    "MockMvcRequestBuilders.{}({})",
+    endpoint.http_method.to_lowercase(),
+    request_arguments.join(", ")
+  );
+  if endpoint.has_request_body {
+    request.push_str(
+      "\n            .contentType(MediaType.APPLICATION_JSON)\n            .content(\"{}\")",
+    );
+  }
+  let verifications = endpoint
+    .service_calls
+    .iter()
+    .map(|call| {
+      format!(
+        "    Mockito.verify({}).{}({});\n",
+        call.field_name,
+        call.method_name,
+        vec!["ArgumentMatchers.any()"; call.arguments_count].join(", ")
+      )
+    })
+    .collect::<String>();
+  format!(
+    "  @Test\n  void {}() throws Exception {{\n    mockMvc.perform({})\n        .andExpect(MockMvcResultMatchers.status().{}());\n{}  }}",
+    get_test_method_name(endpoint),
+    request,
+    endpoint.expected_status,
+    verifications
+  )
+}
+
+fn build_test_body(services: &[MockedService], endpoints: &[Endpoint]) -> String {
+  let mut members = vec!["  @Autowired\n  private MockMvc mockMvc;".to_string()];
+  for service in services {
+    members.push(format!("  @MockBean\n  private {} {};", service.type_name, service.field_name));
+  }
+  members.extend(endpoints.iter().map(build_test_method));
+  format!("{{\n{}\n}}\n", members.join("\n\n"))
+}
+
+pub fn run(
+  cwd: &Path,
+  controller_file_b64_src: &str,
+  package_name: Option<&str>,
+  test_name: Option<&str>,
+) -> Result<GenerateControllerTestResponse, String> {
+  // Step 1: Parse controller file
+  let controller_ts_file =
+    TSFile::from_base64_source_code(controller_file_b64_src, SupportedLanguage::Java);
+  let class_node = get_public_class_node(&controller_ts_file)
+    .ok_or_else(|| "Unable to get public class node".to_string())?;
+  let is_controller = ["RestController", "Controller"].iter().any(|name| {
+    find_declaration_annotation_node_by_name(&controller_ts_file, class_node, name).is_some()
+  });
+  if !is_controller {
+    return Err("Class is not annotated with @RestController or @Controller".to_string());
+  }
+  let controller_type = get_class_declaration_name_node(&controller_ts_file, class_node)
+    .and_then(|name_node| controller_ts_file.get_text_from_node(&name_node))
+    .map(|name| name.to_string())
+    .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+  let controller_package_name = get_package_name(&controller_ts_file)
+    .ok_or_else(|| "Unable to get controller package declaration node".to_string())?;
+  // Step 2: Read the mocked services and endpoints before creating any file
+  let test_package_name = package_name.unwrap_or(&controller_package_name).to_string();
+  let test_name =
+    test_name.map(|name| name.to_string()).unwrap_or(format!("{}Test", controller_type));
+  if test_name == controller_type {
+    return Err(format!("Test name '{}' must differ from the controller name", test_name));
+  }
+  let base_path =
+    find_declaration_annotation_node_by_name(&controller_ts_file, class_node, "RequestMapping")
+      .map(|annotation| {
+        get_annotation_text_value(&controller_ts_file, annotation, &["value", "path"])
+      })
+      .unwrap_or_default();
+  let services = collect_mocked_services(&controller_ts_file, class_node);
+  let mut imports: Vec<(String, String)> = TEST_IMPORTS
+    .iter()
+    .map(|(package_name, class_name)| (package_name.to_string(), class_name.to_string()))
+    .collect();
+  let mut warnings = Vec::new();
+  let endpoints = collect_endpoints(
+    &controller_ts_file,
+    class_node,
+    &base_path,
+    &services,
+    &mut imports,
+    &mut warnings,
+  );
+  if endpoints.is_empty() {
+    return Err(format!("Controller '{}' declares no request mapping methods", controller_type));
+  }
+  if endpoints.iter().any(|endpoint| endpoint.has_request_body) {
+    imports.push(("org.springframework.http".to_string(), "MediaType".to_string()));
+  }
+  if endpoints.iter().any(|endpoint| !endpoint.service_calls.is_empty()) {
+    imports.push(("org.mockito".to_string(), "Mockito".to_string()));
+  }
+  if endpoints.iter().flat_map(|e| &e.service_calls).any(|call| call.arguments_count > 0) {
+    imports.push(("org.mockito".to_string(), "ArgumentMatchers".to_string()));
+  }
+  if controller_package_name != test_package_name {
+    imports.push((controller_package_name.clone(), controller_type.clone()));
+  }
+  for service in &services {
+    let service_package_name = find_imported_class_package(&controller_ts_file, &service.type_name)
+      .or_else(|| Some(controller_package_name.clone()))
+      .filter(|service_package_name| *service_package_name != test_package_name);
+    if let Some(service_package_name) = service_package_name {
+      imports.push((service_package_name, service.type_name.clone()));
+    }
+  }
+  // Step 3: Create the test class under the test sources
+  let file_response = create_java_file_service::run(
+    cwd,
+    &test_package_name,
+    &test_name,
+    &JavaFileType::Class,
+    &JavaSourceDirectoryType::Test,
+    None,
+    None,
+  )?;
+  let test_path = PathBuf::from(&file_response.file_path);
+  let mut test_ts_file = TSFile::from_file(test_path.as_path(), cwd, SupportedLanguage::Java)
+    .map_err(|e| format!("Unable to parse newly created test file: {}", e))?;
+  // Step 4: Write the test members, the @WebMvcTest annotation and the imports
+  let (class_start_byte, body_start_byte, body_end_byte) = {
+    let test_class_node = get_public_class_node(&test_ts_file)
+      .ok_or_else(|| "Unable to get public class node of the test".to_string())?;
+    let body_node = test_class_node
+      .child_by_field_name("body")
+      .ok_or_else(|| "Unable to get class body node".to_string())?;
+    (test_class_node.start_byte(), body_node.start_byte(), body_node.end_byte())
+  };
+  test_ts_file.replace_text_by_range(
+    body_start_byte,
+    body_end_byte,
+    &build_test_body(&services, &endpoints),
+  );
+  test_ts_file.replace_text_by_range(
+    class_start_byte,
+    class_start_byte,
+    &format!("@WebMvcTest({}.class)\n", controller_type),
+  );
+  for (import_package_name, import_class) in &imports {
+    add_import(
+      &mut test_ts_file,
+      &ImportInsertionPosition::AfterLastImport,
+      import_package_name,
+      import_class,
+    );
+  }
+  // Step 5: Save test file
+  test_ts_file.save().map_err(|e| format!("Unable to save test file: {}", e))?;
+  Ok(GenerateControllerTestResponse {
+    test: FileResponse {
+      file_type: file_response.file_type,
+      file_package_name: file_response.file_package_name,
+      file_path: file_response.file_path,
+      content_hash: Some(get_content_hash(&test_ts_file.source_code)),
+      warnings: file_response.warnings,
+    },
+    endpoints: endpoints
+      .iter()
+      .map(|endpoint| ControllerTestEndpointResponse {
+        handler_method: endpoint.handler_method.clone(),
+        http_method: endpoint.http_method.clone(),
+        path: endpoint.path.clone(),
+        test_method: get_test_method_name(endpoint),
+        expected_status: endpoint.expected_status.clone(),
+      })
+      .collect(),
+    mocked_services: services.into_iter().map(|service| service.type_name).collect(),
+    warnings,
+  })
+}
@@ -0,0 +1,257 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use crate::commands::java::responses::generate_entity_diagram_response::GenerateEntityDiagramResponse;
+use crate::commands::java::services::generate_schema_service::{
+  ProjectMappings, load_project_mappings,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::get_simple_type_name;
+use crate::commands::java::treesitter::types::entity_mapping::{
+  EntityMapping, MappedClassKind, RelationshipFieldMapping,
+};
+use crate::commands::java::treesitter::types::entity_side::EntitySide;
+use crate::common::utils::path_security_util::PathSecurityValidator;
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+/// Checks whether the class lives in `package_name` or one of its subpackages.
+fn is_in_package(mapping: &EntityMapping, package_name: Option<&str>) -> bool {
+  let Some(package_name) = package_name else {
+    return true;
+  };
+  mapping.package_name.as_deref().is_some_and(|class_package| {
+    class_package == package_name
+      || class_package.strip_prefix(package_name).is_some_and(|rest| rest.starts_with('.'))
+  })
+}
+
+/// Entities of the package, plus the mapped superclasses they inherit their fields from.
+fn collect_classes<'a>(
+  project: &'a ProjectMappings,
+  package_name: Option<&str>,
+) -> BTreeMap<String, &'a EntityMapping> {
+  let mut classes = BTreeMap::new();
+  for entity in project.classes.values() {
+    if entity.kind != MappedClassKind::Entity || !is_in_package(entity, package_name) {
+      continue;
+    }
+    classes.insert(entity.class_name.clone(), entity);
+    let mut superclass_name = entity.superclass_name.as_deref().map(get_simple_type_name);
+    while let Some(superclass) = superclass_name
+      .and_then(|name| project.classes.get(&name))
+      .filter(|superclass| superclass.kind == MappedClassKind::MappedSuperclass)
+    {
+      if classes.insert(superclass.class_name.clone(), superclass).is_some() {
+        break;
+      }
+      superclass_name = superclass.superclass_name.as_deref().map(get_simple_type_name);
+    }
+  }
+  classes
+}
+
+fn render_class(mapping: &EntityMapping) -> Vec<String> {
+  let mut lines = vec![match mapping.kind {
+    MappedClassKind::MappedSuperclass => {
+      format!("abstract class {} <<MappedSuperclass>> {{", mapping.class_name)
+    }
+    _ => format!("entity {} {{", mapping.class_name),
+  }];
+  let mut id_lines = Vec::new();
+  let mut member_lines = Vec::new();
+  for column in &mapping.columns {
+    match (column.is_id, column.is_version) {
+      (true, _) => {
+        id_lines.push(format!("  * {} : {} <<id>>", column.field_name, column.field_type))
+      }
+      (false, true) => {
+        member_lines.push(format!("  {} : {} <<version>>", column.field_name, column.field_type))
+      }
+      (false, false) => member_lines.push(format!(
+        "  {}{} : {}",
+        if column.nullable { "" } else { "* " },
+        column.field_name,
+        column.field_type
+      )),
+    }
+  }
+  for embedded in &mapping.embedded {
+    match embedded.is_id {
+      true => id_lines.push(format!(
+        "  * {} : {} <<embedded id>>",
+        embedded.field_name, embedded.embeddable_type
+      )),
+      false => member_lines
+        .push(format!("  {} : {} <<embedded>>", embedded.field_name, embedded.embeddable_type)),
+    }
+  }
+  let has_separator = !id_lines.is_empty() && !member_lines.is_empty();
+  lines.extend(id_lines);
+  if has_separator {
+    lines.push("  --".to_string());
+  }
+  lines.extend(member_lines);
+  lines.push("}".to_string());
+  lines
+}
+
+/// Relationship of `target` whose `mappedBy` points back at `field_name` of `source_class_name`.
+fn find_counterpart<'a>(
+  target: &'a EntityMapping,
+  source_class_name: &str,
+  field_name: &str,
+) -> Option<&'a RelationshipFieldMapping> {
+  target.relationships.iter().find(|relationship| {
+    relationship.target_type == source_class_name
+      && relationship.mapped_by.as_deref() == Some(field_name)
+  })
+}
+
+/// Renders an edge per relationship, directed from the declaring entity to its target. The two
+/// sides of a bidirectional relationship are merged into a single edge drawn from the owning side.
+fn render_relationships(
+  project: &ProjectMappings,
+  classes: &BTreeMap<String, &EntityMapping>,
+) -> Vec<String> {
+  let mut lines = Vec::new();
+  for source in classes.values() {
+    for relationship in &source.relationships {
+      let (source_multiplicity, target_multiplicity) = relationship.kind.get_multiplicities();
+      let target = project.classes.get(&relationship.target_type);
+      let edge = match (&relationship.side, &relationship.mapped_by) {
+        (EntitySide::Inverse, Some(mapped_by)) => {
+          let owning_side = target.and_then(|target| {
+            target.relationships.iter().find(|owning| {
+              owning.field_name == *mapped_by && owning.target_type == source.class_name
+            })
+          });
+          // Drawn together with the owning side
+          if owning_side.is_some() && classes.contains_key(&relationship.target_type) {
+            continue;
+          }
+          format!(
+            "{} \"{}\" --> \"{}\" {} : {} (mappedBy = \"{}\")",
+            source.class_name,
+            source_multiplicity,
+            target_multiplicity,
+            relationship.target_type,
+            relationship.field_name,
+            mapped_by
+          )
+        }
+        _ => match target
+          .and_then(|target| find_counterpart(target, &source.class_name, &relationship.field_name))
+        {
+          Some(inverse) => format!(
+            "{} \"{}\" <--> \"{}\" {} : {} / {} (mappedBy = \"{}\")",
+            source.class_name,
+            source_multiplicity,
+            target_multiplicity,
+            relationship.target_type,
+            relationship.field_name,
+            inverse.field_name,
+            relationship.field_name
+          ),
+          None => format!(
+            "{} \"{}\" --> \"{}\" {} : {}",
+            source.class_name,
+            source_multiplicity,
+            target_multiplicity,
+            relationship.target_type,
+            relationship.field_name
+          ),
+        },
+      };
+      lines.push(edge);
+    }
+  }
+  lines
+}
+
+fn render_diagram(
+  classes: &BTreeMap<String, &EntityMapping>,
+  relationship_lines: &[String],
+) -> String {
+  let mut packages: BTreeMap<Option<&str>, Vec<&EntityMapping>> = BTreeMap::new();
+  for mapping in classes.values() {
+    packages.entry(mapping.package_name.as_deref()).or_default().push(mapping);
+  }
+  let mut lines = vec!["@startuml".to_string(), "hide empty members".to_string()];
+  for (package_name, mappings) in packages {
+    lines.push(String::new());
+    let indentation = match package_name {
+      Some(package_name) => {
+        lines.push(format!("package {} {{", package_name));
+        "  "
+      }
+      None => "",
+    };
+    for (index, mapping) in mappings.iter().enumerate() {
+      if index > 0 {
+        lines.push(String::new());
+      }
+      lines
+        .extend(render_class(mapping).into_iter().map(|line| format!("{}{}", indentation, line)));
+    }
+    if package_name.is_some() {
+      lines.push("}".to_string());
+    }
+  }
+  let inheritance_lines: BTreeSet<String> = classes
+    .values()
+    .filter_map(|mapping| {
+      let superclass_name = get_simple_type_name(mapping.superclass_name.as_deref()?);
+      classes
+        .contains_key(&superclass_name)
+        .then(|| format!("{} <|-- {}", superclass_name, mapping.class_name))
+    })
+    .collect();
+  if !inheritance_lines.is_empty() || !relationship_lines.is_empty() {
+    lines.push(String::new());
+  }
+  lines.extend(inheritance_lines);
+  lines.extend(relationship_lines.iter().cloned());
+  lines.push("@enduml".to_string());
+  lines.join("\n") + "\n"
+}
+
+pub fn run(
+  cwd: &Path,
+  package_name: Option<&str>,
+  output_file_path: Option<&Path>,
+  deadline: &ScanDeadline,
+) -> Result<GenerateEntityDiagramResponse, String> {
+  // Step 1: Validate the output file is within the working directory
+  let output_file_path = output_file_path
+    .map(|path| PathSecurityValidator::new(cwd)?.validate_path_containment(path))
+    .transpose()?;
+  // Step 2: Parse every file in parallel and collect the entities of the package
+  let project = load_project_mappings(cwd, deadline);
+  let classes = collect_classes(&project, package_name);
+  // Step 3: Render the classes, inheritance and relationship edges
+  let relationship_lines = render_relationships(&project, &classes);
+  let diagram = render_diagram(&classes, &relationship_lines);
+  // Step 4: Write the diagram when an output file was requested
+  if let Some(output_file_path) = &output_file_path {
+    if let Some(parent) = output_file_path.parent() {
+      fs::create_dir_all(parent).map_err(|e| format!("Unable to create directory: {}", e))?;
+    }
+    fs::write(output_file_path, &diagram)
+      .map_err(|e| format!("Unable to write diagram file: {}", e))?;
+  }
+  // Step 5: Build and return response
+  let entities: Vec<String> = classes
+    .values()
+    .filter(|mapping| mapping.kind == MappedClassKind::Entity)
+    .map(|mapping| mapping.class_name.clone())
+    .collect();
+  Ok(GenerateEntityDiagramResponse {
+    diagram,
+    entities_count: entities.len(),
+    entities,
+    relationships_count: relationship_lines.len(),
+    output_file_path: output_file_path.map(|path| path.display().to_string()),
+    truncated: deadline.is_truncated(),
+    processed_files_count: deadline.processed_files_count(),
+  })
+}
@@ -0,0 +1,265 @@
+use std::path::{Path, PathBuf};
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::file_response::FileResponse;
+use crate::commands::java::responses::generate_entity_listener_response::{
+  EntityListenerCallbackResponse, GenerateEntityListenerResponse,
+};
+use crate::commands::java::responses::multi_file_response::MultiFileResponse;
+use crate::commands::java::services::create_java_file_service;
+use crate::commands::java::treesitter::services::annotation_service::{
+  add_annotation, find_annotation_node_by_name, find_declaration_annotation_node_by_name,
+  find_direct_annotation_value_node_by_key, set_annotation_argument,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_required_public_class_node,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, get_persistence_package,
+};
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::java_file_type::JavaFileType;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::commands::java::treesitter::types::lifecycle_callback_type::LifecycleCallbackType;
+use crate::commands::java::validators::java_class_name_validator::validate_java_class_name;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::case_util;
+use crate::common::utils::hash_util::get_content_hash;
+
+/// Class literals (`AuditListener.class`) listed by an `@EntityListeners` value, which may be a
+/// single class literal or an array of them.
+fn get_listed_class_literals(ts_file: &TSFile, entity_listeners_node: Node) -> Vec<String> {
+  let Some(value_node) =
+    find_direct_annotation_value_node_by_key(ts_file, entity_listeners_node, "value")
+  else {
+    return Vec::new();
+  };
+  let literal_nodes = if value_node.kind() == "element_value_array_initializer" {
+    let mut cursor = value_node.walk();
+    value_node.named_children(&mut cursor).filter(|child| child.kind() != "comment").collect()
+  } else {
+    vec![value_node]
+  };
+  literal_nodes
+    .iter()
+    .filter_map(|node| ts_file.get_text_from_node(node))
+    .map(|text| text.to_string())
+    .collect()
+}
+
+fn is_listener_literal(literal: &str, listener_name: &str) -> bool {
+  let Some(type_name) = literal.trim().strip_suffix(".class") else {
+    return false;
+  };
+  type_name.trim_end().rsplit('.').next() == Some(listener_name)
+}
+
+/// Registers the listener on the entity, merging it into an existing `@EntityListeners`.
+///
+/// # Returns
+/// The resulting annotation text and whether a new annotation was added
+fn register_listener(
+  entity_ts_file: &mut TSFile,
+  listener_name: &str,
+) -> Result<(String, bool), String> {
+  let listener_literal = format!("{}.class", listener_name);
+  let (class_start_byte, existing_annotation) = {
+    let class_node = get_required_public_class_node(entity_ts_file)?;
+    let existing_annotation =
+      find_declaration_annotation_node_by_name(entity_ts_file, class_node, "EntityListeners")
+        .map(|node| (node.start_byte(), get_listed_class_literals(entity_ts_file, node)));
+    (class_node.start_byte(), existing_annotation)
+  };
+  let Some((annotation_start_byte, mut literals)) = existing_annotation else {
+    let annotation = format!("@EntityListeners({})", listener_literal);
+    add_annotation(
+      entity_ts_file,
+      class_start_byte,
+      &AnnotationInsertionPosition::AboveScopeDeclaration,
+      &annotation,
+    )
+    .ok_or_else(|| "Unable to add @EntityListeners annotation".to_string())?;
+    return Ok((annotation, true));
+  };
+  if !literals.iter().any(|literal| is_listener_literal(literal, listener_name)) {
+    literals.push(listener_literal);
+    let value = format!("{{{}}}", literals.join(", "));
+    if !set_annotation_argument(entity_ts_file, annotation_start_byte, "value", &value) {
+      return Err("Unable to update @EntityListeners annotation".to_string());
+    }
+  }
+  let annotation = {
+    let class_node = get_required_public_class_node(entity_ts_file)?;
+    find_declaration_annotation_node_by_name(entity_ts_file, class_node, "EntityListeners")
+      .and_then(|node| entity_ts_file.get_text_from_node(&node))
+      .map(|text| text.to_string())
+      .ok_or_else(|| "Unable to get @EntityListeners annotation".to_string())?
+  };
+  Ok((annotation, false))
+}
+
+fn add_callback_methods(
+  listener_ts_file: &mut TSFile,
+  callbacks: &[LifecycleCallbackType],
+  parameter: &str,
+) -> Result<(), String> {
+  let (body_start_byte, body_end_byte) = {
+    let class_node = get_required_public_class_node(listener_ts_file)?;
+    let body_node = class_node
+      .child_by_field_name("body")
+      .ok_or_else(|| "Unable to get class body node".to_string())?;
+    (body_node.start_byte(), body_node.end_byte())
+  };
+  let methods = callbacks
+    .iter()
+    .map(|callback| {
+      format!(
+        "  @{}\n  public void {}({}) {{}}",
+        callback.annotation_name(),
+        callback.method_name(),
+        parameter
+      )
+    })
+    .collect::<Vec<_>>()
+    .join("\n\n");
+  listener_ts_file.replace_text_by_range(
+    body_start_byte,
+    body_end_byte,
+    &format!("{{\n{}\n}}\n", methods),
+  );
+  Ok(())
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  callbacks: &[LifecycleCallbackType],
+  package_name: Option<&str>,
+  listener_name: Option<&str>,
+  entity_parameter: bool,
+) -> Result<GenerateEntityListenerResponse, String> {
+  // Step 1: Parse entity file
+  let mut entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  let class_node = get_required_public_class_node(&entity_ts_file)?;
+  if find_annotation_node_by_name(&entity_ts_file, class_node, "Entity").is_none()
+    && find_annotation_node_by_name(&entity_ts_file, class_node, "MappedSuperclass").is_none()
+  {
+    return Err("Class is not annotated with @Entity or @MappedSuperclass".to_string());
+  }
+  let entity_type = get_class_declaration_name_node(&entity_ts_file, class_node)
+    .and_then(|name_node| entity_ts_file.get_text_from_node(&name_node))
+    .map(|name| name.to_string())
+    .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+  let entity_package_name = get_package_name(&entity_ts_file)
+    .ok_or_else(|| "Unable to get JPA Entity package declaration node".to_string())?;
+  // Step 2: Validate the listener name and callbacks before creating any file
+  let listener_name =
+    listener_name.map(|name| name.to_string()).unwrap_or(format!("{}Listener", entity_type));
+  validate_java_class_name(&listener_name)?;
+  if listener_name == entity_type {
+    return Err(format!("Listener name '{}' must differ from the entity name", listener_name));
+  }
+  let mut unique_callbacks: Vec<LifecycleCallbackType> = Vec::new();
+  for callback in callbacks {
+    if !unique_callbacks.contains(callback) {
+      unique_callbacks.push(callback.clone());
+    }
+  }
+  if unique_callbacks.is_empty() {
+    return Err("At least one lifecycle callback must be selected".to_string());
+  }
+  let listener_package_name = package_name.unwrap_or(&entity_package_name).to_string();
+  let is_same_package = listener_package_name == entity_package_name;
+  // Step 3: Register the listener on the entity
+  let (annotation, annotation_added) = register_listener(&mut entity_ts_file, &listener_name)?;
+  let persistence_package = get_persistence_package(&entity_ts_file);
+  add_import(
+    &mut entity_ts_file,
+    &ImportInsertionPosition::BeforeFirstImport,
+    persistence_package,
+    "EntityListeners",
+  );
+  if !is_same_package {
+    add_import(
+      &mut entity_ts_file,
+      &ImportInsertionPosition::AfterLastImport,
+      &listener_package_name,
+      &listener_name,
+    );
+  }
+  // Step 4: Create the listener class file
+  let file_response = create_java_file_service::run(
+    cwd,
+    &listener_package_name,
+    &listener_name,
+    &JavaFileType::Class,
+    &JavaSourceDirectoryType::Main,
+    None,
+    None,
+  )?;
+  let listener_path = PathBuf::from(&file_response.file_path);
+  let mut listener_ts_file =
+    TSFile::from_file(listener_path.as_path(), cwd, SupportedLanguage::Java)
+      .map_err(|e| format!("Unable to parse newly created listener file: {}", e))?;
+  // Step 5: Add the callback methods and their imports
+  let parameter = match entity_parameter {
+    true => format!("{} {}", entity_type, case_util::to_camel_case(&entity_type)),
+    false => "Object entity".to_string(),
+  };
+  add_callback_methods(&mut listener_ts_file, &unique_callbacks, &parameter)?;
+  for callback in &unique_callbacks {
+    add_import(
+      &mut listener_ts_file,
+      &ImportInsertionPosition::AfterLastImport,
+      persistence_package,
+      callback.annotation_name(),
+    );
+  }
+  if entity_parameter && !is_same_package {
+    add_import(
+      &mut listener_ts_file,
+      &ImportInsertionPosition::AfterLastImport,
+      &entity_package_name,
+      &entity_type,
+    );
+  }
+  // Step 6: Save both files
+  listener_ts_file.save().map_err(|e| format!("Unable to save listener file: {}", e))?;
+  entity_ts_file
+    .save_to_existing_file(entity_file_path, cwd)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 7: Build and return response
+  Ok(GenerateEntityListenerResponse {
+    written_files: MultiFileResponse::new(vec![
+      FileResponse {
+        file_type: file_response.file_type,
+        file_package_name: file_response.file_package_name,
+        file_path: file_response.file_path,
+        content_hash: Some(get_content_hash(&listener_ts_file.source_code)),
+        warnings: file_response.warnings,
+      },
+      FileResponse {
+        file_type: entity_type,
+        file_package_name: entity_package_name,
+        file_path: entity_file_path.display().to_string(),
+        content_hash: Some(get_content_hash(&entity_ts_file.source_code)),
+        warnings: Vec::new(),
+      },
+    ]),
+    callbacks: unique_callbacks
+      .iter()
+      .map(|callback| EntityListenerCallbackResponse {
+        annotation: format!("@{}", callback.annotation_name()),
+        method_name: callback.method_name().to_string(),
+      })
+      .collect(),
+    annotation,
+    annotation_added,
+  })
+}
@@ -0,0 +1,175 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::generate_factory_method_response::{
+  FactoryParameterResponse, GenerateFactoryMethodResponse,
+};
+use crate::commands::java::treesitter::services::annotation_service::{
+  find_annotation_node_by_name, find_declaration_annotation_node_by_name,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::constructor_declaration_service::{
+  AddConstructorDeclarationParams, add_constructor_declaration, find_no_arg_constructor_node,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::{
+  get_own_field_declaration_nodes, is_persistent_field, is_required_field,
+};
+use crate::commands::java::treesitter::services::method_declaration_service::{
+  add_method_declaration, find_method_declaration_node_by_name,
+};
+use crate::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::case_util::{self, CaseType};
+
+/// Annotations of the classes JPA instantiates reflectively, which need a non-private no-arg
+/// constructor.
+const JPA_ANNOTATIONS: [&str; 3] = ["Entity", "MappedSuperclass", "Embeddable"];
+
+fn validate_method_name(method_name: &str) -> Result<(), String> {
+  let starts_correctly =
+    method_name.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$');
+  if !starts_correctly || !method_name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+  {
+    return Err(format!("'{}' is not a valid method name", method_name));
+  }
+  Ok(())
+}
+
+/// Fields the database assigns (generated ids, versions) are never factory parameters.
+fn is_assigned_by_persistence(ts_file: &TSFile, field_node: Node) -> bool {
+  let find = |name: &str| find_declaration_annotation_node_by_name(ts_file, field_node, name);
+  find("Version").is_some() || (find("Id").is_some() && find("GeneratedValue").is_some())
+}
+
+/// Required fields of the class as (type, name) pairs, in declaration order.
+fn get_required_fields(
+  ts_file: &TSFile,
+  class_node: Node,
+) -> Result<Vec<(String, String)>, String> {
+  let mut required_fields = Vec::new();
+  for field_node in get_own_field_declaration_nodes(ts_file, class_node) {
+    if !is_persistent_field(ts_file, field_node)
+      || is_assigned_by_persistence(ts_file, field_node)
+      || !is_required_field(ts_file, field_node)
+    {
+      continue;
+    }
+    let field_type = field_node
+      .child_by_field_name("type")
+      .and_then(|type_node| ts_file.get_text_from_node(&type_node))
+      .ok_or_else(|| "Unable to get the type of a required field".to_string())?;
+    let mut cursor = field_node.walk();
+    for declarator in field_node.children_by_field_name("declarator", &mut cursor) {
+      let field_name = declarator
+        .child_by_field_name("name")
+        .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+        .ok_or_else(|| "Unable to get the name of a required field".to_string())?;
+      required_fields.push((field_type.to_string(), field_name.to_string()));
+    }
+  }
+  Ok(required_fields)
+}
+
+fn build_factory_method_lines(
+  signature: &str,
+  class_name: &str,
+  required_fields: &[(String, String)],
+) -> Vec<String> {
+  let mut instance_name = case_util::auto_convert_case(class_name, CaseType::Camel);
+  if required_fields.iter().any(|(_, field_name)| *field_name == instance_name) {
+    instance_name = "instance".to_string();
+  }
+  let mut lines = vec![
+    format!("{} {{", signature),
+    format!("  {} {} = new {}();", class_name, instance_name, class_name),
+  ];
+  for (_, field_name) in required_fields {
+    lines.push(format!("  {}.{} = {};", instance_name, field_name, field_name));
+  }
+  lines.push(format!("  return {};", instance_name));
+  lines.push("}".to_string());
+  lines
+}
+
+pub fn run(
+  cwd: &Path,
+  b64_source_code: &str,
+  file_path: &Path,
+  method_name: &str,
+) -> Result<GenerateFactoryMethodResponse, String> {
+  // Step 1: Validate the method name and parse the file
+  validate_method_name(method_name)?;
+  let mut ts_file = TSFile::from_base64_source_code(b64_source_code, SupportedLanguage::Java);
+  // Step 2: Collect the required fields and check the existing constructors
+  let (class_name, class_start_byte, required_fields, constructor_visibility) = {
+    let class_node = get_public_class_node(&ts_file)
+      .ok_or_else(|| "Unable to get public class node".to_string())?;
+    let class_name = get_class_declaration_name_node(&ts_file, class_node)
+      .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+      .map(|name| name.to_string())
+      .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+    if find_method_declaration_node_by_name(&ts_file, class_node, method_name).is_some() {
+      return Err(format!("Class '{}' already declares a method '{}'", class_name, method_name));
+    }
+    let required_fields = get_required_fields(&ts_file, class_node)?;
+    let has_no_arg_constructor = find_no_arg_constructor_node(&ts_file, class_node).is_some()
+      || find_annotation_node_by_name(&ts_file, class_node, "NoArgsConstructor").is_some();
+    // JPA needs at least a protected constructor, other classes hide it behind the factory
+    let constructor_visibility = (!has_no_arg_constructor).then(|| {
+      let is_jpa_class = JPA_ANNOTATIONS
+        .iter()
+        .any(|annotation| find_annotation_node_by_name(&ts_file, class_node, annotation).is_some());
+      match is_jpa_class {
+        true => JavaVisibilityModifier::Protected,
+        false => JavaVisibilityModifier::Private,
+      }
+    });
+    (class_name, class_node.start_byte(), required_fields, constructor_visibility)
+  };
+  // Step 3: Add the no-arg constructor the factory calls when missing
+  if let Some(visibility_modifier) = constructor_visibility {
+    let params = AddConstructorDeclarationParams {
+      visibility_modifier,
+      parameters: Vec::new(),
+      body_statements: Vec::new(),
+    };
+    add_constructor_declaration(&mut ts_file, class_start_byte, params)
+      .ok_or_else(|| "Unable to add no-arg constructor".to_string())?;
+  }
+  // Step 4: Add the factory method at the end of the class body
+  let parameters = required_fields
+    .iter()
+    .map(|(field_type, field_name)| format!("{} {}", field_type, field_name))
+    .collect::<Vec<_>>()
+    .join(", ");
+  let signature = format!("public static {} {}({})", class_name, method_name, parameters);
+  let lines = build_factory_method_lines(&signature, &class_name, &required_fields);
+  let class_start_byte = get_public_class_node(&ts_file)
+    .ok_or_else(|| "Unable to get public class node".to_string())?
+    .start_byte();
+  add_method_declaration(&mut ts_file, class_start_byte, method_name, lines)
+    .ok_or_else(|| "Unable to add the factory method".to_string())?;
+  // Step 5: Save file with working directory validation
+  ts_file
+    .save_to_existing_file(file_path, cwd)
+    .map_err(|e| format!("Unable to save file: {}", e))?;
+  // Step 6: Build and return response
+  Ok(GenerateFactoryMethodResponse {
+    file_path: file_path.display().to_string(),
+    class_name,
+    method_name: method_name.to_string(),
+    signature,
+    parameters: required_fields
+      .into_iter()
+      .map(|(parameter_type, parameter_name)| FactoryParameterResponse {
+        parameter_type,
+        parameter_name,
+      })
+      .collect(),
+    constructor_added: constructor_visibility.is_some(),
+  })
+}
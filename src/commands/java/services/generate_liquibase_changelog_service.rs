@@ -0,0 +1,226 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::java::responses::generate_liquibase_changelog_response::GenerateLiquibaseChangelogResponse;
+use crate::commands::java::services::generate_schema_service::{
+  ForeignKeyDefinition, ResolvedTable, build_foreign_keys, get_join_column_constraint_name,
+  load_project_mappings, map_column_types, resolve_table,
+};
+use crate::commands::java::services::get_jpa_entity_info_service::create_ts_file;
+use crate::commands::java::treesitter::services::entity_mapping_service::get_entity_mapping;
+use crate::commands::java::treesitter::types::entity_mapping::MappedClassKind;
+use crate::commands::java::treesitter::types::sql_dialect::SqlDialect;
+use crate::common::utils::path_security_util::PathSecurityValidator;
+use crate::common::utils::scan_deadline::ScanDeadline;
+use crate::common::utils::time_util::get_utc_timestamp;
+
+const DEFAULT_AUTHOR: &str = "syntaxpresso";
+
+/// Quotes a YAML scalar unless it only holds characters that can't be misread.
+fn to_yaml_scalar(value: &str) -> String {
+  let is_plain = !value.is_empty()
+    && value.chars().next().is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+    && value.chars().all(|c| c.is_ascii_alphanumeric() || "_-./(), ".contains(c))
+    && !value.ends_with(' ');
+  if is_plain { value.to_string() } else { format!("'{}'", value.replace('\'', "''")) }
+}
+
+fn split_qualified_table_name(qualified_table_name: &str) -> (Option<&str>, &str) {
+  match qualified_table_name.rsplit_once('.') {
+    Some((schema, table)) => (Some(schema), table),
+    None => (None, qualified_table_name),
+  }
+}
+
+fn render_column(lines: &mut Vec<String>, name: &str, column_type: &str, attributes: &[String]) {
+  lines.push("              - column:".to_string());
+  lines.push(format!("                  name: {}", to_yaml_scalar(name)));
+  lines.push(format!("                  type: {}", to_yaml_scalar(column_type)));
+  for attribute in attributes {
+    lines.push(format!("                  {}", attribute));
+  }
+}
+
+fn get_constraint_attributes(primary_key: bool, nullable: bool, unique: bool) -> Vec<String> {
+  let mut constraints = Vec::new();
+  if primary_key {
+    constraints.push("primaryKey: true".to_string());
+  }
+  if !nullable {
+    constraints.push("nullable: false".to_string());
+  }
+  if unique && !primary_key {
+    constraints.push("unique: true".to_string());
+  }
+  if constraints.is_empty() {
+    return Vec::new();
+  }
+  let mut attributes = vec!["constraints:".to_string()];
+  attributes.extend(constraints.into_iter().map(|constraint| format!("  {}", constraint)));
+  attributes
+}
+
+fn render_changelog(
+  changeset_id: &str,
+  author: &str,
+  table: &ResolvedTable,
+  column_types: &[String],
+  foreign_keys: &[ForeignKeyDefinition],
+) -> String {
+  let qualified_table_name = table.entity.qualified_table_name();
+  let (schema, table_name) = split_qualified_table_name(&qualified_table_name);
+  let mut lines = vec![
+    "databaseChangeLog:".to_string(),
+    "  - changeSet:".to_string(),
+    format!("      id: {}", to_yaml_scalar(changeset_id)),
+    format!("      author: {}", to_yaml_scalar(author)),
+    "      changes:".to_string(),
+    "        - createTable:".to_string(),
+  ];
+  if let Some(schema) = schema {
+    lines.push(format!("            schemaName: {}", to_yaml_scalar(schema)));
+  }
+  lines.push(format!("            tableName: {}", to_yaml_scalar(table_name)));
+  lines.push("            columns:".to_string());
+  for (column, column_type) in table.columns.iter().zip(column_types) {
+    let mut attributes = Vec::new();
+    if column.is_identity {
+      attributes.push("autoIncrement: true".to_string());
+    }
+    attributes.extend(get_constraint_attributes(column.is_id, column.nullable, column.unique));
+    render_column(&mut lines, &column.column_name, column_type, &attributes);
+  }
+  for foreign_key in foreign_keys {
+    let join_column = &foreign_key.join_column;
+    let attributes = get_constraint_attributes(false, join_column.nullable, join_column.unique);
+    render_column(&mut lines, &join_column.column_name, &foreign_key.column_type, &attributes);
+  }
+  for foreign_key in foreign_keys {
+    let Some(constraint_name) =
+      get_join_column_constraint_name(table_name, &foreign_key.join_column)
+    else {
+      continue;
+    };
+    let (referenced_schema, referenced_table) =
+      split_qualified_table_name(&foreign_key.referenced_table);
+    lines.push("        - addForeignKeyConstraint:".to_string());
+    lines.push(format!("            constraintName: {}", to_yaml_scalar(&constraint_name)));
+    if let Some(schema) = schema {
+      lines.push(format!("            baseTableSchemaName: {}", to_yaml_scalar(schema)));
+    }
+    lines.push(format!("            baseTableName: {}", to_yaml_scalar(table_name)));
+    lines.push(format!(
+      "            baseColumnNames: {}",
+      to_yaml_scalar(&foreign_key.join_column.column_name)
+    ));
+    if let Some(referenced_schema) = referenced_schema {
+      lines.push(format!(
+        "            referencedTableSchemaName: {}",
+        to_yaml_scalar(referenced_schema)
+      ));
+    }
+    lines.push(format!("            referencedTableName: {}", to_yaml_scalar(referenced_table)));
+    lines.push(format!(
+      "            referencedColumnNames: {}",
+      to_yaml_scalar(&foreign_key.referenced_column)
+    ));
+  }
+  lines.join("\n") + "\n"
+}
+
+/// Adds an include of the changeset to the master changelog, creating the master changelog when
+/// it doesn't exist yet.
+fn include_in_master_changelog(
+  master_changelog_path: &Path,
+  changeset_path: &Path,
+  cwd: &Path,
+) -> Result<(), String> {
+  let master_directory = master_changelog_path.parent().unwrap_or(cwd);
+  let include = match changeset_path.strip_prefix(master_directory) {
+    Ok(relative_path) => format!(
+      "  - include:\n      file: {}\n      relativeToChangelogFile: true\n",
+      to_yaml_scalar(&relative_path.to_string_lossy())
+    ),
+    Err(_) => {
+      let project_path = changeset_path.strip_prefix(cwd).unwrap_or(changeset_path);
+      format!("  - include:\n      file: {}\n", to_yaml_scalar(&project_path.to_string_lossy()))
+    }
+  };
+  let content = if master_changelog_path.exists() {
+    let existing = fs::read_to_string(master_changelog_path)
+      .map_err(|e| format!("Unable to read master changelog: {}", e))?;
+    if !existing.lines().any(|line| line.trim_end() == "databaseChangeLog:") {
+      return Err("Master changelog has no 'databaseChangeLog:' list to include into".to_string());
+    }
+    format!("{}\n{}", existing.trim_end(), include)
+  } else {
+    format!("databaseChangeLog:\n{}", include)
+  };
+  fs::write(master_changelog_path, content)
+    .map_err(|e| format!("Unable to write master changelog: {}", e))
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  dialect: &SqlDialect,
+  changelog_directory: &Path,
+  author: Option<&str>,
+  master_changelog_file_path: Option<&Path>,
+) -> Result<GenerateLiquibaseChangelogResponse, String> {
+  // Step 1: Validate the changelog locations are within the working directory
+  let validator = PathSecurityValidator::new(cwd)?;
+  let changelog_directory = validator.validate_directory_creation(changelog_directory)?;
+  let master_changelog_path: Option<PathBuf> =
+    master_changelog_file_path.map(|path| validator.validate_path_containment(path)).transpose()?;
+  // Step 2: Create TSFile and extract the entity mapping
+  let ts_file = create_ts_file(entity_file_path, b64_source_code, cwd)?;
+  let entity = get_entity_mapping(&ts_file)
+    .filter(|mapping| mapping.kind == MappedClassKind::Entity)
+    .ok_or_else(|| "Class is not annotated with @Entity".to_string())?;
+  // Step 3: Resolve the table and its foreign keys the same way the DDL generator does
+  let mut project = load_project_mappings(cwd, &ScanDeadline::unbounded());
+  project.classes.insert(entity.class_name.clone(), entity.clone());
+  let unresolved = |reasons: Vec<String>| {
+    format!("Unable to map '{}' to a table: {}", entity.class_name, reasons.join("; "))
+  };
+  let table = resolve_table(&project, &entity).map_err(unresolved)?;
+  let column_types = map_column_types(dialect, &table.columns).map_err(unresolved)?;
+  let targets: BTreeMap<String, ResolvedTable> = table
+    .join_columns
+    .iter()
+    .filter_map(|join_column| project.classes.get(&join_column.target_type))
+    .filter(|target| target.kind == MappedClassKind::Entity)
+    .filter_map(|target| resolve_table(&project, target).ok())
+    .map(|target| (target.entity.class_name.clone(), target))
+    .collect();
+  let foreign_keys = build_foreign_keys(dialect, &table, &targets).map_err(unresolved)?;
+  // Step 4: Write the changeset file
+  let table_name = entity.table_name.clone();
+  let changeset_id = format!("{}-create-{}", get_utc_timestamp(), table_name.replace('"', ""));
+  let author = author.unwrap_or(DEFAULT_AUTHOR);
+  let changelog = render_changelog(&changeset_id, author, &table, &column_types, &foreign_keys);
+  fs::create_dir_all(&changelog_directory)
+    .map_err(|e| format!("Unable to create changelog directory: {}", e))?;
+  let changeset_path = changelog_directory.join(format!("{}.yaml", changeset_id));
+  if changeset_path.exists() {
+    return Err(format!("Changeset file '{}' already exists", changeset_path.display()));
+  }
+  fs::write(&changeset_path, changelog)
+    .map_err(|e| format!("Unable to write changeset file: {}", e))?;
+  // Step 5: Include the changeset in the master changelog
+  if let Some(master_changelog_path) = &master_changelog_path {
+    include_in_master_changelog(master_changelog_path, &changeset_path, cwd)?;
+  }
+  // Step 6: Build and return response
+  Ok(GenerateLiquibaseChangelogResponse {
+    changeset_file_path: changeset_path.display().to_string(),
+    changeset_id,
+    author: author.to_string(),
+    dialect: dialect.as_str().to_string(),
+    table_name,
+    master_changelog_file_path: master_changelog_path.map(|path| path.display().to_string()),
+  })
+}
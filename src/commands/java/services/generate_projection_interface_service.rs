@@ -0,0 +1,257 @@
+use std::path::{Path, PathBuf};
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::file_response::FileResponse;
+use crate::commands::java::responses::generate_projection_interface_response::{
+  GenerateProjectionInterfaceResponse, ProjectionMethodResponse,
+};
+use crate::commands::java::services::create_java_file_service;
+use crate::commands::java::services::get_superclass_fields_service::{
+  collect_persistent_fields, resolve_inherited_fields,
+};
+use crate::commands::java::treesitter::services::annotation_service::find_annotation_node_by_name;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_name, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, find_imported_class_package,
+};
+use crate::commands::java::treesitter::services::interface_declaration_service::get_public_interface_node;
+use crate::commands::java::treesitter::services::method_declaration_service::get_getter_name;
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::java_file_type::JavaFileType;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::hash_util::get_content_hash;
+use crate::common::utils::path_util::find_file_by_class_name;
+
+const IMPLICIT_TYPES: [&str; 20] = [
+  "boolean",
+  "byte",
+  "char",
+  "double",
+  "float",
+  "int",
+  "long",
+  "short",
+  "Boolean",
+  "Byte",
+  "Character",
+  "Double",
+  "Float",
+  "Integer",
+  "Long",
+  "Number",
+  "Object",
+  "Short",
+  "String",
+  "Void",
+];
+
+struct ProjectedField {
+  field_name: String,
+  field_type: String,
+  declaring_class: String,
+}
+
+/// Resolves the requested fields, in the requested order, from the entity and the persistent
+/// superclasses it extends.
+fn resolve_projected_fields(
+  cwd: &Path,
+  entity_ts_file: &TSFile,
+  class_node: Node,
+  entity_type: &str,
+  field_names: &[String],
+) -> Result<Vec<ProjectedField>, String> {
+  let (_, mut available_fields) = resolve_inherited_fields(cwd, entity_ts_file, class_node);
+  available_fields.extend(collect_persistent_fields(
+    entity_ts_file,
+    class_node,
+    entity_type,
+    false,
+  ));
+  let mut projected_fields: Vec<ProjectedField> = Vec::new();
+  for field_name in field_names {
+    if projected_fields.iter().any(|field| &field.field_name == field_name) {
+      continue;
+    }
+    let field = available_fields
+      .iter()
+      .find(|field| &field.field_name == field_name)
+      .ok_or_else(|| format!("Field '{}' not found in entity '{}'", field_name, entity_type))?;
+    projected_fields.push(ProjectedField {
+      field_name: field.field_name.clone(),
+      field_type: field.field_type.clone(),
+      declaring_class: field.declaring_class.clone(),
+    });
+  }
+  Ok(projected_fields)
+}
+
+/// Simple type names referenced by a field type, e.g. `Map`, `Status` and `Money` for
+/// `Map<Status, List<Money>>`. Qualified names are skipped as they need no import.
+fn get_referenced_type_names(field_type: &str) -> Vec<String> {
+  field_type
+    .split(['<', '>', ',', '[', ']', '?', ' '])
+    .filter(|token| !token.is_empty() && !token.contains('.'))
+    .filter(|token| !matches!(*token, "extends" | "super") && !IMPLICIT_TYPES.contains(token))
+    .map(|token| token.to_string())
+    .collect()
+}
+
+/// Resolves the imports the projection needs for a field type, using the imports of the class
+/// declaring the field and falling back to that class's own package.
+fn resolve_field_imports(
+  cwd: &Path,
+  declaring_ts_file: &TSFile,
+  field_type: &str,
+  projection_package_name: &str,
+) -> Vec<(String, String)> {
+  let declaring_package_name = get_package_name(declaring_ts_file);
+  let mut imports = Vec::new();
+  for type_name in get_referenced_type_names(field_type) {
+    if let Some(package_name) = find_imported_class_package(declaring_ts_file, &type_name) {
+      imports.push((package_name, type_name));
+      continue;
+    }
+    let Some(declaring_package_name) = &declaring_package_name else {
+      continue;
+    };
+    let is_same_package_type = JavaSourceDirectoryType::Main
+      .get_full_path(cwd, declaring_package_name)
+      .join(format!("{}.java", type_name))
+      .exists();
+    if is_same_package_type && declaring_package_name != projection_package_name {
+      imports.push((declaring_package_name.clone(), type_name));
+    }
+  }
+  imports
+}
+
+fn build_projection_methods(projected_fields: &[ProjectedField]) -> Vec<ProjectionMethodResponse> {
+  projected_fields
+    .iter()
+    .map(|field| ProjectionMethodResponse {
+      field_name: field.field_name.clone(),
+      method_name: get_getter_name(&field.field_name, &field.field_type),
+      return_type: field.field_type.clone(),
+    })
+    .collect()
+}
+
+fn add_projection_methods(
+  projection_ts_file: &mut TSFile,
+  methods: &[ProjectionMethodResponse],
+) -> Result<(), String> {
+  let (body_start_byte, body_end_byte) = {
+    let interface_node = get_public_interface_node(projection_ts_file)
+      .ok_or_else(|| "Unable to get public interface node".to_string())?;
+    let body_node = interface_node
+      .child_by_field_name("body")
+      .ok_or_else(|| "Unable to get interface body node".to_string())?;
+    (body_node.start_byte(), body_node.end_byte())
+  };
+  let method_lines = methods
+    .iter()
+    .map(|method| format!("  {} {}();", method.return_type, method.method_name))
+    .collect::<Vec<_>>()
+    .join("\n");
+  projection_ts_file.replace_text_by_range(
+    body_start_byte,
+    body_end_byte,
+    &format!("{{\n{}\n}}", method_lines),
+  );
+  Ok(())
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  field_names: &[String],
+  package_name: Option<&str>,
+  projection_name: Option<&str>,
+) -> Result<GenerateProjectionInterfaceResponse, String> {
+  // Step 1: Parse entity file
+  let entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  let class_node = get_public_class_node(&entity_ts_file)
+    .ok_or_else(|| "Unable to get public class node".to_string())?;
+  if find_annotation_node_by_name(&entity_ts_file, class_node, "Entity").is_none() {
+    return Err("Class is not annotated with @Entity".to_string());
+  }
+  let entity_type = get_class_name(&entity_ts_file, class_node)
+    .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+  let entity_package_name = get_package_name(&entity_ts_file)
+    .ok_or_else(|| "Unable to get JPA Entity package declaration node".to_string())?;
+  // Step 2: Resolve the projected fields before creating any file
+  if field_names.is_empty() {
+    return Err("At least one field must be projected".to_string());
+  }
+  let projected_fields =
+    resolve_projected_fields(cwd, &entity_ts_file, class_node, &entity_type, field_names)?;
+  let projection_package_name = package_name.unwrap_or(&entity_package_name).to_string();
+  let mut imports: Vec<(String, String)> = Vec::new();
+  for field in &projected_fields {
+    let field_imports = if field.declaring_class == entity_type {
+      resolve_field_imports(cwd, &entity_ts_file, &field.field_type, &projection_package_name)
+    } else {
+      find_file_by_class_name(cwd, &JavaSourceDirectoryType::Main, &field.declaring_class)
+        .map(|declaring_ts_file| {
+          resolve_field_imports(
+            cwd,
+            &declaring_ts_file,
+            &field.field_type,
+            &projection_package_name,
+          )
+        })
+        .unwrap_or_default()
+    };
+    for field_import in field_imports {
+      if !imports.contains(&field_import) {
+        imports.push(field_import);
+      }
+    }
+  }
+  // Step 3: Create the projection interface file
+  let projection_name =
+    projection_name.map(|name| name.to_string()).unwrap_or(format!("{}Projection", entity_type));
+  let file_response = create_java_file_service::run(
+    cwd,
+    &projection_package_name,
+    &projection_name,
+    &JavaFileType::Interface,
+    &JavaSourceDirectoryType::Main,
+    None,
+    None,
+  )?;
+  let projection_path = PathBuf::from(&file_response.file_path);
+  let mut projection_ts_file =
+    TSFile::from_file(projection_path.as_path(), cwd, SupportedLanguage::Java)
+      .map_err(|e| format!("Unable to parse newly created projection file: {}", e))?;
+  // Step 4: Add the getter signatures and imports
+  let methods = build_projection_methods(&projected_fields);
+  add_projection_methods(&mut projection_ts_file, &methods)?;
+  for (import_package_name, import_class) in &imports {
+    add_import(
+      &mut projection_ts_file,
+      &ImportInsertionPosition::AfterLastImport,
+      import_package_name,
+      import_class,
+    );
+  }
+  // Step 5: Save projection file
+  projection_ts_file.save().map_err(|e| format!("Unable to save projection file: {}", e))?;
+  Ok(GenerateProjectionInterfaceResponse {
+    projection: FileResponse {
+      file_type: file_response.file_type,
+      file_package_name: file_response.file_package_name,
+      file_path: file_response.file_path,
+      content_hash: Some(get_content_hash(&projection_ts_file.source_code)),
+      warnings: file_response.warnings,
+    },
+    methods,
+  })
+}
@@ -0,0 +1,292 @@
+use std::path::{Path, PathBuf};
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::file_response::FileResponse;
+use crate::commands::java::responses::generate_query_by_example_response::{
+  GenerateQueryByExampleResponse, ProbeFieldResponse,
+};
+use crate::commands::java::services::create_java_file_service;
+use crate::commands::java::treesitter::services::annotation_service::{
+  find_annotation_node_by_name, find_declaration_annotation_node_by_name,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::{
+  get_own_field_declaration_nodes, get_simple_type_name, is_persistent_field,
+};
+use crate::commands::java::treesitter::services::field_declaration_service::get_field_declaration_name_node;
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, find_imported_class_package,
+};
+use crate::commands::java::treesitter::services::method_declaration_service::{
+  find_method_declaration_node_by_name, get_setter_name,
+};
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::java_file_type::JavaFileType;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::hash_util::get_content_hash;
+
+const METHOD_NAME: &str = "toExample";
+
+const SPRING_DATA_DOMAIN_PACKAGE: &str = "org.springframework.data.domain";
+
+const JAVA_LANG_TYPES: [&str; 10] = [
+  "Boolean",
+  "Byte",
+  "Character",
+  "Double",
+  "Float",
+  "Integer",
+  "Long",
+  "Object",
+  "Short",
+  "String",
+];
+
+/// Entity field set on the probe.
+struct ProbeField {
+  field_name: String,
+  parameter_type: String,
+  /// Primitive fields always take part in the example, so they are ignored when left null
+  is_primitive: bool,
+  import: Option<(String, String)>,
+}
+
+fn get_boxed_type(field_type: &str) -> Option<&'static str> {
+  let boxed_type = match field_type {
+    "boolean" => "Boolean",
+    "byte" => "Byte",
+    "char" => "Character",
+    "double" => "Double",
+    "float" => "Float",
+    "int" => "Integer",
+    "long" => "Long",
+    "short" => "Short",
+    _ => return None,
+  };
+  Some(boxed_type)
+}
+
+/// Whether the entity exposes a setter for the field, declared or generated by Lombok.
+fn has_setter(ts_file: &TSFile, class_node: Node, field_node: Node, field_name: &str) -> bool {
+  find_method_declaration_node_by_name(ts_file, class_node, &get_setter_name(field_name)).is_some()
+    || find_declaration_annotation_node_by_name(ts_file, field_node, "Setter").is_some()
+    || ["Data", "Setter"]
+      .iter()
+      .any(|name| find_declaration_annotation_node_by_name(ts_file, class_node, name).is_some())
+}
+
+/// Picks the fields the probe is built from. Versions and collections can't be matched by
+/// example, ids and to-one relationships are only included on request.
+fn collect_probe_fields(
+  entity_ts_file: &TSFile,
+  class_node: Node,
+  entity_package_name: &str,
+  helper_package_name: &str,
+  include_id: bool,
+  include_relations: bool,
+  warnings: &mut Vec<String>,
+) -> Vec<ProbeField> {
+  let mut fields = Vec::new();
+  for field_node in get_own_field_declaration_nodes(entity_ts_file, class_node) {
+    if !is_persistent_field(entity_ts_file, field_node) {
+      continue;
+    }
+    let find =
+      |name: &str| find_declaration_annotation_node_by_name(entity_ts_file, field_node, name);
+    let field_name = get_field_declaration_name_node(entity_ts_file, field_node)
+      .and_then(|node| entity_ts_file.get_text_from_node(&node));
+    let field_type = field_node
+      .child_by_field_name("type")
+      .and_then(|node| entity_ts_file.get_text_from_node(&node));
+    let (Some(field_name), Some(field_type)) = (field_name, field_type) else {
+      continue;
+    };
+    let is_id = find("Id").is_some() || find("EmbeddedId").is_some();
+    let is_relationship = find("ManyToOne").is_some() || find("OneToOne").is_some();
+    let is_unsupported = ["Version", "OneToMany", "ManyToMany", "ElementCollection"]
+      .iter()
+      .any(|name| find(name).is_some());
+    if is_unsupported || (is_id && !include_id) || (is_relationship && !include_relations) {
+      continue;
+    }
+    if !has_setter(entity_ts_file, class_node, field_node, field_name) {
+      warnings.push(format!(
+        "The entity has no '{}' setter, '{}' is left out of the probe",
+        get_setter_name(field_name),
+        field_name
+      ));
+      continue;
+    }
+    let boxed_type = get_boxed_type(field_type);
+    let simple_type = get_simple_type_name(field_type);
+    let import = match boxed_type.is_some() || JAVA_LANG_TYPES.contains(&simple_type.as_str()) {
+      true => None,
+      false => find_imported_class_package(entity_ts_file, &simple_type)
+        .or_else(|| {
+          (entity_package_name != helper_package_name).then(|| entity_package_name.to_string())
+        })
+        .map(|package_name| (package_name, simple_type)),
+    };
+    fields.push(ProbeField {
+      field_name: field_name.to_string(),
+      parameter_type: boxed_type.unwrap_or(field_type).to_string(),
+      is_primitive: boxed_type.is_some(),
+      import,
+    });
+  }
+  fields
+}
+
+fn build_example_method(entity_type: &str, fields: &[ProbeField]) -> String {
+  let parameters = fields
+    .iter()
+    .map(|field| format!("{} {}", field.parameter_type, field.field_name))
+    .collect::<Vec<_>>()
+    .join(", ");
+  let mut lines = vec![
+    format!("public static Example<{}> {}({}) {{", entity_type, METHOD_NAME, parameters),
+    format!("  {} probe = new {}();", entity_type, entity_type),
+    "  ExampleMatcher matcher = ExampleMatcher.matching().withIgnoreNullValues();".to_string(),
+  ];
+  for field in fields {
+    let setter_call =
+      format!("probe.{}({});", get_setter_name(&field.field_name), field.field_name);
+    match field.is_primitive {
+      true => {
+        lines.push(format!("  if ({} != null) {{", field.field_name));
+        lines.push(format!("    {}", setter_call));
+        lines.push("  } else {".to_string());
+        lines.push(format!("    matcher = matcher.withIgnorePaths(\"{}\");", field.field_name));
+        lines.push("  }".to_string());
+      }
+      false => lines.push(format!("  {}", setter_call)),
+    }
+  }
+  lines.push("  return Example.of(probe, matcher);".to_string());
+  lines.push("}".to_string());
+  lines.join("\n")
+}
+
+/// Generates a helper building a Spring Data `Example` of the entity, for dynamic searches through
+/// `QueryByExampleExecutor`. Null arguments are left out of the match.
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  package_name: Option<&str>,
+  helper_name: Option<&str>,
+  include_id: bool,
+  include_relations: bool,
+) -> Result<GenerateQueryByExampleResponse, String> {
+  // Step 1: Parse entity file
+  let entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  let class_node = get_public_class_node(&entity_ts_file)
+    .ok_or_else(|| "Unable to get public class node".to_string())?;
+  if find_annotation_node_by_name(&entity_ts_file, class_node, "Entity").is_none() {
+    return Err("Class is not annotated with @Entity".to_string());
+  }
+  let entity_type = get_class_declaration_name_node(&entity_ts_file, class_node)
+    .and_then(|name_node| entity_ts_file.get_text_from_node(&name_node))
+    .map(|name| name.to_string())
+    .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+  let entity_package_name = get_package_name(&entity_ts_file)
+    .ok_or_else(|| "Unable to get JPA Entity package declaration node".to_string())?;
+  // Step 2: Pick the probe fields before creating any file
+  let helper_package_name = package_name.unwrap_or(&entity_package_name).to_string();
+  let helper_name =
+    helper_name.map(|name| name.to_string()).unwrap_or(format!("{}ExampleFactory", entity_type));
+  if helper_name == entity_type {
+    return Err(format!("Helper name '{}' must differ from the entity name", helper_name));
+  }
+  let mut warnings = Vec::new();
+  let fields = collect_probe_fields(
+    &entity_ts_file,
+    class_node,
+    &entity_package_name,
+    &helper_package_name,
+    include_id,
+    include_relations,
+    &mut warnings,
+  );
+  if fields.is_empty() {
+    return Err(format!("Entity '{}' has no field to build a probe from", entity_type));
+  }
+  // Step 3: Create the helper class
+  let file_response = create_java_file_service::run(
+    cwd,
+    &helper_package_name,
+    &helper_name,
+    &JavaFileType::Class,
+    &JavaSourceDirectoryType::Main,
+    None,
+    None,
+  )?;
+  let helper_path = PathBuf::from(&file_response.file_path);
+  let mut helper_ts_file =
+    TSFile::from_file(helper_path.as_path(), cwd, SupportedLanguage::Java)
+      .map_err(|e| format!("Unable to parse newly created helper file: {}", e))?;
+  // Step 4: Write the method and imports
+  let method = build_example_method(&entity_type, &fields);
+  let (body_start_byte, body_end_byte) = {
+    let helper_class_node = get_public_class_node(&helper_ts_file)
+      .ok_or_else(|| "Unable to get public class node of the helper".to_string())?;
+    let body_node = helper_class_node
+      .child_by_field_name("body")
+      .ok_or_else(|| "Unable to get class body node".to_string())?;
+    (body_node.start_byte(), body_node.end_byte())
+  };
+  let indented_method =
+    method.lines().map(|line| format!("  {}", line)).collect::<Vec<_>>().join("\n");
+  helper_ts_file.replace_text_by_range(
+    body_start_byte,
+    body_end_byte,
+    &format!("{{\n{}\n}}\n", indented_method),
+  );
+  let mut imports: Vec<(String, String)> = vec![
+    (SPRING_DATA_DOMAIN_PACKAGE.to_string(), "Example".to_string()),
+    (SPRING_DATA_DOMAIN_PACKAGE.to_string(), "ExampleMatcher".to_string()),
+  ];
+  if entity_package_name != helper_package_name {
+    imports.push((entity_package_name.clone(), entity_type.clone()));
+  }
+  for field_import in fields.iter().filter_map(|field| field.import.as_ref()) {
+    if !imports.contains(field_import) {
+      imports.push(field_import.clone());
+    }
+  }
+  for (import_package_name, import_class) in &imports {
+    add_import(
+      &mut helper_ts_file,
+      &ImportInsertionPosition::AfterLastImport,
+      import_package_name,
+      import_class,
+    );
+  }
+  // Step 5: Save helper file
+  helper_ts_file.save().map_err(|e| format!("Unable to save helper file: {}", e))?;
+  Ok(GenerateQueryByExampleResponse {
+    helper: FileResponse {
+      file_type: file_response.file_type,
+      file_package_name: file_response.file_package_name,
+      file_path: file_response.file_path,
+      content_hash: Some(get_content_hash(&helper_ts_file.source_code)),
+      warnings: file_response.warnings,
+    },
+    method_name: METHOD_NAME.to_string(),
+    method,
+    probe_fields: fields
+      .into_iter()
+      .map(|field| ProbeFieldResponse {
+        field_name: field.field_name,
+        parameter_type: field.parameter_type,
+      })
+      .collect(),
+    warnings,
+  })
+}
@@ -0,0 +1,87 @@
+use std::path::Path;
+
+use crate::commands::java::responses::generate_query_dsl_metadata_response::{
+  GenerateQueryDslMetadataResponse, QueryDslCoverage,
+};
+use crate::commands::java::treesitter::services::annotation_service::{
+  add_annotation, find_annotation_node_by_name,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::add_import;
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+
+/// Annotations handled by QueryDSL's `JPAAnnotationProcessor`.
+const JPA_ANNOTATIONS: [&str; 3] = ["Entity", "MappedSuperclass", "Embeddable"];
+
+/// Annotations handled by QueryDSL's own `QuerydslAnnotationProcessor`.
+const QUERY_DSL_ANNOTATIONS: [&str; 3] = ["QueryEntity", "QuerySupertype", "QueryEmbeddable"];
+
+pub fn run(
+  cwd: &Path,
+  b64_source_code: &str,
+  file_path: &Path,
+) -> Result<GenerateQueryDslMetadataResponse, String> {
+  // Step 1: Parse the file
+  let mut ts_file = TSFile::from_base64_source_code(b64_source_code, SupportedLanguage::Java);
+  // Step 2: Check which annotation processor covers the class
+  let (type_name, class_start_byte, coverage) = {
+    let class_node = get_public_class_node(&ts_file)
+      .ok_or_else(|| "Unable to get public class node".to_string())?;
+    let type_name = get_class_declaration_name_node(&ts_file, class_node)
+      .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+      .map(|name| name.to_string())
+      .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+    let is_annotated_with = |annotations: &[&str]| {
+      annotations
+        .iter()
+        .any(|annotation| find_annotation_node_by_name(&ts_file, class_node, annotation).is_some())
+    };
+    let coverage = if is_annotated_with(&JPA_ANNOTATIONS) {
+      Some(QueryDslCoverage::JpaAnnotationProcessor)
+    } else if is_annotated_with(&QUERY_DSL_ANNOTATIONS) {
+      Some(QueryDslCoverage::QueryDslAnnotation)
+    } else {
+      None
+    };
+    (type_name, class_node.start_byte(), coverage)
+  };
+  // Step 3: Annotate classes no processor would pick up with @QueryEntity
+  let annotation_added = coverage.is_none();
+  if annotation_added {
+    add_annotation(
+      &mut ts_file,
+      class_start_byte,
+      &AnnotationInsertionPosition::AboveScopeDeclaration,
+      "@QueryEntity",
+    )
+    .ok_or_else(|| "Unable to add @QueryEntity annotation".to_string())?;
+    add_import(
+      &mut ts_file,
+      &ImportInsertionPosition::BeforeFirstImport,
+      "com.querydsl.core.annotations",
+      "QueryEntity",
+    );
+    // Step 4: Save file with working directory validation
+    ts_file
+      .save_to_existing_file(file_path, cwd)
+      .map_err(|e| format!("Unable to save file: {}", e))?;
+  }
+  // Step 5: Build and return response
+  let query_type_name = match get_package_name(&ts_file) {
+    Some(package_name) => format!("{}.Q{}", package_name, type_name),
+    None => format!("Q{}", type_name),
+  };
+  Ok(GenerateQueryDslMetadataResponse {
+    file_path: file_path.display().to_string(),
+    type_name,
+    query_type_name,
+    coverage: coverage.unwrap_or(QueryDslCoverage::QueryDslAnnotation),
+    annotation_added,
+  })
+}
@@ -0,0 +1,504 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::Path;
+use std::sync::LazyLock;
+
+use crate::commands::java::responses::generate_schema_response::{
+  GenerateSchemaResponse, SkippedEntityResponse,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::get_entity_mapping;
+use crate::commands::java::treesitter::types::entity_mapping::{
+  ColumnMapping, EntityMapping, JoinColumnMapping, MappedClassKind,
+};
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::commands::java::treesitter::types::sql_dialect::SqlDialect;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::{get_all_java_file_paths, parse_scanned_file};
+use crate::common::utils::scan_cache::ScanCache;
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+/// Relational mappings of every JPA class in a project, plus the enums they may reference.
+pub struct ProjectMappings {
+  pub classes: HashMap<String, EntityMapping>,
+  pub enums: HashSet<String>,
+}
+
+/// Entity whose inherited and embedded columns have been resolved into a single table.
+#[derive(Clone)]
+pub struct ResolvedTable {
+  pub entity: EntityMapping,
+  pub columns: Vec<ColumnMapping>,
+  pub join_columns: Vec<JoinColumnMapping>,
+}
+
+fn get_enum_names(ts_file: &TSFile) -> Vec<String> {
+  let query_string = r#"
+        (enum_declaration
+          name: (identifier) @enumName
+        )
+    "#;
+  match ts_file.query_builder(query_string).returning("enumName").execute() {
+    Ok(result) => result
+      .nodes()
+      .iter()
+      .filter_map(|node| ts_file.get_text_from_node(node))
+      .map(|name| name.to_string())
+      .collect(),
+    Err(_) => Vec::new(),
+  }
+}
+
+/// Per-file scan results of `load_project_mappings`, reused while a file is unchanged.
+static PROJECT_MAPPINGS_CACHE: LazyLock<ScanCache<(Option<EntityMapping>, Vec<String>)>> =
+  LazyLock::new(ScanCache::new);
+
+/// Drops the cached project scans of `cwd`, or of every project when `None`.
+///
+/// # Returns
+/// The number of cached files dropped
+pub fn clear_project_mappings_cache(cwd: Option<&Path>) -> usize {
+  PROJECT_MAPPINGS_CACHE.clear(cwd)
+}
+
+/// Parses every Java file under `cwd` in parallel and extracts the mappings of its JPA classes.
+/// Files unchanged since the previous scan of `cwd` are not parsed again. Files not yet started
+/// when `deadline` expires are skipped.
+pub fn load_project_mappings(cwd: &Path, deadline: &ScanDeadline) -> ProjectMappings {
+  let paths = get_all_java_file_paths(cwd, &JavaSourceDirectoryType::Main);
+  let parsed = PROJECT_MAPPINGS_CACHE.scan(cwd, &paths, deadline, |path| {
+    let ts_file = parse_scanned_file(cwd, path, deadline)?;
+    Some((get_entity_mapping(&ts_file), get_enum_names(&ts_file)))
+  });
+  let mut classes = HashMap::new();
+  let mut enums = HashSet::new();
+  for (mapping, enum_names) in parsed {
+    if let Some(mapping) = mapping {
+      classes.entry(mapping.class_name.clone()).or_insert(mapping);
+    }
+    enums.extend(enum_names);
+  }
+  ProjectMappings { classes, enums }
+}
+
+fn find_class<'a>(
+  project: &'a ProjectMappings,
+  class_name: &str,
+  kind: MappedClassKind,
+) -> Option<&'a EntityMapping> {
+  project.classes.get(class_name).filter(|mapping| mapping.kind == kind)
+}
+
+/// Flattens the columns of an embeddable, applying the `@AttributeOverride` column names. The
+/// field names of the returned columns are paths from the entity (`address.street`).
+fn resolve_embedded_columns(
+  project: &ProjectMappings,
+  embeddable_type: &str,
+  field_path: &str,
+  attribute_overrides: &[(String, String)],
+  is_id: bool,
+  depth: usize,
+  reasons: &mut Vec<String>,
+) -> Vec<ColumnMapping> {
+  let Some(embeddable) = find_class(project, embeddable_type, MappedClassKind::Embeddable) else {
+    reasons.push(format!("Embeddable '{}' not found in the project", embeddable_type));
+    return Vec::new();
+  };
+  if depth > 8 {
+    reasons.push(format!("Embeddable '{}' nests too deeply", embeddable_type));
+    return Vec::new();
+  }
+  let mut columns = Vec::new();
+  for column in &embeddable.columns {
+    let mut column = column.clone();
+    if let Some((_, column_name)) =
+      attribute_overrides.iter().find(|(attribute, _)| *attribute == column.field_name)
+    {
+      column.column_name = column_name.clone();
+      column.has_explicit_name = true;
+    }
+    if is_id {
+      column.is_id = true;
+      column.nullable = false;
+    }
+    column.field_name = format!("{}.{}", field_path, column.field_name);
+    columns.push(column);
+  }
+  for nested in &embeddable.embedded {
+    // Overrides of nested attributes use a dotted path (`address.street`)
+    let prefix = format!("{}.", nested.field_name);
+    let nested_overrides: Vec<(String, String)> = attribute_overrides
+      .iter()
+      .filter_map(|(attribute, column)| {
+        attribute.strip_prefix(&prefix).map(|rest| (rest.to_string(), column.clone()))
+      })
+      .chain(nested.attribute_overrides.iter().cloned())
+      .collect();
+    columns.extend(resolve_embedded_columns(
+      project,
+      &nested.embeddable_type,
+      &format!("{}.{}", field_path, nested.field_name),
+      &nested_overrides,
+      is_id,
+      depth + 1,
+      reasons,
+    ));
+  }
+  columns
+}
+
+/// Resolves the columns an entity's table is made of: its own columns, the ones inherited from
+/// `@MappedSuperclass` ancestors and the flattened embedded values. Fields typed with a project
+/// enum and no `@Enumerated` default to ordinal storage, as JPA does.
+///
+/// # Returns
+/// The resolved table, or the reasons why the entity can't be mapped to a single table
+pub fn resolve_table(
+  project: &ProjectMappings,
+  entity: &EntityMapping,
+) -> Result<ResolvedTable, Vec<String>> {
+  let mut reasons = Vec::new();
+  // Collect the class hierarchy, root first
+  let mut hierarchy = vec![entity];
+  let mut visited = HashSet::from([entity.class_name.clone()]);
+  let mut superclass_name = entity.superclass_name.clone();
+  while let Some(name) = superclass_name {
+    if !visited.insert(name.clone()) {
+      break;
+    }
+    match project.classes.get(&name) {
+      Some(superclass) if superclass.kind == MappedClassKind::MappedSuperclass => {
+        hierarchy.insert(0, superclass);
+        superclass_name = superclass.superclass_name.clone();
+      }
+      Some(superclass) if superclass.kind == MappedClassKind::Entity => {
+        reasons.push(format!(
+          "Extends entity '{}', entity inheritance is not supported",
+          superclass.class_name
+        ));
+        break;
+      }
+      _ => break,
+    }
+  }
+  let mut columns = Vec::new();
+  let mut join_columns = Vec::new();
+  for class in hierarchy {
+    for column in &class.columns {
+      if find_class(project, &column.field_type, MappedClassKind::Embeddable).is_some() {
+        columns.extend(resolve_embedded_columns(
+          project,
+          &column.field_type,
+          &column.field_name,
+          &[],
+          column.is_id,
+          0,
+          &mut reasons,
+        ));
+        continue;
+      }
+      columns.push(column.clone());
+    }
+    for embedded in &class.embedded {
+      columns.extend(resolve_embedded_columns(
+        project,
+        &embedded.embeddable_type,
+        &embedded.field_name,
+        &embedded.attribute_overrides,
+        embedded.is_id,
+        0,
+        &mut reasons,
+      ));
+    }
+    join_columns.extend(class.join_columns.iter().cloned());
+  }
+  for column in columns.iter_mut() {
+    if column.enum_type.is_none() && project.enums.contains(&column.field_type) {
+      column.enum_type = Some("ORDINAL".to_string());
+    }
+  }
+  if reasons.is_empty() {
+    Ok(ResolvedTable { entity: entity.clone(), columns, join_columns })
+  } else {
+    Err(reasons)
+  }
+}
+
+struct TableDefinition {
+  table: ResolvedTable,
+  column_types: Vec<String>,
+  foreign_keys: Vec<ForeignKeyDefinition>,
+}
+
+pub struct ForeignKeyDefinition {
+  pub join_column: JoinColumnMapping,
+  pub column_type: String,
+  pub referenced_entity: String,
+  pub referenced_table: String,
+  pub referenced_column: String,
+}
+
+/// Maps every column to its SQL type in `dialect`.
+pub fn map_column_types(
+  dialect: &SqlDialect,
+  columns: &[ColumnMapping],
+) -> Result<Vec<String>, Vec<String>> {
+  let mut column_types = Vec::new();
+  let mut reasons = Vec::new();
+  for column in columns {
+    match dialect.sql_type(column) {
+      Ok(column_type) => column_types.push(column_type),
+      Err(error) => reasons.push(error),
+    }
+  }
+  if reasons.is_empty() { Ok(column_types) } else { Err(reasons) }
+}
+
+/// Resolves the table and column referenced by each foreign key of `table`. `resolved` holds the
+/// tables the relationships may target, keyed by entity class name.
+pub fn build_foreign_keys(
+  dialect: &SqlDialect,
+  table: &ResolvedTable,
+  resolved: &BTreeMap<String, ResolvedTable>,
+) -> Result<Vec<ForeignKeyDefinition>, Vec<String>> {
+  let mut foreign_keys = Vec::new();
+  let mut reasons = Vec::new();
+  for join_column in &table.join_columns {
+    let Some(target) = resolved.get(&join_column.target_type) else {
+      reasons.push(format!(
+        "Relationship '{}' targets '{}', which is not a mappable entity",
+        join_column.field_name, join_column.target_type
+      ));
+      continue;
+    };
+    let id_columns: Vec<&ColumnMapping> = target.columns.iter().filter(|c| c.is_id).collect();
+    let referenced = match &join_column.referenced_column_name {
+      Some(name) => target.columns.iter().find(|c| &c.column_name == name),
+      None if id_columns.len() == 1 => Some(id_columns[0]),
+      None => None,
+    };
+    let Some(referenced) = referenced else {
+      reasons.push(format!(
+        "Unable to resolve the column referenced by '{}' in '{}'",
+        join_column.field_name, target.entity.class_name
+      ));
+      continue;
+    };
+    match dialect.sql_type(referenced) {
+      Ok(column_type) => foreign_keys.push(ForeignKeyDefinition {
+        join_column: join_column.clone(),
+        column_type,
+        referenced_entity: target.entity.class_name.clone(),
+        referenced_table: target.entity.qualified_table_name(),
+        referenced_column: referenced.column_name.clone(),
+      }),
+      Err(error) => reasons.push(error),
+    }
+  }
+  if reasons.is_empty() { Ok(foreign_keys) } else { Err(reasons) }
+}
+
+/// Orders tables so that referenced tables come first. Tables taking part in a cycle keep their
+/// alphabetical order after the others, the foreign keys are added afterwards anyway.
+fn sort_tables(definitions: &BTreeMap<String, TableDefinition>) -> Vec<String> {
+  let mut pending: BTreeMap<&String, BTreeSet<&String>> = definitions
+    .iter()
+    .map(|(name, definition)| {
+      let dependencies = definition
+        .foreign_keys
+        .iter()
+        .map(|fk| &fk.referenced_entity)
+        .filter(|dependency| *dependency != name)
+        .collect();
+      (name, dependencies)
+    })
+    .collect();
+  let mut ordered = Vec::new();
+  loop {
+    let ready: Vec<&String> = pending
+      .iter()
+      .filter(|(_, dependencies)| dependencies.iter().all(|d| !pending.contains_key(d)))
+      .map(|(name, _)| *name)
+      .collect();
+    if ready.is_empty() {
+      break;
+    }
+    for name in ready {
+      pending.remove(name);
+      ordered.push(name.clone());
+    }
+  }
+  ordered.extend(pending.keys().map(|name| (*name).clone()));
+  ordered
+}
+
+fn render_create_table(dialect: &SqlDialect, definition: &TableDefinition) -> String {
+  let mut lines = Vec::new();
+  for (column, column_type) in definition.table.columns.iter().zip(&definition.column_types) {
+    let mut line = format!("  {} {}", column.column_name, column_type);
+    if column.is_identity {
+      line.push_str(&format!(" {}", dialect.identity_clause()));
+    }
+    if !column.nullable {
+      line.push_str(" NOT NULL");
+    }
+    if column.unique && !column.is_id {
+      line.push_str(" UNIQUE");
+    }
+    lines.push(line);
+  }
+  for foreign_key in &definition.foreign_keys {
+    let mut line = format!("  {} {}", foreign_key.join_column.column_name, foreign_key.column_type);
+    if !foreign_key.join_column.nullable {
+      line.push_str(" NOT NULL");
+    }
+    if foreign_key.join_column.unique {
+      line.push_str(" UNIQUE");
+    }
+    lines.push(line);
+  }
+  let id_columns: Vec<&str> = definition
+    .table
+    .columns
+    .iter()
+    .filter(|column| column.is_id)
+    .map(|column| column.column_name.as_str())
+    .collect();
+  if !id_columns.is_empty() {
+    lines.push(format!("  PRIMARY KEY ({})", id_columns.join(", ")));
+  }
+  format!(
+    "CREATE TABLE {} (\n{}\n);",
+    definition.table.entity.qualified_table_name(),
+    lines.join(",\n")
+  )
+}
+
+/// Name of a foreign key constraint (`fk_book_author_id`), without the table's schema.
+pub fn get_foreign_key_constraint_name(table_name: &str, column_name: &str) -> String {
+  format!("fk_{}_{}", table_name.rsplit('.').next().unwrap_or(table_name), column_name)
+}
+
+/// Name of the constraint to create for a join column: the `@ForeignKey` name when given, the
+/// default name otherwise, or `None` when the mapping asks for `ConstraintMode.NO_CONSTRAINT`.
+pub fn get_join_column_constraint_name(
+  table_name: &str,
+  join_column: &JoinColumnMapping,
+) -> Option<String> {
+  if join_column.no_constraint {
+    return None;
+  }
+  Some(
+    join_column
+      .foreign_key_name
+      .clone()
+      .unwrap_or_else(|| get_foreign_key_constraint_name(table_name, &join_column.column_name)),
+  )
+}
+
+fn render_foreign_key(table_name: &str, foreign_key: &ForeignKeyDefinition) -> Option<String> {
+  let constraint_name = get_join_column_constraint_name(table_name, &foreign_key.join_column)?;
+  Some(format!(
+    "ALTER TABLE {} ADD CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({});",
+    table_name,
+    constraint_name,
+    foreign_key.join_column.column_name,
+    foreign_key.referenced_table,
+    foreign_key.referenced_column
+  ))
+}
+
+fn skip_entity(
+  skipped: &mut Vec<SkippedEntityResponse>,
+  entity: &EntityMapping,
+  reasons: Vec<String>,
+) {
+  skipped.push(SkippedEntityResponse {
+    entity_type: entity.class_name.clone(),
+    file_path: entity.file_path.clone(),
+    reasons,
+  });
+}
+
+pub fn run(
+  cwd: &Path,
+  dialect: &SqlDialect,
+  deadline: &ScanDeadline,
+) -> Result<GenerateSchemaResponse, String> {
+  // Step 1: Parse every file in parallel and extract the JPA mappings
+  let project = load_project_mappings(cwd, deadline);
+  let mut entities: Vec<&EntityMapping> =
+    project.classes.values().filter(|m| m.kind == MappedClassKind::Entity).collect();
+  entities.sort_by(|a, b| a.class_name.cmp(&b.class_name));
+  // Step 2: Resolve inherited and embedded columns of every entity
+  let mut skipped_entities = Vec::new();
+  let mut resolved: BTreeMap<String, ResolvedTable> = BTreeMap::new();
+  for entity in entities {
+    match resolve_table(&project, entity) {
+      Ok(table) => {
+        resolved.insert(entity.class_name.clone(), table);
+      }
+      Err(reasons) => skip_entity(&mut skipped_entities, entity, reasons),
+    }
+  }
+  // Step 3: Map column types and foreign keys, dropping entities that reference skipped ones
+  let mut definitions: BTreeMap<String, TableDefinition> = BTreeMap::new();
+  loop {
+    let mut newly_skipped = Vec::new();
+    definitions.clear();
+    for (name, table) in &resolved {
+      let column_types = map_column_types(dialect, &table.columns);
+      match (column_types, build_foreign_keys(dialect, table, &resolved)) {
+        (Ok(column_types), Ok(foreign_keys)) => {
+          definitions.insert(
+            name.clone(),
+            TableDefinition { table: table.clone(), column_types, foreign_keys },
+          );
+        }
+        (column_types, foreign_keys) => {
+          let mut reasons = column_types.err().unwrap_or_default();
+          reasons.extend(foreign_keys.err().unwrap_or_default());
+          newly_skipped.push((name.clone(), reasons));
+        }
+      }
+    }
+    if newly_skipped.is_empty() {
+      break;
+    }
+    for (name, reasons) in newly_skipped {
+      if let Some(table) = resolved.remove(&name) {
+        skip_entity(&mut skipped_entities, &table.entity, reasons);
+      }
+    }
+  }
+  // Step 4: Order the tables so referenced ones are created first
+  let ordered_tables = sort_tables(&definitions);
+  // Step 5: Render CREATE TABLE statements followed by the foreign key constraints
+  let mut statements: Vec<String> = ordered_tables
+    .iter()
+    .filter_map(|name| definitions.get(name))
+    .map(|definition| render_create_table(dialect, definition))
+    .collect();
+  for name in &ordered_tables {
+    if let Some(definition) = definitions.get(name) {
+      let table_name = definition.table.entity.qualified_table_name();
+      statements.extend(
+        definition.foreign_keys.iter().filter_map(|fk| render_foreign_key(&table_name, fk)),
+      );
+    }
+  }
+  let script = if statements.is_empty() { String::new() } else { statements.join("\n\n") + "\n" };
+  let tables: Vec<String> = ordered_tables
+    .iter()
+    .filter_map(|name| definitions.get(name))
+    .map(|definition| definition.table.entity.qualified_table_name())
+    .collect();
+  skipped_entities.sort_by(|a, b| a.entity_type.cmp(&b.entity_type));
+  Ok(GenerateSchemaResponse {
+    dialect: dialect.as_str().to_string(),
+    script,
+    tables_count: tables.len(),
+    tables,
+    skipped_entities,
+    truncated: deadline.is_truncated(),
+    processed_files_count: deadline.processed_files_count(),
+  })
+}
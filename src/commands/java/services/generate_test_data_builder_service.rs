@@ -0,0 +1,369 @@
+use std::path::{Path, PathBuf};
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::file_response::FileResponse;
+use crate::commands::java::responses::generate_test_data_builder_response::{
+  GenerateTestDataBuilderResponse, TestDataFieldResponse,
+};
+use crate::commands::java::services::create_java_file_service;
+use crate::commands::java::treesitter::services::annotation_service::{
+  find_annotation_node_by_name, find_declaration_annotation_node_by_name,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::{
+  get_class_mapping, get_own_field_declaration_nodes, get_simple_type_name, is_persistent_field,
+  is_required_field,
+};
+use crate::commands::java::treesitter::services::enum_declaration_service::get_public_enum_node;
+use crate::commands::java::treesitter::services::field_declaration_service::get_field_declaration_name_node;
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, find_imported_class_package,
+};
+use crate::commands::java::treesitter::services::method_declaration_service::{
+  find_method_declaration_node_by_name, get_setter_name,
+};
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::java_file_type::JavaFileType;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::case_util;
+use crate::common::utils::hash_util::get_content_hash;
+use crate::common::utils::path_util::find_file_by_class_name;
+
+const TO_ONE_RELATIONSHIPS: [&str; 2] = ["ManyToOne", "OneToOne"];
+
+const TO_MANY_RELATIONSHIPS: [&str; 2] = ["OneToMany", "ManyToMany"];
+
+/// Field the builder populates, with the imports its default value needs.
+struct BuilderField {
+  field_name: String,
+  field_type: String,
+  default_value: String,
+  imports: Vec<(String, String)>,
+}
+
+/// Plausible value of a basic type, e.g. `UUID.randomUUID()` for `UUID`. Strings default to the
+/// field name, cut to the column length.
+///
+/// # Returns
+/// The value and the `java.*` class it needs imported, `None` for types without a known default
+fn get_basic_default_value(
+  simple_type: &str,
+  field_name: &str,
+  length: Option<u32>,
+) -> Option<(String, Option<(&'static str, &'static str)>)> {
+  let value = match simple_type {
+    "String" => {
+      let length = length.map_or(field_name.len(), |length| length as usize);
+      return Some((format!("\"{}\"", &field_name[..field_name.len().min(length)]), None));
+    }
+    "long" | "Long" => "1L",
+    "int" | "Integer" => "1",
+    "short" | "Short" => "(short) 1",
+    "byte" | "Byte" => "(byte) 1",
+    "double" | "Double" => "1.0",
+    "float" | "Float" => "1.0f",
+    "boolean" | "Boolean" => "true",
+    "char" | "Character" => "'a'",
+    "byte[]" => "new byte[0]",
+    _ => {
+      let (value, import) = match simple_type {
+        "UUID" => ("UUID.randomUUID()", ("java.util", "UUID")),
+        "Date" => ("new Date()", ("java.util", "Date")),
+        "BigDecimal" => ("BigDecimal.ONE", ("java.math", "BigDecimal")),
+        "BigInteger" => ("BigInteger.ONE", ("java.math", "BigInteger")),
+        "LocalDate" => ("LocalDate.now()", ("java.time", "LocalDate")),
+        "LocalDateTime" => ("LocalDateTime.now()", ("java.time", "LocalDateTime")),
+        "LocalTime" => ("LocalTime.now()", ("java.time", "LocalTime")),
+        "Instant" => ("Instant.now()", ("java.time", "Instant")),
+        "OffsetDateTime" => ("OffsetDateTime.now()", ("java.time", "OffsetDateTime")),
+        "ZonedDateTime" => ("ZonedDateTime.now()", ("java.time", "ZonedDateTime")),
+        _ => return None,
+      };
+      return Some((value.to_string(), Some(import)));
+    }
+  };
+  Some((value.to_string(), None))
+}
+
+fn is_project_enum(cwd: &Path, type_name: &str) -> bool {
+  find_file_by_class_name(cwd, &JavaSourceDirectoryType::Main, type_name)
+    .is_some_and(|ts_file| get_public_enum_node(&ts_file).is_some())
+}
+
+/// Import of a project type referenced by the entity, from the entity imports or its package.
+fn resolve_type_import(
+  entity_ts_file: &TSFile,
+  type_name: &str,
+  entity_package_name: &str,
+  builder_package_name: &str,
+) -> Option<(String, String)> {
+  let package_name = find_imported_class_package(entity_ts_file, type_name).or_else(|| {
+    (entity_package_name != builder_package_name).then(|| entity_package_name.into())
+  })?;
+  Some((package_name, type_name.to_string()))
+}
+
+/// Whether the entity exposes a setter for the field, declared or generated by Lombok.
+fn has_setter(ts_file: &TSFile, class_node: Node, field_node: Node, field_name: &str) -> bool {
+  find_method_declaration_node_by_name(ts_file, class_node, &get_setter_name(field_name)).is_some()
+    || find_declaration_annotation_node_by_name(ts_file, field_node, "Setter").is_some()
+    || ["Data", "Setter"]
+      .iter()
+      .any(|name| find_declaration_annotation_node_by_name(ts_file, class_node, name).is_some())
+}
+
+/// Picks the required fields of the entity and their defaults. Generated ids, versions, embedded
+/// values and collections are left to JPA, and relationships are only populated when
+/// `include_relations` is set.
+fn collect_builder_fields(
+  cwd: &Path,
+  entity_ts_file: &TSFile,
+  class_node: Node,
+  entity_package_name: &str,
+  builder_package_name: &str,
+  include_relations: bool,
+  warnings: &mut Vec<String>,
+) -> Vec<BuilderField> {
+  let columns = get_class_mapping(entity_ts_file, class_node).map(|mapping| mapping.columns);
+  let mut fields = Vec::new();
+  for field_node in get_own_field_declaration_nodes(entity_ts_file, class_node) {
+    if !is_persistent_field(entity_ts_file, field_node) {
+      continue;
+    }
+    let find =
+      |name: &str| find_declaration_annotation_node_by_name(entity_ts_file, field_node, name);
+    let field_name = get_field_declaration_name_node(entity_ts_file, field_node)
+      .and_then(|node| entity_ts_file.get_text_from_node(&node));
+    let field_type = field_node
+      .child_by_field_name("type")
+      .and_then(|node| entity_ts_file.get_text_from_node(&node));
+    let (Some(field_name), Some(field_type)) = (field_name, field_type) else {
+      continue;
+    };
+    let is_generated_id = find("Id").is_some() && find("GeneratedValue").is_some();
+    let is_skipped = ["EmbeddedId", "Embedded", "Version", "ElementCollection"]
+      .iter()
+      .any(|name| find(name).is_some());
+    if is_generated_id || is_skipped || TO_MANY_RELATIONSHIPS.iter().any(|n| find(n).is_some()) {
+      continue;
+    }
+    let column = columns.iter().flatten().find(|column| column.field_name == field_name);
+    let is_required = is_required_field(entity_ts_file, field_node)
+      || find("Id").is_some()
+      || column.is_some_and(|column| !column.nullable);
+    if !is_required {
+      continue;
+    }
+    let simple_type = get_simple_type_name(field_type);
+    let is_relationship = TO_ONE_RELATIONSHIPS.iter().any(|name| find(name).is_some());
+    if is_relationship && !include_relations {
+      continue;
+    }
+    let project_import = || {
+      resolve_type_import(entity_ts_file, &simple_type, entity_package_name, builder_package_name)
+    };
+    let (default_value, imports) = if is_relationship {
+      (format!("new {}()", simple_type), project_import().into_iter().collect())
+    } else if let Some((value, import)) =
+      get_basic_default_value(&simple_type, field_name, column.and_then(|column| column.length))
+    {
+      let imports = import.map(|(package, class)| (package.to_string(), class.to_string()));
+      (value, imports.into_iter().collect())
+    } else if find("Enumerated").is_some() || is_project_enum(cwd, &simple_type) {
+      (format!("{}.values()[0]", simple_type), project_import().into_iter().collect())
+    } else {
+      warnings.push(format!(
+        "Required field '{}' of type '{}' has no default value and is left unset",
+        field_name, field_type
+      ));
+      continue;
+    };
+    if !has_setter(entity_ts_file, class_node, field_node, field_name) {
+      warnings.push(format!(
+        "The entity has no '{}' setter, add it for the builder to compile",
+        get_setter_name(field_name)
+      ));
+    }
+    fields.push(BuilderField {
+      field_name: field_name.to_string(),
+      field_type: field_type.to_string(),
+      default_value,
+      imports,
+    });
+  }
+  fields
+}
+
+/// `aUser` for `User`, `anOrder` for `Order`. A leading `U` mostly sounds like "you", so it
+/// keeps `a`.
+fn get_factory_method_name(entity_type: &str) -> String {
+  let article = match entity_type.chars().next().map(|c| c.to_ascii_lowercase()) {
+    Some('a' | 'e' | 'i' | 'o') => "an",
+    _ => "a",
+  };
+  format!("{}{}", article, entity_type)
+}
+
+fn build_builder_body(
+  builder_name: &str,
+  entity_type: &str,
+  factory_method: &str,
+  fields: &[BuilderField],
+) -> String {
+  let entity_variable = case_util::to_camel_case(entity_type);
+  let mut members: Vec<String> = Vec::new();
+  if !fields.is_empty() {
+    members.push(
+      fields
+        .iter()
+        .map(|field| {
+          format!("  private {} {} = {};", field.field_type, field.field_name, field.default_value)
+        })
+        .collect::<Vec<_>>()
+        .join("\n"),
+    );
+  }
+  members.push(format!(
+    "  public static {} {}() {{\n    return new {}();\n  }}",
+    builder_name, factory_method, builder_name
+  ));
+  for field in fields {
+    members.push(format!(
+      "  public {} with{}({} {}) {{\n    this.{} = {};\n    return this;\n  }}",
+      builder_name,
+      case_util::to_pascal_case(&field.field_name),
+      field.field_type,
+      field.field_name,
+      field.field_name,
+      field.field_name
+    ));
+  }
+  let setter_calls = fields
+    .iter()
+    .map(|field| {
+      format!(
+        "    {}.{}({});\n",
+        entity_variable,
+        get_setter_name(&field.field_name),
+        field.field_name
+      )
+    })
+    .collect::<String>();
+  members.push(format!(
+    "  public {} build() {{\n    {} {} = new {}();\n{}    return {};\n  }}",
+    entity_type, entity_type, entity_variable, entity_type, setter_calls, entity_variable
+  ));
+  format!("{{\n{}\n}}\n", members.join("\n\n"))
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  package_name: Option<&str>,
+  builder_name: Option<&str>,
+  include_relations: bool,
+) -> Result<GenerateTestDataBuilderResponse, String> {
+  // Step 1: Parse entity file
+  let entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  let class_node = get_public_class_node(&entity_ts_file)
+    .ok_or_else(|| "Unable to get public class node".to_string())?;
+  if find_annotation_node_by_name(&entity_ts_file, class_node, "Entity").is_none() {
+    return Err("Class is not annotated with @Entity".to_string());
+  }
+  let entity_type = get_class_declaration_name_node(&entity_ts_file, class_node)
+    .and_then(|name_node| entity_ts_file.get_text_from_node(&name_node))
+    .map(|name| name.to_string())
+    .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+  let entity_package_name = get_package_name(&entity_ts_file)
+    .ok_or_else(|| "Unable to get JPA Entity package declaration node".to_string())?;
+  // Step 2: Pick the fields to populate before creating any file
+  let builder_package_name = package_name.unwrap_or(&entity_package_name).to_string();
+  let builder_name =
+    builder_name.map(|name| name.to_string()).unwrap_or(format!("{}TestDataBuilder", entity_type));
+  if builder_name == entity_type {
+    return Err(format!("Builder name '{}' must differ from the entity name", builder_name));
+  }
+  let mut warnings = Vec::new();
+  let fields = collect_builder_fields(
+    cwd,
+    &entity_ts_file,
+    class_node,
+    &entity_package_name,
+    &builder_package_name,
+    include_relations,
+    &mut warnings,
+  );
+  // Step 3: Create the builder class under the test sources
+  let file_response = create_java_file_service::run(
+    cwd,
+    &builder_package_name,
+    &builder_name,
+    &JavaFileType::Class,
+    &JavaSourceDirectoryType::Test,
+    None,
+    None,
+  )?;
+  let builder_path = PathBuf::from(&file_response.file_path);
+  let mut builder_ts_file = TSFile::from_file(builder_path.as_path(), cwd, SupportedLanguage::Java)
+    .map_err(|e| format!("Unable to parse newly created builder file: {}", e))?;
+  // Step 4: Write the builder members and imports
+  let factory_method = get_factory_method_name(&entity_type);
+  let (body_start_byte, body_end_byte) = {
+    let builder_class_node = get_public_class_node(&builder_ts_file)
+      .ok_or_else(|| "Unable to get public class node of the builder".to_string())?;
+    let body_node = builder_class_node
+      .child_by_field_name("body")
+      .ok_or_else(|| "Unable to get class body node".to_string())?;
+    (body_node.start_byte(), body_node.end_byte())
+  };
+  builder_ts_file.replace_text_by_range(
+    body_start_byte,
+    body_end_byte,
+    &build_builder_body(&builder_name, &entity_type, &factory_method, &fields),
+  );
+  let mut imports: Vec<(String, String)> = Vec::new();
+  if entity_package_name != builder_package_name {
+    imports.push((entity_package_name.clone(), entity_type.clone()));
+  }
+  for field_import in fields.iter().flat_map(|field| field.imports.iter()) {
+    if !imports.contains(field_import) {
+      imports.push(field_import.clone());
+    }
+  }
+  for (import_package_name, import_class) in &imports {
+    add_import(
+      &mut builder_ts_file,
+      &ImportInsertionPosition::AfterLastImport,
+      import_package_name,
+      import_class,
+    );
+  }
+  // Step 5: Save builder file
+  builder_ts_file.save().map_err(|e| format!("Unable to save builder file: {}", e))?;
+  Ok(GenerateTestDataBuilderResponse {
+    builder: FileResponse {
+      file_type: file_response.file_type,
+      file_package_name: file_response.file_package_name,
+      file_path: file_response.file_path,
+      content_hash: Some(get_content_hash(&builder_ts_file.source_code)),
+      warnings: file_response.warnings,
+    },
+    factory_method,
+    fields: fields
+      .into_iter()
+      .map(|field| TestDataFieldResponse {
+        field_name: field.field_name,
+        field_type: field.field_type,
+        default_value: field.default_value,
+      })
+      .collect(),
+    warnings,
+  })
+}
@@ -1,5 +1,7 @@
 use std::path::Path;
 
+use tree_sitter::Node;
+
 use crate::{
   commands::java::{
     responses::file_response::FileResponse,
@@ -7,44 +9,109 @@ use crate::{
       services::{
         annotation_service::find_annotation_node_by_name,
         class_declaration_service::get_public_class_node,
-        package_declaration_service::{get_package_declaration_node, get_package_scope_node},
+        package_declaration_service::{get_package_declaration_node, get_package_name},
       },
       types::java_source_directory_type::JavaSourceDirectoryType,
     },
   },
-  common::utils::path_util::parse_all_files,
+  common::{
+    supported_language::SupportedLanguage,
+    ts_file::TSFile,
+    utils::{path_util::parse_all_files_within_deadline, scan_deadline::ScanDeadline},
+  },
 };
 
-pub fn run(cwd: &Path) -> Result<Vec<FileResponse>, String> {
+/// Source of the file with its line comments and non-Javadoc block comments turned back into code,
+/// so a commented-out `@Entity` class can be told apart from a live one.
+fn get_uncommented_source_code(ts_file: &TSFile) -> Option<String> {
+  let root_node = ts_file.tree.as_ref()?.root_node();
+  let mut comment_nodes: Vec<Node> = Vec::new();
+  let mut pending = vec![root_node];
+  while let Some(node) = pending.pop() {
+    if matches!(node.kind(), "line_comment" | "block_comment") {
+      comment_nodes.push(node);
+      continue;
+    }
+    let mut cursor = node.walk();
+    pending.extend(node.named_children(&mut cursor));
+  }
+  comment_nodes.sort_by_key(|node| node.start_byte());
+  let mut source_code = String::with_capacity(ts_file.source_code.len());
+  let mut cursor = 0;
+  for comment_node in comment_nodes {
+    let comment = &ts_file.source_code[comment_node.start_byte()..comment_node.end_byte()];
+    let code = if let Some(code) = comment.strip_prefix("//") {
+      code.to_string()
+    } else if !comment.starts_with("/**") {
+      let code = comment.trim_start_matches("/*").trim_end_matches("*/");
+      code
+        .lines()
+        .map(|line| line.trim_start().trim_start_matches('*'))
+        .collect::<Vec<_>>()
+        .join("\n")
+    } else {
+      continue;
+    };
+    source_code.push_str(&ts_file.source_code[cursor..comment_node.start_byte()]);
+    source_code.push_str(&code);
+    cursor = comment_node.end_byte();
+  }
+  source_code.push_str(&ts_file.source_code[cursor..]);
+  Some(source_code)
+}
+
+fn is_entity_file(ts_file: &TSFile) -> bool {
+  get_public_class_node(ts_file)
+    .is_some_and(|class_node| find_annotation_node_by_name(ts_file, class_node, "Entity").is_some())
+}
+
+/// Package of an entity, `None` for files without a package declaration, which are skipped.
+fn get_entity_package_name(ts_file: &TSFile) -> Option<String> {
+  get_package_declaration_node(ts_file)?;
+  Some(get_package_name(ts_file).unwrap_or_else(|| "No package".to_string()))
+}
+
+/// Finds the entities of the project. Comments are never parsed as code, so a commented-out
+/// `@Entity` class is skipped unless `include_commented` is set, in which case it is reported with
+/// a warning.
+pub fn run(
+  cwd: &Path,
+  deadline: &ScanDeadline,
+  include_commented: bool,
+) -> Result<Vec<FileResponse>, String> {
   let mut files: Vec<FileResponse> = Vec::new();
-  let ts_files = parse_all_files(cwd, &JavaSourceDirectoryType::Main);
+  let ts_files = parse_all_files_within_deadline(cwd, &JavaSourceDirectoryType::Main, deadline);
   for ts_file in ts_files {
-    match get_public_class_node(&ts_file) {
-      Some(public_class_node) => {
-        let mapped_superclass_annotation_node =
-          find_annotation_node_by_name(&ts_file, public_class_node, "Entity");
-        if mapped_superclass_annotation_node.is_some() {
-          let file_type =
-            ts_file.get_file_name_without_ext().unwrap_or_else(|| "Unknown".to_string());
-          let file_path = ts_file
-            .file_path()
-            .map(|p| p.to_string_lossy().to_string())
-            .unwrap_or_else(|| "Unknown path".to_string());
-          let file_package_name = if let Some(package_node) = get_package_declaration_node(&ts_file)
-          {
-            get_package_scope_node(&ts_file, package_node)
-              .and_then(|name_node| ts_file.get_text_from_node(&name_node))
-              .map(|s| s.to_string())
-              .unwrap_or_else(|| "No package".to_string())
-          } else {
-            continue;
-          };
-          let found_file = FileResponse { file_type, file_package_name, file_path };
-          files.push(found_file);
-        }
-      }
-      None => continue,
-    }
+    let mut warnings = Vec::new();
+    let file_package_name = if is_entity_file(&ts_file) {
+      get_entity_package_name(&ts_file)
+    } else if include_commented {
+      let uncommented_ts_file = get_uncommented_source_code(&ts_file)
+        .map(|source_code| TSFile::from_source_code(&source_code, SupportedLanguage::Java))
+        .filter(is_entity_file);
+      let Some(uncommented_ts_file) = uncommented_ts_file else {
+        continue;
+      };
+      warnings.push("The @Entity class is commented out".to_string());
+      get_entity_package_name(&uncommented_ts_file)
+    } else {
+      continue;
+    };
+    let Some(file_package_name) = file_package_name else {
+      continue;
+    };
+    let file_type = ts_file.get_file_name_without_ext().unwrap_or_else(|| "Unknown".to_string());
+    let file_path = ts_file
+      .file_path()
+      .map(|p| p.to_string_lossy().to_string())
+      .unwrap_or_else(|| "Unknown path".to_string());
+    files.push(FileResponse {
+      file_type,
+      file_package_name,
+      file_path,
+      content_hash: None,
+      warnings,
+    });
   }
   Ok(files)
 }
@@ -12,12 +12,12 @@ use crate::{
       types::java_source_directory_type::JavaSourceDirectoryType,
     },
   },
-  common::utils::path_util::parse_all_files,
+  common::utils::{path_util::parse_all_files_within_deadline, scan_deadline::ScanDeadline},
 };
 
-pub fn run(cwd: &Path) -> Result<Vec<FileResponse>, String> {
+pub fn run(cwd: &Path, deadline: &ScanDeadline) -> Result<Vec<FileResponse>, String> {
   let mut files: Vec<FileResponse> = Vec::new();
-  let ts_files = parse_all_files(cwd, &JavaSourceDirectoryType::Main);
+  let ts_files = parse_all_files_within_deadline(cwd, &JavaSourceDirectoryType::Main, deadline);
   for ts_file in ts_files {
     match get_public_class_node(&ts_file) {
       Some(public_class_node) => {
@@ -39,7 +39,13 @@ pub fn run(cwd: &Path) -> Result<Vec<FileResponse>, String> {
           } else {
             continue;
           };
-          let found_file = FileResponse { file_type, file_package_name, file_path };
+          let found_file = FileResponse {
+            file_type,
+            file_package_name,
+            file_path,
+            content_hash: None,
+            warnings: Vec::new(),
+          };
           files.push(found_file);
         }
       }
@@ -0,0 +1,46 @@
+use std::path::Path;
+
+use crate::commands::java::responses::get_annotations_response::GetAnnotationsResponse;
+use crate::commands::java::services::get_jpa_entity_info_service::create_ts_file;
+use crate::commands::java::treesitter::services::annotation_service::{
+  get_annotation_response, get_declaration_annotation_nodes,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  find_type_declaration_node, get_type_declaration_kind, get_type_declaration_name,
+};
+
+fn get_simple_name(name: &str) -> &str {
+  name.rsplit('.').next().unwrap_or(name)
+}
+
+pub fn run(
+  file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  type_name: Option<&str>,
+  cwd: &Path,
+) -> Result<GetAnnotationsResponse, String> {
+  // Step 1: Create TSFile
+  let ts_file = create_ts_file(file_path, b64_source_code, cwd)?;
+  // Step 2: Find the type declaration
+  let type_node =
+    find_type_declaration_node(&ts_file, type_name).ok_or_else(|| match type_name {
+      Some(type_name) => format!("Type '{}' not found in file", type_name),
+      None => "No type declaration found in file".to_string(),
+    })?;
+  let type_kind = get_type_declaration_kind(type_node).unwrap_or_default().to_string();
+  let type_name = get_type_declaration_name(&ts_file, type_node).unwrap_or_default().to_string();
+  // Step 3: Serialize the annotations of the declaration itself, ignoring the ones of its members
+  let mut annotations: Vec<_> = get_declaration_annotation_nodes(type_node)
+    .into_iter()
+    .filter_map(|annotation_node| get_annotation_response(&ts_file, annotation_node))
+    .collect();
+  // Step 4: Sort by simple name, repeated annotations keep their declaration order
+  annotations.sort_by(|a, b| get_simple_name(&a.name).cmp(get_simple_name(&b.name)));
+  // Step 5: Build response
+  Ok(GetAnnotationsResponse {
+    type_name,
+    type_kind,
+    annotations_count: annotations.len(),
+    annotations,
+  })
+}
@@ -0,0 +1,183 @@
+use std::path::Path;
+
+use crate::commands::java::responses::get_class_metrics_response::GetClassMetricsResponse;
+use crate::commands::java::services::get_jpa_entity_info_service::create_ts_file;
+use crate::commands::java::treesitter::services::annotation_service::find_declaration_annotation_node_by_name;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  find_type_declaration_node, get_type_declaration_kind, get_type_declaration_name,
+};
+use crate::commands::java::treesitter::types::relationship_kind::RelationshipKind;
+use crate::common::ts_file::TSFile;
+use tree_sitter::Node;
+
+const RELATIONSHIP_KINDS: [RelationshipKind; 4] = [
+  RelationshipKind::ManyToOne,
+  RelationshipKind::OneToMany,
+  RelationshipKind::OneToOne,
+  RelationshipKind::ManyToMany,
+];
+
+const BRANCH_NODE_KINDS: [&str; 7] = [
+  "if_statement",
+  "for_statement",
+  "enhanced_for_statement",
+  "while_statement",
+  "do_statement",
+  "catch_clause",
+  "ternary_expression",
+];
+
+/// Members declared directly in the type body. Enum members other than the constants live in an
+/// `enum_body_declarations` node, which is flattened.
+fn get_member_nodes(type_node: Node) -> Vec<Node> {
+  let Some(body_node) = type_node.child_by_field_name("body") else {
+    return Vec::new();
+  };
+  let mut cursor = body_node.walk();
+  let mut members = Vec::new();
+  for child in body_node.named_children(&mut cursor) {
+    if child.kind() == "enum_body_declarations" {
+      let mut declarations_cursor = child.walk();
+      members.extend(child.named_children(&mut declarations_cursor));
+    } else {
+      members.push(child);
+    }
+  }
+  members
+}
+
+fn count_declarators(field_node: Node) -> usize {
+  let mut cursor = field_node.walk();
+  field_node
+    .named_children(&mut cursor)
+    .filter(|child| child.kind() == "variable_declarator")
+    .count()
+}
+
+fn is_branch_node(ts_file: &TSFile, node: Node) -> bool {
+  match node.kind() {
+    "switch_label" => {
+      ts_file.get_text_from_node(&node).is_some_and(|text| text.trim_start().starts_with("case"))
+    }
+    "binary_expression" => node
+      .child_by_field_name("operator")
+      .and_then(|operator| ts_file.get_text_from_node(&operator))
+      .is_some_and(|operator| operator == "&&" || operator == "||"),
+    kind => BRANCH_NODE_KINDS.contains(&kind),
+  }
+}
+
+/// 1 plus the branch points found anywhere inside the method or constructor.
+fn get_method_complexity(ts_file: &TSFile, method_node: Node) -> usize {
+  let mut complexity = 1;
+  let mut pending = vec![method_node];
+  while let Some(node) = pending.pop() {
+    if is_branch_node(ts_file, node) {
+      complexity += 1;
+    }
+    let mut cursor = node.walk();
+    pending.extend(node.named_children(&mut cursor));
+  }
+  complexity
+}
+
+/// Counts the lines of the node holding anything other than whitespace and comments.
+fn count_lines_of_code(ts_file: &TSFile, type_node: Node) -> usize {
+  let mut code: Vec<u8> =
+    ts_file.source_code.as_bytes()[type_node.start_byte()..type_node.end_byte()].to_vec();
+  let mut pending = vec![type_node];
+  while let Some(node) = pending.pop() {
+    if matches!(node.kind(), "line_comment" | "block_comment") {
+      for byte in &mut code
+        [node.start_byte() - type_node.start_byte()..node.end_byte() - type_node.start_byte()]
+      {
+        if *byte != b'\n' {
+          *byte = b' ';
+        }
+      }
+      continue;
+    }
+    let mut cursor = node.walk();
+    pending.extend(node.named_children(&mut cursor));
+  }
+  code.split(|byte| *byte == b'\n').filter(|line| !line.trim_ascii().is_empty()).count()
+}
+
+pub fn run(
+  file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  type_name: Option<&str>,
+  cwd: &Path,
+) -> Result<GetClassMetricsResponse, String> {
+  // Step 1: Create TSFile
+  let ts_file = create_ts_file(file_path, b64_source_code, cwd)?;
+  // Step 2: Find the type declaration
+  let type_node =
+    find_type_declaration_node(&ts_file, type_name).ok_or_else(|| match type_name {
+      Some(type_name) => format!("Type '{}' not found in file", type_name),
+      None => "No type declaration found in file".to_string(),
+    })?;
+  let type_kind = get_type_declaration_kind(type_node).unwrap_or_default().to_string();
+  let type_name = get_type_declaration_name(&ts_file, type_node).unwrap_or_default().to_string();
+  // Step 3: Count fields, relationships and methods declared directly in the type
+  let mut field_count = 0;
+  let mut relationship_count = 0;
+  let mut method_count = 0;
+  let mut constructor_count = 0;
+  let mut method_complexities: Vec<(String, usize)> = Vec::new();
+  for member_node in get_member_nodes(type_node) {
+    match member_node.kind() {
+      "field_declaration" | "constant_declaration" => {
+        let declarators_count = count_declarators(member_node);
+        field_count += declarators_count;
+        if RELATIONSHIP_KINDS.iter().any(|kind| {
+          find_declaration_annotation_node_by_name(&ts_file, member_node, kind.annotation_name())
+            .is_some()
+        }) {
+          relationship_count += declarators_count;
+        }
+      }
+      kind @ ("method_declaration"
+      | "annotation_type_element_declaration"
+      | "constructor_declaration"
+      | "compact_constructor_declaration") => {
+        match kind.ends_with("constructor_declaration") {
+          true => constructor_count += 1,
+          false => method_count += 1,
+        }
+        let method_name = member_node
+          .child_by_field_name("name")
+          .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+          .unwrap_or_default()
+          .to_string();
+        method_complexities.push((method_name, get_method_complexity(&ts_file, member_node)));
+      }
+      _ => {}
+    }
+  }
+  // Step 4: Aggregate the method complexities
+  let cyclomatic_complexity = method_complexities.iter().map(|(_, complexity)| complexity).sum();
+  let most_complex_method = method_complexities
+    .iter()
+    .fold(None::<&(String, usize)>, |max, current| match max {
+      Some(max) if max.1 >= current.1 => Some(max),
+      _ => Some(current),
+    })
+    .cloned();
+  // Step 5: Build response
+  Ok(GetClassMetricsResponse {
+    type_name,
+    type_kind,
+    field_count,
+    method_count,
+    constructor_count,
+    lines_of_code: count_lines_of_code(&ts_file, type_node),
+    relationship_count,
+    cyclomatic_complexity,
+    max_method_complexity: most_complex_method
+      .as_ref()
+      .map(|(_, complexity)| *complexity)
+      .unwrap_or_default(),
+    most_complex_method: most_complex_method.map(|(name, _)| name),
+  })
+}
@@ -0,0 +1,60 @@
+use std::path::Path;
+
+use crate::commands::java::responses::get_column_mapping_response::{
+  FieldColumnResponse, GetColumnMappingResponse,
+};
+use crate::commands::java::services::generate_schema_service::{
+  load_project_mappings, resolve_table,
+};
+use crate::commands::java::services::get_jpa_entity_info_service::create_ts_file;
+use crate::commands::java::treesitter::services::entity_mapping_service::get_entity_mapping;
+use crate::commands::java::treesitter::types::entity_mapping::MappedClassKind;
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+pub fn run(
+  entity_file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  cwd: &Path,
+) -> Result<GetColumnMappingResponse, String> {
+  // Step 1: Create TSFile and extract the entity mapping
+  let ts_file = create_ts_file(entity_file_path, b64_source_code, cwd)?;
+  let entity = get_entity_mapping(&ts_file)
+    .filter(|mapping| mapping.kind == MappedClassKind::Entity)
+    .ok_or_else(|| "Class is not annotated with @Entity".to_string())?;
+  // Step 2: Load the project mappings the entity inherits or embeds columns from. The given source
+  // takes precedence over the saved file, which may be outdated
+  let mut project = load_project_mappings(cwd, &ScanDeadline::unbounded());
+  project.classes.insert(entity.class_name.clone(), entity.clone());
+  // Step 3: Resolve the table the same way the DDL generator does
+  let table = resolve_table(&project, &entity).map_err(|reasons| {
+    format!("Unable to resolve the columns of '{}': {}", entity.class_name, reasons.join("; "))
+  })?;
+  // Step 4: Build response
+  let mut columns: Vec<FieldColumnResponse> = table
+    .columns
+    .iter()
+    .map(|column| FieldColumnResponse {
+      field_name: column.field_name.clone(),
+      column_name: column.column_name.clone(),
+      has_explicit_name: column.has_explicit_name,
+      is_id: column.is_id,
+      is_join_column: false,
+    })
+    .collect();
+  columns.extend(table.join_columns.iter().map(|join_column| FieldColumnResponse {
+    field_name: join_column.field_name.clone(),
+    column_name: join_column.column_name.clone(),
+    has_explicit_name: join_column.has_explicit_name,
+    is_id: false,
+    is_join_column: true,
+  }));
+  Ok(GetColumnMappingResponse {
+    entity_type: entity.class_name.clone(),
+    table_name: entity.table_name.clone(),
+    has_explicit_table_name: entity.has_explicit_table_name,
+    schema: entity.schema.clone(),
+    catalog: entity.catalog.clone(),
+    columns_count: columns.len(),
+    columns,
+  })
+}
@@ -0,0 +1,98 @@
+use std::path::Path;
+
+use crate::commands::java::responses::get_constructor_info_response::{
+  ConstructorInfoResponse, ConstructorParameterResponse, GetConstructorInfoResponse,
+};
+use crate::commands::java::services::get_jpa_entity_info_service::create_ts_file;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  find_type_declaration_node, get_type_declaration_kind, get_type_declaration_name,
+};
+use crate::commands::java::treesitter::services::constructor_declaration_service::{
+  get_all_constructor_declaration_nodes, get_constructor_modifier_keywords,
+  get_constructor_parameter_nodes, get_constructor_visibility_modifier,
+  get_parameter_type_and_name, has_no_arg_constructor,
+};
+use crate::common::ts_file::TSFile;
+use tree_sitter::Node;
+
+fn build_constructor_info(
+  ts_file: &TSFile,
+  type_name: &str,
+  constructor_node: Node,
+) -> ConstructorInfoResponse {
+  let parameters: Vec<ConstructorParameterResponse> =
+    get_constructor_parameter_nodes(ts_file, constructor_node)
+      .into_iter()
+      .filter_map(|parameter_node| {
+        let (parameter_type, parameter_name) =
+          get_parameter_type_and_name(ts_file, parameter_node)?;
+        Some(ConstructorParameterResponse {
+          parameter_type: parameter_type.to_string(),
+          parameter_name: parameter_name.to_string(),
+          is_varargs: parameter_node.kind() == "spread_parameter",
+        })
+      })
+      .collect();
+  let modifiers: Vec<String> = get_constructor_modifier_keywords(ts_file, constructor_node)
+    .into_iter()
+    .map(|keyword| keyword.to_string())
+    .collect();
+  let parameters_text = parameters
+    .iter()
+    .map(|parameter| {
+      let separator = if parameter.is_varargs { "... " } else { " " };
+      format!("{}{}{}", parameter.parameter_type, separator, parameter.parameter_name)
+    })
+    .collect::<Vec<_>>()
+    .join(", ");
+  let mut signature = modifiers.join(" ");
+  if !signature.is_empty() {
+    signature.push(' ');
+  }
+  signature.push_str(&format!("{}({})", type_name, parameters_text));
+  ConstructorInfoResponse {
+    signature,
+    visibility: get_constructor_visibility_modifier(ts_file, constructor_node).as_str().to_string(),
+    modifiers,
+    parameters,
+    line: constructor_node.start_position().row + 1,
+  }
+}
+
+pub fn run(
+  file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  type_name: Option<&str>,
+  cwd: &Path,
+) -> Result<GetConstructorInfoResponse, String> {
+  // Step 1: Create TSFile
+  let ts_file = create_ts_file(file_path, b64_source_code, cwd)?;
+  // Step 2: Find the class declaration
+  let type_node =
+    find_type_declaration_node(&ts_file, type_name).ok_or_else(|| match type_name {
+      Some(type_name) => format!("Type '{}' not found in file", type_name),
+      None => "No type declaration found in file".to_string(),
+    })?;
+  let type_name = get_type_declaration_name(&ts_file, type_node).unwrap_or_default().to_string();
+  if type_node.kind() != "class_declaration" {
+    return Err(format!(
+      "Type '{}' is not a class, found {}",
+      type_name,
+      get_type_declaration_kind(type_node).unwrap_or_default()
+    ));
+  }
+  // Step 3: Describe every explicit constructor
+  let constructors: Vec<ConstructorInfoResponse> =
+    get_all_constructor_declaration_nodes(&ts_file, type_node)
+      .into_iter()
+      .map(|constructor_node| build_constructor_info(&ts_file, &type_name, constructor_node))
+      .collect();
+  // Step 4: Build response
+  Ok(GetConstructorInfoResponse {
+    type_name,
+    constructors_count: constructors.len(),
+    has_no_arg: has_no_arg_constructor(&ts_file, type_node),
+    has_implicit_default_constructor: constructors.is_empty(),
+    constructors,
+  })
+}
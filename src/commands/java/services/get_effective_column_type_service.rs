@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::commands::java::responses::get_effective_column_type_response::GetEffectiveColumnTypeResponse;
+use crate::commands::java::services::generate_schema_service::{
+  ProjectMappings, ResolvedTable, build_foreign_keys, load_project_mappings, resolve_table,
+};
+use crate::commands::java::services::get_jpa_entity_info_service::create_ts_file;
+use crate::commands::java::treesitter::services::entity_mapping_service::get_entity_mapping;
+use crate::commands::java::treesitter::types::entity_mapping::{
+  ColumnMapping, JoinColumnMapping, MappedClassKind,
+};
+use crate::commands::java::treesitter::types::sql_dialect::SqlDialect;
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+/// Explains why a column type can't be mapped, listing the mapping inputs that were considered.
+fn describe_unmappable_column(error: String, column: &ColumnMapping) -> String {
+  let mut reasons = vec![format!("'{}' is not a basic JPA type", column.field_type)];
+  if column.enum_type.is_none() {
+    reasons.push("it is not an enum of the project".to_string());
+  }
+  reasons.push("no @Column(columnDefinition) is declared".to_string());
+  format!("{}: {}", error, reasons.join(", "))
+}
+
+fn describe_unmapped_field(table: &ResolvedTable, field_name: &str) -> String {
+  let embedded_prefix = format!("{}.", field_name);
+  match table.columns.iter().find(|column| column.field_name.starts_with(&embedded_prefix)) {
+    Some(embedded_column) => format!(
+      "Field '{}' is embedded, query one of its columns, e.g. '{}'",
+      field_name, embedded_column.field_name
+    ),
+    None => {
+      format!("Field '{}' is not mapped to a column of '{}'", field_name, table.entity.class_name)
+    }
+  }
+}
+
+/// Resolves the type of a join column from the column it references in the target entity.
+fn resolve_join_column_type(
+  project: &ProjectMappings,
+  table: &ResolvedTable,
+  join_column: &JoinColumnMapping,
+  dialect: &SqlDialect,
+) -> Result<(String, String, ColumnMapping), String> {
+  let target = project
+    .classes
+    .get(&join_column.target_type)
+    .filter(|mapping| mapping.kind == MappedClassKind::Entity)
+    .ok_or_else(|| {
+      format!(
+        "Relationship '{}' targets '{}', which is not a mappable entity",
+        join_column.field_name, join_column.target_type
+      )
+    })?;
+  let target_table = resolve_table(project, target).map_err(|reasons| {
+    format!("Unable to resolve the columns of '{}': {}", target.class_name, reasons.join("; "))
+  })?;
+  let join_table = ResolvedTable { join_columns: vec![join_column.clone()], ..table.clone() };
+  let resolved = BTreeMap::from([(target.class_name.clone(), target_table.clone())]);
+  let foreign_key = build_foreign_keys(dialect, &join_table, &resolved)
+    .map_err(|reasons| reasons.join("; "))?
+    .pop()
+    .ok_or_else(|| format!("Unable to resolve the foreign key of '{}'", join_column.field_name))?;
+  let referenced = target_table
+    .columns
+    .into_iter()
+    .find(|column| column.column_name == foreign_key.referenced_column)
+    .ok_or_else(|| {
+      format!("Unable to resolve the column referenced by '{}'", join_column.field_name)
+    })?;
+  Ok((foreign_key.column_type, foreign_key.referenced_entity, referenced))
+}
+
+pub fn run(
+  entity_file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  field_name: &str,
+  dialect: &SqlDialect,
+  cwd: &Path,
+) -> Result<GetEffectiveColumnTypeResponse, String> {
+  // Step 1: Create TSFile and extract the entity mapping
+  let ts_file = create_ts_file(entity_file_path, b64_source_code, cwd)?;
+  let entity = get_entity_mapping(&ts_file)
+    .filter(|mapping| mapping.kind == MappedClassKind::Entity)
+    .ok_or_else(|| "Class is not annotated with @Entity".to_string())?;
+  // Step 2: Load the project mappings, the given source takes precedence over the saved file
+  let mut project = load_project_mappings(cwd, &ScanDeadline::unbounded());
+  project.classes.insert(entity.class_name.clone(), entity.clone());
+  // Step 3: Resolve the table the same way the DDL generator does
+  let table = resolve_table(&project, &entity).map_err(|reasons| {
+    format!("Unable to resolve the columns of '{}': {}", entity.class_name, reasons.join("; "))
+  })?;
+  // Step 4: Resolve the SQL type of the field's column, or of the column a join column references
+  let (sql_type, field_type, column_name, referenced, input_column) =
+    match table.columns.iter().find(|column| column.field_name == field_name) {
+      Some(column) => {
+        let sql_type =
+          dialect.sql_type(column).map_err(|error| describe_unmappable_column(error, column))?;
+        (sql_type, column.field_type.clone(), column.column_name.clone(), None, column.clone())
+      }
+      None => {
+        let join_column = table
+          .join_columns
+          .iter()
+          .find(|join_column| join_column.field_name == field_name)
+          .ok_or_else(|| describe_unmapped_field(&table, field_name))?;
+        let (sql_type, referenced_entity, referenced_column) =
+          resolve_join_column_type(&project, &table, join_column, dialect)?;
+        (
+          sql_type,
+          join_column.target_type.clone(),
+          join_column.column_name.clone(),
+          Some((referenced_entity, referenced_column.column_name.clone())),
+          referenced_column,
+        )
+      }
+    };
+  // Step 5: Build response
+  let (referenced_entity, referenced_column) = referenced.unzip();
+  Ok(GetEffectiveColumnTypeResponse {
+    entity_type: entity.class_name.clone(),
+    field_name: field_name.to_string(),
+    field_type,
+    column_name,
+    dialect: dialect.as_str().to_string(),
+    sql_type,
+    is_join_column: referenced_entity.is_some(),
+    referenced_entity,
+    referenced_column,
+    length: input_column.length,
+    precision: input_column.precision,
+    scale: input_column.scale,
+    is_lob: input_column.is_lob,
+    enum_type: input_column.enum_type,
+    temporal: input_column.temporal,
+    column_definition: input_column.column_definition,
+  })
+}
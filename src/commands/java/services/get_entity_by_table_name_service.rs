@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::commands::java::responses::get_entity_by_table_name_response::{
+  GetEntityByTableNameResponse, TableEntityResponse,
+};
+use crate::commands::java::services::generate_schema_service::{
+  ProjectMappings, load_project_mappings,
+};
+use crate::commands::java::treesitter::types::entity_mapping::{EntityMapping, MappedClassKind};
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+/// Entity owning the table the entity is stored in: the root of its entity hierarchy under
+/// single-table inheritance, the default strategy, otherwise the entity itself.
+fn get_table_owner<'a>(
+  project: &'a ProjectMappings,
+  entity: &'a EntityMapping,
+) -> &'a EntityMapping {
+  let mut root = entity;
+  let mut visited = HashSet::from([entity.class_name.as_str()]);
+  let mut superclass_name = entity.superclass_name.as_deref();
+  // Mapped superclasses in between don't break the hierarchy
+  while let Some(name) = superclass_name
+    && visited.insert(name)
+    && let Some(superclass) = project.classes.get(name)
+  {
+    if superclass.kind == MappedClassKind::Entity {
+      root = superclass;
+    }
+    superclass_name = superclass.superclass_name.as_deref();
+  }
+  match root.inheritance_strategy.as_deref() {
+    None | Some("SINGLE_TABLE") => root,
+    Some(_) => entity,
+  }
+}
+
+/// Table names are compared case-insensitively, without quotes, and with or without the schema.
+fn normalize_table_name(table_name: &str) -> String {
+  table_name.trim().trim_matches(['"', '`']).to_lowercase()
+}
+
+pub fn run(cwd: &Path, table_name: &str) -> Result<GetEntityByTableNameResponse, String> {
+  // Step 1: Validate the table name
+  let normalized_table_name = normalize_table_name(table_name);
+  if normalized_table_name.is_empty() {
+    return Err("Table name can't be empty".to_string());
+  }
+  // Step 2: Load the project mappings
+  let project = load_project_mappings(cwd, &ScanDeadline::unbounded());
+  // Step 3: Match the table of every entity, resolving single-table inheritance
+  let mut entities: Vec<TableEntityResponse> = project
+    .classes
+    .values()
+    .filter(|mapping| mapping.kind == MappedClassKind::Entity)
+    .filter_map(|entity| {
+      let owner = get_table_owner(&project, entity);
+      let matches = [owner.table_name.clone(), owner.qualified_table_name()]
+        .iter()
+        .any(|name| normalize_table_name(name) == normalized_table_name);
+      matches.then(|| TableEntityResponse {
+        entity_type: entity.class_name.clone(),
+        entity_package_name: entity.package_name.clone(),
+        entity_path: entity.file_path.clone(),
+        table_owner_type: owner.class_name.clone(),
+        has_explicit_table_name: owner.has_explicit_table_name,
+      })
+    })
+    .collect();
+  // Step 4: Build response, the table owner first
+  entities.sort_by(|a, b| {
+    (a.entity_type != a.table_owner_type, &a.entity_type)
+      .cmp(&(b.entity_type != b.table_owner_type, &b.entity_type))
+  });
+  Ok(GetEntityByTableNameResponse {
+    table_name: table_name.trim().to_string(),
+    found: !entities.is_empty(),
+    entities,
+  })
+}
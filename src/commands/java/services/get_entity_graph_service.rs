@@ -0,0 +1,325 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::get_entity_graph_response::{
+  EntityGraphResponse, GetEntityGraphResponse,
+};
+use crate::commands::java::services::set_default_fetch_service::get_current_fetch_type;
+use crate::commands::java::treesitter::services::annotation_service::{
+  add_annotation, find_declaration_annotation_node_by_name,
+  find_direct_annotation_value_node_by_key, remove_annotation,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_required_public_class_node,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::{
+  get_own_field_declaration_nodes, is_persistent_field,
+};
+use crate::commands::java::treesitter::services::field_declaration_service::{
+  find_field_declaration_node_by_name, get_field_declaration_name_node,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, get_persistence_package,
+};
+use crate::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
+use crate::commands::java::treesitter::types::fetch_type::FetchType;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::relationship_kind::RelationshipKind;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::case_util::to_pascal_case;
+use crate::common::utils::string_literal_util::to_java_string_literal;
+
+const RELATIONSHIP_KINDS: [RelationshipKind; 4] = [
+  RelationshipKind::ManyToOne,
+  RelationshipKind::OneToMany,
+  RelationshipKind::OneToOne,
+  RelationshipKind::ManyToMany,
+];
+
+/// An association declared by the entity.
+struct Association {
+  field_name: String,
+  is_lazy: bool,
+}
+
+/// A `@NamedEntityGraph` already declared on the entity, kept with its source text so that
+/// attributes this command doesn't manage (e.g. `subgraphs`) are preserved.
+struct ExistingGraph {
+  name: String,
+  annotation_text: String,
+}
+
+fn get_field_name(ts_file: &TSFile, field_node: Node) -> Option<String> {
+  get_field_declaration_name_node(ts_file, field_node)
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+    .map(|name| name.to_string())
+}
+
+fn collect_associations(ts_file: &TSFile, class_node: Node) -> Vec<Association> {
+  let mut associations = Vec::new();
+  for field_node in get_own_field_declaration_nodes(ts_file, class_node) {
+    let Some((kind, annotation_node)) = RELATIONSHIP_KINDS.iter().find_map(|kind| {
+      find_declaration_annotation_node_by_name(ts_file, field_node, kind.annotation_name())
+        .map(|node| (kind, node))
+    }) else {
+      continue;
+    };
+    let Some(field_name) = get_field_name(ts_file, field_node) else {
+      continue;
+    };
+    let fetch_value = find_direct_annotation_value_node_by_key(ts_file, annotation_node, "fetch")
+      .and_then(|value_node| ts_file.get_text_from_node(&value_node));
+    associations.push(Association {
+      field_name,
+      is_lazy: get_current_fetch_type(fetch_value, kind) == FetchType::Lazy,
+    });
+  }
+  associations
+}
+
+fn build_existing_graph(ts_file: &TSFile, graph_node: Node) -> Option<ExistingGraph> {
+  let name = find_direct_annotation_value_node_by_key(ts_file, graph_node, "name")
+    .and_then(|value_node| ts_file.get_text_from_node(&value_node))
+    .map(|literal| literal.trim().trim_matches('"').to_string())?;
+  Some(ExistingGraph {
+    name,
+    annotation_text: ts_file.get_text_from_node(&graph_node)?.to_string(),
+  })
+}
+
+fn collect_existing_graphs(ts_file: &TSFile, class_node: Node) -> Vec<ExistingGraph> {
+  let mut graphs = Vec::new();
+  if let Some(graph_node) =
+    find_declaration_annotation_node_by_name(ts_file, class_node, "NamedEntityGraph")
+  {
+    graphs.extend(build_existing_graph(ts_file, graph_node));
+  }
+  if let Some(value_node) =
+    find_declaration_annotation_node_by_name(ts_file, class_node, "NamedEntityGraphs")
+      .and_then(|node| find_direct_annotation_value_node_by_key(ts_file, node, "value"))
+  {
+    if value_node.kind() == "annotation" {
+      graphs.extend(build_existing_graph(ts_file, value_node));
+    } else {
+      let mut cursor = value_node.walk();
+      let nested: Vec<Node> = value_node
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "annotation")
+        .collect();
+      graphs.extend(nested.into_iter().filter_map(|node| build_existing_graph(ts_file, node)));
+    }
+  }
+  graphs
+}
+
+/// `Order.withItemsAndCustomer` for the attribute nodes `items` and `customer`.
+fn get_default_graph_name(entity_type: &str, attribute_nodes: &[String]) -> String {
+  let attributes: Vec<String> = attribute_nodes.iter().map(|node| to_pascal_case(node)).collect();
+  format!("{}.with{}", entity_type, attributes.join("And"))
+}
+
+/// Suggests a graph per lazy association, the usual source of N+1 selects when it's read in a
+/// loop, and one loading every lazy association. Names already declared aren't suggested.
+fn build_suggestions(
+  entity_type: &str,
+  associations: &[Association],
+  existing_graphs: &[ExistingGraph],
+) -> Vec<EntityGraphResponse> {
+  let lazy_fields: Vec<String> = associations
+    .iter()
+    .filter(|association| association.is_lazy)
+    .map(|association| association.field_name.clone())
+    .collect();
+  let mut suggestions: Vec<EntityGraphResponse> = lazy_fields
+    .iter()
+    .map(|field_name| {
+      let attribute_nodes = vec![field_name.clone()];
+      EntityGraphResponse {
+        name: get_default_graph_name(entity_type, &attribute_nodes),
+        attribute_nodes,
+      }
+    })
+    .collect();
+  if lazy_fields.len() > 1 {
+    suggestions.push(EntityGraphResponse {
+      name: format!("{}.withAssociations", entity_type),
+      attribute_nodes: lazy_fields,
+    });
+  }
+  suggestions
+    .retain(|suggestion| existing_graphs.iter().all(|graph| graph.name != suggestion.name));
+  suggestions
+}
+
+/// Checks every attribute node is a persistent field of the entity.
+///
+/// # Returns
+/// Warnings for the attributes that aren't associations
+fn validate_attribute_nodes(
+  ts_file: &TSFile,
+  entity_type: &str,
+  attribute_nodes: &[String],
+  associations: &[Association],
+) -> Result<Vec<String>, String> {
+  let class_node = get_required_public_class_node(ts_file)?;
+  let mut warnings = Vec::new();
+  for attribute_node in attribute_nodes {
+    let field_node = find_field_declaration_node_by_name(ts_file, attribute_node, class_node)
+      .ok_or_else(|| format!("Field '{}' not found in entity '{}'", attribute_node, entity_type))?;
+    if !is_persistent_field(ts_file, field_node) {
+      return Err(format!(
+        "Field '{}' is not persistent and can't be part of an entity graph",
+        attribute_node
+      ));
+    }
+    if associations.iter().all(|association| &association.field_name != attribute_node) {
+      warnings.push(format!(
+        "'{}' is not an association, the graph only changes how it loads when it's lazily fetched",
+        attribute_node
+      ));
+    }
+  }
+  Ok(warnings)
+}
+
+fn build_graph_text(graph: &EntityGraphResponse) -> String {
+  let attribute_nodes: Vec<String> = graph
+    .attribute_nodes
+    .iter()
+    .map(|attribute_node| format!("@NamedAttributeNode(\"{}\")", attribute_node))
+    .collect();
+  format!(
+    "@NamedEntityGraph(name = {}, attributeNodes = {{{}}})",
+    to_java_string_literal(&graph.name),
+    attribute_nodes.join(", ")
+  )
+}
+
+fn build_graphs_annotation(annotation_texts: &[String], indentation: &str) -> String {
+  if annotation_texts.len() == 1 {
+    return annotation_texts[0].clone();
+  }
+  let nested = annotation_texts
+    .iter()
+    .map(|annotation_text| format!("{}  {}", indentation, annotation_text))
+    .collect::<Vec<_>>()
+    .join(",\n");
+  format!("@NamedEntityGraphs({{\n{}\n{}}})", nested, indentation)
+}
+
+fn remove_existing_graphs(ts_file: &mut TSFile) -> Result<(), String> {
+  for annotation_name in ["NamedEntityGraphs", "NamedEntityGraph"] {
+    loop {
+      let annotation_start_byte = {
+        let class_node = get_required_public_class_node(ts_file)?;
+        match find_declaration_annotation_node_by_name(ts_file, class_node, annotation_name) {
+          Some(node) => node.start_byte(),
+          None => break,
+        }
+      };
+      if !remove_annotation(ts_file, annotation_start_byte) {
+        return Err(format!("Unable to remove @{} annotation", annotation_name));
+      }
+    }
+  }
+  Ok(())
+}
+
+fn add_imports(ts_file: &mut TSFile, graphs_count: usize) {
+  let import_position = ImportInsertionPosition::BeforeFirstImport;
+  let persistence_package = get_persistence_package(ts_file);
+  add_import(ts_file, &import_position, persistence_package, "NamedAttributeNode");
+  add_import(ts_file, &import_position, persistence_package, "NamedEntityGraph");
+  if graphs_count > 1 {
+    add_import(ts_file, &import_position, persistence_package, "NamedEntityGraphs");
+  }
+}
+
+/// Suggests `@NamedEntityGraph` definitions for the entity's lazy associations, and declares the
+/// graph loading `attribute_nodes` when any are given.
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  graph_name: Option<&str>,
+  attribute_nodes: &[String],
+) -> Result<GetEntityGraphResponse, String> {
+  // Step 1: Parse entity file
+  let mut entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  // Step 2: Collect the associations and the graphs already declared
+  let (entity_type, associations, existing_graphs, indentation) = {
+    let class_node = get_required_public_class_node(&entity_ts_file)?;
+    if find_declaration_annotation_node_by_name(&entity_ts_file, class_node, "Entity").is_none() {
+      return Err("Class is not annotated with @Entity".to_string());
+    }
+    let entity_type = get_class_declaration_name_node(&entity_ts_file, class_node)
+      .and_then(|name_node| entity_ts_file.get_text_from_node(&name_node))
+      .map(|name| name.to_string())
+      .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+    (
+      entity_type,
+      collect_associations(&entity_ts_file, class_node),
+      collect_existing_graphs(&entity_ts_file, class_node),
+      entity_ts_file.get_line_indentation(class_node.start_byte()),
+    )
+  };
+  let suggestions = build_suggestions(&entity_type, &associations, &existing_graphs);
+  let entity_file_path_string = entity_file_path.display().to_string();
+  if attribute_nodes.is_empty() {
+    return Ok(GetEntityGraphResponse {
+      entity_type,
+      entity_file_path: entity_file_path_string,
+      generated_graph: None,
+      suggestions,
+      warnings: Vec::new(),
+    });
+  }
+  // Step 3: Validate the graph
+  let mut graph_attribute_nodes: Vec<String> = Vec::new();
+  for attribute_node in attribute_nodes {
+    if !graph_attribute_nodes.contains(attribute_node) {
+      graph_attribute_nodes.push(attribute_node.clone());
+    }
+  }
+  let warnings =
+    validate_attribute_nodes(&entity_ts_file, &entity_type, &graph_attribute_nodes, &associations)?;
+  let graph = EntityGraphResponse {
+    name: graph_name
+      .map(|name| name.to_string())
+      .unwrap_or_else(|| get_default_graph_name(&entity_type, &graph_attribute_nodes)),
+    attribute_nodes: graph_attribute_nodes,
+  };
+  if existing_graphs.iter().any(|existing| existing.name == graph.name) {
+    return Err(format!("Entity already declares an entity graph named '{}'", graph.name));
+  }
+  // Step 4: Replace the existing graph annotations with the merged ones
+  let mut annotation_texts: Vec<String> =
+    existing_graphs.into_iter().map(|existing| existing.annotation_text).collect();
+  annotation_texts.push(build_graph_text(&graph));
+  remove_existing_graphs(&mut entity_ts_file)?;
+  let class_start_byte = get_required_public_class_node(&entity_ts_file)?.start_byte();
+  add_annotation(
+    &mut entity_ts_file,
+    class_start_byte,
+    &AnnotationInsertionPosition::AboveScopeDeclaration,
+    &build_graphs_annotation(&annotation_texts, &indentation),
+  )
+  .ok_or_else(|| "Unable to add @NamedEntityGraph annotation".to_string())?;
+  // Step 5: Add imports
+  add_imports(&mut entity_ts_file, annotation_texts.len());
+  // Step 6: Save file with working directory validation
+  entity_ts_file
+    .save_to_existing_file(entity_file_path, cwd)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 7: Build and return response
+  Ok(GetEntityGraphResponse {
+    entity_type,
+    entity_file_path: entity_file_path_string,
+    generated_graph: Some(graph),
+    suggestions,
+    warnings,
+  })
+}
@@ -0,0 +1,71 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::get_file_diagnostics_response::{
+  FileDiagnosticResponse, GetFileDiagnosticsResponse,
+};
+use crate::commands::java::services::get_jpa_entity_info_service::create_ts_file;
+use crate::common::ts_file::TSFile;
+
+const MAX_SNIPPET_LENGTH: usize = 40;
+
+/// Short description of an error node, quoting the start of the unparsable text.
+fn get_message(ts_file: &TSFile, node: Node) -> String {
+  if node.is_missing() {
+    return format!("Missing '{}'", node.kind());
+  }
+  let text = ts_file.get_text_from_node(&node).unwrap_or_default();
+  match text.lines().map(str::trim).find(|line| !line.is_empty()) {
+    Some(line) if line.chars().count() > MAX_SNIPPET_LENGTH => {
+      format!("Unexpected '{}...'", line.chars().take(MAX_SNIPPET_LENGTH).collect::<String>())
+    }
+    Some(line) => format!("Unexpected '{}'", line),
+    None => "Syntax error".to_string(),
+  }
+}
+
+/// Collects the error and missing nodes in source order. Errors nested in a reported error node
+/// describe the same problem and are skipped.
+fn collect_diagnostics(
+  ts_file: &TSFile,
+  node: Node,
+  diagnostics: &mut Vec<FileDiagnosticResponse>,
+) {
+  if !node.has_error() {
+    return;
+  }
+  if node.is_error() || node.is_missing() {
+    diagnostics.push(FileDiagnosticResponse {
+      kind: match node.is_missing() {
+        true => "missing_token".to_string(),
+        false => "syntax_error".to_string(),
+      },
+      message: get_message(ts_file, node),
+      start_line: node.start_position().row + 1,
+      start_column: node.start_position().column + 1,
+      end_line: node.end_position().row + 1,
+      end_column: node.end_position().column + 1,
+    });
+    return;
+  }
+  let mut cursor = node.walk();
+  for child in node.children(&mut cursor) {
+    collect_diagnostics(ts_file, child, diagnostics);
+  }
+}
+
+pub fn run(
+  file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  cwd: &Path,
+) -> Result<GetFileDiagnosticsResponse, String> {
+  // Step 1: Create TSFile
+  let ts_file = create_ts_file(file_path, b64_source_code, cwd)?;
+  let root_node = ts_file.tree.as_ref().ok_or("Unable to parse source code")?.root_node();
+  // Step 2: Collect the diagnostics of the whole file
+  let mut diagnostics = Vec::new();
+  collect_diagnostics(&ts_file, root_node, &mut diagnostics);
+  // Step 3: Build response
+  Ok(GetFileDiagnosticsResponse { diagnostics })
+}
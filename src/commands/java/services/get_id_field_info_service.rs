@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::get_id_field_info_response::{
+  GetIdFieldInfoResponse, IdFieldInfoResponse,
+};
+use crate::commands::java::services::get_jpa_entity_info_service::create_ts_file;
+use crate::commands::java::treesitter::services::annotation_service::find_annotation_node_by_name;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_name, get_public_class_node, get_superclass_name,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::{
+  get_id_class_name, get_id_field_mappings,
+};
+use crate::commands::java::treesitter::types::entity_mapping::IdFieldMapping;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::find_file_by_class_name;
+
+/// Id fields of a class of the hierarchy, with the class declaring them.
+struct DeclaredIds {
+  declaring_class: String,
+  id_fields: Vec<IdFieldMapping>,
+  id_class_type: Option<String>,
+}
+
+fn get_declared_ids(ts_file: &TSFile, class_node: Node) -> Option<DeclaredIds> {
+  let id_fields = get_id_field_mappings(ts_file, class_node);
+  if id_fields.is_empty() {
+    return None;
+  }
+  Some(DeclaredIds {
+    declaring_class: get_class_name(ts_file, class_node)?,
+    id_fields,
+    id_class_type: get_id_class_name(ts_file, class_node),
+  })
+}
+
+/// Walks the `@MappedSuperclass`/`@Entity` superclasses declared in the project, nearest first,
+/// until one declares id fields. The walk stops at a superclass that can't be found or isn't
+/// mapped.
+fn find_inherited_ids(cwd: &Path, ts_file: &TSFile, class_node: Node) -> Option<DeclaredIds> {
+  let mut superclass_name = get_superclass_name(ts_file, class_node)?;
+  let mut visited = HashSet::new();
+  while visited.insert(superclass_name.clone()) {
+    let superclass_file =
+      find_file_by_class_name(cwd, &JavaSourceDirectoryType::Main, &superclass_name)?;
+    let superclass_node = get_public_class_node(&superclass_file)?;
+    let is_mapped = ["MappedSuperclass", "Entity"].iter().any(|annotation| {
+      find_annotation_node_by_name(&superclass_file, superclass_node, annotation).is_some()
+    });
+    if !is_mapped {
+      return None;
+    }
+    if let Some(declared_ids) = get_declared_ids(&superclass_file, superclass_node) {
+      return Some(declared_ids);
+    }
+    superclass_name = get_superclass_name(&superclass_file, superclass_node)?;
+  }
+  None
+}
+
+pub fn run(
+  entity_file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  cwd: &Path,
+) -> Result<GetIdFieldInfoResponse, String> {
+  // Step 1: Create TSFile
+  let ts_file = create_ts_file(entity_file_path, b64_source_code, cwd)?;
+  // Step 2: Get public class node and its name
+  let class_node =
+    get_public_class_node(&ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  let entity_type = get_class_name(&ts_file, class_node)
+    .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+  // Step 3: Find the id fields in the class, otherwise in its mapped superclasses
+  let declared_ids = get_declared_ids(&ts_file, class_node)
+    .or_else(|| find_inherited_ids(cwd, &ts_file, class_node))
+    .ok_or_else(|| {
+      format!(
+        "NO_ID_FOUND: No @Id or @EmbeddedId field found in '{}' or its mapped superclasses",
+        entity_type
+      )
+    })?;
+  // Step 4: An @IdClass may be declared by the entity even when the ids are inherited
+  let id_class_type =
+    declared_ids.id_class_type.or_else(|| get_id_class_name(&ts_file, class_node));
+  // Step 5: Build and return response
+  let is_embedded_id = declared_ids.id_fields.iter().any(|id_field| id_field.is_embedded_id);
+  let is_composite = is_embedded_id || id_class_type.is_some() || declared_ids.id_fields.len() > 1;
+  Ok(GetIdFieldInfoResponse {
+    is_inherited: declared_ids.declaring_class != entity_type,
+    entity_type,
+    declaring_class: declared_ids.declaring_class,
+    id_fields: declared_ids
+      .id_fields
+      .into_iter()
+      .map(|id_field| IdFieldInfoResponse {
+        field_name: id_field.field_name,
+        field_type: id_field.field_type,
+        generation_strategy: id_field.generation_strategy,
+        generator_name: id_field.generator_name,
+        sequence_name: id_field.sequence_name,
+      })
+      .collect(),
+    is_composite,
+    is_embedded_id,
+    id_class_type,
+  })
+}
@@ -1,4 +1,5 @@
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 use crate::{
   commands::java::{
@@ -15,7 +16,13 @@ use crate::{
       types::{java_file_type::JavaFileType, java_source_directory_type::JavaSourceDirectoryType},
     },
   },
-  common::{ts_file::TSFile, utils::path_util::parse_all_files},
+  common::{
+    ts_file::TSFile,
+    utils::{
+      path_util::{get_all_java_file_paths, get_modified_time_ms, parse_files_within_deadline},
+      scan_deadline::ScanDeadline,
+    },
+  },
 };
 
 fn create_file_response(ts_file: &TSFile) -> Option<FileResponse> {
@@ -32,13 +39,39 @@ fn create_file_response(ts_file: &TSFile) -> Option<FileResponse> {
   } else {
     return None;
   };
-  let found_file = FileResponse { file_type, file_package_name, file_path };
+  let found_file = FileResponse {
+    file_type,
+    file_package_name,
+    file_path,
+    content_hash: None,
+    warnings: Vec::new(),
+  };
   Some(found_file)
 }
 
-pub fn run(cwd: &Path, java_file_type: &JavaFileType) -> Result<Vec<FileResponse>, String> {
+/// Lists the project's files declaring a public type of the given kind, with their modification
+/// times keyed by path.
+///
+/// With `modified_since_ms` only files modified after it are returned. They are picked from the
+/// filesystem metadata, so unchanged files are never read or parsed.
+pub fn run(
+  cwd: &Path,
+  java_file_type: &JavaFileType,
+  modified_since_ms: Option<u64>,
+  deadline: &ScanDeadline,
+) -> Result<(Vec<FileResponse>, BTreeMap<String, u64>), String> {
   let mut files: Vec<FileResponse> = Vec::new();
-  let ts_files = parse_all_files(cwd, &JavaSourceDirectoryType::Main);
+  let mut modified_times: BTreeMap<String, u64> = BTreeMap::new();
+  for path in get_all_java_file_paths(cwd, &JavaSourceDirectoryType::Main) {
+    let Some(modified_time) = get_modified_time_ms(&path) else {
+      continue;
+    };
+    if modified_since_ms.is_none_or(|since| modified_time > since) {
+      modified_times.insert(path.display().to_string(), modified_time);
+    }
+  }
+  let paths: Vec<PathBuf> = modified_times.keys().map(PathBuf::from).collect();
+  let ts_files = parse_files_within_deadline(cwd, &paths, deadline);
   for ts_file in ts_files {
     match java_file_type {
       JavaFileType::Class => match get_public_class_node(&ts_file) {
@@ -83,5 +116,11 @@ pub fn run(cwd: &Path, java_file_type: &JavaFileType) -> Result<Vec<FileResponse
       },
     }
   }
-  Ok(files)
+  let listed_modified_times = files
+    .iter()
+    .filter_map(|file| {
+      modified_times.get(&file.file_path).map(|time| (file.file_path.clone(), *time))
+    })
+    .collect();
+  Ok((files, listed_modified_times))
 }
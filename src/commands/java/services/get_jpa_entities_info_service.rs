@@ -0,0 +1,85 @@
+use std::path::{Path, PathBuf};
+
+use crate::commands::java::responses::get_jpa_entities_info_response::{
+  GetJpaEntitiesInfoResponse, JpaEntityInfoResultResponse,
+};
+use crate::commands::java::responses::get_jpa_entity_info_response::GetJpaEntityInfoResponse;
+use crate::commands::java::services::get_jpa_entity_info_service::get_entity_info;
+use crate::commands::java::treesitter::services::annotation_service::find_annotation_node_by_name;
+use crate::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::parse_all_files_within_deadline;
+use crate::common::utils::scan_deadline::ScanDeadline;
+use crate::common::validators::directory_validator::validate_file_path_within_base;
+
+fn to_result(
+  file_path: String,
+  info: Result<GetJpaEntityInfoResponse, String>,
+) -> JpaEntityInfoResultResponse {
+  match info {
+    Ok(data) => {
+      JpaEntityInfoResultResponse { file_path, succeed: true, data: Some(data), error_reason: None }
+    }
+    Err(error_reason) => JpaEntityInfoResultResponse {
+      file_path,
+      succeed: false,
+      data: None,
+      error_reason: Some(error_reason),
+    },
+  }
+}
+
+/// Parses a single requested file, failing when it lies outside the working directory.
+fn parse_requested_file(cwd: &Path, entity_file_path: &Path) -> Result<TSFile, String> {
+  validate_file_path_within_base(&entity_file_path.display().to_string(), cwd)
+    .map_err(|e| format!("Entity file path must be within working directory: {}", e))?;
+  TSFile::from_file(entity_file_path, cwd, SupportedLanguage::Java).map_err(|e| e.to_string())
+}
+
+fn is_entity_file(ts_file: &TSFile) -> bool {
+  get_public_class_node(ts_file)
+    .is_some_and(|class_node| find_annotation_node_by_name(ts_file, class_node, "Entity").is_some())
+}
+
+/// Collects the entity information of every requested file, or of every `@Entity` class of the
+/// main source directory when `entity_file_paths` is `None`. Each file is parsed once and a file
+/// that cannot be read or parsed is reported in its own result instead of failing the batch.
+pub fn run(
+  cwd: &Path,
+  entity_file_paths: Option<&[PathBuf]>,
+  deadline: &ScanDeadline,
+) -> Result<GetJpaEntitiesInfoResponse, String> {
+  // Step 1: Extract the information of each requested file, or of every entity in the project
+  let entities: Vec<JpaEntityInfoResultResponse> = match entity_file_paths {
+    None => parse_all_files_within_deadline(cwd, &JavaSourceDirectoryType::Main, deadline)
+      .iter()
+      .filter(|ts_file| is_entity_file(ts_file))
+      .map(|ts_file| {
+        let file_path =
+          ts_file.file_path().map(|path| path.display().to_string()).unwrap_or_default();
+        to_result(file_path, get_entity_info(ts_file))
+      })
+      .collect(),
+    Some(entity_file_paths) => entity_file_paths
+      .iter()
+      .take_while(|_| !deadline.is_expired())
+      .map(|entity_file_path| {
+        let info =
+          parse_requested_file(cwd, entity_file_path).and_then(|ts_file| get_entity_info(&ts_file));
+        deadline.mark_processed();
+        to_result(entity_file_path.display().to_string(), info)
+      })
+      .collect(),
+  };
+  // Step 2: Build and return response
+  let succeeded_count = entities.iter().filter(|entity| entity.succeed).count();
+  Ok(GetJpaEntitiesInfoResponse {
+    failed_count: entities.len() - succeeded_count,
+    succeeded_count,
+    entities,
+    truncated: deadline.is_truncated(),
+    processed_files_count: deadline.processed_files_count(),
+  })
+}
@@ -211,12 +211,13 @@ fn get_package_for_type(type_name: &str) -> String {
   }
 }
 
-fn create_ts_file(
-  entity_file_path: Option<&Path>,
+/// Parses the file at `file_path`, or else the base64 encoded source code.
+pub fn create_ts_file(
+  file_path: Option<&Path>,
   b64_source_code: Option<&str>,
   cwd: &Path,
 ) -> Result<TSFile, String> {
-  if let Some(path) = entity_file_path {
+  if let Some(path) = file_path {
     Ok(TSFile::from_file(path, cwd, SupportedLanguage::Java).map_err(|e| e.to_string())?)
   } else if let Some(b64) = b64_source_code {
     let bytes = decode_base64_to_bytes(b64)?;
@@ -247,29 +248,24 @@ fn get_entity_table_name(ts_file: &TSFile, class_declaration_node: &Node) -> Opt
   }
 }
 
-pub fn run(
-  entity_file_path: Option<&Path>,
-  b64_source_code: Option<&str>,
-  cwd: &Path,
-) -> Result<GetJpaEntityInfoResponse, String> {
-  // Step 1: Create TSFile
-  let ts_file = create_ts_file(entity_file_path, b64_source_code, cwd)?;
-  // Step 2: Get public class node
-  let public_class_node = get_public_class_node(&ts_file)?;
-  // Step 3: Check if class is JPA entity
-  let is_jpa_entity = check_is_jpa_entity(&ts_file, &public_class_node)?;
-  // Step 4: Extract class name
-  let entity_type = extract_entity_type(&ts_file, &public_class_node)?;
-  // Step 5: Extract package name
-  let entity_package_name = extract_entity_package_scope(&ts_file)?;
-  // Step 6: Find ID field info
-  let (id_field_type, id_field_package_name) = find_id_field_info(&ts_file, &public_class_node)?;
-  // Step 7: Build and return response
+/// Extracts the entity information of an already parsed file.
+pub fn get_entity_info(ts_file: &TSFile) -> Result<GetJpaEntityInfoResponse, String> {
+  // Step 1: Get public class node
+  let public_class_node = get_public_class_node(ts_file)?;
+  // Step 2: Check if class is JPA entity
+  let is_jpa_entity = check_is_jpa_entity(ts_file, &public_class_node)?;
+  // Step 3: Extract class name
+  let entity_type = extract_entity_type(ts_file, &public_class_node)?;
+  // Step 4: Extract package name
+  let entity_package_name = extract_entity_package_scope(ts_file)?;
+  // Step 5: Find ID field info
+  let (id_field_type, id_field_package_name) = find_id_field_info(ts_file, &public_class_node)?;
+  // Step 6: Build and return response
   let entity_path = ts_file.file_path().map(|path| path.to_string_lossy().to_string());
-  // Step 8: Get superclass type
-  let superclass_name = get_superclass_name(&ts_file, &public_class_node);
-  // Step 9: Get entity table name
-  let entity_table_name = get_entity_table_name(&ts_file, &public_class_node);
+  // Step 7: Get superclass type
+  let superclass_name = get_superclass_name(ts_file, &public_class_node);
+  // Step 8: Get entity table name
+  let entity_table_name = get_entity_table_name(ts_file, &public_class_node);
   Ok(GetJpaEntityInfoResponse {
     is_jpa_entity,
     entity_table_name,
@@ -281,3 +277,12 @@ pub fn run(
     superclass_type: superclass_name,
   })
 }
+
+pub fn run(
+  entity_file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  cwd: &Path,
+) -> Result<GetJpaEntityInfoResponse, String> {
+  let ts_file = create_ts_file(entity_file_path, b64_source_code, cwd)?;
+  get_entity_info(&ts_file)
+}
@@ -0,0 +1,253 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use tree_sitter::{Node, Point};
+
+use crate::commands::java::responses::get_method_callers_response::{
+  GetMethodCallersResponse, MethodCallSiteResponse,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_type_declaration_kind, get_type_declaration_name,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::get_simple_type_name;
+use crate::commands::java::treesitter::services::method_invocation_service::{
+  get_enclosing_method_name, get_enclosing_type_names, get_method_invocation_arguments_count,
+  get_method_invocation_nodes_by_name, resolve_identifier_type,
+};
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::parse_all_files_within_deadline;
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+struct TargetMethod {
+  name: String,
+  declaring_type: String,
+  parameters_count: usize,
+  is_varargs: bool,
+}
+
+impl TargetMethod {
+  fn accepts_arguments_count(&self, arguments_count: usize) -> bool {
+    match self.is_varargs {
+      true => arguments_count + 1 >= self.parameters_count,
+      false => arguments_count == self.parameters_count,
+    }
+  }
+}
+
+/// Innermost method declaration spanning the 1-based line.
+fn find_method_declaration_at_line<'a>(ts_file: &'a TSFile, line: usize) -> Option<Node<'a>> {
+  let line_text = ts_file.source_code.lines().nth(line.checked_sub(1)?)?;
+  let start_column = line_text.len() - line_text.trim_start().len();
+  let point = Point::new(line - 1, start_column);
+  let mut node =
+    ts_file.tree.as_ref()?.root_node().named_descendant_for_point_range(point, point)?;
+  loop {
+    if node.kind() == "method_declaration" {
+      return Some(node);
+    }
+    node = node.parent()?;
+  }
+}
+
+fn get_target_method(ts_file: &TSFile, line: usize) -> Result<TargetMethod, String> {
+  let method_node = find_method_declaration_at_line(ts_file, line)
+    .ok_or_else(|| format!("No method declaration found at line {}", line))?;
+  let name = method_node
+    .child_by_field_name("name")
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+    .ok_or_else(|| "Couldn't get the method name from the tree".to_string())?
+    .to_string();
+  let declaring_type = get_enclosing_type_names(ts_file, method_node)
+    .first()
+    .ok_or_else(|| "Method is not declared in a type".to_string())?
+    .to_string();
+  let parameter_nodes: Vec<Node> = method_node
+    .child_by_field_name("parameters")
+    .map(|parameters| {
+      let mut cursor = parameters.walk();
+      parameters
+        .named_children(&mut cursor)
+        .filter(|child| matches!(child.kind(), "formal_parameter" | "spread_parameter"))
+        .collect()
+    })
+    .unwrap_or_default();
+  Ok(TargetMethod {
+    name,
+    declaring_type,
+    parameters_count: parameter_nodes.len(),
+    is_varargs: parameter_nodes.last().is_some_and(|node| node.kind() == "spread_parameter"),
+  })
+}
+
+/// Direct supertypes (`extends` and `implements`) of every type declared in the files.
+fn collect_supertypes(ts_files: &[TSFile]) -> HashMap<String, Vec<String>> {
+  let mut supertypes: HashMap<String, Vec<String>> = HashMap::new();
+  for ts_file in ts_files {
+    let Some(tree) = ts_file.tree.as_ref() else {
+      continue;
+    };
+    let mut pending = vec![tree.root_node()];
+    while let Some(node) = pending.pop() {
+      let mut cursor = node.walk();
+      pending.extend(node.named_children(&mut cursor));
+      let Some(type_name) =
+        get_type_declaration_kind(node).and_then(|_| get_type_declaration_name(ts_file, node))
+      else {
+        continue;
+      };
+      let mut cursor = node.walk();
+      for child in node.named_children(&mut cursor) {
+        if !matches!(child.kind(), "superclass" | "super_interfaces" | "extends_interfaces") {
+          continue;
+        }
+        let mut type_cursor = child.walk();
+        let type_list =
+          child.named_children(&mut type_cursor).flat_map(|type_node| match type_node.kind() {
+            "type_list" => {
+              let mut list_cursor = type_node.walk();
+              type_node.named_children(&mut list_cursor).collect::<Vec<_>>()
+            }
+            _ => vec![type_node],
+          });
+        let entry = supertypes.entry(type_name.to_string()).or_default();
+        entry.extend(
+          type_list
+            .filter_map(|type_node| ts_file.get_text_from_node(&type_node))
+            .map(get_simple_type_name),
+        );
+      }
+    }
+  }
+  supertypes
+}
+
+/// The declaring type and every type of the project inheriting from it, directly or not.
+fn collect_receiver_types(
+  declaring_type: &str,
+  supertypes: &HashMap<String, Vec<String>>,
+) -> HashSet<String> {
+  let mut receiver_types = HashSet::from([declaring_type.to_string()]);
+  loop {
+    let subtypes: Vec<String> = supertypes
+      .iter()
+      .filter(|(type_name, parents)| {
+        !receiver_types.contains(*type_name)
+          && parents.iter().any(|parent| receiver_types.contains(parent))
+      })
+      .map(|(type_name, _)| type_name.clone())
+      .collect();
+    if subtypes.is_empty() {
+      return receiver_types;
+    }
+    receiver_types.extend(subtypes);
+  }
+}
+
+/// Whether the invocation may call the target method.
+///
+/// # Returns
+/// `Some(true)` when the receiver resolves to a receiver type, `Some(false)` when it can't be
+/// resolved, `None` when it resolves to an unrelated type
+fn match_receiver(
+  ts_file: &TSFile,
+  invocation_node: Node,
+  receiver_types: &HashSet<String>,
+) -> Option<bool> {
+  let is_receiver_type = |type_name: &str| receiver_types.contains(type_name);
+  let enclosing_matches =
+    || get_enclosing_type_names(ts_file, invocation_node).into_iter().any(is_receiver_type);
+  let Some(object_node) = invocation_node.child_by_field_name("object") else {
+    return enclosing_matches().then_some(true);
+  };
+  let object_text = ts_file.get_text_from_node(&object_node)?;
+  let identifier = match object_node.kind() {
+    "this" | "super" => return enclosing_matches().then_some(true),
+    "identifier" => object_text,
+    // `this.repository.save(...)` resolves the field like a plain identifier
+    "field_access"
+      if object_node
+        .child_by_field_name("object")
+        .is_some_and(|object| object.kind() == "this") =>
+    {
+      let field_node = object_node.child_by_field_name("field")?;
+      ts_file.get_text_from_node(&field_node)?
+    }
+    _ => return Some(false),
+  };
+  match resolve_identifier_type(ts_file, invocation_node, identifier) {
+    Some(type_name) => is_receiver_type(&type_name).then_some(true),
+    // An undeclared capitalized identifier is a static call on a type
+    None if identifier.starts_with(char::is_uppercase) => {
+      is_receiver_type(identifier).then_some(true)
+    }
+    None => Some(false),
+  }
+}
+
+fn collect_call_sites(
+  ts_file: &TSFile,
+  target_method: &TargetMethod,
+  receiver_types: &HashSet<String>,
+) -> Vec<MethodCallSiteResponse> {
+  let file_path = ts_file.file_path().map(|path| path.display().to_string()).unwrap_or_default();
+  get_method_invocation_nodes_by_name(ts_file, &target_method.name)
+    .into_iter()
+    .filter(|node| {
+      target_method.accepts_arguments_count(get_method_invocation_arguments_count(*node))
+    })
+    .filter_map(|node| {
+      let receiver_resolved = match_receiver(ts_file, node, receiver_types)?;
+      let name_position = node.child_by_field_name("name")?.start_position();
+      Some(MethodCallSiteResponse {
+        file_path: file_path.clone(),
+        line: name_position.row + 1,
+        column: name_position.column + 1,
+        enclosing_method: get_enclosing_method_name(ts_file, node).map(|name| name.to_string()),
+        enclosing_type: get_enclosing_type_names(ts_file, node)
+          .first()
+          .map(|name| name.to_string()),
+        receiver: node
+          .child_by_field_name("object")
+          .and_then(|object| ts_file.get_text_from_node(&object))
+          .map(|text| text.to_string()),
+        receiver_resolved,
+      })
+    })
+    .collect()
+}
+
+pub fn run(
+  cwd: &Path,
+  file_path: &Path,
+  line: usize,
+  deadline: &ScanDeadline,
+) -> Result<GetMethodCallersResponse, String> {
+  // Step 1: Parse the file declaring the method and find the method at the line
+  let ts_file =
+    TSFile::from_file(file_path, cwd, SupportedLanguage::Java).map_err(|e| e.to_string())?;
+  let target_method = get_target_method(&ts_file, line)?;
+  // Step 2: Parse the main and test sources
+  let ts_files = parse_all_files_within_deadline(cwd, &JavaSourceDirectoryType::All, deadline);
+  // Step 3: Calls may go through the declaring type or any of its subtypes
+  let receiver_types =
+    collect_receiver_types(&target_method.declaring_type, &collect_supertypes(&ts_files));
+  // Step 4: Match the invocations by name, arguments count and receiver type
+  let mut call_sites: Vec<MethodCallSiteResponse> = ts_files
+    .iter()
+    .flat_map(|ts_file| collect_call_sites(ts_file, &target_method, &receiver_types))
+    .collect();
+  call_sites
+    .sort_by(|a, b| (&a.file_path, a.line, a.column).cmp(&(&b.file_path, b.line, b.column)));
+  // Step 5: Build and return response
+  Ok(GetMethodCallersResponse {
+    method_name: target_method.name,
+    declaring_type: target_method.declaring_type,
+    parameters_count: target_method.parameters_count,
+    call_sites_count: call_sites.len(),
+    call_sites,
+    truncated: deadline.is_truncated(),
+    processed_files_count: deadline.processed_files_count(),
+  })
+}
@@ -0,0 +1,85 @@
+use std::path::Path;
+
+use tree_sitter::{Node, Point};
+
+use crate::commands::java::responses::get_parse_tree_response::{
+  GetParseTreeResponse, ParseErrorNodeResponse,
+};
+use crate::commands::java::services::get_jpa_entity_info_service::create_ts_file;
+use crate::common::ts_file::TSFile;
+
+/// Smallest named node spanning the non-blank content of a 1-based line.
+fn find_line_node<'a>(
+  ts_file: &TSFile,
+  root_node: Node<'a>,
+  line: usize,
+) -> Result<Node<'a>, String> {
+  let line_text = ts_file
+    .source_code
+    .lines()
+    .nth(line.wrapping_sub(1))
+    .ok_or_else(|| format!("Line {} is out of range", line))?;
+  let start_column = line_text.len() - line_text.trim_start().len();
+  let end_column = line_text.trim_end().len();
+  let start_point = Point::new(line - 1, start_column);
+  let end_point = Point::new(line - 1, end_column.max(start_column));
+  root_node
+    .named_descendant_for_point_range(start_point, end_point)
+    .ok_or_else(|| format!("No node found at line {}", line))
+}
+
+fn collect_error_nodes(
+  ts_file: &TSFile,
+  node: Node,
+  error_nodes: &mut Vec<ParseErrorNodeResponse>,
+) {
+  if !node.has_error() {
+    return;
+  }
+  if node.is_error() || node.is_missing() {
+    let kind = match node.is_missing() {
+      true => format!("MISSING {}", node.kind()),
+      false => "ERROR".to_string(),
+    };
+    error_nodes.push(ParseErrorNodeResponse {
+      kind,
+      start_line: node.start_position().row + 1,
+      start_column: node.start_position().column + 1,
+      end_line: node.end_position().row + 1,
+      end_column: node.end_position().column + 1,
+      text: ts_file.get_text_from_node(&node).unwrap_or_default().to_string(),
+    });
+  }
+  let mut cursor = node.walk();
+  for child in node.children(&mut cursor) {
+    collect_error_nodes(ts_file, child, error_nodes);
+  }
+}
+
+pub fn run(
+  file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  line: Option<usize>,
+  cwd: &Path,
+) -> Result<GetParseTreeResponse, String> {
+  // Step 1: Create TSFile
+  let ts_file = create_ts_file(file_path, b64_source_code, cwd)?;
+  let root_node = ts_file.tree.as_ref().ok_or("Unable to parse source code")?.root_node();
+  // Step 2: Select the whole tree or the subtree around the requested line
+  let node = match line {
+    Some(line) => find_line_node(&ts_file, root_node, line)?,
+    None => root_node,
+  };
+  // Step 3: Collect the error nodes of the whole file
+  let mut error_nodes = Vec::new();
+  collect_error_nodes(&ts_file, root_node, &mut error_nodes);
+  // Step 4: Build response
+  Ok(GetParseTreeResponse {
+    s_expression: node.to_sexp(),
+    node_kind: node.kind().to_string(),
+    start_line: node.start_position().row + 1,
+    end_line: node.end_position().row + 1,
+    has_errors: !error_nodes.is_empty(),
+    error_nodes,
+  })
+}
@@ -0,0 +1,124 @@
+use std::path::Path;
+
+use crate::commands::java::responses::get_relationship_inverse_response::{
+  GetRelationshipInverseResponse, InverseFieldResponse,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::{
+  find_inverse_relationship_field, get_relationship_field_mappings,
+};
+use crate::commands::java::treesitter::services::field_declaration_service::{
+  find_field_declaration_node_by_name, get_field_declaration_name_node,
+};
+use crate::commands::java::treesitter::types::entity_side::EntitySide;
+use crate::commands::java::treesitter::types::inverse_resolution::InverseResolution;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::find_file_by_class_name;
+use crate::common::validators::directory_validator::validate_file_path_within_base;
+
+fn get_entity_type(ts_file: &TSFile) -> Result<String, String> {
+  let class_node =
+    get_public_class_node(ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  get_class_declaration_name_node(ts_file, class_node)
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+    .map(|name| name.to_string())
+    .ok_or_else(|| "Couldn't get the class name from the tree".to_string())
+}
+
+/// 1-based line and column of the field's name.
+fn get_field_position(ts_file: &TSFile, field_name: &str) -> Option<(usize, usize)> {
+  let class_node = get_public_class_node(ts_file)?;
+  let field_node = find_field_declaration_node_by_name(ts_file, field_name, class_node)?;
+  let name_position = get_field_declaration_name_node(ts_file, field_node)?.start_position();
+  Some((name_position.row + 1, name_position.column + 1))
+}
+
+/// Resolves the field on the other side of an owning relationship, for navigating between both
+/// sides. Reports the relationship as unidirectional when the target entity doesn't map it back.
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+) -> Result<GetRelationshipInverseResponse, String> {
+  // Step 1: Parse entity file and find the owning relationship field
+  let ts_file = TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  let entity_type = get_entity_type(&ts_file)?;
+  let relationship = get_public_class_node(&ts_file)
+    .map(|class_node| get_relationship_field_mappings(&ts_file, class_node))
+    .unwrap_or_default()
+    .into_iter()
+    .find(|relationship| relationship.field_name == field_name)
+    .ok_or_else(|| format!("Field '{}' is not a relationship of '{}'", field_name, entity_type))?;
+  if relationship.side == EntitySide::Inverse {
+    return Err(format!(
+      "Field '{}' is the inverse side of the relationship, mapped by '{}.{}'",
+      field_name,
+      relationship.target_type,
+      relationship.mapped_by.as_deref().unwrap_or_default()
+    ));
+  }
+  // Step 2: Resolve the target entity, the same file for self-references
+  let is_self_reference = relationship.target_type == entity_type;
+  let target = match is_self_reference {
+    true => None,
+    false => Some(
+      find_file_by_class_name(cwd, &JavaSourceDirectoryType::Main, &relationship.target_type)
+        .ok_or_else(|| {
+          format!("Target entity '{}' not found in the project", relationship.target_type)
+        })?,
+    ),
+  };
+  let target_ts_file = target.as_ref().unwrap_or(&ts_file);
+  let target_file_path = match &target {
+    Some(target_ts_file) => target_ts_file
+      .file_path()
+      .map(|path| path.display().to_string())
+      .ok_or_else(|| "Unable to get the target entity file path".to_string())?,
+    None => entity_file_path.display().to_string(),
+  };
+  validate_file_path_within_base(&target_file_path, cwd)
+    .map_err(|e| format!("Target entity file path must be within working directory: {}", e))?;
+  // Step 3: Inspect the target's fields for the inverse side
+  let target_class_node = get_public_class_node(target_ts_file)
+    .ok_or_else(|| "Unable to get public class node of the target entity".to_string())?;
+  let inverse =
+    find_inverse_relationship_field(target_ts_file, target_class_node, &entity_type, &relationship);
+  let mut warnings = Vec::new();
+  let inverse = match inverse {
+    Some((inverse, resolved_by)) => {
+      if resolved_by == InverseResolution::FieldType {
+        warnings.push(format!(
+          "'{}.{}' has no mappedBy = \"{}\", JPA maps it as a separate relationship",
+          relationship.target_type, inverse.field_name, field_name
+        ));
+      }
+      let (line, column) = get_field_position(target_ts_file, &inverse.field_name)
+        .ok_or_else(|| format!("Unable to locate field '{}'", inverse.field_name))?;
+      Some(InverseFieldResponse {
+        field_name: inverse.field_name,
+        field_type: inverse.field_type,
+        relationship_kind: inverse.kind,
+        resolved_by,
+        file_path: target_file_path.clone(),
+        line,
+        column,
+      })
+    }
+    None => None,
+  };
+  // Step 4: Build response
+  Ok(GetRelationshipInverseResponse {
+    entity_type,
+    field_name: field_name.to_string(),
+    target_type: relationship.target_type,
+    target_file_path,
+    bidirectional: inverse.is_some(),
+    inverse,
+    warnings,
+  })
+}
@@ -0,0 +1,81 @@
+use std::path::Path;
+
+use crate::commands::java::responses::get_relationship_targets_response::{
+  GetRelationshipTargetsResponse, RelationshipTargetResponse,
+};
+use crate::commands::java::services::generate_schema_service::load_project_mappings;
+use crate::commands::java::services::get_jpa_entity_info_service::create_ts_file;
+use crate::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+use crate::commands::java::treesitter::services::entity_mapping_service::{
+  get_class_mapping, get_relationship_field_mappings,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  find_imported_class_package, resolve_fully_qualified_type_name,
+};
+use crate::commands::java::treesitter::types::entity_mapping::EntityMapping;
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+fn get_fully_qualified_class_name(mapping: &EntityMapping) -> String {
+  match &mapping.package_name {
+    Some(package_name) => format!("{}.{}", package_name, mapping.class_name),
+    None => mapping.class_name.clone(),
+  }
+}
+
+pub fn run(
+  entity_file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  cwd: &Path,
+) -> Result<GetRelationshipTargetsResponse, String> {
+  // Step 1: Create TSFile and find the mapped class
+  let ts_file = create_ts_file(entity_file_path, b64_source_code, cwd)?;
+  let class_node =
+    get_public_class_node(&ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  let entity = get_class_mapping(&ts_file, class_node).ok_or_else(|| {
+    "Class is not annotated with @Entity, @MappedSuperclass or @Embeddable".to_string()
+  })?;
+  // Step 2: Collect the relationship fields
+  let relationships = get_relationship_field_mappings(&ts_file, class_node);
+  // Step 3: Scan the project for the target entities
+  let project = load_project_mappings(cwd, &ScanDeadline::unbounded());
+  // Step 4: Resolve each target through the imports, then the project scan, then the file's
+  // own package
+  let relationships: Vec<RelationshipTargetResponse> = relationships
+    .into_iter()
+    .map(|relationship| {
+      let project_class = project.classes.get(&relationship.target_type);
+      let target_fully_qualified_name =
+        match find_imported_class_package(&ts_file, &relationship.target_type) {
+          Some(package_name) => Some(format!("{}.{}", package_name, relationship.target_type)),
+          None => match project_class {
+            Some(target) => Some(get_fully_qualified_class_name(target)),
+            None => resolve_fully_qualified_type_name(&ts_file, &relationship.target_type),
+          },
+        };
+      // The scanned class is only the target when it's the one the name resolves to
+      let target_file_path = project_class
+        .filter(|target| {
+          target_fully_qualified_name.as_deref()
+            == Some(get_fully_qualified_class_name(target).as_str())
+        })
+        .and_then(|target| target.file_path.clone());
+      RelationshipTargetResponse {
+        field_name: relationship.field_name,
+        field_type: relationship.field_type,
+        target_type: relationship.target_type,
+        target_fully_qualified_name,
+        target_file_path,
+        relationship_kind: relationship.kind,
+        side: relationship.side,
+        mapped_by: relationship.mapped_by,
+        is_collection: relationship.is_collection,
+      }
+    })
+    .collect();
+  // Step 5: Build response
+  Ok(GetRelationshipTargetsResponse {
+    entity_type: entity.class_name,
+    relationships_count: relationships.len(),
+    relationships,
+  })
+}
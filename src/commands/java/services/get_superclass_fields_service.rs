@@ -0,0 +1,139 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::get_superclass_fields_response::{
+  GetSuperclassFieldsResponse, SuperclassFieldResponse,
+};
+use crate::commands::java::services::get_jpa_entity_info_service::create_ts_file;
+use crate::commands::java::treesitter::services::annotation_service::find_annotation_node_by_name;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_name, get_public_class_node, get_superclass_name,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::{
+  get_own_field_declaration_nodes, is_persistent_field,
+};
+use crate::commands::java::treesitter::services::field_declaration_service::get_field_declaration_name_node;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::parse_all_files;
+
+/// Collects the persistent fields declared directly in `class_node`.
+pub fn collect_persistent_fields(
+  ts_file: &TSFile,
+  class_node: Node,
+  declaring_class: &str,
+  is_inherited: bool,
+) -> Vec<SuperclassFieldResponse> {
+  let mut fields = Vec::new();
+  for field_node in get_own_field_declaration_nodes(ts_file, class_node) {
+    if !is_persistent_field(ts_file, field_node) {
+      continue;
+    }
+    let field_name = get_field_declaration_name_node(ts_file, field_node)
+      .and_then(|node| ts_file.get_text_from_node(&node));
+    let field_type =
+      field_node.child_by_field_name("type").and_then(|node| ts_file.get_text_from_node(&node));
+    if let (Some(field_name), Some(field_type)) = (field_name, field_type) {
+      let is_id = find_annotation_node_by_name(ts_file, field_node, "Id").is_some()
+        || find_annotation_node_by_name(ts_file, field_node, "EmbeddedId").is_some();
+      fields.push(SuperclassFieldResponse {
+        field_name: field_name.to_string(),
+        field_type: field_type.to_string(),
+        declaring_class: declaring_class.to_string(),
+        is_id,
+        is_inherited,
+      });
+    }
+  }
+  fields
+}
+
+fn is_persistent_superclass(ts_file: &TSFile, class_node: Node) -> bool {
+  find_annotation_node_by_name(ts_file, class_node, "MappedSuperclass").is_some()
+    || find_annotation_node_by_name(ts_file, class_node, "Entity").is_some()
+}
+
+/// Indexes every class under `cwd` by its public class name so the superclass chain can be
+/// walked without re-parsing the project for each level.
+fn index_project_classes(cwd: &Path) -> HashMap<String, TSFile> {
+  let mut classes = HashMap::new();
+  for ts_file in parse_all_files(cwd, &JavaSourceDirectoryType::Main) {
+    let class_name =
+      get_public_class_node(&ts_file).and_then(|class_node| get_class_name(&ts_file, class_node));
+    if let Some(class_name) = class_name {
+      classes.entry(class_name).or_insert(ts_file);
+    }
+  }
+  classes
+}
+
+/// Walks the superclass chain of `class_node` within `cwd`, returning the names of every resolved
+/// superclass (nearest first) and the persistent fields contributed by the `@MappedSuperclass` or
+/// `@Entity` ancestors. The walk stops at the first superclass that can't be found in the project.
+pub fn resolve_inherited_fields(
+  cwd: &Path,
+  ts_file: &TSFile,
+  class_node: Node,
+) -> (Vec<String>, Vec<SuperclassFieldResponse>) {
+  let mut chain = Vec::new();
+  let mut fields = Vec::new();
+  let Some(mut superclass_name) = get_superclass_name(ts_file, class_node) else {
+    return (chain, fields);
+  };
+  let project_classes = index_project_classes(cwd);
+  let mut visited = HashSet::new();
+  while visited.insert(superclass_name.clone()) {
+    let Some(superclass_file) = project_classes.get(&superclass_name) else {
+      break;
+    };
+    let Some(superclass_node) = get_public_class_node(superclass_file) else {
+      break;
+    };
+    chain.push(superclass_name.clone());
+    if is_persistent_superclass(superclass_file, superclass_node) {
+      fields.extend(collect_persistent_fields(
+        superclass_file,
+        superclass_node,
+        &superclass_name,
+        true,
+      ));
+    }
+    match get_superclass_name(superclass_file, superclass_node) {
+      Some(name) => superclass_name = name,
+      None => break,
+    }
+  }
+  (chain, fields)
+}
+
+pub fn run(
+  entity_file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  cwd: &Path,
+) -> Result<GetSuperclassFieldsResponse, String> {
+  // Step 1: Create TSFile
+  let ts_file = create_ts_file(entity_file_path, b64_source_code, cwd)?;
+  // Step 2: Get public class node
+  let class_node =
+    get_public_class_node(&ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  // Step 3: Extract class name
+  let class_type = get_class_name(&ts_file, class_node)
+    .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+  // Step 4: Walk the superclass chain and collect inherited fields
+  let (superclass_chain, inherited_fields) = resolve_inherited_fields(cwd, &ts_file, class_node);
+  // Step 5: Collect own fields after the inherited ones
+  let mut fields = inherited_fields;
+  fields.extend(collect_persistent_fields(&ts_file, class_node, &class_type, false));
+  // Step 6: Resolve the id field type from the whole hierarchy
+  let id_field_type = fields.iter().find(|field| field.is_id).map(|field| field.field_type.clone());
+  let fields_count = fields.len();
+  Ok(GetSuperclassFieldsResponse {
+    class_type,
+    superclass_chain,
+    id_field_type,
+    fields,
+    fields_count,
+  })
+}
@@ -0,0 +1,257 @@
+use std::path::{Path, PathBuf};
+
+use tree_sitter::{Node, Point};
+
+use crate::commands::java::responses::get_type_definition_location_response::{
+  GetTypeDefinitionLocationResponse, TypeDefinitionLocationResponse,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_type_declaration_kind, get_type_declaration_name,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  get_all_import_declaration_nodes, resolve_fully_qualified_type_name,
+};
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::get_all_java_file_paths;
+
+/// Project file declaring a top-level type with the referenced name.
+struct CandidateFile {
+  file_path: PathBuf,
+  ts_file: TSFile,
+  package_name: Option<String>,
+}
+
+fn starts_with_uppercase(name: &str) -> bool {
+  name.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+}
+
+/// Type reference at the 1-based position, as written up to the referenced segment: `Order` for
+/// `Order` and `com.example.Order`'s last segment, `Outer` for the first segment of `Outer.Inner`.
+fn find_type_reference_at(ts_file: &TSFile, line: usize, column: usize) -> Result<String, String> {
+  let not_found = || format!("No type reference found at line {}, column {}", line, column);
+  let point = Point::new(line.checked_sub(1).ok_or_else(not_found)?, column.saturating_sub(1));
+  let node = ts_file
+    .tree
+    .as_ref()
+    .and_then(|tree| tree.root_node().named_descendant_for_point_range(point, point))
+    .ok_or_else(not_found)?;
+  let parent = node.parent();
+  let is_type_name = match node.kind() {
+    "type_identifier" => true,
+    "identifier" => parent.is_some_and(|parent| match parent.kind() {
+      "scoped_identifier" | "annotation" | "marker_annotation" => true,
+      "field_access" | "method_invocation" => {
+        parent.child_by_field_name("object").is_some_and(|object| object.id() == node.id())
+      }
+      _ => false,
+    }),
+    _ => false,
+  };
+  let text = ts_file.get_text_from_node(&node).filter(|text| starts_with_uppercase(text));
+  if !is_type_name || text.is_none() {
+    return Err(not_found());
+  }
+  // Qualified names keep the segments written before the referenced one
+  let mut qualified_node = node;
+  while let Some(parent) = qualified_node.parent()
+    && matches!(parent.kind(), "scoped_type_identifier" | "scoped_identifier")
+  {
+    qualified_node = parent;
+  }
+  Ok(ts_file.source_code[qualified_node.start_byte()..node.end_byte()].to_string())
+}
+
+/// Type declarations under the node, nested ones included when `recursive` is set.
+fn collect_type_declarations<'a>(
+  node: Node<'a>,
+  recursive: bool,
+  declarations: &mut Vec<Node<'a>>,
+) {
+  let mut cursor = node.walk();
+  for child in node.named_children(&mut cursor) {
+    if get_type_declaration_kind(child).is_some() {
+      declarations.push(child);
+      if !recursive {
+        continue;
+      }
+    }
+    if recursive || child.kind().ends_with("_body") {
+      collect_type_declarations(child, recursive, declarations);
+    }
+  }
+}
+
+/// Declaration of the type path (`Outer`, `Outer.Inner`) starting at the given top-level or local
+/// declarations.
+fn find_nested_type_declaration<'a>(
+  ts_file: &'a TSFile,
+  declarations: Vec<Node<'a>>,
+  type_path: &[&str],
+) -> Option<Node<'a>> {
+  let (type_name, nested_path) = type_path.split_first()?;
+  let declaration = declarations
+    .into_iter()
+    .find(|declaration| get_type_declaration_name(ts_file, *declaration) == Some(*type_name))?;
+  if nested_path.is_empty() {
+    return Some(declaration);
+  }
+  let mut nested_declarations = Vec::new();
+  collect_type_declarations(
+    declaration.child_by_field_name("body")?,
+    false,
+    &mut nested_declarations,
+  );
+  find_nested_type_declaration(ts_file, nested_declarations, nested_path)
+}
+
+fn build_location(
+  ts_file: &TSFile,
+  file_path: &Path,
+  declaration: Node,
+  package_name: Option<&str>,
+) -> Option<TypeDefinitionLocationResponse> {
+  let name_position = declaration.child_by_field_name("name")?.start_position();
+  // Nested types are named after the types enclosing them
+  let mut type_names = Vec::new();
+  let mut current_node = Some(declaration);
+  while let Some(node) = current_node {
+    if get_type_declaration_kind(node).is_some() {
+      type_names.push(get_type_declaration_name(ts_file, node)?);
+    }
+    current_node = node.parent();
+  }
+  type_names.reverse();
+  let type_name = type_names.join(".");
+  Some(TypeDefinitionLocationResponse {
+    fully_qualified_name: match package_name {
+      Some(package_name) => format!("{}.{}", package_name, type_name),
+      None => type_name,
+    },
+    file_path: file_path.display().to_string(),
+    line: name_position.row + 1,
+    column: name_position.column + 1,
+  })
+}
+
+fn find_candidate_files(cwd: &Path, type_name: &str) -> Vec<CandidateFile> {
+  get_all_java_file_paths(cwd, &JavaSourceDirectoryType::All)
+    .into_iter()
+    .filter(|path| path.file_stem().is_some_and(|stem| stem == type_name))
+    .filter_map(|file_path| {
+      let ts_file = TSFile::from_file(&file_path, cwd, SupportedLanguage::Java).ok()?;
+      let package_name = get_package_name(&ts_file);
+      Some(CandidateFile { file_path, ts_file, package_name })
+    })
+    .collect()
+}
+
+/// Packages a simple name may come from when no single-type import names it: the on-demand
+/// imports and the file's own package.
+fn get_searched_packages(ts_file: &TSFile) -> Vec<String> {
+  let mut packages: Vec<String> = get_all_import_declaration_nodes(ts_file)
+    .into_iter()
+    .filter_map(|node| ts_file.get_text_from_node(&node))
+    .filter_map(|text| {
+      let text = text.trim_start_matches("import").trim().trim_end_matches(';').trim();
+      text.strip_suffix(".*").filter(|_| !text.starts_with("static ")).map(|p| p.to_string())
+    })
+    .collect();
+  packages.extend(get_package_name(ts_file));
+  packages
+}
+
+pub fn run(
+  cwd: &Path,
+  file_path: &Path,
+  line: usize,
+  column: usize,
+) -> Result<GetTypeDefinitionLocationResponse, String> {
+  // Step 1: Find the type reference at the position
+  let ts_file = TSFile::from_file(file_path, cwd, SupportedLanguage::Java)
+    .map_err(|e| format!("Unable to parse '{}': {}", file_path.display(), e))?;
+  let type_name = find_type_reference_at(&ts_file, line, column)?;
+  let segments: Vec<&str> = type_name.split('.').collect();
+  let first_type_index = segments.iter().position(|segment| starts_with_uppercase(segment));
+  let (package_segments, type_path) = segments.split_at(first_type_index.unwrap_or(0));
+  let response = |fully_qualified_name, is_external, locations: Vec<_>| {
+    Ok(GetTypeDefinitionLocationResponse {
+      type_name: type_name.clone(),
+      fully_qualified_name,
+      is_external,
+      ambiguous: locations.len() > 1,
+      locations,
+    })
+  };
+  // Step 2: Types declared by the file itself shadow the imported ones
+  let own_package_name = get_package_name(&ts_file);
+  if package_segments.is_empty() {
+    let mut declarations = Vec::new();
+    if let Some(tree) = ts_file.tree.as_ref() {
+      collect_type_declarations(tree.root_node(), true, &mut declarations);
+    }
+    let location =
+      find_nested_type_declaration(&ts_file, declarations, type_path).and_then(|declaration| {
+        build_location(&ts_file, file_path, declaration, own_package_name.as_deref())
+      });
+    if let Some(location) = location {
+      return response(Some(location.fully_qualified_name.clone()), false, vec![location]);
+    }
+  }
+  // Step 3: Resolve the package through the imports, then look for the type in the project
+  let (package_name, is_resolved) = if package_segments.is_empty() {
+    match resolve_fully_qualified_type_name(&ts_file, type_path[0]) {
+      Some(fully_qualified_name) => {
+        let package_name = fully_qualified_name.rsplit_once('.').map(|(p, _)| p.to_string());
+        let is_own_package = package_name.is_some() && package_name == own_package_name;
+        (package_name, !is_own_package)
+      }
+      None => (None, false),
+    }
+  } else {
+    (Some(package_segments.join(".")), true)
+  };
+  let candidate_files = find_candidate_files(cwd, type_path[0]);
+  let searched_packages = match &package_name {
+    Some(package_name) => vec![package_name.clone()],
+    None => get_searched_packages(&ts_file),
+  };
+  let locate = |candidate: &CandidateFile| {
+    let mut declarations = Vec::new();
+    collect_type_declarations(
+      candidate.ts_file.tree.as_ref()?.root_node(),
+      false,
+      &mut declarations,
+    );
+    let declaration = find_nested_type_declaration(&candidate.ts_file, declarations, type_path)?;
+    let package_name = candidate.package_name.as_deref();
+    build_location(&candidate.ts_file, &candidate.file_path, declaration, package_name)
+  };
+  let locations: Vec<TypeDefinitionLocationResponse> = candidate_files
+    .iter()
+    .filter(|candidate| {
+      candidate.package_name.as_ref().is_some_and(|package| searched_packages.contains(package))
+    })
+    .filter_map(locate)
+    .collect();
+  if !locations.is_empty() {
+    let fully_qualified_name =
+      (locations.len() == 1).then(|| locations[0].fully_qualified_name.clone());
+    return response(fully_qualified_name, false, locations);
+  }
+  // Step 4: Unresolved names fall back to every same-named type of the project
+  let fully_qualified_name =
+    package_name.map(|package| format!("{}.{}", package, type_path.join(".")));
+  if !is_resolved {
+    let locations: Vec<TypeDefinitionLocationResponse> =
+      candidate_files.iter().filter_map(locate).collect();
+    if !locations.is_empty() {
+      let fully_qualified_name =
+        (locations.len() == 1).then(|| locations[0].fully_qualified_name.clone());
+      return response(fully_qualified_name, false, locations);
+    }
+  }
+  response(fully_qualified_name, true, Vec::new())
+}
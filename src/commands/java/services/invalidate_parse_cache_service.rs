@@ -0,0 +1,14 @@
+use std::path::Path;
+
+use crate::commands::java::responses::invalidate_parse_cache_response::InvalidateParseCacheResponse;
+use crate::common::utils::parse_cache;
+
+/// Drops the serve session's cached parse of `file_path`, or of every file when `None`, so the next
+/// field insertion parses the file from scratch after it changed outside of the session.
+pub fn run(file_path: Option<&Path>) -> Result<InvalidateParseCacheResponse, String> {
+  let invalidated_files_count = parse_cache::invalidate(file_path);
+  Ok(InvalidateParseCacheResponse {
+    file_path: file_path.map(|path| path.display().to_string()),
+    invalidated_files_count,
+  })
+}
@@ -0,0 +1,81 @@
+use std::any::TypeId;
+use std::path::PathBuf;
+
+use clap::{Arg, ArgAction, Command};
+
+use crate::commands::java::responses::list_commands_response::{
+  CommandArgumentResponse, CommandInfoResponse, ListCommandsResponse,
+};
+
+/// Type of the value an argument's parser produces.
+fn get_value_type(arg: &Arg) -> &'static str {
+  if !arg.get_possible_values().is_empty() {
+    return "enum";
+  }
+  let type_id = arg.get_value_parser().type_id();
+  let integer_types =
+    [TypeId::of::<u32>(), TypeId::of::<u64>(), TypeId::of::<usize>(), TypeId::of::<i64>()];
+  if integer_types.iter().any(|integer_type| type_id == *integer_type) {
+    "integer"
+  } else if type_id == TypeId::of::<f64>() {
+    "number"
+  } else if type_id == TypeId::of::<PathBuf>() {
+    "path"
+  } else {
+    "string"
+  }
+}
+
+fn build_argument(arg: &Arg) -> Option<CommandArgumentResponse> {
+  let name = arg.get_long()?;
+  // Flags take no value, `true`/`false` and their default only matter to clap
+  if matches!(arg.get_action(), ArgAction::SetTrue | ArgAction::SetFalse) {
+    return Some(CommandArgumentResponse {
+      name: name.to_string(),
+      value_type: "boolean".to_string(),
+      required: false,
+      multiple: false,
+      default_value: None,
+      possible_values: Vec::new(),
+    });
+  }
+  Some(CommandArgumentResponse {
+    name: name.to_string(),
+    value_type: get_value_type(arg).to_string(),
+    required: arg.is_required_set(),
+    multiple: matches!(arg.get_action(), ArgAction::Append) || arg.get_value_delimiter().is_some(),
+    default_value: arg
+      .get_default_values()
+      .first()
+      .map(|default_value| default_value.to_string_lossy().to_string()),
+    possible_values: arg
+      .get_possible_values()
+      .iter()
+      .map(|possible_value| possible_value.get_name().to_string())
+      .collect(),
+  })
+}
+
+/// Describes every subcommand of `command` from its clap definition, so the listing follows the
+/// CLI as it changes. Commands behind the `ui` feature are the interactive forms, named `*-ui`.
+pub fn run(command: &Command) -> Result<ListCommandsResponse, String> {
+  let commands: Vec<CommandInfoResponse> = command
+    .get_subcommands()
+    .filter(|subcommand| !subcommand.is_hide_set())
+    .map(|subcommand| CommandInfoResponse {
+      name: subcommand.get_name().to_string(),
+      description: subcommand.get_about().map(|about| about.to_string()),
+      requires_ui_feature: subcommand.get_name().ends_with("-ui"),
+      arguments: subcommand
+        .get_arguments()
+        .filter(|arg| !arg.is_hide_set() && !arg.is_global_set())
+        .filter_map(build_argument)
+        .collect(),
+    })
+    .collect();
+  Ok(ListCommandsResponse {
+    commands_count: commands.len(),
+    commands,
+    ui_feature_enabled: cfg!(feature = "ui"),
+  })
+}
@@ -0,0 +1,113 @@
+use std::path::Path;
+
+use crate::commands::java::responses::make_entity_immutable_response::MakeEntityImmutableResponse;
+use crate::commands::java::treesitter::services::annotation_service::{
+  add_annotation, find_annotation_node_by_name,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::get_own_field_declaration_nodes;
+use crate::commands::java::treesitter::services::field_declaration_service::get_field_declaration_name_node;
+use crate::commands::java::treesitter::services::import_declaration_service::add_import;
+use crate::commands::java::treesitter::services::method_declaration_service::{
+  find_method_declaration_node_by_name, get_setter_name, is_generated_setter,
+  remove_method_declaration,
+};
+use crate::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+
+/// Finds the setter of the first field that still has one, telling whether it was generated.
+fn find_next_setter(ts_file: &TSFile, skipped_setters: &[String]) -> Option<(String, usize, bool)> {
+  let class_node = get_public_class_node(ts_file)?;
+  get_own_field_declaration_nodes(ts_file, class_node).into_iter().find_map(|field_node| {
+    let field_name = get_field_declaration_name_node(ts_file, field_node)
+      .and_then(|node| ts_file.get_text_from_node(&node))?;
+    let setter_name = get_setter_name(field_name);
+    if skipped_setters.contains(&setter_name) {
+      return None;
+    }
+    let setter_node = find_method_declaration_node_by_name(ts_file, class_node, &setter_name)?;
+    let is_generated = is_generated_setter(ts_file, setter_node, field_name);
+    Some((setter_name, setter_node.start_byte(), is_generated))
+  })
+}
+
+/// Marks an entity as read-only with Hibernate's `@Immutable`. Fields are left non-final, as JPA
+/// requires persistent fields to be assignable by the provider.
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  remove_setters: bool,
+) -> Result<MakeEntityImmutableResponse, String> {
+  // Step 1: Parse entity file
+  let mut entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  // Step 2: Validate the class is an entity
+  let (entity_type, class_start_byte, annotation_added) = {
+    let class_node = get_public_class_node(&entity_ts_file)
+      .ok_or_else(|| "Unable to get public class node".to_string())?;
+    if find_annotation_node_by_name(&entity_ts_file, class_node, "Entity").is_none() {
+      return Err("Class is not annotated with @Entity".to_string());
+    }
+    let entity_type = get_class_declaration_name_node(&entity_ts_file, class_node)
+      .and_then(|name_node| entity_ts_file.get_text_from_node(&name_node))
+      .map(|name| name.to_string())
+      .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+    let annotation_added =
+      find_annotation_node_by_name(&entity_ts_file, class_node, "Immutable").is_none();
+    (entity_type, class_node.start_byte(), annotation_added)
+  };
+  let mut warnings = vec![format!(
+    "Updates to {} entities are ignored by Hibernate and never flushed to the database",
+    entity_type
+  )];
+  // Step 3: Annotate the class and import @Immutable
+  if annotation_added {
+    add_annotation(
+      &mut entity_ts_file,
+      class_start_byte,
+      &AnnotationInsertionPosition::AboveScopeDeclaration,
+      "@Immutable",
+    )
+    .ok_or_else(|| "Unable to add @Immutable annotation".to_string())?;
+    add_import(
+      &mut entity_ts_file,
+      &ImportInsertionPosition::BeforeFirstImport,
+      "org.hibernate.annotations",
+      "Immutable",
+    );
+  }
+  // Step 4: Strip the generated setters, keeping the ones with custom logic
+  let mut removed_setters = Vec::new();
+  if remove_setters {
+    let mut kept_setters = Vec::new();
+    while let Some((setter_name, setter_start_byte, is_generated)) =
+      find_next_setter(&entity_ts_file, &kept_setters)
+    {
+      if is_generated && remove_method_declaration(&mut entity_ts_file, setter_start_byte) {
+        removed_setters.push(setter_name);
+      } else {
+        warnings.push(format!("Setter '{}' has custom logic and was kept", setter_name));
+        kept_setters.push(setter_name);
+      }
+    }
+  }
+  // Step 5: Save file with working directory validation
+  if annotation_added || !removed_setters.is_empty() {
+    entity_ts_file
+      .save_to_existing_file(entity_file_path, cwd)
+      .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  }
+  // Step 6: Build and return response
+  Ok(MakeEntityImmutableResponse {
+    entity_file_path: entity_file_path.display().to_string(),
+    entity_type,
+    annotation_added,
+    removed_setters,
+    warnings,
+  })
+}
@@ -0,0 +1,253 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::merge_packages_response::{
+  MergePackagesResponse, MovedFileResponse,
+};
+use crate::commands::java::services::create_java_file_service::build_save_path;
+use crate::commands::java::treesitter::services::package_declaration_service::{
+  get_package_declaration_node, get_package_name, get_package_scope_node,
+};
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::{get_all_java_file_paths, read_scanned_source};
+use crate::common::utils::progress;
+use crate::common::utils::scan_deadline::ScanDeadline;
+use crate::common::validators::directory_validator::validate_file_path_within_base;
+
+/// Node kinds holding a qualified name: imports (`scoped_identifier`), types
+/// (`scoped_type_identifier`) and static references in expressions (`field_access`).
+const QUALIFIED_NAME_KINDS: [&str; 3] =
+  ["scoped_identifier", "scoped_type_identifier", "field_access"];
+
+/// File of the source package, with the path it moves to.
+struct PackageFile {
+  type_name: String,
+  old_file_path: PathBuf,
+  new_file_path: PathBuf,
+}
+
+/// Lists the files declaring `source_package` in the given source directory. Files of its
+/// subpackages are left where they are.
+fn find_package_files(
+  cwd: &Path,
+  source_directory: &JavaSourceDirectoryType,
+  source_package: &str,
+  destination_package: &str,
+) -> Result<Vec<PackageFile>, String> {
+  let source_dir = source_directory.get_full_path(cwd, source_package);
+  let mut files = Vec::new();
+  for old_file_path in get_all_java_file_paths(cwd, source_directory) {
+    if old_file_path.parent() != Some(source_dir.as_path()) {
+      continue;
+    }
+    let ts_file = TSFile::from_file(&old_file_path, cwd, SupportedLanguage::Java)
+      .map_err(|e| format!("Unable to parse '{}': {}", old_file_path.display(), e))?;
+    if get_package_name(&ts_file).as_deref() != Some(source_package) {
+      continue;
+    }
+    let file_name = old_file_path.file_name().unwrap_or_default().to_string_lossy().to_string();
+    validate_file_path_within_base(&old_file_path.display().to_string(), cwd)?;
+    let new_file_path = build_save_path(source_directory, cwd, destination_package, &file_name)?;
+    files.push(PackageFile {
+      type_name: file_name.trim_end_matches(".java").to_string(),
+      old_file_path,
+      new_file_path,
+    });
+  }
+  Ok(files)
+}
+
+/// Collects the byte ranges of the qualified names pointing to a moved type, or to the source
+/// package in an on-demand import, and whether each belongs to an import. The package declaration
+/// is left alone.
+fn collect_reference_rewrites(
+  ts_file: &TSFile,
+  node: Node,
+  source_package: &str,
+  moved_types: &[&str],
+  rewrites: &mut Vec<(usize, usize, bool)>,
+) {
+  if node.kind() == "package_declaration" {
+    return;
+  }
+  if QUALIFIED_NAME_KINDS.contains(&node.kind())
+    && let Some(text) = ts_file.get_text_from_node(&node)
+  {
+    let is_import = node.parent().is_some_and(|parent| parent.kind() == "import_declaration");
+    let is_moved_type_reference = text
+      .strip_prefix(source_package)
+      .and_then(|rest| rest.strip_prefix('.'))
+      .and_then(|rest| rest.split('.').next())
+      .is_some_and(|type_name| moved_types.contains(&type_name));
+    if is_moved_type_reference || (is_import && text == source_package) {
+      let start_byte = node.start_byte();
+      rewrites.push((start_byte, start_byte + source_package.len(), is_import));
+      return;
+    }
+  }
+  let mut cursor = node.walk();
+  for child in node.named_children(&mut cursor) {
+    collect_reference_rewrites(ts_file, child, source_package, moved_types, rewrites);
+  }
+}
+
+/// Rewrites the references of one file to the moved types, and its package declaration when the
+/// file itself moves, without saving it. Files that aren't valid UTF-8 are recorded as skipped on
+/// `deadline`.
+///
+/// # Returns
+/// The rewritten file and its number of rewritten imports, `None` when the file is left untouched
+fn plan_file_update(
+  file_path: &Path,
+  package_file: Option<&PackageFile>,
+  source_package: &str,
+  destination_package: &str,
+  moved_types: &[&str],
+  deadline: &ScanDeadline,
+) -> Result<Option<(TSFile, usize)>, String> {
+  let Some(source_code) = read_scanned_source(file_path, deadline)? else {
+    // A file that can't be decoded is left alone as long as it doesn't mention the source package
+    let mentions_source_package = fs::read(file_path).is_ok_and(|bytes| {
+      bytes.windows(source_package.len()).any(|window| window == source_package.as_bytes())
+    });
+    if mentions_source_package {
+      return Err(format!(
+        "File '{}' is not valid UTF-8 and references '{}', no file was modified",
+        file_path.display(),
+        source_package
+      ));
+    }
+    return Ok(None);
+  };
+  if package_file.is_none() && !source_code.contains(source_package) {
+    return Ok(None);
+  }
+  let mut ts_file = TSFile::from_source_code(&source_code, SupportedLanguage::Java);
+  let mut rewrites = Vec::new();
+  if let Some(tree) = ts_file.tree.as_ref() {
+    collect_reference_rewrites(
+      &ts_file,
+      tree.root_node(),
+      source_package,
+      moved_types,
+      &mut rewrites,
+    );
+  }
+  if package_file.is_some()
+    && let Some(package_scope_node) =
+      get_package_declaration_node(&ts_file).and_then(|node| get_package_scope_node(&ts_file, node))
+  {
+    rewrites.push((package_scope_node.start_byte(), package_scope_node.end_byte(), false));
+  }
+  if rewrites.is_empty() {
+    return Ok(None);
+  }
+  rewrites.sort_by_key(|(start_byte, _, _)| *start_byte);
+  // Apply from the end of the file so earlier byte ranges stay valid
+  for (start_byte, end_byte, _) in rewrites.iter().rev() {
+    ts_file.replace_text_by_range(*start_byte, *end_byte, destination_package);
+  }
+  let updated_imports_count = rewrites.iter().filter(|(_, _, is_import)| *is_import).count();
+  Ok(Some((ts_file, updated_imports_count)))
+}
+
+pub fn run(
+  cwd: &Path,
+  source_package: &str,
+  destination_package: &str,
+) -> Result<MergePackagesResponse, String> {
+  // Step 1: Find the files of the source package in the main and test sources
+  if source_package == destination_package {
+    return Err("Source and destination packages must differ".to_string());
+  }
+  let mut package_files = Vec::new();
+  for source_directory in [JavaSourceDirectoryType::Main, JavaSourceDirectoryType::Test] {
+    package_files.extend(find_package_files(
+      cwd,
+      &source_directory,
+      source_package,
+      destination_package,
+    )?);
+  }
+  if package_files.is_empty() {
+    return Err(format!("Package '{}' has no Java files to merge", source_package));
+  }
+  // Step 2: Refuse the merge before any change when the destination declares the same types
+  let mut conflicting_types: Vec<&str> = package_files
+    .iter()
+    .filter(|package_file| package_file.new_file_path.exists())
+    .map(|package_file| package_file.type_name.as_str())
+    .collect();
+  if !conflicting_types.is_empty() {
+    conflicting_types.sort();
+    conflicting_types.dedup();
+    return Err(format!(
+      "Cannot merge '{}' into '{}', both packages declare: {}",
+      source_package,
+      destination_package,
+      conflicting_types.join(", ")
+    ));
+  }
+  // Step 3: Plan the rewrites of every file before saving any, so a file that can't be read
+  // leaves the project untouched
+  let moved_types: Vec<&str> =
+    package_files.iter().map(|package_file| package_file.type_name.as_str()).collect();
+  let deadline = ScanDeadline::unbounded();
+  let mut planned_files = Vec::new();
+  let file_paths = get_all_java_file_paths(cwd, &JavaSourceDirectoryType::All);
+  for (index, file_path) in file_paths.iter().enumerate() {
+    let package_file = package_files.iter().find(|file| &file.old_file_path == file_path);
+    if let Some((ts_file, file_imports_count)) = plan_file_update(
+      file_path,
+      package_file,
+      source_package,
+      destination_package,
+      &moved_types,
+      &deadline,
+    )? {
+      planned_files.push((file_path, package_file, ts_file, file_imports_count));
+    }
+    progress::report("rewriting", index + 1, file_paths.len());
+  }
+  // Step 4: Save the rewritten files, the moved ones at their new path
+  let mut updated_imports_count = 0;
+  let mut updated_files_count = 0;
+  for (file_path, package_file, ts_file, file_imports_count) in &mut planned_files {
+    let save_path = package_file.map_or(*file_path, |package_file| &package_file.new_file_path);
+    ts_file
+      .save_to_existing_file(save_path, cwd)
+      .map_err(|e| format!("Unable to save '{}': {}", save_path.display(), e))?;
+    updated_imports_count += *file_imports_count;
+    if package_file.is_none() {
+      updated_files_count += 1;
+    }
+  }
+  // Step 5: Remove the moved files from the source package
+  for package_file in &package_files {
+    fs::remove_file(&package_file.old_file_path)
+      .map_err(|e| format!("Unable to remove '{}': {}", package_file.old_file_path.display(), e))?;
+    if let Some(source_dir) = package_file.old_file_path.parent() {
+      // Only succeeds once the directory is empty, non-Java resources keep it alive
+      let _ = fs::remove_dir(source_dir);
+    }
+  }
+  // Step 6: Build response
+  Ok(MergePackagesResponse {
+    moved_files_count: package_files.len(),
+    moved_files: package_files
+      .into_iter()
+      .map(|package_file| MovedFileResponse {
+        type_name: package_file.type_name,
+        old_file_path: package_file.old_file_path.display().to_string(),
+        new_file_path: package_file.new_file_path.display().to_string(),
+      })
+      .collect(),
+    updated_imports_count,
+    updated_files_count,
+    skipped_files: deadline.skipped_files(),
+  })
+}
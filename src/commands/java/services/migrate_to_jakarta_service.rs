@@ -0,0 +1,138 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::migrate_to_jakarta_response::{
+  MigrateToJakartaResponse, MigratedFileResponse,
+};
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::commands::java::treesitter::types::namespace_mapping::{
+  NamespaceMapping, find_namespace_mapping,
+};
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::{get_all_java_file_paths, read_scanned_source};
+use crate::common::utils::progress;
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+/// Node kinds holding a qualified name: imports and annotation names (`scoped_identifier`), types
+/// (`scoped_type_identifier`) and static references in expressions (`field_access`).
+const QUALIFIED_NAME_KINDS: [&str; 3] =
+  ["scoped_identifier", "scoped_type_identifier", "field_access"];
+
+/// Collects the byte ranges of the namespace prefixes to rewrite, with their replacement. Only the
+/// outermost qualified name is considered, and the file's own package declaration is left alone.
+fn collect_namespace_rewrites(
+  ts_file: &TSFile,
+  node: Node,
+  mappings: &[NamespaceMapping],
+  rewrites: &mut Vec<(usize, usize, String)>,
+) {
+  if node.kind() == "package_declaration" {
+    return;
+  }
+  if QUALIFIED_NAME_KINDS.contains(&node.kind())
+    && let Some(text) = ts_file.get_text_from_node(&node)
+    && let Some(mapping) = find_namespace_mapping(mappings, text)
+  {
+    if mapping.from != mapping.to {
+      let start_byte = node.start_byte();
+      rewrites.push((start_byte, start_byte + mapping.from.len(), mapping.to.clone()));
+    }
+    return;
+  }
+  let mut cursor = node.walk();
+  for child in node.named_children(&mut cursor) {
+    collect_namespace_rewrites(ts_file, child, mappings, rewrites);
+  }
+}
+
+/// Applies the namespace rewrites of one file to its parsed copy, without saving it. Files that
+/// aren't valid UTF-8 are recorded as skipped on `deadline`.
+///
+/// # Returns
+/// The rewritten file and its number of rewritten references, `None` when the file is left
+/// untouched
+fn plan_file_migration(
+  file_path: &Path,
+  mappings: &[NamespaceMapping],
+  deadline: &ScanDeadline,
+) -> Result<Option<(TSFile, usize)>, String> {
+  let Some(source_code) = read_scanned_source(file_path, deadline)? else {
+    return Ok(None);
+  };
+  // Most files never mention a migrated namespace, skip them before parsing
+  if !mappings
+    .iter()
+    .any(|mapping| mapping.from != mapping.to && source_code.contains(&mapping.from))
+  {
+    return Ok(None);
+  }
+  let mut ts_file = TSFile::from_source_code(&source_code, SupportedLanguage::Java);
+  let mut rewrites = Vec::new();
+  if let Some(tree) = ts_file.tree.as_ref() {
+    collect_namespace_rewrites(&ts_file, tree.root_node(), mappings, &mut rewrites);
+  }
+  if rewrites.is_empty() {
+    return Ok(None);
+  }
+  // Apply from the end of the file so earlier byte ranges stay valid
+  for (start_byte, end_byte, replacement) in rewrites.iter().rev() {
+    ts_file.replace_text_by_range(*start_byte, *end_byte, replacement);
+  }
+  Ok(Some((ts_file, rewrites.len())))
+}
+
+pub fn run(
+  cwd: &Path,
+  source_directory: &JavaSourceDirectoryType,
+  additional_mappings: &[NamespaceMapping],
+) -> Result<MigrateToJakartaResponse, String> {
+  // Step 1: Build the mapping table, additional mappings take precedence over the defaults
+  let mut mappings = additional_mappings.to_vec();
+  mappings.extend(NamespaceMapping::jakarta_mappings());
+  // Step 2: Plan the rewrites of every file before saving any, so a file that can't be read
+  // leaves the project untouched
+  let deadline = ScanDeadline::unbounded();
+  let mut planned_files = Vec::new();
+  let mut untouched_files_count = 0;
+  let file_paths = get_all_java_file_paths(cwd, source_directory);
+  for (index, file_path) in file_paths.iter().enumerate() {
+    match plan_file_migration(file_path, &mappings, &deadline)? {
+      Some((ts_file, rewritten_references_count)) => {
+        planned_files.push((file_path, ts_file, rewritten_references_count))
+      }
+      None => untouched_files_count += 1,
+    }
+    progress::report("migrating", index + 1, file_paths.len());
+  }
+  // Step 3: Save the rewritten files, listing the ones already saved if one fails
+  let mut migrated_files: Vec<MigratedFileResponse> = Vec::new();
+  for (file_path, ts_file, rewritten_references_count) in &mut planned_files {
+    if let Err(e) = ts_file.save_to_existing_file(file_path, cwd) {
+      let saved_file_paths: Vec<&str> =
+        migrated_files.iter().map(|file| file.file_path.as_str()).collect();
+      return Err(format!(
+        "Unable to save '{}': {}. Files already migrated: [{}]",
+        file_path.display(),
+        e,
+        saved_file_paths.join(", ")
+      ));
+    }
+    migrated_files.push(MigratedFileResponse {
+      file_path: file_path.to_string_lossy().to_string(),
+      rewritten_references_count: *rewritten_references_count,
+    });
+  }
+  // Step 4: Build response
+  let rewritten_references_count =
+    migrated_files.iter().map(|file| file.rewritten_references_count).sum();
+  let skipped_files = deadline.skipped_files();
+  Ok(MigrateToJakartaResponse {
+    migrated_files_count: migrated_files.len(),
+    migrated_files,
+    rewritten_references_count,
+    skipped_files_count: untouched_files_count - skipped_files.len(),
+    skipped_files,
+  })
+}
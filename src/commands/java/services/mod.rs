@@ -1,14 +1,76 @@
+pub mod add_attribute_override_service;
+pub mod add_constructor_injection_service;
+pub mod add_nested_class_service;
+pub mod add_repository_query_method_service;
+pub mod add_secondary_table_service;
+pub mod add_size_validation_service;
+pub mod add_unique_constraint_service;
+pub mod check_entity_compiles_against_repository_service;
+pub mod clear_scan_cache_service;
+pub mod convert_to_lombok_builder_service;
 pub mod create_java_file_service;
+pub mod create_jpa_embeddable_service;
 pub mod create_jpa_entity_basic_field_service;
 pub mod create_jpa_entity_enum_field_service;
 pub mod create_jpa_entity_id_field_service;
 pub mod create_jpa_entity_service;
+pub mod create_jpa_entity_version_field_service;
 pub mod create_jpa_many_to_one_relationship_service;
 pub mod create_jpa_one_to_one_relationship_service;
 pub mod create_jpa_repository_service;
+pub mod delete_jpa_entity_field_service;
+pub mod emit_camel_to_snake_column_report_service;
+pub mod ensure_no_arg_constructor_service;
+pub mod generate_audit_entity_service;
+pub mod generate_controller_test_service;
+pub mod generate_entity_diagram_service;
+pub mod generate_entity_listener_service;
+pub mod generate_factory_method_service;
+pub mod generate_liquibase_changelog_service;
+pub mod generate_projection_interface_service;
+pub mod generate_query_by_example_service;
+pub mod generate_query_dsl_metadata_service;
+pub mod generate_schema_service;
+pub mod generate_test_data_builder_service;
 pub mod get_all_jpa_entities_service;
 pub mod get_all_jpa_mapped_superclasses;
 pub mod get_all_packages_service;
+pub mod get_annotations_service;
+pub mod get_class_metrics_service;
+pub mod get_column_mapping_service;
+pub mod get_constructor_info_service;
+pub mod get_effective_column_type_service;
+pub mod get_entity_by_table_name_service;
+pub mod get_entity_graph_service;
+pub mod get_file_diagnostics_service;
+pub mod get_id_field_info_service;
 pub mod get_java_basic_types_service;
 pub mod get_java_files_service;
+pub mod get_jpa_entities_info_service;
 pub mod get_jpa_entity_info_service;
+pub mod get_method_callers_service;
+#[cfg(feature = "debug-tools")]
+pub mod get_parse_tree_service;
+pub mod get_relationship_inverse_service;
+pub mod get_relationship_targets_service;
+pub mod get_superclass_fields_service;
+pub mod get_type_definition_location_service;
+pub mod invalidate_parse_cache_service;
+pub mod list_commands_service;
+pub mod make_entity_immutable_service;
+pub mod merge_packages_service;
+pub mod migrate_to_jakarta_service;
+pub mod move_method_service;
+pub mod regenerate_accessors_service;
+pub mod remove_annotation_service;
+pub mod remove_duplicate_imports_service;
+pub mod remove_relationship_service;
+pub mod rename_column_service;
+pub mod rename_java_class_service;
+pub mod rename_java_field_service;
+pub mod replace_annotation_argument_service;
+pub mod resolve_import_for_type_service;
+pub mod set_column_name_service;
+pub mod set_default_fetch_service;
+pub mod validate_entity_service;
+pub mod validate_project_service;
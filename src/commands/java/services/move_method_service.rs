@@ -0,0 +1,499 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::file_response::FileResponse;
+use crate::commands::java::responses::move_method_response::MoveMethodResponse;
+use crate::commands::java::responses::multi_file_response::MultiFileResponse;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  add_member_at_end_of_type_body, get_class_declaration_name_node, get_member_text,
+  get_public_class_node, has_modifier, remove_member,
+};
+use crate::commands::java::treesitter::services::field_declaration_service::{
+  get_all_field_declaration_nodes, get_field_declaration_name_node,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, add_imports, find_imported_class_package, get_all_import_declaration_nodes,
+  get_import_declaration_class_name_node, get_import_declaration_key,
+  get_import_declaration_relative_import_scope_node, remove_unused_import,
+  resolve_fully_qualified_type_name,
+};
+use crate::commands::java::treesitter::services::method_declaration_service::{
+  get_method_declaration_name, get_own_method_declaration_nodes, get_referenced_field_names,
+};
+use crate::commands::java::treesitter::services::method_invocation_service::{
+  get_enclosing_type_names, get_method_invocation_nodes_by_name, resolve_identifier_type,
+};
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::hash_util::get_content_hash;
+use crate::common::utils::path_util::{is_same_file, parse_all_files_within_deadline};
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+struct ClassInfo {
+  name: String,
+  package_name: String,
+}
+
+fn get_class_info(ts_file: &TSFile, role: &str) -> Result<ClassInfo, String> {
+  let class_node = get_public_class_node(ts_file)
+    .ok_or_else(|| format!("No public class found in {} file", role))?;
+  let name = get_class_declaration_name_node(ts_file, class_node)
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+    .ok_or_else(|| format!("Couldn't get the class name of the {} file", role))?
+    .to_string();
+  let package_name = get_package_name(ts_file).unwrap_or_default();
+  Ok(ClassInfo { name, package_name })
+}
+
+fn get_qualified_name(class_info: &ClassInfo) -> String {
+  format!("{}.{}", class_info.package_name, class_info.name).trim_start_matches('.').to_string()
+}
+
+fn is_within(node: Node, container: Node) -> bool {
+  node.start_byte() >= container.start_byte() && node.end_byte() <= container.end_byte()
+}
+
+/// Whether the invocation calls a method of the class it's written in, i.e. has no receiver or
+/// `this`.
+fn is_own_invocation(invocation_node: Node) -> bool {
+  invocation_node.child_by_field_name("object").is_none_or(|object| object.kind() == "this")
+}
+
+/// Private methods of the class called from the moved method and from nowhere else in the file.
+fn find_private_helpers<'a>(
+  ts_file: &'a TSFile,
+  method_nodes: &[Node<'a>],
+  moved_method_node: Node<'a>,
+) -> Vec<Node<'a>> {
+  method_nodes
+    .iter()
+    .copied()
+    .filter(|node| node.id() != moved_method_node.id() && has_modifier(*node, "private"))
+    .filter(|helper_node| {
+      let Some(helper_name) = get_method_declaration_name(ts_file, *helper_node) else {
+        return false;
+      };
+      let is_overloaded = method_nodes
+        .iter()
+        .filter(|node| get_method_declaration_name(ts_file, **node) == Some(helper_name))
+        .count()
+        > 1;
+      let invocations = get_method_invocation_nodes_by_name(ts_file, helper_name);
+      !is_overloaded
+        && invocations
+          .iter()
+          .any(|node| is_own_invocation(*node) && is_within(*node, moved_method_node))
+        && invocations.iter().all(|node| is_within(*node, moved_method_node))
+    })
+    .collect()
+}
+
+/// Identifiers and type names used in the node, to find the imports the moved code needs.
+fn collect_referenced_names<'a>(ts_file: &'a TSFile, node: Node<'a>, names: &mut HashSet<&'a str>) {
+  let mut pending = vec![node];
+  while let Some(current) = pending.pop() {
+    if matches!(current.kind(), "identifier" | "type_identifier") {
+      names.extend(ts_file.get_text_from_node(&current));
+    }
+    let mut cursor = current.walk();
+    pending.extend(current.named_children(&mut cursor));
+  }
+}
+
+/// Members of the source class the moved code relies on but that stay behind.
+fn collect_warnings(
+  ts_file: &TSFile,
+  class_node: Node,
+  moved_nodes: &[Node],
+  is_static: bool,
+  target_class_name: &str,
+) -> Vec<String> {
+  let source_class_name = get_class_declaration_name_node(ts_file, class_node)
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+    .unwrap_or_default();
+  let field_names: Vec<&str> = get_all_field_declaration_nodes(ts_file, class_node)
+    .into_iter()
+    .filter_map(|field_node| get_field_declaration_name_node(ts_file, field_node))
+    .filter_map(|name_node| ts_file.get_text_from_node(&name_node))
+    .collect();
+  let moved_names: Vec<&str> =
+    moved_nodes.iter().filter_map(|node| get_method_declaration_name(ts_file, *node)).collect();
+  let remaining_method_names: Vec<&str> = get_own_method_declaration_nodes(ts_file, class_node)
+    .into_iter()
+    .filter_map(|node| get_method_declaration_name(ts_file, node))
+    .filter(|name| !moved_names.contains(name))
+    .collect();
+  let mut warnings = Vec::new();
+  for moved_node in moved_nodes {
+    let method_name = get_method_declaration_name(ts_file, *moved_node).unwrap_or_default();
+    let mut uses_this = false;
+    let mut called_methods: Vec<&str> = Vec::new();
+    let mut pending: Vec<Node> = moved_node.child_by_field_name("body").into_iter().collect();
+    while let Some(node) = pending.pop() {
+      match node.kind() {
+        "this" => uses_this = true,
+        "method_invocation" if is_own_invocation(node) => {
+          let called_name = node
+            .child_by_field_name("name")
+            .and_then(|name_node| ts_file.get_text_from_node(&name_node));
+          if let Some(called_name) = called_name
+            && remaining_method_names.contains(&called_name)
+            && !called_methods.contains(&called_name)
+          {
+            called_methods.push(called_name);
+          }
+        }
+        _ => {}
+      }
+      let mut cursor = node.walk();
+      let mut children: Vec<Node> = node.named_children(&mut cursor).collect();
+      children.reverse();
+      pending.extend(children);
+    }
+    if uses_this && !is_static {
+      warnings.push(format!(
+        "Method '{}' uses 'this', which refers to '{}' once moved",
+        method_name, target_class_name
+      ));
+    }
+    for field_name in get_referenced_field_names(ts_file, *moved_node, &field_names) {
+      warnings.push(format!(
+        "Method '{}' references field '{}' of '{}', which stays behind",
+        method_name, field_name, source_class_name
+      ));
+    }
+    for called_name in called_methods {
+      warnings.push(format!(
+        "Method '{}' calls '{}' of '{}', which stays behind",
+        method_name, called_name, source_class_name
+      ));
+    }
+  }
+  warnings
+}
+
+/// Single-type imports of the file as (simple name, package).
+fn get_single_type_imports(ts_file: &TSFile) -> Vec<(String, String)> {
+  get_all_import_declaration_nodes(ts_file)
+    .into_iter()
+    .filter(|node| ts_file.get_text_from_node(node).is_some_and(|text| !text.contains("static ")))
+    .filter_map(|node| {
+      let class_name = get_import_declaration_class_name_node(ts_file, node)
+        .and_then(|name_node| ts_file.get_text_from_node(&name_node))?;
+      let package_name = get_import_declaration_relative_import_scope_node(ts_file, node)
+        .and_then(|scope_node| ts_file.get_text_from_node(&scope_node))?;
+      Some((class_name.to_string(), package_name.to_string()))
+    })
+    .collect()
+}
+
+/// Rewrites the static calls of the moved method through the source class, and, in the source
+/// file, the calls without receiver, to go through the target class.
+///
+/// # Returns
+/// The number of updated call sites
+fn update_static_call_sites(
+  ts_file: &mut TSFile,
+  method_name: &str,
+  source: &ClassInfo,
+  target: &ClassInfo,
+  is_source_file: bool,
+) -> usize {
+  let refers_to_source = is_source_file
+    || resolve_fully_qualified_type_name(ts_file, &source.name) == Some(get_qualified_name(source));
+  if !refers_to_source {
+    return 0;
+  }
+  let mut edits: Vec<(usize, usize, String)> =
+    get_method_invocation_nodes_by_name(ts_file, method_name)
+      .into_iter()
+      .filter_map(|invocation_node| match invocation_node.child_by_field_name("object") {
+        None
+          if is_source_file
+            && get_enclosing_type_names(ts_file, invocation_node).first()
+              == Some(&source.name.as_str()) =>
+        {
+          let start_byte = invocation_node.start_byte();
+          Some((start_byte, start_byte, format!("{}.", target.name)))
+        }
+        Some(object_node)
+          if object_node.kind() == "identifier"
+            && ts_file.get_text_from_node(&object_node) == Some(source.name.as_str())
+            && resolve_identifier_type(ts_file, invocation_node, &source.name).is_none() =>
+        {
+          Some((object_node.start_byte(), object_node.end_byte(), target.name.clone()))
+        }
+        _ => None,
+      })
+      .collect();
+  if edits.is_empty() {
+    return 0;
+  }
+  edits.sort_by_key(|(start_byte, _, _)| Reverse(*start_byte));
+  for (start_byte, end_byte, text) in &edits {
+    ts_file.replace_text_by_range(*start_byte, *end_byte, text);
+  }
+  let file_package_name = get_package_name(ts_file).unwrap_or_default();
+  if file_package_name != target.package_name
+    && find_imported_class_package(ts_file, &target.name).is_none()
+  {
+    add_import(
+      ts_file,
+      &ImportInsertionPosition::AfterLastImport,
+      &target.package_name,
+      &target.name,
+    );
+  }
+  remove_unused_import(ts_file, &source.name);
+  edits.len()
+}
+
+/// Points the static imports of the moved method at the target class. A wildcard static import of
+/// the source class gets a static import of the method added after it, and the target file, which
+/// now declares the method, drops the import.
+///
+/// # Returns
+/// The number of calls without receiver relying on the rewritten imports
+fn update_static_imports(
+  ts_file: &mut TSFile,
+  method_name: &str,
+  source: &ClassInfo,
+  target: &ClassInfo,
+  is_target_file: bool,
+) -> usize {
+  let source_fqn = get_qualified_name(source);
+  let method_import = format!("import static {}.{};", get_qualified_name(target), method_name);
+  let calls_count = get_method_invocation_nodes_by_name(ts_file, method_name)
+    .into_iter()
+    .filter(|invocation_node| invocation_node.child_by_field_name("object").is_none())
+    .count();
+  let mut edits: Vec<(usize, usize, String)> = Vec::new();
+  for import_node in get_all_import_declaration_nodes(ts_file) {
+    let Some((true, name, is_wildcard)) = get_import_declaration_key(ts_file, import_node) else {
+      continue;
+    };
+    let (start_byte, end_byte) = (import_node.start_byte(), import_node.end_byte());
+    if is_wildcard {
+      if name == source_fqn && !is_target_file && calls_count > 0 {
+        edits.push((end_byte, end_byte, format!("\n{}", method_import)));
+      }
+    } else if name == format!("{}.{}", source_fqn, method_name) {
+      if is_target_file {
+        let source_code = &ts_file.source_code;
+        let line_end_byte = match source_code[end_byte..].find('\n') {
+          Some(offset) if source_code[end_byte..end_byte + offset].trim().is_empty() => {
+            end_byte + offset + 1
+          }
+          _ => end_byte,
+        };
+        edits.push((start_byte, line_end_byte, String::new()));
+      } else {
+        edits.push((start_byte, end_byte, method_import.clone()));
+      }
+    }
+  }
+  if edits.is_empty() {
+    return 0;
+  }
+  edits.sort_by_key(|(start_byte, _, _)| Reverse(*start_byte));
+  for (start_byte, end_byte, text) in &edits {
+    ts_file.replace_text_by_range(*start_byte, *end_byte, text);
+  }
+  if is_target_file { 0 } else { calls_count }
+}
+
+fn build_file_response(ts_file: &TSFile, file_path: &Path) -> FileResponse {
+  let class_info = get_class_info(ts_file, "updated").ok();
+  FileResponse {
+    file_type: class_info.as_ref().map(|info| info.name.clone()).unwrap_or_else(|| {
+      file_path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default()
+    }),
+    file_package_name: class_info.map(|info| info.package_name).unwrap_or_default(),
+    file_path: file_path.display().to_string(),
+    content_hash: Some(get_content_hash(&ts_file.source_code)),
+    warnings: Vec::new(),
+  }
+}
+
+pub fn run(
+  cwd: &Path,
+  source_file_path: &Path,
+  target_file_path: &Path,
+  method_name: &str,
+  include_private_helpers: bool,
+  deadline: &ScanDeadline,
+) -> Result<MoveMethodResponse, String> {
+  // Step 1: Parse both classes
+  if is_same_file(source_file_path, target_file_path) {
+    return Err("Source and target files must be different".to_string());
+  }
+  let mut source_ts_file = TSFile::from_file(source_file_path, cwd, SupportedLanguage::Java)
+    .map_err(|e| format!("Unable to parse source file: {}", e))?;
+  let mut target_ts_file = TSFile::from_file(target_file_path, cwd, SupportedLanguage::Java)
+    .map_err(|e| format!("Unable to parse target file: {}", e))?;
+  let source = get_class_info(&source_ts_file, "source")?;
+  let target = get_class_info(&target_ts_file, "target")?;
+  // Step 2: Find the method, its private helpers and what they leave behind
+  let (is_static, moved_members, moved_helpers, imports_to_copy, mut warnings) = {
+    let source_class_node = get_public_class_node(&source_ts_file)
+      .ok_or_else(|| "No public class found in source file".to_string())?;
+    let method_nodes = get_own_method_declaration_nodes(&source_ts_file, source_class_node);
+    let matching_nodes: Vec<Node> = method_nodes
+      .iter()
+      .copied()
+      .filter(|node| get_method_declaration_name(&source_ts_file, *node) == Some(method_name))
+      .collect();
+    let method_node = match matching_nodes.as_slice() {
+      [] => {
+        return Err(format!("Method '{}' not found in class '{}'", method_name, source.name));
+      }
+      [method_node] => *method_node,
+      _ => {
+        return Err(format!(
+          "Method '{}' is overloaded in class '{}', moving overloads is not supported",
+          method_name, source.name
+        ));
+      }
+    };
+    let target_class_node = get_public_class_node(&target_ts_file)
+      .ok_or_else(|| "No public class found in target file".to_string())?;
+    let mut moved_nodes = vec![method_node];
+    if include_private_helpers {
+      moved_nodes.extend(find_private_helpers(&source_ts_file, &method_nodes, method_node));
+    }
+    for node in &moved_nodes {
+      let name = get_method_declaration_name(&source_ts_file, *node).unwrap_or_default();
+      let collides = get_own_method_declaration_nodes(&target_ts_file, target_class_node)
+        .into_iter()
+        .any(|target_node| get_method_declaration_name(&target_ts_file, target_node) == Some(name));
+      if collides {
+        return Err(format!("Class '{}' already declares a method named '{}'", target.name, name));
+      }
+    }
+    let mut referenced_names = HashSet::new();
+    for node in &moved_nodes {
+      collect_referenced_names(&source_ts_file, *node, &mut referenced_names);
+    }
+    let mut imports_to_copy: HashMap<String, String> = get_single_type_imports(&source_ts_file)
+      .into_iter()
+      .filter(|(class_name, package_name)| {
+        referenced_names.contains(class_name.as_str()) && *package_name != target.package_name
+      })
+      .collect();
+    if referenced_names.contains(source.name.as_str())
+      && source.package_name != target.package_name
+      && !source.package_name.is_empty()
+    {
+      imports_to_copy.insert(source.name.clone(), source.package_name.clone());
+    }
+    let is_static = has_modifier(method_node, "static");
+    let warnings =
+      collect_warnings(&source_ts_file, source_class_node, &moved_nodes, is_static, &target.name);
+    let moved_members: Vec<(usize, String)> = moved_nodes
+      .iter()
+      .map(|node| {
+        get_member_text(&source_ts_file, *node)
+          .map(|text| (node.start_byte(), text))
+          .ok_or_else(|| "Couldn't get the method text from the tree".to_string())
+      })
+      .collect::<Result<_, _>>()?;
+    let moved_helpers: Vec<String> = moved_nodes[1..]
+      .iter()
+      .filter_map(|node| get_method_declaration_name(&source_ts_file, *node))
+      .map(|name| name.to_string())
+      .collect();
+    (is_static, moved_members, moved_helpers, imports_to_copy, warnings)
+  };
+  // Step 3: Splice the methods into the target class and remove them from the source class
+  let target_class_byte = get_public_class_node(&target_ts_file)
+    .map(|node| node.start_byte())
+    .ok_or_else(|| "No public class found in target file".to_string())?;
+  for (_, member_text) in &moved_members {
+    add_member_at_end_of_type_body(&mut target_ts_file, target_class_byte, member_text)
+      .ok_or_else(|| "Unable to add the method to the target class".to_string())?;
+  }
+  let mut removal_bytes: Vec<usize> = moved_members.iter().map(|(byte, _)| *byte).collect();
+  removal_bytes.sort_by_key(|byte| Reverse(*byte));
+  for member_byte in removal_bytes {
+    if !remove_member(&mut source_ts_file, member_byte) {
+      return Err("Unable to remove the method from the source class".to_string());
+    }
+  }
+  // Step 4: Fix the imports on both sides
+  add_imports(&mut target_ts_file, &imports_to_copy);
+  for class_name in imports_to_copy.keys() {
+    remove_unused_import(&mut source_ts_file, class_name);
+  }
+  // Step 5: Update the call sites of static methods, instance calls need a new receiver
+  let mut updated_call_sites_count = 0;
+  let mut updated_files = Vec::new();
+  if is_static {
+    updated_call_sites_count +=
+      update_static_call_sites(&mut source_ts_file, method_name, &source, &target, true);
+    updated_call_sites_count +=
+      update_static_call_sites(&mut target_ts_file, method_name, &source, &target, false);
+    update_static_imports(&mut target_ts_file, method_name, &source, &target, true);
+    for mut ts_file in parse_all_files_within_deadline(cwd, &JavaSourceDirectoryType::All, deadline)
+    {
+      let Some(file_path) = ts_file.file_path().cloned() else {
+        continue;
+      };
+      if is_same_file(&file_path, source_file_path) || is_same_file(&file_path, target_file_path) {
+        continue;
+      }
+      let original_source_code = ts_file.source_code.clone();
+      updated_call_sites_count +=
+        update_static_call_sites(&mut ts_file, method_name, &source, &target, false);
+      updated_call_sites_count +=
+        update_static_imports(&mut ts_file, method_name, &source, &target, false);
+      if ts_file.source_code != original_source_code {
+        updated_files.push((file_path, ts_file));
+      }
+    }
+    // Nothing is written when call sites may have been missed
+    if deadline.is_truncated() {
+      return Err(format!(
+        "Timed out scanning the call sites of '{}', no file was modified",
+        method_name
+      ));
+    }
+  } else {
+    warnings.push(format!(
+      "Call sites of instance method '{}' were left unchanged, they need a '{}' receiver",
+      method_name, target.name
+    ));
+  }
+  // Step 6: Save every modified file
+  source_ts_file
+    .save_to_existing_file(source_file_path, cwd)
+    .map_err(|e| format!("Unable to save source file: {}", e))?;
+  target_ts_file
+    .save_to_existing_file(target_file_path, cwd)
+    .map_err(|e| format!("Unable to save target file: {}", e))?;
+  updated_files.sort_by(|a, b| a.0.cmp(&b.0));
+  for (file_path, ts_file) in &mut updated_files {
+    ts_file.save().map_err(|e| format!("Unable to save file '{}': {}", file_path.display(), e))?;
+  }
+  // Step 7: Build and return response
+  let mut files = vec![
+    build_file_response(&source_ts_file, source_file_path),
+    build_file_response(&target_ts_file, target_file_path),
+  ];
+  files.extend(
+    updated_files.iter().map(|(file_path, ts_file)| build_file_response(ts_file, file_path)),
+  );
+  Ok(MoveMethodResponse {
+    written_files: MultiFileResponse::new(files),
+    method_name: method_name.to_string(),
+    is_static,
+    moved_helpers,
+    updated_call_sites_count,
+    warnings,
+  })
+}
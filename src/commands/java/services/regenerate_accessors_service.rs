@@ -0,0 +1,114 @@
+use std::path::Path;
+
+use crate::commands::java::responses::regenerate_accessors_response::RegenerateAccessorsResponse;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node, has_modifier,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::get_own_field_declaration_nodes;
+use crate::commands::java::treesitter::services::field_declaration_service::get_field_declaration_name_node;
+use crate::commands::java::treesitter::services::method_declaration_service::{
+  AccessorRegeneration, regenerate_accessor_methods,
+};
+use crate::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use tree_sitter::Node;
+
+struct AccessorField {
+  field_name: String,
+  field_type: String,
+  is_final: bool,
+}
+
+/// Collects the instance fields of the class, in declaration order.
+fn get_accessor_fields(ts_file: &TSFile, class_node: Node) -> Vec<AccessorField> {
+  let mut fields = Vec::new();
+  for field_node in get_own_field_declaration_nodes(ts_file, class_node) {
+    if has_modifier(field_node, "static") {
+      continue;
+    }
+    let field_name = get_field_declaration_name_node(ts_file, field_node)
+      .and_then(|node| ts_file.get_text_from_node(&node));
+    let field_type =
+      field_node.child_by_field_name("type").and_then(|node| ts_file.get_text_from_node(&node));
+    if let (Some(field_name), Some(field_type)) = (field_name, field_type) {
+      fields.push(AccessorField {
+        field_name: field_name.to_string(),
+        field_type: field_type.to_string(),
+        is_final: has_modifier(field_node, "final"),
+      });
+    }
+  }
+  fields
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_names: &[String],
+  accessor_visibility: JavaVisibilityModifier,
+) -> Result<RegenerateAccessorsResponse, String> {
+  // Step 1: Parse entity file
+  let mut entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  let original_source_code = entity_ts_file.source_code.clone();
+  // Step 2: Resolve the fields whose accessors are regenerated
+  let (entity_type, class_start_byte, fields) = {
+    let class_node = get_public_class_node(&entity_ts_file)
+      .ok_or_else(|| "Unable to get public class node".to_string())?;
+    let entity_type = get_class_declaration_name_node(&entity_ts_file, class_node)
+      .and_then(|name_node| entity_ts_file.get_text_from_node(&name_node))
+      .map(|name| name.to_string())
+      .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+    let mut fields = get_accessor_fields(&entity_ts_file, class_node);
+    if !field_names.is_empty() {
+      if let Some(missing) =
+        field_names.iter().find(|name| !fields.iter().any(|field| &field.field_name == *name))
+      {
+        return Err(format!("Instance field '{}' not found in {}", missing, entity_type));
+      }
+      fields.retain(|field| field_names.contains(&field.field_name));
+    }
+    (entity_type, class_node.start_byte(), fields)
+  };
+  // Step 3: Rewrite existing accessors in place and add the missing ones
+  let mut replaced_accessors = Vec::new();
+  let mut custom_accessors = Vec::new();
+  let mut created_accessors = Vec::new();
+  for field in &fields {
+    let regenerations = regenerate_accessor_methods(
+      &mut entity_ts_file,
+      class_start_byte,
+      &field.field_type,
+      &field.field_name,
+      !field.is_final,
+      accessor_visibility,
+    )
+    .ok_or_else(|| format!("Unable to regenerate accessors of field '{}'", field.field_name))?;
+    for (method_name, regeneration) in regenerations {
+      match regeneration {
+        AccessorRegeneration::Replaced => replaced_accessors.push(method_name),
+        AccessorRegeneration::SignatureReplaced => {
+          replaced_accessors.push(method_name.clone());
+          custom_accessors.push(method_name);
+        }
+        AccessorRegeneration::Created => created_accessors.push(method_name),
+      }
+    }
+  }
+  // Step 4: Save file with working directory validation
+  if entity_ts_file.source_code != original_source_code {
+    entity_ts_file
+      .save_to_existing_file(entity_file_path, cwd)
+      .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  }
+  // Step 5: Build and return response
+  Ok(RegenerateAccessorsResponse {
+    entity_file_path: entity_file_path.display().to_string(),
+    entity_type,
+    replaced_accessors,
+    custom_accessors,
+    created_accessors,
+  })
+}
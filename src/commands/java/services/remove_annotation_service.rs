@@ -0,0 +1,58 @@
+use std::path::Path;
+
+use crate::commands::java::responses::remove_annotation_response::RemoveAnnotationResponse;
+use crate::commands::java::services::replace_annotation_argument_service::find_target_node;
+use crate::commands::java::treesitter::services::annotation_service::{
+  find_declaration_annotation_node_by_name, remove_annotation,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::remove_unused_import;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+
+pub fn run(
+  cwd: &Path,
+  b64_source_code: &str,
+  file_path: &Path,
+  type_name: Option<&str>,
+  field_name: Option<&str>,
+  annotation_name: &str,
+  remove_import: bool,
+) -> Result<RemoveAnnotationResponse, String> {
+  // Step 1: Parse the file
+  let mut ts_file = TSFile::from_base64_source_code(b64_source_code, SupportedLanguage::Java);
+  let annotation_name = annotation_name.trim_start_matches('@');
+  // Step 2: Find the annotation on the target declaration
+  let (target_name, annotation_start_byte, removed_annotation) = {
+    let (target_node, target_name) = find_target_node(&ts_file, type_name, field_name)?;
+    let annotation_node =
+      find_declaration_annotation_node_by_name(&ts_file, target_node, annotation_name)
+        .ok_or_else(|| format!("'{}' is not annotated with @{}", target_name, annotation_name))?;
+    let removed_annotation =
+      ts_file.get_text_from_node(&annotation_node).unwrap_or_default().to_string();
+    (target_name, annotation_node.start_byte(), removed_annotation)
+  };
+  // Step 3: Remove the annotation, with its line when it stood alone on it
+  if !remove_annotation(&mut ts_file, annotation_start_byte) {
+    return Err(format!("Unable to remove @{} from '{}'", annotation_name, target_name));
+  }
+  // Step 4: Remove the import once nothing else in the file uses the annotation
+  let simple_annotation_name = annotation_name.rsplit('.').next().unwrap_or(annotation_name);
+  let removed_import = match remove_import {
+    true => remove_unused_import(&mut ts_file, simple_annotation_name),
+    false => None,
+  };
+  // Step 5: Save file with working directory validation
+  ts_file
+    .save_to_existing_file(file_path, cwd)
+    .map_err(|e| format!("Unable to save file: {}", e))?;
+  // Step 6: Build and return response
+  Ok(RemoveAnnotationResponse {
+    file_path: file_path.display().to_string(),
+    target_name,
+    annotation_name: simple_annotation_name.to_string(),
+    annotation_removed: true,
+    removed_annotation,
+    import_removed: removed_import.is_some(),
+    removed_import,
+  })
+}
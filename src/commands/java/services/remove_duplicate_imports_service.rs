@@ -0,0 +1,28 @@
+use std::path::Path;
+
+use crate::commands::java::responses::remove_duplicate_imports_response::RemoveDuplicateImportsResponse;
+use crate::commands::java::treesitter::services::import_declaration_service::remove_duplicate_imports;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+
+pub fn run(
+  cwd: &Path,
+  b64_source_code: &str,
+  file_path: &Path,
+) -> Result<RemoveDuplicateImportsResponse, String> {
+  // Step 1: Parse the file
+  let mut ts_file = TSFile::from_base64_source_code(b64_source_code, SupportedLanguage::Java);
+  if ts_file.tree.is_none() {
+    return Err("Unable to parse the Java file".to_string());
+  }
+  // Step 2: Collapse duplicate and wildcard-covered imports
+  let removed_imports = remove_duplicate_imports(&mut ts_file);
+  // Step 3: Save file with working directory validation, only when something changed
+  if !removed_imports.is_empty() {
+    ts_file
+      .save_to_existing_file(file_path, cwd)
+      .map_err(|e| format!("Unable to save file: {}", e))?;
+  }
+  // Step 4: Build and return response
+  Ok(RemoveDuplicateImportsResponse { file_path: file_path.display().to_string(), removed_imports })
+}
@@ -0,0 +1,207 @@
+use std::path::{Path, PathBuf};
+
+use crate::commands::java::responses::file_response::FileResponse;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::{
+  get_class_mapping, get_relationship_field_mappings,
+};
+use crate::commands::java::treesitter::services::field_declaration_service::{
+  get_leftover_references_warning, remove_field_declaration,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::remove_unused_import;
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::entity_mapping::RelationshipFieldMapping;
+use crate::commands::java::treesitter::types::entity_side::EntitySide;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::hash_util::get_content_hash;
+use crate::common::utils::path_util::find_file_by_class_name;
+use crate::common::validators::directory_validator::validate_file_path_within_base;
+
+fn get_entity_type(ts_file: &TSFile) -> Result<String, String> {
+  let class_node =
+    get_public_class_node(ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  get_class_declaration_name_node(ts_file, class_node)
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+    .map(|name| name.to_string())
+    .ok_or_else(|| "Couldn't get the class name from the tree".to_string())
+}
+
+fn get_relationships(ts_file: &TSFile) -> Vec<RelationshipFieldMapping> {
+  get_public_class_node(ts_file)
+    .map(|class_node| get_relationship_field_mappings(ts_file, class_node))
+    .unwrap_or_default()
+}
+
+/// Field on the other side of a bidirectional relationship: the owning field named by `mappedBy`
+/// for an inverse field, and the inverse field mapped by it for an owning field. Either way it
+/// must point back to `entity_type`.
+fn find_other_side(
+  relationship: &RelationshipFieldMapping,
+  entity_type: &str,
+  target_relationships: &[RelationshipFieldMapping],
+) -> Option<String> {
+  target_relationships
+    .iter()
+    .filter(|other| other.target_type == entity_type && other.field_name != relationship.field_name)
+    .find(|other| match relationship.side {
+      EntitySide::Inverse => relationship.mapped_by.as_deref() == Some(other.field_name.as_str()),
+      EntitySide::Owning => {
+        other.side == EntitySide::Inverse
+          && other.mapped_by.as_deref() == Some(relationship.field_name.as_str())
+      }
+    })
+    .map(|other| other.field_name.clone())
+}
+
+/// Warns that removing the owning field leaves its foreign key column, or join table, in the
+/// schema.
+fn get_dropped_foreign_key_warning(ts_file: &TSFile, field_name: &str) -> String {
+  let join_column_name = get_public_class_node(ts_file)
+    .and_then(|class_node| get_class_mapping(ts_file, class_node))
+    .and_then(|mapping| {
+      mapping
+        .join_columns
+        .into_iter()
+        .find(|join_column| join_column.field_name == field_name)
+        .map(|join_column| join_column.column_name)
+    });
+  match join_column_name {
+    Some(column_name) => format!(
+      "Removing '{}' drops the mapping of the '{}' foreign key column, which stays in the schema \
+       until a migration drops it",
+      field_name, column_name
+    ),
+    None => format!(
+      "Removing '{}' drops the mapping of its join table, which stays in the schema until a \
+       migration drops it",
+      field_name
+    ),
+  }
+}
+
+/// Removes the field, then the imports of the types and annotations it was the last to use.
+fn remove_relationship_field(ts_file: &mut TSFile, field_name: &str) -> Result<(), String> {
+  let class_start_byte = get_public_class_node(ts_file)
+    .map(|class_node| class_node.start_byte())
+    .ok_or_else(|| "Unable to get public class node".to_string())?;
+  let removed_text = remove_field_declaration(ts_file, class_start_byte, field_name)
+    .ok_or_else(|| format!("Unable to remove field '{}'", field_name))?;
+  let mut names: Vec<&str> = removed_text
+    .split(|c: char| !c.is_alphanumeric() && c != '_')
+    .filter(|word| word.starts_with(|c: char| c.is_ascii_uppercase()))
+    .collect();
+  names.sort();
+  names.dedup();
+  for name in names {
+    remove_unused_import(ts_file, name);
+  }
+  Ok(())
+}
+
+fn build_file_response(
+  ts_file: &TSFile,
+  file_path: &Path,
+  warnings: Vec<String>,
+) -> Result<FileResponse, String> {
+  let file_type = get_entity_type(ts_file)?;
+  let file_package_name = get_package_name(ts_file).unwrap_or_default();
+  Ok(FileResponse {
+    file_type,
+    file_package_name,
+    file_path: file_path.display().to_string(),
+    content_hash: Some(get_content_hash(&ts_file.source_code)),
+    warnings,
+  })
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  owning_only: bool,
+) -> Result<Vec<FileResponse>, String> {
+  // Step 1: Parse entity file and find the relationship field
+  let mut ts_file = TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  let entity_type = get_entity_type(&ts_file)?;
+  let relationship = get_relationships(&ts_file)
+    .into_iter()
+    .find(|relationship| relationship.field_name == field_name)
+    .ok_or_else(|| format!("Field '{}' is not a relationship of '{}'", field_name, entity_type))?;
+  if owning_only && relationship.side == EntitySide::Inverse {
+    return Err(format!(
+      "Field '{}' is the inverse side of the relationship, mapped by '{}.{}'",
+      field_name,
+      relationship.target_type,
+      relationship.mapped_by.as_deref().unwrap_or_default()
+    ));
+  }
+  // Step 2: Resolve the other side in the target entity, the same file for self-references
+  let is_self_reference = relationship.target_type == entity_type;
+  let mut target = match owning_only || is_self_reference {
+    true => None,
+    false => {
+      find_file_by_class_name(cwd, &JavaSourceDirectoryType::Main, &relationship.target_type)
+    }
+  };
+  let other_side_relationships = match (owning_only, is_self_reference) {
+    (true, _) => Vec::new(),
+    (false, true) => get_relationships(&ts_file),
+    (false, false) => target.as_ref().map(get_relationships).unwrap_or_default(),
+  };
+  let other_field_name = find_other_side(&relationship, &entity_type, &other_side_relationships);
+  let target_file_path: Option<PathBuf> = match (&target, &other_field_name) {
+    (Some(target_ts_file), Some(_)) => target_ts_file.file_path().map(|path| path.to_path_buf()),
+    _ => None,
+  };
+  if let Some(target_file_path) = &target_file_path {
+    validate_file_path_within_base(&target_file_path.display().to_string(), cwd)
+      .map_err(|e| format!("Target entity file path must be within working directory: {}", e))?;
+  }
+  // Step 3: Warn about the foreign key mapped by the owning field
+  let mut entity_warnings = Vec::new();
+  let mut target_warnings = Vec::new();
+  match (&relationship.side, &other_field_name) {
+    (EntitySide::Owning, _) => {
+      entity_warnings.push(get_dropped_foreign_key_warning(&ts_file, field_name));
+    }
+    (EntitySide::Inverse, Some(owning_field_name)) => match &target {
+      Some(target_ts_file) if !is_self_reference => {
+        target_warnings.push(get_dropped_foreign_key_warning(target_ts_file, owning_field_name))
+      }
+      _ => entity_warnings.push(get_dropped_foreign_key_warning(&ts_file, owning_field_name)),
+    },
+    (EntitySide::Inverse, None) => {}
+  }
+  // Step 4: Remove the field, and the other side when it lives in the same entity
+  remove_relationship_field(&mut ts_file, field_name)?;
+  let mut removed_field_names = vec![field_name];
+  if is_self_reference && let Some(other_field_name) = &other_field_name {
+    remove_relationship_field(&mut ts_file, other_field_name)?;
+    removed_field_names.push(other_field_name);
+  }
+  // Step 5: Report the references to the removed fields left in the entity
+  entity_warnings.extend(removed_field_names.into_iter().filter_map(|removed_field_name| {
+    get_leftover_references_warning(&ts_file, removed_field_name)
+  }));
+  ts_file
+    .save_to_existing_file(entity_file_path, cwd)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  let mut responses = vec![build_file_response(&ts_file, entity_file_path, entity_warnings)?];
+  // Step 6: Remove the other side from the target entity
+  if let (Some(target_ts_file), Some(other_field_name), Some(target_file_path)) =
+    (target.as_mut(), &other_field_name, &target_file_path)
+  {
+    remove_relationship_field(target_ts_file, other_field_name)?;
+    target_warnings.extend(get_leftover_references_warning(target_ts_file, other_field_name));
+    target_ts_file
+      .save_to_existing_file(target_file_path, cwd)
+      .map_err(|e| format!("Unable to save target JPA Entity file: {}", e))?;
+    responses.push(build_file_response(target_ts_file, target_file_path, target_warnings)?);
+  }
+  Ok(responses)
+}
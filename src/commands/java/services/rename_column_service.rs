@@ -0,0 +1,157 @@
+use std::path::Path;
+
+use crate::commands::java::responses::rename_column_response::RenameColumnResponse;
+use crate::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+use crate::commands::java::treesitter::services::entity_mapping_service::get_class_mapping;
+use crate::commands::java::treesitter::services::field_declaration_service::{
+  find_field_declaration_node_by_name, set_field_annotation_argument,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, get_persistence_package,
+};
+use crate::commands::java::treesitter::types::entity_mapping::MappedClassKind;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::validators::sql_identifier_validator::validate_sql_identifier;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::string_literal_util::to_java_string_literal;
+
+/// Column a field maps to and the annotation naming it.
+struct MappedColumn {
+  entity_type: String,
+  table_name: Option<String>,
+  class_start_byte: usize,
+  column_name: String,
+  is_join_column: bool,
+}
+
+impl MappedColumn {
+  fn annotation_name(&self) -> &'static str {
+    if self.is_join_column { "JoinColumn" } else { "Column" }
+  }
+}
+
+/// Resolves the column of a basic field, or the foreign key column of a relationship owning a
+/// single join column.
+fn get_mapped_column(ts_file: &TSFile, field_name: &str) -> Result<MappedColumn, String> {
+  let class_node =
+    get_public_class_node(ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  let mapping = get_class_mapping(ts_file, class_node).ok_or_else(|| {
+    "Class is not annotated with @Entity, @MappedSuperclass or @Embeddable".to_string()
+  })?;
+  let table_name =
+    (mapping.kind == MappedClassKind::Entity).then(|| mapping.qualified_table_name());
+  let column = |column_name: &str, is_join_column: bool| MappedColumn {
+    entity_type: mapping.class_name.clone(),
+    table_name: table_name.clone(),
+    class_start_byte: class_node.start_byte(),
+    column_name: column_name.to_string(),
+    is_join_column,
+  };
+  if let Some(basic) = mapping.columns.iter().find(|column| column.field_name == field_name) {
+    return Ok(column(&basic.column_name, false));
+  }
+  let join_columns: Vec<_> = mapping
+    .join_columns
+    .iter()
+    .filter(|join_column| join_column.field_name == field_name)
+    .collect();
+  match join_columns.as_slice() {
+    [join_column] => return Ok(column(&join_column.column_name, true)),
+    [_, _, ..] => {
+      return Err(format!(
+        "Field '{}' has several join columns, rename them in its @JoinColumns",
+        field_name
+      ));
+    }
+    [] => {}
+  }
+  if mapping.relationships.iter().any(|relationship| relationship.field_name == field_name) {
+    return Err(format!(
+      "Field '{}' is a relationship without a join column, its columns belong to another table",
+      field_name
+    ));
+  }
+  if mapping.embedded.iter().any(|embedded| embedded.field_name == field_name) {
+    return Err(format!(
+      "Field '{}' is an embedded value, rename its columns with @AttributeOverride instead",
+      field_name
+    ));
+  }
+  match find_field_declaration_node_by_name(ts_file, field_name, class_node) {
+    Some(_) => Err(format!("Field '{}' is not mapped to a column", field_name)),
+    None => Err(format!("Field '{}' not found in '{}'", field_name, mapping.class_name)),
+  }
+}
+
+fn build_migration_warning(column: &MappedColumn, new_column_name: &str) -> String {
+  match &column.table_name {
+    Some(table_name) => format!(
+      "Column '{}' of table '{}' must be renamed to '{}' by a database migration",
+      column.column_name, table_name, new_column_name
+    ),
+    None => format!(
+      "Column '{}' must be renamed to '{}' by a database migration in every table mapping '{}'",
+      column.column_name, new_column_name, column.entity_type
+    ),
+  }
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  column_name: &str,
+) -> Result<RenameColumnResponse, String> {
+  // Step 1: Validate the column name and parse entity file
+  let new_column_name = validate_sql_identifier(column_name)?;
+  let mut entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  // Step 2: Resolve the column the field maps to
+  let column = get_mapped_column(&entity_ts_file, field_name)?;
+  let migration_required = column.column_name != new_column_name;
+  // Step 3: Set the name argument of the mapping annotation, the field itself is left untouched
+  let mut annotation_added = false;
+  if migration_required {
+    annotation_added = set_field_annotation_argument(
+      &mut entity_ts_file,
+      column.class_start_byte,
+      field_name,
+      column.annotation_name(),
+      "name",
+      &to_java_string_literal(&new_column_name),
+    )
+    .ok_or_else(|| format!("Unable to set the column name of field '{}'", field_name))?;
+  }
+  // Step 4: Import the annotation when it was added
+  if annotation_added {
+    let persistence_package = get_persistence_package(&entity_ts_file);
+    add_import(
+      &mut entity_ts_file,
+      &ImportInsertionPosition::BeforeFirstImport,
+      persistence_package,
+      column.annotation_name(),
+    );
+  }
+  // Step 5: Save file with working directory validation
+  entity_ts_file
+    .save_to_existing_file(entity_file_path, cwd)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 6: Build and return response
+  let warnings = match migration_required {
+    true => vec![build_migration_warning(&column, &new_column_name)],
+    false => Vec::new(),
+  };
+  Ok(RenameColumnResponse {
+    entity_file_path: entity_file_path.display().to_string(),
+    entity_type: column.entity_type,
+    field_name: field_name.to_string(),
+    old_column_name: column.column_name,
+    new_column_name,
+    is_join_column: column.is_join_column,
+    annotation_added,
+    migration_required,
+    warnings,
+  })
+}
@@ -0,0 +1,235 @@
+use std::cmp::Reverse;
+use std::path::{Path, PathBuf};
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::rename_response::{RenameResponse, RenamedFileResponse};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_type_declaration_kind, get_type_declaration_name,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  get_all_import_declaration_nodes, resolve_fully_qualified_type_name,
+};
+use crate::commands::java::treesitter::services::method_invocation_service::resolve_identifier_type;
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::commands::java::validators::java_identifier_validator::validate_java_identifier;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::{is_same_file, parse_all_files_within_deadline};
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+fn get_top_level_type_names(ts_file: &TSFile) -> Vec<&str> {
+  let Some(tree) = ts_file.tree.as_ref() else {
+    return Vec::new();
+  };
+  let root_node = tree.root_node();
+  let mut cursor = root_node.walk();
+  root_node
+    .named_children(&mut cursor)
+    .filter(|node| get_type_declaration_kind(*node).is_some())
+    .filter_map(|node| get_type_declaration_name(ts_file, node))
+    .collect()
+}
+
+fn is_in_comment_or_string(node: Node) -> bool {
+  let mut current = Some(node);
+  while let Some(ancestor) = current {
+    if matches!(
+      ancestor.kind(),
+      "line_comment" | "block_comment" | "string_literal" | "text_block" | "character_literal"
+    ) {
+      return true;
+    }
+    current = ancestor.parent();
+  }
+  false
+}
+
+/// Scope of a qualified name (`com.example` for `com.example.Order`) when the node is its last
+/// segment.
+fn get_qualifier<'a>(ts_file: &'a TSFile, node: Node) -> Option<&'a str> {
+  let parent = node.parent()?;
+  let scope_node = match parent.kind() {
+    "scoped_identifier" | "scoped_type_identifier" => {
+      let last_child = parent.named_child(parent.named_child_count().checked_sub(1)?)?;
+      let first_child = parent.named_child(0)?;
+      (last_child.id() == node.id() && first_child.id() != node.id()).then_some(first_child)?
+    }
+    "field_access" if parent.child_by_field_name("field").is_some_and(|f| f.id() == node.id()) => {
+      parent.child_by_field_name("object")?
+    }
+    _ => return None,
+  };
+  ts_file.get_text_from_node(&scope_node)
+}
+
+/// Whether an `identifier` node stands for a type: a declaration or constructor name, the
+/// receiver of a static call or field, a method reference or an annotation name.
+fn is_type_position(node: Node) -> bool {
+  let Some(parent) = node.parent() else {
+    return false;
+  };
+  let is_field = |field_name: &str| {
+    parent.child_by_field_name(field_name).is_some_and(|child| child.id() == node.id())
+  };
+  match parent.kind() {
+    "constructor_declaration"
+    | "compact_constructor_declaration"
+    | "marker_annotation"
+    | "annotation" => is_field("name"),
+    "method_invocation" | "field_access" => is_field("object"),
+    "method_reference" => parent.named_child(0).is_some_and(|child| child.id() == node.id()),
+    "scoped_identifier" | "scoped_type_identifier" => true,
+    _ => get_type_declaration_kind(parent).is_some() && is_field("name"),
+  }
+}
+
+/// Whether the simple name resolves to the renamed class in the file: same package, single-type
+/// import or wildcard import of its package.
+fn refers_to_class(ts_file: &TSFile, class_name: &str, package_name: &str) -> bool {
+  let fully_qualified_name = match package_name.is_empty() {
+    true => class_name.to_string(),
+    false => format!("{}.{}", package_name, class_name),
+  };
+  match resolve_fully_qualified_type_name(ts_file, class_name) {
+    Some(resolved_name) => resolved_name == fully_qualified_name,
+    None => {
+      let wildcard_import = format!("import {}.*;", package_name);
+      get_package_name(ts_file).unwrap_or_default() == package_name
+        || get_all_import_declaration_nodes(ts_file).iter().any(|node| {
+          ts_file.get_text_from_node(node).is_some_and(|text| {
+            text.split_whitespace().collect::<Vec<_>>().join(" ") == wildcard_import
+          })
+        })
+    }
+  }
+}
+
+/// Byte ranges of the references to the class in the file, comments and string literals aside.
+fn find_reference_ranges(
+  ts_file: &TSFile,
+  class_name: &str,
+  package_name: &str,
+  is_declaration_file: bool,
+) -> Vec<(usize, usize)> {
+  let query_string = format!(
+    r#"
+      ((type_identifier) @reference (#eq? @reference "{0}"))
+      ((identifier) @reference (#eq? @reference "{0}"))
+    "#,
+    class_name
+  );
+  let Ok(result) = ts_file.query_builder(&query_string).returning("reference").execute() else {
+    return Vec::new();
+  };
+  let refers_to_class = is_declaration_file || refers_to_class(ts_file, class_name, package_name);
+  let mut ranges: Vec<(usize, usize)> = result
+    .nodes()
+    .into_iter()
+    .filter(|node| !is_in_comment_or_string(*node))
+    .filter(|node| match get_qualifier(ts_file, *node) {
+      Some(qualifier) => {
+        qualifier.split_whitespace().collect::<String>() == package_name && !package_name.is_empty()
+      }
+      None => {
+        refers_to_class
+          && (node.kind() == "type_identifier"
+            || (is_type_position(*node)
+              && resolve_identifier_type(ts_file, *node, class_name).is_none()))
+      }
+    })
+    .map(|node| (node.start_byte(), node.end_byte()))
+    .collect();
+  ranges.sort_by_key(|(start_byte, _)| Reverse(*start_byte));
+  ranges.dedup();
+  ranges
+}
+
+fn rename_references(ts_file: &mut TSFile, ranges: &[(usize, usize)], new_name: &str) {
+  for (start_byte, end_byte) in ranges {
+    ts_file.replace_text_by_range(*start_byte, *end_byte, new_name);
+  }
+}
+
+pub fn run(
+  cwd: &Path,
+  file_path: &Path,
+  new_name: &str,
+  deadline: &ScanDeadline,
+) -> Result<RenameResponse, String> {
+  // Step 1: Parse the declaration file and find the class named after it
+  let mut declaration_ts_file =
+    TSFile::from_file(file_path, cwd, SupportedLanguage::Java).map_err(|e| e.to_string())?;
+  let old_name = file_path
+    .file_stem()
+    .map(|stem| stem.to_string_lossy().to_string())
+    .ok_or_else(|| "Couldn't get the class name from the file name".to_string())?;
+  if !get_top_level_type_names(&declaration_ts_file).contains(&old_name.as_str()) {
+    return Err(format!("No type named '{}' declared in file", old_name));
+  }
+  let new_name = validate_java_identifier(new_name)?;
+  if new_name == old_name {
+    return Err(format!("Class is already named '{}'", old_name));
+  }
+  let package_name = get_package_name(&declaration_ts_file).unwrap_or_default();
+  // Step 2: Parse the main and test sources and check the new name is free in the package
+  let new_file_path: PathBuf = file_path.with_file_name(format!("{}.java", new_name));
+  let mut ts_files = parse_all_files_within_deadline(cwd, &JavaSourceDirectoryType::All, deadline);
+  ts_files.retain(|ts_file| ts_file.file_path().is_none_or(|path| !is_same_file(path, file_path)));
+  // References in the files left out would keep the old name, so nothing is written
+  if deadline.is_truncated() {
+    return Err(format!(
+      "Timed out scanning the project for references to '{}', no file was modified",
+      old_name
+    ));
+  }
+  let collides = new_file_path.exists()
+    || ts_files.iter().any(|ts_file| {
+      get_package_name(ts_file).unwrap_or_default() == package_name
+        && get_top_level_type_names(ts_file).contains(&new_name.as_str())
+    });
+  if collides {
+    return Err(format!("Type '{}' already exists in package '{}'", new_name, package_name));
+  }
+  // Step 3: Rename the declaration, its constructors and the references in the same file
+  let ranges = find_reference_ranges(&declaration_ts_file, &old_name, &package_name, true);
+  rename_references(&mut declaration_ts_file, &ranges, &new_name);
+  let mut files = vec![RenamedFileResponse {
+    file_path: new_file_path.display().to_string(),
+    previous_file_path: Some(file_path.display().to_string()),
+    edits_count: ranges.len(),
+  }];
+  // Step 4: Rename the references in every other file
+  let mut updated_files = Vec::new();
+  for mut ts_file in ts_files {
+    let ranges = find_reference_ranges(&ts_file, &old_name, &package_name, false);
+    if ranges.is_empty() {
+      continue;
+    }
+    rename_references(&mut ts_file, &ranges, &new_name);
+    updated_files.push((ts_file, ranges.len()));
+  }
+  // Step 5: Save every file, moving the declaration file to its new name
+  declaration_ts_file
+    .move_file(&new_file_path, cwd)
+    .map_err(|e| format!("Unable to rename file: {}", e))?;
+  declaration_ts_file.save().map_err(|e| format!("Unable to save file: {}", e))?;
+  for (ts_file, edits_count) in &mut updated_files {
+    ts_file.save().map_err(|e| format!("Unable to save file: {}", e))?;
+    files.push(RenamedFileResponse {
+      file_path: ts_file.file_path().map(|path| path.display().to_string()).unwrap_or_default(),
+      previous_file_path: None,
+      edits_count: *edits_count,
+    });
+  }
+  files[1..].sort_by(|a, b| a.file_path.cmp(&b.file_path));
+  // Step 6: Build and return response
+  Ok(RenameResponse {
+    old_name,
+    new_name,
+    files_count: files.len(),
+    edits_count: files.iter().map(|file| file.edits_count).sum(),
+    files,
+  })
+}
@@ -0,0 +1,446 @@
+use std::cmp::Reverse;
+use std::path::{Path, PathBuf};
+
+use tree_sitter::{Node, Point};
+
+use crate::commands::java::responses::rename_java_field_response::{
+  RenameJavaFieldResponse, RenamedAccessorResponse,
+};
+use crate::commands::java::responses::rename_response::RenamedFileResponse;
+use crate::commands::java::treesitter::services::class_declaration_service::get_type_declaration_name;
+use crate::commands::java::treesitter::services::import_declaration_service::resolve_fully_qualified_type_name;
+use crate::commands::java::treesitter::services::method_declaration_service::{
+  get_getter_name, get_method_declaration_name, get_setter_name,
+};
+use crate::commands::java::treesitter::services::method_invocation_service::{
+  get_method_invocation_arguments_count, get_method_invocation_nodes_by_name, is_local_identifier,
+  resolve_identifier_type,
+};
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::commands::java::validators::java_identifier_validator::is_java_identifier;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::{is_same_file, parse_all_files_within_deadline};
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+/// Field being renamed and the class declaring it.
+struct TargetField<'a> {
+  declarator_name_node: Node<'a>,
+  name: &'a str,
+  field_type: &'a str,
+  class_node: Node<'a>,
+  class_name: &'a str,
+}
+
+/// Accessor method of the field with the name it takes after the rename.
+struct Accessor<'a> {
+  method_node: Node<'a>,
+  new_name: String,
+  parameters_count: usize,
+}
+
+/// Accessor renamed along with the field, kept to rename its calls in the other files.
+struct AccessorRename {
+  old_name: String,
+  new_name: String,
+  parameters_count: usize,
+}
+
+fn get_parameters_count(method_node: Node) -> usize {
+  method_node.child_by_field_name("parameters").map_or(0, |parameters| {
+    let mut cursor = parameters.walk();
+    parameters
+      .named_children(&mut cursor)
+      .filter(|child| matches!(child.kind(), "formal_parameter" | "spread_parameter"))
+      .count()
+  })
+}
+
+/// Finds the field declarator at the 1-based position, the declarator spanning it when the
+/// declaration declares several fields.
+fn find_target_field(
+  ts_file: &TSFile,
+  line: usize,
+  column: usize,
+) -> Result<TargetField<'_>, String> {
+  let not_found = || format!("No field declaration found at line {}, column {}", line, column);
+  let point = Point::new(line.checked_sub(1).ok_or_else(not_found)?, column.saturating_sub(1));
+  let tree = ts_file.tree.as_ref().ok_or_else(not_found)?;
+  let mut node =
+    tree.root_node().named_descendant_for_point_range(point, point).ok_or_else(not_found)?;
+  while node.kind() != "field_declaration" {
+    node = node.parent().ok_or_else(not_found)?;
+  }
+  let field_node = node;
+  let mut cursor = field_node.walk();
+  let declarators: Vec<Node> = field_node
+    .named_children(&mut cursor)
+    .filter(|child| child.kind() == "variable_declarator")
+    .collect();
+  let declarator = match declarators.as_slice() {
+    [declarator] => *declarator,
+    _ => *declarators
+      .iter()
+      .find(|declarator| declarator.start_position() <= point && point <= declarator.end_position())
+      .ok_or_else(|| {
+        format!(
+          "Field declaration at line {} declares several fields, point at the one to rename",
+          line
+        )
+      })?,
+  };
+  let declarator_name_node =
+    declarator.child_by_field_name("name").ok_or_else(|| "Field has no name".to_string())?;
+  let class_node = field_node
+    .parent()
+    .and_then(|body| body.parent())
+    .ok_or_else(|| "Field is not declared in a class".to_string())?;
+  Ok(TargetField {
+    declarator_name_node,
+    name: ts_file.get_text_from_node(&declarator_name_node).unwrap_or_default(),
+    field_type: field_node
+      .child_by_field_name("type")
+      .and_then(|type_node| ts_file.get_text_from_node(&type_node))
+      .unwrap_or_default(),
+    class_node,
+    class_name: get_type_declaration_name(ts_file, class_node).unwrap_or_default(),
+  })
+}
+
+fn get_class_members<'a>(class_node: Node<'a>, kind: &str) -> Vec<Node<'a>> {
+  let Some(body_node) = class_node.child_by_field_name("body") else {
+    return Vec::new();
+  };
+  let mut cursor = body_node.walk();
+  body_node.named_children(&mut cursor).filter(|child| child.kind() == kind).collect()
+}
+
+fn declares_field(ts_file: &TSFile, class_node: Node, field_name: &str) -> bool {
+  get_class_members(class_node, "field_declaration").into_iter().any(|field_node| {
+    let mut cursor = field_node.walk();
+    field_node.named_children(&mut cursor).any(|declarator| {
+      declarator.kind() == "variable_declarator"
+        && declarator.child_by_field_name("name").and_then(|name| ts_file.get_text_from_node(&name))
+          == Some(field_name)
+    })
+  })
+}
+
+/// Getter and setter of the field following the JavaBeans naming.
+fn find_accessors<'a>(
+  ts_file: &'a TSFile,
+  field: &TargetField<'a>,
+  new_name: &str,
+) -> Result<Vec<Accessor<'a>>, String> {
+  let method_nodes = get_class_members(field.class_node, "method_declaration");
+  let accessor_names = [
+    (get_getter_name(field.name, field.field_type), get_getter_name(new_name, field.field_type), 0),
+    (get_setter_name(field.name), get_setter_name(new_name), 1),
+  ];
+  let mut accessors = Vec::new();
+  for (old_accessor_name, new_accessor_name, parameters_count) in accessor_names {
+    let Some(method_node) = method_nodes.iter().copied().find(|node| {
+      get_method_declaration_name(ts_file, *node) == Some(old_accessor_name.as_str())
+        && get_parameters_count(*node) == parameters_count
+    }) else {
+      continue;
+    };
+    let taken = method_nodes.iter().any(|node| {
+      get_method_declaration_name(ts_file, *node) == Some(new_accessor_name.as_str())
+        && get_parameters_count(*node) == parameters_count
+    });
+    if taken {
+      return Err(format!(
+        "Method '{}' already exists in class '{}'",
+        new_accessor_name, field.class_name
+      ));
+    }
+    accessors.push(Accessor { method_node, new_name: new_accessor_name, parameters_count });
+  }
+  Ok(accessors)
+}
+
+fn is_within(node: Node, container: Node) -> bool {
+  node.start_byte() >= container.start_byte() && node.end_byte() <= container.end_byte()
+}
+
+/// Whether the receiver of a field access or invocation is the instance of the class: `this`, or
+/// a variable of the class type (`other.name` in `equals`).
+fn is_own_receiver(ts_file: &TSFile, object_node: Node, class_name: &str) -> bool {
+  match object_node.kind() {
+    "this" => true,
+    "identifier" => ts_file.get_text_from_node(&object_node).is_some_and(|identifier| {
+      resolve_identifier_type(ts_file, object_node, identifier).as_deref() == Some(class_name)
+    }),
+    _ => false,
+  }
+}
+
+/// Byte ranges of the identifiers referring to the field in its class. In a setter whose
+/// parameter is named after the field, the parameter is renamed too so `this.name = name` stays
+/// consistent.
+fn collect_reference_ranges(
+  ts_file: &TSFile,
+  field: &TargetField,
+  setter_node: Option<Node>,
+) -> Vec<(usize, usize)> {
+  let renamed_setter = setter_node.filter(|setter_node| {
+    setter_node.child_by_field_name("parameters").is_some_and(|parameters| {
+      let mut cursor = parameters.walk();
+      parameters.named_children(&mut cursor).any(|parameter| {
+        parameter.child_by_field_name("name").and_then(|name| ts_file.get_text_from_node(&name))
+          == Some(field.name)
+      })
+    })
+  });
+  let mut ranges = Vec::new();
+  let mut pending = vec![field.class_node];
+  while let Some(node) = pending.pop() {
+    let mut cursor = node.walk();
+    pending.extend(node.named_children(&mut cursor));
+    if node.kind() != "identifier" || ts_file.get_text_from_node(&node) != Some(field.name) {
+      continue;
+    }
+    let Some(parent) = node.parent() else {
+      continue;
+    };
+    let is_field = |field_name: &str| {
+      parent.child_by_field_name(field_name).is_some_and(|child| child.id() == node.id())
+    };
+    let in_renamed_setter = renamed_setter.is_some_and(|setter| is_within(node, setter));
+    let is_reference = match parent.kind() {
+      // Only the identifier after `this.` changes
+      "field_access" if is_field("field") => parent
+        .child_by_field_name("object")
+        .is_some_and(|object| is_own_receiver(ts_file, object, field.class_name)),
+      "method_invocation" | "method_declaration" if is_field("name") => false,
+      "element_value_pair" | "method_reference" | "labeled_statement" => false,
+      "variable_declarator" if is_field("name") => false,
+      "formal_parameter" => in_renamed_setter,
+      _ => in_renamed_setter || !is_local_identifier(ts_file, node, field.name),
+    };
+    if is_reference {
+      ranges.push((node.start_byte(), node.end_byte()));
+    }
+  }
+  ranges
+}
+
+/// Renames the accessor calls of another file made on a variable declared with the class type.
+/// Calls whose receiver type can't be resolved, such as on a method's result or a `var`, are left
+/// unchanged and reported as warnings.
+///
+/// # Returns
+/// The number of renamed calls
+fn rename_external_accessor_calls(
+  ts_file: &mut TSFile,
+  class_name: &str,
+  class_fqn: &str,
+  accessor_renames: &[AccessorRename],
+  file_label: &str,
+  warnings: &mut Vec<String>,
+) -> usize {
+  // Files where the simple name is another class can't call the accessors
+  if resolve_fully_qualified_type_name(ts_file, class_name).is_some_and(|fqn| fqn != class_fqn) {
+    return 0;
+  }
+  let mut edits: Vec<(usize, usize, &str)> = Vec::new();
+  for accessor in accessor_renames {
+    for invocation in get_method_invocation_nodes_by_name(ts_file, &accessor.old_name) {
+      if get_method_invocation_arguments_count(invocation) != accessor.parameters_count {
+        continue;
+      }
+      let (Some(object), Some(name_node)) =
+        (invocation.child_by_field_name("object"), invocation.child_by_field_name("name"))
+      else {
+        continue;
+      };
+      let receiver_type = match object.kind() {
+        "identifier" => ts_file
+          .get_text_from_node(&object)
+          .and_then(|identifier| resolve_identifier_type(ts_file, object, identifier)),
+        _ => None,
+      };
+      match receiver_type {
+        Some(receiver_type) if receiver_type == class_name => {
+          edits.push((name_node.start_byte(), name_node.end_byte(), &accessor.new_name));
+        }
+        Some(_) => {}
+        None => warnings.push(format!(
+          "Call to '{}' in {} at line {} was left unchanged, its receiver type couldn't be resolved",
+          accessor.old_name,
+          file_label,
+          invocation.start_position().row + 1
+        )),
+      }
+    }
+  }
+  edits.sort_by_key(|(start_byte, _, _)| Reverse(*start_byte));
+  for (start_byte, end_byte, text) in &edits {
+    ts_file.replace_text_by_range(*start_byte, *end_byte, text);
+  }
+  edits.len()
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  line: usize,
+  column: usize,
+  new_name: &str,
+  deadline: &ScanDeadline,
+) -> Result<RenameJavaFieldResponse, String> {
+  // Step 1: Validate the new name and parse the file
+  if !is_java_identifier(new_name) {
+    return Err(format!("'{}' is not a valid Java field name", new_name));
+  }
+  let mut ts_file = TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  // Step 2: Find the field at the position and check the new name is free
+  let (
+    old_name,
+    class_name,
+    edits,
+    renamed_accessors,
+    accessor_renames,
+    getter_renamed,
+    setter_renamed,
+  ) = {
+    let field = find_target_field(&ts_file, line, column)?;
+    if field.name == new_name {
+      return Err(format!("Field is already named '{}'", new_name));
+    }
+    if declares_field(&ts_file, field.class_node, new_name) {
+      return Err(format!("Field '{}' already exists in class '{}'", new_name, field.class_name));
+    }
+    // Step 3: Find the getter and setter of the field
+    let accessors = find_accessors(&ts_file, &field, new_name)?;
+    let setter_node =
+      accessors.iter().find(|accessor| accessor.parameters_count == 1).map(|a| a.method_node);
+    // Step 4: Collect the declaration, its references and the accessor names and calls
+    let mut edits: Vec<(usize, usize, String)> = vec![(
+      field.declarator_name_node.start_byte(),
+      field.declarator_name_node.end_byte(),
+      new_name.to_string(),
+    )];
+    edits.extend(
+      collect_reference_ranges(&ts_file, &field, setter_node)
+        .into_iter()
+        .map(|(start_byte, end_byte)| (start_byte, end_byte, new_name.to_string())),
+    );
+    let mut renamed_accessors = Vec::new();
+    let mut accessor_renames = Vec::new();
+    for accessor in &accessors {
+      let Some(old_accessor_name) = get_method_declaration_name(&ts_file, accessor.method_node)
+      else {
+        continue;
+      };
+      let name_nodes = accessor.method_node.child_by_field_name("name").into_iter().chain(
+        get_method_invocation_nodes_by_name(&ts_file, old_accessor_name)
+          .into_iter()
+          .filter(|invocation| {
+            get_method_invocation_arguments_count(*invocation) == accessor.parameters_count
+              && invocation
+                .child_by_field_name("object")
+                .is_none_or(|object| is_own_receiver(&ts_file, object, field.class_name))
+          })
+          .filter_map(|invocation| invocation.child_by_field_name("name")),
+      );
+      edits.extend(
+        name_nodes.map(|node| (node.start_byte(), node.end_byte(), accessor.new_name.clone())),
+      );
+      renamed_accessors.push(RenamedAccessorResponse {
+        old_name: old_accessor_name.to_string(),
+        new_name: accessor.new_name.clone(),
+      });
+      accessor_renames.push(AccessorRename {
+        old_name: old_accessor_name.to_string(),
+        new_name: accessor.new_name.clone(),
+        parameters_count: accessor.parameters_count,
+      });
+    }
+    (
+      field.name.to_string(),
+      field.class_name.to_string(),
+      edits,
+      renamed_accessors,
+      accessor_renames,
+      accessors.iter().any(|accessor| accessor.parameters_count == 0),
+      setter_node.is_some(),
+    )
+  };
+  // Step 5: Apply the edits from the end of the file so earlier offsets stay valid
+  let mut edits = edits;
+  edits.sort_by_key(|(start_byte, _, _)| Reverse(*start_byte));
+  edits.dedup_by_key(|(start_byte, _, _)| *start_byte);
+  for (start_byte, end_byte, text) in &edits {
+    ts_file.replace_text_by_range(*start_byte, *end_byte, text);
+  }
+  // Step 6: Rename the accessor calls in the other files of the project
+  let package_name = get_package_name(&ts_file).unwrap_or_default();
+  let class_fqn = format!("{}.{}", package_name, class_name).trim_start_matches('.').to_string();
+  let mut updated_files: Vec<(PathBuf, TSFile, usize)> = Vec::new();
+  let mut warnings = Vec::new();
+  if !accessor_renames.is_empty() {
+    for mut other_ts_file in
+      parse_all_files_within_deadline(cwd, &JavaSourceDirectoryType::All, deadline)
+    {
+      let Some(file_path) = other_ts_file.file_path().cloned() else {
+        continue;
+      };
+      if is_same_file(&file_path, entity_file_path) {
+        continue;
+      }
+      let file_label = file_path.strip_prefix(cwd).unwrap_or(&file_path).display().to_string();
+      let edits_count = rename_external_accessor_calls(
+        &mut other_ts_file,
+        &class_name,
+        &class_fqn,
+        &accessor_renames,
+        &file_label,
+        &mut warnings,
+      );
+      if edits_count > 0 {
+        updated_files.push((file_path, other_ts_file, edits_count));
+      }
+    }
+    // Calls in the files left out would keep the old accessor names, so nothing is written
+    if deadline.is_truncated() {
+      return Err(format!(
+        "Timed out scanning the project for calls to the accessors of '{}', no file was modified",
+        old_name
+      ));
+    }
+  }
+  // Step 7: Save every file, the entity file with working directory validation
+  ts_file
+    .save_to_existing_file(entity_file_path, cwd)
+    .map_err(|e| format!("Unable to save file: {}", e))?;
+  updated_files.sort_by(|a, b| a.0.cmp(&b.0));
+  for (file_path, other_ts_file, _) in &mut updated_files {
+    other_ts_file
+      .save()
+      .map_err(|e| format!("Unable to save file '{}': {}", file_path.display(), e))?;
+  }
+  // Step 8: Build and return response
+  Ok(RenameJavaFieldResponse {
+    entity_file_path: entity_file_path.display().to_string(),
+    class_name,
+    old_name,
+    new_name: new_name.to_string(),
+    getter_renamed,
+    setter_renamed,
+    renamed_accessors,
+    edits_count: edits.len(),
+    updated_files: updated_files
+      .iter()
+      .map(|(file_path, _, edits_count)| RenamedFileResponse {
+        file_path: file_path.display().to_string(),
+        previous_file_path: None,
+        edits_count: *edits_count,
+      })
+      .collect(),
+    warnings,
+  })
+}
@@ -0,0 +1,91 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::replace_annotation_argument_response::ReplaceAnnotationArgumentResponse;
+use crate::commands::java::treesitter::services::annotation_service::{
+  find_declaration_annotation_node_by_name, find_direct_annotation_value_node_by_key,
+  set_annotation_argument,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  find_type_declaration_node, get_type_declaration_name,
+};
+use crate::commands::java::treesitter::services::field_declaration_service::find_field_declaration_node_by_name;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+
+/// Finds the annotated declaration: the field when `field_name` is given, the type otherwise.
+pub fn find_target_node<'a>(
+  ts_file: &'a TSFile,
+  type_name: Option<&str>,
+  field_name: Option<&str>,
+) -> Result<(Node<'a>, String), String> {
+  let type_node =
+    find_type_declaration_node(ts_file, type_name).ok_or_else(|| match type_name {
+      Some(type_name) => format!("Type '{}' not found in file", type_name),
+      None => "No type declaration found in file".to_string(),
+    })?;
+  let type_name = get_type_declaration_name(ts_file, type_node).unwrap_or_default().to_string();
+  match field_name {
+    Some(field_name) => find_field_declaration_node_by_name(ts_file, field_name, type_node)
+      .map(|field_node| (field_node, field_name.to_string()))
+      .ok_or_else(|| format!("Field '{}' not found in type '{}'", field_name, type_name)),
+    None => Ok((type_node, type_name)),
+  }
+}
+
+fn get_annotation_text(ts_file: &TSFile, annotation_start_byte: usize) -> Option<String> {
+  let mut node = ts_file.get_named_node_at_byte_position(annotation_start_byte)?;
+  while !matches!(node.kind(), "annotation" | "marker_annotation") {
+    node = node.parent()?;
+  }
+  ts_file.get_text_from_node(&node).map(|text| text.to_string())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+  cwd: &Path,
+  b64_source_code: &str,
+  file_path: &Path,
+  type_name: Option<&str>,
+  field_name: Option<&str>,
+  annotation_name: &str,
+  key: &str,
+  value: &str,
+) -> Result<ReplaceAnnotationArgumentResponse, String> {
+  // Step 1: Parse the file
+  let mut ts_file = TSFile::from_base64_source_code(b64_source_code, SupportedLanguage::Java);
+  let annotation_name = annotation_name.trim_start_matches('@');
+  // Step 2: Find the annotation on the target declaration
+  let (target_name, annotation_start_byte, annotation_before, argument_added) = {
+    let (target_node, target_name) = find_target_node(&ts_file, type_name, field_name)?;
+    let annotation_node =
+      find_declaration_annotation_node_by_name(&ts_file, target_node, annotation_name)
+        .ok_or_else(|| format!("'{}' is not annotated with @{}", target_name, annotation_name))?;
+    let annotation_before =
+      ts_file.get_text_from_node(&annotation_node).unwrap_or_default().to_string();
+    let argument_added =
+      find_direct_annotation_value_node_by_key(&ts_file, annotation_node, key).is_none();
+    (target_name, annotation_node.start_byte(), annotation_before, argument_added)
+  };
+  // Step 3: Set the argument, keeping the other ones
+  if !set_annotation_argument(&mut ts_file, annotation_start_byte, key, value) {
+    return Err(format!("Unable to set '{}' on @{}", key, annotation_name));
+  }
+  let annotation_after = get_annotation_text(&ts_file, annotation_start_byte)
+    .ok_or_else(|| format!("Unable to read @{} after the change", annotation_name))?;
+  // Step 4: Save file with working directory validation
+  ts_file
+    .save_to_existing_file(file_path, cwd)
+    .map_err(|e| format!("Unable to save file: {}", e))?;
+  // Step 5: Build and return response
+  Ok(ReplaceAnnotationArgumentResponse {
+    file_path: file_path.display().to_string(),
+    target_name,
+    annotation_name: annotation_name.to_string(),
+    key: key.to_string(),
+    argument_added,
+    annotation_before,
+    annotation_after,
+  })
+}
@@ -0,0 +1,286 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::resolve_import_for_type_response::{
+  ImportCandidateResponse, ImportCandidateSource, ResolveImportForTypeResponse,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_type_declaration_kind, get_type_declaration_name,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::get_all_import_declaration_nodes;
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::commands::java::validators::java_identifier_validator::is_java_identifier;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::parse_all_files_within_deadline;
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+/// Commonly used JDK types as (package, simple name), most used first.
+const JDK_TYPES: &[(&str, &str)] = &[
+  ("java.lang", "String"),
+  ("java.lang", "Integer"),
+  ("java.lang", "Long"),
+  ("java.lang", "Boolean"),
+  ("java.lang", "Double"),
+  ("java.lang", "Object"),
+  ("java.lang", "Exception"),
+  ("java.lang", "RuntimeException"),
+  ("java.lang", "IllegalArgumentException"),
+  ("java.lang", "IllegalStateException"),
+  ("java.lang", "StringBuilder"),
+  ("java.util", "List"),
+  ("java.util", "ArrayList"),
+  ("java.util", "Map"),
+  ("java.util", "HashMap"),
+  ("java.util", "Set"),
+  ("java.util", "HashSet"),
+  ("java.util", "Optional"),
+  ("java.util", "UUID"),
+  ("java.util", "Objects"),
+  ("java.util", "Collection"),
+  ("java.util", "Collections"),
+  ("java.util", "Arrays"),
+  ("java.util", "LinkedHashMap"),
+  ("java.util", "LinkedHashSet"),
+  ("java.util", "LinkedList"),
+  ("java.util", "TreeMap"),
+  ("java.util", "TreeSet"),
+  ("java.util", "Comparator"),
+  ("java.util", "Iterator"),
+  ("java.util", "Locale"),
+  ("java.util", "Date"),
+  ("java.util.function", "Function"),
+  ("java.util.function", "Supplier"),
+  ("java.util.function", "Consumer"),
+  ("java.util.function", "Predicate"),
+  ("java.util.function", "BiFunction"),
+  ("java.util.stream", "Stream"),
+  ("java.util.stream", "Collectors"),
+  ("java.util.stream", "IntStream"),
+  ("java.util.concurrent", "CompletableFuture"),
+  ("java.util.concurrent", "ConcurrentHashMap"),
+  ("java.util.concurrent", "TimeUnit"),
+  ("java.time", "LocalDate"),
+  ("java.time", "LocalDateTime"),
+  ("java.time", "LocalTime"),
+  ("java.time", "Instant"),
+  ("java.time", "Duration"),
+  ("java.time", "Period"),
+  ("java.time", "ZonedDateTime"),
+  ("java.time", "OffsetDateTime"),
+  ("java.time", "ZoneId"),
+  ("java.time", "YearMonth"),
+  ("java.math", "BigDecimal"),
+  ("java.math", "BigInteger"),
+  ("java.math", "RoundingMode"),
+  ("java.io", "Serializable"),
+  ("java.io", "IOException"),
+  ("java.io", "UncheckedIOException"),
+  ("java.io", "InputStream"),
+  ("java.io", "OutputStream"),
+  ("java.io", "File"),
+  ("java.nio.file", "Path"),
+  ("java.nio.file", "Paths"),
+  ("java.nio.file", "Files"),
+  ("java.sql", "Timestamp"),
+  ("java.sql", "Date"),
+];
+
+/// Commonly used JPA, Hibernate, Spring and JUnit types as (package, simple name). JPA types are
+/// listed under `jakarta.persistence` and offered under `javax.persistence` as well.
+const LIBRARY_TYPES: &[(&str, &str)] = &[
+  ("jakarta.persistence", "Entity"),
+  ("jakarta.persistence", "Table"),
+  ("jakarta.persistence", "Id"),
+  ("jakarta.persistence", "GeneratedValue"),
+  ("jakarta.persistence", "GenerationType"),
+  ("jakarta.persistence", "Column"),
+  ("jakarta.persistence", "ManyToOne"),
+  ("jakarta.persistence", "OneToMany"),
+  ("jakarta.persistence", "OneToOne"),
+  ("jakarta.persistence", "ManyToMany"),
+  ("jakarta.persistence", "JoinColumn"),
+  ("jakarta.persistence", "JoinTable"),
+  ("jakarta.persistence", "FetchType"),
+  ("jakarta.persistence", "CascadeType"),
+  ("jakarta.persistence", "Embeddable"),
+  ("jakarta.persistence", "Embedded"),
+  ("jakarta.persistence", "EmbeddedId"),
+  ("jakarta.persistence", "Enumerated"),
+  ("jakarta.persistence", "EnumType"),
+  ("jakarta.persistence", "MappedSuperclass"),
+  ("jakarta.persistence", "Transient"),
+  ("jakarta.persistence", "Version"),
+  ("jakarta.persistence", "Lob"),
+  ("jakarta.persistence", "ElementCollection"),
+  ("jakarta.persistence", "SequenceGenerator"),
+  ("jakarta.persistence", "EntityManager"),
+  ("jakarta.persistence", "Index"),
+  ("jakarta.persistence", "UniqueConstraint"),
+  ("jakarta.persistence", "Convert"),
+  ("jakarta.persistence", "AttributeConverter"),
+  ("jakarta.persistence", "PrePersist"),
+  ("jakarta.persistence", "PreUpdate"),
+  ("org.hibernate.annotations", "DynamicUpdate"),
+  ("org.hibernate.annotations", "CreationTimestamp"),
+  ("org.hibernate.annotations", "UpdateTimestamp"),
+  ("org.springframework.stereotype", "Service"),
+  ("org.springframework.stereotype", "Component"),
+  ("org.springframework.stereotype", "Repository"),
+  ("org.springframework.stereotype", "Controller"),
+  ("org.springframework.web.bind.annotation", "RestController"),
+  ("org.springframework.web.bind.annotation", "RequestMapping"),
+  ("org.springframework.web.bind.annotation", "GetMapping"),
+  ("org.springframework.web.bind.annotation", "PostMapping"),
+  ("org.springframework.web.bind.annotation", "PutMapping"),
+  ("org.springframework.web.bind.annotation", "DeleteMapping"),
+  ("org.springframework.web.bind.annotation", "PathVariable"),
+  ("org.springframework.web.bind.annotation", "RequestBody"),
+  ("org.springframework.web.bind.annotation", "RequestParam"),
+  ("org.springframework.beans.factory.annotation", "Autowired"),
+  ("org.springframework.beans.factory.annotation", "Value"),
+  ("org.springframework.context.annotation", "Configuration"),
+  ("org.springframework.context.annotation", "Bean"),
+  ("org.springframework.transaction.annotation", "Transactional"),
+  ("org.springframework.data.jpa.repository", "JpaRepository"),
+  ("org.springframework.data.jpa.repository", "Query"),
+  ("org.springframework.data.jpa.repository", "Modifying"),
+  ("org.springframework.data.repository.query", "Param"),
+  ("org.junit.jupiter.api", "Test"),
+  ("org.junit.jupiter.api", "BeforeEach"),
+  ("org.junit.jupiter.api", "AfterEach"),
+  ("org.junit.jupiter.api", "DisplayName"),
+  ("org.junit.jupiter.api", "Assertions"),
+];
+
+fn build_candidate(
+  package_name: &str,
+  qualified_type_name: &str,
+  source: ImportCandidateSource,
+  file_path: Option<String>,
+) -> ImportCandidateResponse {
+  let fully_qualified_name = match package_name.is_empty() {
+    true => qualified_type_name.to_string(),
+    false => format!("{}.{}", package_name, qualified_type_name),
+  };
+  // Types of the default package can't be imported
+  let import_statement = (!matches!(package_name, "" | "java.lang"))
+    .then(|| format!("import {};", fully_qualified_name));
+  ImportCandidateResponse {
+    fully_qualified_name,
+    package_name: package_name.to_string(),
+    source,
+    import_statement,
+    file_path,
+  }
+}
+
+/// Collects the types named `type_name` declared under `node`, as their name qualified by the
+/// enclosing types (`Order.Status`).
+fn collect_matching_types(
+  ts_file: &TSFile,
+  node: Node,
+  enclosing_name: Option<&str>,
+  type_name: &str,
+  matches: &mut Vec<String>,
+) {
+  let mut cursor = node.walk();
+  for child in node.named_children(&mut cursor) {
+    let Some(name) =
+      get_type_declaration_kind(child).and_then(|_| get_type_declaration_name(ts_file, child))
+    else {
+      // Enum members live in a nested `enum_body_declarations` node
+      if child.kind() == "enum_body_declarations" {
+        collect_matching_types(ts_file, child, enclosing_name, type_name, matches);
+      }
+      continue;
+    };
+    let qualified_name = match enclosing_name {
+      Some(enclosing_name) => format!("{}.{}", enclosing_name, name),
+      None => name.to_string(),
+    };
+    if name == type_name {
+      matches.push(qualified_name.clone());
+    }
+    if let Some(body_node) = child.child_by_field_name("body") {
+      collect_matching_types(ts_file, body_node, Some(&qualified_name), type_name, matches);
+    }
+  }
+}
+
+fn uses_javax_persistence(ts_files: &[TSFile]) -> bool {
+  ts_files.iter().any(|ts_file| {
+    get_all_import_declaration_nodes(ts_file).into_iter().any(|node| {
+      ts_file.get_text_from_node(&node).is_some_and(|text| text.contains("javax.persistence"))
+    })
+  })
+}
+
+pub fn run(
+  cwd: &Path,
+  type_name: &str,
+  deadline: &ScanDeadline,
+) -> Result<ResolveImportForTypeResponse, String> {
+  // Step 1: Validate the type name
+  let type_name = type_name.trim();
+  if !is_java_identifier(type_name) {
+    return Err(format!("'{}' is not a valid simple type name", type_name));
+  }
+  // Step 2: Find the project types with that name, main sources before test sources
+  let ts_files = parse_all_files_within_deadline(cwd, &JavaSourceDirectoryType::All, deadline);
+  let test_directory = cwd.join(JavaSourceDirectoryType::Test.get_directory_path());
+  let mut project_candidates: Vec<(bool, ImportCandidateResponse)> = Vec::new();
+  for ts_file in &ts_files {
+    let Some(tree) = ts_file.tree.as_ref() else {
+      continue;
+    };
+    let mut matches = Vec::new();
+    collect_matching_types(ts_file, tree.root_node(), None, type_name, &mut matches);
+    if matches.is_empty() {
+      continue;
+    }
+    let package_name = get_package_name(ts_file).unwrap_or_default();
+    let file_path = ts_file.file_path();
+    let is_test = file_path.is_some_and(|path| path.starts_with(&test_directory));
+    for qualified_name in matches {
+      let candidate = build_candidate(
+        &package_name,
+        &qualified_name,
+        ImportCandidateSource::Project,
+        file_path.map(|path| path.display().to_string()),
+      );
+      project_candidates.push((is_test, candidate));
+    }
+  }
+  project_candidates.sort_by(|(a_is_test, a), (b_is_test, b)| {
+    (a_is_test, &a.fully_qualified_name).cmp(&(b_is_test, &b.fully_qualified_name))
+  });
+  let mut candidates: Vec<ImportCandidateResponse> =
+    project_candidates.into_iter().map(|(_, candidate)| candidate).collect();
+  // Step 3: Add the built-in JDK and library types, in the JPA namespace the project uses
+  candidates.extend(JDK_TYPES.iter().filter(|(_, name)| *name == type_name).map(
+    |(package_name, name)| build_candidate(package_name, name, ImportCandidateSource::Jdk, None),
+  ));
+  let persistence_packages = match uses_javax_persistence(&ts_files) {
+    true => ["javax.persistence", "jakarta.persistence"],
+    false => ["jakarta.persistence", "javax.persistence"],
+  };
+  for (package_name, name) in LIBRARY_TYPES.iter().filter(|(_, name)| *name == type_name) {
+    let packages = match *package_name == "jakarta.persistence" {
+      true => persistence_packages.to_vec(),
+      false => vec![*package_name],
+    };
+    candidates.extend(packages.into_iter().map(|package_name| {
+      build_candidate(package_name, name, ImportCandidateSource::Library, None)
+    }));
+  }
+  // Step 4: Build and return response
+  Ok(ResolveImportForTypeResponse {
+    type_name: type_name.to_string(),
+    candidates_count: candidates.len(),
+    is_ambiguous: candidates.len() > 1,
+    candidates,
+    truncated: deadline.is_truncated(),
+  })
+}
@@ -0,0 +1,128 @@
+use std::path::Path;
+
+use crate::commands::java::responses::set_column_name_response::SetColumnNameResponse;
+use crate::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+use crate::commands::java::treesitter::services::entity_mapping_service::get_class_mapping;
+use crate::commands::java::treesitter::services::field_declaration_service::{
+  find_field_declaration_node_by_name, set_field_annotation_argument,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, get_persistence_package,
+};
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::validators::sql_identifier_validator::{
+  is_unquoted_mixed_case, validate_sql_identifier,
+};
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::string_literal_util::to_java_string_literal;
+
+/// Quotes a mixed case name when requested, as dialects folding unquoted names (PostgreSQL,
+/// Oracle, H2) would otherwise lose its case, and warns about whichever way it is written.
+fn resolve_mixed_case_name(column_name: String, quote_mixed_case: bool) -> (String, Vec<String>) {
+  if !is_unquoted_mixed_case(&column_name) {
+    return (column_name, Vec::new());
+  }
+  match quote_mixed_case {
+    true => {
+      let warning = format!(
+        "MIXED_CASE_COLUMN_NAME: Column name '{0}' was quoted, native queries and migrations have \
+         to quote it as \"{0}\" too",
+        column_name
+      );
+      (format!("\"{}\"", column_name), vec![warning])
+    }
+    false => {
+      let warning = format!(
+        "MIXED_CASE_COLUMN_NAME: Column name '{}' is mixed case, postgresql, oracle and h2 fold \
+         unquoted names so it doesn't keep its case, use --quote-mixed-case to quote it",
+        column_name
+      );
+      (column_name, vec![warning])
+    }
+  }
+}
+
+/// Resolves the column the field currently maps to. Only basic fields have a `@Column`, foreign
+/// keys and embedded values are renamed through their own annotations.
+fn get_current_column_name(
+  ts_file: &TSFile,
+  field_name: &str,
+) -> Result<(String, usize, String), String> {
+  let class_node =
+    get_public_class_node(ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  let mapping = get_class_mapping(ts_file, class_node).ok_or_else(|| {
+    "Class is not annotated with @Entity, @MappedSuperclass or @Embeddable".to_string()
+  })?;
+  if let Some(column) = mapping.columns.iter().find(|column| column.field_name == field_name) {
+    return Ok((mapping.class_name, class_node.start_byte(), column.column_name.clone()));
+  }
+  if mapping.relationships.iter().any(|relationship| relationship.field_name == field_name) {
+    return Err(format!(
+      "Field '{}' is a relationship, set its column with @JoinColumn instead",
+      field_name
+    ));
+  }
+  if mapping.embedded.iter().any(|embedded| embedded.field_name == field_name) {
+    return Err(format!(
+      "Field '{}' is an embedded value, set its columns with @AttributeOverride instead",
+      field_name
+    ));
+  }
+  match find_field_declaration_node_by_name(ts_file, field_name, class_node) {
+    Some(_) => Err(format!("Field '{}' is not mapped to a column", field_name)),
+    None => Err(format!("Field '{}' not found in '{}'", field_name, mapping.class_name)),
+  }
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  field_name: &str,
+  column_name: &str,
+  quote_mixed_case: bool,
+) -> Result<SetColumnNameResponse, String> {
+  // Step 1: Validate the column name, quoting it when mixed case, and parse entity file
+  let (column_name, warnings) =
+    resolve_mixed_case_name(validate_sql_identifier(column_name)?, quote_mixed_case);
+  let mut entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  // Step 2: Resolve the column the field maps to
+  let (entity_type, class_start_byte, previous_column_name) =
+    get_current_column_name(&entity_ts_file, field_name)?;
+  // Step 3: Set the name of @Column, adding the annotation when missing
+  let column_annotation_added = set_field_annotation_argument(
+    &mut entity_ts_file,
+    class_start_byte,
+    field_name,
+    "Column",
+    "name",
+    &to_java_string_literal(&column_name),
+  )
+  .ok_or_else(|| format!("Unable to set the column name of field '{}'", field_name))?;
+  // Step 4: Import @Column when it was added
+  if column_annotation_added {
+    let persistence_package = get_persistence_package(&entity_ts_file);
+    add_import(
+      &mut entity_ts_file,
+      &ImportInsertionPosition::BeforeFirstImport,
+      persistence_package,
+      "Column",
+    );
+  }
+  // Step 5: Save file with working directory validation
+  entity_ts_file
+    .save_to_existing_file(entity_file_path, cwd)
+    .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  // Step 6: Build and return response
+  Ok(SetColumnNameResponse {
+    entity_file_path: entity_file_path.display().to_string(),
+    entity_type,
+    field_name: field_name.to_string(),
+    previous_column_name,
+    column_name,
+    column_annotation_added,
+    warnings,
+  })
+}
@@ -0,0 +1,152 @@
+use std::path::Path;
+
+use crate::commands::java::responses::set_default_fetch_response::SetDefaultFetchResponse;
+use crate::commands::java::treesitter::services::annotation_service::{
+  find_declaration_annotation_node_by_name, find_direct_annotation_value_node_by_key,
+  set_annotation_argument,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::get_own_field_declaration_nodes;
+use crate::commands::java::treesitter::services::field_declaration_service::get_field_declaration_name_node;
+use crate::commands::java::treesitter::services::import_declaration_service::{
+  add_import, get_persistence_package,
+};
+use crate::commands::java::treesitter::types::fetch_type::FetchType;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
+use crate::commands::java::treesitter::types::relationship_kind::RelationshipKind;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+
+const RELATIONSHIP_KINDS: [RelationshipKind; 4] = [
+  RelationshipKind::ManyToOne,
+  RelationshipKind::OneToMany,
+  RelationshipKind::OneToOne,
+  RelationshipKind::ManyToMany,
+];
+
+/// A relationship annotation whose `fetch` argument has to be rewritten.
+struct FetchChange {
+  field_name: String,
+  kind: RelationshipKind,
+  annotation_start_byte: usize,
+}
+
+/// Fetch type a `fetch` argument resolves to, the JPA default of the relationship when unset.
+pub fn get_current_fetch_type(fetch_value: Option<&str>, kind: &RelationshipKind) -> FetchType {
+  match fetch_value.and_then(|value| value.rsplit('.').next()).map(str::trim) {
+    Some("LAZY") => FetchType::Lazy,
+    Some("EAGER") => FetchType::Eager,
+    _ => kind.get_default_fetch_type(),
+  }
+}
+
+/// Finds the relationships of the entity matching `relationship_kinds` (every kind when empty)
+/// that don't already resolve to `fetch_type`.
+fn collect_fetch_changes(
+  ts_file: &TSFile,
+  fetch_type: &FetchType,
+  relationship_kinds: &[RelationshipKind],
+) -> Result<(String, Vec<FetchChange>), String> {
+  let class_node =
+    get_public_class_node(ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  if find_declaration_annotation_node_by_name(ts_file, class_node, "Entity").is_none() {
+    return Err("Class is not annotated with @Entity".to_string());
+  }
+  let entity_type = get_class_declaration_name_node(ts_file, class_node)
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+    .map(|name| name.to_string())
+    .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+  let mut changes = Vec::new();
+  for field_node in get_own_field_declaration_nodes(ts_file, class_node) {
+    let Some((kind, annotation_node)) = RELATIONSHIP_KINDS.iter().find_map(|kind| {
+      find_declaration_annotation_node_by_name(ts_file, field_node, kind.annotation_name())
+        .map(|node| (kind.clone(), node))
+    }) else {
+      continue;
+    };
+    if !relationship_kinds.is_empty() && !relationship_kinds.contains(&kind) {
+      continue;
+    }
+    let fetch_value = find_direct_annotation_value_node_by_key(ts_file, annotation_node, "fetch")
+      .and_then(|value_node| ts_file.get_text_from_node(&value_node));
+    if get_current_fetch_type(fetch_value, &kind) == *fetch_type {
+      continue;
+    }
+    let field_name = get_field_declaration_name_node(ts_file, field_node)
+      .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+      .unwrap_or_default()
+      .to_string();
+    changes.push(FetchChange {
+      field_name,
+      kind,
+      annotation_start_byte: annotation_node.start_byte(),
+    });
+  }
+  Ok((entity_type, changes))
+}
+
+pub fn run(
+  cwd: &Path,
+  entity_file_b64_src: &str,
+  entity_file_path: &Path,
+  fetch_type: &FetchType,
+  relationship_kinds: &[RelationshipKind],
+) -> Result<SetDefaultFetchResponse, String> {
+  // Step 1: Validate the fetch type and parse entity file
+  if *fetch_type == FetchType::None {
+    return Err("Fetch type must be 'lazy' or 'eager'".to_string());
+  }
+  let mut entity_ts_file =
+    TSFile::from_base64_source_code(entity_file_b64_src, SupportedLanguage::Java);
+  // Step 2: Find the relationships not already at the target fetch type
+  let (entity_type, changes) =
+    collect_fetch_changes(&entity_ts_file, fetch_type, relationship_kinds)?;
+  // Step 3: Rewrite the fetch arguments bottom-up so earlier positions stay valid
+  let fetch_value = format!("FetchType.{}", fetch_type.as_str());
+  for change in changes.iter().rev() {
+    if !set_annotation_argument(
+      &mut entity_ts_file,
+      change.annotation_start_byte,
+      "fetch",
+      &fetch_value,
+    ) {
+      return Err(format!("Unable to set the fetch type of field '{}'", change.field_name));
+    }
+  }
+  // Step 4: Warn about collections loaded eagerly
+  let warnings = changes
+    .iter()
+    .filter(|change| *fetch_type == FetchType::Eager && change.kind == RelationshipKind::OneToMany)
+    .map(|change| {
+      format!(
+        "Field '{}' is a @OneToMany, loading it eagerly fetches the whole collection with every '{}' and can cause performance issues",
+        change.field_name, entity_type
+      )
+    })
+    .collect();
+  // Step 5: Add the FetchType import and save when anything changed
+  if !changes.is_empty() {
+    let persistence_package = get_persistence_package(&entity_ts_file);
+    add_import(
+      &mut entity_ts_file,
+      &ImportInsertionPosition::BeforeFirstImport,
+      persistence_package,
+      "FetchType",
+    );
+    entity_ts_file
+      .save_to_existing_file(entity_file_path, cwd)
+      .map_err(|e| format!("Unable to save JPA Entity file: {}", e))?;
+  }
+  // Step 6: Build and return response
+  let changed_fields: Vec<String> = changes.into_iter().map(|change| change.field_name).collect();
+  Ok(SetDefaultFetchResponse {
+    entity_file_path: entity_file_path.display().to_string(),
+    entity_type,
+    fetch_type: fetch_type.as_str().to_string(),
+    changed_fields_count: changed_fields.len(),
+    changed_fields,
+    warnings,
+  })
+}
@@ -0,0 +1,367 @@
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::commands::java::responses::validate_entity_response::{
+  EntityFindingResponse, ValidateEntityResponse,
+};
+use crate::commands::java::services::get_jpa_entity_info_service::create_ts_file;
+use crate::commands::java::services::set_default_fetch_service::get_current_fetch_type;
+use crate::commands::java::treesitter::services::annotation_service::{
+  find_annotation_node_by_name, find_declaration_annotation_node_by_name,
+  find_direct_annotation_value_node_by_key,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::constructor_declaration_service::{
+  find_no_arg_constructor_node, get_constructor_visibility_modifier, has_no_arg_constructor,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::{
+  get_class_mapping, get_own_field_declaration_nodes, get_simple_type_name,
+};
+use crate::commands::java::treesitter::services::field_declaration_service::get_field_declaration_name_node;
+use crate::commands::java::treesitter::services::method_declaration_service::{
+  get_method_declaration_name, get_own_method_declaration_nodes, get_referenced_field_names,
+};
+use crate::commands::java::treesitter::types::fetch_type::FetchType;
+use crate::commands::java::treesitter::types::finding_severity::FindingSeverity;
+use crate::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+use crate::commands::java::treesitter::types::relationship_kind::RelationshipKind;
+use crate::commands::java::treesitter::types::reserved_word_check::ReservedWordCheck;
+use crate::commands::java::treesitter::types::sql_dialect::SqlDialect;
+use crate::commands::java::validators::sql_identifier_validator::is_unquoted_mixed_case;
+use crate::common::ts_file::TSFile;
+
+fn finding(
+  severity: FindingSeverity,
+  code: &str,
+  message: String,
+  field_name: Option<&str>,
+  suggestion: Option<&str>,
+) -> EntityFindingResponse {
+  EntityFindingResponse {
+    severity: severity.as_str().to_string(),
+    code: code.to_string(),
+    message,
+    field_name: field_name.map(|name| name.to_string()),
+    suggestion: suggestion.map(|s| s.to_string()),
+  }
+}
+
+fn check_no_arg_constructor(
+  ts_file: &TSFile,
+  class_node: Node,
+  entity_type: &str,
+  findings: &mut Vec<EntityFindingResponse>,
+) {
+  if !has_no_arg_constructor(ts_file, class_node) {
+    findings.push(finding(
+      FindingSeverity::Error,
+      "MISSING_NO_ARG_CONSTRUCTOR",
+      format!(
+        "Entity '{}' only declares constructors with parameters, JPA requires a no-arg constructor",
+        entity_type
+      ),
+      None,
+      Some("Add a protected no-arg constructor (ensure-no-arg-constructor)"),
+    ));
+    return;
+  }
+  if let Some(constructor_node) = find_no_arg_constructor_node(ts_file, class_node)
+    && get_constructor_visibility_modifier(ts_file, constructor_node)
+      == JavaVisibilityModifier::Private
+  {
+    findings.push(finding(
+      FindingSeverity::Warning,
+      "PRIVATE_NO_ARG_CONSTRUCTOR",
+      format!("The no-arg constructor of entity '{}' is private", entity_type),
+      None,
+      Some("Make the no-arg constructor public or protected"),
+    ));
+  }
+}
+
+fn check_big_decimal_dimensions(
+  ts_file: &TSFile,
+  class_node: Node,
+  findings: &mut Vec<EntityFindingResponse>,
+) {
+  let Some(mapping) = get_class_mapping(ts_file, class_node) else {
+    return;
+  };
+  for column in mapping.columns {
+    if get_simple_type_name(&column.field_type) != "BigDecimal"
+      || column.precision.is_some()
+      || column.scale.is_some()
+      || column.column_definition.is_some()
+    {
+      continue;
+    }
+    findings.push(finding(
+      FindingSeverity::Warning,
+      "DIMENSIONLESS_BIG_DECIMAL",
+      format!(
+        "BigDecimal field '{}' has no precision or scale, the database default may round values",
+        column.field_name
+      ),
+      Some(&column.field_name),
+      Some("Set precision and scale on @Column, e.g. precision = 19, scale = 2"),
+    ));
+  }
+}
+
+const RELATIONSHIP_KINDS: [RelationshipKind; 4] = [
+  RelationshipKind::ManyToOne,
+  RelationshipKind::OneToMany,
+  RelationshipKind::OneToOne,
+  RelationshipKind::ManyToMany,
+];
+
+/// Names of the relationship fields resolving to `FetchType.LAZY`, explicitly or by default.
+fn get_lazy_relationship_field_names<'a>(ts_file: &'a TSFile, class_node: Node) -> Vec<&'a str> {
+  get_own_field_declaration_nodes(ts_file, class_node)
+    .into_iter()
+    .filter(|field_node| {
+      RELATIONSHIP_KINDS.iter().any(|kind| {
+        find_declaration_annotation_node_by_name(ts_file, *field_node, kind.annotation_name())
+          .is_some_and(|annotation_node| {
+            let fetch_value =
+              find_direct_annotation_value_node_by_key(ts_file, annotation_node, "fetch")
+                .and_then(|value_node| ts_file.get_text_from_node(&value_node));
+            get_current_fetch_type(fetch_value, kind) == FetchType::Lazy
+          })
+      })
+    })
+    .filter_map(|field_node| {
+      get_field_declaration_name_node(ts_file, field_node)
+        .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+    })
+    .collect()
+}
+
+/// `toString`, `equals` and `hashCode` overrides reading LAZY relationships, which triggers a fetch
+/// or a `LazyInitializationException` on detached entities.
+fn check_lazy_fields_in_object_methods(
+  ts_file: &TSFile,
+  class_node: Node,
+  findings: &mut Vec<EntityFindingResponse>,
+) {
+  let lazy_field_names = get_lazy_relationship_field_names(ts_file, class_node);
+  if lazy_field_names.is_empty() {
+    return;
+  }
+  for method_node in get_own_method_declaration_nodes(ts_file, class_node) {
+    let parameters_count = method_node.child_by_field_name("parameters").map_or(0, |node| {
+      let mut cursor = node.walk();
+      node.named_children(&mut cursor).filter(|child| child.kind() == "formal_parameter").count()
+    });
+    let method_name = match get_method_declaration_name(ts_file, method_node) {
+      Some(name @ ("toString" | "hashCode")) if parameters_count == 0 => name,
+      Some(name @ "equals") if parameters_count == 1 => name,
+      _ => continue,
+    };
+    for field_name in get_referenced_field_names(ts_file, method_node, &lazy_field_names) {
+      let suggestion = match method_name {
+        "toString" => format!("Leave '{}' out of toString() or print only its id", field_name),
+        _ => {
+          format!("Base {}() on the id or a natural key instead of '{}'", method_name, field_name)
+        }
+      };
+      findings.push(finding(
+        FindingSeverity::Warning,
+        "LAZY_FIELD_IN_OBJECT_METHOD",
+        format!(
+          "{}() reads LAZY relationship '{}', which fetches it or throws a LazyInitializationException outside a session",
+          method_name, field_name
+        ),
+        Some(field_name),
+        Some(&suggestion),
+      ));
+    }
+  }
+}
+
+/// Unquoted table, column and join column names that are reserved words of the dialect.
+fn check_reserved_names(
+  ts_file: &TSFile,
+  class_node: Node,
+  reserved_word_check: &ReservedWordCheck,
+  findings: &mut Vec<EntityFindingResponse>,
+) {
+  let Some(mapping) = get_class_mapping(ts_file, class_node) else {
+    return;
+  };
+  let mut names = vec![("Table", mapping.table_name.as_str(), None)];
+  names.extend(
+    mapping
+      .columns
+      .iter()
+      .map(|column| ("Column", column.column_name.as_str(), Some(column.field_name.as_str()))),
+  );
+  names.extend(mapping.join_columns.iter().map(|join_column| {
+    ("Join column", join_column.column_name.as_str(), Some(join_column.field_name.as_str()))
+  }));
+  for (kind, name, field_name) in names {
+    if !reserved_word_check.is_reserved(name) {
+      continue;
+    }
+    findings.push(finding(
+      FindingSeverity::Warning,
+      "RESERVED_SQL_WORD",
+      format!("{} name '{}' is a reserved SQL word", kind, name),
+      field_name,
+      Some(&format!("Quote the name, e.g. name = \"\\\"{}\\\"\"", name)),
+    ));
+  }
+}
+
+/// Unquoted column and join column names mixing upper and lower case, which dialects folding
+/// unquoted names don't keep. Skipped for a dialect that keeps them as written.
+fn check_mixed_case_column_names(
+  ts_file: &TSFile,
+  class_node: Node,
+  dialect: Option<&SqlDialect>,
+  findings: &mut Vec<EntityFindingResponse>,
+) {
+  if dialect.is_some_and(|dialect| !dialect.folds_unquoted_identifiers()) {
+    return;
+  }
+  let Some(mapping) = get_class_mapping(ts_file, class_node) else {
+    return;
+  };
+  let names = mapping
+    .columns
+    .iter()
+    .map(|column| ("Column", column.column_name.as_str(), column.field_name.as_str()))
+    .chain(mapping.join_columns.iter().map(|join_column| {
+      ("Join column", join_column.column_name.as_str(), join_column.field_name.as_str())
+    }));
+  let folding = match dialect {
+    Some(dialect) => format!("{} folds unquoted names", dialect.as_str()),
+    None => "postgresql, oracle and h2 fold unquoted names".to_string(),
+  };
+  for (kind, name, field_name) in names {
+    if !is_unquoted_mixed_case(name) {
+      continue;
+    }
+    findings.push(finding(
+      FindingSeverity::Warning,
+      "MIXED_CASE_COLUMN_NAME",
+      format!("{} name '{}' is mixed case, {} so it doesn't keep its case", kind, name, folding),
+      Some(field_name),
+      Some(&format!(
+        "Quote the name, e.g. name = \"\\\"{}\\\"\" (set-column-name --quote-mixed-case does it), native queries and migrations then have to quote it with the same case",
+        name
+      )),
+    ));
+  }
+}
+
+/// Columns an entity maps, besides its id and version, from which `@DynamicUpdate` is suggested.
+pub const DEFAULT_DYNAMIC_UPDATE_THRESHOLD: usize = 20;
+
+/// Suggests `@DynamicUpdate` for entities mapping many columns, most of them nullable, as updates
+/// then typically touch few of them while Hibernate writes every column by default.
+fn check_dynamic_update(
+  ts_file: &TSFile,
+  class_node: Node,
+  entity_type: &str,
+  threshold: usize,
+  findings: &mut Vec<EntityFindingResponse>,
+) {
+  if find_annotation_node_by_name(ts_file, class_node, "DynamicUpdate").is_some() {
+    return;
+  }
+  let Some(mapping) = get_class_mapping(ts_file, class_node) else {
+    return;
+  };
+  let columns: Vec<bool> = mapping
+    .columns
+    .iter()
+    .filter(|column| !column.is_id && !column.is_version)
+    .map(|column| column.nullable)
+    .chain(mapping.join_columns.iter().map(|join_column| join_column.nullable))
+    .collect();
+  let nullable_count = columns.iter().filter(|nullable| **nullable).count();
+  if columns.len() < threshold || nullable_count * 2 <= columns.len() {
+    return;
+  }
+  findings.push(finding(
+    FindingSeverity::Info,
+    "DYNAMIC_UPDATE_SUGGESTED",
+    format!(
+      "Entity '{}' maps {} columns, {} of them nullable, and every update writes all of them",
+      entity_type,
+      columns.len(),
+      nullable_count
+    ),
+    None,
+    Some(
+      "Annotate the entity with @DynamicUpdate (org.hibernate.annotations) if most updates touch few fields, Hibernate then only writes the changed columns at the cost of building the statement on each flush",
+    ),
+  ));
+}
+
+/// Runs every entity check against the given class node. Reserved and mixed case names are
+/// checked against `dialect`, or against every supported dialect when none is given.
+pub fn validate_entity(
+  ts_file: &TSFile,
+  class_node: Node,
+  entity_type: &str,
+  dialect: Option<SqlDialect>,
+  dynamic_update_threshold: Option<usize>,
+) -> Vec<EntityFindingResponse> {
+  let mut findings = Vec::new();
+  check_no_arg_constructor(ts_file, class_node, entity_type, &mut findings);
+  check_big_decimal_dimensions(ts_file, class_node, &mut findings);
+  check_lazy_fields_in_object_methods(ts_file, class_node, &mut findings);
+  let reserved_word_check = ReservedWordCheck { dialect, quote_reserved: false };
+  check_reserved_names(ts_file, class_node, &reserved_word_check, &mut findings);
+  check_mixed_case_column_names(
+    ts_file,
+    class_node,
+    reserved_word_check.dialect.as_ref(),
+    &mut findings,
+  );
+  let threshold = dynamic_update_threshold.unwrap_or(DEFAULT_DYNAMIC_UPDATE_THRESHOLD);
+  check_dynamic_update(ts_file, class_node, entity_type, threshold, &mut findings);
+  findings
+}
+
+pub fn run(
+  entity_file_path: Option<&Path>,
+  b64_source_code: Option<&str>,
+  dialect: Option<SqlDialect>,
+  dynamic_update_threshold: Option<usize>,
+  cwd: &Path,
+) -> Result<ValidateEntityResponse, String> {
+  // Step 1: Create TSFile
+  let ts_file = create_ts_file(entity_file_path, b64_source_code, cwd)?;
+  // Step 2: Get public class node and ensure it is an entity
+  let class_node =
+    get_public_class_node(&ts_file).ok_or_else(|| "Unable to get public class node".to_string())?;
+  if find_annotation_node_by_name(&ts_file, class_node, "Entity").is_none() {
+    return Err("Class is not annotated with @Entity".to_string());
+  }
+  let entity_type = get_class_declaration_name_node(&ts_file, class_node)
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+    .map(|name| name.to_string())
+    .ok_or_else(|| "Couldn't get the class name from the tree".to_string())?;
+  // Step 3: Run the checks
+  let findings =
+    validate_entity(&ts_file, class_node, &entity_type, dialect, dynamic_update_threshold);
+  // Step 4: Build response
+  let count = |severity: FindingSeverity| {
+    findings.iter().filter(|finding| finding.severity == severity.as_str()).count()
+  };
+  let errors_count = count(FindingSeverity::Error);
+  let warnings_count = count(FindingSeverity::Warning);
+  Ok(ValidateEntityResponse {
+    entity_type,
+    is_valid: errors_count == 0,
+    findings,
+    errors_count,
+    warnings_count,
+  })
+}
@@ -0,0 +1,257 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+
+use crate::commands::java::responses::validate_entity_response::EntityFindingResponse;
+use crate::commands::java::responses::validate_project_response::{
+  FileFindingsResponse, ValidateProjectResponse,
+};
+use crate::commands::java::services::add_repository_query_method_service::find_repository_entity_type;
+use crate::commands::java::services::check_entity_compiles_against_repository_service;
+use crate::commands::java::services::generate_schema_service::{
+  ProjectMappings, load_project_mappings,
+};
+use crate::commands::java::services::validate_entity_service::validate_entity;
+use crate::commands::java::treesitter::services::annotation_service::find_annotation_node_by_name;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_public_class_node,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::get_simple_type_name;
+use crate::commands::java::treesitter::services::interface_declaration_service::{
+  get_interface_name_node, get_public_interface_node,
+};
+use crate::commands::java::treesitter::types::entity_mapping::{EntityMapping, MappedClassKind};
+use crate::commands::java::treesitter::types::finding_severity::FindingSeverity;
+use crate::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+use crate::commands::java::treesitter::types::relationship_kind::RelationshipKind;
+use crate::commands::java::treesitter::types::sql_dialect::SqlDialect;
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::path_util::get_all_java_file_paths;
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+/// Entity or repository declared by a project file, with the findings of its own checks.
+enum ScannedFile {
+  Entity { file_path: PathBuf, entity_type: String, findings: Vec<EntityFindingResponse> },
+  Repository { file_path: PathBuf, repository_type: String, entity_type: String },
+}
+
+fn scan_file(path: &Path, cwd: &Path, dialect: Option<SqlDialect>) -> Option<ScannedFile> {
+  let ts_file = TSFile::from_file(path, cwd, SupportedLanguage::Java).ok()?;
+  if let Some(class_node) = get_public_class_node(&ts_file) {
+    find_annotation_node_by_name(&ts_file, class_node, "Entity")?;
+    let entity_type = get_class_declaration_name_node(&ts_file, class_node)
+      .and_then(|name_node| ts_file.get_text_from_node(&name_node))?
+      .to_string();
+    let findings = validate_entity(&ts_file, class_node, &entity_type, dialect, None);
+    return Some(ScannedFile::Entity { file_path: path.to_path_buf(), entity_type, findings });
+  }
+  let interface_node = get_public_interface_node(&ts_file)?;
+  let entity_type = find_repository_entity_type(&ts_file, interface_node)?;
+  let repository_type = get_interface_name_node(&ts_file, interface_node)
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))?
+    .to_string();
+  Some(ScannedFile::Repository { file_path: path.to_path_buf(), repository_type, entity_type })
+}
+
+fn error_finding(code: &str, message: String, field_name: Option<&str>) -> EntityFindingResponse {
+  EntityFindingResponse {
+    severity: FindingSeverity::Error.as_str().to_string(),
+    code: code.to_string(),
+    message,
+    field_name: field_name.map(|name| name.to_string()),
+    suggestion: None,
+  }
+}
+
+/// The class and the superclasses of the project it inherits relationship fields from.
+fn get_class_hierarchy<'a>(
+  project: &'a ProjectMappings,
+  class_name: &str,
+) -> Vec<&'a EntityMapping> {
+  let mut hierarchy: Vec<&EntityMapping> = Vec::new();
+  let mut current_name = Some(class_name.to_string());
+  while let Some(mapping) = current_name.and_then(|name| project.classes.get(&name)) {
+    if hierarchy.iter().any(|class| class.class_name == mapping.class_name) {
+      break;
+    }
+    hierarchy.push(mapping);
+    current_name = mapping.superclass_name.as_deref().map(get_simple_type_name);
+  }
+  hierarchy
+}
+
+fn get_owning_kind(inverse_kind: &RelationshipKind) -> Option<RelationshipKind> {
+  match inverse_kind {
+    RelationshipKind::OneToMany => Some(RelationshipKind::ManyToOne),
+    RelationshipKind::OneToOne => Some(RelationshipKind::OneToOne),
+    RelationshipKind::ManyToMany => Some(RelationshipKind::ManyToMany),
+    RelationshipKind::ManyToOne => None,
+  }
+}
+
+/// Checks that every `mappedBy` of the entity names a relationship field of the target that points
+/// back to the entity with the matching annotation.
+///
+/// # Returns
+/// The findings, reported on the inverse side fields
+fn check_bidirectional_relationships(
+  project: &ProjectMappings,
+  entity: &EntityMapping,
+) -> Vec<EntityFindingResponse> {
+  let mut findings = Vec::new();
+  let entity_hierarchy = get_class_hierarchy(project, &entity.class_name);
+  for relationship in &entity.relationships {
+    let Some(mapped_by) = relationship.mapped_by.as_deref().filter(|name| !name.contains('.'))
+    else {
+      continue;
+    };
+    let target_hierarchy = get_class_hierarchy(project, &relationship.target_type);
+    if target_hierarchy.is_empty() {
+      continue;
+    }
+    let field_name = Some(relationship.field_name.as_str());
+    let Some(owning_field) = target_hierarchy
+      .iter()
+      .flat_map(|class| class.relationships.iter())
+      .find(|owning_field| owning_field.field_name == mapped_by)
+    else {
+      findings.push(error_finding(
+        "MAPPED_BY_FIELD_NOT_FOUND",
+        format!(
+          "mappedBy of '{}.{}' names '{}', which is not a relationship field of '{}'",
+          entity.class_name, relationship.field_name, mapped_by, relationship.target_type
+        ),
+        field_name,
+      ));
+      continue;
+    };
+    if owning_field.mapped_by.is_some() {
+      findings.push(error_finding(
+        "MAPPED_BY_ON_BOTH_SIDES",
+        format!(
+          "'{}.{}' and '{}.{}' both declare mappedBy, one of them must own the relationship",
+          entity.class_name, relationship.field_name, relationship.target_type, mapped_by
+        ),
+        field_name,
+      ));
+    }
+    if !entity_hierarchy.iter().any(|class| class.class_name == owning_field.target_type) {
+      findings.push(error_finding(
+        "MAPPED_BY_TARGET_MISMATCH",
+        format!(
+          "'{}.{}' targets '{}' instead of '{}'",
+          relationship.target_type, mapped_by, owning_field.target_type, entity.class_name
+        ),
+        field_name,
+      ));
+    }
+    if let Some(owning_kind) = get_owning_kind(&relationship.kind)
+      && owning_kind != owning_field.kind
+    {
+      findings.push(error_finding(
+        "MAPPED_BY_KIND_MISMATCH",
+        format!(
+          "@{} '{}.{}' is mapped by @{} '{}.{}', expected @{}",
+          relationship.kind.annotation_name(),
+          entity.class_name,
+          relationship.field_name,
+          owning_field.kind.annotation_name(),
+          relationship.target_type,
+          mapped_by,
+          owning_kind.annotation_name()
+        ),
+        field_name,
+      ));
+    }
+  }
+  findings
+}
+
+pub fn run(
+  cwd: &Path,
+  dialect: Option<SqlDialect>,
+  fail_on: Option<FindingSeverity>,
+) -> Result<ValidateProjectResponse, String> {
+  // Step 1: Run the entity checks and find the repositories in parallel
+  let paths = get_all_java_file_paths(cwd, &JavaSourceDirectoryType::Main);
+  let scanned_files: Vec<ScannedFile> =
+    paths.par_iter().filter_map(|path| scan_file(path, cwd, dialect.clone())).collect();
+  let mut files: BTreeMap<PathBuf, (String, Vec<EntityFindingResponse>)> = BTreeMap::new();
+  let mut entity_paths: HashMap<String, PathBuf> = HashMap::new();
+  let mut repositories = Vec::new();
+  for scanned_file in scanned_files {
+    match scanned_file {
+      ScannedFile::Entity { file_path, entity_type, findings } => {
+        entity_paths.insert(entity_type.clone(), file_path.clone());
+        files.insert(file_path, (entity_type, findings));
+      }
+      ScannedFile::Repository { file_path, repository_type, entity_type } => {
+        repositories.push((file_path, repository_type, entity_type));
+      }
+    }
+  }
+  // Step 2: Check each repository against its entity, skipping entities outside of the project
+  let repository_findings: Vec<(PathBuf, String, Vec<EntityFindingResponse>)> = repositories
+    .par_iter()
+    .filter_map(|(repository_path, repository_type, entity_type)| {
+      let entity_path = entity_paths.get(entity_type)?;
+      let findings = match check_entity_compiles_against_repository_service::run(
+        cwd,
+        entity_path,
+        repository_path,
+      ) {
+        Ok(response) => response
+          .mismatches
+          .into_iter()
+          .map(|mismatch| error_finding(&mismatch.code, mismatch.message, None))
+          .collect(),
+        Err(error_msg) => vec![error_finding("REPOSITORY_CHECK_FAILED", error_msg, None)],
+      };
+      Some((repository_path.clone(), repository_type.clone(), findings))
+    })
+    .collect();
+  let repositories_count = repository_findings.len();
+  for (repository_path, repository_type, findings) in repository_findings {
+    files.insert(repository_path, (repository_type, findings));
+  }
+  // Step 3: Check the consistency of the bidirectional relationships
+  let project = load_project_mappings(cwd, &ScanDeadline::unbounded());
+  for entity in project.classes.values().filter(|class| class.kind == MappedClassKind::Entity) {
+    let Some((_, findings)) =
+      entity_paths.get(&entity.class_name).and_then(|path| files.get_mut(path))
+    else {
+      continue;
+    };
+    findings.extend(check_bidirectional_relationships(&project, entity));
+  }
+  // Step 4: Count the findings and apply the fail-on threshold
+  let entities_count = entity_paths.len();
+  let severities: Vec<FindingSeverity> = files
+    .values()
+    .flat_map(|(_, findings)| findings.iter())
+    .filter_map(|finding| FindingSeverity::from_value(&finding.severity).ok())
+    .collect();
+  let count = |severity: FindingSeverity| severities.iter().filter(|s| **s == severity).count();
+  let passed =
+    fail_on.is_none_or(|threshold| severities.iter().all(|severity| *severity < threshold));
+  // Step 5: Build response
+  Ok(ValidateProjectResponse {
+    files: files
+      .into_iter()
+      .filter(|(_, (_, findings))| !findings.is_empty())
+      .map(|(file_path, (type_name, findings))| FileFindingsResponse {
+        file_path: file_path.display().to_string(),
+        type_name,
+        findings,
+      })
+      .collect(),
+    entities_count,
+    repositories_count,
+    errors_count: count(FindingSeverity::Error),
+    warnings_count: count(FindingSeverity::Warning),
+    fail_on: fail_on.map(|severity| severity.as_str().to_string()),
+    passed,
+  })
+}
@@ -1,5 +1,9 @@
 #![allow(dead_code)]
 
+use crate::commands::java::responses::get_annotations_response::{
+  AnnotationArgumentResponse, AnnotationForm, AnnotationResponse, AnnotationValueResponse,
+};
+use crate::commands::java::treesitter::services::import_declaration_service::resolve_fully_qualified_type_name;
 use crate::commands::java::treesitter::types::annotation_types::{
   AnnotationInsertionPoint, AnnotationInsertionPosition,
 };
@@ -91,6 +95,37 @@ pub fn find_annotation_node_by_name<'a>(
     .first_node()
 }
 
+/// Returns the annotations placed directly on a declaration (its `modifiers`), leaving out
+/// annotations nested inside annotation arguments or declared on inner members.
+pub fn get_declaration_annotation_nodes<'a>(declaration_node: Node<'a>) -> Vec<Node<'a>> {
+  let mut cursor = declaration_node.walk();
+  let Some(modifiers_node) =
+    declaration_node.children(&mut cursor).find(|child| child.kind() == "modifiers")
+  else {
+    return Vec::new();
+  };
+  let mut modifiers_cursor = modifiers_node.walk();
+  modifiers_node
+    .children(&mut modifiers_cursor)
+    .filter(|child| matches!(child.kind(), "annotation" | "marker_annotation"))
+    .collect()
+}
+
+pub fn find_declaration_annotation_node_by_name<'a>(
+  ts_file: &'a TSFile,
+  declaration_node: Node<'a>,
+  annotation_name: &str,
+) -> Option<Node<'a>> {
+  get_declaration_annotation_nodes(declaration_node).into_iter().find(|annotation_node| {
+    annotation_node
+      .child_by_field_name("name")
+      .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+      .is_some_and(|name| {
+        name == annotation_name || name.ends_with(&format!(".{}", annotation_name))
+      })
+  })
+}
+
 pub fn get_annotation_argument_pair_nodes<'a>(
   ts_file: &'a TSFile,
   scope_node: Node<'a>,
@@ -186,6 +221,31 @@ pub fn find_annotation_value_node_by_key<'a>(
     .first_node()
 }
 
+/// Finds the value of `key` in the annotation's own argument list, without descending into nested
+/// annotations. For `key == "value"` a single unnamed argument (`@Enumerated(EnumType.STRING)`)
+/// is returned as well.
+pub fn find_direct_annotation_value_node_by_key<'a>(
+  ts_file: &'a TSFile,
+  annotation_node: Node<'a>,
+  key: &str,
+) -> Option<Node<'a>> {
+  let arguments_node = annotation_node.child_by_field_name("arguments")?;
+  let mut cursor = arguments_node.walk();
+  let arguments: Vec<Node> = arguments_node.named_children(&mut cursor).collect();
+  for argument in arguments {
+    if argument.kind() == "element_value_pair" {
+      let key_text =
+        argument.child_by_field_name("key").and_then(|n| ts_file.get_text_from_node(&n));
+      if key_text == Some(key) {
+        return argument.child_by_field_name("value");
+      }
+    } else if key == "value" && argument.kind() != "comment" {
+      return Some(argument);
+    }
+  }
+  None
+}
+
 fn detect_indentation(
   ts_file: &TSFile,
   declaration_node: Node,
@@ -215,6 +275,35 @@ fn detect_indentation(
   "  ".to_string()
 }
 
+/// Drops the annotations repeating an earlier one of the same declaration with the same name and
+/// arguments, whitespace aside, together with the whitespace separating them from the next token.
+///
+/// # Returns
+/// The declaration text and the ranges of the annotations it keeps, relative to that text
+fn collapse_duplicate_annotations(
+  text: &str,
+  annotation_ranges: &[(usize, usize)],
+) -> (String, Vec<(usize, usize)>) {
+  let mut seen_annotations: Vec<String> = Vec::new();
+  let mut collapsed_text = String::with_capacity(text.len());
+  let mut kept_ranges = Vec::new();
+  let mut cursor = 0;
+  for &(start, end) in annotation_ranges {
+    let normalized: String = text[start..end].split_whitespace().collect();
+    if !seen_annotations.contains(&normalized) {
+      seen_annotations.push(normalized);
+      let shift = cursor - collapsed_text.len();
+      kept_ranges.push((start - shift, end - shift));
+      continue;
+    }
+    collapsed_text.push_str(&text[cursor..start]);
+    let trailing_whitespace = text[end..].len() - text[end..].trim_start().len();
+    cursor = end + trailing_whitespace;
+  }
+  collapsed_text.push_str(&text[cursor..]);
+  (collapsed_text, kept_ranges)
+}
+
 pub fn add_annotation<'a>(
   ts_file: &'a mut TSFile,
   declaration_byte_position: usize,
@@ -249,13 +338,19 @@ pub fn add_annotation<'a>(
       current_node = parent;
       node_kind = current_node.kind();
     }
-    // If we found an annotation, navigate up to find the containing class_declaration
+    // If we found an annotation, navigate up to find the declaration it belongs to
     if matches!(node_kind, "annotation" | "marker_annotation") {
-      // The annotation is likely a child of modifiers, which is a child of class_declaration
-      // So we need to go up the parent chain to find class_declaration
+      // The annotation is likely a child of modifiers, which is a child of the declaration
+      // So we need to go up the parent chain to find the closest declaration
       let mut current_ancestor = Some(current_node);
       while let Some(ancestor) = current_ancestor {
-        if ancestor.kind() == "class_declaration" {
+        if matches!(
+          ancestor.kind(),
+          "class_declaration"
+            | "field_declaration"
+            | "method_declaration"
+            | "interface_declaration"
+        ) {
           current_node = ancestor;
           node_kind = current_node.kind();
           break;
@@ -271,7 +366,17 @@ pub fn add_annotation<'a>(
     }
     declaration_node = Some(current_node);
     let declaration_node = declaration_node.unwrap();
-    let all_annotations = get_all_annotation_nodes(ts_file, declaration_node);
+    // Only keep the annotations of the declaration itself, nested ones such as the `@Column` in
+    // `@AttributeOverride(column = @Column(...))` must not be used as insertion anchors
+    let all_annotations: Vec<Node> = get_all_annotation_nodes(ts_file, declaration_node)
+      .into_iter()
+      .filter(|annotation| {
+        annotation.parent().is_some_and(|parent| {
+          parent.kind() == "modifiers"
+            && parent.parent().is_some_and(|decl| decl.id() == declaration_node.id())
+        })
+      })
+      .collect();
     let current_text = ts_file.get_text_from_node(&declaration_node);
     current_text.as_ref()?;
     let current_text = current_text.unwrap().to_string();
@@ -294,63 +399,47 @@ pub fn add_annotation<'a>(
       indentation,
     )
   };
-  let mut annotation_insertion_point = AnnotationInsertionPoint::new();
-  annotation_insertion_point.position = insertion_position.clone();
-  match insertion_position {
-    AnnotationInsertionPosition::BeforeFirstAnnotation => {
-      if !all_annotations.is_empty() {
-        annotation_insertion_point.break_line_after = true;
-        annotation_insertion_point.insert_byte = all_annotations[0].start_byte();
-      } else {
-        annotation_insertion_point.break_line_after = true;
-        annotation_insertion_point.insert_byte = declaration_start_byte;
-      }
-    }
-    AnnotationInsertionPosition::AboveScopeDeclaration => {
-      if all_annotations.is_empty() {
-        annotation_insertion_point.break_line_after = true;
-        annotation_insertion_point.insert_byte = declaration_start_byte;
-      } else {
-        annotation_insertion_point.break_line_before = true;
-        annotation_insertion_point.insert_byte = all_annotations.last()?.end_byte();
-      }
-    }
-  }
+  // Collapse exact duplicates before writing, so repeated edits can't stack the same annotation
+  let annotation_ranges: Vec<(usize, usize)> = all_annotations
+    .iter()
+    .map(|annotation| {
+      (
+        annotation.start_byte() - declaration_start_byte,
+        annotation.end_byte() - declaration_start_byte,
+      )
+    })
+    .collect();
+  let (current_text, annotation_ranges) =
+    collapse_duplicate_annotations(&current_text, &annotation_ranges);
+  let normalized_annotation: String = annotation_text.split_whitespace().collect();
+  let is_already_present = annotation_ranges.iter().any(|&(start, end)| {
+    current_text[start..end].split_whitespace().collect::<String>() == normalized_annotation
+  });
   let new_content = match insertion_position {
+    _ if is_already_present => current_text,
     AnnotationInsertionPosition::BeforeFirstAnnotation => {
-      if !all_annotations.is_empty() {
+      if let Some(&(first_start, _)) = annotation_ranges.first() {
         // Insert before first annotation
-        let first_annotation = &all_annotations[0];
-        let relative_pos = first_annotation.start_byte() - declaration_start_byte;
+        let relative_pos = first_start;
         let before = &current_text[..relative_pos];
         let after = &current_text[relative_pos..];
-
-        // If 'before' is empty (meaning first annotation has no indentation),
-        // use the detected indentation from field context
-        if before.trim().is_empty() && !indentation.is_empty() {
-          format!("{}{}\n{}", indentation, annotation_text, after)
-        } else if before.is_empty() {
-          // Fallback: use field-level indentation (2 spaces for fields)
-          format!("  {}\n{}", annotation_text, after)
-        } else {
-          // Use the existing indentation pattern
-          format!("{}{}\n{}", before, annotation_text, after)
-        }
+        // The line indentation precedes the replaced range, so it is repeated for the moved
+        // first annotation instead
+        format!("{}{}\n{}{}", before, annotation_text, indentation, after)
       } else {
         // No annotations exist, insert at beginning
         format!("{}{}\n{}", indentation, annotation_text, current_text)
       }
     }
     AnnotationInsertionPosition::AboveScopeDeclaration => {
-      if all_annotations.is_empty() {
+      if annotation_ranges.is_empty() {
         // No annotations exist, insert at beginning
         // Since we're now including the leading whitespace in the replacement range,
         // we need to format the content with proper indentation for both annotation and field
         format!("{}{}\n{}{}", indentation, annotation_text, indentation, current_text)
       } else {
         // Insert after last annotation
-        let last_annotation = all_annotations.last()?;
-        let relative_pos = last_annotation.end_byte() - declaration_start_byte;
+        let &(_, relative_pos) = annotation_ranges.last()?;
         let before = &current_text[..relative_pos];
         let after = &current_text[relative_pos..];
         format!("{}\n{}{}{}", before, indentation, annotation_text, after)
@@ -371,7 +460,6 @@ pub fn add_annotation_argument<'a>(
   }
   // Collect all necessary information before any mutable operations
   let (
-    annotation_start_byte,
     annotation_end_byte,
     node_kind,
     current_text,
@@ -405,7 +493,6 @@ pub fn add_annotation_argument<'a>(
       .map(|n| n.end_byte() - annotation_start + name_offset);
     let existing_arguments = get_annotation_argument_pair_nodes(ts_file, annotation_node);
     (
-      annotation_node.start_byte(),
       annotation_node.end_byte(),
       node_kind,
       current_text,
@@ -435,7 +522,7 @@ pub fn add_annotation_argument<'a>(
       // Add argument to existing arguments
       // @Column(name = "test") -> @Column(name = "test", key = value)
       let last_argument = existing_arguments.last()?;
-      let insert_pos = last_argument.end_byte() - annotation_start_byte;
+      let insert_pos = last_argument.end_byte() - actual_start_byte;
       let before = &current_text[..insert_pos];
       let after = &current_text[insert_pos..];
       format!("{}, {}{}", before, argument_pair, after)
@@ -444,6 +531,104 @@ pub fn add_annotation_argument<'a>(
   ts_file.replace_text_by_byte_range(actual_start_byte, annotation_end_byte, &new_content)
 }
 
+/// Sets `key = value` on an annotation, replacing the current value when the key is already
+/// present and adding the argument otherwise. Other arguments are left untouched, a single unnamed
+/// element (`@Table("users")`) is spelled out as `value = ...` before another key is added.
+pub fn set_annotation_argument(
+  ts_file: &mut TSFile,
+  annotation_byte_position: usize,
+  key: &str,
+  value: &str,
+) -> bool {
+  if ts_file.tree.is_none() || key.trim().is_empty() || value.trim().is_empty() {
+    return false;
+  }
+  let (annotation_start_byte, existing_value_range, unnamed_value) = {
+    let Some(mut annotation_node) =
+      ts_file.get_named_node_at_byte_position(annotation_byte_position)
+    else {
+      return false;
+    };
+    while !matches!(annotation_node.kind(), "annotation" | "marker_annotation") {
+      match annotation_node.parent() {
+        Some(parent) => annotation_node = parent,
+        None => return false,
+      }
+    }
+    let existing_value_range =
+      find_direct_annotation_value_node_by_key(ts_file, annotation_node, key)
+        .map(|value_node| (value_node.start_byte(), value_node.end_byte()));
+    let unnamed_value = find_direct_annotation_value_node_by_key(ts_file, annotation_node, "value")
+      .filter(|value_node| {
+        value_node.parent().is_some_and(|parent| parent.kind() == "annotation_argument_list")
+      })
+      .and_then(|value_node| {
+        let text = ts_file.get_text_from_node(&value_node)?;
+        Some((value_node.start_byte(), value_node.end_byte(), text.to_string()))
+      });
+    (annotation_node.start_byte(), existing_value_range, unnamed_value)
+  };
+  match (existing_value_range, unnamed_value) {
+    (Some((start_byte, end_byte)), _) => {
+      ts_file.replace_text_by_range(start_byte, end_byte, value);
+      true
+    }
+    (None, Some((start_byte, end_byte, unnamed_value_text))) => {
+      let named_value = format!("value = {}", unnamed_value_text);
+      ts_file.replace_text_by_range(start_byte, end_byte, &named_value);
+      add_annotation_argument(ts_file, annotation_start_byte, key, value).is_some()
+    }
+    (None, None) => add_annotation_argument(ts_file, annotation_start_byte, key, value).is_some(),
+  }
+}
+
+/// Removes the `key = value` argument of an annotation together with its separating comma. An
+/// annotation left without arguments becomes a marker annotation (`@Column(unique = true)` ->
+/// `@Column`).
+///
+/// # Returns
+/// `false` when the annotation has no such argument
+pub fn remove_annotation_argument(
+  ts_file: &mut TSFile,
+  annotation_byte_position: usize,
+  key: &str,
+) -> bool {
+  if ts_file.tree.is_none() {
+    return false;
+  }
+  let (start_byte, end_byte) = {
+    let Some(mut annotation_node) =
+      ts_file.get_named_node_at_byte_position(annotation_byte_position)
+    else {
+      return false;
+    };
+    while !matches!(annotation_node.kind(), "annotation" | "marker_annotation") {
+      match annotation_node.parent() {
+        Some(parent) => annotation_node = parent,
+        None => return false,
+      }
+    }
+    let Some(arguments_node) = annotation_node.child_by_field_name("arguments") else {
+      return false;
+    };
+    let mut cursor = arguments_node.walk();
+    let Some(pair_node) = arguments_node.named_children(&mut cursor).find(|child| {
+      child.kind() == "element_value_pair"
+        && child.child_by_field_name("key").and_then(|n| ts_file.get_text_from_node(&n))
+          == Some(key)
+    }) else {
+      return false;
+    };
+    match (pair_node.prev_named_sibling(), pair_node.next_named_sibling()) {
+      (_, Some(next)) => (pair_node.start_byte(), next.start_byte()),
+      (Some(previous), None) => (previous.end_byte(), pair_node.end_byte()),
+      (None, None) => (arguments_node.start_byte(), arguments_node.end_byte()),
+    }
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, "");
+  true
+}
+
 pub fn add_annotation_single_value<'a>(
   ts_file: &'a mut TSFile,
   annotation_byte_position: usize,
@@ -509,3 +694,117 @@ pub fn add_annotation_single_value<'a>(
   };
   ts_file.replace_text_by_byte_range(annotation_start_byte, annotation_end_byte, &new_content)
 }
+
+pub fn remove_annotation(ts_file: &mut TSFile, annotation_byte_position: usize) -> bool {
+  if ts_file.tree.is_none() {
+    return false;
+  }
+  let (start_byte, end_byte) = {
+    let Some(mut annotation_node) =
+      ts_file.get_named_node_at_byte_position(annotation_byte_position)
+    else {
+      return false;
+    };
+    // Navigate up in case the position points inside the annotation (e.g. its name)
+    while !matches!(annotation_node.kind(), "annotation" | "marker_annotation") {
+      match annotation_node.parent() {
+        Some(parent) => annotation_node = parent,
+        None => return false,
+      }
+    }
+    let source_text = &ts_file.source_code;
+    let annotation_start = annotation_node.start_byte();
+    let annotation_end = annotation_node.end_byte();
+    let line_start = source_text[..annotation_start].rfind('\n').map(|pos| pos + 1).unwrap_or(0);
+    let line_end = source_text[annotation_end..]
+      .find('\n')
+      .map(|pos| annotation_end + pos)
+      .unwrap_or(source_text.len());
+    let leading_text = &source_text[line_start..annotation_start];
+    let trailing_text = &source_text[annotation_end..line_end];
+    if leading_text.trim().is_empty() && trailing_text.trim().is_empty() {
+      // The annotation is alone on its line, remove the whole line
+      (line_start, (line_end + 1).min(source_text.len()))
+    } else {
+      // Inline annotation, remove it together with the whitespace that follows it
+      let trailing_whitespace = trailing_text.len() - trailing_text.trim_start().len();
+      (annotation_start, annotation_end + trailing_whitespace)
+    }
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, "");
+  true
+}
+
+fn get_annotation_value_response(ts_file: &TSFile, value_node: Node) -> AnnotationValueResponse {
+  match value_node.kind() {
+    "annotation" | "marker_annotation" => match get_annotation_response(ts_file, value_node) {
+      Some(annotation) => AnnotationValueResponse::Annotation(Box::new(annotation)),
+      None => AnnotationValueResponse::Expression {
+        text: ts_file.get_text_from_node(&value_node).unwrap_or_default().to_string(),
+      },
+    },
+    "element_value_array_initializer" => {
+      let mut cursor = value_node.walk();
+      let elements = value_node
+        .named_children(&mut cursor)
+        .filter(|child| !matches!(child.kind(), "line_comment" | "block_comment"))
+        .map(|child| get_annotation_value_response(ts_file, child))
+        .collect();
+      AnnotationValueResponse::Array { elements }
+    }
+    _ => AnnotationValueResponse::Expression {
+      text: ts_file.get_text_from_node(&value_node).unwrap_or_default().to_string(),
+    },
+  }
+}
+
+/// Serializes an annotation generically: its name resolved through the imports, its form and its
+/// arguments, including nested annotations and arrays.
+pub fn get_annotation_response(
+  ts_file: &TSFile,
+  annotation_node: Node,
+) -> Option<AnnotationResponse> {
+  if !matches!(annotation_node.kind(), "annotation" | "marker_annotation") {
+    return None;
+  }
+  let name_node = annotation_node.child_by_field_name("name")?;
+  let name = ts_file.get_text_from_node(&name_node)?.to_string();
+  let fully_qualified_name = resolve_fully_qualified_type_name(ts_file, &name);
+  let Some(arguments_node) = annotation_node.child_by_field_name("arguments") else {
+    return Some(AnnotationResponse {
+      name,
+      fully_qualified_name,
+      form: AnnotationForm::Marker,
+      arguments: Vec::new(),
+    });
+  };
+  let mut cursor = arguments_node.walk();
+  let argument_nodes: Vec<Node> = arguments_node
+    .named_children(&mut cursor)
+    .filter(|child| !matches!(child.kind(), "line_comment" | "block_comment"))
+    .collect();
+  let mut form = AnnotationForm::Normal;
+  let mut arguments = Vec::new();
+  for argument_node in argument_nodes {
+    if argument_node.kind() == "element_value_pair" {
+      let key_node = argument_node.child_by_field_name("key")?;
+      let value_node = argument_node.child_by_field_name("value")?;
+      arguments.push(AnnotationArgumentResponse {
+        key: ts_file.get_text_from_node(&key_node)?.to_string(),
+        value: get_annotation_value_response(ts_file, value_node),
+      });
+    } else {
+      // `@Table("users")` is shorthand for `@Table(value = "users")`
+      form = AnnotationForm::SingleValue;
+      arguments.push(AnnotationArgumentResponse {
+        key: "value".to_string(),
+        value: get_annotation_value_response(ts_file, argument_node),
+      });
+    }
+  }
+  // `@Table()` has no arguments to report, it is equivalent to the marker form
+  if arguments.is_empty() {
+    form = AnnotationForm::Marker;
+  }
+  Some(AnnotationResponse { name, fully_qualified_name, form, arguments })
+}
@@ -0,0 +1,262 @@
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, has_modifier,
+};
+use crate::commands::java::treesitter::services::constructor_declaration_service::{
+  get_all_constructor_declaration_nodes, get_constructor_parameter_nodes,
+  get_parameter_type_and_name,
+};
+use crate::commands::java::treesitter::services::method_declaration_service::{
+  get_method_declaration_name, get_own_method_declaration_nodes,
+};
+use crate::common::ts_file::TSFile;
+use tree_sitter::Node;
+
+/// Member of a hand-written builder: the nested `Builder` class, the `builder()` factory or the
+/// constructor taking the builder.
+pub struct BuilderMember {
+  /// Member as shown to the user, e.g. `Builder`, `builder()` or `Book(Builder)`
+  pub name: String,
+  pub start_byte: usize,
+  /// Parts of the member doing more than the code Lombok generates, e.g. a validating `build()`
+  pub custom_logic: Vec<String>,
+}
+
+fn get_body_statements(declaration_node: Node) -> Vec<Node> {
+  let Some(body_node) = declaration_node.child_by_field_name("body") else {
+    return Vec::new();
+  };
+  let mut cursor = body_node.walk();
+  body_node.named_children(&mut cursor).filter(|child| !child.is_extra()).collect()
+}
+
+fn get_parameters<'a>(ts_file: &'a TSFile, declaration_node: Node<'a>) -> Vec<(&'a str, &'a str)> {
+  let Some(parameters_node) = declaration_node.child_by_field_name("parameters") else {
+    return Vec::new();
+  };
+  let mut cursor = parameters_node.walk();
+  parameters_node
+    .named_children(&mut cursor)
+    .filter(|child| matches!(child.kind(), "formal_parameter" | "spread_parameter"))
+    .filter_map(|parameter| get_parameter_type_and_name(ts_file, parameter))
+    .collect()
+}
+
+/// Field assigned by `this.name = value;` or `name = value;`, along with the assigned value.
+fn get_field_assignment<'a>(
+  ts_file: &'a TSFile,
+  statement: Node<'a>,
+) -> Option<(&'a str, Node<'a>)> {
+  let assignment =
+    statement.named_child(0).filter(|_| statement.kind() == "expression_statement")?;
+  if assignment.kind() != "assignment_expression" {
+    return None;
+  }
+  let left = assignment.child_by_field_name("left")?;
+  let field_name_node = match left.kind() {
+    "identifier" => left,
+    "field_access" => {
+      let object = left.child_by_field_name("object")?;
+      (object.kind() == "this").then_some(left.child_by_field_name("field")?)?
+    }
+    _ => return None,
+  };
+  Some((ts_file.get_text_from_node(&field_name_node)?, assignment.child_by_field_name("right")?))
+}
+
+/// Type instantiated by a `return new Type(...);` statement, along with its arguments count.
+fn get_returned_instance_type<'a>(
+  ts_file: &'a TSFile,
+  statement: Node<'a>,
+) -> Option<(&'a str, usize)> {
+  let creation = statement.named_child(0).filter(|_| statement.kind() == "return_statement")?;
+  if creation.kind() != "object_creation_expression" {
+    return None;
+  }
+  let type_name = ts_file.get_text_from_node(&creation.child_by_field_name("type")?)?;
+  let arguments_count = creation.child_by_field_name("arguments")?.named_child_count();
+  Some((type_name, arguments_count))
+}
+
+/// A builder setter only assigns its parameter to the field of the same name and returns `this`.
+fn is_plain_builder_setter(ts_file: &TSFile, method_node: Node) -> bool {
+  let Some(method_name) = get_method_declaration_name(ts_file, method_node) else {
+    return false;
+  };
+  let parameters = get_parameters(ts_file, method_node);
+  let [(_, parameter_name)] = parameters.as_slice() else {
+    return false;
+  };
+  let statements = get_body_statements(method_node);
+  let [assignment, return_statement] = statements.as_slice() else {
+    return false;
+  };
+  let assigns_parameter =
+    get_field_assignment(ts_file, *assignment).is_some_and(|(field, value)| {
+      field == method_name && ts_file.get_text_from_node(&value) == Some(*parameter_name)
+    });
+  assigns_parameter
+    && return_statement.kind() == "return_statement"
+    && return_statement.named_child(0).is_some_and(|value| value.kind() == "this")
+}
+
+/// `build()` only returns a new instance of the built class.
+fn is_plain_build_method(ts_file: &TSFile, method_node: Node, class_name: &str) -> bool {
+  let statements = get_body_statements(method_node);
+  let [statement] = statements.as_slice() else {
+    return false;
+  };
+  get_parameters(ts_file, method_node).is_empty()
+    && get_returned_instance_type(ts_file, *statement)
+      .is_some_and(|(type_name, _)| type_name == class_name)
+}
+
+fn get_builder_class_custom_logic(
+  ts_file: &TSFile,
+  builder_node: Node,
+  class_name: &str,
+) -> Vec<String> {
+  let Some(body_node) = builder_node.child_by_field_name("body") else {
+    return Vec::new();
+  };
+  let mut custom_logic = Vec::new();
+  let mut cursor = body_node.walk();
+  for member in body_node.named_children(&mut cursor).filter(|child| !child.is_extra()) {
+    match member.kind() {
+      "field_declaration" => {
+        let mut declarator_cursor = member.walk();
+        for declarator in member
+          .named_children(&mut declarator_cursor)
+          .filter(|child| child.kind() == "variable_declarator")
+        {
+          // Defaults need `@Builder.Default` on the class field to survive
+          if declarator.child_by_field_name("value").is_some()
+            && let Some(name) = declarator
+              .child_by_field_name("name")
+              .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+          {
+            custom_logic.push(format!("Builder.{}", name));
+          }
+        }
+      }
+      "method_declaration" => {
+        let method_name = get_method_declaration_name(ts_file, member).unwrap_or_default();
+        let is_plain = match method_name {
+          "build" => is_plain_build_method(ts_file, member, class_name),
+          _ => is_plain_builder_setter(ts_file, member),
+        };
+        if !is_plain {
+          custom_logic.push(format!("Builder.{}()", method_name));
+        }
+      }
+      "constructor_declaration" => {
+        if !get_parameters(ts_file, member).is_empty() || !get_body_statements(member).is_empty() {
+          custom_logic.push("Builder()".to_string());
+        }
+      }
+      _ => custom_logic.push(format!("Builder {}", member.kind().replace('_', " "))),
+    }
+  }
+  custom_logic
+}
+
+/// The constructor taking the builder only copies each builder field to the field of the same
+/// name (`this.title = builder.title;`).
+fn is_plain_builder_constructor(
+  ts_file: &TSFile,
+  constructor_node: Node,
+  parameter_name: &str,
+) -> bool {
+  get_body_statements(constructor_node).iter().all(|statement| {
+    get_field_assignment(ts_file, *statement).is_some_and(|(field_name, value)| {
+      value.kind() == "field_access"
+        && value
+          .child_by_field_name("object")
+          .and_then(|object| ts_file.get_text_from_node(&object))
+          == Some(parameter_name)
+        && value.child_by_field_name("field").and_then(|field| ts_file.get_text_from_node(&field))
+          == Some(field_name)
+    })
+  })
+}
+
+/// Finds the hand-written builder scaffolding of a class: the static nested `Builder` class, the
+/// static `builder()` factory returning it and the constructor taking it, in source order.
+///
+/// # Returns
+/// The builder members, empty when the class has no static nested `Builder` class
+pub fn find_builder_members(ts_file: &TSFile, class_declaration_node: Node) -> Vec<BuilderMember> {
+  let Some(class_name) = get_class_declaration_name_node(ts_file, class_declaration_node)
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+  else {
+    return Vec::new();
+  };
+  let Some(body_node) = class_declaration_node.child_by_field_name("body") else {
+    return Vec::new();
+  };
+  let mut cursor = body_node.walk();
+  let Some(builder_node) = body_node.named_children(&mut cursor).find(|child| {
+    child.kind() == "class_declaration"
+      && child
+        .child_by_field_name("name")
+        .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+        == Some("Builder")
+      && has_modifier(*child, "static")
+  }) else {
+    return Vec::new();
+  };
+  let mut members = vec![BuilderMember {
+    name: "Builder".to_string(),
+    start_byte: builder_node.start_byte(),
+    custom_logic: get_builder_class_custom_logic(ts_file, builder_node, class_name),
+  }];
+  let builder_type_names = ["Builder".to_string(), format!("{}.Builder", class_name)];
+  let is_builder_type = |type_name: Option<&str>| {
+    type_name.is_some_and(|name| builder_type_names.iter().any(|b| b == name))
+  };
+  for method_node in get_own_method_declaration_nodes(ts_file, class_declaration_node) {
+    let return_type = method_node
+      .child_by_field_name("type")
+      .and_then(|type_node| ts_file.get_text_from_node(&type_node));
+    if get_method_declaration_name(ts_file, method_node) != Some("builder")
+      || !has_modifier(method_node, "static")
+      || !get_parameters(ts_file, method_node).is_empty()
+      || !is_builder_type(return_type)
+    {
+      continue;
+    }
+    let statements = get_body_statements(method_node);
+    let is_plain = matches!(
+      statements.as_slice(),
+      [statement] if get_returned_instance_type(ts_file, *statement)
+        .is_some_and(|(type_name, arguments_count)| {
+          is_builder_type(Some(type_name)) && arguments_count == 0
+        })
+    );
+    members.push(BuilderMember {
+      name: "builder()".to_string(),
+      start_byte: method_node.start_byte(),
+      custom_logic: if is_plain { Vec::new() } else { vec!["builder()".to_string()] },
+    });
+  }
+  for constructor_node in get_all_constructor_declaration_nodes(ts_file, class_declaration_node) {
+    let parameters: Vec<(&str, &str)> = get_constructor_parameter_nodes(ts_file, constructor_node)
+      .into_iter()
+      .filter_map(|parameter| get_parameter_type_and_name(ts_file, parameter))
+      .collect();
+    let [(parameter_type, parameter_name)] = parameters.as_slice() else {
+      continue;
+    };
+    if !is_builder_type(Some(parameter_type)) {
+      continue;
+    }
+    let name = format!("{}({})", class_name, parameter_type);
+    let is_plain = is_plain_builder_constructor(ts_file, constructor_node, parameter_name);
+    members.push(BuilderMember {
+      custom_logic: if is_plain { Vec::new() } else { vec![name.clone()] },
+      name,
+      start_byte: constructor_node.start_byte(),
+    });
+  }
+  members.sort_by_key(|member| member.start_byte);
+  members
+}
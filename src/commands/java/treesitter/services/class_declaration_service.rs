@@ -1,5 +1,7 @@
 #![allow(dead_code)]
 
+use crate::commands::java::treesitter::services::import_declaration_service::add_import;
+use crate::commands::java::treesitter::types::import_types::ImportInsertionPosition;
 use crate::common::ts_file::TSFile;
 use std::collections::HashMap;
 use tree_sitter::Node;
@@ -47,6 +49,11 @@ pub fn get_public_class_node<'a>(ts_file: &'a TSFile) -> Option<Node<'a>> {
   }
 }
 
+/// Same as `get_public_class_node`, failing with a message for services to return as is.
+pub fn get_required_public_class_node(ts_file: &TSFile) -> Result<Node<'_>, String> {
+  get_public_class_node(ts_file).ok_or_else(|| "Unable to get public class node".to_string())
+}
+
 pub fn get_all_class_declaration_nodes<'a>(ts_file: &'a TSFile) -> Vec<HashMap<String, Node<'a>>> {
   if ts_file.tree.is_none() {
     return Vec::new();
@@ -90,7 +97,11 @@ pub fn get_class_superclass_name_node<'a>(
         (class_declaration
           name: (identifier) @className
           (superclass
-            (type_identifier) @superclassName
+            [
+              (type_identifier) @superclassName
+              (generic_type
+                (type_identifier) @superclassName)
+            ]
           )?
         )
     "#;
@@ -102,3 +113,364 @@ pub fn get_class_superclass_name_node<'a>(
     .ok()?
     .first_node()
 }
+
+pub fn get_class_name(ts_file: &TSFile, class_node: Node) -> Option<String> {
+  get_class_declaration_name_node(ts_file, class_node)
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node))
+    .map(|name| name.to_string())
+}
+
+/// Name of the class's superclass as written, without its type arguments.
+pub fn get_superclass_name(ts_file: &TSFile, class_node: Node) -> Option<String> {
+  get_class_superclass_name_node(ts_file, class_node)
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .map(|name| name.to_string())
+}
+
+/// Indentation used for the members of a class: the one of its first member, otherwise one level
+/// (two spaces) deeper than the class declaration line.
+pub fn get_class_member_indentation(ts_file: &TSFile, class_declaration_node: Node) -> String {
+  let first_member = class_declaration_node.child_by_field_name("body").and_then(|body| {
+    let mut cursor = body.walk();
+    body
+      .named_children(&mut cursor)
+      .find(|child| child.start_position().row > body.start_position().row)
+  });
+  match first_member {
+    Some(member_node) => ts_file.get_line_indentation(member_node.start_byte()),
+    None => format!("{}  ", ts_file.get_line_indentation(class_declaration_node.start_byte())),
+  }
+}
+
+/// Start of the member including the comments (e.g. Javadoc) directly attached above it.
+pub fn get_member_start_byte(member_node: Node) -> usize {
+  let mut start_byte = member_node.start_byte();
+  let mut current = member_node;
+  while let Some(previous) = current.prev_named_sibling() {
+    if !matches!(previous.kind(), "block_comment" | "line_comment")
+      || previous.end_position().row + 1 < current.start_position().row
+    {
+      break;
+    }
+    start_byte = previous.start_byte();
+    current = previous;
+  }
+  start_byte
+}
+
+/// Text of the member from its attached comments to its end. Continuation lines lose the member
+/// indentation, so the text can be indented again wherever it is inserted.
+pub fn get_member_text(ts_file: &TSFile, member_node: Node) -> Option<String> {
+  let start_byte = get_member_start_byte(member_node);
+  let indentation = ts_file.get_line_indentation(start_byte);
+  let text = ts_file.source_code.get(start_byte..member_node.end_byte())?;
+  let lines: Vec<&str> = text
+    .lines()
+    .enumerate()
+    .map(|(index, line)| match index {
+      0 => line,
+      _ => line.strip_prefix(indentation.as_str()).unwrap_or(line.trim_start()),
+    })
+    .collect();
+  Some(lines.join("\n"))
+}
+
+/// Indents the continuation lines of a member text returned by `get_member_text`.
+pub fn indent_member_text(member_text: &str, indentation: &str) -> String {
+  member_text
+    .lines()
+    .enumerate()
+    .map(|(index, line)| match index == 0 || line.is_empty() {
+      true => line.to_string(),
+      false => format!("{}{}", indentation, line),
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Widens the removal range of the last member of a type body over the blank lines around it, so
+/// the closing brace directly follows the previous member once it's removed.
+pub fn extend_last_member_removal_range(
+  source: &str,
+  start_byte: usize,
+  end_byte: usize,
+) -> (usize, usize) {
+  let rest = &source[end_byte..];
+  let rest_trimmed = rest.trim_start();
+  if !rest_trimmed.starts_with('}') {
+    return (start_byte, end_byte);
+  }
+  let whitespace_after = &rest[..rest.len() - rest_trimmed.len()];
+  let end_byte = end_byte + whitespace_after.rfind('\n').map_or(0, |pos| pos + 1);
+  let previous_end_byte = source[..start_byte].trim_end().len();
+  let start_byte = match source[previous_end_byte..start_byte].find('\n') {
+    Some(pos) => previous_end_byte + pos + 1,
+    None => start_byte,
+  };
+  (start_byte, end_byte)
+}
+
+/// Byte range covering the whole lines of the member and its attached comments, plus one of the
+/// blank lines around it when it sits between two blank lines, or all of them when it is the last
+/// member.
+pub fn get_member_removal_range(ts_file: &TSFile, member_node: Node) -> (usize, usize) {
+  let source = &ts_file.source_code;
+  let member_start_byte = get_member_start_byte(member_node);
+  let line_start = source[..member_start_byte].rfind('\n').map_or(0, |pos| pos + 1);
+  let start_byte = match source[line_start..member_start_byte].trim().is_empty() {
+    true => line_start,
+    false => member_start_byte,
+  };
+  let end_byte = match source[member_node.end_byte()..].find('\n') {
+    Some(offset)
+      if source[member_node.end_byte()..member_node.end_byte() + offset].trim().is_empty() =>
+    {
+      member_node.end_byte() + offset + 1
+    }
+    _ => member_node.end_byte(),
+  };
+  if source[end_byte..].trim_start().starts_with('}') {
+    return extend_last_member_removal_range(source, start_byte, end_byte);
+  }
+  let is_blank_before = source[..start_byte].trim_end_matches([' ', '\t']).ends_with("\n\n");
+  let next_line_end = source[end_byte..].find('\n').map(|offset| end_byte + offset + 1);
+  match next_line_end {
+    Some(next_line_end) if is_blank_before && source[end_byte..next_line_end].trim().is_empty() => {
+      (start_byte, next_line_end)
+    }
+    _ => (start_byte, end_byte),
+  }
+}
+
+/// Removes the type member (field, method, constructor, nested type...) starting at
+/// `member_byte_position`, together with its attached comments.
+pub fn remove_member(ts_file: &mut TSFile, member_byte_position: usize) -> bool {
+  if ts_file.tree.is_none() {
+    return false;
+  }
+  let (start_byte, end_byte) = {
+    let Some(mut node) = ts_file.get_named_node_at_byte_position(member_byte_position) else {
+      return false;
+    };
+    loop {
+      match node.parent() {
+        Some(parent) if parent.kind() == "class_body" => break,
+        Some(parent) => node = parent,
+        None => return false,
+      }
+    }
+    let (start_byte, end_byte) = get_member_removal_range(ts_file, node);
+    let source = &ts_file.source_code;
+    let is_last = source[end_byte..].trim_start().starts_with('}');
+    let is_first = source[..start_byte].trim_end().ends_with('{');
+    let blank_line_end = source[end_byte..]
+      .find('\n')
+      .filter(|offset| source[end_byte..end_byte + offset].trim().is_empty())
+      .map(|offset| end_byte + offset + 1);
+    match blank_line_end.filter(|_| is_first && !is_last) {
+      // Drop the blank line separating the first member from the next one
+      Some(blank_line_end) => (start_byte, blank_line_end),
+      None => (start_byte, end_byte),
+    }
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, "");
+  true
+}
+
+/// Whether a class member or type declaration carries the modifier keyword (`static`,
+/// `final`, `private`, ...).
+pub fn has_modifier(declaration_node: Node, modifier: &str) -> bool {
+  let mut cursor = declaration_node.walk();
+  declaration_node.children(&mut cursor).filter(|child| child.kind() == "modifiers").any(
+    |modifiers| {
+      let mut modifiers_cursor = modifiers.walk();
+      modifiers.children(&mut modifiers_cursor).any(|child| child.kind() == modifier)
+    },
+  )
+}
+
+const TYPE_DECLARATION_KINDS: [(&str, &str); 5] = [
+  ("class_declaration", "class"),
+  ("interface_declaration", "interface"),
+  ("enum_declaration", "enum"),
+  ("record_declaration", "record"),
+  ("annotation_type_declaration", "annotation"),
+];
+
+/// Keyword of a type declaration node (`class`, `enum`...), `None` for other nodes.
+pub fn get_type_declaration_kind(node: Node) -> Option<&'static str> {
+  TYPE_DECLARATION_KINDS.iter().find(|(kind, _)| *kind == node.kind()).map(|(_, name)| *name)
+}
+
+pub fn get_type_declaration_name<'a>(ts_file: &'a TSFile, node: Node) -> Option<&'a str> {
+  let name_node = node.child_by_field_name("name")?;
+  ts_file.get_text_from_node(&name_node)
+}
+
+fn is_public_type_declaration(ts_file: &TSFile, node: Node) -> bool {
+  let mut cursor = node.walk();
+  let modifiers_node = node.children(&mut cursor).find(|child| child.kind() == "modifiers");
+  modifiers_node
+    .and_then(|modifiers| ts_file.get_text_from_node(&modifiers))
+    .is_some_and(|text| text.split_whitespace().any(|keyword| keyword == "public"))
+}
+
+/// Finds the type declaration by name at any nesting level, or the file's public top-level type
+/// (falling back to the first one) when no name is given.
+pub fn find_type_declaration_node<'a>(
+  ts_file: &'a TSFile,
+  type_name: Option<&str>,
+) -> Option<Node<'a>> {
+  let root_node = ts_file.tree.as_ref()?.root_node();
+  match type_name {
+    Some(type_name) => {
+      let mut pending = vec![root_node];
+      while let Some(node) = pending.pop() {
+        if get_type_declaration_kind(node).is_some()
+          && get_type_declaration_name(ts_file, node) == Some(type_name)
+        {
+          return Some(node);
+        }
+        let mut cursor = node.walk();
+        let mut children: Vec<Node> = node.named_children(&mut cursor).collect();
+        children.reverse();
+        pending.extend(children);
+      }
+      None
+    }
+    None => {
+      let mut cursor = root_node.walk();
+      let top_level_types: Vec<Node> = root_node
+        .named_children(&mut cursor)
+        .filter(|child| get_type_declaration_kind(*child).is_some())
+        .collect();
+      top_level_types
+        .iter()
+        .find(|node| is_public_type_declaration(ts_file, **node))
+        .or(top_level_types.first())
+        .copied()
+    }
+  }
+}
+
+/// Names of the types nested directly in the body of a type declaration.
+pub fn get_nested_type_declaration_names<'a>(
+  ts_file: &'a TSFile,
+  type_declaration_node: Node,
+) -> Vec<&'a str> {
+  let Some(body_node) = type_declaration_node.child_by_field_name("body") else {
+    return Vec::new();
+  };
+  let mut members = Vec::new();
+  let mut cursor = body_node.walk();
+  for child in body_node.named_children(&mut cursor) {
+    // Enum members live in a nested `enum_body_declarations` node
+    if child.kind() == "enum_body_declarations" {
+      let mut declarations_cursor = child.walk();
+      members.extend(child.named_children(&mut declarations_cursor));
+    } else {
+      members.push(child);
+    }
+  }
+  members
+    .into_iter()
+    .filter(|member| get_type_declaration_kind(*member).is_some())
+    .filter_map(|member| get_type_declaration_name(ts_file, member))
+    .collect()
+}
+
+/// Inserts a member at the end of the body of the type declaration at
+/// `type_declaration_byte_position`, indented one level deeper than the type. Enum constants are
+/// terminated with `;` when the enum declares no other members yet.
+///
+/// # Returns
+/// The byte range of the inserted member
+pub fn add_member_at_end_of_type_body(
+  ts_file: &mut TSFile,
+  type_declaration_byte_position: usize,
+  member_text: &str,
+) -> Option<(usize, usize)> {
+  ts_file.tree.as_ref()?;
+  let (insert_byte, closing_brace_byte, text, member_offset) = {
+    let mut node = ts_file.get_named_node_at_byte_position(type_declaration_byte_position)?;
+    while get_type_declaration_kind(node).is_none() {
+      node = node.parent()?;
+    }
+    let body_node = node.child_by_field_name("body")?;
+    let type_indentation = ts_file.get_line_indentation(node.start_byte());
+    let member_indentation = get_class_member_indentation(ts_file, node);
+    let closing_brace_byte = body_node.end_byte() - 1;
+    let insert_byte = ts_file.source_code[..closing_brace_byte].trim_end().len();
+    let mut cursor = body_node.walk();
+    let needs_enum_terminator = body_node.kind() == "enum_body"
+      && !body_node.named_children(&mut cursor).any(|c| c.kind() == "enum_body_declarations")
+      && body_node.named_child_count() > 0;
+    let mut text = String::new();
+    if needs_enum_terminator {
+      text.push(';');
+    }
+    match ts_file.source_code[..insert_byte].ends_with('{') {
+      true => text.push('\n'),
+      false => text.push_str("\n\n"),
+    }
+    text.push_str(&member_indentation);
+    let member_offset = text.len();
+    let indented_member_text = member_text
+      .lines()
+      .map(|line| match line.is_empty() {
+        true => String::new(),
+        false => format!("{}{}", member_indentation, line),
+      })
+      .collect::<Vec<_>>()
+      .join("\n");
+    text.push_str(indented_member_text.trim_start());
+    let member_end_offset = text.len();
+    text.push_str(&format!("\n{}", type_indentation));
+    (insert_byte, closing_brace_byte, text, (member_offset, member_end_offset))
+  };
+  ts_file.replace_text_by_range(insert_byte, closing_brace_byte, &text);
+  Some((insert_byte + member_offset.0, insert_byte + member_offset.1))
+}
+
+/// Makes the public class implement `java.io.Serializable`, declaring its `serialVersionUID`,
+/// unless `Serializable` is already in its `implements` list.
+///
+/// # Returns
+/// Whether `Serializable` was added, `None` when the file has no public class
+pub fn add_serializable_interface(ts_file: &mut TSFile) -> Option<bool> {
+  let (insert_byte, text) = {
+    let class_node = get_public_class_node(ts_file)?;
+    match class_node.child_by_field_name("interfaces") {
+      Some(interfaces_node) => {
+        let type_list_node = interfaces_node.named_child(0)?;
+        let mut cursor = type_list_node.walk();
+        let is_serializable = type_list_node.named_children(&mut cursor).any(|type_node| {
+          matches!(
+            ts_file.get_text_from_node(&type_node),
+            Some("Serializable" | "java.io.Serializable")
+          )
+        });
+        if is_serializable {
+          return Some(false);
+        }
+        (type_list_node.end_byte(), ", Serializable")
+      }
+      None => {
+        let preceding_node = class_node
+          .child_by_field_name("superclass")
+          .or_else(|| class_node.child_by_field_name("type_parameters"))
+          .or_else(|| class_node.child_by_field_name("name"))?;
+        (preceding_node.end_byte(), " implements Serializable")
+      }
+    }
+  };
+  ts_file.insert_text(insert_byte, text);
+  let class_start_byte = get_public_class_node(ts_file)?.start_byte();
+  add_member_at_end_of_type_body(
+    ts_file,
+    class_start_byte,
+    "private static final long serialVersionUID = 1L;",
+  )?;
+  add_import(ts_file, &ImportInsertionPosition::AfterLastImport, "java.io", "Serializable");
+  Some(true)
+}
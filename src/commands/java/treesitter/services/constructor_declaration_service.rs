@@ -0,0 +1,337 @@
+#![allow(dead_code)]
+
+use crate::commands::java::treesitter::services::annotation_service::find_annotation_node_by_name;
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_member_start_byte,
+};
+use crate::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+use crate::common::ts_file::TSFile;
+use tree_sitter::Node;
+
+pub struct AddConstructorDeclarationParams<'a> {
+  pub visibility_modifier: JavaVisibilityModifier,
+  /// Parameters as (type, name) pairs, in declaration order
+  pub parameters: Vec<(&'a str, &'a str)>,
+  /// Statements of the constructor body, without indentation or trailing newline
+  pub body_statements: Vec<String>,
+}
+
+/// Returns the constructors declared directly in the class body, excluding constructors of
+/// nested types.
+pub fn get_all_constructor_declaration_nodes<'a>(
+  ts_file: &'a TSFile,
+  class_declaration_node: Node<'a>,
+) -> Vec<Node<'a>> {
+  if ts_file.tree.is_none() || class_declaration_node.kind() != "class_declaration" {
+    return Vec::new();
+  }
+  let Some(class_body_node) = class_declaration_node.child_by_field_name("body") else {
+    return Vec::new();
+  };
+  let mut cursor = class_body_node.walk();
+  class_body_node
+    .named_children(&mut cursor)
+    .filter(|child| child.kind() == "constructor_declaration")
+    .collect()
+}
+
+/// Returns the formal parameters of a constructor, including a trailing varargs parameter.
+pub fn get_constructor_parameter_nodes<'a>(
+  ts_file: &'a TSFile,
+  constructor_declaration_node: Node<'a>,
+) -> Vec<Node<'a>> {
+  if ts_file.tree.is_none() || constructor_declaration_node.kind() != "constructor_declaration" {
+    return Vec::new();
+  }
+  let Some(parameters_node) = constructor_declaration_node.child_by_field_name("parameters") else {
+    return Vec::new();
+  };
+  let mut cursor = parameters_node.walk();
+  parameters_node
+    .named_children(&mut cursor)
+    .filter(|child| matches!(child.kind(), "formal_parameter" | "spread_parameter"))
+    .collect()
+}
+
+pub fn get_constructor_visibility_modifier(
+  ts_file: &TSFile,
+  constructor_declaration_node: Node,
+) -> JavaVisibilityModifier {
+  let mut cursor = constructor_declaration_node.walk();
+  let modifiers_node = constructor_declaration_node
+    .named_children(&mut cursor)
+    .find(|child| child.kind() == "modifiers");
+  let Some(modifiers_text) = modifiers_node.and_then(|n| ts_file.get_text_from_node(&n)) else {
+    return JavaVisibilityModifier::PackagePrivate;
+  };
+  let keywords: Vec<&str> = modifiers_text.split_whitespace().collect();
+  if keywords.contains(&"public") {
+    JavaVisibilityModifier::Public
+  } else if keywords.contains(&"protected") {
+    JavaVisibilityModifier::Protected
+  } else if keywords.contains(&"private") {
+    JavaVisibilityModifier::Private
+  } else {
+    JavaVisibilityModifier::PackagePrivate
+  }
+}
+
+/// Keywords of the constructor modifiers, e.g. `protected`, annotations excluded.
+pub fn get_constructor_modifier_keywords<'a>(
+  ts_file: &'a TSFile,
+  constructor_declaration_node: Node<'a>,
+) -> Vec<&'a str> {
+  let mut cursor = constructor_declaration_node.walk();
+  let Some(modifiers_node) = constructor_declaration_node
+    .named_children(&mut cursor)
+    .find(|child| child.kind() == "modifiers")
+  else {
+    return Vec::new();
+  };
+  let mut modifiers_cursor = modifiers_node.walk();
+  modifiers_node
+    .children(&mut modifiers_cursor)
+    .filter(|child| {
+      !matches!(child.kind(), "annotation" | "marker_annotation") && !child.is_extra()
+    })
+    .filter_map(|child| ts_file.get_text_from_node(&child))
+    .collect()
+}
+
+/// Returns the type and name of a formal or varargs parameter. The type of a varargs parameter
+/// is its element type, without the `...`.
+pub fn get_parameter_type_and_name<'a>(
+  ts_file: &'a TSFile,
+  parameter_node: Node<'a>,
+) -> Option<(&'a str, &'a str)> {
+  match parameter_node.kind() {
+    "spread_parameter" => {
+      let mut cursor = parameter_node.walk();
+      let children: Vec<Node> = parameter_node.named_children(&mut cursor).collect();
+      let type_node = children
+        .iter()
+        .find(|child| !matches!(child.kind(), "modifiers" | "variable_declarator"))?;
+      let name_node = children
+        .iter()
+        .find(|child| child.kind() == "variable_declarator")
+        .and_then(|declarator| declarator.child_by_field_name("name"))?;
+      Some((ts_file.get_text_from_node(type_node)?, ts_file.get_text_from_node(&name_node)?))
+    }
+    _ => Some((
+      ts_file.get_text_from_node(&parameter_node.child_by_field_name("type")?)?,
+      ts_file.get_text_from_node(&parameter_node.child_by_field_name("name")?)?,
+    )),
+  }
+}
+
+pub fn find_no_arg_constructor_node<'a>(
+  ts_file: &'a TSFile,
+  class_declaration_node: Node<'a>,
+) -> Option<Node<'a>> {
+  get_all_constructor_declaration_nodes(ts_file, class_declaration_node)
+    .into_iter()
+    .find(|constructor| get_constructor_parameter_nodes(ts_file, *constructor).is_empty())
+}
+
+/// Checks whether the class can be instantiated without arguments: it either declares a no-arg
+/// constructor, declares no constructor at all (the compiler adds the default one), or is
+/// annotated with Lombok's `@NoArgsConstructor`.
+pub fn has_no_arg_constructor(ts_file: &TSFile, class_declaration_node: Node) -> bool {
+  if find_annotation_node_by_name(ts_file, class_declaration_node, "NoArgsConstructor").is_some() {
+    return true;
+  }
+  let constructors = get_all_constructor_declaration_nodes(ts_file, class_declaration_node);
+  constructors.is_empty() || find_no_arg_constructor_node(ts_file, class_declaration_node).is_some()
+}
+
+fn build_constructor_text(
+  class_name: &str,
+  params: &AddConstructorDeclarationParams,
+  indentation: &str,
+  indentation_unit: &str,
+) -> String {
+  let mut constructor_text = String::new();
+  if params.visibility_modifier.has_keyword() {
+    constructor_text.push_str(params.visibility_modifier.keyword());
+    constructor_text.push(' ');
+  }
+  let parameters = params
+    .parameters
+    .iter()
+    .map(|(parameter_type, parameter_name)| format!("{} {}", parameter_type, parameter_name))
+    .collect::<Vec<_>>()
+    .join(", ");
+  constructor_text.push_str(&format!("{}({}) {{", class_name, parameters));
+  if params.body_statements.is_empty() {
+    constructor_text.push('}');
+    return constructor_text;
+  }
+  for statement in &params.body_statements {
+    constructor_text.push_str(&format!("\n{}{}{}", indentation, indentation_unit, statement));
+  }
+  constructor_text.push_str(&format!("\n{}}}", indentation));
+  constructor_text
+}
+
+/// Adds a constructor to the class at `class_declaration_byte_position`. The constructor is placed
+/// before the first existing constructor, otherwise after the last field, otherwise at the start of
+/// the class body.
+///
+/// # Returns
+/// The start byte of the inserted constructor
+pub fn add_constructor_declaration(
+  ts_file: &mut TSFile,
+  class_declaration_byte_position: usize,
+  params: AddConstructorDeclarationParams,
+) -> Option<usize> {
+  ts_file.tree.as_ref()?;
+  let (start_byte, end_byte, text) = {
+    let mut node = ts_file.get_named_node_at_byte_position(class_declaration_byte_position)?;
+    while node.kind() != "class_declaration" {
+      node = node.parent()?;
+    }
+    let class_name_node = get_class_declaration_name_node(ts_file, node)?;
+    let class_name = ts_file.get_text_from_node(&class_name_node)?.to_string();
+    let class_body_node = node.child_by_field_name("body")?;
+    let first_constructor = get_all_constructor_declaration_nodes(ts_file, node).first().copied();
+    let mut cursor = class_body_node.walk();
+    let last_field = class_body_node
+      .named_children(&mut cursor)
+      .filter(|child| child.kind() == "field_declaration")
+      .last();
+    // Members are indented one level deeper than the class declaration line
+    let class_indentation = ts_file.get_line_indentation(node.start_byte());
+    let member_indentation = match first_constructor.or(last_field) {
+      Some(member_node) => ts_file.get_line_indentation(member_node.start_byte()),
+      None => format!("{}  ", class_indentation),
+    };
+    let indentation_unit = member_indentation
+      .strip_prefix(class_indentation.as_str())
+      .filter(|unit| !unit.is_empty())
+      .unwrap_or("  ")
+      .to_string();
+    let constructor_text =
+      build_constructor_text(&class_name, &params, &member_indentation, &indentation_unit);
+    if let Some(constructor_node) = first_constructor {
+      let member_start_byte = get_member_start_byte(constructor_node);
+      let text = format!("{}\n\n{}", constructor_text, member_indentation);
+      (member_start_byte, member_start_byte, text)
+    } else if let Some(field_node) = last_field {
+      let mut text = format!("\n\n{}{}", member_indentation, constructor_text);
+      // Keep a blank line before a member that directly followed the field
+      if field_node
+        .next_named_sibling()
+        .is_some_and(|next| next.start_position().row == field_node.end_position().row + 1)
+      {
+        text.push('\n');
+      }
+      (field_node.end_byte(), field_node.end_byte(), text)
+    } else {
+      let body_text = ts_file.get_text_from_node(&class_body_node)?;
+      if body_text[1..body_text.len() - 1].trim().is_empty() {
+        let text =
+          format!("{{\n{}{}\n{}}}", member_indentation, constructor_text, class_indentation);
+        (class_body_node.start_byte(), class_body_node.end_byte(), text)
+      } else {
+        let text = format!("\n{}{}\n", member_indentation, constructor_text);
+        (class_body_node.start_byte() + 1, class_body_node.start_byte() + 1, text)
+      }
+    }
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, &text);
+  let leading_text_len = text.len() - text.trim_start_matches(['{', '\n', ' ', '\t']).len();
+  Some(start_byte + leading_text_len)
+}
+
+/// Appends a parameter to the constructor at `constructor_byte_position`, before a trailing
+/// varargs parameter, and `body_statement` at the end of its body.
+///
+/// # Returns
+/// Whether the parameter was added, `false` when the constructor already declares a parameter
+/// with that name
+pub fn add_constructor_parameter(
+  ts_file: &mut TSFile,
+  constructor_byte_position: usize,
+  parameter_type: &str,
+  parameter_name: &str,
+  body_statement: &str,
+) -> Option<bool> {
+  ts_file.tree.as_ref()?;
+  let (parameter_edit, body_edit) = {
+    let mut node = ts_file.get_named_node_at_byte_position(constructor_byte_position)?;
+    while node.kind() != "constructor_declaration" {
+      node = node.parent()?;
+    }
+    let parameter_nodes = get_constructor_parameter_nodes(ts_file, node);
+    let has_parameter = parameter_nodes.iter().any(|parameter| {
+      let name_node = match parameter.kind() {
+        "spread_parameter" => parameter
+          .named_children(&mut parameter.walk())
+          .find(|child| child.kind() == "variable_declarator")
+          .and_then(|declarator| declarator.child_by_field_name("name")),
+        _ => parameter.child_by_field_name("name"),
+      };
+      name_node.and_then(|name_node| ts_file.get_text_from_node(&name_node)) == Some(parameter_name)
+    });
+    if has_parameter {
+      return Some(false);
+    }
+    // Parameters on separate lines get the new one on its own line as well
+    let parameter_text = format!("{} {}", parameter_type, parameter_name);
+    let parameters_node = node.child_by_field_name("parameters")?;
+    let separator = match parameter_nodes.last() {
+      Some(last) if last.start_position().row > parameters_node.start_position().row => {
+        format!(",\n{}", ts_file.get_line_indentation(last.start_byte()))
+      }
+      _ => ", ".to_string(),
+    };
+    let parameter_edit = match parameter_nodes.last() {
+      Some(last) if last.kind() == "spread_parameter" => {
+        (last.start_byte(), format!("{}{}", parameter_text, separator))
+      }
+      Some(last) => (last.end_byte(), format!("{}{}", separator, parameter_text)),
+      None => (parameters_node.start_byte() + 1, parameter_text),
+    };
+    let body_node = node.child_by_field_name("body")?;
+    let constructor_indentation = ts_file.get_line_indentation(node.start_byte());
+    let mut cursor = body_node.walk();
+    let last_statement = body_node.named_children(&mut cursor).last();
+    let body_edit = match last_statement {
+      Some(statement) => {
+        let statement_indentation = ts_file.get_line_indentation(statement.start_byte());
+        (
+          statement.end_byte(),
+          statement.end_byte(),
+          format!("\n{}{}", statement_indentation, body_statement),
+        )
+      }
+      None => {
+        let class_indentation = node
+          .parent()
+          .and_then(|body| body.parent())
+          .map(|class_node| ts_file.get_line_indentation(class_node.start_byte()))
+          .unwrap_or_default();
+        let indentation_unit = constructor_indentation
+          .strip_prefix(class_indentation.as_str())
+          .filter(|unit| !unit.is_empty())
+          .unwrap_or("  ")
+          .to_string();
+        (
+          body_node.start_byte(),
+          body_node.end_byte(),
+          format!(
+            "{{\n{}{}{}\n{}}}",
+            constructor_indentation, indentation_unit, body_statement, constructor_indentation
+          ),
+        )
+      }
+    };
+    (parameter_edit, body_edit)
+  };
+  // The body follows the parameters, editing it first keeps the parameter position valid
+  let (body_start_byte, body_end_byte, body_text) = body_edit;
+  ts_file.replace_text_by_range(body_start_byte, body_end_byte, &body_text);
+  let (parameter_byte, parameter_text) = parameter_edit;
+  ts_file.replace_text_by_range(parameter_byte, parameter_byte, &parameter_text);
+  Some(true)
+}
@@ -0,0 +1,510 @@
+#![allow(dead_code)]
+
+use tree_sitter::Node;
+
+use crate::commands::java::treesitter::services::annotation_service::{
+  find_declaration_annotation_node_by_name, find_direct_annotation_value_node_by_key,
+  get_declaration_annotation_nodes,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_declaration_name_node, get_class_superclass_name_node, get_public_class_node,
+  has_modifier,
+};
+use crate::commands::java::treesitter::services::field_declaration_service::{
+  get_all_field_declaration_nodes, get_field_declaration_name_node,
+};
+use crate::commands::java::treesitter::services::package_declaration_service::get_package_name;
+use crate::commands::java::treesitter::types::entity_mapping::{
+  ColumnMapping, EmbeddedMapping, EntityMapping, IdFieldMapping, JoinColumnMapping,
+  MappedClassKind, RelationshipFieldMapping,
+};
+use crate::commands::java::treesitter::types::entity_side::EntitySide;
+use crate::commands::java::treesitter::types::inverse_resolution::InverseResolution;
+use crate::commands::java::treesitter::types::relationship_kind::RelationshipKind;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::case_util::to_snake_case;
+
+/// Default physical table name for an entity, following Spring Boot's
+/// `CamelCaseToUnderscoresNamingStrategy` (`OrderItem` -> `order_item`).
+pub fn get_default_table_name(entity_name: &str) -> String {
+  to_snake_case(entity_name)
+}
+
+/// Default physical column name for a field (`createdAt` -> `created_at`).
+pub fn get_default_column_name(field_name: &str) -> String {
+  to_snake_case(field_name)
+}
+
+/// Default foreign key column name for a relationship field (`author` -> `author_id`).
+pub fn get_default_join_column_name(field_name: &str) -> String {
+  format!("{}_id", to_snake_case(field_name))
+}
+
+/// Returns true when the field takes part in the persistent state of the entity, that is, it is
+/// neither `static`, `transient` nor annotated with `@Transient`.
+pub fn is_persistent_field(ts_file: &TSFile, field_node: Node) -> bool {
+  !has_modifier(field_node, "static")
+    && !has_modifier(field_node, "transient")
+    && find_declaration_annotation_node_by_name(ts_file, field_node, "Transient").is_none()
+}
+
+/// Returns true when the field is declared non-null, through `@NotNull`/`@NonNull`,
+/// `@Column(nullable = false)`, `@JoinColumn(nullable = false)`, `@Basic(optional = false)` or a
+/// relationship annotation with `optional = false`.
+pub fn is_required_field(ts_file: &TSFile, field_node: Node) -> bool {
+  let find = |name: &str| find_declaration_annotation_node_by_name(ts_file, field_node, name);
+  let is_false = |name: &str, key: &str| {
+    find(name).and_then(|node| get_bool_argument(ts_file, node, key)) == Some(false)
+  };
+  find("NotNull").is_some()
+    || find("NonNull").is_some()
+    || is_false("Column", "nullable")
+    || is_false("JoinColumn", "nullable")
+    || is_false("Basic", "optional")
+    || is_false("ManyToOne", "optional")
+    || is_false("OneToOne", "optional")
+}
+
+/// Returns the field declarations of `class_node` itself, skipping fields of nested classes.
+pub fn get_own_field_declaration_nodes<'a>(
+  ts_file: &'a TSFile,
+  class_node: Node<'a>,
+) -> Vec<Node<'a>> {
+  get_all_field_declaration_nodes(ts_file, class_node)
+    .into_iter()
+    .filter(|field_node| {
+      field_node.parent().and_then(|body| body.parent()).map(|c| c.id()) == Some(class_node.id())
+    })
+    .collect()
+}
+
+/// Simple name of a declared type: generics and package qualifiers are dropped, arrays are kept
+/// (`java.util.List<Book>` -> `List`, `byte[]` -> `byte[]`).
+pub fn get_simple_type_name(type_text: &str) -> String {
+  let without_generics = type_text.split('<').next().unwrap_or(type_text).trim();
+  without_generics.rsplit('.').next().unwrap_or(without_generics).trim().to_string()
+}
+
+/// Type argument of a collection type (`List<Book>` -> `Book`), or the type itself.
+pub fn get_element_type_name(type_text: &str) -> String {
+  match (type_text.find('<'), type_text.rfind('>')) {
+    (Some(start), Some(end)) if start < end => {
+      let arguments = &type_text[start + 1..end];
+      // For maps the value type is the related element
+      let element = arguments.rsplit(',').next().unwrap_or(arguments);
+      get_simple_type_name(element)
+    }
+    _ => get_simple_type_name(type_text),
+  }
+}
+
+fn get_string_argument(ts_file: &TSFile, annotation_node: Node, key: &str) -> Option<String> {
+  let value_node = find_direct_annotation_value_node_by_key(ts_file, annotation_node, key)?;
+  let text = ts_file.get_text_from_node(&value_node)?;
+  Some(text.trim().trim_matches('"').to_string())
+}
+
+fn get_bool_argument(ts_file: &TSFile, annotation_node: Node, key: &str) -> Option<bool> {
+  match get_string_argument(ts_file, annotation_node, key)?.as_str() {
+    "true" => Some(true),
+    "false" => Some(false),
+    _ => None,
+  }
+}
+
+fn get_number_argument(ts_file: &TSFile, annotation_node: Node, key: &str) -> Option<u32> {
+  get_string_argument(ts_file, annotation_node, key)?.parse().ok()
+}
+
+/// Reads an enum constant argument such as `EnumType.STRING`, returning only the constant name.
+fn get_constant_argument(ts_file: &TSFile, annotation_node: Node, key: &str) -> Option<String> {
+  let text = get_string_argument(ts_file, annotation_node, key)?;
+  text.rsplit('.').next().map(|constant| constant.to_string())
+}
+
+fn get_attribute_overrides(ts_file: &TSFile, field_node: Node) -> Vec<(String, String)> {
+  let mut override_nodes = Vec::new();
+  for annotation_node in get_declaration_annotation_nodes(field_node) {
+    let name = annotation_node
+      .child_by_field_name("name")
+      .and_then(|n| ts_file.get_text_from_node(&n))
+      .unwrap_or_default();
+    if name == "AttributeOverride" {
+      override_nodes.push(annotation_node);
+    } else if name == "AttributeOverrides"
+      && let Some(value_node) =
+        find_direct_annotation_value_node_by_key(ts_file, annotation_node, "value")
+    {
+      let mut cursor = value_node.walk();
+      override_nodes
+        .extend(value_node.named_children(&mut cursor).filter(|n| n.kind() == "annotation"));
+    }
+  }
+  override_nodes
+    .into_iter()
+    .filter_map(|override_node| {
+      let attribute_name = get_string_argument(ts_file, override_node, "name")?;
+      let column_node = find_direct_annotation_value_node_by_key(ts_file, override_node, "column")?;
+      let column_name = get_string_argument(ts_file, column_node, "name")?;
+      Some((attribute_name, column_name))
+    })
+    .collect()
+}
+
+fn build_column_mapping(
+  ts_file: &TSFile,
+  field_node: Node,
+  field_name: &str,
+  field_type: &str,
+) -> ColumnMapping {
+  let find = |name: &str| find_declaration_annotation_node_by_name(ts_file, field_node, name);
+  let is_id = find("Id").is_some();
+  let column_annotation = find("Column");
+  let explicit_name = column_annotation.and_then(|node| get_string_argument(ts_file, node, "name"));
+  let is_primitive = matches!(
+    field_type,
+    "int" | "long" | "short" | "byte" | "boolean" | "double" | "float" | "char"
+  );
+  let nullable = !is_id
+    && !is_primitive
+    && column_annotation
+      .and_then(|node| get_bool_argument(ts_file, node, "nullable"))
+      .unwrap_or(true)
+    && find("Basic").and_then(|node| get_bool_argument(ts_file, node, "optional")).unwrap_or(true);
+  let enum_type = find("Enumerated").map(|node| {
+    get_constant_argument(ts_file, node, "value").unwrap_or_else(|| "ORDINAL".to_string())
+  });
+  let is_identity = find("GeneratedValue")
+    .and_then(|node| get_constant_argument(ts_file, node, "strategy"))
+    .is_some_and(|strategy| strategy == "IDENTITY");
+  ColumnMapping {
+    field_name: field_name.to_string(),
+    field_type: field_type.to_string(),
+    column_name: explicit_name.clone().unwrap_or_else(|| get_default_column_name(field_name)),
+    has_explicit_name: explicit_name.is_some(),
+    is_id,
+    is_identity,
+    is_version: find("Version").is_some(),
+    nullable,
+    unique: column_annotation
+      .and_then(|node| get_bool_argument(ts_file, node, "unique"))
+      .unwrap_or(false),
+    length: column_annotation.and_then(|node| get_number_argument(ts_file, node, "length")),
+    precision: column_annotation.and_then(|node| get_number_argument(ts_file, node, "precision")),
+    scale: column_annotation.and_then(|node| get_number_argument(ts_file, node, "scale")),
+    is_lob: find("Lob").is_some(),
+    enum_type,
+    temporal: find("Temporal").and_then(|node| get_constant_argument(ts_file, node, "value")),
+    column_definition: column_annotation
+      .and_then(|node| get_string_argument(ts_file, node, "columnDefinition")),
+  }
+}
+
+fn build_join_column_mapping(
+  ts_file: &TSFile,
+  field_node: Node,
+  relationship_node: Node,
+  field_name: &str,
+  target_type: &str,
+  is_one_to_one: bool,
+) -> JoinColumnMapping {
+  let join_column = find_declaration_annotation_node_by_name(ts_file, field_node, "JoinColumn");
+  let explicit_name = join_column.and_then(|node| get_string_argument(ts_file, node, "name"));
+  let optional = get_bool_argument(ts_file, relationship_node, "optional").unwrap_or(true);
+  let nullable = optional
+    && join_column.and_then(|node| get_bool_argument(ts_file, node, "nullable")).unwrap_or(true);
+  let unique = is_one_to_one
+    || join_column.and_then(|node| get_bool_argument(ts_file, node, "unique")).unwrap_or(false);
+  let foreign_key = join_column
+    .and_then(|node| find_direct_annotation_value_node_by_key(ts_file, node, "foreignKey"))
+    .filter(|node| node.kind() == "annotation");
+  JoinColumnMapping {
+    field_name: field_name.to_string(),
+    target_type: target_type.to_string(),
+    column_name: explicit_name.clone().unwrap_or_else(|| get_default_join_column_name(field_name)),
+    has_explicit_name: explicit_name.is_some(),
+    referenced_column_name: join_column
+      .and_then(|node| get_string_argument(ts_file, node, "referencedColumnName")),
+    nullable,
+    unique,
+    foreign_key_name: foreign_key.and_then(|node| get_string_argument(ts_file, node, "name")),
+    no_constraint: foreign_key
+      .and_then(|node| get_constant_argument(ts_file, node, "value"))
+      .is_some_and(|mode| mode == "NO_CONSTRAINT"),
+  }
+}
+
+fn get_class_kind(ts_file: &TSFile, class_node: Node) -> Option<MappedClassKind> {
+  if find_declaration_annotation_node_by_name(ts_file, class_node, "Entity").is_some() {
+    Some(MappedClassKind::Entity)
+  } else if find_declaration_annotation_node_by_name(ts_file, class_node, "MappedSuperclass")
+    .is_some()
+  {
+    Some(MappedClassKind::MappedSuperclass)
+  } else if find_declaration_annotation_node_by_name(ts_file, class_node, "Embeddable").is_some() {
+    Some(MappedClassKind::Embeddable)
+  } else {
+    None
+  }
+}
+
+/// Returns true when a field of the class already maps the column, as a basic column or a join
+/// column, so another field can map it read-only.
+pub fn is_column_mapped(ts_file: &TSFile, class_node: Node, column_name: &str) -> bool {
+  let Some(mapping) = get_class_mapping(ts_file, class_node) else {
+    return false;
+  };
+  mapping.columns.iter().any(|column| column.column_name.eq_ignore_ascii_case(column_name))
+    || mapping
+      .join_columns
+      .iter()
+      .any(|join_column| join_column.column_name.eq_ignore_ascii_case(column_name))
+}
+
+/// Extracts the relational mapping of a JPA class (entity, mapped superclass or embeddable):
+/// its table and the columns, foreign keys, embedded values and relationships declared by its own
+/// fields.
+///
+/// Names not given explicitly through `@Table`, `@Column` or `@JoinColumn` are resolved with the
+/// default naming strategy (`get_default_table_name`, `get_default_column_name` and
+/// `get_default_join_column_name`). Inherited fields are not included, callers resolve them
+/// through `superclass_name`.
+///
+/// # Returns
+/// The mapping, or `None` if the class isn't annotated with `@Entity`, `@MappedSuperclass` or
+/// `@Embeddable`
+pub fn get_class_mapping(ts_file: &TSFile, class_node: Node) -> Option<EntityMapping> {
+  let kind = get_class_kind(ts_file, class_node)?;
+  let class_name = get_class_declaration_name_node(ts_file, class_node)
+    .and_then(|node| ts_file.get_text_from_node(&node))?
+    .to_string();
+  let entity_annotation = find_declaration_annotation_node_by_name(ts_file, class_node, "Entity");
+  let entity_name = entity_annotation
+    .and_then(|node| get_string_argument(ts_file, node, "name"))
+    .unwrap_or_else(|| class_name.clone());
+  let table_annotation = find_declaration_annotation_node_by_name(ts_file, class_node, "Table");
+  let explicit_table_name =
+    table_annotation.and_then(|node| get_string_argument(ts_file, node, "name"));
+  let package_name = get_package_name(ts_file).map(|name| name.to_string());
+  let superclass_name = get_class_superclass_name_node(ts_file, class_node)
+    .and_then(|node| ts_file.get_text_from_node(&node))
+    .map(|name| name.to_string());
+  let inheritance_strategy =
+    find_declaration_annotation_node_by_name(ts_file, class_node, "Inheritance").map(|node| {
+      get_constant_argument(ts_file, node, "strategy").unwrap_or_else(|| "SINGLE_TABLE".to_string())
+    });
+  let mut mapping = EntityMapping {
+    kind,
+    class_name,
+    package_name,
+    file_path: ts_file.file_path().map(|path| path.to_string_lossy().to_string()),
+    superclass_name,
+    table_name: explicit_table_name.clone().unwrap_or_else(|| get_default_table_name(&entity_name)),
+    has_explicit_table_name: explicit_table_name.is_some(),
+    schema: table_annotation.and_then(|node| get_string_argument(ts_file, node, "schema")),
+    catalog: table_annotation.and_then(|node| get_string_argument(ts_file, node, "catalog")),
+    inheritance_strategy,
+    columns: Vec::new(),
+    join_columns: Vec::new(),
+    embedded: Vec::new(),
+    relationships: get_relationship_field_mappings(ts_file, class_node),
+  };
+  for field_node in get_own_field_declaration_nodes(ts_file, class_node) {
+    if !is_persistent_field(ts_file, field_node) {
+      continue;
+    }
+    let Some(field_name) = get_field_declaration_name_node(ts_file, field_node)
+      .and_then(|node| ts_file.get_text_from_node(&node))
+    else {
+      continue;
+    };
+    let Some(type_text) =
+      field_node.child_by_field_name("type").and_then(|node| ts_file.get_text_from_node(&node))
+    else {
+      continue;
+    };
+    let find = |name: &str| find_declaration_annotation_node_by_name(ts_file, field_node, name);
+    // Collection mappings live in the other table or in a join table
+    if find("OneToMany").is_some()
+      || find("ManyToMany").is_some()
+      || find("ElementCollection").is_some()
+    {
+      continue;
+    }
+    let to_one =
+      find("ManyToOne").map(|node| (node, false)).or(find("OneToOne").map(|n| (n, true)));
+    if let Some((relationship_node, is_one_to_one)) = to_one {
+      // The inverse side of a one-to-one doesn't own a foreign key
+      if get_string_argument(ts_file, relationship_node, "mappedBy").is_some() {
+        continue;
+      }
+      mapping.join_columns.push(build_join_column_mapping(
+        ts_file,
+        field_node,
+        relationship_node,
+        field_name,
+        &get_simple_type_name(type_text),
+        is_one_to_one,
+      ));
+      continue;
+    }
+    let embedded_id = find("EmbeddedId").is_some();
+    if embedded_id || find("Embedded").is_some() {
+      mapping.embedded.push(EmbeddedMapping {
+        field_name: field_name.to_string(),
+        embeddable_type: get_simple_type_name(type_text),
+        is_id: embedded_id,
+        attribute_overrides: get_attribute_overrides(ts_file, field_node),
+      });
+      continue;
+    }
+    let field_type = get_simple_type_name(type_text);
+    mapping.columns.push(build_column_mapping(ts_file, field_node, field_name, &field_type));
+  }
+  Some(mapping)
+}
+
+/// Extracts the relational mapping of the file's public class. See `get_class_mapping`.
+pub fn get_entity_mapping(ts_file: &TSFile) -> Option<EntityMapping> {
+  let class_node = get_public_class_node(ts_file)?;
+  get_class_mapping(ts_file, class_node)
+}
+
+/// Returns the relationship fields (`@ManyToOne`, `@OneToMany`, `@OneToOne` and `@ManyToMany`)
+/// declared by `class_node` itself, in declaration order.
+pub fn get_relationship_field_mappings(
+  ts_file: &TSFile,
+  class_node: Node,
+) -> Vec<RelationshipFieldMapping> {
+  let relationship_kinds = [
+    RelationshipKind::ManyToOne,
+    RelationshipKind::OneToMany,
+    RelationshipKind::OneToOne,
+    RelationshipKind::ManyToMany,
+  ];
+  let mut relationships = Vec::new();
+  for field_node in get_own_field_declaration_nodes(ts_file, class_node) {
+    let Some((kind, relationship_node)) = relationship_kinds.iter().find_map(|kind| {
+      find_declaration_annotation_node_by_name(ts_file, field_node, kind.annotation_name())
+        .map(|node| (kind.clone(), node))
+    }) else {
+      continue;
+    };
+    let Some(field_name) = get_field_declaration_name_node(ts_file, field_node)
+      .and_then(|node| ts_file.get_text_from_node(&node))
+    else {
+      continue;
+    };
+    let Some(type_text) =
+      field_node.child_by_field_name("type").and_then(|node| ts_file.get_text_from_node(&node))
+    else {
+      continue;
+    };
+    let target_type = get_string_argument(ts_file, relationship_node, "targetEntity")
+      .map(|target| get_simple_type_name(target.trim_end_matches(".class")))
+      .unwrap_or_else(|| get_element_type_name(type_text));
+    let mapped_by = get_string_argument(ts_file, relationship_node, "mappedBy");
+    // A many-to-one always owns the foreign key, the other kinds are inverse when mapped by
+    // a field of the target entity
+    let side = if kind != RelationshipKind::ManyToOne && mapped_by.is_some() {
+      EntitySide::Inverse
+    } else {
+      EntitySide::Owning
+    };
+    relationships.push(RelationshipFieldMapping {
+      field_name: field_name.to_string(),
+      field_type: type_text.to_string(),
+      target_type,
+      is_collection: matches!(kind, RelationshipKind::OneToMany | RelationshipKind::ManyToMany),
+      kind,
+      side,
+      mapped_by,
+    });
+  }
+  relationships
+}
+
+/// Finds the field of `target_class_node` on the inverse side of `relationship`, the owning field
+/// of `entity_type`: the field mapped by it, otherwise the only association of the matching kind
+/// pointing back at `entity_type` without `mappedBy`.
+pub fn find_inverse_relationship_field(
+  ts_file: &TSFile,
+  target_class_node: Node,
+  entity_type: &str,
+  relationship: &RelationshipFieldMapping,
+) -> Option<(RelationshipFieldMapping, InverseResolution)> {
+  let candidates: Vec<RelationshipFieldMapping> =
+    get_relationship_field_mappings(ts_file, target_class_node)
+      .into_iter()
+      .filter(|other| {
+        other.target_type == entity_type && other.field_name != relationship.field_name
+      })
+      .collect();
+  if let Some(mapped) = candidates.iter().find(|other| {
+    other.side == EntitySide::Inverse
+      && other.mapped_by.as_deref() == Some(relationship.field_name.as_str())
+  }) {
+    return Some((mapped.clone(), InverseResolution::MappedBy));
+  }
+  let inverse_kind = relationship.kind.get_inverse_kind();
+  let mut unmapped =
+    candidates.into_iter().filter(|other| other.kind == inverse_kind && other.mapped_by.is_none());
+  match (unmapped.next(), unmapped.next()) {
+    (Some(other), None) => Some((other, InverseResolution::FieldType)),
+    _ => None,
+  }
+}
+
+/// Returns the `@Id` and `@EmbeddedId` fields declared by `class_node` itself, with the
+/// `@GeneratedValue` and `@SequenceGenerator` (on the field, otherwise on the class) describing how
+/// their values are generated.
+pub fn get_id_field_mappings(ts_file: &TSFile, class_node: Node) -> Vec<IdFieldMapping> {
+  let class_sequence_generator =
+    find_declaration_annotation_node_by_name(ts_file, class_node, "SequenceGenerator");
+  let mut id_fields = Vec::new();
+  for field_node in get_own_field_declaration_nodes(ts_file, class_node) {
+    let find = |name: &str| find_declaration_annotation_node_by_name(ts_file, field_node, name);
+    let is_embedded_id = find("EmbeddedId").is_some();
+    if !is_embedded_id && find("Id").is_none() {
+      continue;
+    }
+    let Some(field_name) = get_field_declaration_name_node(ts_file, field_node)
+      .and_then(|node| ts_file.get_text_from_node(&node))
+    else {
+      continue;
+    };
+    let Some(field_type) =
+      field_node.child_by_field_name("type").and_then(|node| ts_file.get_text_from_node(&node))
+    else {
+      continue;
+    };
+    let generated_value = find("GeneratedValue");
+    let generator_name =
+      generated_value.and_then(|node| get_string_argument(ts_file, node, "generator"));
+    // A class level generator only applies when the field refers to it
+    let sequence_generator =
+      find("SequenceGenerator").or(class_sequence_generator.filter(|node| {
+        generator_name.is_some() && get_string_argument(ts_file, *node, "name") == generator_name
+      }));
+    id_fields.push(IdFieldMapping {
+      field_name: field_name.to_string(),
+      field_type: field_type.to_string(),
+      is_embedded_id,
+      generation_strategy: generated_value.map(|node| {
+        get_constant_argument(ts_file, node, "strategy").unwrap_or_else(|| "AUTO".to_string())
+      }),
+      generator_name,
+      sequence_name: sequence_generator
+        .and_then(|node| get_string_argument(ts_file, node, "sequenceName")),
+    });
+  }
+  id_fields
+}
+
+/// Returns the simple name of the `@IdClass` of `class_node` (`@IdClass(OrderKey.class)` ->
+/// `OrderKey`).
+pub fn get_id_class_name(ts_file: &TSFile, class_node: Node) -> Option<String> {
+  let id_class = find_declaration_annotation_node_by_name(ts_file, class_node, "IdClass")?;
+  let value = get_string_argument(ts_file, id_class, "value")?;
+  Some(get_simple_type_name(value.trim_end_matches(".class")))
+}
@@ -1,6 +1,15 @@
 #![allow(dead_code)]
 
-use crate::commands::java::treesitter::services::annotation_service::add_annotation;
+use crate::commands::java::treesitter::services::annotation_service::{
+  add_annotation, find_declaration_annotation_node_by_name, set_annotation_argument,
+};
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_class_member_indentation, get_class_name, get_member_removal_range, get_member_text,
+  get_public_class_node, indent_member_text,
+};
+use crate::commands::java::treesitter::services::method_invocation_service::{
+  is_local_identifier, resolve_identifier_type,
+};
 use crate::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
 use crate::commands::java::treesitter::types::java_basic_types::{
   FieldInsertionPoint, FieldInsertionPosition,
@@ -512,6 +521,46 @@ fn find_class_declaration_node_from_position<'a>(
   }
 }
 
+/// Computes where and how a new member is inserted in a class body, so that repeated insertions
+/// produce the same spacing.
+///
+/// # Returns
+/// The replaced byte range, the replacement text and the offset of the member within that text
+fn get_member_insertion_text(
+  ts_file: &TSFile,
+  class_declaration_node: Node,
+  insertion_position: &FieldInsertionPosition,
+  member_text: &str,
+) -> Option<(usize, usize, String, usize)> {
+  let class_body_node = get_class_body_node(ts_file, class_declaration_node)?;
+  let class_indentation = ts_file.get_line_indentation(class_declaration_node.start_byte());
+  let member_indentation = get_class_member_indentation(ts_file, class_declaration_node);
+  let body_start_byte = class_body_node.start_byte();
+  let closing_brace_byte = class_body_node.end_byte() - 1;
+  // Empty body: the member goes on its own line and the closing brace on the next one
+  if ts_file.source_code[body_start_byte + 1..closing_brace_byte].trim().is_empty() {
+    let text = format!("{{\n{}{}\n{}}}", member_indentation, member_text, class_indentation);
+    return Some((body_start_byte, closing_brace_byte + 1, text, 2 + member_indentation.len()));
+  }
+  match insertion_position {
+    FieldInsertionPosition::AfterLastField | FieldInsertionPosition::BeforeFirstMethod => {
+      let insert_byte =
+        match get_all_field_declaration_nodes(ts_file, class_declaration_node).last() {
+          Some(last_field) => last_field.end_byte(),
+          None => body_start_byte + 1,
+        };
+      let text = format!("\n{}{}", member_indentation, member_text);
+      Some((insert_byte, insert_byte, text, 1 + member_indentation.len()))
+    }
+    FieldInsertionPosition::EndOfClassBody => {
+      // Separate from the previous member with a blank line, keeping the brace on its own line
+      let insert_byte = ts_file.source_code[..closing_brace_byte].trim_end().len();
+      let text = format!("\n\n{}{}\n{}", member_indentation, member_text, class_indentation);
+      Some((insert_byte, closing_brace_byte, text, 2 + member_indentation.len()))
+    }
+  }
+}
+
 pub fn add_field_declaration<'a, F, R>(
   ts_file: &'a mut TSFile,
   class_declaration_byte_position: usize,
@@ -527,22 +576,10 @@ where
   {
     return None;
   }
-  // Find the class declaration node
-  let class_declaration_node =
-    find_class_declaration_node_from_position(ts_file, class_declaration_byte_position)?;
-  // Get the class body node
-  let class_body_node = get_class_body_node(ts_file, class_declaration_node)?;
-  // Collect all necessary information before any mutable operations
-  let (class_body_start_byte, class_body_end_byte, current_body_text, all_fields) = {
-    let current_body_text = ts_file.get_text_from_node(&class_body_node)?.to_string();
-    let all_fields = get_all_field_declaration_nodes(ts_file, class_declaration_node);
-    (class_body_node.start_byte(), class_body_node.end_byte(), current_body_text, all_fields)
-  };
   // Build the field declaration text
   let modifiers_str =
     params.field_modifiers.iter().map(|m| m.keyword()).collect::<Vec<_>>().join(" ");
   let mut field_text = String::new();
-  field_text.push_str("  "); // Indentation
   if params.visibility_modifier.has_keyword() {
     field_text.push_str(params.visibility_modifier.keyword());
     field_text.push(' ');
@@ -561,69 +598,182 @@ where
     field_text.push_str(field_init);
   }
   field_text.push(';');
-  // Build the new class body content with the field inserted at the proper position
-  let new_body_content = match params.insertion_position {
-    FieldInsertionPosition::AfterLastField => {
-      if !all_fields.is_empty() {
-        // Insert after last field
-        let last_field = all_fields.last()?;
-        let relative_pos = last_field.end_byte() - class_body_start_byte;
-        let before = &current_body_text[..relative_pos];
-        let after = &current_body_text[relative_pos..];
-        format!("{}\n{}{}", before, field_text, after)
-      } else {
-        // No fields exist, insert after opening brace and before any content
-        // The class body text includes braces, so we need to find the right position
-        if let Some(after_brace) = current_body_text.strip_prefix('{') {
-          format!("{{\n{}{}", field_text, after_brace)
-        } else {
-          current_body_text
-        }
-      }
+  // Collect all necessary information before any mutable operations
+  let (start_byte, end_byte, text, field_offset) = {
+    let class_declaration_node =
+      find_class_declaration_node_from_position(ts_file, class_declaration_byte_position)?;
+    get_member_insertion_text(
+      ts_file,
+      class_declaration_node,
+      &params.insertion_position,
+      &field_text,
+    )?
+  };
+  // Replace the range with the new content - tree is updated incrementally
+  ts_file.replace_text_by_range(start_byte, end_byte, &text);
+  // Locate the new field by position, a field with the same name may already exist
+  let field_start_byte = start_byte + field_offset;
+  let mut new_field_node = ts_file.get_named_node_at_byte_position(field_start_byte)?;
+  while new_field_node.kind() != "field_declaration" {
+    new_field_node = new_field_node.parent()?;
+  }
+  if new_field_node.start_byte() != field_start_byte {
+    return None;
+  }
+  // Create the builder and call the callback
+  let mut builder = FieldAnnotationBuilder::new(ts_file, field_start_byte);
+  Some(callback(&mut builder))
+}
+
+/// Removes a field declaration together with its attached comments (e.g. Javadoc), so it can be
+/// moved with `add_field_declaration_text`.
+///
+/// # Returns
+/// The removed declaration as returned by `get_member_text`, `None` when the field isn't found
+/// or shares its declaration with other fields (`int a, b;`)
+pub fn remove_field_declaration(
+  ts_file: &mut TSFile,
+  class_declaration_byte_position: usize,
+  field_name: &str,
+) -> Option<String> {
+  ts_file.tree.as_ref()?;
+  let (start_byte, end_byte, field_text) = {
+    let class_node =
+      find_class_declaration_node_from_position(ts_file, class_declaration_byte_position)?;
+    let field_node = find_field_declaration_node_by_name(ts_file, field_name, class_node)?;
+    let mut cursor = field_node.walk();
+    let declarators_count = field_node
+      .named_children(&mut cursor)
+      .filter(|child| child.kind() == "variable_declarator")
+      .count();
+    if declarators_count != 1 {
+      return None;
     }
-    FieldInsertionPosition::BeforeFirstMethod => {
-      // For now, use same logic as AfterLastField
-      // TODO: Implement method detection if needed
-      if !all_fields.is_empty() {
-        let last_field = all_fields.last()?;
-        let relative_pos = last_field.end_byte() - class_body_start_byte;
-        let before = &current_body_text[..relative_pos];
-        let after = &current_body_text[relative_pos..];
-        format!("{}\n{}{}", before, field_text, after)
-      } else if let Some(after_brace) = current_body_text.strip_prefix('{') {
-        format!("{{\n{}{}", field_text, after_brace)
-      } else {
-        current_body_text
-      }
+    let (start_byte, end_byte) = get_member_removal_range(ts_file, field_node);
+    (start_byte, end_byte, get_member_text(ts_file, field_node)?)
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, "");
+  Some(field_text)
+}
+
+/// Inserts a complete field declaration, possibly spanning several lines with its Javadoc and
+/// annotations, indenting every line as a member of the class.
+///
+/// # Returns
+/// The start byte of the inserted text
+pub fn add_field_declaration_text(
+  ts_file: &mut TSFile,
+  class_declaration_byte_position: usize,
+  insertion_position: &FieldInsertionPosition,
+  field_text: &str,
+) -> Option<usize> {
+  ts_file.tree.as_ref()?;
+  let (start_byte, end_byte, text, field_offset) = {
+    let class_node =
+      find_class_declaration_node_from_position(ts_file, class_declaration_byte_position)?;
+    let member_indentation = get_class_member_indentation(ts_file, class_node);
+    let member_text = indent_member_text(field_text.trim(), &member_indentation);
+    get_member_insertion_text(ts_file, class_node, insertion_position, &member_text)?
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, &text);
+  Some(start_byte + field_offset)
+}
+
+/// Sets `key = value` on the `annotation_name` annotation of a field, leaving its other arguments
+/// untouched. The annotation is added right above the field declaration when absent.
+///
+/// # Returns
+/// Whether the annotation was added, or `None` when the field isn't found or can't be edited
+pub fn set_field_annotation_argument(
+  ts_file: &mut TSFile,
+  class_declaration_byte_position: usize,
+  field_name: &str,
+  annotation_name: &str,
+  key: &str,
+  value: &str,
+) -> Option<bool> {
+  ts_file.tree.as_ref()?;
+  let (field_start_byte, annotation_start_byte) = {
+    let class_node =
+      find_class_declaration_node_from_position(ts_file, class_declaration_byte_position)?;
+    let field_node = find_field_declaration_node_by_name(ts_file, field_name, class_node)?;
+    let annotation_node =
+      find_declaration_annotation_node_by_name(ts_file, field_node, annotation_name);
+    (field_node.start_byte(), annotation_node.map(|node| node.start_byte()))
+  };
+  match annotation_start_byte {
+    Some(annotation_start_byte) => {
+      set_annotation_argument(ts_file, annotation_start_byte, key, value).then_some(false)
     }
-    FieldInsertionPosition::EndOfClassBody => {
-      // Insert before the closing brace
-      if let Some(before_brace) = current_body_text.strip_suffix('}') {
-        format!("{}\n{}\n}}", before_brace, field_text)
-      } else {
-        format!("{}\n{}\n", current_body_text, field_text)
-      }
+    None => {
+      let annotation_text = format!("@{}({} = {})", annotation_name, key, value);
+      add_annotation(
+        ts_file,
+        field_start_byte,
+        &AnnotationInsertionPosition::AboveScopeDeclaration,
+        &annotation_text,
+      )
+      .map(|_| true)
     }
+  }
+}
+
+/// Lines of the public class still reading or writing a removed field, in `toString()` or an
+/// accessor for instance, which no longer compile.
+pub fn find_leftover_reference_lines(ts_file: &TSFile, field_name: &str) -> Vec<usize> {
+  let Some(class_node) = get_public_class_node(ts_file) else {
+    return Vec::new();
   };
-  // Replace the class body with the new content - tree is updated incrementally
-  let update_success = ts_file.replace_text_by_byte_range(
-    class_body_start_byte,
-    class_body_end_byte,
-    &new_body_content,
-  );
-  if update_success.is_some() {
-    // The tree is updated. We must get the class node again from the *new* tree.
-    let new_class_decl_node =
-      find_class_declaration_node_from_position(ts_file, class_declaration_byte_position)?;
-    // Now, find the new field node we just added
-    let new_field_node =
-      find_field_declaration_node_by_name(ts_file, params.field_name, new_class_decl_node)?;
-    let field_start_byte = new_field_node.start_byte();
-    // Create the builder and call the callback
-    let mut builder = FieldAnnotationBuilder::new(ts_file, field_start_byte);
-    Some(callback(&mut builder))
-  } else {
-    // Replacement failed
-    None
+  let entity_type = get_class_name(ts_file, class_node).unwrap_or_default();
+  let mut lines = Vec::new();
+  let mut pending = vec![class_node];
+  while let Some(node) = pending.pop() {
+    let mut cursor = node.walk();
+    pending.extend(node.named_children(&mut cursor));
+    if node.kind() != "identifier" || ts_file.get_text_from_node(&node) != Some(field_name) {
+      continue;
+    }
+    let Some(parent) = node.parent() else {
+      continue;
+    };
+    let is_field =
+      |name: &str| parent.child_by_field_name(name).is_some_and(|child| child.id() == node.id());
+    let is_reference = match parent.kind() {
+      "field_access" if is_field("field") => {
+        parent.child_by_field_name("object").is_some_and(|object| match object.kind() {
+          "this" => true,
+          "identifier" => ts_file.get_text_from_node(&object).is_some_and(|identifier| {
+            resolve_identifier_type(ts_file, object, identifier).as_deref()
+              == Some(entity_type.as_str())
+          }),
+          _ => false,
+        })
+      }
+      "method_invocation" | "method_declaration" | "variable_declarator" if is_field("name") => {
+        false
+      }
+      "formal_parameter" | "element_value_pair" | "method_reference" | "labeled_statement" => false,
+      _ => !is_local_identifier(ts_file, node, field_name),
+    };
+    if is_reference {
+      lines.push(node.start_position().row + 1);
+    }
   }
+  lines.sort();
+  lines.dedup();
+  lines
+}
+
+/// Warns about the references to the removed field left in the public class.
+pub fn get_leftover_references_warning(ts_file: &TSFile, field_name: &str) -> Option<String> {
+  let lines = find_leftover_reference_lines(ts_file, field_name);
+  let (label, lines) = match lines.as_slice() {
+    [] => return None,
+    [line] => ("line", line.to_string()),
+    _ => ("lines", lines.iter().map(|line| line.to_string()).collect::<Vec<_>>().join(", ")),
+  };
+  Some(format!(
+    "Field '{}' was removed but is still referenced at {} {}, update them before compiling",
+    field_name, label, lines
+  ))
 }
@@ -1,10 +1,13 @@
 #![allow(dead_code)]
 
-use crate::commands::java::treesitter::services::package_declaration_service::get_package_declaration_node;
+use crate::commands::java::treesitter::services::package_declaration_service::{
+  get_package_declaration_node, get_package_name,
+};
 use crate::commands::java::treesitter::types::import_types::{
   ImportInsertionPoint, ImportInsertionPosition,
 };
 use crate::common::ts_file::TSFile;
+use std::collections::HashMap;
 use tree_sitter::Node;
 
 impl ImportInsertionPoint {
@@ -161,18 +164,266 @@ pub fn find_import_declaration_node<'a>(
   None
 }
 
+/// Returns the package a class is imported from through a single-type import, e.g.
+/// `java.time` for `LocalDate` when the file has `import java.time.LocalDate;`.
+pub fn find_imported_class_package(ts_file: &TSFile, class_name: &str) -> Option<String> {
+  if ts_file.tree.is_none() || class_name.is_empty() {
+    return None;
+  }
+  for import_declaration_node in get_all_import_declaration_nodes(ts_file) {
+    let class_text = get_import_declaration_class_name_node(ts_file, import_declaration_node)
+      .and_then(|node| ts_file.get_text_from_node(&node));
+    if class_text != Some(class_name) {
+      continue;
+    }
+    if let Some(scope_node) =
+      get_import_declaration_relative_import_scope_node(ts_file, import_declaration_node)
+    {
+      return ts_file.get_text_from_node(&scope_node).map(|scope| scope.to_string());
+    }
+  }
+  None
+}
+
+const JAVA_LANG_TYPES: [&str; 17] = [
+  "Boolean",
+  "Byte",
+  "Character",
+  "Deprecated",
+  "Double",
+  "Float",
+  "FunctionalInterface",
+  "Integer",
+  "Long",
+  "Number",
+  "Object",
+  "Override",
+  "SafeVarargs",
+  "Short",
+  "String",
+  "SuppressWarnings",
+  "Void",
+];
+
+fn has_wildcard_import(ts_file: &TSFile) -> bool {
+  get_all_import_declaration_nodes(ts_file).iter().any(|import_declaration_node| {
+    let mut cursor = import_declaration_node.walk();
+    import_declaration_node.children(&mut cursor).any(|child| child.kind() == "asterisk")
+  })
+}
+
+/// Resolves a type name as written in the file to its fully qualified name, looking at the
+/// single-type imports, then `java.lang`, then the file's own package.
+///
+/// # Returns
+/// The fully qualified name, or `None` when the type may come from a wildcard import
+pub fn resolve_fully_qualified_type_name(ts_file: &TSFile, type_name: &str) -> Option<String> {
+  let type_name = type_name.split('<').next().unwrap_or(type_name).trim();
+  if type_name.is_empty() {
+    return None;
+  }
+  if type_name.contains('.') {
+    return Some(type_name.to_string());
+  }
+  if let Some(package_name) = find_imported_class_package(ts_file, type_name) {
+    return Some(format!("{}.{}", package_name, type_name));
+  }
+  if JAVA_LANG_TYPES.contains(&type_name) {
+    return Some(format!("java.lang.{}", type_name));
+  }
+  if has_wildcard_import(ts_file) {
+    return None;
+  }
+  let package_name = get_package_name(ts_file);
+  match package_name {
+    Some(package_name) => Some(format!("{}.{}", package_name, type_name)),
+    None => Some(type_name.to_string()),
+  }
+}
+
+/// JPA package used by the file: `javax.persistence` when it already imports from it (Java EE /
+/// Spring Boot 2 projects), `jakarta.persistence` otherwise.
+pub fn get_persistence_package(ts_file: &TSFile) -> &'static str {
+  let uses_javax = get_all_import_declaration_nodes(ts_file).into_iter().any(|node| {
+    ts_file.get_text_from_node(&node).is_some_and(|text| text.contains("javax.persistence"))
+  });
+  if uses_javax { "javax.persistence" } else { "jakarta.persistence" }
+}
+
+/// Element type of an array or varargs type (`byte[]` -> `byte`, `String...` -> `String`), which
+/// is the type to import.
+pub fn get_array_element_type(type_name: &str) -> &str {
+  let mut element_type = type_name.trim();
+  element_type = element_type.strip_suffix("...").unwrap_or(element_type).trim_end();
+  while let Some(without_brackets) =
+    element_type.strip_suffix(']').and_then(|rest| rest.trim_end().strip_suffix('['))
+  {
+    element_type = without_brackets.trim_end();
+  }
+  element_type
+}
+
+/// Static flag, imported name without whitespace and wildcard flag of an import declaration.
+pub fn get_import_declaration_key(
+  ts_file: &TSFile,
+  import_declaration_node: Node,
+) -> Option<(bool, String, bool)> {
+  let mut cursor = import_declaration_node.walk();
+  let children: Vec<Node> = import_declaration_node.children(&mut cursor).collect();
+  let is_static = children.iter().any(|child| child.kind() == "static");
+  let is_wildcard = children.iter().any(|child| child.kind() == "asterisk");
+  let name_node =
+    children.iter().find(|child| matches!(child.kind(), "scoped_identifier" | "identifier"))?;
+  let name: String =
+    ts_file.get_text_from_node(name_node)?.chars().filter(|c| !c.is_whitespace()).collect();
+  Some((is_static, name, is_wildcard))
+}
+
+/// Removes imports that are exact duplicates of an earlier import, and single-type imports covered
+/// by a wildcard import of the same package. Covered imports are kept when the file has several
+/// wildcard imports, as the explicit import may then be what resolves an ambiguous name.
+///
+/// # Returns
+/// The text of the removed imports, in source order
+pub fn remove_duplicate_imports(ts_file: &mut TSFile) -> Vec<String> {
+  if ts_file.tree.is_none() {
+    return Vec::new();
+  }
+  let removed_ranges: Vec<(usize, usize, String)> = {
+    let imports: Vec<(Node, (bool, String, bool))> = get_all_import_declaration_nodes(ts_file)
+      .into_iter()
+      .filter_map(|node| get_import_declaration_key(ts_file, node).map(|key| (node, key)))
+      .collect();
+    let wildcard_count = imports.iter().filter(|(_, (_, _, is_wildcard))| *is_wildcard).count();
+    let mut seen_keys = Vec::new();
+    let mut removed_ranges = Vec::new();
+    for (node, key) in &imports {
+      let (is_static, name, is_wildcard) = key;
+      let is_duplicate = seen_keys.contains(key);
+      let is_covered_by_wildcard = !is_wildcard
+        && wildcard_count == 1
+        && name.rsplit_once('.').is_some_and(|(scope, _)| {
+          imports.iter().any(|(_, (other_is_static, other_name, other_is_wildcard))| {
+            *other_is_wildcard && other_is_static == is_static && other_name == scope
+          })
+        });
+      if !is_duplicate && !is_covered_by_wildcard {
+        seen_keys.push(key.clone());
+        continue;
+      }
+      // Remove the whole line when the import is the only thing on it
+      let source_text = &ts_file.source_code;
+      let line_start_byte =
+        source_text[..node.start_byte()].rfind('\n').map(|pos| pos + 1).unwrap_or(0);
+      let line_end_byte = source_text[node.end_byte()..]
+        .find('\n')
+        .map(|pos| node.end_byte() + pos + 1)
+        .unwrap_or(source_text.len());
+      let is_alone_on_line = source_text[line_start_byte..node.start_byte()].trim().is_empty()
+        && source_text[node.end_byte()..line_end_byte].trim().is_empty();
+      let (start_byte, end_byte) = if is_alone_on_line {
+        (line_start_byte, line_end_byte)
+      } else {
+        (node.start_byte(), node.end_byte())
+      };
+      let import_text = ts_file.get_text_from_node(node).unwrap_or_default().to_string();
+      removed_ranges.push((start_byte, end_byte, import_text));
+    }
+    removed_ranges
+  };
+  // Remove from the end of the file so the earlier ranges stay valid
+  for (start_byte, end_byte, _) in removed_ranges.iter().rev() {
+    ts_file.replace_text_by_range(*start_byte, *end_byte, "");
+  }
+  removed_ranges.into_iter().map(|(_, _, import_text)| import_text).collect()
+}
+
+/// Checks whether `name` is referenced anywhere in the file outside of the import declarations,
+/// as a type, an annotation or a qualifier.
+fn is_name_referenced(ts_file: &TSFile, name: &str) -> bool {
+  let Some(tree) = ts_file.tree.as_ref() else {
+    return false;
+  };
+  let mut pending = vec![tree.root_node()];
+  while let Some(node) = pending.pop() {
+    if matches!(node.kind(), "import_declaration" | "package_declaration") {
+      continue;
+    }
+    if matches!(node.kind(), "identifier" | "type_identifier")
+      && ts_file.get_text_from_node(&node) == Some(name)
+    {
+      return true;
+    }
+    let mut cursor = node.walk();
+    pending.extend(node.named_children(&mut cursor));
+  }
+  false
+}
+
+/// Removes the single-type import of `class_name` when the class is no longer referenced in the
+/// file. Wildcard and static imports are left alone. Removing the last import also removes the
+/// blank line that separated the imports from the type declaration.
+///
+/// # Returns
+/// The text of the removed import, `None` when there was nothing to remove
+pub fn remove_unused_import(ts_file: &mut TSFile, class_name: &str) -> Option<String> {
+  if is_name_referenced(ts_file, class_name) {
+    return None;
+  }
+  let (start_byte, end_byte, import_text) = {
+    let import_nodes = get_all_import_declaration_nodes(ts_file);
+    let import_node = import_nodes.iter().copied().find(|node| {
+      get_import_declaration_key(ts_file, *node).is_some_and(|(is_static, name, is_wildcard)| {
+        !is_static && !is_wildcard && name.rsplit('.').next() == Some(class_name)
+      })
+    })?;
+    let source_text = &ts_file.source_code;
+    let line_start_byte =
+      source_text[..import_node.start_byte()].rfind('\n').map(|pos| pos + 1).unwrap_or(0);
+    let line_end_byte = source_text[import_node.end_byte()..]
+      .find('\n')
+      .map(|pos| import_node.end_byte() + pos + 1)
+      .unwrap_or(source_text.len());
+    let is_alone_on_line = source_text[line_start_byte..import_node.start_byte()].trim().is_empty()
+      && source_text[import_node.end_byte()..line_end_byte].trim().is_empty();
+    let import_text = ts_file.get_text_from_node(&import_node)?.to_string();
+    let blank_line_end_byte = source_text[line_end_byte..]
+      .find('\n')
+      .filter(|pos| source_text[line_end_byte..line_end_byte + pos].trim().is_empty())
+      .map(|pos| line_end_byte + pos + 1);
+    match (is_alone_on_line, blank_line_end_byte) {
+      (true, Some(blank_line_end_byte)) if import_nodes.len() == 1 => {
+        (line_start_byte, blank_line_end_byte, import_text)
+      }
+      (true, _) => (line_start_byte, line_end_byte, import_text),
+      (false, _) => (import_node.start_byte(), import_node.end_byte(), import_text),
+    }
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, "");
+  Some(import_text)
+}
+
+/// Adds a single-type import. Array and varargs types import their element type, and types of
+/// `java.lang` are skipped as they are always in scope. Duplicate imports already in the file are
+/// removed first, see [`remove_duplicate_imports`].
 pub fn add_import<'a>(
   ts_file: &'a mut TSFile,
   insertion_position: &ImportInsertionPosition,
   import_package_scope: &str,
   import_class: &str,
 ) -> Option<Node<'a>> {
+  let import_class = get_array_element_type(import_class);
+  if import_package_scope == "java.lang" {
+    return None;
+  }
   if ts_file.tree.is_none()
     || import_package_scope.trim().is_empty()
     || import_class.trim().is_empty()
   {
     return None;
   }
+  // Normalize hand-edited imports instead of preserving their duplication
+  remove_duplicate_imports(ts_file);
   // Don't add import if it already exists
   if find_import_declaration_node(ts_file, import_package_scope, import_class).is_some() {
     return None;
@@ -248,3 +499,17 @@ pub fn add_import<'a>(
   // Replace the entire file content with the new content
   ts_file.replace_text_by_byte_range(0, file_content.len(), &new_content)
 }
+
+/// Adds the imports of a class name to package map before the existing imports. Imports are added
+/// in a fixed order, so the same map always yields the same import block.
+pub fn add_imports(ts_file: &mut TSFile, import_map: &HashMap<String, String>) {
+  let mut imports: Vec<(&String, &String)> = import_map.iter().collect();
+  // Each import is placed before the previous one, insert in reverse to keep them sorted
+  imports.sort_by(|(a_class, a_package), (b_class, b_package)| {
+    (b_package, b_class).cmp(&(a_package, a_class))
+  });
+  let import_position = ImportInsertionPosition::BeforeFirstImport;
+  for (class_name, package_name) in imports {
+    add_import(ts_file, &import_position, package_name, class_name);
+  }
+}
@@ -81,3 +81,85 @@ pub fn get_interface_name_node<'a>(
   }
   None
 }
+
+/// Returns the type nodes listed in the interface's `extends` clause, e.g. the
+/// `JpaRepository<User, Long>` generic type of a Spring Data repository.
+pub fn get_interface_extended_type_nodes<'a>(
+  ts_file: &'a TSFile,
+  interface_declaration_node: Node<'a>,
+) -> Vec<Node<'a>> {
+  if ts_file.tree.is_none() || interface_declaration_node.kind() != "interface_declaration" {
+    return Vec::new();
+  }
+  let mut cursor = interface_declaration_node.walk();
+  let Some(extends_node) = interface_declaration_node
+    .named_children(&mut cursor)
+    .find(|child| child.kind() == "extends_interfaces")
+  else {
+    return Vec::new();
+  };
+  let mut extends_cursor = extends_node.walk();
+  let Some(type_list_node) =
+    extends_node.named_children(&mut extends_cursor).find(|child| child.kind() == "type_list")
+  else {
+    return Vec::new();
+  };
+  let mut type_list_cursor = type_list_node.walk();
+  type_list_node.named_children(&mut type_list_cursor).collect()
+}
+
+/// Returns the methods declared directly in the interface body.
+pub fn get_interface_method_declaration_nodes<'a>(
+  ts_file: &'a TSFile,
+  interface_declaration_node: Node<'a>,
+) -> Vec<Node<'a>> {
+  if ts_file.tree.is_none() || interface_declaration_node.kind() != "interface_declaration" {
+    return Vec::new();
+  }
+  let Some(body_node) = interface_declaration_node.child_by_field_name("body") else {
+    return Vec::new();
+  };
+  let mut cursor = body_node.walk();
+  body_node
+    .named_children(&mut cursor)
+    .filter(|child| child.kind() == "method_declaration")
+    .collect()
+}
+
+/// Adds an abstract method declaration (`declaration` holds the signature and the trailing `;`) at
+/// the end of the interface at `interface_declaration_byte_position`, separated from the previous
+/// member by a blank line.
+///
+/// # Returns
+/// The start byte of the inserted declaration
+pub fn add_interface_method_declaration(
+  ts_file: &mut TSFile,
+  interface_declaration_byte_position: usize,
+  declaration: &str,
+) -> Option<usize> {
+  ts_file.tree.as_ref()?;
+  let (start_byte, end_byte, text) = {
+    let mut node = ts_file.get_named_node_at_byte_position(interface_declaration_byte_position)?;
+    while node.kind() != "interface_declaration" {
+      node = node.parent()?;
+    }
+    let body_node = node.child_by_field_name("body")?;
+    let interface_indentation = ts_file.get_line_indentation(node.start_byte());
+    let mut cursor = body_node.walk();
+    match body_node.named_children(&mut cursor).last() {
+      Some(last_member) => {
+        let member_indentation = ts_file.get_line_indentation(last_member.start_byte());
+        let text = format!("\n\n{}{}", member_indentation, declaration);
+        (last_member.end_byte(), last_member.end_byte(), text)
+      }
+      None => {
+        let text =
+          format!("{{\n{}  {}\n{}}}", interface_indentation, declaration, interface_indentation);
+        (body_node.start_byte(), body_node.end_byte(), text)
+      }
+    }
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, &text);
+  let leading_text_len = text.len() - text.trim_start_matches(['{', '\n', ' ', '\t']).len();
+  Some(start_byte + leading_text_len)
+}
@@ -0,0 +1,478 @@
+#![allow(dead_code)]
+
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  extend_last_member_removal_range, get_class_member_indentation,
+};
+use crate::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+use crate::common::ts_file::TSFile;
+use crate::common::utils::case_util;
+use tree_sitter::Node;
+
+/// Returns the methods declared directly in the class body, excluding methods of nested types.
+pub fn get_own_method_declaration_nodes<'a>(
+  ts_file: &'a TSFile,
+  class_declaration_node: Node<'a>,
+) -> Vec<Node<'a>> {
+  if ts_file.tree.is_none() || class_declaration_node.kind() != "class_declaration" {
+    return Vec::new();
+  }
+  let Some(class_body_node) = class_declaration_node.child_by_field_name("body") else {
+    return Vec::new();
+  };
+  let mut cursor = class_body_node.walk();
+  class_body_node
+    .named_children(&mut cursor)
+    .filter(|child| child.kind() == "method_declaration")
+    .collect()
+}
+
+pub fn get_method_declaration_name<'a>(
+  ts_file: &'a TSFile,
+  method_declaration_node: Node<'a>,
+) -> Option<&'a str> {
+  let name_node = method_declaration_node.child_by_field_name("name")?;
+  ts_file.get_text_from_node(&name_node)
+}
+
+pub fn find_method_declaration_node_by_name<'a>(
+  ts_file: &'a TSFile,
+  class_declaration_node: Node<'a>,
+  method_name: &str,
+) -> Option<Node<'a>> {
+  get_own_method_declaration_nodes(ts_file, class_declaration_node)
+    .into_iter()
+    .find(|method| get_method_declaration_name(ts_file, *method) == Some(method_name))
+}
+
+/// Returns the fields among `field_names` that the method body reads, either directly (`lines`,
+/// `this.lines`, `other.lines`) or through their getter (`getLines()`), in `field_names` order.
+pub fn get_referenced_field_names<'a>(
+  ts_file: &TSFile,
+  method_declaration_node: Node,
+  field_names: &[&'a str],
+) -> Vec<&'a str> {
+  let Some(body_node) = method_declaration_node.child_by_field_name("body") else {
+    return Vec::new();
+  };
+  let mut identifiers = Vec::new();
+  let mut invoked_methods = Vec::new();
+  let mut pending = vec![body_node];
+  while let Some(node) = pending.pop() {
+    match node.kind() {
+      "identifier" => identifiers.extend(ts_file.get_text_from_node(&node)),
+      "method_invocation" => invoked_methods.extend(
+        node
+          .child_by_field_name("name")
+          .and_then(|name_node| ts_file.get_text_from_node(&name_node)),
+      ),
+      _ => {}
+    }
+    let mut cursor = node.walk();
+    pending.extend(node.named_children(&mut cursor));
+  }
+  field_names
+    .iter()
+    .filter(|field_name| {
+      identifiers.contains(field_name)
+        || invoked_methods.contains(&get_getter_name(field_name, "").as_str())
+    })
+    .copied()
+    .collect()
+}
+
+/// JavaBeans getter name: `isActive` for a primitive `boolean`, `getActive` otherwise.
+pub fn get_getter_name(field_name: &str, field_type: &str) -> String {
+  let prefix = if field_type == "boolean" { "is" } else { "get" };
+  format!("{}{}", prefix, case_util::to_pascal_case(field_name))
+}
+
+pub fn get_setter_name(field_name: &str) -> String {
+  format!("set{}", case_util::to_pascal_case(field_name))
+}
+
+fn build_accessor_texts(
+  field_type: &str,
+  field_name: &str,
+  visibility_modifier: JavaVisibilityModifier,
+) -> Vec<(String, Vec<String>)> {
+  let visibility = match visibility_modifier.has_keyword() {
+    true => format!("{} ", visibility_modifier.keyword()),
+    false => String::new(),
+  };
+  let getter_name = get_getter_name(field_name, field_type);
+  let setter_name = get_setter_name(field_name);
+  vec![
+    (
+      getter_name.clone(),
+      vec![
+        format!("{}{} {}() {{", visibility, field_type, getter_name),
+        format!("  return {};", field_name),
+        "}".to_string(),
+      ],
+    ),
+    (
+      setter_name.clone(),
+      vec![
+        format!("{}void {}({} {}) {{", visibility, setter_name, field_type, field_name),
+        format!("  this.{} = {};", field_name, field_name),
+        "}".to_string(),
+      ],
+    ),
+  ]
+}
+
+/// One level of indentation, as the difference between member and class indentation.
+fn get_indentation_unit(class_indentation: &str, member_indentation: &str) -> String {
+  member_indentation
+    .strip_prefix(class_indentation)
+    .filter(|unit| !unit.is_empty())
+    .unwrap_or("  ")
+    .to_string()
+}
+
+/// Inserts accessor texts at the end of the class body, skipping any accessor the class already
+/// declares.
+fn insert_accessor_texts(
+  ts_file: &mut TSFile,
+  class_declaration_byte_position: usize,
+  accessor_texts: Vec<(String, Vec<String>)>,
+) -> Option<Vec<String>> {
+  ts_file.tree.as_ref()?;
+  let (insert_byte, closing_brace_byte, text, added_accessors) = {
+    let mut node = ts_file.get_named_node_at_byte_position(class_declaration_byte_position)?;
+    while node.kind() != "class_declaration" {
+      node = node.parent()?;
+    }
+    let class_body_node = node.child_by_field_name("body")?;
+    let class_indentation = ts_file.get_line_indentation(node.start_byte());
+    let member_indentation = get_class_member_indentation(ts_file, node);
+    let indentation_unit = get_indentation_unit(&class_indentation, &member_indentation);
+    let mut text = String::new();
+    let mut added_accessors = Vec::new();
+    for (method_name, lines) in accessor_texts {
+      if find_method_declaration_node_by_name(ts_file, node, &method_name).is_some() {
+        continue;
+      }
+      text.push('\n');
+      for line in lines {
+        // Accessor texts are built with a two space body indentation
+        let line = match line.strip_prefix("  ") {
+          Some(statement) => format!("{}{}", indentation_unit, statement),
+          None => line,
+        };
+        text.push_str(&format!("\n{}{}", member_indentation, line));
+      }
+      added_accessors.push(method_name);
+    }
+    if added_accessors.is_empty() {
+      return Some(added_accessors);
+    }
+    // Insert before the closing brace, keeping it on its own line
+    let closing_brace_byte = class_body_node.end_byte() - 1;
+    let body_before_brace = &ts_file.source_code[..closing_brace_byte];
+    let insert_byte = body_before_brace.trim_end().len();
+    if body_before_brace[..insert_byte].ends_with('{') {
+      text.remove(0);
+    }
+    text.push_str(&format!("\n{}", class_indentation));
+    (insert_byte, closing_brace_byte, text, added_accessors)
+  };
+  ts_file.replace_text_by_range(insert_byte, closing_brace_byte, &text);
+  Some(added_accessors)
+}
+
+/// Adds a getter and a setter for a field at the end of the class body, skipping any accessor
+/// the class already declares.
+///
+/// # Returns
+/// The names of the accessors that were added
+pub fn add_accessor_methods(
+  ts_file: &mut TSFile,
+  class_declaration_byte_position: usize,
+  field_type: &str,
+  field_name: &str,
+  visibility_modifier: JavaVisibilityModifier,
+) -> Option<Vec<String>> {
+  let accessor_texts = build_accessor_texts(field_type, field_name, visibility_modifier);
+  insert_accessor_texts(ts_file, class_declaration_byte_position, accessor_texts)
+}
+
+/// Adds a method at the end of the class body. `lines` hold the method text, with statements
+/// indented by two spaces relative to the signature; they are re-indented to match the class.
+///
+/// # Returns
+/// Whether the method was added, `false` when the class already declares a method with that name
+pub fn add_method_declaration(
+  ts_file: &mut TSFile,
+  class_declaration_byte_position: usize,
+  method_name: &str,
+  lines: Vec<String>,
+) -> Option<bool> {
+  let method_texts = vec![(method_name.to_string(), lines)];
+  insert_accessor_texts(ts_file, class_declaration_byte_position, method_texts)
+    .map(|added_methods| !added_methods.is_empty())
+}
+
+/// Outcome of regenerating a single accessor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessorRegeneration {
+  /// The signature and body were rewritten in place
+  Replaced,
+  /// Only the signature was rewritten, the body holds a `// custom` marker and was kept
+  SignatureReplaced,
+  /// The accessor did not exist and was added at the end of the class body
+  Created,
+}
+
+fn get_method_parameter_nodes(method_declaration_node: Node) -> Vec<Node> {
+  let Some(parameters_node) = method_declaration_node.child_by_field_name("parameters") else {
+    return Vec::new();
+  };
+  let mut cursor = parameters_node.walk();
+  parameters_node
+    .named_children(&mut cursor)
+    .filter(|child| matches!(child.kind(), "formal_parameter" | "spread_parameter"))
+    .collect()
+}
+
+fn find_accessor_node<'a>(
+  ts_file: &'a TSFile,
+  class_declaration_node: Node<'a>,
+  method_names: &[String],
+  parameter_count: usize,
+) -> Option<Node<'a>> {
+  method_names.iter().find_map(|method_name| {
+    get_own_method_declaration_nodes(ts_file, class_declaration_node).into_iter().find(|method| {
+      get_method_declaration_name(ts_file, *method) == Some(method_name.as_str())
+        && get_method_parameter_nodes(*method).len() == parameter_count
+    })
+  })
+}
+
+/// Checks whether the method body holds a `// custom` line comment marking hand-written logic.
+fn has_custom_marker(ts_file: &TSFile, method_declaration_node: Node) -> bool {
+  let Some(body_node) = method_declaration_node.child_by_field_name("body") else {
+    return false;
+  };
+  let mut stack = vec![body_node];
+  while let Some(node) = stack.pop() {
+    if node.kind() == "line_comment"
+      && ts_file
+        .get_text_from_node(&node)
+        .is_some_and(|text| text.trim_start_matches('/').trim_start().starts_with("custom"))
+    {
+      return true;
+    }
+    let mut cursor = node.walk();
+    stack.extend(node.named_children(&mut cursor));
+  }
+  false
+}
+
+/// Rewrites an existing accessor from its return type up to the end of the method, keeping its
+/// modifiers, annotations and leading comments. Bodies holding a `// custom` marker are kept and
+/// only the signature is rewritten; `signature` receives the name of the existing parameter.
+fn replace_accessor_node(
+  ts_file: &mut TSFile,
+  method_byte_position: usize,
+  signature: impl Fn(Option<&str>) -> String,
+  body_statement: &str,
+) -> Option<AccessorRegeneration> {
+  let (start_byte, end_byte, text, regeneration) = {
+    let mut node = ts_file.get_named_node_at_byte_position(method_byte_position)?;
+    while node.kind() != "method_declaration" {
+      node = node.parent()?;
+    }
+    let start_node =
+      node.child_by_field_name("type_parameters").or(node.child_by_field_name("type"))?;
+    let parameter_name = get_method_parameter_nodes(node)
+      .first()
+      .and_then(|parameter| parameter.child_by_field_name("name"))
+      .and_then(|name_node| ts_file.get_text_from_node(&name_node));
+    if has_custom_marker(ts_file, node) {
+      let parameters_node = node.child_by_field_name("parameters")?;
+      let text = signature(parameter_name);
+      (
+        start_node.start_byte(),
+        parameters_node.end_byte(),
+        text,
+        AccessorRegeneration::SignatureReplaced,
+      )
+    } else {
+      let class_node = node.parent().and_then(|body| body.parent())?;
+      let member_indentation = ts_file.get_line_indentation(node.start_byte());
+      let class_indentation = ts_file.get_line_indentation(class_node.start_byte());
+      let indentation_unit = get_indentation_unit(&class_indentation, &member_indentation);
+      let text = format!(
+        "{} {{\n{}{}{}\n{}}}",
+        signature(None),
+        member_indentation,
+        indentation_unit,
+        body_statement,
+        member_indentation
+      );
+      (start_node.start_byte(), node.end_byte(), text, AccessorRegeneration::Replaced)
+    }
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, &text);
+  Some(regeneration)
+}
+
+/// Regenerates the getter and, unless `include_setter` is false, the setter of a field so they
+/// match its current type and name. Existing accessors are rewritten in place (a getter declared
+/// with the other `get`/`is` prefix is renamed), missing ones are added at the end of the class
+/// body with `visibility_modifier`.
+///
+/// # Returns
+/// The accessor names with how each one was regenerated, getter first
+pub fn regenerate_accessor_methods(
+  ts_file: &mut TSFile,
+  class_declaration_byte_position: usize,
+  field_type: &str,
+  field_name: &str,
+  include_setter: bool,
+  visibility_modifier: JavaVisibilityModifier,
+) -> Option<Vec<(String, AccessorRegeneration)>> {
+  ts_file.tree.as_ref()?;
+  let getter_name = get_getter_name(field_name, field_type);
+  let pascal_case_name = case_util::to_pascal_case(field_name);
+  let getter_names = vec![
+    getter_name.clone(),
+    format!("get{}", pascal_case_name),
+    format!("is{}", pascal_case_name),
+  ];
+  let setter_name = get_setter_name(field_name);
+  let mut accessors = vec![(getter_name.clone(), getter_names, 0)];
+  if include_setter {
+    accessors.push((setter_name.clone(), vec![setter_name.clone()], 1));
+  }
+  let mut regenerations = Vec::new();
+  let mut missing_accessor_names = Vec::new();
+  for (method_name, candidate_names, parameter_count) in accessors {
+    let method_byte_position = {
+      let mut node = ts_file.get_named_node_at_byte_position(class_declaration_byte_position)?;
+      while node.kind() != "class_declaration" {
+        node = node.parent()?;
+      }
+      find_accessor_node(ts_file, node, &candidate_names, parameter_count)
+        .map(|method| method.start_byte())
+    };
+    let Some(method_byte_position) = method_byte_position else {
+      missing_accessor_names.push(method_name);
+      continue;
+    };
+    let regeneration = if parameter_count == 0 {
+      let signature = |_: Option<&str>| format!("{} {}()", field_type, getter_name);
+      let body_statement = format!("return {};", field_name);
+      replace_accessor_node(ts_file, method_byte_position, signature, &body_statement)?
+    } else {
+      let signature = |parameter_name: Option<&str>| {
+        let parameter_name = parameter_name.unwrap_or(field_name);
+        format!("void {}({} {})", setter_name, field_type, parameter_name)
+      };
+      let body_statement = format!("this.{} = {};", field_name, field_name);
+      replace_accessor_node(ts_file, method_byte_position, signature, &body_statement)?
+    };
+    regenerations.push((method_name, regeneration));
+  }
+  let accessor_texts = build_accessor_texts(field_type, field_name, visibility_modifier)
+    .into_iter()
+    .filter(|(method_name, _)| missing_accessor_names.contains(method_name))
+    .collect();
+  for method_name in
+    insert_accessor_texts(ts_file, class_declaration_byte_position, accessor_texts)?
+  {
+    regenerations.push((method_name, AccessorRegeneration::Created));
+  }
+  Some(regenerations)
+}
+
+/// Checks whether the method is a plain setter of the field, that is a single parameter method
+/// whose body only assigns the parameter to the field (`this.name = name;` or `name = value;`).
+pub fn is_generated_setter(
+  ts_file: &TSFile,
+  method_declaration_node: Node,
+  field_name: &str,
+) -> bool {
+  let parameters = get_method_parameter_nodes(method_declaration_node);
+  let [parameter] = parameters.as_slice() else {
+    return false;
+  };
+  let parameter_name = parameter
+    .child_by_field_name("name")
+    .and_then(|name_node| ts_file.get_text_from_node(&name_node));
+  let Some(body_node) = method_declaration_node.child_by_field_name("body") else {
+    return false;
+  };
+  let mut cursor = body_node.walk();
+  let statements: Vec<Node> = body_node.named_children(&mut cursor).collect();
+  let [statement] = statements.as_slice() else {
+    return false;
+  };
+  let Some(assignment) =
+    statement.named_child(0).filter(|_| statement.kind() == "expression_statement")
+  else {
+    return false;
+  };
+  if assignment.kind() != "assignment_expression" {
+    return false;
+  }
+  let text_of = |field: &str| {
+    assignment.child_by_field_name(field).and_then(|node| ts_file.get_text_from_node(&node))
+  };
+  let assigns_field = matches!(
+    text_of("left"),
+    Some(left) if left == field_name || left == format!("this.{}", field_name)
+  );
+  assigns_field && text_of("right").is_some() && text_of("right") == parameter_name
+}
+
+/// Removes a method together with its attached comments, keeping a single blank line between
+/// the remaining members.
+pub fn remove_method_declaration(ts_file: &mut TSFile, method_byte_position: usize) -> bool {
+  if ts_file.tree.is_none() {
+    return false;
+  }
+  let (start_byte, end_byte) = {
+    let Some(mut node) = ts_file.get_named_node_at_byte_position(method_byte_position) else {
+      return false;
+    };
+    while node.kind() != "method_declaration" {
+      match node.parent() {
+        Some(parent) => node = parent,
+        None => return false,
+      }
+    }
+    let mut member_start_byte = node.start_byte();
+    let mut current = node;
+    while let Some(previous) = current.prev_named_sibling() {
+      if !matches!(previous.kind(), "block_comment" | "line_comment")
+        || previous.end_position().row + 1 < current.start_position().row
+      {
+        break;
+      }
+      member_start_byte = previous.start_byte();
+      current = previous;
+    }
+    let source_text = &ts_file.source_code;
+    let mut start_byte =
+      source_text[..member_start_byte].rfind('\n').map(|pos| pos + 1).unwrap_or(0);
+    let mut end_byte = source_text[node.end_byte()..]
+      .find('\n')
+      .map(|pos| node.end_byte() + pos + 1)
+      .unwrap_or(source_text.len());
+    if source_text[end_byte..].trim_start().starts_with('}') {
+      extend_last_member_removal_range(source_text, start_byte, end_byte)
+    } else {
+      if source_text[..start_byte].ends_with("\n\n") {
+        // Drop the blank line that separated the method from the previous member
+        start_byte -= 1;
+      } else if source_text[end_byte..].starts_with('\n') {
+        end_byte += 1;
+      }
+      (start_byte, end_byte)
+    }
+  };
+  ts_file.replace_text_by_range(start_byte, end_byte, "");
+  true
+}
@@ -0,0 +1,172 @@
+use crate::commands::java::treesitter::services::class_declaration_service::{
+  get_type_declaration_kind, get_type_declaration_name,
+};
+use crate::commands::java::treesitter::services::entity_mapping_service::get_simple_type_name;
+use crate::common::ts_file::TSFile;
+use tree_sitter::Node;
+
+/// Returns every method invocation in the file calling a method named `method_name`, in source
+/// order. Method references (`Type::method`) are not invocations and are not returned.
+pub fn get_method_invocation_nodes_by_name<'a>(
+  ts_file: &'a TSFile,
+  method_name: &str,
+) -> Vec<Node<'a>> {
+  let Some(tree) = ts_file.tree.as_ref() else {
+    return Vec::new();
+  };
+  let mut invocations = Vec::new();
+  let mut pending = vec![tree.root_node()];
+  while let Some(node) = pending.pop() {
+    if node.kind() == "method_invocation"
+      && node.child_by_field_name("name").and_then(|name| ts_file.get_text_from_node(&name))
+        == Some(method_name)
+    {
+      invocations.push(node);
+    }
+    let mut cursor = node.walk();
+    let mut children: Vec<Node> = node.named_children(&mut cursor).collect();
+    children.reverse();
+    pending.extend(children);
+  }
+  invocations
+}
+
+pub fn get_method_invocation_arguments_count(method_invocation_node: Node) -> usize {
+  method_invocation_node.child_by_field_name("arguments").map_or(0, |arguments| {
+    let mut cursor = arguments.walk();
+    arguments.named_children(&mut cursor).filter(|child| !child.is_extra()).count()
+  })
+}
+
+/// Name of the method or constructor whose body contains the node.
+pub fn get_enclosing_method_name<'a>(ts_file: &'a TSFile, node: Node<'a>) -> Option<&'a str> {
+  let mut current = node.parent();
+  while let Some(ancestor) = current {
+    if matches!(ancestor.kind(), "method_declaration" | "constructor_declaration") {
+      return ts_file.get_text_from_node(&ancestor.child_by_field_name("name")?);
+    }
+    current = ancestor.parent();
+  }
+  None
+}
+
+/// Names of the type declarations enclosing the node, innermost first.
+pub fn get_enclosing_type_names<'a>(ts_file: &'a TSFile, node: Node<'a>) -> Vec<&'a str> {
+  let mut type_names = Vec::new();
+  let mut current = node.parent();
+  while let Some(ancestor) = current {
+    if get_type_declaration_kind(ancestor).is_some()
+      && let Some(type_name) = get_type_declaration_name(ts_file, ancestor)
+    {
+      type_names.push(type_name);
+    }
+    current = ancestor.parent();
+  }
+  type_names
+}
+
+/// Declared type of a local variable, parameter or field named `declarator_name` among the
+/// named children of `scope_node`, only considering locals declared before `before_byte`.
+fn find_declared_type<'a>(
+  ts_file: &'a TSFile,
+  scope_node: Node<'a>,
+  declarator_name: &str,
+  before_byte: usize,
+) -> Option<&'a str> {
+  let mut cursor = scope_node.walk();
+  for child in scope_node.named_children(&mut cursor) {
+    let declares_name = |declaration: Node| {
+      let mut declaration_cursor = declaration.walk();
+      declaration.named_children(&mut declaration_cursor).any(|declarator| {
+        declarator.kind() == "variable_declarator"
+          && declarator.child_by_field_name("name").and_then(|n| ts_file.get_text_from_node(&n))
+            == Some(declarator_name)
+      })
+    };
+    let is_match = match child.kind() {
+      "local_variable_declaration" => child.start_byte() < before_byte && declares_name(child),
+      "field_declaration" => declares_name(child),
+      "formal_parameter" | "catch_formal_parameter" | "resource" => {
+        child.child_by_field_name("name").and_then(|n| ts_file.get_text_from_node(&n))
+          == Some(declarator_name)
+      }
+      _ => false,
+    };
+    if is_match {
+      return child.child_by_field_name("type").and_then(|n| ts_file.get_text_from_node(&n));
+    }
+  }
+  None
+}
+
+/// Declared type text of `identifier` in the innermost scope enclosing `node` that declares it.
+/// Fields are only considered when `include_fields` is set, otherwise the walk stops at the first
+/// class body.
+fn find_identifier_declaration_type<'a>(
+  ts_file: &'a TSFile,
+  node: Node<'a>,
+  identifier: &str,
+  include_fields: bool,
+) -> Option<&'a str> {
+  let mut current = node.parent();
+  while let Some(scope_node) = current {
+    let scope_nodes = match scope_node.kind() {
+      "method_declaration" | "constructor_declaration" | "lambda_expression" => {
+        scope_node.child_by_field_name("parameters").into_iter().collect()
+      }
+      "enhanced_for_statement" => {
+        if scope_node.child_by_field_name("name").and_then(|n| ts_file.get_text_from_node(&n))
+          == Some(identifier)
+        {
+          return scope_node
+            .child_by_field_name("type")
+            .and_then(|n| ts_file.get_text_from_node(&n));
+        }
+        Vec::new()
+      }
+      "catch_clause" => {
+        let mut cursor = scope_node.walk();
+        scope_node
+          .named_children(&mut cursor)
+          .filter(|c| c.kind() == "catch_formal_parameter")
+          .collect()
+      }
+      "try_with_resources_statement" => {
+        scope_node.child_by_field_name("resources").into_iter().collect()
+      }
+      "class_body" | "enum_body_declarations" if !include_fields => return None,
+      // The `init` declaration of a `for` loop is one of its own children
+      "block"
+      | "switch_block_statement_group"
+      | "class_body"
+      | "enum_body_declarations"
+      | "for_statement" => vec![scope_node],
+      _ => Vec::new(),
+    };
+    for scope in scope_nodes {
+      if let Some(type_text) = find_declared_type(ts_file, scope, identifier, node.start_byte()) {
+        return Some(type_text);
+      }
+    }
+    current = scope_node.parent();
+  }
+  None
+}
+
+/// Resolves the declared type of the variable, parameter or field `identifier` as seen from
+/// `node`, walking the enclosing scopes outwards.
+///
+/// # Returns
+/// The simple type name, `None` when the identifier isn't declared in the file or is declared
+/// with `var`
+pub fn resolve_identifier_type(ts_file: &TSFile, node: Node, identifier: &str) -> Option<String> {
+  find_identifier_declaration_type(ts_file, node, identifier, true)
+    .filter(|type_text| *type_text != "var")
+    .map(get_simple_type_name)
+}
+
+/// Whether `identifier` names a local variable or parameter in scope at `node`, shadowing any
+/// field of the same name.
+pub fn is_local_identifier(ts_file: &TSFile, node: Node, identifier: &str) -> bool {
+  find_identifier_declaration_type(ts_file, node, identifier, false).is_some()
+}
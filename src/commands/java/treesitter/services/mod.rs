@@ -1,9 +1,14 @@
 pub mod annotation_service;
 pub mod annotation_type_declaration_service;
+pub mod builder_declaration_service;
 pub mod class_declaration_service;
+pub mod constructor_declaration_service;
+pub mod entity_mapping_service;
 pub mod enum_declaration_service;
 pub mod field_declaration_service;
 pub mod import_declaration_service;
 pub mod interface_declaration_service;
+pub mod method_declaration_service;
+pub mod method_invocation_service;
 pub mod package_declaration_service;
 pub mod record_declaration_service;
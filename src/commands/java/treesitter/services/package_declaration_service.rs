@@ -170,3 +170,14 @@ pub fn get_package_scope_node<'a>(
     .ok()
     .flatten()
 }
+
+/// Retrieves the dotted package name declared in a Java source file.
+///
+/// # Returns
+/// * `Some(String)` - The package name, e.g. "com.example.myapp"
+/// * `None` - If the file has no package declaration
+pub fn get_package_name(ts_file: &TSFile) -> Option<String> {
+  let package_declaration_node = get_package_declaration_node(ts_file)?;
+  let package_scope_node = get_package_scope_node(ts_file, package_declaration_node)?;
+  ts_file.get_text_from_node(&package_scope_node).map(|name| name.to_string())
+}
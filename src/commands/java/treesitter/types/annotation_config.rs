@@ -1,6 +1,9 @@
 use crate::commands::java::treesitter::types::cascade_type::CascadeType;
 use crate::commands::java::treesitter::types::collection_type::CollectionType;
 use crate::commands::java::treesitter::types::fetch_type::FetchType;
+use crate::commands::java::treesitter::types::foreign_key_constraint::ForeignKeyConstraint;
+use crate::commands::java::treesitter::types::join_column_access::JoinColumnAccess;
+use crate::commands::java::treesitter::types::map_key_config::MapKeyConfig;
 use crate::commands::java::treesitter::types::other_type::OtherType;
 
 /// Common annotation configuration for JPA relationship services
@@ -21,10 +24,16 @@ pub struct AnnotationConfig {
   pub other_options: Vec<OtherType>,
   pub mapped_by_field: Option<String>,
   pub needs_join_column: bool,
+  /// `foreignKey` of the join column, `None` keeps the provider default
+  pub foreign_key: Option<ForeignKeyConstraint>,
+  /// `insertable` and `updatable` of the join column
+  pub join_column_access: JoinColumnAccess,
 
   // Optional fields for ManyToOne relationships
   pub fetch_type: Option<FetchType>,
   pub collection_type: Option<CollectionType>,
+  /// Key of a `Map` collection, only set on the collection side
+  pub map_key: Option<MapKeyConfig>,
 }
 
 impl AnnotationConfig {
@@ -42,8 +51,11 @@ impl AnnotationConfig {
       other_options,
       mapped_by_field,
       needs_join_column,
+      foreign_key: None,
+      join_column_access: JoinColumnAccess::default(),
       fetch_type: None,
       collection_type: None,
+      map_key: None,
     }
   }
 
@@ -63,11 +75,32 @@ impl AnnotationConfig {
       other_options,
       mapped_by_field,
       needs_join_column,
+      foreign_key: None,
+      join_column_access: JoinColumnAccess::default(),
       fetch_type: Some(fetch_type),
       collection_type: Some(collection_type),
+      map_key: None,
     }
   }
 
+  /// Set the foreign key constraint of the join column
+  pub fn with_foreign_key(mut self, foreign_key: Option<ForeignKeyConstraint>) -> Self {
+    self.foreign_key = foreign_key;
+    self
+  }
+
+  /// Set the `insertable` and `updatable` options of the join column
+  pub fn with_join_column_access(mut self, join_column_access: JoinColumnAccess) -> Self {
+    self.join_column_access = join_column_access;
+    self
+  }
+
+  /// Set the key of a `Map` collection
+  pub fn with_map_key(mut self, map_key: Option<MapKeyConfig>) -> Self {
+    self.map_key = map_key;
+    self
+  }
+
   /// Get the fetch type, or None if not applicable
   pub fn get_fetch_type(&self) -> Option<&FetchType> {
     self.fetch_type.as_ref()
@@ -1,20 +1,88 @@
 #![allow(dead_code)]
 
+use serde::Deserialize;
+
 use crate::commands::java::treesitter::types::{
-  java_field_temporal::JavaFieldTemporal, java_field_time_zone_storage::JavaFieldTimeZoneStorage,
+  fetch_type::FetchType, java_field_temporal::JavaFieldTemporal,
+  java_field_time_zone_storage::JavaFieldTimeZoneStorage, member_access_config::MemberAccessConfig,
+  reserved_word_check::ReservedWordCheck,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct BasicFieldConfig {
   pub field_name: String,
   pub field_type: String,
+  #[serde(default)]
   pub field_type_package_name: Option<String>,
+  #[serde(default)]
   pub field_length: Option<u16>,
+  #[serde(default)]
   pub field_precision: Option<u16>,
+  #[serde(default)]
   pub field_scale: Option<u16>,
+  /// Precision given to `BigDecimal` fields without their own, `None` leaves it unset
+  #[serde(default = "default_money_precision")]
+  pub money_precision: Option<u16>,
+  /// Scale given to `BigDecimal` fields without their own, `None` leaves it unset
+  #[serde(default = "default_money_scale")]
+  pub money_scale: Option<u16>,
+  #[serde(default)]
   pub field_temporal: Option<JavaFieldTemporal>,
+  #[serde(default)]
   pub field_timezone_storage: Option<JavaFieldTimeZoneStorage>,
+  #[serde(default)]
   pub field_unique: bool,
+  #[serde(default)]
   pub field_nullable: bool,
+  #[serde(default)]
   pub field_large_object: bool,
+  /// `@Basic(optional = ...)`, only emitted when false
+  #[serde(default = "default_field_optional")]
+  pub field_optional: bool,
+  /// `@Basic(fetch = ...)`, only emitted for lazy loading since eager is the default
+  #[serde(default)]
+  pub field_fetch_type: Option<FetchType>,
+  /// Also emit `@Size(max = ...)` matching the column length
+  #[serde(default)]
+  pub sync_length: bool,
+  /// Emit the default `length = 255` on `String` and `char[]` fields without an explicit length
+  #[serde(default)]
+  pub explicit_string_length: bool,
+  #[serde(default)]
+  pub access_config: MemberAccessConfig,
+  /// Warns about, or quotes, a column name that is a SQL reserved word
+  #[serde(default)]
+  pub reserved_word_check: ReservedWordCheck,
+}
+
+// Same defaults as the `create-jpa-entity-basic-field` flags
+fn default_money_precision() -> Option<u16> {
+  Some(19)
+}
+
+fn default_money_scale() -> Option<u16> {
+  Some(2)
+}
+
+fn default_field_optional() -> bool {
+  true
+}
+
+impl BasicFieldConfig {
+  /// Parses the config from JSON, using the same value names as the command line flags, e.g.
+  /// `{"fieldName": "street", "fieldType": "String", "fieldLength": 120}`. Basic fields never
+  /// carry `@Id`, so `"id": true` is rejected rather than reported as an unknown field.
+  pub fn from_json(value: &str) -> Result<Self, String> {
+    let json: serde_json::Value =
+      serde_json::from_str(value).map_err(|e| format!("Invalid basic field config JSON: {}", e))?;
+    if json.get("id").and_then(|id| id.as_bool()) == Some(true) {
+      let field_name = json.get("fieldName").and_then(|name| name.as_str()).unwrap_or_default();
+      return Err(format!(
+        "Field '{}' is requested as an @Id, which a basic field can't declare",
+        field_name
+      ));
+    }
+    serde_json::from_value(json).map_err(|e| format!("Invalid basic field config JSON: {}", e))
+  }
 }
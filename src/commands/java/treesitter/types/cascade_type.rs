@@ -1,6 +1,8 @@
 use clap::ValueEnum;
+use serde::Deserialize;
 
-#[derive(Debug, Clone, PartialEq, ValueEnum)]
+#[derive(Debug, Clone, PartialEq, ValueEnum, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum CascadeType {
   #[value(name = "all")]
   All,
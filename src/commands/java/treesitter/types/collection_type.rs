@@ -1,6 +1,8 @@
 use clap::ValueEnum;
+use serde::Deserialize;
 
-#[derive(Debug, Clone, PartialEq, ValueEnum)]
+#[derive(Debug, Clone, PartialEq, ValueEnum, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum CollectionType {
   #[value(name = "set")]
   Set,
@@ -8,6 +10,8 @@ pub enum CollectionType {
   List,
   #[value(name = "collection")]
   Collection,
+  #[value(name = "map")]
+  Map,
 }
 
 impl CollectionType {
@@ -16,6 +20,7 @@ impl CollectionType {
       "set" => Ok(CollectionType::Set),
       "list" => Ok(CollectionType::List),
       "collection" => Ok(CollectionType::Collection),
+      "map" => Ok(CollectionType::Map),
       _ => Err(format!("No matching enum member for value '{}'", value)),
     }
   }
@@ -25,6 +30,7 @@ impl CollectionType {
       CollectionType::Set => "Set",
       CollectionType::List => "List",
       CollectionType::Collection => "Collection",
+      CollectionType::Map => "Map",
     }
   }
 
@@ -33,6 +39,7 @@ impl CollectionType {
       CollectionType::Set => "java.util.Set",
       CollectionType::List => "java.util.List",
       CollectionType::Collection => "java.util.Collection",
+      CollectionType::Map => "java.util.Map",
     }
   }
 }
@@ -0,0 +1,116 @@
+#![allow(dead_code)]
+
+use crate::commands::java::treesitter::types::{
+  entity_side::EntitySide, relationship_kind::RelationshipKind,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MappedClassKind {
+  Entity,
+  MappedSuperclass,
+  Embeddable,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ColumnMapping {
+  pub field_name: String,
+  pub field_type: String,
+  pub column_name: String,
+  pub has_explicit_name: bool,
+  pub is_id: bool,
+  pub is_identity: bool,
+  pub is_version: bool,
+  pub nullable: bool,
+  pub unique: bool,
+  pub length: Option<u32>,
+  pub precision: Option<u32>,
+  pub scale: Option<u32>,
+  pub is_lob: bool,
+  pub enum_type: Option<String>,
+  pub temporal: Option<String>,
+  pub column_definition: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JoinColumnMapping {
+  pub field_name: String,
+  pub target_type: String,
+  pub column_name: String,
+  pub has_explicit_name: bool,
+  pub referenced_column_name: Option<String>,
+  pub nullable: bool,
+  pub unique: bool,
+  /// Constraint name from `foreignKey = @ForeignKey(name = "...")`
+  pub foreign_key_name: Option<String>,
+  /// Whether `foreignKey = @ForeignKey(ConstraintMode.NO_CONSTRAINT)` disables the constraint
+  pub no_constraint: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RelationshipFieldMapping {
+  pub field_name: String,
+  /// Declared type, e.g. `Set<Book>`
+  pub field_type: String,
+  /// Simple name of the related entity, from `targetEntity` or the element type
+  pub target_type: String,
+  pub kind: RelationshipKind,
+  /// Inverse when the relationship is mapped by a field of the target entity
+  pub side: EntitySide,
+  pub mapped_by: Option<String>,
+  pub is_collection: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct EmbeddedMapping {
+  pub field_name: String,
+  pub embeddable_type: String,
+  pub is_id: bool,
+  /// `@AttributeOverride` entries as (attribute name, column name)
+  pub attribute_overrides: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IdFieldMapping {
+  pub field_name: String,
+  pub field_type: String,
+  pub is_embedded_id: bool,
+  /// `@GeneratedValue` strategy constant, `AUTO` when the annotation has no strategy
+  pub generation_strategy: Option<String>,
+  /// `@GeneratedValue(generator = ...)`
+  pub generator_name: Option<String>,
+  /// `sequenceName` of the `@SequenceGenerator` declared on the field or its class
+  pub sequence_name: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EntityMapping {
+  pub kind: MappedClassKind,
+  pub class_name: String,
+  pub package_name: Option<String>,
+  pub file_path: Option<String>,
+  pub superclass_name: Option<String>,
+  pub table_name: String,
+  pub has_explicit_table_name: bool,
+  pub schema: Option<String>,
+  pub catalog: Option<String>,
+  /// `@Inheritance` strategy constant, `SINGLE_TABLE` when the annotation has no strategy
+  pub inheritance_strategy: Option<String>,
+  pub columns: Vec<ColumnMapping>,
+  pub join_columns: Vec<JoinColumnMapping>,
+  pub embedded: Vec<EmbeddedMapping>,
+  pub relationships: Vec<RelationshipFieldMapping>,
+}
+
+impl EntityMapping {
+  pub fn id_columns(&self) -> Vec<&ColumnMapping> {
+    self.columns.iter().filter(|column| column.is_id).collect()
+  }
+
+  /// Table name qualified with the schema when one is declared in `@Table`.
+  pub fn qualified_table_name(&self) -> String {
+    match &self.schema {
+      Some(schema) => format!("{}.{}", schema, self.table_name),
+      None => self.table_name.clone(),
+    }
+  }
+}
@@ -1,4 +1,7 @@
-#[derive(Debug, Clone, PartialEq)]
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub enum EntitySide {
   /// Owning side of the relationship - contains foreign key, defines relationship
   Owning,
@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 
-use crate::commands::java::treesitter::types::java_enum_type::JavaEnumType;
+use crate::commands::java::treesitter::types::{
+  java_enum_type::JavaEnumType, member_access_config::MemberAccessConfig,
+};
 
 #[derive(Debug, Clone)]
 pub struct EnumFieldConfig {
@@ -11,4 +13,7 @@ pub struct EnumFieldConfig {
   pub field_length: Option<u16>,
   pub field_nullable: bool,
   pub field_unique: bool,
+  /// Checks that the enum exists and is an `enum` when its package belongs to the project
+  pub validate_enum_type: bool,
+  pub access_config: MemberAccessConfig,
 }
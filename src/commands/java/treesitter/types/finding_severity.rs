@@ -0,0 +1,30 @@
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum FindingSeverity {
+  #[value(name = "info")]
+  Info,
+  #[value(name = "warning")]
+  Warning,
+  #[value(name = "error")]
+  Error,
+}
+
+impl FindingSeverity {
+  pub fn from_value(value: &str) -> Result<Self, String> {
+    match value {
+      "info" => Ok(FindingSeverity::Info),
+      "warning" => Ok(FindingSeverity::Warning),
+      "error" => Ok(FindingSeverity::Error),
+      _ => Err(format!("No matching enum member for value '{}'", value)),
+    }
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      FindingSeverity::Info => "info",
+      FindingSeverity::Warning => "warning",
+      FindingSeverity::Error => "error",
+    }
+  }
+}
@@ -0,0 +1,44 @@
+use crate::commands::java::validators::sql_identifier_validator::validate_sql_identifier;
+
+/// Foreign key constraint of a join column, rendered as `@JoinColumn(foreignKey = ...)`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForeignKeyConstraint {
+  /// `@ForeignKey(name = "...")`, a deterministic constraint name
+  Named(String),
+  /// `@ForeignKey(ConstraintMode.NO_CONSTRAINT)`, no constraint is created
+  NoConstraint,
+}
+
+impl ForeignKeyConstraint {
+  /// Builds the constraint from the `foreign_key_name` and `no_foreign_key_constraint` options of
+  /// the relationship configs, `None` keeping the provider default.
+  pub fn from_options(
+    foreign_key_name: Option<&str>,
+    no_foreign_key_constraint: bool,
+  ) -> Result<Option<Self>, String> {
+    match (foreign_key_name, no_foreign_key_constraint) {
+      (Some(_), true) => {
+        Err("A foreign key can't be both named and disabled with NO_CONSTRAINT".to_string())
+      }
+      (Some(name), false) => Ok(Some(Self::Named(validate_sql_identifier(name)?))),
+      (None, true) => Ok(Some(Self::NoConstraint)),
+      (None, false) => Ok(None),
+    }
+  }
+
+  /// Value of the `foreignKey` argument of `@JoinColumn`.
+  pub fn get_annotation_value(&self) -> String {
+    match self {
+      Self::Named(name) => format!("@ForeignKey(name = \"{}\")", name.trim_matches('"')),
+      Self::NoConstraint => "@ForeignKey(ConstraintMode.NO_CONSTRAINT)".to_string(),
+    }
+  }
+
+  /// Classes of `jakarta.persistence` the annotation value references.
+  pub fn get_imported_classes(&self) -> Vec<&'static str> {
+    match self {
+      Self::Named(_) => vec!["ForeignKey"],
+      Self::NoConstraint => vec!["ForeignKey", "ConstraintMode"],
+    }
+  }
+}
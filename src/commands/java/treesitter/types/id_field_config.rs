@@ -2,6 +2,7 @@
 
 use crate::commands::java::treesitter::types::{
   java_id_generation::JavaIdGeneration, java_id_generation_type::JavaIdGenerationType,
+  member_access_config::MemberAccessConfig,
 };
 
 #[derive(Debug, Clone)]
@@ -16,4 +17,5 @@ pub struct IdFieldConfig {
   pub field_initial_value: Option<i64>,
   pub field_allocation_size: Option<i64>,
   pub field_nullable: bool,
+  pub access_config: MemberAccessConfig,
 }
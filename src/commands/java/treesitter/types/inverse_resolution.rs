@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+/// How the inverse side of a relationship was found on the target entity.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InverseResolution {
+  /// The target field's `mappedBy` names the owning field
+  MappedBy,
+  /// The target field has no `mappedBy` but is the only association of the matching kind pointing
+  /// back at the entity
+  FieldType,
+}
@@ -1,11 +1,13 @@
 #![allow(dead_code)]
 
 use clap::ValueEnum;
+use serde::Deserialize;
 use std::fmt;
 use std::str::FromStr;
 
 /// Represents the temporal type of a field, such as date, time, or timestamp.
-#[derive(Debug, Clone, PartialEq, ValueEnum)]
+#[derive(Debug, Clone, PartialEq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum JavaFieldTemporal {
   #[value(name = "date")]
   Date,
@@ -1,11 +1,13 @@
 #![allow(dead_code)]
 
 use clap::ValueEnum;
+use serde::Deserialize;
 use std::fmt;
 use std::str::FromStr;
 
 /// Defines strategies for handling time zone information in date-time fields.
-#[derive(Debug, Clone, PartialEq, ValueEnum)]
+#[derive(Debug, Clone, PartialEq, ValueEnum, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum JavaFieldTimeZoneStorage {
   #[value(name = "native")]
   Native,
@@ -0,0 +1,68 @@
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::commands::java::treesitter::types::java_file_type::JavaFileType;
+
+/// Opinionated skeleton seeding the body of a new file instead of the empty declaration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum JavaFileTemplate {
+  /// Spring `@Configuration` class
+  #[value(name = "configuration")]
+  Configuration,
+  /// Spring `@Service` class
+  #[value(name = "service")]
+  Service,
+  /// Spring `@Component` class
+  #[value(name = "component")]
+  Component,
+  /// Spring `@RestController` class
+  #[value(name = "rest-controller")]
+  RestController,
+  /// JUnit 5 test class with a setup and a test method
+  #[value(name = "junit-test")]
+  JunitTest,
+}
+
+impl JavaFileTemplate {
+  pub fn get_name(&self) -> &'static str {
+    match self {
+      JavaFileTemplate::Configuration => "configuration",
+      JavaFileTemplate::Service => "service",
+      JavaFileTemplate::Component => "component",
+      JavaFileTemplate::RestController => "rest-controller",
+      JavaFileTemplate::JunitTest => "junit-test",
+    }
+  }
+
+  /// File type the template applies to.
+  pub fn get_file_type(&self) -> JavaFileType {
+    JavaFileType::Class
+  }
+
+  /// Annotations placed on the type declaration, as (package, simple name).
+  pub fn get_annotations(&self) -> Vec<(&'static str, &'static str)> {
+    match self {
+      JavaFileTemplate::Configuration => {
+        vec![("org.springframework.context.annotation", "Configuration")]
+      }
+      JavaFileTemplate::Service => vec![("org.springframework.stereotype", "Service")],
+      JavaFileTemplate::Component => vec![("org.springframework.stereotype", "Component")],
+      JavaFileTemplate::RestController => {
+        vec![("org.springframework.web.bind.annotation", "RestController")]
+      }
+      JavaFileTemplate::JunitTest => Vec::new(),
+    }
+  }
+
+  /// Members of the generated body and the imports they need, as (package, simple name).
+  pub fn get_members(&self) -> Option<(&'static str, Vec<(&'static str, &'static str)>)> {
+    match self {
+      JavaFileTemplate::JunitTest => Some((
+        "  @BeforeEach\n  void setUp() {}\n\n  @Test\n  void test() {}",
+        vec![("org.junit.jupiter.api", "BeforeEach"), ("org.junit.jupiter.api", "Test")],
+      )),
+      _ => None,
+    }
+  }
+}
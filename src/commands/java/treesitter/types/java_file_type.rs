@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 
+use crate::commands::java::treesitter::types::java_language_feature::JavaLanguageFeature;
 use crate::common::utils::case_util;
 use clap::ValueEnum;
 
@@ -22,6 +23,14 @@ pub enum JavaFileType {
 }
 
 impl JavaFileType {
+  /// Language feature the type declaration needs beyond Java 8, if any.
+  pub fn get_language_feature(&self) -> Option<JavaLanguageFeature> {
+    match self {
+      JavaFileType::Record => Some(JavaLanguageFeature::Record),
+      _ => None,
+    }
+  }
+
   /// Generate source content with package name and class name
   ///
   /// # Arguments
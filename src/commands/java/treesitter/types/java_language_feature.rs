@@ -0,0 +1,20 @@
+/// Syntax emitted by the generators that only compiles from a given Java version on.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JavaLanguageFeature {
+  Record,
+}
+
+impl JavaLanguageFeature {
+  pub fn description(&self) -> &'static str {
+    match self {
+      JavaLanguageFeature::Record => "Records",
+    }
+  }
+
+  /// First Java release where the feature is final, not a preview.
+  pub fn min_java_version(&self) -> u32 {
+    match self {
+      JavaLanguageFeature::Record => 16,
+    }
+  }
+}
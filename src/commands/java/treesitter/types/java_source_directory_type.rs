@@ -2,6 +2,8 @@ use std::path::{Path, PathBuf};
 
 use clap::ValueEnum;
 
+use crate::common::utils::package_path;
+
 #[derive(Debug, Clone, PartialEq, ValueEnum)]
 pub enum JavaSourceDirectoryType {
   #[value(name = "main")]
@@ -22,9 +24,10 @@ impl JavaSourceDirectoryType {
     }
   }
 
-  /// Get the Maven/Gradle standard directory structure path
+  /// Get the Maven/Gradle standard directory structure path, with the package directory laid out
+  /// by the current package path strategy
   pub fn get_full_path(&self, base_path: &Path, package_name: &str) -> PathBuf {
-    let package_path = package_name.replace('.', "/");
+    let package_path = package_path::to_relative_path(package_name);
     match self {
       JavaSourceDirectoryType::Main => base_path.join("src/main/java").join(&package_path),
       JavaSourceDirectoryType::Test => base_path.join("src/test/java").join(&package_path),
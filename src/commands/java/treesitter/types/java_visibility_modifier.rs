@@ -1,19 +1,46 @@
 #![allow(dead_code)]
 
+use clap::ValueEnum;
+use serde::Deserialize;
+
 /// Represents Java visibility modifiers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum JavaVisibilityModifier {
   /// Public visibility - accessible from anywhere
+  #[value(name = "public")]
   Public,
   /// Private visibility - accessible only within the same class
+  #[value(name = "private")]
   Private,
   /// Protected visibility - accessible within package and subclasses
+  #[value(name = "protected")]
   Protected,
   /// Package-private visibility - no explicit modifier keyword
+  #[value(name = "package-private")]
   PackagePrivate,
 }
 
 impl JavaVisibilityModifier {
+  pub fn from_value(value: &str) -> Result<Self, String> {
+    match value {
+      "public" => Ok(JavaVisibilityModifier::Public),
+      "private" => Ok(JavaVisibilityModifier::Private),
+      "protected" => Ok(JavaVisibilityModifier::Protected),
+      "package-private" => Ok(JavaVisibilityModifier::PackagePrivate),
+      _ => Err(format!("No matching enum member for value '{}'", value)),
+    }
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      JavaVisibilityModifier::Public => "public",
+      JavaVisibilityModifier::Private => "private",
+      JavaVisibilityModifier::Protected => "protected",
+      JavaVisibilityModifier::PackagePrivate => "package-private",
+    }
+  }
+
   /// Gets the Java keyword for this visibility modifier.
   /// Returns empty string for package-private.
   pub fn keyword(&self) -> &'static str {
@@ -29,14 +56,20 @@ impl JavaVisibilityModifier {
   pub fn has_keyword(&self) -> bool {
     !self.keyword().is_empty()
   }
+
+  /// Ranks the modifier from the most restrictive (`private`) to the most open (`public`).
+  pub fn access_level(&self) -> u8 {
+    match self {
+      JavaVisibilityModifier::Private => 0,
+      JavaVisibilityModifier::PackagePrivate => 1,
+      JavaVisibilityModifier::Protected => 2,
+      JavaVisibilityModifier::Public => 3,
+    }
+  }
 }
 
 impl std::fmt::Display for JavaVisibilityModifier {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    let s = match self.keyword() {
-      "" => "package-private",
-      kw => kw,
-    };
-    write!(f, "{}", s)
+    write!(f, "{}", self.as_str())
   }
 }
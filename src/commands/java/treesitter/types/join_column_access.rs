@@ -0,0 +1,39 @@
+/// `insertable` and `updatable` of a join column, `None` keeping the JPA default of `true`.
+///
+/// Turning both off makes the association read-only, which is how a second field maps a foreign
+/// key already written through another field.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JoinColumnAccess {
+  pub insertable: Option<bool>,
+  pub updatable: Option<bool>,
+}
+
+impl JoinColumnAccess {
+  /// Whether the join column is left out of inserts or updates.
+  pub fn restricts_writes(&self) -> bool {
+    self.insertable == Some(false) || self.updatable == Some(false)
+  }
+
+  /// Arguments of `@JoinColumn` for the options that were set.
+  pub fn get_arguments(&self) -> Vec<(&'static str, String)> {
+    [("insertable", self.insertable), ("updatable", self.updatable)]
+      .into_iter()
+      .filter_map(|(key, value)| value.map(|value| (key, value.to_string())))
+      .collect()
+  }
+
+  /// Warning for a restricted join column that no other field of the entity maps.
+  pub fn get_unwritable_warning(&self, column_name: &str) -> String {
+    let disabled: Vec<&str> = self
+      .get_arguments()
+      .into_iter()
+      .filter(|(_, value)| value == "false")
+      .map(|(key, _)| key)
+      .collect();
+    format!(
+      "Join column '{}' is not {} and no other field maps it, so the foreign key can't be written",
+      column_name,
+      disabled.join(" nor ")
+    )
+  }
+}
@@ -0,0 +1,48 @@
+use clap::ValueEnum;
+use serde::Deserialize;
+
+/// JPA entity lifecycle callback an entity listener method can be annotated with.
+#[derive(Debug, Clone, PartialEq, ValueEnum, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LifecycleCallbackType {
+  #[value(name = "pre_persist")]
+  PrePersist,
+  #[value(name = "post_persist")]
+  PostPersist,
+  #[value(name = "pre_update")]
+  PreUpdate,
+  #[value(name = "post_update")]
+  PostUpdate,
+  #[value(name = "pre_remove")]
+  PreRemove,
+  #[value(name = "post_remove")]
+  PostRemove,
+  #[value(name = "post_load")]
+  PostLoad,
+}
+
+impl LifecycleCallbackType {
+  pub fn annotation_name(&self) -> &'static str {
+    match self {
+      LifecycleCallbackType::PrePersist => "PrePersist",
+      LifecycleCallbackType::PostPersist => "PostPersist",
+      LifecycleCallbackType::PreUpdate => "PreUpdate",
+      LifecycleCallbackType::PostUpdate => "PostUpdate",
+      LifecycleCallbackType::PreRemove => "PreRemove",
+      LifecycleCallbackType::PostRemove => "PostRemove",
+      LifecycleCallbackType::PostLoad => "PostLoad",
+    }
+  }
+
+  pub fn method_name(&self) -> &'static str {
+    match self {
+      LifecycleCallbackType::PrePersist => "prePersist",
+      LifecycleCallbackType::PostPersist => "postPersist",
+      LifecycleCallbackType::PreUpdate => "preUpdate",
+      LifecycleCallbackType::PostUpdate => "postUpdate",
+      LifecycleCallbackType::PreRemove => "preRemove",
+      LifecycleCallbackType::PostRemove => "postRemove",
+      LifecycleCallbackType::PostLoad => "postLoad",
+    }
+  }
+}
@@ -0,0 +1,38 @@
+/// Kind of type keying a `Map`-valued relationship, which decides the annotation its key column is
+/// mapped with.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MapKeyKind {
+  /// Keyed by an entity, mapped with `@MapKeyJoinColumn`
+  Entity,
+  /// Keyed by an enum, mapped with `@MapKeyEnumerated` and `@MapKeyColumn`
+  Enum,
+  /// Keyed by a basic type, mapped with `@MapKeyColumn`
+  Basic,
+}
+
+#[derive(Debug, Clone)]
+pub struct MapKeyConfig {
+  pub key_type: String,
+  /// Package to import the key type from, `None` for `java.lang` types
+  pub key_type_package: Option<String>,
+  pub kind: MapKeyKind,
+  pub column_name: String,
+}
+
+impl MapKeyConfig {
+  pub fn get_column_annotation(&self) -> &'static str {
+    match self.kind {
+      MapKeyKind::Entity => "@MapKeyJoinColumn",
+      MapKeyKind::Enum | MapKeyKind::Basic => "@MapKeyColumn",
+    }
+  }
+
+  /// Persistence classes the key annotations need imported.
+  pub fn get_imported_classes(&self) -> Vec<&'static str> {
+    match self.kind {
+      MapKeyKind::Entity => vec!["MapKeyJoinColumn"],
+      MapKeyKind::Enum => vec!["MapKeyEnumerated", "EnumType", "MapKeyColumn"],
+      MapKeyKind::Basic => vec!["MapKeyColumn"],
+    }
+  }
+}
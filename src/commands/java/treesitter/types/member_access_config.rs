@@ -0,0 +1,42 @@
+use serde::Deserialize;
+
+use crate::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+
+/// Visibility of a generated field and of its optional getter/setter pair.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MemberAccessConfig {
+  pub field_visibility: JavaVisibilityModifier,
+  pub generate_accessors: bool,
+  pub accessor_visibility: JavaVisibilityModifier,
+}
+
+impl Default for MemberAccessConfig {
+  fn default() -> Self {
+    Self {
+      field_visibility: JavaVisibilityModifier::Private,
+      generate_accessors: false,
+      accessor_visibility: JavaVisibilityModifier::Public,
+    }
+  }
+}
+
+impl MemberAccessConfig {
+  /// Rejects accessor visibilities that defeat the purpose of generating accessors: a private
+  /// accessor, or one more restrictive than the field it exposes.
+  pub fn validate(&self) -> Result<(), String> {
+    if !self.generate_accessors {
+      return Ok(());
+    }
+    if self.accessor_visibility == JavaVisibilityModifier::Private {
+      return Err("Accessors can't be private, they would only be usable inside the class".into());
+    }
+    if self.accessor_visibility.access_level() < self.field_visibility.access_level() {
+      return Err(format!(
+        "Accessor visibility '{}' is more restrictive than field visibility '{}'",
+        self.accessor_visibility, self.field_visibility
+      ));
+    }
+    Ok(())
+  }
+}
@@ -3,23 +3,38 @@ pub mod annotation_types;
 pub mod basic_field_config;
 pub mod cascade_type;
 pub mod collection_type;
+pub mod entity_mapping;
 pub mod entity_side;
 pub mod enum_field_config;
 pub mod fetch_type;
+pub mod finding_severity;
+pub mod foreign_key_constraint;
 pub mod id_field_config;
 pub mod import_types;
+pub mod inverse_resolution;
 pub mod java_basic_types;
 pub mod java_enum_type;
 pub mod java_field_modifier;
 pub mod java_field_temporal;
 pub mod java_field_time_zone_storage;
+pub mod java_file_template;
 pub mod java_file_type;
 pub mod java_id_generation;
 pub mod java_id_generation_type;
+pub mod java_language_feature;
 pub mod java_source_directory_type;
 pub mod java_visibility_modifier;
+pub mod join_column_access;
+pub mod lifecycle_callback_type;
 pub mod many_to_one_field_config;
+pub mod map_key_config;
 pub mod mapping_type;
+pub mod member_access_config;
+pub mod namespace_mapping;
 pub mod one_to_one_field_config;
 pub mod other_type;
 pub mod processed_imports;
+pub mod relationship_kind;
+pub mod reserved_word_check;
+pub mod sql_dialect;
+pub mod version_field_config;
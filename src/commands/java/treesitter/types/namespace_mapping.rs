@@ -0,0 +1,78 @@
+#![allow(dead_code)]
+
+use crate::commands::java::validators::package_name_validator::validate_package_name;
+
+/// Package prefix rewritten from `from` to `to`, e.g. `javax.persistence` to
+/// `jakarta.persistence`. Sub-packages are rewritten as well.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamespaceMapping {
+  pub from: String,
+  pub to: String,
+}
+
+/// Java EE namespaces moved to Jakarta EE 9. Identity entries keep the packages that stayed in
+/// the JDK under a migrated prefix.
+const JAKARTA_NAMESPACE_MAPPINGS: [(&str, &str); 20] = [
+  ("javax.activation", "jakarta.activation"),
+  ("javax.annotation", "jakarta.annotation"),
+  ("javax.annotation.processing", "javax.annotation.processing"),
+  ("javax.batch", "jakarta.batch"),
+  ("javax.ejb", "jakarta.ejb"),
+  ("javax.el", "jakarta.el"),
+  ("javax.enterprise", "jakarta.enterprise"),
+  ("javax.faces", "jakarta.faces"),
+  ("javax.inject", "jakarta.inject"),
+  ("javax.interceptor", "jakarta.interceptor"),
+  ("javax.jms", "jakarta.jms"),
+  ("javax.json", "jakarta.json"),
+  ("javax.mail", "jakarta.mail"),
+  ("javax.persistence", "jakarta.persistence"),
+  ("javax.servlet", "jakarta.servlet"),
+  ("javax.transaction", "jakarta.transaction"),
+  ("javax.transaction.xa", "javax.transaction.xa"),
+  ("javax.validation", "jakarta.validation"),
+  ("javax.websocket", "jakarta.websocket"),
+  ("javax.ws.rs", "jakarta.ws.rs"),
+];
+
+impl NamespaceMapping {
+  pub fn new(from: &str, to: &str) -> Self {
+    Self { from: from.to_string(), to: to.to_string() }
+  }
+
+  pub fn jakarta_mappings() -> Vec<Self> {
+    JAKARTA_NAMESPACE_MAPPINGS.iter().map(|(from, to)| Self::new(from, to)).collect()
+  }
+
+  /// Parses a `from=to` pair such as `javax.xml.bind=jakarta.xml.bind`.
+  pub fn parse(value: &str) -> Result<Self, String> {
+    let (from, to) = value
+      .split_once('=')
+      .ok_or_else(|| format!("Invalid namespace mapping '{}', expected 'from=to'", value))?;
+    let from = validate_package_name(from.trim())?;
+    let to = validate_package_name(to.trim())?;
+    Ok(Self { from, to })
+  }
+
+  /// Checks whether `qualified_name` is the mapped package itself or lives under it.
+  pub fn matches(&self, qualified_name: &str) -> bool {
+    qualified_name
+      .strip_prefix(self.from.as_str())
+      .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+  }
+}
+
+/// Finds the mapping with the longest matching prefix. On equal prefixes the first mapping wins,
+/// so mappings listed first override later ones.
+pub fn find_namespace_mapping<'a>(
+  mappings: &'a [NamespaceMapping],
+  qualified_name: &str,
+) -> Option<&'a NamespaceMapping> {
+  mappings.iter().filter(|mapping| mapping.matches(qualified_name)).fold(
+    None,
+    |best: Option<&NamespaceMapping>, mapping| match best {
+      Some(best) if best.from.len() >= mapping.from.len() => Some(best),
+      _ => Some(mapping),
+    },
+  )
+}
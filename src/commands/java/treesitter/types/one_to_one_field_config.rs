@@ -1,13 +1,66 @@
+use serde::Deserialize;
+
 use crate::commands::java::treesitter::types::{
-  cascade_type::CascadeType, mapping_type::MappingType, other_type::OtherType,
+  cascade_type::CascadeType, foreign_key_constraint::ForeignKeyConstraint,
+  join_column_access::JoinColumnAccess, mapping_type::MappingType, other_type::OtherType,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct OneToOneFieldConfig {
   pub inverse_field_type: String,
+  #[serde(default)]
   pub mapping_type: Option<MappingType>,
+  #[serde(default)]
   pub owning_side_cascades: Vec<CascadeType>,
+  #[serde(default)]
   pub inverse_side_cascades: Vec<CascadeType>,
+  #[serde(default)]
   pub owning_side_other: Vec<OtherType>,
+  #[serde(default)]
   pub inverse_side_other: Vec<OtherType>,
+  /// Name of the join column's foreign key constraint
+  #[serde(default)]
+  pub foreign_key_name: Option<String>,
+  /// Disables the join column's foreign key with `ConstraintMode.NO_CONSTRAINT`
+  #[serde(default)]
+  pub no_foreign_key_constraint: bool,
+  /// Makes the owning side required, emitting `optional = false` together with the join column's
+  /// `nullable = false`
+  #[serde(default)]
+  pub required: bool,
+  /// `insertable` of the join column, `false` for a read-only association
+  #[serde(default)]
+  pub insertable: Option<bool>,
+  /// `updatable` of the join column, `false` for a read-only association
+  #[serde(default)]
+  pub updatable: Option<bool>,
+}
+
+impl OneToOneFieldConfig {
+  pub fn get_foreign_key(&self) -> Result<Option<ForeignKeyConstraint>, String> {
+    ForeignKeyConstraint::from_options(
+      self.foreign_key_name.as_deref(),
+      self.no_foreign_key_constraint,
+    )
+  }
+
+  pub fn get_join_column_access(&self) -> JoinColumnAccess {
+    JoinColumnAccess { insertable: self.insertable, updatable: self.updatable }
+  }
+
+  /// Other options of the owning side, plus `mandatory` when the relationship is required.
+  pub fn get_owning_side_other(&self) -> Vec<OtherType> {
+    let mut owning_side_other = self.owning_side_other.clone();
+    if self.required && !owning_side_other.contains(&OtherType::Mandatory) {
+      owning_side_other.push(OtherType::Mandatory);
+    }
+    owning_side_other
+  }
+
+  /// Parses the config from JSON, using the same value names as the command line flags, e.g.
+  /// `{"inverseFieldType": "Profile", "owningSideCascades": ["persist", "merge"]}`.
+  pub fn from_json(value: &str) -> Result<Self, String> {
+    serde_json::from_str(value).map_err(|e| format!("Invalid one-to-one config JSON: {}", e))
+  }
 }
@@ -0,0 +1,131 @@
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::commands::java::treesitter::types::{
+  cascade_type::CascadeType, entity_side::EntitySide, fetch_type::FetchType, other_type::OtherType,
+};
+
+/// JPA annotation a relationship field is mapped with. Holds the matrix of cascade and other
+/// options that are meaningful for each kind, so the services and the UI agree on what can be
+/// generated.
+#[derive(Debug, Clone, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "camelCase")]
+pub enum RelationshipKind {
+  #[value(name = "many_to_one")]
+  ManyToOne,
+  #[value(name = "one_to_many")]
+  OneToMany,
+  #[value(name = "one_to_one")]
+  OneToOne,
+  #[value(name = "many_to_many")]
+  ManyToMany,
+}
+
+impl RelationshipKind {
+  pub fn annotation_name(&self) -> &'static str {
+    match self {
+      RelationshipKind::ManyToOne => "ManyToOne",
+      RelationshipKind::OneToMany => "OneToMany",
+      RelationshipKind::OneToOne => "OneToOne",
+      RelationshipKind::ManyToMany => "ManyToMany",
+    }
+  }
+
+  /// Fetch type JPA uses when the annotation doesn't set one: single-valued associations are
+  /// eager, collections are lazy.
+  pub fn get_default_fetch_type(&self) -> FetchType {
+    match self {
+      RelationshipKind::ManyToOne | RelationshipKind::OneToOne => FetchType::Eager,
+      RelationshipKind::OneToMany | RelationshipKind::ManyToMany => FetchType::Lazy,
+    }
+  }
+
+  /// UML multiplicities of the relationship as (declaring entity, target entity), e.g. a
+  /// `@ManyToOne` is `("*", "1")`.
+  pub fn get_multiplicities(&self) -> (&'static str, &'static str) {
+    match self {
+      RelationshipKind::ManyToOne => ("*", "1"),
+      RelationshipKind::OneToMany => ("1", "*"),
+      RelationshipKind::OneToOne => ("1", "1"),
+      RelationshipKind::ManyToMany => ("*", "*"),
+    }
+  }
+
+  /// Kind of the field mapping the other side of a bidirectional relationship, e.g. the
+  /// `@OneToMany` collection mirroring a `@ManyToOne`.
+  pub fn get_inverse_kind(&self) -> RelationshipKind {
+    match self {
+      RelationshipKind::ManyToOne => RelationshipKind::OneToMany,
+      RelationshipKind::OneToMany => RelationshipKind::ManyToOne,
+      RelationshipKind::OneToOne => RelationshipKind::OneToOne,
+      RelationshipKind::ManyToMany => RelationshipKind::ManyToMany,
+    }
+  }
+
+  /// Cascade types allowed for the relationship. JPA only defines cascading removal for
+  /// `@OneToOne` and `@OneToMany`: on `@ManyToOne` and `@ManyToMany` it would delete rows still
+  /// referenced by others, so `REMOVE` (and `ALL`, which includes it) is rejected there.
+  pub fn get_allowed_cascades(&self) -> Vec<CascadeType> {
+    match self {
+      RelationshipKind::ManyToOne | RelationshipKind::ManyToMany => {
+        vec![CascadeType::Persist, CascadeType::Merge, CascadeType::Refresh, CascadeType::Detach]
+      }
+      RelationshipKind::OneToMany | RelationshipKind::OneToOne => vec![
+        CascadeType::All,
+        CascadeType::Persist,
+        CascadeType::Merge,
+        CascadeType::Remove,
+        CascadeType::Refresh,
+        CascadeType::Detach,
+      ],
+    }
+  }
+
+  /// Other options allowed for the relationship on the given side. `orphanRemoval` only exists on
+  /// `@OneToOne` and `@OneToMany`, while `optional` and the join column flags only apply to the
+  /// side that holds the relationship annotation with those attributes.
+  pub fn get_allowed_other_options(&self, side: &EntitySide) -> Vec<OtherType> {
+    match (self, side) {
+      (RelationshipKind::ManyToOne, _) => vec![OtherType::Mandatory, OtherType::Unique],
+      (RelationshipKind::OneToMany, _) => vec![OtherType::OrphanRemoval],
+      (RelationshipKind::ManyToMany, _) => Vec::new(),
+      (RelationshipKind::OneToOne, EntitySide::Owning) => {
+        vec![OtherType::Mandatory, OtherType::Unique, OtherType::OrphanRemoval]
+      }
+      (RelationshipKind::OneToOne, EntitySide::Inverse) => {
+        vec![OtherType::Mandatory, OtherType::OrphanRemoval]
+      }
+    }
+  }
+
+  /// Rejects cascade and other options that are illegal or meaningless for the relationship.
+  pub fn validate_options(
+    &self,
+    side: &EntitySide,
+    cascades: &[CascadeType],
+    other_options: &[OtherType],
+  ) -> Result<(), String> {
+    let allowed_cascades = self.get_allowed_cascades();
+    if let Some(cascade) = cascades.iter().find(|cascade| !allowed_cascades.contains(cascade)) {
+      return Err(format!(
+        "Cascade type '{}' is not allowed on @{}",
+        cascade.as_str(),
+        self.annotation_name()
+      ));
+    }
+    let allowed_other_options = self.get_allowed_other_options(side);
+    if let Some(other) = other_options.iter().find(|other| !allowed_other_options.contains(other)) {
+      let other_name = other.to_possible_value().map(|value| value.get_name().to_string());
+      return Err(format!(
+        "Option '{}' is not allowed on the {} side of @{}",
+        other_name.unwrap_or_default(),
+        match side {
+          EntitySide::Owning => "owning",
+          EntitySide::Inverse => "inverse",
+        },
+        self.annotation_name()
+      ));
+    }
+    Ok(())
+  }
+}
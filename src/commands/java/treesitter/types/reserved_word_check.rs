@@ -0,0 +1,59 @@
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::commands::java::treesitter::types::sql_dialect::SqlDialect;
+
+/// How table and column names that are SQL reserved words are handled by the generators.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct ReservedWordCheck {
+  /// Dialect whose keywords are checked, the keywords of every dialect when `None`
+  pub dialect: Option<SqlDialect>,
+  /// Quote reserved names (`@Table(name = "\"user\"")`) instead of only warning about them
+  pub quote_reserved: bool,
+}
+
+impl ReservedWordCheck {
+  pub fn is_reserved(&self, identifier: &str) -> bool {
+    match &self.dialect {
+      Some(dialect) => dialect.is_reserved_word(identifier),
+      None => {
+        SqlDialect::value_variants().iter().any(|dialect| dialect.is_reserved_word(identifier))
+      }
+    }
+  }
+
+  fn dialect_label(&self) -> &'static str {
+    self.dialect.as_ref().map(|dialect| dialect.as_str()).unwrap_or("a supported dialect")
+  }
+
+  /// Name to write in the mapping annotation, wrapped in escaped quotes when it is reserved and
+  /// quoting was requested.
+  pub fn resolve_name(&self, identifier: &str) -> String {
+    match self.quote_reserved && self.is_reserved(identifier) {
+      true => format!("\\\"{}\\\"", identifier),
+      false => identifier.to_string(),
+    }
+  }
+
+  /// Warning for a reserved name, `None` when the name is safe to use unquoted.
+  pub fn get_warning(&self, kind: &str, identifier: &str) -> Option<String> {
+    if !self.is_reserved(identifier) {
+      return None;
+    }
+    Some(match self.quote_reserved {
+      true => format!(
+        "RESERVED_SQL_WORD: {} name '{}' is a reserved word in {}, it was quoted",
+        kind,
+        identifier,
+        self.dialect_label()
+      ),
+      false => format!(
+        "RESERVED_SQL_WORD: {} name '{}' is a reserved word in {}, quote it or use --quote-reserved",
+        kind,
+        identifier,
+        self.dialect_label()
+      ),
+    })
+  }
+}
@@ -0,0 +1,589 @@
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::commands::java::treesitter::types::entity_mapping::ColumnMapping;
+
+/// Reserved in every supported dialect, mostly SQL standard keywords.
+const COMMON_RESERVED_WORDS: &[&str] = &[
+  "all",
+  "and",
+  "any",
+  "as",
+  "asc",
+  "between",
+  "by",
+  "case",
+  "check",
+  "column",
+  "constraint",
+  "create",
+  "cross",
+  "current_date",
+  "current_time",
+  "current_timestamp",
+  "current_user",
+  "default",
+  "delete",
+  "desc",
+  "distinct",
+  "drop",
+  "else",
+  "end",
+  "except",
+  "exists",
+  "false",
+  "fetch",
+  "for",
+  "foreign",
+  "from",
+  "full",
+  "grant",
+  "group",
+  "having",
+  "in",
+  "inner",
+  "insert",
+  "intersect",
+  "into",
+  "is",
+  "join",
+  "left",
+  "like",
+  "natural",
+  "not",
+  "null",
+  "of",
+  "on",
+  "or",
+  "order",
+  "outer",
+  "primary",
+  "references",
+  "right",
+  "select",
+  "session_user",
+  "set",
+  "some",
+  "table",
+  "then",
+  "to",
+  "true",
+  "union",
+  "unique",
+  "update",
+  "user",
+  "using",
+  "values",
+  "when",
+  "where",
+  "with",
+];
+
+const POSTGRESQL_RESERVED_WORDS: &[&str] = &[
+  "analyse",
+  "analyze",
+  "array",
+  "asymmetric",
+  "authorization",
+  "binary",
+  "both",
+  "cast",
+  "collate",
+  "concurrently",
+  "deferrable",
+  "do",
+  "freeze",
+  "ilike",
+  "initially",
+  "isnull",
+  "lateral",
+  "leading",
+  "limit",
+  "localtime",
+  "localtimestamp",
+  "notnull",
+  "offset",
+  "only",
+  "placing",
+  "returning",
+  "similar",
+  "symmetric",
+  "tablesample",
+  "trailing",
+  "variadic",
+  "verbose",
+  "window",
+];
+
+const MYSQL_RESERVED_WORDS: &[&str] = &[
+  "add",
+  "alter",
+  "condition",
+  "database",
+  "databases",
+  "dec",
+  "decimal",
+  "describe",
+  "div",
+  "double",
+  "explain",
+  "float",
+  "groups",
+  "index",
+  "int",
+  "integer",
+  "interval",
+  "key",
+  "keys",
+  "leave",
+  "limit",
+  "lock",
+  "long",
+  "loop",
+  "match",
+  "mod",
+  "option",
+  "range",
+  "rank",
+  "read",
+  "real",
+  "release",
+  "rename",
+  "repeat",
+  "replace",
+  "require",
+  "row",
+  "rows",
+  "schema",
+  "show",
+  "signal",
+  "trigger",
+  "usage",
+  "varchar",
+  "window",
+  "write",
+];
+
+const H2_RESERVED_WORDS: &[&str] = &[
+  "array",
+  "asymmetric",
+  "authorization",
+  "both",
+  "cast",
+  "day",
+  "groups",
+  "hour",
+  "if",
+  "ilike",
+  "interval",
+  "key",
+  "leading",
+  "limit",
+  "localtime",
+  "localtimestamp",
+  "minus",
+  "minute",
+  "month",
+  "offset",
+  "over",
+  "partition",
+  "qualify",
+  "range",
+  "regexp",
+  "row",
+  "rownum",
+  "rows",
+  "second",
+  "symmetric",
+  "system_user",
+  "top",
+  "trailing",
+  "unknown",
+  "value",
+  "window",
+  "year",
+];
+
+const ORACLE_RESERVED_WORDS: &[&str] = &[
+  "access",
+  "add",
+  "alter",
+  "audit",
+  "cluster",
+  "comment",
+  "compress",
+  "connect",
+  "date",
+  "exclusive",
+  "file",
+  "identified",
+  "immediate",
+  "increment",
+  "index",
+  "initial",
+  "level",
+  "lock",
+  "long",
+  "maxextents",
+  "minus",
+  "mode",
+  "modify",
+  "noaudit",
+  "nocompress",
+  "nowait",
+  "number",
+  "offline",
+  "online",
+  "option",
+  "pctfree",
+  "prior",
+  "privileges",
+  "public",
+  "raw",
+  "rename",
+  "resource",
+  "row",
+  "rowid",
+  "rownum",
+  "rows",
+  "session",
+  "share",
+  "size",
+  "start",
+  "successful",
+  "synonym",
+  "sysdate",
+  "trigger",
+  "uid",
+  "validate",
+  "varchar",
+  "varchar2",
+  "view",
+  "whenever",
+];
+
+const SQL_SERVER_RESERVED_WORDS: &[&str] = &[
+  "add",
+  "alter",
+  "backup",
+  "begin",
+  "break",
+  "browse",
+  "bulk",
+  "cascade",
+  "checkpoint",
+  "close",
+  "clustered",
+  "coalesce",
+  "collate",
+  "commit",
+  "compute",
+  "contains",
+  "continue",
+  "convert",
+  "cursor",
+  "database",
+  "deallocate",
+  "declare",
+  "deny",
+  "disk",
+  "distributed",
+  "double",
+  "dump",
+  "escape",
+  "exec",
+  "execute",
+  "exit",
+  "external",
+  "file",
+  "fillfactor",
+  "function",
+  "goto",
+  "holdlock",
+  "identity",
+  "if",
+  "index",
+  "key",
+  "kill",
+  "lineno",
+  "load",
+  "merge",
+  "national",
+  "nocheck",
+  "nonclustered",
+  "nullif",
+  "off",
+  "offsets",
+  "open",
+  "option",
+  "over",
+  "percent",
+  "pivot",
+  "plan",
+  "precision",
+  "print",
+  "proc",
+  "procedure",
+  "public",
+  "read",
+  "restore",
+  "restrict",
+  "return",
+  "revert",
+  "revoke",
+  "rollback",
+  "rowcount",
+  "rule",
+  "save",
+  "schema",
+  "setuser",
+  "shutdown",
+  "statistics",
+  "system_user",
+  "tablesample",
+  "textsize",
+  "top",
+  "tran",
+  "transaction",
+  "trigger",
+  "truncate",
+  "unpivot",
+  "use",
+  "varying",
+  "view",
+  "waitfor",
+  "while",
+];
+
+#[derive(Debug, Clone, PartialEq, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SqlDialect {
+  #[value(name = "postgresql")]
+  Postgresql,
+  #[value(name = "mysql")]
+  Mysql,
+  #[value(name = "h2")]
+  H2,
+  #[value(name = "oracle")]
+  Oracle,
+  #[value(name = "sql-server")]
+  SqlServer,
+}
+
+impl SqlDialect {
+  pub fn from_value(value: &str) -> Result<Self, String> {
+    match value {
+      "postgresql" => Ok(SqlDialect::Postgresql),
+      "mysql" => Ok(SqlDialect::Mysql),
+      "h2" => Ok(SqlDialect::H2),
+      "oracle" => Ok(SqlDialect::Oracle),
+      "sql-server" => Ok(SqlDialect::SqlServer),
+      _ => Err(format!("No matching enum member for value '{}'", value)),
+    }
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      SqlDialect::Postgresql => "postgresql",
+      SqlDialect::Mysql => "mysql",
+      SqlDialect::H2 => "h2",
+      SqlDialect::Oracle => "oracle",
+      SqlDialect::SqlServer => "sql-server",
+    }
+  }
+
+  fn dialect_reserved_words(&self) -> &'static [&'static str] {
+    match self {
+      SqlDialect::Postgresql => POSTGRESQL_RESERVED_WORDS,
+      SqlDialect::Mysql => MYSQL_RESERVED_WORDS,
+      SqlDialect::H2 => H2_RESERVED_WORDS,
+      SqlDialect::Oracle => ORACLE_RESERVED_WORDS,
+      SqlDialect::SqlServer => SQL_SERVER_RESERVED_WORDS,
+    }
+  }
+
+  /// Checks whether an unquoted table or column name is a reserved word of this dialect, which
+  /// breaks the generated DDL unless the name is quoted. Quoted names are never reserved.
+  pub fn is_reserved_word(&self, identifier: &str) -> bool {
+    if identifier.starts_with(['"', '\\', '`', '[']) {
+      return false;
+    }
+    let identifier = identifier.to_lowercase();
+    COMMON_RESERVED_WORDS.contains(&identifier.as_str())
+      || self.dialect_reserved_words().contains(&identifier.as_str())
+  }
+
+  /// Whether the dialect folds the case of unquoted names, to lower case for PostgreSQL and upper
+  /// case for Oracle and H2, so a mixed case name only keeps its case when quoted. MySQL and SQL
+  /// Server keep column names as written and compare them case-insensitively.
+  pub fn folds_unquoted_identifiers(&self) -> bool {
+    matches!(self, SqlDialect::Postgresql | SqlDialect::Oracle | SqlDialect::H2)
+  }
+
+  /// Suffix appended to an identity primary key column.
+  pub fn identity_clause(&self) -> &'static str {
+    match self {
+      SqlDialect::Mysql => "AUTO_INCREMENT",
+      SqlDialect::SqlServer => "IDENTITY(1,1)",
+      _ => "GENERATED BY DEFAULT AS IDENTITY",
+    }
+  }
+
+  fn varchar(&self, length: u32) -> String {
+    match self {
+      SqlDialect::Oracle => format!("VARCHAR2({} CHAR)", length),
+      SqlDialect::SqlServer => format!("NVARCHAR({})", length),
+      _ => format!("VARCHAR({})", length),
+    }
+  }
+
+  fn decimal(&self, precision: u32, scale: u32) -> String {
+    match self {
+      SqlDialect::Oracle => format!("NUMBER({},{})", precision, scale),
+      SqlDialect::Mysql => format!("DECIMAL({},{})", precision, scale),
+      _ => format!("NUMERIC({},{})", precision, scale),
+    }
+  }
+
+  fn timestamp(&self) -> &'static str {
+    match self {
+      SqlDialect::Mysql => "DATETIME(6)",
+      SqlDialect::SqlServer => "DATETIME2(6)",
+      _ => "TIMESTAMP(6)",
+    }
+  }
+
+  fn timestamp_with_time_zone(&self) -> &'static str {
+    match self {
+      SqlDialect::Mysql => "DATETIME(6)",
+      SqlDialect::SqlServer => "DATETIMEOFFSET(6)",
+      _ => "TIMESTAMP(6) WITH TIME ZONE",
+    }
+  }
+
+  fn text_lob(&self) -> &'static str {
+    match self {
+      SqlDialect::Postgresql => "TEXT",
+      SqlDialect::Mysql => "LONGTEXT",
+      SqlDialect::SqlServer => "NVARCHAR(MAX)",
+      _ => "CLOB",
+    }
+  }
+
+  fn binary_lob(&self) -> &'static str {
+    match self {
+      SqlDialect::Postgresql => "BYTEA",
+      SqlDialect::Mysql => "LONGBLOB",
+      SqlDialect::SqlServer => "VARBINARY(MAX)",
+      _ => "BLOB",
+    }
+  }
+
+  fn integer_type(&self, java_type: &str) -> Option<String> {
+    let sql_type = match (self, java_type) {
+      (SqlDialect::Oracle, "Long" | "long") => "NUMBER(19,0)",
+      (SqlDialect::Oracle, "Integer" | "int") => "NUMBER(10,0)",
+      (SqlDialect::Oracle, "Short" | "short" | "Byte" | "byte") => "NUMBER(5,0)",
+      (_, "Long" | "long") => "BIGINT",
+      (_, "Integer" | "int") => "INTEGER",
+      (SqlDialect::Mysql | SqlDialect::SqlServer, "Byte" | "byte") => "TINYINT",
+      (_, "Short" | "short" | "Byte" | "byte") => "SMALLINT",
+      _ => return None,
+    };
+    Some(sql_type.to_string())
+  }
+
+  /// Resolves the SQL column type of a mapped column for this dialect, following the defaults
+  /// Hibernate applies (`VARCHAR(255)` strings, `NUMERIC(38,2)` decimals, ordinal enums as small
+  /// integers). An explicit `columnDefinition` always wins.
+  ///
+  /// # Returns
+  /// The SQL type, or an error describing why the Java type can't be mapped
+  pub fn sql_type(&self, column: &ColumnMapping) -> Result<String, String> {
+    if let Some(column_definition) = &column.column_definition {
+      return Ok(column_definition.clone());
+    }
+    let length = column.length.unwrap_or(255);
+    if let Some(enum_type) = &column.enum_type {
+      return Ok(match enum_type.as_str() {
+        "STRING" => self.varchar(length),
+        _ => self.integer_type("short").unwrap_or_else(|| "SMALLINT".to_string()),
+      });
+    }
+    let java_type = column.field_type.as_str();
+    if let Some(sql_type) = self.integer_type(java_type) {
+      return Ok(sql_type);
+    }
+    let sql_type = match java_type {
+      "String" if column.is_lob => self.text_lob().to_string(),
+      "String" => self.varchar(length),
+      "char" | "Character" => match self {
+        SqlDialect::SqlServer => "NCHAR(1)".to_string(),
+        _ => "CHAR(1)".to_string(),
+      },
+      "char[]" | "Character[]" => match column.is_lob {
+        true => self.text_lob().to_string(),
+        false => self.varchar(length),
+      },
+      "byte[]" | "Byte[]" if column.is_lob => self.binary_lob().to_string(),
+      "byte[]" | "Byte[]" => match self {
+        SqlDialect::Postgresql => "BYTEA".to_string(),
+        SqlDialect::Oracle => format!("RAW({})", length),
+        _ => format!("VARBINARY({})", length),
+      },
+      "Blob" => self.binary_lob().to_string(),
+      "Clob" => self.text_lob().to_string(),
+      "Boolean" | "boolean" => match self {
+        SqlDialect::Mysql | SqlDialect::SqlServer => "BIT".to_string(),
+        SqlDialect::Oracle => "NUMBER(1,0)".to_string(),
+        _ => "BOOLEAN".to_string(),
+      },
+      "Double" | "double" => match self {
+        SqlDialect::Postgresql => "DOUBLE PRECISION".to_string(),
+        SqlDialect::Oracle => "BINARY_DOUBLE".to_string(),
+        SqlDialect::SqlServer => "FLOAT(53)".to_string(),
+        _ => "DOUBLE".to_string(),
+      },
+      "Float" | "float" => match self {
+        SqlDialect::Oracle => "BINARY_FLOAT".to_string(),
+        SqlDialect::Mysql => "FLOAT".to_string(),
+        _ => "REAL".to_string(),
+      },
+      "BigDecimal" => self.decimal(column.precision.unwrap_or(38), column.scale.unwrap_or(2)),
+      "BigInteger" => self.decimal(column.precision.unwrap_or(38), 0),
+      "LocalDate" => "DATE".to_string(),
+      "LocalTime" | "Time" => "TIME".to_string(),
+      "LocalDateTime" | "Instant" | "Timestamp" => self.timestamp().to_string(),
+      "OffsetDateTime" | "ZonedDateTime" => self.timestamp_with_time_zone().to_string(),
+      "OffsetTime" => match self {
+        SqlDialect::Postgresql | SqlDialect::H2 => "TIME WITH TIME ZONE".to_string(),
+        _ => "TIME".to_string(),
+      },
+      "Date" | "Calendar" => match column.temporal.as_deref() {
+        Some("DATE") => "DATE".to_string(),
+        Some("TIME") => "TIME".to_string(),
+        _ => self.timestamp().to_string(),
+      },
+      "Duration" => match self {
+        SqlDialect::Postgresql => "INTERVAL SECOND(6)".to_string(),
+        SqlDialect::Oracle => "INTERVAL DAY TO SECOND".to_string(),
+        _ => self.decimal(21, 0),
+      },
+      "UUID" => match self {
+        SqlDialect::Postgresql | SqlDialect::H2 => "UUID".to_string(),
+        SqlDialect::SqlServer => "UNIQUEIDENTIFIER".to_string(),
+        SqlDialect::Oracle => "RAW(16)".to_string(),
+        SqlDialect::Mysql => "BINARY(16)".to_string(),
+      },
+      _ => {
+        return Err(format!(
+          "Field '{}' of type '{}' can't be mapped to a {} column type",
+          column.field_name,
+          column.field_type,
+          self.as_str()
+        ));
+      }
+    };
+    Ok(sql_type)
+  }
+}
@@ -0,0 +1,11 @@
+use crate::commands::java::treesitter::types::member_access_config::MemberAccessConfig;
+
+#[derive(Debug, Clone)]
+pub struct VersionFieldConfig {
+  pub field_name: String,
+  pub field_type: String,
+  /// Existing optimistic-lock column to map, the snake_case field name when unset
+  pub column_name: Option<String>,
+  pub column_nullable: bool,
+  pub access_config: MemberAccessConfig,
+}
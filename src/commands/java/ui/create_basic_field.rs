@@ -13,9 +13,15 @@ use std::path::PathBuf;
 use crate::commands::java::treesitter::types::java_basic_types::JavaBasicType;
 use crate::commands::java::treesitter::types::java_field_temporal::JavaFieldTemporal;
 use crate::commands::java::treesitter::types::java_field_time_zone_storage::JavaFieldTimeZoneStorage;
+use crate::commands::java::treesitter::types::java_file_type::JavaFileType;
+use crate::commands::java::treesitter::types::member_access_config::MemberAccessConfig;
+use crate::commands::java::treesitter::types::reserved_word_check::ReservedWordCheck;
 use crate::commands::java::{
   create_jpa_entity_basic_field_command, treesitter::types::basic_field_config::BasicFieldConfig,
 };
+use crate::commands::java::{
+  get_all_jpa_entities_command, get_java_files_command, responses::file_response::FileResponse,
+};
 use crate::commands::java::{
   get_java_basic_types_command, responses::basic_java_type_response::JavaBasicTypeResponse,
 };
@@ -25,6 +31,7 @@ use crate::common::ui::form_trait::{FormBehavior, FormState, InputMode, helpers}
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum FocusedField {
   FieldType,
+  CustomType,
   FieldName,
   FieldLength,
   TimeZoneStorage,
@@ -44,6 +51,7 @@ pub struct CreateBasicFieldForm {
   field_type_index: usize,
   field_package_path: Option<String>,
   field_type: String,
+  custom_type: String,
   field_name: String,
   field_length: String,
   field_precision: String,
@@ -63,6 +71,8 @@ pub struct CreateBasicFieldForm {
   types_with_temporal: Vec<String>,
   types_with_extra_other: Vec<String>,
   types_with_precision_and_scale: Vec<String>,
+  project_enum_types: Vec<String>,
+  project_entity_types: Vec<String>,
 
   // List states
   field_type_state: ListState,
@@ -71,12 +81,14 @@ pub struct CreateBasicFieldForm {
   other_options_state: ListState,
 
   // Text input states
+  custom_type_cursor: usize,
   field_name_cursor: usize,
   field_length_cursor: usize,
   field_precision_cursor: usize,
   field_scale_cursor: usize,
 
   // Visibility flags
+  custom_type_hidden: bool,
   field_length_hidden: bool,
   field_temporal_hidden: bool,
   field_time_zone_storage_hidden: bool,
@@ -109,12 +121,22 @@ impl CreateBasicFieldForm {
     };
 
     // If still no types (shouldn't happen with defaults), use defaults as last resort
-    let type_data =
+    let mut type_data =
       if type_data.all_types.is_empty() { Self::get_default_type_data() } else { type_data };
 
     // ASSERTION: Verify we have types
     assert!(!type_data.all_types.is_empty(), "type_data.all_types must not be empty!");
 
+    // Enums and entities of the project are offered after the basic types
+    let project_enum_types = Self::append_project_types(
+      &mut type_data.all_types,
+      get_java_files_command::execute(&cwd, &JavaFileType::Enum, None, None).data.map(|d| d.files),
+    );
+    let project_entity_types = Self::append_project_types(
+      &mut type_data.all_types,
+      get_all_jpa_entities_command::execute(&cwd, None, false).data.map(|d| d.files),
+    );
+
     let mut field_type_state = ListState::default();
     field_type_state.select(Some(0));
 
@@ -139,6 +161,7 @@ impl CreateBasicFieldForm {
       field_type_index: 0,
       field_package_path: default_package,
       field_type: default_type,
+      custom_type: String::new(),
       field_name: String::new(),
       field_length: "255".to_string(),
       field_precision: "19".to_string(),
@@ -154,14 +177,18 @@ impl CreateBasicFieldForm {
       types_with_temporal: type_data.types_with_temporal,
       types_with_extra_other: type_data.types_with_extra_other,
       types_with_precision_and_scale: type_data.types_with_precision_and_scale,
+      project_enum_types,
+      project_entity_types,
       field_type_state,
       time_zone_storage_state,
       temporal_state,
       other_options_state,
+      custom_type_cursor: 0,
       field_name_cursor: 0,
       field_length_cursor: 3,
       field_precision_cursor: 2,
       field_scale_cursor: 1,
+      custom_type_hidden: true,
       field_length_hidden: false,
       field_temporal_hidden: true,
       field_time_zone_storage_hidden: true,
@@ -293,8 +320,66 @@ impl CreateBasicFieldForm {
     Ok(type_data)
   }
 
+  /// Append the discovered project files to the type list
+  ///
+  /// # Returns
+  /// The ids of the appended types
+  fn append_project_types(
+    all_types: &mut Vec<JavaBasicTypeResponse>,
+    files: Option<Vec<FileResponse>>,
+  ) -> Vec<String> {
+    let mut ids = Vec::new();
+    for file in files.unwrap_or_default() {
+      let id = format!("{}.{}", file.file_package_name, file.file_type);
+      if all_types.iter().any(|type_info| type_info.id == id) {
+        continue;
+      }
+      ids.push(id.clone());
+      all_types.push(JavaBasicTypeResponse {
+        id,
+        name: file.file_type,
+        package_path: Some(file.file_package_name),
+      });
+    }
+    ids
+  }
+
+  /// Check if the "Other" entry, after all listed types, is selected
+  fn is_custom_type_selected(&self) -> bool {
+    self.field_type_state.selected() == Some(self.all_types.len())
+  }
+
+  /// Split the typed type into the simple name and the package to import it from
+  fn update_custom_type(&mut self) {
+    let custom_type = self.custom_type.trim();
+    match custom_type.rsplit_once('.') {
+      Some((package_path, name)) => {
+        self.field_type = name.to_string();
+        self.field_package_path = Some(package_path.to_string());
+      }
+      None => {
+        self.field_type = custom_type.to_string();
+        self.field_package_path = None;
+      }
+    }
+  }
+
   /// Update field type and related visibility flags
   fn update_field_type(&mut self) {
+    self.custom_type_hidden = !self.is_custom_type_selected();
+    if !self.custom_type_hidden {
+      // External types get no type specific column options
+      self.field_type_index = self.all_types.len();
+      self.update_custom_type();
+      self.field_length_hidden = true;
+      self.field_time_zone_storage_hidden = true;
+      self.field_temporal_hidden = true;
+      self.other_hidden = false;
+      self.other_extra_hidden = true;
+      self.field_scale_hidden = true;
+      self.field_precision_hidden = true;
+      return;
+    }
     if let Some(idx) = self.field_type_state.selected()
       && let Some(type_info) = self.all_types.get(idx)
     {
@@ -329,7 +414,8 @@ impl CreateBasicFieldForm {
 
     loop {
       self.focused_field = match self.focused_field {
-        FocusedField::FieldType => FocusedField::FieldName,
+        FocusedField::FieldType => FocusedField::CustomType,
+        FocusedField::CustomType => FocusedField::FieldName,
         FocusedField::FieldName => FocusedField::FieldLength,
         FocusedField::FieldLength => FocusedField::TimeZoneStorage,
         FocusedField::TimeZoneStorage => FocusedField::Temporal,
@@ -355,7 +441,8 @@ impl CreateBasicFieldForm {
     loop {
       self.focused_field = match self.focused_field {
         FocusedField::FieldType => FocusedField::ConfirmButton,
-        FocusedField::FieldName => FocusedField::FieldType,
+        FocusedField::CustomType => FocusedField::FieldType,
+        FocusedField::FieldName => FocusedField::CustomType,
         FocusedField::FieldLength => FocusedField::FieldName,
         FocusedField::TimeZoneStorage => FocusedField::FieldLength,
         FocusedField::Temporal => FocusedField::TimeZoneStorage,
@@ -375,6 +462,7 @@ impl CreateBasicFieldForm {
   /// Check if a field is hidden
   fn is_field_hidden(&self, field: FocusedField) -> bool {
     match field {
+      FocusedField::CustomType => self.custom_type_hidden,
       FocusedField::FieldLength => self.field_length_hidden,
       FocusedField::TimeZoneStorage => self.field_time_zone_storage_hidden,
       FocusedField::Temporal => self.field_temporal_hidden,
@@ -388,6 +476,9 @@ impl CreateBasicFieldForm {
   fn on_enter_insert_mode(&mut self, key: KeyCode) {
     if key == KeyCode::Char('a') {
       match self.focused_field {
+        FocusedField::CustomType => {
+          self.custom_type_cursor = self.custom_type.len();
+        }
         FocusedField::FieldName => {
           self.field_name_cursor = self.field_name.len();
         }
@@ -428,6 +519,15 @@ impl CreateBasicFieldForm {
   fn handle_field_insert(&mut self, key: KeyCode) {
     match self.focused_field {
       FocusedField::FieldType => self.handle_field_type_insert(key),
+      FocusedField::CustomType => {
+        helpers::handle_text_input(
+          key,
+          &mut self.custom_type,
+          &mut self.custom_type_cursor,
+          &mut self.state.input_mode,
+        );
+        self.update_custom_type();
+      }
       FocusedField::FieldName => self.handle_field_name_input(key),
       FocusedField::FieldLength => self.handle_field_length_input(key),
       FocusedField::TimeZoneStorage => self.handle_time_zone_storage_insert(key),
@@ -456,12 +556,13 @@ impl CreateBasicFieldForm {
   fn handle_field_type_insert(&mut self, key: KeyCode) {
     match key {
       KeyCode::Char('j') | KeyCode::Down => {
-        let len = self.all_types.len();
+        // The extra entry is the free text "Other" type
+        let len = self.all_types.len() + 1;
         helpers::navigate_list_static(&KeyCode::Down, &mut self.field_type_state, len);
         self.update_field_type();
       }
       KeyCode::Char('k') | KeyCode::Up => {
-        let len = self.all_types.len();
+        let len = self.all_types.len() + 1;
         helpers::navigate_list_static(&KeyCode::Up, &mut self.field_type_state, len);
         self.update_field_type();
       }
@@ -684,6 +785,11 @@ impl CreateBasicFieldForm {
   }
 
   fn execute_create_basic_field(&mut self) {
+    if self.field_type.is_empty() {
+      self.state.error_message = Some("Field type is required".to_string());
+      return;
+    }
+
     // Parse numeric fields
     let field_length = self.field_length.parse::<u16>().ok();
     let field_precision = self.field_precision.parse::<u16>().ok();
@@ -717,11 +823,19 @@ impl CreateBasicFieldForm {
       field_length,
       field_precision,
       field_scale,
+      money_precision: Some(19),
+      money_scale: Some(2),
       field_temporal,
       field_timezone_storage,
       field_unique: self.unique,
       field_nullable: !self.mandatory,
       field_large_object: self.large_object,
+      field_optional: true,
+      field_fetch_type: None,
+      sync_length: false,
+      explicit_string_length: false,
+      access_config: MemberAccessConfig::default(),
+      reserved_word_check: ReservedWordCheck::default(),
     };
 
     // Call command layer instead of service directly
@@ -743,18 +857,32 @@ impl CreateBasicFieldForm {
       // This shouldn't happen due to defaults, but just in case
       vec![ListItem::new(" No types available")]
     } else {
-      self
+      let mut items: Vec<ListItem> = self
         .all_types
         .iter()
         .enumerate()
         .map(|(i, type_info)| {
           let is_selected = self.field_type_state.selected() == Some(i);
           let prefix = if is_selected { "●" } else { "○" };
-          let display =
-            format!("{} ({})", type_info.name, type_info.package_path.as_deref().unwrap_or(""));
+          let kind = if self.project_enum_types.contains(&type_info.id) {
+            " [enum]"
+          } else if self.project_entity_types.contains(&type_info.id) {
+            " [entity]"
+          } else {
+            ""
+          };
+          let display = format!(
+            "{} ({}){}",
+            type_info.name,
+            type_info.package_path.as_deref().unwrap_or(""),
+            kind
+          );
           ListItem::new(format!(" {} {}", prefix, display))
         })
-        .collect()
+        .collect();
+      let prefix = if self.is_custom_type_selected() { "●" } else { "○" };
+      items.push(ListItem::new(format!(" {} Other (type the name)", prefix)));
+      items
     };
 
     let border_style =
@@ -769,6 +897,19 @@ impl CreateBasicFieldForm {
     frame.render_stateful_widget(list, area, &mut self.field_type_state);
   }
 
+  fn render_custom_type_input(&mut self, frame: &mut Frame, area: Rect) {
+    let is_focused = self.focused_field == FocusedField::CustomType;
+    let border_style =
+      if is_focused { Style::default().fg(Color::Yellow) } else { Style::default() };
+    let title = self.generate_title("Other type (e.g. java.util.UUID)", is_focused);
+    let input = Paragraph::new(self.custom_type.as_str())
+      .block(Block::default().title(title).borders(Borders::ALL).border_style(border_style));
+    frame.render_widget(input, area);
+    if is_focused && self.state.input_mode == InputMode::Insert {
+      frame.set_cursor_position((area.x + self.custom_type_cursor as u16 + 1, area.y + 1));
+    }
+  }
+
   fn render_field_name_input(&mut self, frame: &mut Frame, area: Rect) {
     let is_focused = self.focused_field == FocusedField::FieldName;
     let border_style =
@@ -981,7 +1122,8 @@ impl CreateBasicFieldForm {
 
     // Calculate dynamic heights
     // Ensure minimum height of 7 for the type list (5 items + 2 for borders)
-    let type_list_height = (self.all_types.len() as u16 + 2).clamp(7, 15);
+    let type_list_height = (self.all_types.len() as u16 + 3).clamp(7, 15);
+    let custom_type_height = if self.custom_type_hidden { 0 } else { 3 };
     let length_height = if self.field_length_hidden { 0 } else { 3 };
     let tz_storage_height = if self.field_time_zone_storage_hidden { 0 } else { 7 };
     let temporal_height = if self.field_temporal_hidden { 0 } else { 5 };
@@ -998,9 +1140,13 @@ impl CreateBasicFieldForm {
     let mut constraints = vec![
       Constraint::Length(2),                // Title bar
       Constraint::Length(type_list_height), // Field type selector
-      Constraint::Length(3),                // Field name input
     ];
 
+    if custom_type_height > 0 {
+      constraints.push(Constraint::Length(custom_type_height));
+    }
+    constraints.push(Constraint::Length(3)); // Field name input
+
     if length_height > 0 {
       constraints.push(Constraint::Length(length_height));
     }
@@ -1031,6 +1177,11 @@ impl CreateBasicFieldForm {
     self.render_field_type_selector(frame, chunks[chunk_idx]);
     chunk_idx += 1;
 
+    if custom_type_height > 0 {
+      self.render_custom_type_input(frame, chunks[chunk_idx]);
+      chunk_idx += 1;
+    }
+
     self.render_field_name_input(frame, chunks[chunk_idx]);
     chunk_idx += 1;
 
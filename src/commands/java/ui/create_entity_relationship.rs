@@ -7,6 +7,7 @@ use ratatui::{
 };
 use std::path::{Path, PathBuf};
 
+use crate::commands::java::treesitter::types::relationship_kind::RelationshipKind;
 use crate::commands::java::ui::create_one_to_one_relationship::CreateOneToOneRelationshipForm;
 use crate::commands::java::{
   get_all_jpa_entities_command,
@@ -17,8 +18,8 @@ use crate::common::ui::form_trait::{FormBehavior, FormState, InputMode, button_h
 /// Enum to hold different child form types
 enum ChildFormType {
   OneToOne(Box<CreateOneToOneRelationshipForm>),
+  /// Many-to-one or one-to-many, the same form configures both sides
   ManyToOne(Box<CreateManyToOneRelationshipForm>),
-  // OneToMany(Box<CreateOneToManyRelationshipForm>), // Future
   // ManyToMany(Box<CreateManyToManyRelationshipForm>), // Future
 }
 
@@ -45,13 +46,18 @@ impl RelationshipCategory {
     match self {
       RelationshipCategory::OneToOne => "One-to-One",
       RelationshipCategory::ManyToOne => "Many-to-One",
-      RelationshipCategory::OneToMany => "One-to-Many (Coming Soon)",
+      RelationshipCategory::OneToMany => "One-to-Many",
       RelationshipCategory::ManyToMany => "Many-to-Many (Coming Soon)",
     }
   }
 
   fn is_implemented(&self) -> bool {
-    matches!(self, RelationshipCategory::OneToOne | RelationshipCategory::ManyToOne)
+    matches!(
+      self,
+      RelationshipCategory::OneToOne
+        | RelationshipCategory::ManyToOne
+        | RelationshipCategory::OneToMany
+    )
   }
 }
 
@@ -117,7 +123,7 @@ impl CreateEntityRelationshipForm {
   }
 
   fn fetch_entity_files(cwd: &Path) -> Result<String, Box<dyn std::error::Error>> {
-    let response = get_all_jpa_entities_command::execute(cwd);
+    let response = get_all_jpa_entities_command::execute(cwd, None, false);
     let json = response.to_json_pretty()?;
     Ok(json)
   }
@@ -155,12 +161,17 @@ impl CreateEntityRelationshipForm {
         // Clear any previous error messages
         self.state.error_message = None;
       }
-      RelationshipCategory::ManyToOne => {
+      RelationshipCategory::ManyToOne | RelationshipCategory::OneToMany => {
+        let kind = match self.selected_category {
+          RelationshipCategory::OneToMany => RelationshipKind::OneToMany,
+          _ => RelationshipKind::ManyToOne,
+        };
         let many_to_one_form = CreateManyToOneRelationshipForm::new(
           self.cwd.clone(),
           self.entity_file_b64_src.clone(),
           self.entity_file_path.clone(),
           self.entity_files_json.clone(),
+          kind,
         );
         self.child_form = Some(ChildFormType::ManyToOne(Box::new(many_to_one_form)));
         self.phase = FormPhase::ChildForm;
@@ -10,6 +10,7 @@ use ratatui::{
 use std::path::{Path, PathBuf};
 
 use crate::commands::java::treesitter::types::java_file_type::JavaFileType;
+use crate::commands::java::treesitter::types::member_access_config::MemberAccessConfig;
 use crate::commands::java::{
   create_jpa_entity_enum_field_command, treesitter::types::java_enum_type::JavaEnumType,
 };
@@ -144,7 +145,7 @@ impl CreateEnumFieldForm {
 
   /// Fetch enum types from syntaxpresso-core
   fn fetch_enum_types(cwd: &Path) -> Result<Vec<EnumFileResponse>, Box<dyn std::error::Error>> {
-    let response = get_java_files_command::execute(cwd, &JavaFileType::Enum);
+    let response = get_java_files_command::execute(cwd, &JavaFileType::Enum, None, None);
 
     let mut enum_types = Vec::new();
     if let Some(data) = response.data {
@@ -478,6 +479,8 @@ impl CreateEnumFieldForm {
       field_length,
       field_nullable: !self.mandatory,
       field_unique: self.unique,
+      validate_enum_type: true,
+      access_config: MemberAccessConfig::default(),
     };
 
     // Call command layer instead of service directly
@@ -13,6 +13,7 @@ use std::path::{Path, PathBuf};
 use crate::commands::java::treesitter::types::java_basic_types::JavaBasicType;
 use crate::commands::java::treesitter::types::java_id_generation::JavaIdGeneration;
 use crate::commands::java::treesitter::types::java_id_generation_type::JavaIdGenerationType;
+use crate::commands::java::treesitter::types::member_access_config::MemberAccessConfig;
 use crate::commands::java::{
   create_jpa_entity_id_field_command, treesitter::types::id_field_config::IdFieldConfig,
 };
@@ -729,6 +730,7 @@ impl CreateIdFieldForm {
       field_initial_value: initial_value,
       field_allocation_size: allocation_size,
       field_nullable: !self.mandatory,
+      access_config: MemberAccessConfig::default(),
     };
 
     // Call command layer instead of service directly
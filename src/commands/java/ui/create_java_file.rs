@@ -411,6 +411,8 @@ impl CreateJavaFileForm {
       &self.file_name,
       &self.file_type,
       &JavaSourceDirectoryType::Main,
+      None,
+      None,
     );
 
     // Use helper function to output response and exit
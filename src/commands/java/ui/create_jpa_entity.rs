@@ -10,6 +10,7 @@ use std::path::{Path, PathBuf};
 use crate::commands::java::{
   create_jpa_entity_command, get_all_jpa_mapped_superclasses, get_all_packages_command,
   treesitter::types::java_source_directory_type::JavaSourceDirectoryType,
+  treesitter::types::reserved_word_check::ReservedWordCheck,
 };
 use crate::common::ui::form_trait::{FormBehavior, FormState, InputMode, button_helpers, helpers};
 
@@ -124,7 +125,7 @@ impl CreateJpaEntityForm {
 
   /// Fetch mapped superclasses from syntaxpresso-core
   fn fetch_superclasses(cwd: &Path) -> Result<Vec<SuperclassInfo>, Box<dyn std::error::Error>> {
-    let response = get_all_jpa_mapped_superclasses::execute(cwd);
+    let response = get_all_jpa_mapped_superclasses::execute(cwd, None);
 
     let mut superclasses = Vec::new();
     if let Some(data) = response.data {
@@ -436,6 +437,10 @@ impl CreateJpaEntityForm {
       &self.entity_name,
       superclass_type,
       superclass_package_name,
+      true,
+      &ReservedWordCheck::default(),
+      false,
+      false,
     );
 
     // Use helper function to output response and exit
@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 
+use base64::prelude::*;
 use crossterm::event::KeyCode;
 use ratatui::{
   Frame,
@@ -11,21 +12,25 @@ use std::path::{Path, PathBuf};
 
 use crate::commands::java::treesitter::types::cascade_type::CascadeType;
 use crate::commands::java::treesitter::types::collection_type::CollectionType;
+use crate::commands::java::treesitter::types::entity_side::EntitySide;
 use crate::commands::java::treesitter::types::fetch_type::FetchType;
 use crate::commands::java::treesitter::types::many_to_one_field_config::ManyToOneFieldConfig;
 use crate::commands::java::treesitter::types::mapping_type::MappingType;
 use crate::commands::java::treesitter::types::other_type::OtherType;
+use crate::commands::java::treesitter::types::relationship_kind::RelationshipKind;
 use crate::commands::java::{
   create_jpa_many_to_one_relationship_command,
   services::{get_all_jpa_entities_service, get_jpa_entity_info_service},
 };
 use crate::common::ui::form_trait::{FormBehavior, FormState, InputMode, button_helpers, helpers};
+use crate::common::utils::scan_deadline::ScanDeadline;
 
 /// Entity type information
 #[derive(Debug, Clone)]
 struct EntityTypeInfo {
   name: String,
   package_name: String,
+  file_path: PathBuf,
 }
 
 /// Represents which phase of the form we're in
@@ -46,6 +51,8 @@ enum FocusedField {
   FetchType,
   OwningCascades,
   OwningOther,
+  ForeignKeyName,
+  NoForeignKeyConstraint,
 
   // Phase 2: Inverse Configuration (One side -> becomes OneToMany)
   CollectionType,
@@ -71,11 +78,15 @@ struct SelectorParams<'a> {
   selected_indices: &'a [usize],
 }
 
-/// Main form state for creating many-to-one relationships
+/// Main form state for creating many-to-one relationships, also used for one-to-many
+/// relationships where the current entity is the inverse side
 pub struct CreateManyToOneRelationshipForm {
   // Common form state (embedded)
   state: FormState,
 
+  // Relationship as seen from the current entity: ManyToOne or OneToMany
+  kind: RelationshipKind,
+
   // Current phase
   phase: FormPhase,
 
@@ -86,8 +97,13 @@ pub struct CreateManyToOneRelationshipForm {
   inverse_field_name: String,
   fetch_type_index: usize,
   collection_type_index: usize,
+  foreign_key_name: String,
+  no_foreign_key_constraint: bool,
+
+  // Typed target entity type, narrows down the entity types
+  entity_type_filter: String,
 
-  // Current entity information (owning side - Many side)
+  // Current entity information
   current_entity_name: String,
   current_entity_package: String,
 
@@ -115,6 +131,7 @@ pub struct CreateManyToOneRelationshipForm {
   // Text input cursors
   owning_field_name_cursor: usize,
   inverse_field_name_cursor: usize,
+  foreign_key_name_cursor: usize,
 
   // Focus management
   focused_field: FocusedField,
@@ -135,6 +152,7 @@ impl CreateManyToOneRelationshipForm {
     entity_file_b64_src: String,
     entity_file_path: PathBuf,
     _entity_files_json: String, // Not used anymore, we fetch directly
+    kind: RelationshipKind,
   ) -> Self {
     // Fetch current entity info
     let (current_entity_name, current_entity_package) =
@@ -170,6 +188,7 @@ impl CreateManyToOneRelationshipForm {
 
     let mut form = Self {
       state: FormState::new(),
+      kind,
       phase: FormPhase::OwningConfiguration,
       mapping_type_index: 0,
       target_entity_index: None,
@@ -177,6 +196,9 @@ impl CreateManyToOneRelationshipForm {
       inverse_field_name: String::new(),
       fetch_type_index: 0,
       collection_type_index: 0,
+      foreign_key_name: String::new(),
+      no_foreign_key_constraint: false,
+      entity_type_filter: String::new(),
       current_entity_name,
       current_entity_package,
       entity_types,
@@ -194,6 +216,7 @@ impl CreateManyToOneRelationshipForm {
       inverse_other_state,
       owning_field_name_cursor: 0,
       inverse_field_name_cursor: 0,
+      foreign_key_name_cursor: 0,
       focused_field: FocusedField::MappingType,
       cwd,
       entity_file_b64_src,
@@ -203,6 +226,8 @@ impl CreateManyToOneRelationshipForm {
     };
 
     // Initialize target entity and auto-generate field name
+    form.phase = form.get_first_phase();
+    form.focused_field = form.get_visible_fields()[0];
     form.update_target_entity();
 
     form
@@ -229,7 +254,7 @@ impl CreateManyToOneRelationshipForm {
     current_entity_name: &str,
     current_entity_package: &str,
   ) -> Vec<EntityTypeInfo> {
-    match get_all_jpa_entities_service::run(cwd) {
+    match get_all_jpa_entities_service::run(cwd, &ScanDeadline::unbounded(), false) {
       Ok(entities) => entities
         .into_iter()
         .filter(|entity| {
@@ -240,6 +265,7 @@ impl CreateManyToOneRelationshipForm {
         .map(|entity| EntityTypeInfo {
           name: entity.file_type,
           package_name: entity.file_package_name,
+          file_path: PathBuf::from(entity.file_path),
         })
         .collect(),
       Err(_) => {
@@ -277,22 +303,77 @@ impl CreateManyToOneRelationshipForm {
     format!("{}{}", base_name, collection_suffix)
   }
 
-  /// Update target entity and auto-fill owning field name
+  /// Check if the current entity is the inverse (One) side
+  fn is_one_to_many(&self) -> bool {
+    self.kind == RelationshipKind::OneToMany
+  }
+
+  /// Name of the selected target entity
+  fn get_target_entity_name(&self) -> Option<String> {
+    self.target_entity_index.and_then(|idx| self.entity_types.get(idx)).map(|e| e.name.clone())
+  }
+
+  /// Indices of the entity types whose name contains the typed filter, ignoring case
+  fn get_filtered_entity_indices(&self) -> Vec<usize> {
+    let filter = self.entity_type_filter.to_lowercase();
+    self
+      .entity_types
+      .iter()
+      .enumerate()
+      .filter(|(_, entity)| entity.name.to_lowercase().contains(&filter))
+      .map(|(idx, _)| idx)
+      .collect()
+  }
+
+  /// Validate the typed target entity type against the discovered entities
+  fn get_entity_type_filter_error(&self) -> Option<String> {
+    if self.entity_types.is_empty() {
+      return Some("No JPA entities found in the project".to_string());
+    }
+    if self.get_filtered_entity_indices().is_empty() {
+      return Some(format!("No entity matching '{}' was found", self.entity_type_filter));
+    }
+    None
+  }
+
+  /// Re-select the target entity after the filter changed, preferring an exact name match
+  fn update_entity_type_filter(&mut self) {
+    let filtered_indices = self.get_filtered_entity_indices();
+    let exact_match = filtered_indices
+      .iter()
+      .position(|&idx| self.entity_types[idx].name.eq_ignore_ascii_case(&self.entity_type_filter));
+    let selected = (!filtered_indices.is_empty()).then(|| exact_match.unwrap_or(0));
+    self.entity_type_state.select(selected);
+    self.update_target_entity();
+  }
+
+  /// Update target entity and auto-fill field names
   fn update_target_entity(&mut self) {
-    if let Some(idx) = self.entity_type_state.selected() {
-      self.target_entity_index = Some(idx);
-      if let Some(entity) = self.entity_types.get(idx) {
-        // Owning side (Many side): single reference to target entity
-        self.owning_field_name = Self::auto_field_name(&entity.name);
-        self.owning_field_name_cursor = self.owning_field_name.len();
-      }
+    let filtered_indices = self.get_filtered_entity_indices();
+    self.target_entity_index =
+      self.entity_type_state.selected().and_then(|idx| filtered_indices.get(idx).copied());
+    let Some(target_entity_name) = self.get_target_entity_name() else {
+      return;
+    };
+    if self.is_one_to_many() {
+      // The target entity is the Many side, its field is the mappedBy of the collection
+      self.owning_field_name = Self::auto_field_name(&self.current_entity_name);
+      self.update_inverse_field_name();
+    } else {
+      // Owning side (Many side): single reference to target entity
+      self.owning_field_name = Self::auto_field_name(&target_entity_name);
     }
+    self.owning_field_name_cursor = self.owning_field_name.len();
   }
 
   /// Update inverse field name when collection type or entering inverse phase
   fn update_inverse_field_name(&mut self) {
+    let many_side_entity_name = match self.is_one_to_many() {
+      true => self.get_target_entity_name().unwrap_or_default(),
+      false => self.current_entity_name.clone(),
+    };
     self.inverse_field_name =
-      Self::auto_inverse_field_name(&self.current_entity_name, self.collection_type_index);
+      Self::auto_inverse_field_name(&many_side_entity_name, self.collection_type_index);
     self.inverse_field_name_cursor = self.inverse_field_name.len();
   }
 
@@ -319,16 +400,16 @@ impl CreateManyToOneRelationshipForm {
     }
   }
 
-  /// Check if bidirectional mapping is selected
+  /// Check if bidirectional mapping is selected, one-to-many always needs the owning side
   fn is_bidirectional(&self) -> bool {
-    self.mapping_type_index == 0
+    self.is_one_to_many() || self.mapping_type_index == 0
   }
 
   /// Get mapping type from index
   fn get_mapping_type(&self) -> MappingType {
-    match self.mapping_type_index {
-      0 => MappingType::BidirectionalJoinColumn,
-      _ => MappingType::UnidirectionalJoinColumn,
+    match self.is_bidirectional() {
+      true => MappingType::BidirectionalJoinColumn,
+      false => MappingType::UnidirectionalJoinColumn,
     }
   }
 
@@ -350,8 +431,8 @@ impl CreateManyToOneRelationshipForm {
   }
 
   /// Get cascade types from indices
-  fn get_cascade_types(indices: &[usize]) -> Vec<CascadeType> {
-    let all_cascades = Self::get_all_cascades();
+  fn get_cascade_types(indices: &[usize], is_owning: bool) -> Vec<CascadeType> {
+    let all_cascades = Self::get_cascade_options(is_owning);
     indices.iter().filter_map(|&i| all_cascades.get(i).cloned()).collect()
   }
 
@@ -362,25 +443,20 @@ impl CreateManyToOneRelationshipForm {
     indices.iter().filter_map(|&i| all_others.get(i).cloned()).collect()
   }
 
-  /// Get all cascade types
-  fn get_all_cascades() -> Vec<CascadeType> {
-    vec![
-      CascadeType::Persist,
-      CascadeType::Merge,
-      CascadeType::Remove,
-      CascadeType::Refresh,
-      CascadeType::Detach,
-    ]
+  /// Get cascade types offered for a side, `ALL` is left out in favour of the explicit types
+  fn get_cascade_options(is_owning: bool) -> Vec<CascadeType> {
+    let kind = if is_owning { RelationshipKind::ManyToOne } else { RelationshipKind::OneToMany };
+    kind.get_allowed_cascades().into_iter().filter(|cascade| *cascade != CascadeType::All).collect()
   }
 
   /// Get owning side other options (Many side)
   fn get_owning_other_options() -> Vec<OtherType> {
-    vec![OtherType::Mandatory, OtherType::Unique]
+    RelationshipKind::ManyToOne.get_allowed_other_options(&EntitySide::Owning)
   }
 
   /// Get inverse side other options (One side)
   fn get_inverse_other_options() -> Vec<OtherType> {
-    vec![OtherType::OrphanRemoval]
+    RelationshipKind::OneToMany.get_allowed_other_options(&EntitySide::Inverse)
   }
 
   /// Toggle item in a list
@@ -406,74 +482,101 @@ impl CreateManyToOneRelationshipForm {
     }
   }
 
+  /// Many-to-one starts on the owning side, one-to-many on the current entity's inverse side
+  fn get_first_phase(&self) -> FormPhase {
+    match self.is_one_to_many() {
+      true => FormPhase::InverseConfiguration,
+      false => FormPhase::OwningConfiguration,
+    }
+  }
+
+  /// Fields of the current phase, in focus order
+  fn get_visible_fields(&self) -> Vec<FocusedField> {
+    let mut fields = Vec::new();
+    match self.phase {
+      FormPhase::OwningConfiguration => {
+        if !self.is_one_to_many() {
+          fields.extend([FocusedField::MappingType, FocusedField::TargetEntityType]);
+        }
+        fields.extend([
+          FocusedField::OwningFieldName,
+          FocusedField::FetchType,
+          FocusedField::OwningCascades,
+          FocusedField::OwningOther,
+          FocusedField::ForeignKeyName,
+          FocusedField::NoForeignKeyConstraint,
+        ]);
+      }
+      FormPhase::InverseConfiguration => {
+        if self.is_one_to_many() {
+          fields.push(FocusedField::TargetEntityType);
+        }
+        fields.extend([
+          FocusedField::CollectionType,
+          FocusedField::InverseFieldName,
+          FocusedField::InverseCascades,
+          FocusedField::InverseOther,
+        ]);
+      }
+    }
+    fields.push(FocusedField::BackButton);
+    if self.phase == self.get_first_phase() && self.is_bidirectional() {
+      fields.push(FocusedField::NextButton);
+    } else {
+      fields.push(FocusedField::ConfirmButton);
+    }
+    fields
+  }
+
   /// Move focus to the next visible field
   fn focus_next(&mut self) {
     self.back_pressed_once = false;
-
-    self.focused_field = match self.phase {
-      FormPhase::OwningConfiguration => match self.focused_field {
-        FocusedField::MappingType => FocusedField::TargetEntityType,
-        FocusedField::TargetEntityType => FocusedField::OwningFieldName,
-        FocusedField::OwningFieldName => FocusedField::FetchType,
-        FocusedField::FetchType => FocusedField::OwningCascades,
-        FocusedField::OwningCascades => FocusedField::OwningOther,
-        FocusedField::OwningOther => FocusedField::BackButton,
-        FocusedField::BackButton => {
-          if self.is_bidirectional() {
-            FocusedField::NextButton
-          } else {
-            FocusedField::ConfirmButton
-          }
-        }
-        FocusedField::NextButton => FocusedField::MappingType,
-        FocusedField::ConfirmButton => FocusedField::MappingType,
-        _ => FocusedField::MappingType,
-      },
-      FormPhase::InverseConfiguration => match self.focused_field {
-        FocusedField::CollectionType => FocusedField::InverseFieldName,
-        FocusedField::InverseFieldName => FocusedField::InverseCascades,
-        FocusedField::InverseCascades => FocusedField::InverseOther,
-        FocusedField::InverseOther => FocusedField::BackButton,
-        FocusedField::BackButton => FocusedField::ConfirmButton,
-        FocusedField::ConfirmButton => FocusedField::CollectionType,
-        _ => FocusedField::CollectionType,
-      },
+    let fields = self.get_visible_fields();
+    self.focused_field = match fields.iter().position(|field| *field == self.focused_field) {
+      Some(idx) => fields[(idx + 1) % fields.len()],
+      None => fields[0],
     };
   }
 
   /// Move focus to the previous visible field
   fn focus_prev(&mut self) {
     self.back_pressed_once = false;
+    let fields = self.get_visible_fields();
+    self.focused_field = match fields.iter().position(|field| *field == self.focused_field) {
+      Some(idx) => fields[(idx + fields.len() - 1) % fields.len()],
+      None => fields[0],
+    };
+  }
 
-    self.focused_field = match self.phase {
-      FormPhase::OwningConfiguration => match self.focused_field {
-        FocusedField::MappingType => {
-          if self.is_bidirectional() {
-            FocusedField::NextButton
-          } else {
-            FocusedField::ConfirmButton
-          }
-        }
-        FocusedField::TargetEntityType => FocusedField::MappingType,
-        FocusedField::OwningFieldName => FocusedField::TargetEntityType,
-        FocusedField::FetchType => FocusedField::OwningFieldName,
-        FocusedField::OwningCascades => FocusedField::FetchType,
-        FocusedField::OwningOther => FocusedField::OwningCascades,
-        FocusedField::BackButton => FocusedField::OwningOther,
-        FocusedField::NextButton => FocusedField::BackButton,
-        FocusedField::ConfirmButton => FocusedField::BackButton,
-        _ => FocusedField::MappingType,
-      },
-      FormPhase::InverseConfiguration => match self.focused_field {
-        FocusedField::CollectionType => FocusedField::ConfirmButton,
-        FocusedField::InverseFieldName => FocusedField::CollectionType,
-        FocusedField::InverseCascades => FocusedField::InverseFieldName,
-        FocusedField::InverseOther => FocusedField::InverseCascades,
-        FocusedField::BackButton => FocusedField::InverseOther,
-        FocusedField::ConfirmButton => FocusedField::BackButton,
-        _ => FocusedField::CollectionType,
-      },
+  /// Move to the second phase
+  fn go_to_next_phase(&mut self) {
+    self.phase = match self.get_first_phase() {
+      FormPhase::OwningConfiguration => FormPhase::InverseConfiguration,
+      FormPhase::InverseConfiguration => FormPhase::OwningConfiguration,
     };
+    self.focused_field = self.get_visible_fields()[0];
+    self.back_pressed_once = false;
+    self.state.input_mode = InputMode::Normal;
+    if !self.is_one_to_many() {
+      // Auto-generate inverse field name based on current collection type
+      self.update_inverse_field_name();
+    }
+  }
+
+  /// Go back to the first phase, or to the parent form from the first phase, on second press
+  fn go_back(&mut self) {
+    if !self.back_pressed_once {
+      self.back_pressed_once = true;
+      return;
+    }
+    if self.phase == self.get_first_phase() {
+      self.should_go_back = true;
+    } else {
+      self.phase = self.get_first_phase();
+      self.focused_field = self.get_visible_fields()[0];
+      self.back_pressed_once = false;
+      self.state.input_mode = InputMode::Normal;
+    }
   }
 
   /// Called when entering insert mode
@@ -486,6 +589,9 @@ impl CreateManyToOneRelationshipForm {
         FocusedField::InverseFieldName => {
           self.inverse_field_name_cursor = self.inverse_field_name.len();
         }
+        FocusedField::ForeignKeyName => {
+          self.foreign_key_name_cursor = self.foreign_key_name.len();
+        }
         _ => {}
       }
     }
@@ -494,41 +600,24 @@ impl CreateManyToOneRelationshipForm {
   /// Called when Enter is pressed in Normal mode
   fn on_enter_pressed(&mut self) {
     match self.focused_field {
-      FocusedField::NextButton => {
-        // Move to inverse configuration phase
-        self.phase = FormPhase::InverseConfiguration;
-        self.focused_field = FocusedField::CollectionType;
-        self.back_pressed_once = false;
-        // Auto-generate inverse field name based on current collection type
-        self.update_inverse_field_name();
-      }
+      FocusedField::NextButton => self.go_to_next_phase(),
       FocusedField::ConfirmButton => {
         self.execute_create_relationship();
       }
-      FocusedField::BackButton => {
-        if self.back_pressed_once {
-          // If in inverse phase, go back to owning phase
-          if self.phase == FormPhase::InverseConfiguration {
-            self.phase = FormPhase::OwningConfiguration;
-            self.focused_field = FocusedField::MappingType;
-            self.back_pressed_once = false;
-          } else {
-            // If in owning phase, go back to parent form
-            self.should_go_back = true;
-          }
-        } else {
-          self.back_pressed_once = true;
-        }
-      }
+      FocusedField::BackButton => self.go_back(),
       _ => {}
     }
   }
 
   fn execute_create_relationship(&mut self) {
     // Validate
-    let target_entity_name = match self.target_entity_index {
+    if let Some(error) = self.get_entity_type_filter_error() {
+      self.state.error_message = Some(error);
+      return;
+    }
+    let target_entity = match self.target_entity_index {
       Some(idx) => match self.entity_types.get(idx) {
-        Some(entity) => entity.name.clone(),
+        Some(entity) => entity.clone(),
         None => {
           self.state.error_message = Some("No target entity selected".to_string());
           return;
@@ -541,7 +630,10 @@ impl CreateManyToOneRelationshipForm {
     };
 
     if self.owning_field_name.is_empty() {
-      self.state.error_message = Some("Owning side field name is required".to_string());
+      self.state.error_message = Some(match self.is_one_to_many() {
+        true => "mappedBy field name is required".to_string(),
+        false => "Owning side field name is required".to_string(),
+      });
       return;
     }
 
@@ -551,23 +643,51 @@ impl CreateManyToOneRelationshipForm {
       return;
     }
 
+    // The Many side owns the relationship: the current entity, or the target for one-to-many
+    let (owning_entity_file_b64_src, owning_entity_file_path, inverse_field_type) =
+      match self.is_one_to_many() {
+        true => match std::fs::read_to_string(&target_entity.file_path) {
+          Ok(source) => (
+            BASE64_STANDARD.encode(source),
+            target_entity.file_path,
+            self.current_entity_name.clone(),
+          ),
+          Err(e) => {
+            self.state.error_message =
+              Some(format!("Unable to read {} entity file: {}", target_entity.name, e));
+            return;
+          }
+        },
+        false => {
+          (self.entity_file_b64_src.clone(), self.entity_file_path.clone(), target_entity.name)
+        }
+      };
+
     // Build field config
     let field_config = ManyToOneFieldConfig {
-      inverse_field_type: target_entity_name,
+      inverse_field_type,
       fetch_type: self.get_fetch_type(),
       collection_type: self.get_collection_type(),
       mapping_type: Some(self.get_mapping_type()),
-      owning_side_cascades: Self::get_cascade_types(&self.owning_cascades),
-      inverse_side_cascades: Self::get_cascade_types(&self.inverse_cascades),
+      owning_side_cascades: Self::get_cascade_types(&self.owning_cascades, true),
+      inverse_side_cascades: Self::get_cascade_types(&self.inverse_cascades, false),
       owning_side_other: Self::get_other_types(&self.owning_other, true),
       inverse_side_other: Self::get_other_types(&self.inverse_other, false),
+      foreign_key_name: Some(self.foreign_key_name.trim().to_string())
+        .filter(|name| !name.is_empty()),
+      no_foreign_key_constraint: self.no_foreign_key_constraint,
+      required: false,
+      insertable: None,
+      updatable: None,
+      map_key_type: None,
+      map_key_column: None,
     };
 
     // Call command layer instead of service directly
     let response = create_jpa_many_to_one_relationship_command::execute(
       &self.cwd,
-      &self.entity_file_b64_src,
-      &self.entity_file_path,
+      &owning_entity_file_b64_src,
+      &owning_entity_file_path,
       self.owning_field_name.clone(),
       self.inverse_field_name.clone(),
       field_config,
@@ -594,16 +714,22 @@ impl CreateManyToOneRelationshipForm {
         }
         _ => {}
       },
+      // Typing narrows down the entity types, arrows pick among the matches
       FocusedField::TargetEntityType => match key {
-        KeyCode::Char('j') | KeyCode::Down => {
-          let len = self.entity_types.len();
-          helpers::navigate_list_static(&KeyCode::Down, &mut self.entity_type_state, len);
-          self.update_target_entity();
+        KeyCode::Down | KeyCode::Up => {
+          let len = self.get_filtered_entity_indices().len();
+          if len > 0 {
+            helpers::navigate_list_static(&key, &mut self.entity_type_state, len);
+            self.update_target_entity();
+          }
         }
-        KeyCode::Char('k') | KeyCode::Up => {
-          let len = self.entity_types.len();
-          helpers::navigate_list_static(&KeyCode::Up, &mut self.entity_type_state, len);
-          self.update_target_entity();
+        KeyCode::Char(c) => {
+          self.entity_type_filter.push(c);
+          self.update_entity_type_filter();
+        }
+        KeyCode::Backspace => {
+          self.entity_type_filter.pop();
+          self.update_entity_type_filter();
         }
         KeyCode::Enter => {
           self.state.input_mode = InputMode::Normal;
@@ -626,6 +752,23 @@ impl CreateManyToOneRelationshipForm {
           &mut self.state.input_mode,
         );
       }
+      FocusedField::ForeignKeyName => {
+        helpers::handle_text_input(
+          key,
+          &mut self.foreign_key_name,
+          &mut self.foreign_key_name_cursor,
+          &mut self.state.input_mode,
+        );
+      }
+      FocusedField::NoForeignKeyConstraint => match key {
+        KeyCode::Char(' ') | KeyCode::Enter => {
+          self.no_foreign_key_constraint = !self.no_foreign_key_constraint;
+          if key == KeyCode::Enter {
+            self.state.input_mode = InputMode::Normal;
+          }
+        }
+        _ => {}
+      },
       FocusedField::FetchType => match key {
         KeyCode::Char('j') | KeyCode::Down => {
           helpers::navigate_list_static(&KeyCode::Down, &mut self.fetch_type_state, 2);
@@ -655,24 +798,21 @@ impl CreateManyToOneRelationshipForm {
         _ => {}
       },
       FocusedField::OwningCascades | FocusedField::InverseCascades => {
-        let state = if matches!(self.focused_field, FocusedField::OwningCascades) {
+        let is_owning = matches!(self.focused_field, FocusedField::OwningCascades);
+        let state = if is_owning {
           &mut self.owning_cascades_state
         } else {
           &mut self.inverse_cascades_state
         };
-        let list = if matches!(self.focused_field, FocusedField::OwningCascades) {
-          &mut self.owning_cascades
-        } else {
-          &mut self.inverse_cascades
-        };
+        let list = if is_owning { &mut self.owning_cascades } else { &mut self.inverse_cascades };
 
         match key {
           KeyCode::Char('j') | KeyCode::Down => {
-            let len = Self::get_all_cascades().len();
+            let len = Self::get_cascade_options(is_owning).len();
             helpers::navigate_list_static(&KeyCode::Down, state, len);
           }
           KeyCode::Char('k') | KeyCode::Up => {
-            let len = Self::get_all_cascades().len();
+            let len = Self::get_cascade_options(is_owning).len();
             helpers::navigate_list_static(&KeyCode::Up, state, len);
           }
           KeyCode::Char(' ') | KeyCode::Enter => {
@@ -723,28 +863,12 @@ impl CreateManyToOneRelationshipForm {
       }
       FocusedField::NextButton => {
         if key == KeyCode::Enter {
-          self.phase = FormPhase::InverseConfiguration;
-          self.focused_field = FocusedField::CollectionType;
-          self.back_pressed_once = false;
-          self.state.input_mode = InputMode::Normal;
-          // Auto-generate inverse field name based on current collection type
-          self.update_inverse_field_name();
+          self.go_to_next_phase();
         }
       }
       FocusedField::BackButton => {
         if key == KeyCode::Enter {
-          if self.back_pressed_once {
-            if self.phase == FormPhase::InverseConfiguration {
-              self.phase = FormPhase::OwningConfiguration;
-              self.focused_field = FocusedField::MappingType;
-              self.back_pressed_once = false;
-              self.state.input_mode = InputMode::Normal;
-            } else {
-              self.should_go_back = true;
-            }
-          } else {
-            self.back_pressed_once = true;
-          }
+          self.go_back();
         }
       }
       FocusedField::ConfirmButton => {
@@ -765,45 +889,62 @@ impl CreateManyToOneRelationshipForm {
   fn render_owning_phase(&mut self, frame: &mut Frame) {
     let area = frame.area();
 
-    let chunks = Layout::default()
-      .direction(Direction::Vertical)
-      .constraints([
-        Constraint::Length(2), // Title
-        Constraint::Length(4), // Mapping type
-        Constraint::Length(9), // Entity type
-        Constraint::Length(3), // Owning field name
-        Constraint::Length(4), // Fetch type
-        Constraint::Length(7), // Owning cascades
-        Constraint::Length(4), // Owning other
-        Constraint::Min(0),    // Errors
-        Constraint::Length(1), // Buttons
-      ])
-      .split(area);
+    let mut constraints = vec![Constraint::Length(2)]; // Title
+    if !self.is_one_to_many() {
+      constraints.push(Constraint::Length(4)); // Mapping type
+      constraints.push(Constraint::Length(9)); // Entity type
+    }
+    constraints.extend([
+      Constraint::Length(3), // Owning field name
+      Constraint::Length(4), // Fetch type
+      Constraint::Length(7), // Owning cascades
+      Constraint::Length(4), // Owning other
+      Constraint::Length(3), // Foreign key
+      Constraint::Min(0),    // Errors
+      Constraint::Length(1), // Buttons
+    ]);
+    let chunks =
+      Layout::default().direction(Direction::Vertical).constraints(constraints).split(area);
 
     let mut idx = 0;
 
     // Title
-    let title = Paragraph::new("Many-to-One: Owning Side Configuration")
+    let title_text = match self.is_one_to_many() {
+      true => format!(
+        "One-to-Many: Many Side ({}) Configuration",
+        self.get_target_entity_name().unwrap_or_default()
+      ),
+      false => "Many-to-One: Owning Side Configuration".to_string(),
+    };
+    let title = Paragraph::new(title_text)
       .alignment(Alignment::Center)
       .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
       .block(Block::default().borders(Borders::BOTTOM));
     frame.render_widget(title, chunks[idx]);
     idx += 1;
 
-    // Mapping type
-    self.render_mapping_type_selector(frame, chunks[idx]);
-    idx += 1;
+    if !self.is_one_to_many() {
+      // Mapping type
+      self.render_mapping_type_selector(frame, chunks[idx]);
+      idx += 1;
 
-    // Entity type
-    self.render_entity_type_selector(frame, chunks[idx]);
-    idx += 1;
+      // Entity type
+      self.render_entity_type_selector(frame, chunks[idx]);
+      idx += 1;
+    }
 
-    // Owning field name
+    // Owning field name, the mappedBy of the collection for one-to-many
+    let owning_field_title = match self.is_one_to_many() {
+      true => {
+        format!("mappedBy Field Name (on {})", self.get_target_entity_name().unwrap_or_default())
+      }
+      false => "Owning Side Field Name (Many side)".to_string(),
+    };
     self.render_text_input(
       frame,
       chunks[idx],
       FocusedField::OwningFieldName,
-      "Owning Side Field Name (Many side)",
+      &owning_field_title,
       &self.owning_field_name.clone(),
       self.owning_field_name_cursor,
     );
@@ -845,6 +986,10 @@ impl CreateManyToOneRelationshipForm {
     );
     idx += 1;
 
+    // Foreign key
+    self.render_foreign_key_inputs(frame, chunks[idx]);
+    idx += 1;
+
     // Error
     if let Some(ref error_msg) = self.state.error_message {
       let error_paragraph =
@@ -859,35 +1004,47 @@ impl CreateManyToOneRelationshipForm {
     idx += 1;
 
     // Buttons
-    self.render_owning_phase_buttons(frame, chunks[idx]);
+    self.render_buttons(frame, chunks[idx]);
   }
 
   fn render_inverse_phase(&mut self, frame: &mut Frame) {
     let area = frame.area();
 
-    let chunks = Layout::default()
-      .direction(Direction::Vertical)
-      .constraints([
-        Constraint::Length(2), // Title
-        Constraint::Length(5), // Collection type
-        Constraint::Length(3), // Inverse field name
-        Constraint::Length(7), // Inverse cascades
-        Constraint::Length(3), // Inverse other
-        Constraint::Min(0),    // Errors
-        Constraint::Length(1), // Buttons
-      ])
-      .split(area);
+    let mut constraints = vec![Constraint::Length(2)]; // Title
+    if self.is_one_to_many() {
+      constraints.push(Constraint::Length(9)); // Entity type
+    }
+    constraints.extend([
+      Constraint::Length(5), // Collection type
+      Constraint::Length(3), // Inverse field name
+      Constraint::Length(7), // Inverse cascades
+      Constraint::Length(3), // Inverse other
+      Constraint::Min(0),    // Errors
+      Constraint::Length(1), // Buttons
+    ]);
+    let chunks =
+      Layout::default().direction(Direction::Vertical).constraints(constraints).split(area);
 
     let mut idx = 0;
 
     // Title
-    let title = Paragraph::new("Many-to-One: Inverse Side (One-to-Many) Configuration")
+    let title_text = match self.is_one_to_many() {
+      true => format!("One-to-Many: One Side ({}) Configuration", self.current_entity_name),
+      false => "Many-to-One: Inverse Side (One-to-Many) Configuration".to_string(),
+    };
+    let title = Paragraph::new(title_text)
       .alignment(Alignment::Center)
       .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
       .block(Block::default().borders(Borders::BOTTOM));
     frame.render_widget(title, chunks[idx]);
     idx += 1;
 
+    if self.is_one_to_many() {
+      // Entity type
+      self.render_entity_type_selector(frame, chunks[idx]);
+      idx += 1;
+    }
+
     // Collection type
     self.render_collection_type_selector(frame, chunks[idx]);
     idx += 1;
@@ -980,22 +1137,40 @@ impl CreateManyToOneRelationshipForm {
 
   fn render_entity_type_selector(&mut self, frame: &mut Frame, area: Rect) {
     let is_focused = self.focused_field == FocusedField::TargetEntityType;
+    let filter_error = self.get_entity_type_filter_error();
 
-    let items: Vec<ListItem> = self
-      .entity_types
-      .iter()
-      .enumerate()
-      .map(|(i, entity)| {
-        let is_selected = self.entity_type_state.selected() == Some(i);
-        let prefix = if is_selected { "●" } else { "○" };
-        ListItem::new(format!(" {} {} ({})", prefix, entity.name, entity.package_name))
-      })
-      .collect();
+    let items: Vec<ListItem> = match &filter_error {
+      Some(error) => {
+        vec![ListItem::new(format!(" {}", error)).style(Style::default().fg(Color::Red))]
+      }
+      None => self
+        .get_filtered_entity_indices()
+        .into_iter()
+        .enumerate()
+        .map(|(i, entity_idx)| {
+          let entity = &self.entity_types[entity_idx];
+          let is_selected = self.entity_type_state.selected() == Some(i);
+          let prefix = if is_selected { "●" } else { "○" };
+          ListItem::new(format!(" {} {} ({})", prefix, entity.name, entity.package_name))
+        })
+        .collect(),
+    };
 
-    let border_style =
-      if is_focused { Style::default().fg(Color::Yellow) } else { Style::default() };
+    let border_style = match (&filter_error, is_focused) {
+      (Some(_), _) => Style::default().fg(Color::Red),
+      (None, true) => Style::default().fg(Color::Yellow),
+      (None, false) => Style::default(),
+    };
 
-    let title = self.generate_title("Target Entity Type (the 'One' side)", is_focused);
+    let base_title = match self.is_one_to_many() {
+      true => "Target Entity Type (the 'Many' side, type to filter)",
+      false => "Target Entity Type (the 'One' side, type to filter)",
+    };
+    let base_title = match self.entity_type_filter.is_empty() {
+      true => base_title.to_string(),
+      false => format!("{}: {}", base_title, self.entity_type_filter),
+    };
+    let title = self.generate_title(&base_title, is_focused);
     let list = List::new(items)
       .block(Block::default().title(title).borders(Borders::ALL).border_style(border_style))
       .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
@@ -1004,6 +1179,31 @@ impl CreateManyToOneRelationshipForm {
     frame.render_stateful_widget(list, area, &mut self.entity_type_state);
   }
 
+  fn render_foreign_key_inputs(&self, frame: &mut Frame, area: Rect) {
+    let columns = Layout::default()
+      .direction(Direction::Horizontal)
+      .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+      .split(area);
+
+    self.render_text_input(
+      frame,
+      columns[0],
+      FocusedField::ForeignKeyName,
+      "Foreign Key Name (optional)",
+      &self.foreign_key_name,
+      self.foreign_key_name_cursor,
+    );
+
+    let is_focused = self.focused_field == FocusedField::NoForeignKeyConstraint;
+    let border_style =
+      if is_focused { Style::default().fg(Color::Yellow) } else { Style::default() };
+    let checkbox = if self.no_foreign_key_constraint { "[x]" } else { "[ ]" };
+    let title = self.generate_title("No Foreign Key Constraint", is_focused);
+    let toggle = Paragraph::new(format!(" {} ConstraintMode.NO_CONSTRAINT", checkbox))
+      .block(Block::default().title(title).borders(Borders::ALL).border_style(border_style));
+    frame.render_widget(toggle, columns[1]);
+  }
+
   fn render_fetch_type_selector(&mut self, frame: &mut Frame, area: Rect) {
     let is_focused = self.focused_field == FocusedField::FetchType;
 
@@ -1102,7 +1302,7 @@ impl CreateManyToOneRelationshipForm {
     ctx: &RenderContext,
   ) {
     let is_focused = ctx.focused_field == params.field;
-    let cascades = Self::get_all_cascades();
+    let cascades = Self::get_cascade_options(params.field == FocusedField::OwningCascades);
 
     let items: Vec<ListItem> = cascades
       .iter()
@@ -1194,9 +1394,9 @@ impl CreateManyToOneRelationshipForm {
     frame.render_stateful_widget(list, area, state);
   }
 
-  fn render_owning_phase_buttons(&self, frame: &mut Frame, area: Rect) {
-    if self.is_bidirectional() {
-      // Show Back + Next for bidirectional
+  fn render_buttons(&self, frame: &mut Frame, area: Rect) {
+    if self.get_visible_fields().contains(&FocusedField::NextButton) {
+      // Show Back + Next when the other side still has to be configured
       button_helpers::render_two_button_layout(
         frame,
         area,
@@ -1207,7 +1407,6 @@ impl CreateManyToOneRelationshipForm {
         button_helpers::ButtonType::Next,
       );
     } else {
-      // Show Back + Confirm for unidirectional
       button_helpers::render_two_button_layout(
         frame,
         area,
@@ -1219,18 +1418,6 @@ impl CreateManyToOneRelationshipForm {
       );
     }
   }
-
-  fn render_buttons(&self, frame: &mut Frame, area: Rect) {
-    button_helpers::render_two_button_layout(
-      frame,
-      area,
-      self.focused_field == FocusedField::BackButton,
-      self.focused_field == FocusedField::ConfirmButton,
-      self.back_pressed_once,
-      self.state.escape_handler.pressed_once,
-      button_helpers::ButtonType::Confirm,
-    );
-  }
 }
 
 // Implement the FormBehavior trait
@@ -10,14 +10,17 @@ use ratatui::{
 use std::path::{Path, PathBuf};
 
 use crate::commands::java::treesitter::types::cascade_type::CascadeType;
+use crate::commands::java::treesitter::types::entity_side::EntitySide;
 use crate::commands::java::treesitter::types::mapping_type::MappingType;
 use crate::commands::java::treesitter::types::one_to_one_field_config::OneToOneFieldConfig;
 use crate::commands::java::treesitter::types::other_type::OtherType;
+use crate::commands::java::treesitter::types::relationship_kind::RelationshipKind;
 use crate::commands::java::{
   create_jpa_one_to_one_relationship_command,
   services::{get_all_jpa_entities_service, get_jpa_entity_info_service},
 };
 use crate::common::ui::form_trait::{FormBehavior, FormState, InputMode, button_helpers, helpers};
+use crate::common::utils::scan_deadline::ScanDeadline;
 
 /// Entity type information
 #[derive(Debug, Clone)]
@@ -211,7 +214,7 @@ impl CreateOneToOneRelationshipForm {
     current_entity_name: &str,
     current_entity_package: &str,
   ) -> Vec<EntityTypeInfo> {
-    match get_all_jpa_entities_service::run(cwd) {
+    match get_all_jpa_entities_service::run(cwd, &ScanDeadline::unbounded(), false) {
       Ok(entities) => {
         entities
           .into_iter()
@@ -328,12 +331,12 @@ impl CreateOneToOneRelationshipForm {
 
   /// Get owning side other options
   fn get_owning_other_options() -> Vec<OtherType> {
-    vec![OtherType::Mandatory, OtherType::Unique, OtherType::OrphanRemoval]
+    RelationshipKind::OneToOne.get_allowed_other_options(&EntitySide::Owning)
   }
 
   /// Get inverse side other options
   fn get_inverse_other_options() -> Vec<OtherType> {
-    vec![OtherType::Mandatory, OtherType::OrphanRemoval]
+    RelationshipKind::OneToOne.get_allowed_other_options(&EntitySide::Inverse)
   }
 
   /// Toggle item in a list
@@ -653,6 +656,11 @@ impl CreateOneToOneRelationshipForm {
       inverse_side_cascades: Self::get_cascade_types(&self.inverse_cascades),
       owning_side_other: Self::get_other_types(&self.owning_other, true),
       inverse_side_other: Self::get_other_types(&self.inverse_other, false),
+      foreign_key_name: None,
+      no_foreign_key_constraint: false,
+      required: false,
+      insertable: None,
+      updatable: None,
     };
 
     // Call command layer instead of service directly
@@ -0,0 +1,28 @@
+use std::path::Path;
+
+use crate::{
+  commands::java::{
+    responses::validate_project_response::ValidateProjectResponse,
+    services::validate_project_service::run,
+    treesitter::types::{finding_severity::FindingSeverity, sql_dialect::SqlDialect},
+  },
+  common::{response::Response, utils::exit_status},
+};
+
+pub fn execute(
+  cwd: &Path,
+  dialect: Option<SqlDialect>,
+  fail_on: Option<FindingSeverity>,
+) -> Response<ValidateProjectResponse> {
+  let cwd_string = cwd.display().to_string();
+  let cmd_name = String::from("validate-project");
+  match run(cwd, dialect, fail_on) {
+    Ok(response) => {
+      if !response.passed {
+        exit_status::set_failed();
+      }
+      Response::success(cmd_name, cwd_string, response)
+    }
+    Err(error_msg) => Response::error(cmd_name, cwd_string, error_msg),
+  }
+}
@@ -1,3 +1,5 @@
+use crate::commands::java::validators::java_identifier_validator::JAVA_RESERVED_WORDS;
+
 pub fn validate_java_class_name(s: &str) -> Result<String, String> {
   if s.trim().is_empty() {
     return Err("Class name cannot be empty".to_string());
@@ -9,62 +11,7 @@ pub fn validate_java_class_name(s: &str) -> Result<String, String> {
       "Class name can only contain letters, numbers, underscores, and hyphens".to_string(),
     );
   }
-  let reserved_words = [
-    "abstract",
-    "assert",
-    "boolean",
-    "break",
-    "byte",
-    "case",
-    "catch",
-    "char",
-    "class",
-    "const",
-    "continue",
-    "default",
-    "do",
-    "double",
-    "else",
-    "enum",
-    "extends",
-    "final",
-    "finally",
-    "float",
-    "for",
-    "goto",
-    "if",
-    "implements",
-    "import",
-    "instanceof",
-    "int",
-    "interface",
-    "long",
-    "native",
-    "new",
-    "package",
-    "private",
-    "protected",
-    "public",
-    "return",
-    "short",
-    "static",
-    "strictfp",
-    "super",
-    "switch",
-    "synchronized",
-    "this",
-    "throw",
-    "throws",
-    "transient",
-    "try",
-    "void",
-    "volatile",
-    "while",
-    "true",
-    "false",
-    "null",
-  ];
-  if reserved_words.contains(&s.to_lowercase().as_str()) {
+  if JAVA_RESERVED_WORDS.contains(&s.to_lowercase().as_str()) {
     return Err(format!("'{}' conflicts with a Java reserved word", s));
   }
   if s.contains("__") {
@@ -0,0 +1,75 @@
+/// Keywords and literals Java doesn't accept as identifiers.
+pub const JAVA_RESERVED_WORDS: [&str; 53] = [
+  "abstract",
+  "assert",
+  "boolean",
+  "break",
+  "byte",
+  "case",
+  "catch",
+  "char",
+  "class",
+  "const",
+  "continue",
+  "default",
+  "do",
+  "double",
+  "else",
+  "enum",
+  "extends",
+  "final",
+  "finally",
+  "float",
+  "for",
+  "goto",
+  "if",
+  "implements",
+  "import",
+  "instanceof",
+  "int",
+  "interface",
+  "long",
+  "native",
+  "new",
+  "package",
+  "private",
+  "protected",
+  "public",
+  "return",
+  "short",
+  "static",
+  "strictfp",
+  "super",
+  "switch",
+  "synchronized",
+  "this",
+  "throw",
+  "throws",
+  "transient",
+  "try",
+  "void",
+  "volatile",
+  "while",
+  "true",
+  "false",
+  "null",
+];
+
+/// Whether the name is made of Java identifier characters, without checking reserved words.
+pub fn is_java_identifier(name: &str) -> bool {
+  let mut chars = name.chars();
+  chars.next().is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+    && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Validates a name used exactly as written, unlike `validate_java_class_name` which accepts names
+/// converted to PascalCase afterwards.
+pub fn validate_java_identifier(s: &str) -> Result<String, String> {
+  if !is_java_identifier(s) {
+    return Err(format!("'{}' is not a valid Java identifier", s));
+  }
+  if JAVA_RESERVED_WORDS.contains(&s) {
+    return Err(format!("'{}' conflicts with a Java reserved word", s));
+  }
+  Ok(s.to_string())
+}
@@ -0,0 +1,51 @@
+/// Validates the type of a field. Arrays such as `byte[]` or `String[][]` are accepted, varargs
+/// are not as they only exist on parameters.
+pub fn validate_java_field_type(s: &str) -> Result<String, String> {
+  validate_java_type(s, false)
+}
+
+/// Validates the type of a method or constructor parameter, where a trailing `...` declares
+/// varargs (`String...`). Callers must still make sure it is the last parameter.
+pub fn validate_java_parameter_type(s: &str) -> Result<String, String> {
+  validate_java_type(s, true)
+}
+
+fn validate_java_type(s: &str, allow_varargs: bool) -> Result<String, String> {
+  let type_text = s.trim();
+  if type_text.is_empty() {
+    return Err("Type cannot be empty".to_string());
+  }
+  let mut element_type = match type_text.strip_suffix("...") {
+    Some(_) if !allow_varargs => {
+      return Err(format!(
+        "'{}' is a varargs type, which is only allowed on the last parameter of a method",
+        type_text
+      ));
+    }
+    Some(element_type) => element_type.trim_end(),
+    None => type_text,
+  };
+  // Strip the array dimensions, `byte [ ]` is as valid as `byte[]`
+  while let Some(without_bracket) = element_type.strip_suffix(']') {
+    element_type = without_bracket
+      .trim_end()
+      .strip_suffix('[')
+      .map(|element| element.trim_end())
+      .ok_or_else(|| format!("'{}' has a malformed array dimension", type_text))?;
+  }
+  if element_type.is_empty() || element_type.contains(['[', ']']) {
+    return Err(format!("'{}' has a malformed array dimension", type_text));
+  }
+  if element_type == "void" {
+    return Err(format!("'{}' is not a valid type, void cannot be declared", type_text));
+  }
+  let starts_correctly =
+    element_type.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$');
+  let has_valid_chars = element_type
+    .chars()
+    .all(|c| c.is_alphanumeric() || matches!(c, '_' | '$' | '.' | '<' | '>' | ',' | '?' | ' '));
+  if !starts_correctly || !has_valid_chars {
+    return Err(format!("'{}' is not a valid Java type", type_text));
+  }
+  Ok(type_text.to_string())
+}
@@ -0,0 +1,27 @@
+use crate::commands::java::treesitter::types::java_language_feature::JavaLanguageFeature;
+
+/// Parses a `--target-java-version` value, accepting the legacy `1.8` form for Java 8.
+pub fn validate_java_version(s: &str) -> Result<u32, String> {
+  let version = s.trim();
+  let version = version.strip_prefix("1.").unwrap_or(version);
+  match version.parse::<u32>() {
+    Ok(version) if version >= 1 => Ok(version),
+    _ => Err(format!("'{}' is not a Java version, expected e.g. 11, 17 or 1.8", s)),
+  }
+}
+
+/// Rejects a feature the target Java version can't compile. An unknown target is not checked.
+pub fn validate_java_feature_support(
+  feature: &JavaLanguageFeature,
+  target_java_version: Option<u32>,
+) -> Result<(), String> {
+  match target_java_version {
+    Some(version) if version < feature.min_java_version() => Err(format!(
+      "UNSUPPORTED_JAVA_VERSION: {} require Java {}, the target is Java {}",
+      feature.description(),
+      feature.min_java_version(),
+      version
+    )),
+    _ => Ok(()),
+  }
+}
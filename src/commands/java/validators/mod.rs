@@ -1,2 +1,6 @@
 pub mod java_class_name_validator;
+pub mod java_identifier_validator;
+pub mod java_type_validator;
+pub mod java_version_validator;
 pub mod package_name_validator;
+pub mod sql_identifier_validator;
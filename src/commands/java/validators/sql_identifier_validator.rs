@@ -0,0 +1,30 @@
+/// Validates a table or column name: either a plain SQL identifier (`user_details`) or a
+/// double-quoted one (`"Order"`) that is kept verbatim by the database.
+pub fn validate_sql_identifier(s: &str) -> Result<String, String> {
+  if s.trim().is_empty() {
+    return Err("SQL identifier cannot be empty".to_string());
+  }
+  if s.len() >= 2 && s.starts_with('"') && s.ends_with('"') {
+    if s[1..s.len() - 1].contains('"') {
+      return Err(format!("Quoted SQL identifier {} cannot contain inner quotes", s));
+    }
+    return Ok(s.to_string());
+  }
+  let mut chars = s.chars();
+  let starts_correctly = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+  if !starts_correctly || !chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$') {
+    return Err(format!(
+      "'{}' is not a valid SQL identifier: it must start with a letter or underscore and only contain letters, numbers, underscores and dollar signs",
+      s
+    ));
+  }
+  Ok(s.to_string())
+}
+
+/// Whether an unquoted identifier mixes upper and lower case letters (`MyColumn`), a case that
+/// dialects folding unquoted names don't keep.
+pub fn is_unquoted_mixed_case(s: &str) -> bool {
+  !s.starts_with(['"', '\\', '`', '['])
+    && s.chars().any(|c| c.is_ascii_uppercase())
+    && s.chars().any(|c| c.is_ascii_lowercase())
+}
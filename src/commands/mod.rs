@@ -1,17 +1,27 @@
 pub mod java;
+pub mod serve;
 
 use clap::Subcommand;
 
 #[derive(Subcommand)]
 pub enum Commands {
   #[command(subcommand)]
-  Java(java::JavaCommands),
+  Java(Box<java::JavaCommands>),
+  Serve {
+    /// Frames requests as JSON lines carrying an `id` that is echoed back in the response
+    #[arg(long)]
+    stdin_json: bool,
+  },
 }
 
 impl Commands {
   pub fn execute(&self) -> Result<String, Box<dyn std::error::Error>> {
     match self {
       Commands::Java(java_command) => java_command.execute(),
+      Commands::Serve { stdin_json } => {
+        serve::run(*stdin_json)?;
+        Ok(String::new())
+      }
     }
   }
 }
@@ -0,0 +1,164 @@
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+use clap::Parser;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::commands::Commands;
+use crate::common::error_response::ErrorResponse;
+use crate::common::utils::file_header::{self, FileHeader};
+use crate::common::utils::package_path::{self, MappedPackagePathStrategy};
+use crate::common::utils::progress::{self, ProgressEvent};
+use crate::common::utils::{execution_meta, parse_cache};
+
+/// Command line of a single serve request, given without the binary name
+/// (`java get-column-mapping --cwd ...`).
+#[derive(Parser)]
+#[command(no_binary_name = true)]
+struct ServeRequest {
+  #[command(subcommand)]
+  command: Commands,
+
+  #[arg(long, global = true)]
+  with_meta: bool,
+
+  #[arg(long, global = true)]
+  progress: bool,
+
+  #[arg(
+    long = "package-path-mapping",
+    global = true,
+    value_parser = MappedPackagePathStrategy::parse_mapping
+  )]
+  package_path_mappings: Vec<(String, PathBuf)>,
+
+  #[arg(long, global = true, value_parser = FileHeader::from_file)]
+  header_file: Option<FileHeader>,
+}
+
+/// Response line of the `--stdin-json` protocol: the request id next to the usual response
+/// envelope, so clients pipelining requests can tell which one a response answers.
+#[derive(Serialize)]
+struct JsonResponse {
+  id: Value,
+  #[serde(flatten)]
+  response: Value,
+}
+
+/// Progress line written while a `--progress` request runs, before its response. Carries the
+/// request id under `--stdin-json`.
+#[derive(Serialize)]
+struct ProgressLine {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  id: Option<Value>,
+  event: &'static str,
+  #[serde(flatten)]
+  progress: ProgressEvent,
+}
+
+fn error_value(error: &str, message: String) -> Value {
+  let error_response = ErrorResponse { error: error.to_string(), message };
+  serde_json::to_value(error_response).unwrap_or(Value::Null)
+}
+
+fn execute_args<I: IntoIterator<Item = String>>(args: I, id: Option<&Value>) -> Value {
+  let request = match ServeRequest::try_parse_from(args) {
+    Ok(request) => request,
+    Err(e) => return error_value("invalid_request", e.to_string()),
+  };
+  if let Commands::Serve { .. } = request.command {
+    return error_value(
+      "invalid_request",
+      "Serve mode can't be started from a serve request".to_string(),
+    );
+  }
+  if request.with_meta {
+    execution_meta::enable();
+  } else {
+    execution_meta::disable();
+  }
+  if request.package_path_mappings.is_empty() {
+    package_path::reset_strategy();
+  } else {
+    package_path::set_strategy(MappedPackagePathStrategy::new(request.package_path_mappings));
+  }
+  match request.header_file {
+    Some(header) => file_header::set_header(header),
+    None => file_header::reset_header(),
+  }
+  if request.progress {
+    let id = id.cloned();
+    progress::set_sink(move |event| {
+      let line = ProgressLine { id: id.clone(), event: "progress", progress: event.clone() };
+      if let Ok(line_json) = serde_json::to_string(&line) {
+        let mut stdout = io::stdout().lock();
+        let _ = writeln!(stdout, "{}", line_json);
+        let _ = stdout.flush();
+      }
+    });
+  }
+  let response = match request.command.execute() {
+    Ok(json) => serde_json::from_str(&json).unwrap_or(Value::String(json)),
+    Err(e) => error_value("execution_error", e.to_string()),
+  };
+  progress::clear_sink();
+  response
+}
+
+/// Handles a plain request line, whose arguments are separated by whitespace.
+///
+/// # Returns
+/// The response as a single line of JSON
+fn handle_request_line(line: &str) -> String {
+  let response = execute_args(line.split_whitespace().map(|arg| arg.to_string()), None);
+  response.to_string()
+}
+
+/// Handles a `--stdin-json` request line, e.g.
+/// `{"id": 7, "args": ["java", "get-column-mapping", "--cwd", "/project"]}`. The id may be any
+/// JSON value and is echoed back as is. A malformed request still gets an error response, carrying
+/// the id when it could be read.
+///
+/// # Returns
+/// The response as a single line of JSON
+fn handle_json_request_line(line: &str) -> String {
+  let (id, response) = match serde_json::from_str::<Value>(line) {
+    Ok(request) => {
+      let id = request.get("id").cloned().unwrap_or(Value::Null);
+      let response = match request.get("args").cloned().map(serde_json::from_value::<Vec<String>>) {
+        Some(Ok(args)) => execute_args(args, Some(&id)),
+        Some(Err(e)) => error_value("invalid_request", format!("Invalid request args: {}", e)),
+        None => error_value("invalid_request", "Request has no args array".to_string()),
+      };
+      (id, response)
+    }
+    Err(e) => {
+      (Value::Null, error_value("invalid_request", format!("Malformed JSON request: {}", e)))
+    }
+  };
+  serde_json::to_string(&JsonResponse { id, response }).unwrap_or_default()
+}
+
+/// Serves commands read from stdin, one request per line, writing one response line per request
+/// in the order the requests arrive. Requests given `--progress` are preceded by their progress
+/// lines, told apart from responses by `"event": "progress"`. The process and its scan caches
+/// stay alive between requests, and field insertions reuse the parse of the buffer they last
+/// wrote.
+pub fn run(stdin_json: bool) -> io::Result<()> {
+  parse_cache::enable();
+  let mut stdout = io::stdout().lock();
+  for line in io::stdin().lock().lines() {
+    let line = line?;
+    if line.trim().is_empty() {
+      continue;
+    }
+    let response = match stdin_json {
+      true => handle_json_request_line(&line),
+      false => handle_request_line(&line),
+    };
+    writeln!(stdout, "{}", response)?;
+    stdout.flush()?;
+  }
+  Ok(())
+}
@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
 use crate::common::ts_file::TSFile;
+use crate::common::utils::execution_meta;
 use std::collections::HashMap;
 use tree_sitter::{Node, Query, QueryCursor, StreamingIterator};
 
@@ -186,6 +187,7 @@ impl<'a> TSQueryBuilder<'a> {
   pub fn execute(self) -> Result<TSQueryResult<'a>, QueryError> {
     let tree = self.file.tree.as_ref().ok_or(QueryError::NoTree)?;
     let query = Query::new(&self.file.language, &self.query_string)?;
+    execution_meta::record_query_executed();
     let mut cursor = QueryCursor::new();
     let root_node = self.scope_node.unwrap_or_else(|| tree.root_node());
     let mut query_matches = cursor.matches(&query, root_node, self.file.source_code.as_bytes());
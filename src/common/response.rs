@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::common::utils::execution_meta;
+
 /// Universal response wrapper for all API operations.
 ///
 /// This struct provides a consistent format for all responses, whether successful or failed.
@@ -52,6 +54,24 @@ pub struct Response<T> {
   /// Error description, present only on failed operations
   #[serde(skip_serializing_if = "Option::is_none")]
   pub error_reason: Option<String>,
+
+  /// Execution metadata, present only when requested with `--with-meta`
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub meta: Option<ResponseMeta>,
+}
+
+/// Execution metadata used to profile commands.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseMeta {
+  /// Milliseconds elapsed since metadata collection started
+  pub elapsed_ms: u64,
+
+  /// Number of source files parsed
+  pub files_scanned: usize,
+
+  /// Number of tree-sitter queries executed
+  pub queries_executed: usize,
 }
 
 impl<T> Response<T>
@@ -70,7 +90,14 @@ where
   /// # Returns
   /// A new Response representing a successful operation
   pub fn success(command: String, cwd: String, data: T) -> Self {
-    Self { command, cwd, succeed: true, data: Some(data), error_reason: None }
+    Self {
+      command,
+      cwd,
+      succeed: true,
+      data: Some(data),
+      error_reason: None,
+      meta: execution_meta::snapshot(),
+    }
   }
 
   /// Creates a successful response without any data payload.
@@ -85,7 +112,14 @@ where
   /// # Returns
   /// A new Response representing a successful operation without data
   pub fn success_empty(command: String, cwd: String) -> Self {
-    Self { command, cwd, succeed: true, data: None, error_reason: None }
+    Self {
+      command,
+      cwd,
+      succeed: true,
+      data: None,
+      error_reason: None,
+      meta: execution_meta::snapshot(),
+    }
   }
 
   /// Creates a failure response with the provided error message.
@@ -107,7 +141,14 @@ where
     if reason.trim().is_empty() {
       panic!("Error reason cannot be empty");
     }
-    Self { command, cwd, succeed: false, data: None, error_reason: Some(reason) }
+    Self {
+      command,
+      cwd,
+      succeed: false,
+      data: None,
+      error_reason: Some(reason),
+      meta: execution_meta::snapshot(),
+    }
   }
 
   /// Serializes this Response to a compact JSON string.
@@ -1,8 +1,9 @@
 #![allow(dead_code)]
 
 use crate::common::{
-  query::TSQueryBuilder, supported_language::SupportedLanguage,
-  utils::path_security_util::PathSecurityValidator,
+  query::TSQueryBuilder,
+  supported_language::SupportedLanguage,
+  utils::{execution_meta, path_security_util::PathSecurityValidator},
 };
 use base64::Engine;
 use std::fs;
@@ -120,6 +121,7 @@ impl TSFile {
       };
     parser.set_language(&ts_language).expect("Error loading parser");
     let tree = parser.parse(&converted_source_code, None);
+    execution_meta::record_file_scanned();
     TSFile {
       language: ts_language,
       parser,
@@ -136,6 +138,34 @@ impl TSFile {
     let ts_language = language.tree_sitter_language();
     parser.set_language(&ts_language).expect("Error loading parser");
     let tree = parser.parse(source_code, None);
+    execution_meta::record_file_scanned();
+    TSFile {
+      language: ts_language,
+      parser,
+      file: None,
+      tree,
+      source_code: source_code.to_string(),
+      new_path: None,
+      modified: false,
+    }
+  }
+
+  /// Parse source code incrementally, reusing the tree of a previous version of the source
+  ///
+  /// `old_tree` must be the tree of the previous source and `edit` the change turning that source
+  /// into `source_code`. Only the subtrees touched by the edit are parsed again.
+  pub fn from_source_code_with_old_tree(
+    source_code: &str,
+    mut old_tree: Tree,
+    edit: &InputEdit,
+    language: SupportedLanguage,
+  ) -> Self {
+    let mut parser = Parser::new();
+    let ts_language = language.tree_sitter_language();
+    parser.set_language(&ts_language).expect("Error loading parser");
+    old_tree.edit(edit);
+    let tree = parser.parse(source_code, Some(&old_tree));
+    execution_meta::record_file_scanned();
     TSFile {
       language: ts_language,
       parser,
@@ -187,11 +217,19 @@ impl TSFile {
         e
       ))
     })?;
-    let source_code = fs::read_to_string(&validated_path)?;
+    let source_code = fs::read_to_string(&validated_path).map_err(|e| match e.kind() {
+      // Refuse the file instead of lossily decoding it, saving it back would corrupt it
+      std::io::ErrorKind::InvalidData => std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("File '{}' is not valid UTF-8 (INVALID_ENCODING)", validated_path.display()),
+      ),
+      _ => e,
+    })?;
     let mut parser = Parser::new();
     let ts_language = language.tree_sitter_language();
     parser.set_language(&ts_language).expect("Error loading parser");
     let tree = parser.parse(&source_code, None);
+    execution_meta::record_file_scanned();
     Ok(TSFile {
       language: ts_language,
       parser,
@@ -435,6 +473,12 @@ impl TSFile {
     self.get_text_from_range(node.start_byte(), node.end_byte())
   }
 
+  /// Whitespace at the start of the line containing `byte_position`, up to that position
+  pub fn get_line_indentation(&self, byte_position: usize) -> String {
+    let line_start = self.source_code[..byte_position].rfind('\n').map(|pos| pos + 1).unwrap_or(0);
+    self.source_code[line_start..byte_position].chars().take_while(|c| c.is_whitespace()).collect()
+  }
+
   pub fn get_file_name_without_ext(&self) -> Option<String> {
     self.file.as_ref()?.file_stem()?.to_str().map(|s| s.to_string())
   }
@@ -465,6 +509,7 @@ impl TSFile {
   pub fn query(&self, query_string: &str) -> Result<Vec<Node<'_>>, Box<dyn std::error::Error>> {
     let tree = self.tree.as_ref().ok_or("No parsed tree available")?;
     let query = Query::new(&self.language, query_string)?;
+    execution_meta::record_query_executed();
     let mut cursor = QueryCursor::new();
     let root_node = tree.root_node();
     let mut nodes = Vec::new();
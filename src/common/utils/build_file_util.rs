@@ -0,0 +1,53 @@
+use std::fs;
+use std::path::Path;
+
+/// Maven properties and plugin settings holding the Java version, most specific first.
+const MAVEN_VERSION_TAGS: [&str; 5] = [
+  "maven.compiler.release",
+  "release",
+  "java.version",
+  "maven.compiler.target",
+  "maven.compiler.source",
+];
+
+/// Gradle settings holding the Java version, toolchains first.
+const GRADLE_VERSION_MARKERS: [&str; 4] =
+  ["JavaLanguageVersion.of(", "JavaVersion.VERSION_", "targetCompatibility", "sourceCompatibility"];
+
+/// Reads the leading version number of `text` (`17`, `1.8` -> 8, `1_8` -> 8).
+fn parse_leading_version(text: &str) -> Option<u32> {
+  let text = text.trim_start_matches(|c: char| c.is_whitespace() || matches!(c, '=' | '\'' | '"'));
+  let text = text.strip_prefix("1.").or_else(|| text.strip_prefix("1_")).unwrap_or(text);
+  let digits: String = text.chars().take_while(|c| c.is_ascii_digit()).collect();
+  digits.parse().ok().filter(|version| *version > 0)
+}
+
+fn detect_maven_java_version(pom_content: &str) -> Option<u32> {
+  MAVEN_VERSION_TAGS.iter().find_map(|tag| {
+    let start = pom_content.find(&format!("<{}>", tag))? + tag.len() + 2;
+    parse_leading_version(&pom_content[start..])
+  })
+}
+
+fn detect_gradle_java_version(build_content: &str) -> Option<u32> {
+  GRADLE_VERSION_MARKERS.iter().find_map(|marker| {
+    let start = build_content.find(marker)? + marker.len();
+    let rest = &build_content[start..];
+    // `sourceCompatibility = JavaVersion.VERSION_17` is found through the JavaVersion marker
+    parse_leading_version(rest.trim_start_matches(['(', ' ', '=']))
+  })
+}
+
+/// Detects the Java version the project compiles for from its Maven or Gradle build file.
+///
+/// # Returns
+/// The major version (`8` for `1.8`), or `None` when there is no build file or it doesn't state it
+pub fn detect_java_version(cwd: &Path) -> Option<u32> {
+  if let Ok(pom_content) = fs::read_to_string(cwd.join("pom.xml")) {
+    return detect_maven_java_version(&pom_content);
+  }
+  ["build.gradle.kts", "build.gradle"]
+    .iter()
+    .find_map(|file_name| fs::read_to_string(cwd.join(file_name)).ok())
+    .and_then(|build_content| detect_gradle_java_version(&build_content))
+}
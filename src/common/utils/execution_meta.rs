@@ -0,0 +1,64 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Instant;
+
+use crate::common::response::ResponseMeta;
+
+/// Process-wide execution counters reported in the `meta` object of responses.
+///
+/// Collection is disabled by default so commands pay a single atomic load per parse or query.
+/// The CLI enables it for `--with-meta`, and every `Response` built afterwards carries a snapshot.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static FILES_SCANNED: AtomicUsize = AtomicUsize::new(0);
+static QUERIES_EXECUTED: AtomicUsize = AtomicUsize::new(0);
+static STARTED_AT: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Resets the counters and starts collecting metadata.
+pub fn enable() {
+  FILES_SCANNED.store(0, Ordering::Relaxed);
+  QUERIES_EXECUTED.store(0, Ordering::Relaxed);
+  if let Ok(mut started_at) = STARTED_AT.lock() {
+    *started_at = Some(Instant::now());
+  }
+  ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn disable() {
+  ENABLED.store(false, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+  ENABLED.load(Ordering::Relaxed)
+}
+
+/// Records that a source file was parsed.
+pub fn record_file_scanned() {
+  if is_enabled() {
+    FILES_SCANNED.fetch_add(1, Ordering::Relaxed);
+  }
+}
+
+/// Records that a tree-sitter query was run.
+pub fn record_query_executed() {
+  if is_enabled() {
+    QUERIES_EXECUTED.fetch_add(1, Ordering::Relaxed);
+  }
+}
+
+/// Metadata gathered since `enable`, `None` while collection is disabled.
+pub fn snapshot() -> Option<ResponseMeta> {
+  if !is_enabled() {
+    return None;
+  }
+  let elapsed_ms = STARTED_AT
+    .lock()
+    .ok()
+    .and_then(|started_at| *started_at)
+    .map(|started_at| started_at.elapsed().as_millis() as u64)
+    .unwrap_or_default();
+  Some(ResponseMeta {
+    elapsed_ms,
+    files_scanned: FILES_SCANNED.load(Ordering::Relaxed),
+    queries_executed: QUERIES_EXECUTED.load(Ordering::Relaxed),
+  })
+}
@@ -0,0 +1,13 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Process-wide failure flag of commands whose outcome is reported through the exit code, like
+/// `validate-project --fail-on`. The response is still printed, then the CLI exits with status 1.
+static FAILED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_failed() {
+  FAILED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_failed() -> bool {
+  FAILED.load(Ordering::Relaxed)
+}
@@ -0,0 +1,70 @@
+use std::cell::RefCell;
+use std::fs;
+use std::path::Path;
+
+use crate::common::utils::time_util::get_utc_timestamp;
+
+/// Header comment placed above the `package` statement of generated files, e.g. a license.
+///
+/// The template may use the `{year}` and `{filename}` placeholders. A template that isn't a Java
+/// comment is wrapped in a block comment.
+#[derive(Clone, Debug)]
+pub struct FileHeader {
+  template: String,
+}
+
+impl FileHeader {
+  pub fn new(template: &str) -> Result<Self, String> {
+    let template = template.trim();
+    if template.is_empty() {
+      return Err("Header template is empty".to_string());
+    }
+    let template = match template.starts_with("/*") || template.starts_with("//") {
+      true => template.to_string(),
+      false => {
+        let lines: Vec<String> = template
+          .lines()
+          .map(|line| match line.trim_end().is_empty() {
+            true => " *".to_string(),
+            false => format!(" * {}", line.trim_end()),
+          })
+          .collect();
+        format!("/*\n{}\n */", lines.join("\n"))
+      }
+    };
+    Ok(Self { template })
+  }
+
+  pub fn from_file(path: &str) -> Result<Self, String> {
+    let template = fs::read_to_string(Path::new(path))
+      .map_err(|e| format!("Unable to read header file '{}': {}", path, e))?;
+    Self::new(&template)
+  }
+
+  /// Header text for the file, followed by a line break.
+  pub fn render(&self, file_name: &str) -> String {
+    let year = &get_utc_timestamp()[..4];
+    let header = self.template.replace("{year}", year).replace("{filename}", file_name);
+    format!("{}\n", header)
+  }
+}
+
+thread_local! {
+  /// Header of the command running on this thread, none when unset.
+  ///
+  /// The CLI sets it for `--header-file`, and serve mode for the requests passing it.
+  static HEADER: RefCell<Option<FileHeader>> = const { RefCell::new(None) };
+}
+
+pub fn set_header(header: FileHeader) {
+  HEADER.with(|current| *current.borrow_mut() = Some(header));
+}
+
+pub fn reset_header() {
+  HEADER.with(|current| *current.borrow_mut() = None);
+}
+
+/// Header of a new file named `file_name` (`Order.java`), `None` when no header is configured.
+pub fn render_header(file_name: &str) -> Option<String> {
+  HEADER.with(|current| current.borrow().as_ref().map(|header| header.render(file_name)))
+}
@@ -0,0 +1,6 @@
+use sha2::{Digest, Sha256};
+
+/// Returns the lowercase hex SHA-256 digest of the content, as written to disk.
+pub fn get_content_hash(content: &str) -> String {
+  Sha256::digest(content.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
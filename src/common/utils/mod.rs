@@ -1,3 +1,15 @@
+pub mod build_file_util;
 pub mod case_util;
+pub mod execution_meta;
+pub mod exit_status;
+pub mod file_header;
+pub mod hash_util;
+pub mod package_path;
+pub mod parse_cache;
 pub mod path_security_util;
 pub mod path_util;
+pub mod progress;
+pub mod scan_cache;
+pub mod scan_deadline;
+pub mod string_literal_util;
+pub mod time_util;
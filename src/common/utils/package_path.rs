@@ -0,0 +1,92 @@
+use std::cell::RefCell;
+use std::path::{Component, Path, PathBuf};
+
+/// Translates package names into directories relative to a source root.
+pub trait PackagePathStrategy {
+  fn to_relative_path(&self, package_name: &str) -> PathBuf;
+}
+
+/// One directory per package segment, `com.foo.bar` in `com/foo/bar`.
+pub struct StandardPackagePathStrategy;
+
+impl PackagePathStrategy for StandardPackagePathStrategy {
+  fn to_relative_path(&self, package_name: &str) -> PathBuf {
+    package_name.split('.').filter(|segment| !segment.is_empty()).collect()
+  }
+}
+
+/// Maps package prefixes to custom directories, e.g. `com.foo=foo` places `com.foo.bar` in
+/// `foo/bar` and `com.foo=` places `com.foo` in the source root. The longest matching prefix wins,
+/// and packages no prefix matches follow the standard layout.
+pub struct MappedPackagePathStrategy {
+  mappings: Vec<(String, PathBuf)>,
+}
+
+impl MappedPackagePathStrategy {
+  pub fn new(mappings: Vec<(String, PathBuf)>) -> Self {
+    Self { mappings }
+  }
+
+  /// Parses a `PREFIX=DIR` mapping. The directory must stay within the source root, so absolute
+  /// paths and `..` components are rejected.
+  pub fn parse_mapping(mapping: &str) -> Result<(String, PathBuf), String> {
+    let (prefix, directory) = mapping
+      .split_once('=')
+      .ok_or_else(|| format!("Package path mapping '{}' must be PREFIX=DIR", mapping))?;
+    let prefix = prefix.trim();
+    if prefix.is_empty() {
+      return Err(format!("Package path mapping '{}' has no package prefix", mapping));
+    }
+    let directory = Path::new(directory.trim());
+    if !directory.components().all(|component| matches!(component, Component::Normal(_))) {
+      return Err(format!(
+        "Package path mapping '{}' must map to a relative directory without '..'",
+        mapping
+      ));
+    }
+    Ok((prefix.to_string(), directory.to_path_buf()))
+  }
+}
+
+impl PackagePathStrategy for MappedPackagePathStrategy {
+  fn to_relative_path(&self, package_name: &str) -> PathBuf {
+    let matching_mapping = self
+      .mappings
+      .iter()
+      .filter_map(|(prefix, directory)| {
+        let rest = package_name.strip_prefix(prefix.as_str())?;
+        (rest.is_empty() || rest.starts_with('.')).then_some((prefix, directory, rest))
+      })
+      .max_by_key(|(prefix, _, _)| prefix.len());
+    match matching_mapping {
+      Some((_, directory, rest)) => {
+        directory.join(StandardPackagePathStrategy.to_relative_path(rest))
+      }
+      None => StandardPackagePathStrategy.to_relative_path(package_name),
+    }
+  }
+}
+
+thread_local! {
+  /// Layout of the command running on this thread, the standard one when unset.
+  ///
+  /// The CLI sets it for `--package-path-mapping`, and serve mode for the requests passing it.
+  /// Keeping it per thread lets concurrent commands use different layouts.
+  static STRATEGY: RefCell<Option<Box<dyn PackagePathStrategy>>> = RefCell::new(None);
+}
+
+pub fn set_strategy(strategy: impl PackagePathStrategy + 'static) {
+  STRATEGY.with(|current| *current.borrow_mut() = Some(Box::new(strategy)));
+}
+
+pub fn reset_strategy() {
+  STRATEGY.with(|current| *current.borrow_mut() = None);
+}
+
+/// Directory of the package relative to its source root, following the current strategy.
+pub fn to_relative_path(package_name: &str) -> PathBuf {
+  STRATEGY.with(|current| match current.borrow().as_ref() {
+    Some(strategy) => strategy.to_relative_path(package_name),
+    None => StandardPackagePathStrategy.to_relative_path(package_name),
+  })
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use base64::prelude::*;
+use tree_sitter::{InputEdit, Point, Tree};
+
+use crate::common::supported_language::SupportedLanguage;
+use crate::common::ts_file::TSFile;
+
+/// Last parse of a file written by a field insertion.
+struct CachedParse {
+  source_code: String,
+  tree: Tree,
+  reuses_count: usize,
+}
+
+/// Session-scoped parses of the files edited in serve mode, keyed by file path.
+///
+/// A client adding several fields in a row sends the buffer it got back from the previous
+/// insertion, so the next parse only has to reparse the edit between both versions instead of the
+/// whole file. Disabled outside serve mode, where every process parses a file at most once.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static PARSES: Mutex<Option<HashMap<PathBuf, CachedParse>>> = Mutex::new(None);
+
+pub fn enable() {
+  ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+  ENABLED.load(Ordering::Relaxed)
+}
+
+/// Row and byte column of `byte_position`, the coordinates tree-sitter expects in an `InputEdit`.
+fn get_point(source_code: &str, byte_position: usize) -> Point {
+  let before = &source_code[..byte_position];
+  let row = before.matches('\n').count();
+  let column = before.rfind('\n').map_or(byte_position, |index| byte_position - index - 1);
+  Point::new(row, column)
+}
+
+/// Computes the single edit turning `old_source_code` into `new_source_code`, spanning from the
+/// first to the last differing byte.
+pub fn compute_input_edit(old_source_code: &str, new_source_code: &str) -> InputEdit {
+  let mut start_byte = old_source_code
+    .bytes()
+    .zip(new_source_code.bytes())
+    .take_while(|(old_byte, new_byte)| old_byte == new_byte)
+    .count();
+  while !old_source_code.is_char_boundary(start_byte) {
+    start_byte -= 1;
+  }
+  let max_suffix_len = old_source_code.len().min(new_source_code.len()) - start_byte;
+  let mut suffix_len = old_source_code
+    .bytes()
+    .rev()
+    .zip(new_source_code.bytes().rev())
+    .take(max_suffix_len)
+    .take_while(|(old_byte, new_byte)| old_byte == new_byte)
+    .count();
+  while !old_source_code.is_char_boundary(old_source_code.len() - suffix_len) {
+    suffix_len -= 1;
+  }
+  let old_end_byte = old_source_code.len() - suffix_len;
+  let new_end_byte = new_source_code.len() - suffix_len;
+  InputEdit {
+    start_byte,
+    old_end_byte,
+    new_end_byte,
+    start_position: get_point(old_source_code, start_byte),
+    old_end_position: get_point(old_source_code, old_end_byte),
+    new_end_position: get_point(new_source_code, new_end_byte),
+  }
+}
+
+/// Parses the base64 source of `file_path`, reparsing incrementally from the cached parse of the
+/// file when there is one.
+pub fn parse_base64_source_code(
+  base64_source_code: &str,
+  file_path: &Path,
+  language: SupportedLanguage,
+) -> TSFile {
+  let source_code =
+    BASE64_STANDARD.decode(base64_source_code).ok().and_then(|bytes| String::from_utf8(bytes).ok());
+  let Some(source_code) = source_code.filter(|_| is_enabled()) else {
+    return TSFile::from_base64_source_code(base64_source_code, language);
+  };
+  let old_parse = PARSES.lock().ok().and_then(|mut parses| {
+    let cached = parses.as_mut()?.get_mut(file_path)?;
+    cached.reuses_count += 1;
+    Some((cached.source_code.clone(), cached.tree.clone()))
+  });
+  match old_parse {
+    Some((old_source_code, old_tree)) => {
+      let edit = compute_input_edit(&old_source_code, &source_code);
+      TSFile::from_source_code_with_old_tree(&source_code, old_tree, &edit, language)
+    }
+    None => TSFile::from_source_code(&source_code, language),
+  }
+}
+
+/// Caches the current parse of `ts_file` as the last parse of `file_path`.
+pub fn store(file_path: &Path, ts_file: &TSFile) {
+  let Some(tree) = ts_file.tree.clone().filter(|_| is_enabled()) else {
+    return;
+  };
+  if let Ok(mut parses) = PARSES.lock() {
+    let parses = parses.get_or_insert_with(HashMap::new);
+    let reuses_count = parses.get(file_path).map_or(0, |cached| cached.reuses_count);
+    parses.insert(
+      file_path.to_path_buf(),
+      CachedParse { source_code: ts_file.source_code.clone(), tree, reuses_count },
+    );
+  }
+}
+
+/// Number of times the cached parse of `file_path` was reused, `None` when it isn't cached.
+pub fn get_reuses_count(file_path: &Path) -> Option<usize> {
+  let parses = PARSES.lock().ok()?;
+  parses.as_ref()?.get(file_path).map(|cached| cached.reuses_count)
+}
+
+/// Drops the cached parse of `file_path`, or of every file when `None`, after the client changed
+/// them outside of the session.
+///
+/// # Returns
+/// The number of cached parses dropped
+pub fn invalidate(file_path: Option<&Path>) -> usize {
+  let Ok(mut parses) = PARSES.lock() else {
+    return 0;
+  };
+  let Some(parses) = parses.as_mut() else {
+    return 0;
+  };
+  match file_path {
+    Some(file_path) => parses.remove(file_path).map_or(0, |_| 1),
+    None => parses.drain().count(),
+  }
+}
@@ -1,15 +1,22 @@
 #![allow(dead_code)]
 
 use std::fs;
+use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
 
 use walkdir::WalkDir;
 
 use crate::{
   commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType,
   common::{
-    supported_language::SupportedLanguage, ts_file::TSFile,
-    utils::path_security_util::PathSecurityValidator,
+    supported_language::SupportedLanguage,
+    ts_file::TSFile,
+    utils::{
+      package_path,
+      path_security_util::PathSecurityValidator,
+      scan_deadline::{ScanDeadline, SkipReason},
+    },
   },
 };
 
@@ -31,23 +38,127 @@ fn find_directory_recursively(root_dir: &Path, target_dir: &str) -> Option<PathB
   None
 }
 
-pub fn parse_all_files(cwd: &Path, source_directory_type: &JavaSourceDirectoryType) -> Vec<TSFile> {
+/// Lists every `.java` file under the given source directory of `cwd`.
+pub fn get_all_java_file_paths(
+  cwd: &Path,
+  source_directory_type: &JavaSourceDirectoryType,
+) -> Vec<PathBuf> {
   let extension = "java";
-  let mut files = Vec::new();
-  let src_dir_path = source_directory_type.get_directory_path();
-  let target_dir = cwd.join(src_dir_path);
-  if target_dir.exists() {
-    for entry in WalkDir::new(&target_dir).into_iter().flatten() {
-      let path = entry.path();
-      if let Some(ext) = path.extension()
-        && ext.to_string_lossy().eq_ignore_ascii_case(extension)
-        && let Ok(ts_file) = TSFile::from_file(path, cwd, SupportedLanguage::Java)
-      {
-        files.push(ts_file);
-      }
+  let target_dir = cwd.join(source_directory_type.get_directory_path());
+  if !target_dir.exists() {
+    return Vec::new();
+  }
+  WalkDir::new(&target_dir)
+    .into_iter()
+    .flatten()
+    .map(|entry| entry.into_path())
+    .filter(|path| {
+      path.extension().is_some_and(|ext| ext.to_string_lossy().eq_ignore_ascii_case(extension))
+    })
+    .collect()
+}
+
+pub fn parse_all_files(cwd: &Path, source_directory_type: &JavaSourceDirectoryType) -> Vec<TSFile> {
+  get_all_java_file_paths(cwd, source_directory_type)
+    .iter()
+    .filter_map(|path| TSFile::from_file(path, cwd, SupportedLanguage::Java).ok())
+    .collect()
+}
+
+/// Whether both paths point to the same file, comparing them as given when either doesn't exist.
+pub fn is_same_file(a: &Path, b: &Path) -> bool {
+  match (a.canonicalize(), b.canonicalize()) {
+    (Ok(a), Ok(b)) => a == b,
+    _ => a == b,
+  }
+}
+
+/// Last modification time of the file in milliseconds since the Unix epoch, read from the
+/// filesystem metadata without opening the file.
+pub fn get_modified_time_ms(path: &Path) -> Option<u64> {
+  let modified = fs::metadata(path).and_then(|metadata| metadata.modified()).ok()?;
+  modified.duration_since(UNIX_EPOCH).ok().map(|duration| duration.as_millis() as u64)
+}
+
+/// Parses the Java files under the source directory until `deadline` expires. Each file read is
+/// counted as processed on the deadline, whether or not it parses.
+pub fn parse_all_files_within_deadline(
+  cwd: &Path,
+  source_directory_type: &JavaSourceDirectoryType,
+  deadline: &ScanDeadline,
+) -> Vec<TSFile> {
+  parse_files_within_deadline(cwd, &get_all_java_file_paths(cwd, source_directory_type), deadline)
+}
+
+/// Parses the given files until `deadline` expires, counting each file read as processed. Files
+/// that aren't valid UTF-8 are recorded as skipped on `deadline`.
+pub fn parse_files_within_deadline(
+  cwd: &Path,
+  paths: &[PathBuf],
+  deadline: &ScanDeadline,
+) -> Vec<TSFile> {
+  paths
+    .iter()
+    .take_while(|_| !deadline.is_expired())
+    .filter_map(|path| {
+      let ts_file = parse_scanned_file(cwd, path, deadline);
+      deadline.mark_processed();
+      ts_file
+    })
+    .collect()
+}
+
+/// Parses one file of a project scan, recording it as skipped on `deadline` when its content
+/// isn't valid UTF-8.
+pub fn parse_scanned_file(cwd: &Path, path: &Path, deadline: &ScanDeadline) -> Option<TSFile> {
+  match TSFile::from_file(path, cwd, SupportedLanguage::Java) {
+    Ok(ts_file) => Some(ts_file),
+    Err(e) if e.kind() == ErrorKind::InvalidData => {
+      deadline.mark_skipped(path, SkipReason::InvalidEncoding);
+      None
+    }
+    Err(_) => None,
+  }
+}
+
+/// Reads the source of one file of a project scan, recording it as skipped on `deadline` when its
+/// content isn't valid UTF-8.
+///
+/// # Returns
+/// The source code, `None` when the file was skipped
+pub fn read_scanned_source(path: &Path, deadline: &ScanDeadline) -> Result<Option<String>, String> {
+  match fs::read_to_string(path) {
+    Ok(source_code) => Ok(Some(source_code)),
+    Err(e) if e.kind() == ErrorKind::InvalidData => {
+      deadline.mark_skipped(path, SkipReason::InvalidEncoding);
+      Ok(None)
     }
+    Err(e) => Err(format!("Unable to read '{}': {}", path.display(), e)),
+  }
+}
+
+/// Finds and parses the Java file declaring `class_name` as its public type.
+///
+/// Java requires a public top-level type to live in a file with the same name, so only the file
+/// whose stem matches `class_name` is parsed instead of the whole source directory.
+///
+/// # Returns
+/// The parsed `TSFile`, or `None` if no such file exists under the source directory
+pub fn find_file_by_class_name(
+  cwd: &Path,
+  source_directory_type: &JavaSourceDirectoryType,
+  class_name: &str,
+) -> Option<TSFile> {
+  let target_dir = cwd.join(source_directory_type.get_directory_path());
+  if !target_dir.exists() {
+    return None;
   }
-  files
+  let file_name = format!("{}.java", class_name);
+  WalkDir::new(&target_dir)
+    .into_iter()
+    .flatten()
+    .filter(|entry| entry.file_name().to_string_lossy() == file_name)
+    .find_map(|entry| TSFile::from_file(entry.path(), cwd, SupportedLanguage::Java).ok())
 }
 
 /// Resolves the file system path for a given package scope within the specified source directory type.
@@ -111,8 +222,9 @@ pub fn get_file_path_from_package_scope(
     .unwrap_or_else(|| root_dir.join(src_dir_name));
   // Validate that the source directory is within root (for the constructed case)
   let validated_source_dir = validator.validate_directory_creation(&source_dir)?;
-  // Convert package scope to path (e.g., "com.example.foo" -> "com/example/foo")
-  let package_as_path = package_scope.replace('.', "/");
+  // Convert package scope to path with the current strategy (by default "com.example.foo" ->
+  // "com/example/foo")
+  let package_as_path = package_path::to_relative_path(package_scope);
   let full_package_dir = validated_source_dir.join(package_as_path);
   // Validate the full package directory path for security
   let validated_package_dir = validator.validate_directory_creation(&full_package_dir)?;
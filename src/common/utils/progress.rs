@@ -0,0 +1,40 @@
+use std::cell::RefCell;
+
+use serde::Serialize;
+
+/// Progress of a project-wide refactor, reported at file boundaries.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProgressEvent {
+  pub phase: String,
+  /// Files processed so far in the phase, counting the one just finished
+  pub current: usize,
+  pub total: usize,
+}
+
+type ProgressSink = Box<dyn Fn(&ProgressEvent)>;
+
+thread_local! {
+  /// Receiver of the progress events of the command running on this thread.
+  ///
+  /// No sink is set by default, so engines pay a single lookup per file. The CLI sets one for
+  /// `--progress`, and serve mode for the requests asking for it. Commands run on the thread that
+  /// received them, which keeps concurrent commands from seeing each other's events.
+  static SINK: RefCell<Option<ProgressSink>> = RefCell::new(None);
+}
+
+pub fn set_sink(sink: impl Fn(&ProgressEvent) + 'static) {
+  SINK.with(|current_sink| *current_sink.borrow_mut() = Some(Box::new(sink)));
+}
+
+pub fn clear_sink() {
+  SINK.with(|current_sink| *current_sink.borrow_mut() = None);
+}
+
+/// Reports that `current` of the `total` files of `phase` were processed.
+pub fn report(phase: &str, current: usize, total: usize) {
+  SINK.with(|current_sink| {
+    if let Some(sink) = current_sink.borrow().as_ref() {
+      sink(&ProgressEvent { phase: phase.to_string(), current, total });
+    }
+  });
+}
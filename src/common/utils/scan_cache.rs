@@ -0,0 +1,126 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use rayon::prelude::*;
+
+use crate::common::utils::scan_deadline::ScanDeadline;
+
+/// Modification stamp of a file. A file whose stamp is unchanged since the last scan is assumed
+/// to have the same content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileStamp {
+  modified: SystemTime,
+  len: u64,
+}
+
+impl FileStamp {
+  fn read(path: &Path) -> Option<Self> {
+    let metadata = fs::metadata(path).ok()?;
+    Some(Self { modified: metadata.modified().ok()?, len: metadata.len() })
+  }
+}
+
+struct CachedFile<T> {
+  stamp: FileStamp,
+  value: T,
+}
+
+/// Per-file results of project scans, keyed by the scanned directory.
+///
+/// Repeated scans of the same directory only parse the files whose modification time or size
+/// changed since the previous scan, and reuse the cached results of every other file. Files that
+/// no longer exist are dropped from the cache on the next scan.
+pub struct ScanCache<T> {
+  directories: Mutex<HashMap<PathBuf, HashMap<PathBuf, CachedFile<T>>>>,
+}
+
+impl<T> Default for ScanCache<T> {
+  fn default() -> Self {
+    Self { directories: Mutex::new(HashMap::new()) }
+  }
+}
+
+impl<T: Clone + Send + Sync> ScanCache<T> {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Produces the result of every file in `paths` in parallel, calling `parse` only for files not
+  /// cached or changed since the last scan of `cwd`. Files not yet started when `deadline`
+  /// expires are skipped, and files `parse` fails on are neither returned nor cached.
+  ///
+  /// # Returns
+  /// The results in the order of `paths`
+  pub fn scan<F>(&self, cwd: &Path, paths: &[PathBuf], deadline: &ScanDeadline, parse: F) -> Vec<T>
+  where
+    F: Fn(&Path) -> Option<T> + Sync,
+  {
+    let mut cached_files = self
+      .directories
+      .lock()
+      .ok()
+      .and_then(|mut directories| directories.remove(cwd))
+      .unwrap_or_default();
+    let scanned: Vec<(PathBuf, FileStamp, T, bool)> = paths
+      .par_iter()
+      .filter_map(|path| {
+        if deadline.is_expired() {
+          return None;
+        }
+        let stamp = FileStamp::read(path);
+        let cached = cached_files.get(path).filter(|cached| Some(cached.stamp) == stamp);
+        let result = match cached {
+          Some(cached) => Some((path.clone(), cached.stamp, cached.value.clone(), false)),
+          None => {
+            stamp.and_then(|stamp| parse(path).map(|value| (path.clone(), stamp, value, true)))
+          }
+        };
+        deadline.mark_processed();
+        result
+      })
+      .collect();
+    // Unchanged files that were skipped by the deadline stay cached for the next scan
+    let scanned_paths: HashSet<&PathBuf> = paths.iter().collect();
+    cached_files.retain(|path, _| scanned_paths.contains(path));
+    let mut results = Vec::with_capacity(scanned.len());
+    for (path, stamp, value, is_parsed) in scanned {
+      if is_parsed {
+        cached_files.insert(path, CachedFile { stamp, value: value.clone() });
+      }
+      results.push(value);
+    }
+    if let Ok(mut directories) = self.directories.lock() {
+      directories.insert(cwd.to_path_buf(), cached_files);
+    }
+    results
+  }
+
+  /// Number of files cached for `cwd`.
+  pub fn get_cached_files_count(&self, cwd: &Path) -> usize {
+    self
+      .directories
+      .lock()
+      .ok()
+      .and_then(|directories| directories.get(cwd).map(|cached_files| cached_files.len()))
+      .unwrap_or_default()
+  }
+
+  /// Drops the cached results of `cwd`, or of every directory when `None`.
+  ///
+  /// # Returns
+  /// The number of cached files dropped
+  pub fn clear(&self, cwd: Option<&Path>) -> usize {
+    let Ok(mut directories) = self.directories.lock() else {
+      return 0;
+    };
+    match cwd {
+      Some(cwd) => {
+        directories.remove(cwd).map(|cached_files| cached_files.len()).unwrap_or_default()
+      }
+      None => directories.drain().map(|(_, cached_files)| cached_files.len()).sum(),
+    }
+  }
+}
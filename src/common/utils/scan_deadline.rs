@@ -0,0 +1,95 @@
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SkipReason {
+  /// The file content isn't valid UTF-8
+  InvalidEncoding,
+}
+
+/// File a scan left out instead of aborting.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedFile {
+  pub file_path: String,
+  pub reason: SkipReason,
+}
+
+/// Time budget of a project scan, shared by the sequential and the rayon scan loops.
+///
+/// Scans check `is_expired` before processing each file and stop once it returns true, so a
+/// timed-out scan returns the results gathered so far instead of running to completion. Once
+/// expired the deadline stays expired, which lets parallel workers skip their remaining files.
+/// Files a scan can't read are recorded as skipped, so one bad file doesn't break the scan.
+pub struct ScanDeadline {
+  started_at: Instant,
+  timeout: Option<Duration>,
+  expired: AtomicBool,
+  processed_files_count: AtomicUsize,
+  skipped_files: Mutex<Vec<SkippedFile>>,
+}
+
+impl ScanDeadline {
+  /// Creates a deadline expiring `timeout_ms` milliseconds from now, or never when `None`.
+  pub fn new(timeout_ms: Option<u64>) -> Self {
+    Self {
+      started_at: Instant::now(),
+      timeout: timeout_ms.map(Duration::from_millis),
+      expired: AtomicBool::new(false),
+      processed_files_count: AtomicUsize::new(0),
+      skipped_files: Mutex::new(Vec::new()),
+    }
+  }
+
+  pub fn unbounded() -> Self {
+    Self::new(None)
+  }
+
+  pub fn is_expired(&self) -> bool {
+    if self.expired.load(Ordering::Relaxed) {
+      return true;
+    }
+    let Some(timeout) = self.timeout else {
+      return false;
+    };
+    if self.started_at.elapsed() >= timeout {
+      self.expired.store(true, Ordering::Relaxed);
+      return true;
+    }
+    false
+  }
+
+  /// Records that one more file was fully processed before the deadline.
+  pub fn mark_processed(&self) {
+    self.processed_files_count.fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub fn processed_files_count(&self) -> usize {
+    self.processed_files_count.load(Ordering::Relaxed)
+  }
+
+  /// Whether the scan stopped early, i.e. its results are partial.
+  pub fn is_truncated(&self) -> bool {
+    self.expired.load(Ordering::Relaxed)
+  }
+
+  /// Records that `path` was left out of the scan.
+  pub fn mark_skipped(&self, path: &Path, reason: SkipReason) {
+    if let Ok(mut skipped_files) = self.skipped_files.lock() {
+      skipped_files.push(SkippedFile { file_path: path.display().to_string(), reason });
+    }
+  }
+
+  /// Files left out of the scan, sorted by path.
+  pub fn skipped_files(&self) -> Vec<SkippedFile> {
+    let mut skipped_files =
+      self.skipped_files.lock().map(|skipped_files| skipped_files.clone()).unwrap_or_default();
+    skipped_files.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+    skipped_files
+  }
+}
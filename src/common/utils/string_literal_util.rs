@@ -0,0 +1,23 @@
+/// Quotes the value as a Java string literal, escaping backslashes and double quotes.
+pub fn to_java_string_literal(value: &str) -> String {
+  format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Reads the value of a Java string literal, unescaping backslashes and double quotes. Text that
+/// isn't quoted is returned as is.
+pub fn from_java_string_literal(literal: &str) -> String {
+  let trimmed = literal.trim();
+  let inner = trimmed.strip_prefix('"').and_then(|s| s.strip_suffix('"')).unwrap_or(trimmed);
+  let mut value = String::with_capacity(inner.len());
+  let mut chars = inner.chars();
+  while let Some(c) = chars.next() {
+    match (c, chars.clone().next()) {
+      ('\\', Some(escaped @ ('\\' | '"'))) => {
+        value.push(escaped);
+        chars.next();
+      }
+      _ => value.push(c),
+    }
+  }
+  value
+}
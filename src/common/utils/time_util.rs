@@ -0,0 +1,27 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Current UTC time as `yyyyMMddHHmmss`, e.g. the prefix of Liquibase changeset ids.
+pub fn get_utc_timestamp() -> String {
+  let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+  let (days, seconds_of_day) = ((seconds / 86_400) as i64, seconds % 86_400);
+  // Civil date from days since 1970-01-01 (proleptic Gregorian calendar)
+  let z = days + 719_468;
+  let era = z.div_euclid(146_097);
+  let day_of_era = z.rem_euclid(146_097);
+  let year_of_era =
+    (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let month_index = (5 * day_of_year + 2) / 153;
+  let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+  let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+  let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+  format!(
+    "{:04}{:02}{:02}{:02}{:02}{:02}",
+    year,
+    month,
+    day,
+    seconds_of_day / 3_600,
+    seconds_of_day % 3_600 / 60,
+    seconds_of_day % 60
+  )
+}
@@ -1,6 +1,11 @@
+use std::path::PathBuf;
+
 use clap::Parser;
 use syntaxpresso_core::commands::Commands;
 use syntaxpresso_core::common::error_response::ErrorResponse;
+use syntaxpresso_core::common::utils::file_header::{self, FileHeader};
+use syntaxpresso_core::common::utils::package_path::{self, MappedPackagePathStrategy};
+use syntaxpresso_core::common::utils::{execution_meta, exit_status, progress};
 
 #[derive(Parser)]
 #[command(name = "syntaxpresso-core")]
@@ -11,13 +16,54 @@ use syntaxpresso_core::common::error_response::ErrorResponse;
 struct Cli {
   #[command(subcommand)]
   command: Commands,
+
+  /// Adds execution metadata (timing, files scanned, queries run) to the response
+  #[arg(long, global = true)]
+  with_meta: bool,
+
+  /// Writes progress events of project-wide refactors to stderr, one JSON line per file
+  #[arg(long, global = true)]
+  progress: bool,
+
+  /// Places packages starting with PREFIX under DIR of the source root instead of one directory
+  /// per package segment, e.g. `com.example.legacy=legacy`. Can be repeated
+  #[arg(long = "package-path-mapping", value_name = "PREFIX=DIR", global = true, value_parser = MappedPackagePathStrategy::parse_mapping)]
+  package_path_mappings: Vec<(String, PathBuf)>,
+
+  /// Prepends the header read from FILE to the generated Java files, e.g. a license comment.
+  /// `{year}` and `{filename}` are replaced with the current year and the file name
+  #[arg(long, value_name = "FILE", global = true, value_parser = FileHeader::from_file)]
+  header_file: Option<FileHeader>,
 }
 
 fn main() {
   let cli = Cli::parse();
+  if cli.with_meta {
+    execution_meta::enable();
+  }
+  if !cli.package_path_mappings.is_empty() {
+    package_path::set_strategy(MappedPackagePathStrategy::new(cli.package_path_mappings));
+  }
+  if let Some(header) = cli.header_file {
+    file_header::set_header(header);
+  }
+  if cli.progress {
+    progress::set_sink(|event| {
+      if let Ok(event_json) = serde_json::to_string(event) {
+        eprintln!("{}", event_json);
+      }
+    });
+  }
 
   match cli.command.execute() {
-    Ok(json) => println!("{}", json),
+    // Serve mode writes its responses as requests arrive and has nothing left to print
+    Ok(json) if json.is_empty() => {}
+    Ok(json) => {
+      println!("{}", json);
+      if exit_status::is_failed() {
+        std::process::exit(1);
+      }
+    }
     Err(e) => {
       let error_response =
         ErrorResponse { error: "execution_error".to_string(), message: e.to_string() };
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod add_constructor_injection_service_tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::add_constructor_injection_response::AddConstructorInjectionResponse;
+  use syntaxpresso_core::commands::java::services::add_constructor_injection_service::run;
+  use tempfile::TempDir;
+
+  fn setup_file(file_name: &str, source: &str) -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example/service");
+    fs::create_dir_all(&package_dir).unwrap();
+    let file_path = package_dir.join(file_name);
+    fs::write(&file_path, source).unwrap();
+    (temp_dir, file_path)
+  }
+
+  fn inject(
+    source: &str,
+    dependency_type: &str,
+    dependency_package_name: Option<&str>,
+  ) -> (Result<AddConstructorInjectionResponse, String>, String) {
+    let (temp_dir, file_path) = setup_file("UserService.java", source);
+    let b64_source = BASE64_STANDARD.encode(source);
+    let result =
+      run(temp_dir.path(), &b64_source, &file_path, dependency_type, dependency_package_name, None);
+    (result, fs::read_to_string(&file_path).unwrap())
+  }
+
+  #[test]
+  fn test_creates_constructor_when_class_has_none() {
+    let source = "package com.example.service;\n\n@Service\npublic class UserService {\n  public void register() {}\n}\n";
+
+    let (result, written) = inject(source, "UserRepository", Some("com.example.repository"));
+
+    let response = result.unwrap();
+    assert_eq!(response.field_name, "userRepository");
+    assert!(response.field_added && response.constructor_parameter_added);
+    assert!(response.constructor_created);
+    assert_eq!(
+      written,
+      "package com.example.service;\n\nimport com.example.repository.UserRepository;\n\n@Service\npublic class UserService {\n  private final UserRepository userRepository;\n\n  public UserService(UserRepository userRepository) {\n    this.userRepository = userRepository;\n  }\n\n  public void register() {}\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_extends_existing_constructor() {
+    let source = "package com.example.service;\n\npublic class UserService {\n  private final Clock clock;\n\n  public UserService(Clock clock) {\n    this.clock = clock;\n  }\n}\n";
+
+    let (result, written) = inject(source, "PasswordEncoder", Some("com.example.service"));
+
+    let response = result.unwrap();
+    assert!(response.field_added && response.constructor_parameter_added);
+    assert!(!response.constructor_created);
+    assert_eq!(
+      written,
+      "package com.example.service;\n\npublic class UserService {\n  private final Clock clock;\n  private final PasswordEncoder passwordEncoder;\n\n  public UserService(Clock clock, PasswordEncoder passwordEncoder) {\n    this.clock = clock;\n    this.passwordEncoder = passwordEncoder;\n  }\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_rejects_ambiguous_constructors() {
+    let source = "package com.example.service;\n\npublic class UserService {\n  public UserService() {}\n\n  public UserService(Clock clock) {}\n}\n";
+
+    let (result, written) = inject(source, "Clock", Some("java.time"));
+
+    assert_eq!(
+      result.err().unwrap(),
+      "Class 'UserService' declares 2 constructors, unable to choose the one receiving 'clock'"
+    );
+    assert_eq!(written, source);
+  }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod add_repository_query_method_service_tests {
+  use std::fs;
+  use std::path::{Path, PathBuf};
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::add_repository_query_method_response::AddRepositoryQueryMethodResponse;
+  use syntaxpresso_core::commands::java::services::add_repository_query_method_service::run;
+  use tempfile::TempDir;
+
+  const REPOSITORY_SOURCE: &str = "package com.example.repository;\n\nimport com.example.domain.Book;\nimport org.springframework.data.jpa.repository.JpaRepository;\n\npublic interface BookRepository extends JpaRepository<Book, Long> {\n}\n";
+
+  fn write_file(root: &Path, relative_path: &str, source: &str) -> PathBuf {
+    let path = root.join("src/main/java").join(relative_path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, source).unwrap();
+    path
+  }
+
+  fn setup_project() -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    write_file(
+      temp_dir.path(),
+      "com/example/domain/Book.java",
+      "package com.example.domain;\n\nimport com.example.domain.enums.Status;\nimport java.math.BigDecimal;\n\n@Entity\npublic class Book {\n  @Id\n  private Long id;\n\n  private String title;\n\n  private BigDecimal price;\n\n  @Enumerated(EnumType.STRING)\n  private Status status;\n\n  @ManyToOne\n  private Author author;\n}\n",
+    );
+    write_file(
+      temp_dir.path(),
+      "com/example/domain/Author.java",
+      "package com.example.domain;\n\n@Entity\npublic class Author {\n  @Id\n  private Long id;\n\n  private String name;\n}\n",
+    );
+    let repository_path =
+      write_file(temp_dir.path(), "com/example/repository/BookRepository.java", REPOSITORY_SOURCE);
+    (temp_dir, repository_path)
+  }
+
+  fn add_method(
+    project: &(TempDir, PathBuf),
+    method_name: &str,
+    pageable: bool,
+    sort: bool,
+  ) -> Result<AddRepositoryQueryMethodResponse, String> {
+    let (temp_dir, repository_path) = project;
+    let b64_source = BASE64_STANDARD.encode(fs::read_to_string(repository_path).unwrap());
+    run(temp_dir.path(), &b64_source, repository_path, method_name, pageable, sort)
+  }
+
+  #[test]
+  fn test_pageable_method_returns_page_with_imports() {
+    let project = setup_project();
+
+    let response = add_method(&project, "findByStatus", true, false).unwrap();
+    let source = fs::read_to_string(&project.1).unwrap();
+
+    assert_eq!(
+      response.method_signature,
+      "Page<Book> findByStatus(Status status, Pageable pageable)"
+    );
+    assert_eq!(response.return_type, "Page<Book>");
+    assert_eq!(
+      response.imports_added,
+      vec![
+        "org.springframework.data.domain.Page",
+        "org.springframework.data.domain.Pageable",
+        "com.example.domain.enums.Status",
+      ]
+    );
+    assert!(
+      source.contains(
+        "public interface BookRepository extends JpaRepository<Book, Long> {\n  Page<Book> findByStatus(Status status, Pageable pageable);\n}"
+      ),
+      "{}",
+      source
+    );
+  }
+
+  #[test]
+  fn test_resolves_nested_properties_keywords_and_sort() {
+    let project = setup_project();
+
+    let response = add_method(
+      &project,
+      "findByAuthorNameAndPriceBetweenAndStatusInOrderByTitleDesc",
+      false,
+      true,
+    )
+    .unwrap();
+    let second = add_method(&project, "findFirstByTitleIgnoreCase", false, false).unwrap();
+    let source = fs::read_to_string(&project.1).unwrap();
+
+    assert_eq!(
+      response.method_signature,
+      "List<Book> findByAuthorNameAndPriceBetweenAndStatusInOrderByTitleDesc(String name, BigDecimal priceFrom, BigDecimal priceTo, Collection<Status> statuses, Sort sort)"
+    );
+    assert_eq!(response.property_paths, vec!["author.name", "price", "status", "title"]);
+    assert!(response.imports_added.contains(&"java.math.BigDecimal".to_string()));
+    assert!(response.imports_added.contains(&"java.util.Collection".to_string()));
+    assert_eq!(second.method_signature, "Optional<Book> findFirstByTitleIgnoreCase(String title)");
+    assert!(
+      source.contains(", Sort sort);\n\n  Optional<Book> findFirstByTitleIgnoreCase("),
+      "{}",
+      source
+    );
+  }
+
+  #[test]
+  fn test_rejects_unknown_properties_and_invalid_paging() {
+    let project = setup_project();
+
+    assert_eq!(
+      add_method(&project, "findByPublisherName", true, false).err().unwrap(),
+      "Property 'publisherName' of method 'findByPublisherName' does not match any field of the entity"
+    );
+    assert_eq!(
+      add_method(&project, "countByStatus", true, false).err().unwrap(),
+      "'count' queries can't take a Pageable or Sort parameter"
+    );
+    assert_eq!(
+      add_method(&project, "findByTitle", true, true).err().unwrap(),
+      "A Pageable already carries its Sort, use either --pageable or --sort"
+    );
+    assert_eq!(fs::read_to_string(&project.1).unwrap(), REPOSITORY_SOURCE);
+  }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod add_size_validation_service_tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::add_size_validation_response::AddSizeValidationResponse;
+  use syntaxpresso_core::commands::java::services::add_size_validation_service::run;
+  use tempfile::TempDir;
+
+  const ENTITY_SOURCE: &str = "package com.example;\n\nimport jakarta.persistence.Column;\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Article {\n  @Column(name = \"title\", length = 255)\n  private String title;\n\n  private String summary;\n\n  private Integer views;\n}\n";
+
+  fn add_size(
+    field_name: &str,
+    size_max: Option<u32>,
+    sync_length: bool,
+  ) -> (Result<AddSizeValidationResponse, String>, String) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    let entity_file_path: PathBuf = package_dir.join("Article.java");
+    fs::write(&entity_file_path, ENTITY_SOURCE).unwrap();
+    let b64_source = BASE64_STANDARD.encode(ENTITY_SOURCE);
+    let response = run(
+      temp_dir.path(),
+      &b64_source,
+      &entity_file_path,
+      field_name,
+      Some(1),
+      size_max,
+      sync_length,
+    );
+    (response, fs::read_to_string(&entity_file_path).unwrap())
+  }
+
+  #[test]
+  fn test_sync_length_updates_existing_column_length() {
+    let (response, source) = add_size("title", Some(100), true);
+
+    let response = response.unwrap();
+    assert!(response.length_synced);
+    assert_eq!(response.column_length, Some(100));
+    assert!(response.warnings.is_empty());
+    assert!(
+      source.contains(
+        "  @Column(name = \"title\", length = 100)\n  @Size(min = 1, max = 100)\n  private String title;"
+      ),
+      "{}",
+      source
+    );
+    assert!(source.contains("import jakarta.validation.constraints.Size;"), "{}", source);
+  }
+
+  #[test]
+  fn test_sync_length_adds_column_when_missing() {
+    let (response, source) = add_size("summary", Some(500), true);
+
+    assert!(response.unwrap().length_synced);
+    assert!(
+      source.contains(
+        "  @Size(min = 1, max = 500)\n  @Column(length = 500)\n  private String summary;"
+      ),
+      "{}",
+      source
+    );
+  }
+
+  #[test]
+  fn test_conflicting_length_without_sync_is_reported() {
+    let (response, source) = add_size("summary", Some(500), false);
+
+    let response = response.unwrap();
+    assert!(!response.length_synced);
+    assert_eq!(response.column_length, Some(255));
+    assert_eq!(response.warnings.len(), 1, "{:?}", response.warnings);
+    assert!(!source.contains("@Column(length"), "{}", source);
+  }
+
+  #[test]
+  fn test_matching_default_length_needs_no_sync() {
+    let (response, source) = add_size("summary", Some(255), true);
+
+    let response = response.unwrap();
+    assert!(!response.length_synced);
+    assert!(response.warnings.is_empty());
+    assert!(!source.contains("@Column(length"), "{}", source);
+  }
+
+  #[test]
+  fn test_sync_length_on_field_without_length_warns() {
+    let (response, _) = add_size("views", Some(10), true);
+
+    let response = response.unwrap();
+    assert!(!response.length_synced);
+    assert_eq!(response.column_length, None);
+    assert_eq!(response.warnings.len(), 1);
+  }
+
+  #[test]
+  fn test_unknown_field_fails() {
+    let (response, _) = add_size("missing", Some(10), true);
+
+    assert!(response.is_err());
+  }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod add_unique_constraint_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::add_unique_constraint_response::AddUniqueConstraintResponse;
+  use syntaxpresso_core::commands::java::services::add_unique_constraint_service::run;
+  use tempfile::TempDir;
+
+  const ENTITY_SOURCE: &str = "package com.example;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Book {\n  @Id\n  private Long id;\n\n  @Column(unique = true)\n  private String isbn;\n\n  @Column(name = \"edition_no\", unique = true, nullable = false)\n  private Integer edition;\n\n  @ManyToOne\n  @JoinColumn(unique = true)\n  private Author author;\n\n  private String title;\n}\n";
+
+  fn add_constraint(
+    source: &str,
+    field_names: &[&str],
+    constraint_name: Option<&str>,
+  ) -> (Result<AddUniqueConstraintResponse, String>, String) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_file_path = temp_dir.path().join("Book.java");
+    fs::write(&entity_file_path, source).unwrap();
+    let b64_source = BASE64_STANDARD.encode(source);
+    let field_names: Vec<String> = field_names.iter().map(|name| name.to_string()).collect();
+    let response =
+      run(temp_dir.path(), &b64_source, &entity_file_path, &field_names, constraint_name);
+    (response, fs::read_to_string(&entity_file_path).unwrap())
+  }
+
+  #[test]
+  fn test_promotes_unique_columns_into_composite_constraint() {
+    let (response, source) =
+      add_constraint(ENTITY_SOURCE, &["author", "edition", "title"], Some("uk_book_edition"));
+
+    let response = response.unwrap();
+    assert!(response.table_annotation_added);
+    assert_eq!(response.column_names, vec!["author_id", "edition_no", "title"]);
+    assert_eq!(response.demoted_fields, vec!["author", "edition"]);
+    assert_eq!(
+      source,
+      "package com.example;\n\nimport jakarta.persistence.Table;\nimport jakarta.persistence.UniqueConstraint;\nimport jakarta.persistence.Entity;\n\n@Entity\n@Table(uniqueConstraints = @UniqueConstraint(name = \"uk_book_edition\", columnNames = {\"author_id\", \"edition_no\", \"title\"}))\npublic class Book {\n  @Id\n  private Long id;\n\n  @Column(unique = true)\n  private String isbn;\n\n  @Column(name = \"edition_no\", nullable = false)\n  private Integer edition;\n\n  @ManyToOne\n  @JoinColumn\n  private Author author;\n\n  private String title;\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_appends_to_existing_table_constraints_and_rejects_duplicates() {
+    let source = ENTITY_SOURCE.replace(
+      "@Entity\n",
+      "@Entity\n@Table(name = \"books\", uniqueConstraints = @UniqueConstraint(columnNames = {\"title\", \"isbn\"}))\n",
+    );
+
+    let (response, updated_source) = add_constraint(&source, &["isbn"], None);
+
+    let response = response.unwrap();
+    assert!(!response.table_annotation_added);
+    assert_eq!(response.demoted_fields, vec!["isbn"]);
+    assert!(
+      updated_source.contains("@Table(name = \"books\", uniqueConstraints = {@UniqueConstraint(columnNames = {\"title\", \"isbn\"}), @UniqueConstraint(columnNames = {\"isbn\"})})"),
+      "{}",
+      updated_source
+    );
+
+    let (duplicate_response, unchanged_source) = add_constraint(&source, &["isbn", "title"], None);
+    assert_eq!(
+      duplicate_response.err().unwrap(),
+      "A unique constraint on (isbn, title) already exists"
+    );
+    assert_eq!(unchanged_source, source);
+  }
+
+  #[test]
+  fn test_rejects_unknown_identifier_and_unmapped_fields() {
+    let source =
+      ENTITY_SOURCE.replace("  private String title;\n", "  @Transient\n  private String title;\n");
+
+    for (field_name, error) in [
+      ("missing", "Field 'missing' not found in entity 'Book'"),
+      ("id", "Field 'id' is an identifier and already unique"),
+      ("title", "Field 'title' is not mapped to a column"),
+    ] {
+      let (response, unchanged_source) = add_constraint(&source, &["isbn", field_name], None);
+
+      assert_eq!(response.err().unwrap(), error);
+      assert_eq!(unchanged_source, source);
+    }
+  }
+}
@@ -1,5 +1,8 @@
 #[cfg(test)]
 mod annotation_service_tests {
+  use syntaxpresso_core::commands::java::responses::get_annotations_response::{
+    AnnotationForm, AnnotationResponse, AnnotationValueResponse,
+  };
   use syntaxpresso_core::commands::java::treesitter::services::annotation_service::*;
   use syntaxpresso_core::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
   use syntaxpresso_core::common::supported_language::SupportedLanguage;
@@ -381,6 +384,44 @@ public class User {
     assert!(ts_file.source_code.contains("@Entity"), "Should preserve existing annotation");
   }
 
+  #[test]
+  fn test_add_annotation_twice_keeps_single_instance() {
+    let mut ts_file = create_ts_file("public class User {\n  private String name;\n}");
+
+    for _ in 0..2 {
+      let field_pos = ts_file.source_code.find("private").unwrap();
+      let result = add_annotation(
+        &mut ts_file,
+        field_pos,
+        &AnnotationInsertionPosition::AboveScopeDeclaration,
+        "@NotNull",
+      );
+      assert!(result.is_some(), "Should accept an annotation already present");
+    }
+
+    assert_eq!(ts_file.source_code, "public class User {\n  @NotNull\n  private String name;\n}");
+  }
+
+  #[test]
+  fn test_add_annotation_collapses_existing_duplicates() {
+    let mut ts_file = create_ts_file(
+      "public class User {\n  @NotNull\n  @Size(max = 10)\n  @Size(max=10)\n  @NotNull private String name;\n}",
+    );
+    let field_pos = ts_file.source_code.find("@NotNull").unwrap();
+
+    add_annotation(
+      &mut ts_file,
+      field_pos,
+      &AnnotationInsertionPosition::AboveScopeDeclaration,
+      "@Size(min = 1)",
+    );
+
+    assert_eq!(
+      ts_file.source_code,
+      "public class User {\n  @NotNull\n  @Size(max = 10)\n  @Size(min = 1)\n  private String name;\n}"
+    );
+  }
+
   #[test]
   fn test_add_annotation_argument_to_existing_arguments() {
     let mut ts_file = create_ts_file("@Column(name = \"username\")\nprivate String username;");
@@ -395,4 +436,168 @@ public class User {
       "Should preserve existing argument"
     );
   }
+
+  #[test]
+  fn test_add_annotation_to_annotated_field_stays_on_field() {
+    let mut ts_file =
+      create_ts_file("public class User {\n  @Embedded\n  private Address address;\n}");
+    let field_pos = ts_file.source_code.find("@Embedded").unwrap();
+
+    let result = add_annotation(
+      &mut ts_file,
+      field_pos,
+      &AnnotationInsertionPosition::AboveScopeDeclaration,
+      "@AttributeOverride(name = \"street\", column = @Column(name = \"home_street\"))",
+    );
+
+    assert!(result.is_some(), "Should successfully add annotation");
+    assert_eq!(
+      ts_file.source_code,
+      "public class User {\n  @Embedded\n  @AttributeOverride(name = \"street\", column = @Column(name = \"home_street\"))\n  private Address address;\n}"
+    );
+  }
+
+  #[test]
+  fn test_remove_annotation_on_own_line() {
+    let mut ts_file =
+      create_ts_file("public class User {\n  @Id\n  @Column(name = \"id\")\n  private Long id;\n}");
+    let annotation_pos = ts_file.source_code.find("@Column").unwrap();
+
+    assert!(remove_annotation(&mut ts_file, annotation_pos));
+    assert_eq!(ts_file.source_code, "public class User {\n  @Id\n  private Long id;\n}");
+  }
+
+  #[test]
+  fn test_remove_annotation_inline() {
+    let mut ts_file = create_ts_file("public class User {\n  @Id @Column private Long id;\n}");
+    let annotation_pos = ts_file.source_code.find("@Column").unwrap();
+
+    assert!(remove_annotation(&mut ts_file, annotation_pos));
+    assert_eq!(ts_file.source_code, "public class User {\n  @Id private Long id;\n}");
+  }
+
+  #[test]
+  fn test_remove_annotation_not_an_annotation() {
+    let mut ts_file = create_ts_file("public class User {\n  private Long id;\n}");
+    let field_pos = ts_file.source_code.find("private").unwrap();
+
+    assert!(!remove_annotation(&mut ts_file, field_pos));
+  }
+
+  #[test]
+  fn test_add_annotation_argument_to_indented_annotation_with_arguments() {
+    let mut ts_file = create_ts_file(
+      "public class User {\n  @Column(name = \"bio\", length = 500)\n  private String bio;\n}",
+    );
+    let annotation_pos = ts_file.source_code.find("@Column").unwrap();
+
+    assert!(
+      add_annotation_argument(&mut ts_file, annotation_pos, "table", "\"details\"").is_some()
+    );
+    assert!(
+      ts_file
+        .source_code
+        .contains("  @Column(name = \"bio\", length = 500, table = \"details\")\n"),
+      "Argument should be appended after the last one: {}",
+      ts_file.source_code
+    );
+  }
+
+  #[test]
+  fn test_set_annotation_argument_replaces_existing_value() {
+    let mut ts_file = create_ts_file(
+      "public class User {\n  @Column(name = \"bio\", table = \"old\")\n  private String bio;\n}",
+    );
+    let annotation_pos = ts_file.source_code.find("@Column").unwrap();
+
+    assert!(set_annotation_argument(&mut ts_file, annotation_pos, "table", "\"details\""));
+    assert!(ts_file.source_code.contains("@Column(name = \"bio\", table = \"details\")"));
+  }
+
+  #[test]
+  fn test_set_annotation_argument_adds_missing_key() {
+    let mut ts_file = create_ts_file("public class User {\n  @Column\n  private String bio;\n}");
+    let annotation_pos = ts_file.source_code.find("@Column").unwrap();
+
+    assert!(set_annotation_argument(&mut ts_file, annotation_pos, "table", "\"details\""));
+    assert!(ts_file.source_code.contains("  @Column(table = \"details\")\n"));
+  }
+
+  #[test]
+  fn test_set_annotation_argument_names_single_unnamed_value() {
+    let mut ts_file = create_ts_file("@Table(\"users\")\npublic class User {}");
+
+    assert!(set_annotation_argument(&mut ts_file, 0, "schema", "\"app\""));
+    assert!(ts_file.source_code.starts_with("@Table(value = \"users\", schema = \"app\")\n"));
+    assert!(set_annotation_argument(&mut ts_file, 0, "value", "\"people\""));
+    assert!(ts_file.source_code.starts_with("@Table(value = \"people\", schema = \"app\")\n"));
+  }
+
+  fn get_first_annotation_response(source: &str) -> AnnotationResponse {
+    let ts_file = create_ts_file(source);
+    let root_node = ts_file.tree.as_ref().unwrap().root_node();
+    let annotation_node = get_all_annotation_nodes(&ts_file, root_node)[0];
+    get_annotation_response(&ts_file, annotation_node).unwrap()
+  }
+
+  #[test]
+  fn test_get_annotation_response_marker_resolves_import() {
+    let annotation = get_first_annotation_response(
+      "package com.example;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class User {}",
+    );
+
+    assert_eq!(annotation.name, "Entity");
+    assert_eq!(annotation.fully_qualified_name.as_deref(), Some("jakarta.persistence.Entity"));
+    assert_eq!(annotation.form, AnnotationForm::Marker);
+    assert!(annotation.arguments.is_empty());
+  }
+
+  #[test]
+  fn test_get_annotation_response_single_value_uses_value_key() {
+    let annotation =
+      get_first_annotation_response("@SuppressWarnings(\"unchecked\")\npublic class User {}");
+
+    assert_eq!(annotation.fully_qualified_name.as_deref(), Some("java.lang.SuppressWarnings"));
+    assert_eq!(annotation.form, AnnotationForm::SingleValue);
+    assert_eq!(annotation.arguments[0].key, "value");
+    assert!(matches!(
+      &annotation.arguments[0].value,
+      AnnotationValueResponse::Expression { text } if text == "\"unchecked\""
+    ));
+  }
+
+  #[test]
+  fn test_get_annotation_response_serializes_nested_annotations_and_arrays() {
+    let annotation = get_first_annotation_response(
+      "@Table(name = \"users\", uniqueConstraints = {@UniqueConstraint(columnNames = {\"a\", \"b\"})})\npublic class User {}",
+    );
+
+    assert_eq!(annotation.form, AnnotationForm::Normal);
+    assert_eq!(annotation.arguments.len(), 2);
+    let AnnotationValueResponse::Array { elements } = &annotation.arguments[1].value else {
+      panic!("uniqueConstraints should be an array");
+    };
+    let AnnotationValueResponse::Annotation(nested) = &elements[0] else {
+      panic!("Array element should be an annotation");
+    };
+    assert_eq!(nested.name, "UniqueConstraint");
+    assert!(matches!(
+      &nested.arguments[0].value,
+      AnnotationValueResponse::Array { elements } if elements.len() == 2
+    ));
+  }
+  #[test]
+  fn test_remove_annotation_argument_keeps_other_arguments() {
+    for (source, expected) in [
+      ("@Column(unique = true, name = \"a\")\nclass A {}", "@Column(name = \"a\")\nclass A {}"),
+      ("@Column(name = \"a\", unique = true)\nclass A {}", "@Column(name = \"a\")\nclass A {}"),
+      ("@Column(unique = true)\nclass A {}", "@Column\nclass A {}"),
+    ] {
+      let mut ts_file = create_ts_file(source);
+
+      assert!(remove_annotation_argument(&mut ts_file, 0, "unique"));
+      assert_eq!(ts_file.source_code, expected);
+      assert!(!remove_annotation_argument(&mut ts_file, 0, "unique"));
+    }
+  }
 }
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod api_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::api;
+  use syntaxpresso_core::commands::java::services::create_jpa_entity_basic_field_service;
+  use syntaxpresso_core::commands::java::treesitter::types::basic_field_config::BasicFieldConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::enum_field_config::EnumFieldConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::id_field_config::IdFieldConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::java_enum_type::JavaEnumType;
+  use syntaxpresso_core::commands::java::treesitter::types::java_id_generation::JavaIdGeneration;
+  use syntaxpresso_core::commands::java::treesitter::types::java_id_generation_type::JavaIdGenerationType;
+  use syntaxpresso_core::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+  use syntaxpresso_core::commands::java::treesitter::types::member_access_config::MemberAccessConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::reserved_word_check::ReservedWordCheck;
+  use tempfile::TempDir;
+
+  const ENTITY_SOURCE: &str = "package com.example;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class User {\n}\n";
+
+  fn create_basic_field_config() -> BasicFieldConfig {
+    BasicFieldConfig {
+      field_name: "email".to_string(),
+      field_type: "String".to_string(),
+      field_type_package_name: None,
+      field_length: Some(120),
+      field_precision: None,
+      field_scale: None,
+      money_precision: None,
+      money_scale: None,
+      field_temporal: None,
+      field_timezone_storage: None,
+      field_unique: true,
+      field_nullable: false,
+      field_large_object: false,
+      field_optional: false,
+      field_fetch_type: None,
+      sync_length: false,
+      explicit_string_length: false,
+      access_config: MemberAccessConfig::default(),
+      reserved_word_check: ReservedWordCheck::default(),
+    }
+  }
+
+  #[test]
+  fn test_create_basic_field_from_plain_source() {
+    let result =
+      api::create_jpa_entity_basic_field(ENTITY_SOURCE, &create_basic_field_config()).unwrap();
+
+    assert!(
+      result.contains(
+        "  @Column(name = \"email\", unique = true, nullable = false, length = 120)\n  private String email;"
+      ),
+      "{}",
+      result
+    );
+    assert!(result.contains("import jakarta.persistence.Column;"), "{}", result);
+  }
+
+  #[test]
+  fn test_plain_source_api_matches_cli_service() {
+    let field_config = create_basic_field_config();
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_file_path = temp_dir.path().join("User.java");
+    fs::write(&entity_file_path, ENTITY_SOURCE).unwrap();
+    let b64_source = BASE64_STANDARD.encode(ENTITY_SOURCE);
+    create_jpa_entity_basic_field_service::run(
+      &b64_source,
+      &entity_file_path,
+      &field_config,
+      temp_dir.path(),
+    )
+    .unwrap();
+
+    let result = api::create_jpa_entity_basic_field(ENTITY_SOURCE, &field_config).unwrap();
+
+    assert_eq!(result, fs::read_to_string(&entity_file_path).unwrap());
+  }
+
+  #[test]
+  fn test_create_enum_and_id_fields_from_plain_source() {
+    let id_field_config = IdFieldConfig {
+      field_name: "id".to_string(),
+      field_type: "Long".to_string(),
+      field_type_package_name: None,
+      field_id_generation: JavaIdGeneration::Identity,
+      field_id_generation_type: JavaIdGenerationType::None,
+      field_generator_name: None,
+      field_sequence_name: None,
+      field_initial_value: None,
+      field_allocation_size: None,
+      field_nullable: false,
+      access_config: MemberAccessConfig::default(),
+    };
+    let enum_field_config = EnumFieldConfig {
+      field_name: "status".to_string(),
+      enum_type: "Status".to_string(),
+      enum_package_name: "com.example".to_string(),
+      enum_type_storage: JavaEnumType::String,
+      field_length: None,
+      field_nullable: true,
+      field_unique: false,
+      validate_enum_type: false,
+      access_config: MemberAccessConfig::default(),
+    };
+
+    let with_id = api::create_jpa_entity_id_field(ENTITY_SOURCE, &id_field_config).unwrap();
+    let result = api::create_jpa_entity_enum_field(&with_id, &enum_field_config).unwrap();
+
+    assert!(result.contains("  @Id\n"), "{}", result);
+    assert!(result.contains("private Long id;"), "{}", result);
+    assert!(result.contains("@Enumerated(value = EnumType.STRING)"), "{}", result);
+    assert!(result.contains("private Status status;"), "{}", result);
+  }
+
+  #[test]
+  fn test_rejects_invalid_access_config() {
+    let mut field_config = create_basic_field_config();
+    field_config.access_config.generate_accessors = true;
+    field_config.access_config.accessor_visibility = JavaVisibilityModifier::Private;
+
+    assert!(api::create_jpa_entity_basic_field(ENTITY_SOURCE, &field_config).is_err());
+  }
+}
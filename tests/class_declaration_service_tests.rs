@@ -670,4 +670,77 @@ public class UserService {
     let all_classes = get_all_class_declaration_nodes(&ts_file);
     assert_eq!(all_classes.len(), 2);
   }
+
+  #[test]
+  fn test_add_member_at_end_of_type_body_in_empty_and_filled_bodies() {
+    let content = "public class Outer {}\n";
+    let mut ts_file = create_ts_file_from_content(content, None);
+    let class_start_byte = get_public_class_node(&ts_file).unwrap().start_byte();
+
+    let (start_byte, end_byte) =
+      add_member_at_end_of_type_body(&mut ts_file, class_start_byte, "static class Key {}")
+        .unwrap();
+    add_member_at_end_of_type_body(&mut ts_file, class_start_byte, "enum Kind {}").unwrap();
+
+    assert_eq!(&ts_file.source_code[start_byte..end_byte], "static class Key {}");
+    assert_eq!(
+      ts_file.source_code,
+      "public class Outer {\n  static class Key {}\n\n  enum Kind {}\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_add_member_at_end_of_type_body_terminates_enum_constants() {
+    let content = "class Outer {\n    enum Color {\n        RED,\n        GREEN\n    }\n}\n";
+    let mut ts_file = create_ts_file_from_content(content, None);
+    let enum_start_byte = find_type_declaration_node(&ts_file, Some("Color")).unwrap().start_byte();
+
+    add_member_at_end_of_type_body(&mut ts_file, enum_start_byte, "interface Shade {}").unwrap();
+
+    assert_eq!(
+      ts_file.source_code,
+      "class Outer {\n    enum Color {\n        RED,\n        GREEN;\n\n        interface Shade {}\n    }\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_get_nested_type_declaration_names() {
+    let content = "public class Outer {\n  private Key key;\n\n  static class Key {\n    class Deep {}\n  }\n\n  interface Listener {}\n\n  enum Kind {\n    A;\n\n    record Pair() {}\n  }\n}\n";
+    let ts_file = create_ts_file_from_content(content, None);
+    let class_node = get_public_class_node(&ts_file).unwrap();
+    let kind_node = find_type_declaration_node(&ts_file, Some("Kind")).unwrap();
+
+    assert_eq!(
+      get_nested_type_declaration_names(&ts_file, class_node),
+      vec!["Key", "Listener", "Kind"]
+    );
+    assert_eq!(get_nested_type_declaration_names(&ts_file, kind_node), vec!["Pair"]);
+    assert_eq!(get_type_declaration_kind(kind_node), Some("enum"));
+  }
+
+  #[test]
+  fn test_add_serializable_interface_keeps_existing_implements() {
+    let mut ts_file = create_ts_file_from_content(
+      "package com.example;\n\npublic class OrderId implements Comparable<OrderId> {\n  private Long id;\n}\n",
+      None,
+    );
+    let mut serializable_ts_file = create_ts_file_from_content(
+      "package com.example;\n\npublic class LineId implements java.io.Serializable {}\n",
+      None,
+    );
+
+    assert_eq!(add_serializable_interface(&mut ts_file), Some(true));
+    assert_eq!(add_serializable_interface(&mut serializable_ts_file), Some(false));
+    assert!(
+      ts_file.source_code.contains(
+        "import java.io.Serializable;\n\npublic class OrderId implements Comparable<OrderId>, Serializable {\n  private Long id;\n\n  private static final long serialVersionUID = 1L;\n}"
+      ),
+      "{}",
+      ts_file.source_code
+    );
+    assert_eq!(
+      serializable_ts_file.source_code,
+      "package com.example;\n\npublic class LineId implements java.io.Serializable {}\n"
+    );
+  }
 }
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod constructor_declaration_service_tests {
+  use syntaxpresso_core::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+  use syntaxpresso_core::commands::java::treesitter::services::constructor_declaration_service::*;
+  use syntaxpresso_core::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+  use syntaxpresso_core::common::supported_language::SupportedLanguage;
+  use syntaxpresso_core::common::ts_file::TSFile;
+
+  fn create_ts_file_from_content(content: &str) -> TSFile {
+    TSFile::from_source_code(content, SupportedLanguage::Java)
+  }
+
+  fn no_arg_params() -> AddConstructorDeclarationParams<'static> {
+    AddConstructorDeclarationParams {
+      visibility_modifier: JavaVisibilityModifier::Protected,
+      parameters: Vec::new(),
+      body_statements: Vec::new(),
+    }
+  }
+
+  #[test]
+  fn test_get_all_constructor_declaration_nodes_ignores_nested_classes() {
+    let content = r#"
+public class User {
+  public User(String name) {}
+  private User() {}
+
+  static class Builder {
+    Builder(int size) {}
+  }
+}
+"#;
+    let ts_file = create_ts_file_from_content(content);
+    let class_node = get_public_class_node(&ts_file).unwrap();
+    let constructors = get_all_constructor_declaration_nodes(&ts_file, class_node);
+    assert_eq!(constructors.len(), 2);
+    assert_eq!(get_constructor_parameter_nodes(&ts_file, constructors[0]).len(), 1);
+    let no_arg_node = find_no_arg_constructor_node(&ts_file, class_node).unwrap();
+    assert_eq!(
+      get_constructor_visibility_modifier(&ts_file, no_arg_node),
+      JavaVisibilityModifier::Private
+    );
+  }
+
+  #[test]
+  fn test_has_no_arg_constructor() {
+    let implicit = create_ts_file_from_content("public class User {\n  private Long id;\n}\n");
+    let class_node = get_public_class_node(&implicit).unwrap();
+    assert!(has_no_arg_constructor(&implicit, class_node));
+
+    let only_parameterized = create_ts_file_from_content(
+      "public class User {\n  public User(Long id, String... tags) {}\n}\n",
+    );
+    let class_node = get_public_class_node(&only_parameterized).unwrap();
+    assert!(!has_no_arg_constructor(&only_parameterized, class_node));
+
+    let lombok = create_ts_file_from_content(
+      "@NoArgsConstructor\npublic class User {\n  public User(Long id) {}\n}\n",
+    );
+    let class_node = get_public_class_node(&lombok).unwrap();
+    assert!(has_no_arg_constructor(&lombok, class_node));
+  }
+
+  #[test]
+  fn test_add_constructor_declaration_before_first_constructor() {
+    let content = r#"public class User {
+  private String name;
+
+  /** Creates a user. */
+  public User(String name) {
+    this.name = name;
+  }
+}
+"#;
+    let mut ts_file = create_ts_file_from_content(content);
+    let class_start_byte = get_public_class_node(&ts_file).unwrap().start_byte();
+    let inserted_byte =
+      add_constructor_declaration(&mut ts_file, class_start_byte, no_arg_params()).unwrap();
+    assert!(ts_file.source_code[inserted_byte..].starts_with("protected User() {}"));
+    assert!(
+      ts_file
+        .source_code
+        .contains("  protected User() {}\n\n  /** Creates a user. */\n  public User(String name)")
+    );
+  }
+
+  #[test]
+  fn test_add_constructor_declaration_with_body_after_last_field() {
+    let content = "public class User {\n  private String name;\n\n  public void run() {}\n}\n";
+    let mut ts_file = create_ts_file_from_content(content);
+    let class_start_byte = get_public_class_node(&ts_file).unwrap().start_byte();
+    let params = AddConstructorDeclarationParams {
+      visibility_modifier: JavaVisibilityModifier::Public,
+      parameters: vec![("String", "name")],
+      body_statements: vec!["this.name = name;".to_string()],
+    };
+    add_constructor_declaration(&mut ts_file, class_start_byte, params).unwrap();
+    assert_eq!(
+      ts_file.source_code,
+      "public class User {\n  private String name;\n\n  public User(String name) {\n    this.name = name;\n  }\n\n  public void run() {}\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_add_constructor_declaration_to_empty_class() {
+    let mut ts_file = create_ts_file_from_content("public class User {}\n");
+    let class_start_byte = get_public_class_node(&ts_file).unwrap().start_byte();
+    add_constructor_declaration(&mut ts_file, class_start_byte, no_arg_params()).unwrap();
+    assert_eq!(ts_file.source_code, "public class User {\n  protected User() {}\n}\n");
+  }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod convert_to_lombok_builder_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::convert_to_lombok_builder_response::ConvertToLombokBuilderResponse;
+  use syntaxpresso_core::commands::java::services::convert_to_lombok_builder_service::run;
+  use tempfile::TempDir;
+
+  const BOOK_SOURCE: &str = r#"package com.example;
+
+import java.util.Objects;
+
+public class Book {
+  private final String title;
+
+  private final int pages;
+
+  private Book(Builder builder) {
+    this.title = builder.title;
+    this.pages = builder.pages;
+  }
+
+  public String getTitle() {
+    return title;
+  }
+
+  public static Builder builder() {
+    return new Builder();
+  }
+
+  /** Fluent builder of books. */
+  public static class Builder {
+    private String title;
+
+    private int pages;
+
+    public Builder title(String title) {
+      this.title = title;
+      return this;
+    }
+
+    public Builder pages(int pages) {
+      this.pages = pages;
+      return this;
+    }
+
+    public Book build() {
+      return new Book(this);
+    }
+  }
+}
+"#;
+
+  fn convert(source: &str) -> (Result<ConvertToLombokBuilderResponse, String>, String) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("Book.java");
+    fs::write(&file_path, source).unwrap();
+    let b64_source = BASE64_STANDARD.encode(source);
+    let response = run(temp_dir.path(), &b64_source, &file_path);
+    (response, fs::read_to_string(&file_path).unwrap())
+  }
+
+  #[test]
+  fn test_replaces_hand_written_builder_with_annotation() {
+    let (response, source) = convert(BOOK_SOURCE);
+
+    let response = response.unwrap();
+    assert_eq!(response.removed_members, vec!["Book(Builder)", "builder()", "Builder"]);
+    assert!(response.annotation_added);
+    assert_eq!(
+      response.warnings,
+      vec![
+        "Lombok names the builder class 'BookBuilder', references to 'Book.Builder' must be updated"
+      ]
+    );
+    assert_eq!(
+      source,
+      "package com.example;\n\nimport lombok.Builder;\nimport java.util.Objects;\n\n@Builder\npublic class Book {\n  private final String title;\n\n  private final int pages;\n\n  public String getTitle() {\n    return title;\n  }\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_warns_about_custom_builder_logic() {
+    let source = BOOK_SOURCE
+      .replace(
+        "      return new Book(this);",
+        "      Objects.requireNonNull(title);\n      return new Book(this);",
+      )
+      .replace("    private int pages;", "    private int pages = 100;");
+
+    let (response, converted_source) = convert(&source);
+
+    let response = response.unwrap();
+    assert_eq!(
+      response.warnings[..2],
+      [
+        "'Builder.pages' has custom logic that Lombok's @Builder can't preserve",
+        "'Builder.build()' has custom logic that Lombok's @Builder can't preserve",
+      ]
+    );
+    assert!(!converted_source.contains("class Builder"), "{}", converted_source);
+  }
+
+  #[test]
+  fn test_rejects_class_without_builder() {
+    let source = "package com.example;\n\npublic class Book {\n  private String title;\n}\n";
+
+    let (response, unchanged_source) = convert(source);
+
+    assert_eq!(response.err().unwrap(), "Class 'Book' has no hand-written static Builder class");
+    assert_eq!(unchanged_source, source);
+  }
+}
@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod create_java_file_service_tests {
+  use std::fs;
+
+  use syntaxpresso_core::commands::java::create_java_file_command;
+  use syntaxpresso_core::commands::java::services::create_java_file_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::java_file_template::JavaFileTemplate;
+  use syntaxpresso_core::commands::java::treesitter::types::java_file_type::JavaFileType;
+  use syntaxpresso_core::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+  use syntaxpresso_core::common::utils::build_file_util::detect_java_version;
+  use tempfile::TempDir;
+
+  #[test]
+  fn test_rejects_record_below_java_16() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+    let result = run(
+      temp_dir.path(),
+      "com.example",
+      "Money",
+      &JavaFileType::Record,
+      &JavaSourceDirectoryType::Main,
+      Some(11),
+      None,
+    );
+
+    assert_eq!(
+      result.err().unwrap(),
+      "UNSUPPORTED_JAVA_VERSION: Records require Java 16, the target is Java 11"
+    );
+    assert!(!temp_dir.path().join("src/main/java/com/example/Money.java").exists());
+  }
+
+  #[test]
+  fn test_creates_record_for_java_17_and_classes_for_any_version() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+    let record = run(
+      temp_dir.path(),
+      "com.example",
+      "Money",
+      &JavaFileType::Record,
+      &JavaSourceDirectoryType::Main,
+      Some(17),
+      None,
+    )
+    .unwrap();
+    let class = run(
+      temp_dir.path(),
+      "com.example",
+      "Wallet",
+      &JavaFileType::Class,
+      &JavaSourceDirectoryType::Main,
+      Some(8),
+      None,
+    );
+
+    assert!(fs::read_to_string(record.file_path).unwrap().contains("public record Money()"));
+    assert!(class.is_ok());
+  }
+
+  #[test]
+  fn test_detects_java_version_from_build_file() {
+    let maven_dir = TempDir::new().expect("Failed to create temp directory");
+    fs::write(
+      maven_dir.path().join("pom.xml"),
+      "<project>\n  <properties>\n    <java.version>11</java.version>\n  </properties>\n</project>\n",
+    )
+    .unwrap();
+    let gradle_dir = TempDir::new().expect("Failed to create temp directory");
+    fs::write(
+      gradle_dir.path().join("build.gradle"),
+      "java {\n  sourceCompatibility = JavaVersion.VERSION_1_8\n}\n",
+    )
+    .unwrap();
+    let kotlin_dir = TempDir::new().expect("Failed to create temp directory");
+    fs::write(
+      kotlin_dir.path().join("build.gradle.kts"),
+      "java {\n  toolchain {\n    languageVersion.set(JavaLanguageVersion.of(21))\n  }\n}\n",
+    )
+    .unwrap();
+
+    assert_eq!(detect_java_version(maven_dir.path()), Some(11));
+    assert_eq!(detect_java_version(gradle_dir.path()), Some(8));
+    assert_eq!(detect_java_version(kotlin_dir.path()), Some(21));
+    // The detected version applies when no target is given
+    let response = create_java_file_command::execute(
+      maven_dir.path(),
+      "com.example",
+      "Money",
+      &JavaFileType::Record,
+      &JavaSourceDirectoryType::Main,
+      None,
+      None,
+    );
+    assert!(!response.succeed);
+    assert!(response.error_reason.unwrap().contains("the target is Java 11"));
+  }
+
+  #[test]
+  fn test_seeds_body_from_template() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+    let response = create_java_file_command::execute(
+      temp_dir.path(),
+      "com.example.config",
+      "WebConfig",
+      &JavaFileType::Class,
+      &JavaSourceDirectoryType::Main,
+      None,
+      Some(&JavaFileTemplate::Configuration),
+    );
+    let test_class = run(
+      temp_dir.path(),
+      "com.example",
+      "OrderServiceTest",
+      &JavaFileType::Class,
+      &JavaSourceDirectoryType::Test,
+      None,
+      Some(&JavaFileTemplate::JunitTest),
+    )
+    .unwrap();
+
+    let data = response.data.unwrap();
+    assert_eq!(data.template, Some(JavaFileTemplate::Configuration));
+    let config_source = fs::read_to_string(&data.file.file_path).unwrap();
+    assert!(
+      config_source.contains(
+        "import org.springframework.context.annotation.Configuration;\n\n@Configuration\npublic class WebConfig {}"
+      ),
+      "{}",
+      config_source
+    );
+    let test_source = fs::read_to_string(&test_class.file_path).unwrap();
+    assert!(
+      test_source.contains(
+        "public class OrderServiceTest {\n  @BeforeEach\n  void setUp() {}\n\n  @Test\n  void test() {}\n}"
+      ),
+      "{}",
+      test_source
+    );
+    assert!(test_source.contains("import org.junit.jupiter.api.Test;"), "{}", test_source);
+    assert!(test_class.warnings.is_empty());
+  }
+
+  #[test]
+  fn test_rejects_template_for_other_file_type() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+    let result = run(
+      temp_dir.path(),
+      "com.example",
+      "OrderService",
+      &JavaFileType::Interface,
+      &JavaSourceDirectoryType::Main,
+      None,
+      Some(&JavaFileTemplate::Service),
+    );
+
+    assert_eq!(result.err().unwrap(), "Template 'service' only applies to class files");
+    assert!(!temp_dir.path().join("src/main/java/com/example/OrderService.java").exists());
+  }
+}
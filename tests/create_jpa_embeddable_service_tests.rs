@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod create_jpa_embeddable_service_tests {
+  use std::fs;
+
+  use syntaxpresso_core::commands::java::services::create_jpa_embeddable_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::basic_field_config::BasicFieldConfig;
+  use tempfile::TempDir;
+
+  #[test]
+  fn test_embeddable_is_created_with_basic_fields() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let fields = vec![
+      BasicFieldConfig::from_json(
+        r#"{"fieldName": "street", "fieldType": "String", "fieldLength": 120}"#,
+      )
+      .unwrap(),
+      BasicFieldConfig::from_json(
+        r#"{"fieldName": "zipCode", "fieldType": "String", "fieldNullable": true}"#,
+      )
+      .unwrap(),
+    ];
+
+    let response =
+      run(temp_dir.path(), "com.example.model", "address", &fields, false, None).unwrap();
+
+    assert_eq!(response.file.file_type, "Address");
+    let field_names: Vec<&str> =
+      response.fields.iter().map(|field| field.field_name.as_str()).collect();
+    assert_eq!(field_names, vec!["street", "zipCode"]);
+    assert!(response.file.file_path.ends_with("src/main/java/com/example/model/Address.java"));
+    let source = fs::read_to_string(&response.file.file_path).unwrap();
+    assert!(source.contains("import jakarta.persistence.Embeddable;\n"));
+    assert!(source.contains("@Embeddable\npublic class Address {\n"));
+    assert!(
+      source.contains("@Column(name = \"street\", unique = false, nullable = false, length = 120)")
+    );
+    assert!(source.contains("private String zipCode;"));
+    assert!(!source.contains("@Id"));
+    assert!(!response.serializable_added);
+  }
+
+  #[test]
+  fn test_composite_key_class_implements_serializable_by_default() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let fields = vec![
+      BasicFieldConfig::from_json(r#"{"fieldName": "orderId", "fieldType": "Long"}"#).unwrap(),
+      BasicFieldConfig::from_json(r#"{"fieldName": "lineNumber", "fieldType": "Integer"}"#)
+        .unwrap(),
+    ];
+
+    let key =
+      run(temp_dir.path(), "com.example.model", "OrderLineId", &fields, true, None).unwrap();
+    let opted_out =
+      run(temp_dir.path(), "com.example.model", "ShipmentId", &fields, true, Some(false)).unwrap();
+
+    assert!(key.serializable_added);
+    let source = fs::read_to_string(&key.file.file_path).unwrap();
+    assert!(
+      source.contains(
+        "@Embeddable\npublic class OrderLineId implements Serializable {\n  private static final long serialVersionUID = 1L;\n\n"
+      ),
+      "{}",
+      source
+    );
+    assert!(source.contains("import java.io.Serializable;"), "{}", source);
+    assert!(source.contains("private Integer lineNumber;"), "{}", source);
+    assert!(!opted_out.serializable_added);
+    assert_eq!(
+      opted_out.file.warnings,
+      vec!["Composite key class 'ShipmentId' doesn't implement Serializable, which JPA requires"]
+    );
+  }
+
+  #[test]
+  fn test_id_field_is_rejected() {
+    let error =
+      BasicFieldConfig::from_json(r#"{"fieldName": "id", "fieldType": "Long", "id": true}"#).err();
+
+    assert_eq!(
+      error,
+      Some("Field 'id' is requested as an @Id, which a basic field can't declare".to_string())
+    );
+  }
+}
@@ -0,0 +1,333 @@
+#[cfg(test)]
+mod create_jpa_entity_basic_field_service_tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::services::create_jpa_entity_basic_field_service::run;
+  use syntaxpresso_core::commands::java::services::validate_entity_service;
+  use syntaxpresso_core::commands::java::treesitter::types::basic_field_config::BasicFieldConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::fetch_type::FetchType;
+  use syntaxpresso_core::commands::java::treesitter::types::member_access_config::MemberAccessConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::reserved_word_check::ReservedWordCheck;
+  use syntaxpresso_core::commands::java::treesitter::types::sql_dialect::SqlDialect;
+  use syntaxpresso_core::commands::java::validators::java_type_validator::{
+    validate_java_field_type, validate_java_parameter_type,
+  };
+  use syntaxpresso_core::common::utils::hash_util::get_content_hash;
+  use tempfile::TempDir;
+
+  const ENTITY_SOURCE: &str =
+    "package com.example;\n\n@Entity\npublic class Article {\n  @Id\n  private Long id;\n}\n";
+
+  fn setup_entity() -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    let entity_file_path = package_dir.join("Article.java");
+    fs::write(&entity_file_path, ENTITY_SOURCE).unwrap();
+    (temp_dir, entity_file_path)
+  }
+
+  fn string_field_config(field_name: &str) -> BasicFieldConfig {
+    BasicFieldConfig {
+      field_name: field_name.to_string(),
+      field_type: "String".to_string(),
+      field_type_package_name: Some("java.lang".to_string()),
+      field_length: None,
+      field_precision: None,
+      field_scale: None,
+      money_precision: None,
+      money_scale: None,
+      field_temporal: None,
+      field_timezone_storage: None,
+      field_unique: false,
+      field_nullable: true,
+      field_large_object: false,
+      field_optional: true,
+      field_fetch_type: None,
+      sync_length: false,
+      explicit_string_length: false,
+      access_config: MemberAccessConfig::default(),
+      reserved_word_check: ReservedWordCheck::default(),
+    }
+  }
+
+  fn add_field(field_config: &BasicFieldConfig) -> String {
+    let (temp_dir, entity_file_path) = setup_entity();
+    let b64_source = BASE64_STANDARD.encode(ENTITY_SOURCE);
+    run(&b64_source, &entity_file_path, field_config, temp_dir.path()).unwrap();
+    fs::read_to_string(&entity_file_path).unwrap()
+  }
+
+  #[test]
+  fn test_lazy_large_string_field_combines_basic_and_lob() {
+    let field_config = BasicFieldConfig {
+      field_large_object: true,
+      field_fetch_type: Some(FetchType::Lazy),
+      ..string_field_config("body")
+    };
+
+    let source = add_field(&field_config);
+
+    assert!(source.contains("  @Basic(fetch = FetchType.LAZY)\n"), "{}", source);
+    assert!(source.contains("  @Lob\n  private String body;"), "{}", source);
+    assert!(source.contains("import jakarta.persistence.Basic;"));
+    assert!(source.contains("import jakarta.persistence.FetchType;"));
+    assert!(source.contains("import jakarta.persistence.Lob;"));
+  }
+
+  #[test]
+  fn test_non_optional_field_adds_basic_optional_false() {
+    let field_config = BasicFieldConfig { field_optional: false, ..string_field_config("title") };
+
+    let source = add_field(&field_config);
+
+    assert!(source.contains("  @Basic(optional = false)\n"), "{}", source);
+    assert!(!source.contains("FetchType"), "{}", source);
+  }
+
+  #[test]
+  fn test_default_basic_attributes_omit_basic_annotation() {
+    let field_config =
+      BasicFieldConfig { field_fetch_type: Some(FetchType::Eager), ..string_field_config("title") };
+
+    let source = add_field(&field_config);
+
+    assert!(!source.contains("@Basic"), "{}", source);
+    assert!(!source.contains("import jakarta.persistence.Basic;"), "{}", source);
+  }
+
+  #[test]
+  fn test_sync_length_adds_size_matching_column_length() {
+    let field_config = BasicFieldConfig {
+      field_length: Some(120),
+      sync_length: true,
+      ..string_field_config("title")
+    };
+
+    let source = add_field(&field_config);
+
+    assert!(
+      source.contains(
+        "  @Column(name = \"title\", unique = false, nullable = true, length = 120)\n  @Size(max = 120)\n  private String title;"
+      ),
+      "{}",
+      source
+    );
+    assert!(source.contains("import jakarta.validation.constraints.Size;"), "{}", source);
+  }
+
+  #[test]
+  fn test_length_without_sync_omits_size() {
+    let field_config = BasicFieldConfig { field_length: Some(120), ..string_field_config("title") };
+
+    let source = add_field(&field_config);
+
+    assert!(source.contains("length = 120)"), "{}", source);
+    assert!(!source.contains("@Size"), "{}", source);
+  }
+
+  #[test]
+  fn test_explicit_string_length_emits_default_length_only_when_set() {
+    let implicit = add_field(&string_field_config("title"));
+    let explicit =
+      add_field(&BasicFieldConfig { explicit_string_length: true, ..string_field_config("title") });
+    let explicit_number = add_field(&BasicFieldConfig {
+      field_type: "Integer".to_string(),
+      explicit_string_length: true,
+      ..string_field_config("pages")
+    });
+
+    assert!(!implicit.contains("length"), "{}", implicit);
+    assert!(
+      explicit.contains("@Column(name = \"title\", unique = false, nullable = true, length = 255)"),
+      "{}",
+      explicit
+    );
+    assert!(!explicit_number.contains("length"), "{}", explicit_number);
+  }
+
+  #[test]
+  fn test_lob_byte_array_field() {
+    let field_config = BasicFieldConfig {
+      field_type: "byte[]".to_string(),
+      field_type_package_name: None,
+      field_large_object: true,
+      ..string_field_config("thumbnail")
+    };
+
+    let source = add_field(&field_config);
+
+    assert!(
+      source.contains(
+        "  @Column(name = \"thumbnail\", unique = false, nullable = true)\n  @Lob\n  private byte[] thumbnail;"
+      ),
+      "{}",
+      source
+    );
+    assert!(source.contains("import jakarta.persistence.Lob;"), "{}", source);
+  }
+
+  #[test]
+  fn test_string_array_field_imports_nothing_from_java_lang() {
+    let field_config = BasicFieldConfig {
+      field_type: "String[]".to_string(),
+      field_large_object: true,
+      ..string_field_config("tags")
+    };
+
+    let source = add_field(&field_config);
+
+    assert!(source.contains("  private String[] tags;"), "{}", source);
+    assert!(!source.contains("@Lob"), "{}", source);
+    assert!(!source.contains("import java.lang"), "{}", source);
+  }
+
+  #[test]
+  fn test_rejects_illegal_array_and_varargs_types() {
+    for field_type in ["void[]", "String...", "int[]]", "[]", "9Type[]"] {
+      let field_config =
+        BasicFieldConfig { field_type: field_type.to_string(), ..string_field_config("values") };
+      let (temp_dir, entity_file_path) = setup_entity();
+      let b64_source = BASE64_STANDARD.encode(ENTITY_SOURCE);
+
+      let result = run(&b64_source, &entity_file_path, &field_config, temp_dir.path());
+
+      assert!(result.is_err(), "{} should be rejected", field_type);
+    }
+    assert_eq!(validate_java_parameter_type("String..."), Ok("String...".to_string()));
+    assert_eq!(validate_java_field_type("int[][]"), Ok("int[][]".to_string()));
+  }
+  #[test]
+  fn test_response_content_hash_matches_written_file() {
+    let (temp_dir, entity_file_path) = setup_entity();
+    let b64_source = BASE64_STANDARD.encode(ENTITY_SOURCE);
+
+    let response =
+      run(&b64_source, &entity_file_path, &string_field_config("title"), temp_dir.path()).unwrap();
+
+    let written_source = fs::read_to_string(&entity_file_path).unwrap();
+    assert_eq!(response.content_hash, Some(get_content_hash(&written_source)));
+    assert_ne!(response.content_hash, Some(get_content_hash(ENTITY_SOURCE)));
+    assert_eq!(
+      get_content_hash("abc"),
+      "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+  }
+
+  fn money_field_config(field_name: &str) -> BasicFieldConfig {
+    BasicFieldConfig {
+      field_type: "BigDecimal".to_string(),
+      field_type_package_name: Some("java.math".to_string()),
+      money_precision: Some(19),
+      money_scale: Some(2),
+      ..string_field_config(field_name)
+    }
+  }
+
+  #[test]
+  fn test_big_decimal_field_uses_money_defaults() {
+    let source = add_field(&money_field_config("price"));
+
+    assert!(
+      source.contains(
+        "  @Column(name = \"price\", unique = false, nullable = true, precision = 19, scale = 2)\n  private BigDecimal price;"
+      ),
+      "{}",
+      source
+    );
+    let string_source =
+      add_field(&BasicFieldConfig { money_precision: Some(19), ..string_field_config("title") });
+    assert!(!string_source.contains("precision"), "{}", string_source);
+  }
+
+  #[test]
+  fn test_big_decimal_field_keeps_explicit_precision_and_scale() {
+    let field_config = BasicFieldConfig {
+      field_precision: Some(12),
+      money_scale: Some(4),
+      ..money_field_config("amount")
+    };
+
+    let source = add_field(&field_config);
+
+    assert!(source.contains("nullable = true, precision = 12, scale = 4)"), "{}", source);
+    let dimensionless = add_field(&BasicFieldConfig {
+      money_precision: None,
+      money_scale: None,
+      ..money_field_config("total")
+    });
+    assert!(dimensionless.contains("@Column(name = \"total\", unique = false, nullable = true)"));
+  }
+
+  #[test]
+  fn test_validate_entity_warns_about_dimensionless_big_decimal() {
+    let source = "package com.example;\n\n@Entity\npublic class Invoice {\n  @Id\n  private Long id;\n\n  private BigDecimal total;\n\n  @Column(precision = 10, scale = 2)\n  private BigDecimal tax;\n}\n";
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+    let response = validate_entity_service::run(
+      None,
+      Some(&BASE64_STANDARD.encode(source)),
+      None,
+      None,
+      temp_dir.path(),
+    )
+    .unwrap();
+
+    let codes: Vec<(&str, Option<&str>)> = response
+      .findings
+      .iter()
+      .map(|finding| (finding.code.as_str(), finding.field_name.as_deref()))
+      .collect();
+    assert_eq!(codes, vec![("DIMENSIONLESS_BIG_DECIMAL", Some("total"))]);
+    assert!(response.is_valid);
+  }
+
+  #[test]
+  fn test_quotes_reserved_column_name_when_requested() {
+    let (temp_dir, entity_file_path) = setup_entity();
+    let b64_source = BASE64_STANDARD.encode(ENTITY_SOURCE);
+    let field_config = BasicFieldConfig {
+      reserved_word_check: ReservedWordCheck {
+        dialect: Some(SqlDialect::Mysql),
+        quote_reserved: true,
+      },
+      ..string_field_config("order")
+    };
+
+    let response = run(&b64_source, &entity_file_path, &field_config, temp_dir.path()).unwrap();
+    let source = fs::read_to_string(&entity_file_path).unwrap();
+
+    assert!(source.contains("@Column(name = \"\\\"order\\\"\""), "{}", source);
+    assert_eq!(
+      response.warnings,
+      vec!["RESERVED_SQL_WORD: Column name 'order' is a reserved word in mysql, it was quoted"]
+    );
+  }
+
+  #[test]
+  fn test_validate_entity_warns_about_reserved_table_and_column_names() {
+    let source = "package com.example;\n\n@Entity\npublic class User {\n  @Id\n  private Long id;\n\n  private Integer order;\n\n  @Column(name = \"\\\"user\\\"\")\n  private String name;\n}\n";
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+    let response = validate_entity_service::run(
+      None,
+      Some(&BASE64_STANDARD.encode(source)),
+      Some(SqlDialect::Postgresql),
+      None,
+      temp_dir.path(),
+    )
+    .unwrap();
+
+    let codes: Vec<(&str, Option<&str>)> = response
+      .findings
+      .iter()
+      .map(|finding| (finding.code.as_str(), finding.field_name.as_deref()))
+      .collect();
+    // The explicitly quoted column is left alone
+    assert_eq!(codes, vec![("RESERVED_SQL_WORD", None), ("RESERVED_SQL_WORD", Some("order"))]);
+    assert!(response.findings[0].message.contains("'user'"));
+    assert!(response.is_valid);
+  }
+}
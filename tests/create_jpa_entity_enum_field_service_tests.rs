@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod create_jpa_entity_enum_field_service_tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::file_response::FileResponse;
+  use syntaxpresso_core::commands::java::services::create_jpa_entity_enum_field_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::enum_field_config::EnumFieldConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::java_enum_type::JavaEnumType;
+  use syntaxpresso_core::commands::java::treesitter::types::member_access_config::MemberAccessConfig;
+  use tempfile::TempDir;
+
+  const ENTITY_SOURCE: &str =
+    "package com.example;\n\n@Entity\npublic class Order {\n  @Id\n  private Long id;\n}\n";
+
+  fn setup_project() -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(package_dir.join("model")).unwrap();
+    fs::write(
+      package_dir.join("model/OrderStatus.java"),
+      "package com.example.model;\n\npublic enum OrderStatus { OPEN, CLOSED }\n",
+    )
+    .unwrap();
+    fs::write(
+      package_dir.join("model/Priority.java"),
+      "package com.example.model;\n\npublic class Priority {}\n",
+    )
+    .unwrap();
+    let entity_file_path = package_dir.join("Order.java");
+    fs::write(&entity_file_path, ENTITY_SOURCE).unwrap();
+    (temp_dir, entity_file_path)
+  }
+
+  fn enum_field_config(enum_type: &str, enum_package_name: &str) -> EnumFieldConfig {
+    EnumFieldConfig {
+      field_name: "status".to_string(),
+      enum_type: enum_type.to_string(),
+      enum_package_name: enum_package_name.to_string(),
+      enum_type_storage: JavaEnumType::String,
+      field_length: None,
+      field_nullable: false,
+      field_unique: false,
+      validate_enum_type: true,
+      access_config: MemberAccessConfig::default(),
+    }
+  }
+
+  fn add_field(field_config: EnumFieldConfig) -> (Result<FileResponse, String>, String) {
+    let (temp_dir, entity_file_path) = setup_project();
+    let b64_source = BASE64_STANDARD.encode(ENTITY_SOURCE);
+    let result = run(temp_dir.path(), &b64_source, &entity_file_path, field_config);
+    (result, fs::read_to_string(&entity_file_path).unwrap())
+  }
+
+  #[test]
+  fn test_rejects_enum_missing_from_project_package() {
+    let (result, written) = add_field(enum_field_config("OrderStatsu", "com.example.model"));
+
+    assert_eq!(
+      result.err().unwrap(),
+      "NOT_AN_ENUM: Enum 'com.example.model.OrderStatsu' not found in the project"
+    );
+    assert_eq!(written, ENTITY_SOURCE);
+  }
+
+  #[test]
+  fn test_rejects_type_that_is_not_an_enum() {
+    let (result, written) = add_field(enum_field_config("Priority", "com.example.model"));
+
+    assert_eq!(
+      result.err().unwrap(),
+      "NOT_AN_ENUM: 'com.example.model.Priority' is declared as 'class', not as 'enum'"
+    );
+    assert_eq!(written, ENTITY_SOURCE);
+  }
+
+  #[test]
+  fn test_accepts_project_enum_and_trusts_external_packages() {
+    let (result, written) = add_field(enum_field_config("OrderStatus", "com.example.model"));
+    assert!(result.is_ok());
+    assert!(written.contains("import com.example.model.OrderStatus;"), "{}", written);
+
+    let (result, written) = add_field(enum_field_config("DayOfWeek", "java.time"));
+    assert!(result.is_ok());
+    assert!(written.contains("import java.time.DayOfWeek;"), "{}", written);
+    assert!(written.contains("private DayOfWeek status;"), "{}", written);
+  }
+}
@@ -0,0 +1,288 @@
+#[cfg(test)]
+mod create_jpa_entity_service_tests {
+  use std::fs;
+
+  use syntaxpresso_core::commands::java::responses::create_jpa_entity_response::CreateJPAEntityResponse;
+  use syntaxpresso_core::commands::java::services::create_jpa_entity_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::reserved_word_check::ReservedWordCheck;
+  use syntaxpresso_core::commands::java::treesitter::types::sql_dialect::SqlDialect;
+  use tempfile::TempDir;
+
+  fn setup_project(superclass_source: Option<&str>) -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let common_dir = temp_dir.path().join("src/main/java/com/example/common");
+    fs::create_dir_all(&common_dir).unwrap();
+    if let Some(source) = superclass_source {
+      fs::write(common_dir.join("BaseEntity.java"), source).unwrap();
+    }
+    temp_dir
+  }
+
+  fn create_entity(
+    project: &TempDir,
+    superclass_type: &str,
+    superclass_package_name: &str,
+  ) -> (CreateJPAEntityResponse, String) {
+    let response = run(
+      project.path(),
+      "com.example.domain",
+      "Book",
+      Some(superclass_type),
+      Some(superclass_package_name),
+      true,
+      &ReservedWordCheck::default(),
+      false,
+      false,
+    )
+    .unwrap();
+    let source = fs::read_to_string(&response.file.file_path).unwrap();
+    (response, source)
+  }
+
+  #[test]
+  fn test_entity_extends_generic_mapped_superclass() {
+    let project = setup_project(Some(
+      "package com.example.common;\n\n@MappedSuperclass\npublic abstract class BaseEntity<ID extends Serializable> {\n  @Id\n  private ID id;\n}\n",
+    ));
+
+    let (response, source) = create_entity(&project, "BaseEntity<Long>", "com.example.common");
+
+    assert!(response.file.warnings.is_empty(), "{:?}", response.file.warnings);
+    assert!(source.contains("public class Book extends BaseEntity<Long> {"), "{}", source);
+    assert!(source.contains("import com.example.common.BaseEntity;\n"), "{}", source);
+    assert!(!source.contains("BaseEntity<Long>;"), "{}", source);
+  }
+
+  #[test]
+  fn test_warns_about_unmapped_superclass_and_type_argument_mismatch() {
+    let project = setup_project(Some(
+      "package com.example.common;\n\npublic abstract class BaseEntity<ID> {\n  private ID id;\n}\n",
+    ));
+
+    let (response, source) = create_entity(&project, "BaseEntity", "com.example.common");
+
+    assert_eq!(
+      response.file.warnings,
+      vec![
+        "Superclass 'BaseEntity' is not annotated with @MappedSuperclass or @Entity, its fields won't be persisted",
+        "Superclass 'BaseEntity' declares 1 type parameter(s) but 0 type argument(s) were given",
+      ]
+    );
+    assert!(source.contains("public class Book extends BaseEntity {"), "{}", source);
+  }
+
+  #[test]
+  fn test_superclass_outside_project_is_trusted_and_imported() {
+    let project = setup_project(None);
+
+    let (response, source) =
+      create_entity(&project, "AbstractAggregateRoot<Book>", "org.springframework.data.domain");
+    let same_package = run(
+      project.path(),
+      "com.example.domain",
+      "Author",
+      Some("Person"),
+      Some("com.example.domain"),
+      true,
+      &ReservedWordCheck::default(),
+      false,
+      false,
+    )
+    .unwrap();
+    let invalid = run(
+      project.path(),
+      "com.example.domain",
+      "Review",
+      Some("BaseEntity[]"),
+      Some("com.example.common"),
+      true,
+      &ReservedWordCheck::default(),
+      false,
+      false,
+    );
+
+    assert!(response.file.warnings.is_empty());
+    assert!(
+      source.contains("import org.springframework.data.domain.AbstractAggregateRoot;\n"),
+      "{}",
+      source
+    );
+    assert!(
+      !fs::read_to_string(&same_package.file.file_path)
+        .unwrap()
+        .contains("import com.example.domain")
+    );
+    assert!(invalid.is_err());
+  }
+
+  #[test]
+  fn test_no_annotations_generates_plain_class() {
+    let project =
+      setup_project(Some("package com.example.common;\n\npublic abstract class BaseEntity {\n}\n"));
+
+    let response = run(
+      project.path(),
+      "com.example.domain",
+      "Book",
+      Some("BaseEntity"),
+      Some("com.example.common"),
+      false,
+      &ReservedWordCheck::default(),
+      false,
+      false,
+    )
+    .unwrap();
+    let source = fs::read_to_string(&response.file.file_path).unwrap();
+
+    assert!(!response.annotations_emitted);
+    // An unmapped superclass is expected for a plain class
+    assert!(response.file.warnings.is_empty(), "{:?}", response.file.warnings);
+    assert!(!source.contains("jakarta.persistence"), "{}", source);
+    assert!(!source.contains("@Entity") && !source.contains("@Table"), "{}", source);
+    assert!(source.contains("public class Book extends BaseEntity {"), "{}", source);
+  }
+
+  #[test]
+  fn test_warns_about_reserved_table_name_and_quotes_it_when_requested() {
+    let project = setup_project(None);
+    let check = ReservedWordCheck { dialect: Some(SqlDialect::Postgresql), quote_reserved: false };
+
+    let response =
+      run(project.path(), "com.example.domain", "User", None, None, true, &check, false, false)
+        .unwrap();
+    let source = fs::read_to_string(&response.file.file_path).unwrap();
+    let quote_check = ReservedWordCheck { quote_reserved: true, ..check };
+    let quoted = run(
+      project.path(),
+      "com.example.domain",
+      "Order",
+      None,
+      None,
+      true,
+      &quote_check,
+      false,
+      false,
+    )
+    .unwrap();
+    let quoted_source = fs::read_to_string(&quoted.file.file_path).unwrap();
+
+    assert!(source.contains("@Table(name = \"user\")"), "{}", source);
+    assert_eq!(
+      response.file.warnings,
+      vec![
+        "RESERVED_SQL_WORD: Table name 'user' is a reserved word in postgresql, quote it or use --quote-reserved"
+      ]
+    );
+    assert!(quoted_source.contains("@Table(name = \"\\\"order\\\"\")"), "{}", quoted_source);
+    assert_eq!(
+      quoted.file.warnings,
+      vec!["RESERVED_SQL_WORD: Table name 'order' is a reserved word in postgresql, it was quoted"]
+    );
+  }
+
+  #[test]
+  fn test_warns_about_class_with_same_name_in_another_package() {
+    let project = setup_project(None);
+    fs::write(
+      project.path().join("src/main/java/com/example/common/Book.java"),
+      "package com.example.common;\n\npublic class Book {\n}\n",
+    )
+    .unwrap();
+
+    let response = run(
+      project.path(),
+      "com.example.domain",
+      "Book",
+      None,
+      None,
+      true,
+      &ReservedWordCheck::default(),
+      false,
+      false,
+    )
+    .unwrap();
+    let strict = run(
+      project.path(),
+      "com.example.catalog",
+      "Book",
+      None,
+      None,
+      false,
+      &ReservedWordCheck::default(),
+      true,
+      false,
+    );
+
+    assert_eq!(response.duplicate_class_paths.len(), 1);
+    assert!(response.duplicate_class_paths[0].ends_with("com/example/common/Book.java"));
+    assert_eq!(
+      response.file.warnings[0],
+      format!("A class named 'Book' already exists in {}", response.duplicate_class_paths[0])
+    );
+    let error = strict.err().unwrap();
+    assert!(error.starts_with("A class named 'Book' already exists: "), "{}", error);
+    assert!(error.contains("com/example/domain/Book.java"), "{}", error);
+  }
+
+  #[test]
+  fn test_fails_when_table_name_is_already_mapped() {
+    let project = setup_project(None);
+    fs::write(
+      project.path().join("src/main/java/com/example/common/Volume.java"),
+      "package com.example.common;\n\n@Entity\n@Table(name = \"book\")\npublic class Volume {\n  @Id\n  private Long id;\n}\n",
+    )
+    .unwrap();
+
+    let error = run(
+      project.path(),
+      "com.example.domain",
+      "Book",
+      None,
+      None,
+      true,
+      &ReservedWordCheck::default(),
+      false,
+      false,
+    )
+    .err()
+    .unwrap();
+
+    assert!(
+      error.starts_with("Table 'book' is already mapped by entity 'Volume' in "),
+      "{}",
+      error
+    );
+    assert!(!project.path().join("src/main/java/com/example/domain/Book.java").exists());
+  }
+
+  #[test]
+  fn test_entity_implements_serializable_after_superclass() {
+    let project = setup_project(Some(
+      "package com.example.common;\n\n@MappedSuperclass\npublic abstract class BaseEntity {\n}\n",
+    ));
+
+    let response = run(
+      project.path(),
+      "com.example.domain",
+      "Invoice",
+      Some("BaseEntity"),
+      Some("com.example.common"),
+      true,
+      &ReservedWordCheck::default(),
+      false,
+      true,
+    )
+    .unwrap();
+    let source = fs::read_to_string(&response.file.file_path).unwrap();
+
+    assert!(response.serializable_added);
+    assert!(
+      source.contains(
+        "public class Invoice extends BaseEntity implements Serializable {\n  private static final long serialVersionUID = 1L;\n}"
+      ),
+      "{}",
+      source
+    );
+    assert_eq!(source.matches("import java.io.Serializable;").count(), 1, "{}", source);
+  }
+}
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod create_jpa_entity_version_field_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::file_response::FileResponse;
+  use syntaxpresso_core::commands::java::services::create_jpa_entity_version_field_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::member_access_config::MemberAccessConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::version_field_config::VersionFieldConfig;
+  use tempfile::TempDir;
+
+  const ENTITY_SOURCE: &str =
+    "package com.example;\n\n@Entity\npublic class Order {\n  @Id\n  private Long id;\n}\n";
+
+  fn version_field_config(column_name: Option<&str>, column_nullable: bool) -> VersionFieldConfig {
+    VersionFieldConfig {
+      field_name: "version".to_string(),
+      field_type: "Long".to_string(),
+      column_name: column_name.map(|name| name.to_string()),
+      column_nullable,
+      access_config: MemberAccessConfig::default(),
+    }
+  }
+
+  fn add_field(
+    source: &str,
+    field_config: VersionFieldConfig,
+  ) -> (Result<FileResponse, String>, String) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_file_path = temp_dir.path().join("src/main/java/com/example/Order.java");
+    fs::create_dir_all(entity_file_path.parent().unwrap()).unwrap();
+    fs::write(&entity_file_path, source).unwrap();
+    let b64_source = BASE64_STANDARD.encode(source);
+    let result = run(temp_dir.path(), &b64_source, &entity_file_path, field_config);
+    (result, fs::read_to_string(&entity_file_path).unwrap())
+  }
+
+  #[test]
+  fn test_maps_version_to_named_column() {
+    let (result, written) =
+      add_field(ENTITY_SOURCE, version_field_config(Some("row_version"), false));
+
+    assert!(result.unwrap().warnings.is_empty());
+    assert!(
+      written.contains(
+        "  @Version\n  @Column(name = \"row_version\", nullable = false)\n  private Long version;"
+      ),
+      "{}",
+      written
+    );
+    assert!(written.contains("import jakarta.persistence.Version;"), "{}", written);
+    assert!(written.contains("import jakarta.persistence.Column;"), "{}", written);
+  }
+
+  #[test]
+  fn test_warns_about_nullable_version_column() {
+    let (result, written) = add_field(ENTITY_SOURCE, version_field_config(None, true));
+
+    assert!(written.contains("@Column(name = \"version\", nullable = true)"), "{}", written);
+    assert_eq!(
+      result.unwrap().warnings,
+      vec![
+        "Version column 'version' is nullable, rows with a null version aren't protected by optimistic locking"
+      ]
+    );
+  }
+
+  #[test]
+  fn test_rejects_invalid_column_and_second_version_field() {
+    let (result, written) =
+      add_field(ENTITY_SOURCE, version_field_config(Some("row version"), false));
+    assert!(result.err().unwrap().starts_with("'row version' is not a valid SQL identifier"));
+    assert_eq!(written, ENTITY_SOURCE);
+
+    let versioned = ENTITY_SOURCE.replace("}\n", "\n  @Version\n  private int revision;\n}\n");
+    let (result, _) = add_field(&versioned, version_field_config(None, false));
+    assert_eq!(result.err().unwrap(), "Entity already has a @Version field: 'revision'");
+  }
+}
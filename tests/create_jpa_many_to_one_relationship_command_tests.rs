@@ -0,0 +1,236 @@
+#[cfg(test)]
+mod create_jpa_many_to_one_relationship_command_tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::create_jpa_many_to_one_relationship_command;
+  use syntaxpresso_core::commands::java::treesitter::types::collection_type::CollectionType;
+  use syntaxpresso_core::commands::java::treesitter::types::fetch_type::FetchType;
+  use syntaxpresso_core::commands::java::treesitter::types::many_to_one_field_config::ManyToOneFieldConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::mapping_type::MappingType;
+  use tempfile::TempDir;
+
+  const BOOK_SOURCE: &str = "package com.example;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Book {\n  @Id\n  private Long id;\n}\n";
+
+  const AUTHOR_SOURCE: &str = "package com.example;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Author {\n  @Id\n  private Long id;\n}\n";
+
+  const PUBLISHER_SOURCE: &str = "package com.example.publishing;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Publisher {\n  @Id\n  private Long id;\n}\n";
+
+  const GENRE_SOURCE: &str =
+    "package com.example;\n\npublic enum Genre {\n  FICTION,\n  POETRY\n}\n";
+
+  fn create_project() -> (TempDir, PathBuf, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    let book_path = package_dir.join("Book.java");
+    let author_path = package_dir.join("Author.java");
+    fs::write(&book_path, BOOK_SOURCE).unwrap();
+    fs::write(&author_path, AUTHOR_SOURCE).unwrap();
+    fs::write(package_dir.join("Genre.java"), GENRE_SOURCE).unwrap();
+    fs::create_dir_all(package_dir.join("publishing")).unwrap();
+    fs::write(package_dir.join("publishing/Publisher.java"), PUBLISHER_SOURCE).unwrap();
+    (temp_dir, book_path, author_path)
+  }
+
+  fn create_config(mapping_type: Option<MappingType>) -> ManyToOneFieldConfig {
+    ManyToOneFieldConfig {
+      inverse_field_type: "Author".to_string(),
+      fetch_type: FetchType::Lazy,
+      collection_type: CollectionType::List,
+      mapping_type,
+      owning_side_cascades: Vec::new(),
+      inverse_side_cascades: Vec::new(),
+      owning_side_other: Vec::new(),
+      inverse_side_other: Vec::new(),
+      foreign_key_name: None,
+      no_foreign_key_constraint: false,
+      required: false,
+      insertable: None,
+      updatable: None,
+      map_key_type: None,
+      map_key_column: None,
+    }
+  }
+
+  #[test]
+  fn test_bidirectional_relationship_reports_owning_and_inverse_files() {
+    let (temp_dir, book_path, author_path) = create_project();
+
+    let response = create_jpa_many_to_one_relationship_command::execute(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(BOOK_SOURCE),
+      &book_path,
+      "author".to_string(),
+      "books".to_string(),
+      create_config(None),
+    );
+
+    assert!(response.succeed, "{:?}", response.error_reason);
+    let data = response.data.unwrap();
+    assert_eq!(data.files_count, 2);
+    assert_eq!(data.files[0].file_type, "Book");
+    assert_eq!(PathBuf::from(&data.files[0].file_path), book_path);
+    assert_eq!(data.files[1].file_type, "Author");
+    assert_eq!(PathBuf::from(&data.files[1].file_path), author_path);
+    assert!(data.files.iter().all(|file| file.content_hash.is_some()));
+    assert!(fs::read_to_string(&author_path).unwrap().contains("mappedBy = \"author\""));
+  }
+
+  #[test]
+  fn test_unidirectional_relationship_reports_only_owning_file() {
+    let (temp_dir, book_path, author_path) = create_project();
+
+    let response = create_jpa_many_to_one_relationship_command::execute(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(BOOK_SOURCE),
+      &book_path,
+      "author".to_string(),
+      "books".to_string(),
+      create_config(Some(MappingType::UnidirectionalJoinColumn)),
+    );
+
+    let data = response.data.unwrap();
+    assert_eq!(data.files_count, 1);
+    assert_eq!(PathBuf::from(&data.files[0].file_path), book_path);
+    assert_eq!(fs::read_to_string(&author_path).unwrap(), AUTHOR_SOURCE);
+  }
+
+  fn create_map_config(map_key_type: &str) -> ManyToOneFieldConfig {
+    ManyToOneFieldConfig {
+      collection_type: CollectionType::Map,
+      map_key_type: Some(map_key_type.to_string()),
+      ..create_config(None)
+    }
+  }
+
+  #[test]
+  fn test_entity_keyed_map_uses_map_key_join_column() {
+    let (temp_dir, book_path, author_path) = create_project();
+
+    let response = create_jpa_many_to_one_relationship_command::execute(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(BOOK_SOURCE),
+      &book_path,
+      "author".to_string(),
+      "booksByPublisher".to_string(),
+      create_map_config("Publisher"),
+    );
+
+    assert!(response.succeed, "{:?}", response.error_reason);
+    let author_source = fs::read_to_string(&author_path).unwrap();
+    assert!(author_source.contains("@MapKeyJoinColumn(name = \"publisher_id\")"));
+    assert!(author_source.contains("private Map<Publisher, Book> booksByPublisher"));
+    assert!(author_source.contains("import jakarta.persistence.MapKeyJoinColumn;"));
+    assert!(author_source.contains("import com.example.publishing.Publisher;"));
+    assert!(author_source.contains("import java.util.Map;"));
+    assert!(!fs::read_to_string(&book_path).unwrap().contains("MapKey"));
+  }
+
+  #[test]
+  fn test_enum_keyed_map_uses_map_key_enumerated_and_column() {
+    let (temp_dir, book_path, author_path) = create_project();
+    let config = ManyToOneFieldConfig {
+      map_key_column: Some("genre".to_string()),
+      ..create_map_config("Genre")
+    };
+
+    let response = create_jpa_many_to_one_relationship_command::execute(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(BOOK_SOURCE),
+      &book_path,
+      "author".to_string(),
+      "booksByGenre".to_string(),
+      config,
+    );
+
+    assert!(response.succeed, "{:?}", response.error_reason);
+    let author_source = fs::read_to_string(&author_path).unwrap();
+    assert!(author_source.contains("@MapKeyEnumerated(EnumType.STRING)"));
+    assert!(author_source.contains("@MapKeyColumn(name = \"genre\")"));
+    assert!(author_source.contains("private Map<Genre, Book> booksByGenre"));
+    assert!(author_source.contains("import jakarta.persistence.EnumType;"));
+  }
+
+  #[test]
+  fn test_map_key_type_must_be_basic_entity_or_enum() {
+    let (temp_dir, book_path, author_path) = create_project();
+
+    let response = create_jpa_many_to_one_relationship_command::execute(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(BOOK_SOURCE),
+      &book_path,
+      "author".to_string(),
+      "booksByShelf".to_string(),
+      create_map_config("Shelf"),
+    );
+
+    assert!(!response.succeed);
+    assert_eq!(
+      response.error_reason.unwrap(),
+      "Map key type 'Shelf' is neither a basic type nor an entity or enum of the project"
+    );
+    assert_eq!(fs::read_to_string(&book_path).unwrap(), BOOK_SOURCE);
+    assert_eq!(fs::read_to_string(&author_path).unwrap(), AUTHOR_SOURCE);
+  }
+
+  fn create_read_only_config() -> ManyToOneFieldConfig {
+    ManyToOneFieldConfig {
+      insertable: Some(false),
+      updatable: Some(false),
+      ..create_config(Some(MappingType::UnidirectionalJoinColumn))
+    }
+  }
+
+  #[test]
+  fn test_read_only_join_column_next_to_writable_column_has_no_warning() {
+    let (temp_dir, book_path, _) = create_project();
+    let book_source = BOOK_SOURCE.replace(
+      "  private Long id;\n",
+      "  private Long id;\n\n  @Column(name = \"author_id\")\n  private Long authorId;\n",
+    );
+    fs::write(&book_path, &book_source).unwrap();
+
+    let response = create_jpa_many_to_one_relationship_command::execute(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(&book_source),
+      &book_path,
+      "author".to_string(),
+      "books".to_string(),
+      create_read_only_config(),
+    );
+
+    assert!(response.succeed, "{:?}", response.error_reason);
+    let data = response.data.unwrap();
+    assert!(data.files[0].warnings.is_empty(), "{:?}", data.files[0].warnings);
+    assert!(fs::read_to_string(&book_path).unwrap().contains(
+      "@JoinColumn(name = \"author_id\", nullable = true, insertable = false, updatable = false)"
+    ));
+  }
+
+  #[test]
+  fn test_read_only_join_column_as_only_mapping_warns() {
+    let (temp_dir, book_path, _) = create_project();
+
+    let response = create_jpa_many_to_one_relationship_command::execute(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(BOOK_SOURCE),
+      &book_path,
+      "author".to_string(),
+      "books".to_string(),
+      create_read_only_config(),
+    );
+
+    assert!(response.succeed, "{:?}", response.error_reason);
+    let data = response.data.unwrap();
+    assert_eq!(
+      data.files[0].warnings,
+      vec![
+        "Join column 'author_id' is not insertable nor updatable and no other field maps it, so \
+         the foreign key can't be written"
+          .to_string()
+      ]
+    );
+  }
+}
@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod delete_jpa_entity_field_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::file_response::FileResponse;
+  use syntaxpresso_core::commands::java::services::delete_jpa_entity_field_service::run;
+  use syntaxpresso_core::common::utils::scan_deadline::ScanDeadline;
+  use tempfile::TempDir;
+
+  const PRODUCT_SOURCE: &str = r#"package com.example;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Entity;
+import jakarta.persistence.EnumType;
+import jakarta.persistence.Enumerated;
+import jakarta.persistence.Id;
+
+@Entity
+public class Product {
+  @Id
+  private Long id;
+
+  @Column(name = "product_status", nullable = false)
+  @Enumerated(EnumType.STRING)
+  private Status status;
+
+  @Column(nullable = false)
+  private boolean active;
+
+  public Status getStatus() {
+    return status;
+  }
+
+  public void setStatus(Status status) {
+    this.status = status;
+  }
+
+  public boolean isActive() {
+    return active;
+  }
+}
+"#;
+
+  fn delete(field_name: &str) -> (Result<FileResponse, String>, String) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("Product.java");
+    fs::write(&file_path, PRODUCT_SOURCE).unwrap();
+    let result = run(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(PRODUCT_SOURCE),
+      &file_path,
+      field_name,
+      &ScanDeadline::unbounded(),
+    );
+    (result, fs::read_to_string(&file_path).unwrap())
+  }
+
+  #[test]
+  fn test_deletes_field_annotations_accessors_and_unused_imports() {
+    let (result, written) = delete("status");
+
+    let response = result.unwrap();
+    assert_eq!(response.file_type, "Product");
+    assert_eq!(response.file_package_name, "com.example");
+    assert!(response.content_hash.is_some());
+    assert!(!written.contains("status"), "{}", written);
+    assert!(!written.contains("Status"), "{}", written);
+    assert!(!written.contains("import jakarta.persistence.Enumerated;"));
+    assert!(!written.contains("import jakarta.persistence.EnumType;"));
+    assert!(written.contains("import jakarta.persistence.Column;"));
+    assert!(written.contains("@Column(nullable = false)\n  private boolean active;"));
+    assert!(written.contains("public boolean isActive() {"));
+  }
+
+  #[test]
+  fn test_deletes_boolean_field_with_is_getter() {
+    let (result, written) = delete("active");
+
+    assert!(result.is_ok());
+    assert!(!written.contains("active"), "{}", written);
+    assert!(!written.contains("isActive"));
+    assert!(written.contains("public Status getStatus() {"));
+    assert!(written.contains("import jakarta.persistence.Column;"));
+  }
+
+  #[test]
+  fn test_missing_field_returns_error_without_writing() {
+    let (result, written) = delete("price");
+
+    assert_eq!(result.err().unwrap(), "Field 'price' not found in class 'Product'");
+    assert_eq!(written, PRODUCT_SOURCE);
+  }
+
+  #[test]
+  fn test_deleting_last_field_and_accessors_trims_blank_lines() {
+    let source = "package com.example;\n\n@Entity\npublic class Tag {\n  @Id\n  private Long id;\n\n  private String label;\n\n  public String getLabel() {\n    return label;\n  }\n\n  public void setLabel(String label) {\n    this.label = label;\n  }\n\n}\n";
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("Tag.java");
+    fs::write(&file_path, source).unwrap();
+
+    run(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(source),
+      &file_path,
+      "label",
+      &ScanDeadline::unbounded(),
+    )
+    .unwrap();
+
+    assert_eq!(
+      fs::read_to_string(&file_path).unwrap(),
+      "package com.example;\n\n@Entity\npublic class Tag {\n  @Id\n  private Long id;\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_warns_about_leftover_field_and_accessor_references() {
+    let source = "package com.example;\n\n@Entity\npublic class User {\n  @Id\n  private Long id;\n\n  private String firstName;\n\n  public String getFirstName() {\n    return firstName;\n  }\n\n  @Override\n  public String toString() {\n    return \"User(\" + firstName + \")\";\n  }\n}\n";
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    let file_path = package_dir.join("User.java");
+    fs::write(&file_path, source).unwrap();
+    fs::write(
+      package_dir.join("UserService.java"),
+      "package com.example;\n\npublic class UserService {\n  public String greet(User user) {\n    return \"Hello \" + user.getFirstName();\n  }\n}\n",
+    )
+    .unwrap();
+
+    let response = run(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(source),
+      &file_path,
+      "firstName",
+      &ScanDeadline::unbounded(),
+    )
+    .unwrap();
+
+    assert_eq!(
+      response.warnings,
+      vec![
+        "Field 'firstName' was removed but is still referenced at line 10, update them before \
+         compiling",
+        "Accessor 'getFirstName' was removed but is still called in \
+         src/main/java/com/example/UserService.java at line 5, update it before compiling",
+      ]
+    );
+    assert!(!fs::read_to_string(&file_path).unwrap().contains("getFirstName"));
+  }
+}
@@ -0,0 +1,230 @@
+#[cfg(test)]
+mod edit_idempotency_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::services::create_jpa_entity_basic_field_service;
+  use syntaxpresso_core::commands::java::treesitter::services::annotation_service::add_annotation;
+  use syntaxpresso_core::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+  use syntaxpresso_core::commands::java::treesitter::services::constructor_declaration_service::{
+    AddConstructorDeclarationParams, add_constructor_declaration,
+  };
+  use syntaxpresso_core::commands::java::treesitter::services::field_declaration_service::{
+    AddFieldDeclarationParams, add_field_declaration,
+  };
+  use syntaxpresso_core::commands::java::treesitter::types::annotation_types::AnnotationInsertionPosition;
+  use syntaxpresso_core::commands::java::treesitter::types::basic_field_config::BasicFieldConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::java_basic_types::FieldInsertionPosition;
+  use syntaxpresso_core::commands::java::treesitter::types::java_field_temporal::JavaFieldTemporal;
+  use syntaxpresso_core::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+  use syntaxpresso_core::commands::java::treesitter::types::member_access_config::MemberAccessConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::reserved_word_check::ReservedWordCheck;
+  use syntaxpresso_core::common::supported_language::SupportedLanguage;
+  use syntaxpresso_core::common::ts_file::TSFile;
+  use tempfile::TempDir;
+
+  const ENTITY_SHAPES: [&str; 7] = [
+    // Annotated fields
+    "package com.example;\n\n@Entity\npublic class User {\n  @Id\n  private Long id;\n}\n",
+    // Empty body on one line
+    "package com.example;\n\n@Entity\npublic class User {}\n",
+    // Empty body on two lines
+    "package com.example;\n\n@Entity\npublic class User {\n}\n",
+    // Fields and methods, four space indentation
+    "package com.example;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class User {\n    private Long id;\n\n    public Long getId() {\n        return id;\n    }\n}\n",
+    // Methods only
+    "package com.example;\n\npublic class User {\n  public String toString() {\n    return \"User\";\n  }\n}\n",
+    // Javadoc and trailing blank line before the closing brace
+    "package com.example;\n\n/** A user. */\n@Entity\npublic class User {\n  /** The id. */\n  @Id\n  private Long id;\n\n}\n",
+    // Nested class
+    "package com.example;\n\n@Entity\npublic class User {\n  private Long id;\n\n  static class Key {\n    private Long value;\n  }\n}\n",
+  ];
+
+  /// Text added by an edit, `None` when the edit also changed existing text.
+  fn get_inserted_text(before: &str, after: &str) -> Option<String> {
+    let prefix_len = before.bytes().zip(after.bytes()).take_while(|(a, b)| a == b).count();
+    let max_suffix_len = before.len().min(after.len()) - prefix_len;
+    let suffix_len = before
+      .bytes()
+      .rev()
+      .zip(after.bytes().rev())
+      .take(max_suffix_len)
+      .take_while(|(a, b)| a == b)
+      .count();
+    if prefix_len + suffix_len != before.len() {
+      return None;
+    }
+    Some(after[prefix_len..after.len() - suffix_len].to_string())
+  }
+
+  /// Applies an edit repeatedly and checks that every repetition inserts the same text, without
+  /// touching the surrounding whitespace. The first application is only compared for determinism,
+  /// as it may also add imports or break an empty body `{}` onto separate lines.
+  fn assert_idempotent_formatting(source: &str, edit: impl Fn(&str) -> String) {
+    let once = edit(source);
+    assert_eq!(edit(source), once, "Edit is not deterministic");
+    let twice = edit(&once);
+    let thrice = edit(&twice);
+    let second = get_inserted_text(&once, &twice)
+      .unwrap_or_else(|| panic!("Second edit changed existing text:\n{}", twice));
+    let third = get_inserted_text(&twice, &thrice)
+      .unwrap_or_else(|| panic!("Third edit changed existing text:\n{}", thrice));
+    // The diff of a repeated block may be shifted, which makes it a rotation of the previous one
+    assert!(
+      second.len() == third.len() && format!("{}{}", second, second).contains(&third),
+      "Third edit inserted {:?} instead of {:?}:\n{}",
+      third,
+      second,
+      thrice
+    );
+    for line in thrice.lines() {
+      assert_eq!(line.trim_end(), line, "Trailing whitespace in:\n{}", thrice);
+    }
+    assert!(!thrice.contains("\n\n\n"), "Consecutive blank lines in:\n{}", thrice);
+    assert!(!thrice.contains("{\n\n"), "Blank line after an opening brace in:\n{}", thrice);
+    assert!(!thrice.contains(";}"), "Closing brace not on its own line in:\n{}", thrice);
+  }
+
+  fn add_annotated_field(source: &str, insertion_position: FieldInsertionPosition) -> String {
+    let mut ts_file = TSFile::from_source_code(source, SupportedLanguage::Java);
+    let class_start_byte = get_public_class_node(&ts_file).unwrap().start_byte();
+    let params = AddFieldDeclarationParams {
+      insertion_position,
+      visibility_modifier: JavaVisibilityModifier::Private,
+      field_modifiers: Vec::new(),
+      field_type: "String",
+      field_name: "name",
+      field_initialization: None,
+    };
+    add_field_declaration(&mut ts_file, class_start_byte, params, |builder| {
+      builder.add_annotation("@Column")?.with_argument("@Column", "name", "\"name\"")?;
+      builder.add_annotation("@Lob")?;
+      builder.build()
+    })
+    .unwrap()
+    .unwrap();
+    ts_file.source_code.clone()
+  }
+
+  #[test]
+  fn test_add_field_declaration_is_idempotent_for_every_insertion_position() {
+    for source in ENTITY_SHAPES {
+      for insertion_position in [
+        FieldInsertionPosition::AfterLastField,
+        FieldInsertionPosition::BeforeFirstMethod,
+        FieldInsertionPosition::EndOfClassBody,
+      ] {
+        assert_idempotent_formatting(source, |current| {
+          add_annotated_field(current, insertion_position.clone())
+        });
+      }
+    }
+  }
+
+  #[test]
+  fn test_add_field_declaration_annotates_new_field_when_name_already_exists() {
+    let source = "package com.example;\n\npublic class User {\n  private String name;\n}\n";
+
+    let result = add_annotated_field(source, FieldInsertionPosition::EndOfClassBody);
+
+    assert_eq!(
+      result,
+      "package com.example;\n\npublic class User {\n  private String name;\n\n  @Column(name = \"name\")\n  @Lob\n  private String name;\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_add_field_declaration_uses_class_member_indentation() {
+    let source = ENTITY_SHAPES[3];
+
+    let result = add_annotated_field(source, FieldInsertionPosition::AfterLastField);
+
+    assert!(
+      result
+        .contains("    private Long id;\n    @Column(name = \"name\")\n    @Lob\n    private String name;\n\n    public Long getId()"),
+      "{}",
+      result
+    );
+  }
+
+  #[test]
+  fn test_add_class_annotation_is_idempotent() {
+    for source in ENTITY_SHAPES {
+      for insertion_position in [
+        AnnotationInsertionPosition::AboveScopeDeclaration,
+        AnnotationInsertionPosition::BeforeFirstAnnotation,
+      ] {
+        assert_idempotent_formatting(source, |current| {
+          let mut ts_file = TSFile::from_source_code(current, SupportedLanguage::Java);
+          let class_start_byte = get_public_class_node(&ts_file).unwrap().start_byte();
+          add_annotation(
+            &mut ts_file,
+            class_start_byte,
+            &insertion_position,
+            "@Table(name = \"users\")",
+          )
+          .unwrap();
+          ts_file.source_code.clone()
+        });
+      }
+    }
+  }
+
+  #[test]
+  fn test_add_constructor_declaration_is_idempotent() {
+    for source in ENTITY_SHAPES {
+      assert_idempotent_formatting(source, |current| {
+        let mut ts_file = TSFile::from_source_code(current, SupportedLanguage::Java);
+        let class_start_byte = get_public_class_node(&ts_file).unwrap().start_byte();
+        let params = AddConstructorDeclarationParams {
+          visibility_modifier: JavaVisibilityModifier::Public,
+          parameters: vec![("String", "name")],
+          body_statements: vec!["this.name = name;".to_string()],
+        };
+        add_constructor_declaration(&mut ts_file, class_start_byte, params).unwrap();
+        ts_file.source_code.clone()
+      });
+    }
+  }
+
+  #[test]
+  fn test_create_basic_field_service_is_idempotent() {
+    let field_config = BasicFieldConfig {
+      field_name: "created_at".to_string(),
+      field_type: "Date".to_string(),
+      field_type_package_name: Some("java.util".to_string()),
+      field_length: None,
+      field_precision: None,
+      field_scale: None,
+      money_precision: None,
+      money_scale: None,
+      field_temporal: Some(JavaFieldTemporal::Timestamp),
+      field_timezone_storage: None,
+      field_unique: false,
+      field_nullable: false,
+      field_large_object: false,
+      field_optional: false,
+      field_fetch_type: None,
+      sync_length: false,
+      explicit_string_length: false,
+      access_config: MemberAccessConfig::default(),
+      reserved_word_check: ReservedWordCheck::default(),
+    };
+    for source in ENTITY_SHAPES {
+      assert_idempotent_formatting(source, |current| {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let entity_file_path = temp_dir.path().join("User.java");
+        fs::write(&entity_file_path, current).unwrap();
+        let b64_source = BASE64_STANDARD.encode(current);
+        create_jpa_entity_basic_field_service::run(
+          &b64_source,
+          &entity_file_path,
+          &field_config,
+          temp_dir.path(),
+        )
+        .unwrap();
+        fs::read_to_string(&entity_file_path).unwrap()
+      });
+    }
+  }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod emit_camel_to_snake_column_report_service_tests {
+  use std::fs;
+  use std::path::{Path, PathBuf};
+
+  use syntaxpresso_core::commands::java::services::emit_camel_to_snake_column_report_service::run;
+  use tempfile::TempDir;
+
+  const CUSTOMER_SOURCE: &str = r#"package com.example;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Entity;
+import jakarta.persistence.Id;
+
+@Entity
+public class Customer {
+  @Id
+  private Long id;
+
+  @Column(name = "firstName", nullable = false)
+  private String firstName;
+
+  private String lastName;
+
+  @Column(name = "zip_code")
+  private String zipCode;
+
+  @Column(name = "\"eMail\"")
+  private String email;
+}
+"#;
+
+  fn write_java_file(root: &Path, class_name: &str, source: &str) -> PathBuf {
+    let package_dir = root.join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    let path = package_dir.join(format!("{}.java", class_name));
+    fs::write(&path, source).unwrap();
+    path
+  }
+
+  #[test]
+  fn test_reports_explicit_mixed_case_columns() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let customer_path = write_java_file(temp_dir.path(), "Customer", CUSTOMER_SOURCE);
+    write_java_file(
+      temp_dir.path(),
+      "Address",
+      "package com.example;\n\n@Embeddable\npublic class Address {\n  @Column(name = \"STREET_NAME\")\n  private String streetName;\n}\n",
+    );
+
+    let response = run(temp_dir.path(), false, false).unwrap();
+
+    assert!(!response.applied);
+    assert_eq!(response.columns_count, 2);
+    let customer =
+      response.entities.iter().find(|entity| entity.entity_type == "Customer").unwrap();
+    assert_eq!(customer.columns.len(), 1);
+    assert_eq!(customer.columns[0].field, "firstName");
+    assert_eq!(customer.columns[0].current_column, "firstName");
+    assert_eq!(customer.columns[0].suggested_column, "first_name");
+    let address = response.entities.iter().find(|entity| entity.entity_type == "Address").unwrap();
+    assert_eq!(address.columns[0].suggested_column, "street_name");
+    assert_eq!(fs::read_to_string(customer_path).unwrap(), CUSTOMER_SOURCE);
+  }
+
+  #[test]
+  fn test_applies_suggestions_including_verbatim_implicit_names() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let customer_path = write_java_file(temp_dir.path(), "Customer", CUSTOMER_SOURCE);
+
+    let response = run(temp_dir.path(), true, true).unwrap();
+
+    let fields: Vec<&str> =
+      response.entities[0].columns.iter().map(|column| column.field.as_str()).collect();
+    assert_eq!(fields, vec!["firstName", "lastName"]);
+    assert!(!response.entities[0].columns[1].has_explicit_name);
+    let source = fs::read_to_string(customer_path).unwrap();
+    assert!(source.contains("@Column(name = \"first_name\", nullable = false)"), "{}", source);
+    assert!(
+      source.contains("  @Column(name = \"last_name\")\n  private String lastName;"),
+      "{}",
+      source
+    );
+    assert!(source.contains("@Column(name = \"\\\"eMail\\\"\")"), "{}", source);
+    assert_eq!(source.matches("import jakarta.persistence.Column;").count(), 1);
+    assert_eq!(run(temp_dir.path(), true, false).unwrap().columns_count, 0);
+  }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod entity_mapping_service_tests {
+  use syntaxpresso_core::commands::java::treesitter::services::entity_mapping_service::*;
+  use syntaxpresso_core::commands::java::treesitter::types::entity_mapping::MappedClassKind;
+  use syntaxpresso_core::common::supported_language::SupportedLanguage;
+  use syntaxpresso_core::common::ts_file::TSFile;
+
+  fn create_ts_file(content: &str) -> TSFile {
+    TSFile::from_source_code(content, SupportedLanguage::Java)
+  }
+
+  const ORDER_ENTITY: &str = r#"
+package com.example.shop;
+
+@Entity
+public class OrderItem {
+  @Id
+  @GeneratedValue(strategy = GenerationType.IDENTITY)
+  private Long id;
+
+  private String productName;
+
+  @Column(name = "qty", nullable = false)
+  private int quantity;
+
+  @Enumerated(EnumType.STRING)
+  @Column(length = 20)
+  private Status status;
+
+  @ManyToOne(optional = false)
+  private Order order;
+
+  @OneToMany(mappedBy = "item")
+  private List<Discount> discounts;
+
+  @Transient
+  private String cached;
+
+  private static final long serialVersionUID = 1L;
+
+  @Embedded
+  @AttributeOverride(name = "street", column = @Column(name = "ship_street"))
+  private Address shippingAddress;
+}
+"#;
+
+  #[test]
+  fn test_default_naming_strategy() {
+    assert_eq!(get_default_table_name("OrderItem"), "order_item");
+    assert_eq!(get_default_column_name("createdAt"), "created_at");
+    assert_eq!(get_default_join_column_name("mainAuthor"), "main_author_id");
+  }
+
+  #[test]
+  fn test_get_entity_mapping_columns() {
+    let ts_file = create_ts_file(ORDER_ENTITY);
+    let mapping = get_entity_mapping(&ts_file).expect("Should extract entity mapping");
+
+    assert_eq!(mapping.kind, MappedClassKind::Entity);
+    assert_eq!(mapping.table_name, "order_item");
+    assert!(!mapping.has_explicit_table_name);
+    assert_eq!(mapping.package_name.as_deref(), Some("com.example.shop"));
+    let column_names: Vec<&str> = mapping.columns.iter().map(|c| c.column_name.as_str()).collect();
+    assert_eq!(column_names, vec!["id", "product_name", "qty", "status"]);
+
+    let id = &mapping.columns[0];
+    assert!(id.is_id && id.is_identity && !id.nullable);
+    let quantity = &mapping.columns[2];
+    assert!(quantity.has_explicit_name);
+    assert!(!quantity.nullable);
+    let status = &mapping.columns[3];
+    assert_eq!(status.enum_type.as_deref(), Some("STRING"));
+    assert_eq!(status.length, Some(20));
+  }
+
+  #[test]
+  fn test_get_entity_mapping_relationships_and_embedded() {
+    let ts_file = create_ts_file(ORDER_ENTITY);
+    let mapping = get_entity_mapping(&ts_file).expect("Should extract entity mapping");
+
+    assert_eq!(mapping.join_columns.len(), 1, "Collections are not foreign key columns");
+    let order = &mapping.join_columns[0];
+    assert_eq!(order.column_name, "order_id");
+    assert_eq!(order.target_type, "Order");
+    assert!(!order.nullable);
+
+    assert_eq!(mapping.embedded.len(), 1);
+    let address = &mapping.embedded[0];
+    assert_eq!(address.embeddable_type, "Address");
+    assert_eq!(
+      address.attribute_overrides,
+      vec![("street".to_string(), "ship_street".to_string())]
+    );
+  }
+
+  #[test]
+  fn test_get_entity_mapping_explicit_table() {
+    let ts_file = create_ts_file(
+      "@Entity\n@Table(name = \"orders\", schema = \"shop\")\npublic class Order {\n  @Id\n  private Long id;\n}",
+    );
+    let mapping = get_entity_mapping(&ts_file).expect("Should extract entity mapping");
+
+    assert_eq!(mapping.table_name, "orders");
+    assert_eq!(mapping.schema.as_deref(), Some("shop"));
+    assert_eq!(mapping.qualified_table_name(), "shop.orders");
+  }
+
+  #[test]
+  fn test_get_entity_mapping_not_jpa_class() {
+    let ts_file = create_ts_file("public class Helper {\n  private String name;\n}");
+    assert!(get_entity_mapping(&ts_file).is_none());
+  }
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod execution_meta_tests {
+  use std::fs;
+
+  use syntaxpresso_core::commands::java::get_all_jpa_entities_command;
+  use syntaxpresso_core::common::response::Response;
+  use syntaxpresso_core::common::utils::execution_meta;
+  use tempfile::TempDir;
+
+  // The counters are process-wide, so every scenario runs in a single test to avoid races
+  #[test]
+  fn test_meta_is_populated_only_when_enabled() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    for entity in ["Author", "Book"] {
+      let source = format!(
+        "package com.example;\n\n@Entity\npublic class {} {{\n  @Id\n  private Long id;\n}}\n",
+        entity
+      );
+      fs::write(package_dir.join(format!("{}.java", entity)), source).unwrap();
+    }
+
+    let response = get_all_jpa_entities_command::execute(temp_dir.path(), None, false);
+    assert!(response.meta.is_none());
+    assert!(!response.to_json().unwrap().contains("meta"));
+
+    execution_meta::enable();
+    let response = get_all_jpa_entities_command::execute(temp_dir.path(), None, false);
+    let meta = response.meta.clone().expect("Meta should be present when enabled");
+    assert_eq!(meta.files_scanned, 2);
+    assert!(meta.queries_executed > 0);
+    assert!(response.to_json().unwrap().contains("\"filesScanned\":2"));
+
+    let error_response: Response<()> =
+      Response::error("get-all-jpa-entities".to_string(), String::new(), "Failed".to_string());
+    assert!(error_response.meta.is_some());
+
+    execution_meta::disable();
+    let response = get_all_jpa_entities_command::execute(temp_dir.path(), None, false);
+    assert!(response.meta.is_none());
+  }
+}
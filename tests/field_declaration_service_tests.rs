@@ -2,6 +2,7 @@
 mod field_declaration_service_tests {
   use syntaxpresso_core::commands::java::treesitter::services::class_declaration_service::find_class_node_by_name;
   use syntaxpresso_core::commands::java::treesitter::services::field_declaration_service::*;
+  use syntaxpresso_core::commands::java::treesitter::types::java_basic_types::FieldInsertionPosition;
   use syntaxpresso_core::common::supported_language::SupportedLanguage;
   use syntaxpresso_core::common::ts_file::TSFile;
 
@@ -349,4 +350,71 @@ public class UserService {
       );
     }
   }
+
+  #[test]
+  fn test_moved_field_keeps_its_javadoc() {
+    let mut source_file = create_ts_file(
+      r#"public class Order {
+  private Long id;
+
+  /**
+   * Total amount, taxes included.
+   */
+  @Column(nullable = false)
+  private BigDecimal total;
+
+  private String reference;
+}
+"#,
+    );
+    let mut destination_file = create_ts_file(
+      r#"public class Invoice {
+    private Long id;
+}
+"#,
+    );
+    let source_class_byte = find_class_node_by_name(&source_file, "Order").unwrap().start_byte();
+    let field_text =
+      remove_field_declaration(&mut source_file, source_class_byte, "total").unwrap();
+    let destination_class_byte =
+      find_class_node_by_name(&destination_file, "Invoice").unwrap().start_byte();
+    add_field_declaration_text(
+      &mut destination_file,
+      destination_class_byte,
+      &FieldInsertionPosition::AfterLastField,
+      &field_text,
+    )
+    .unwrap();
+
+    assert_eq!(
+      source_file.source_code,
+      "public class Order {\n  private Long id;\n\n  private String reference;\n}\n"
+    );
+    assert_eq!(
+      destination_file.source_code,
+      r#"public class Invoice {
+    private Long id;
+    /**
+     * Total amount, taxes included.
+     */
+    @Column(nullable = false)
+    private BigDecimal total;
+}
+"#
+    );
+    let invoice_node = find_class_node_by_name(&destination_file, "Invoice").unwrap();
+    assert!(
+      find_field_declaration_node_by_name(&destination_file, "total", invoice_node).is_some()
+    );
+  }
+
+  #[test]
+  fn test_remove_field_declaration_rejects_shared_declarations() {
+    let mut ts_file = create_ts_file("public class Point {\n  private int x, y;\n}\n");
+    let class_byte = find_class_node_by_name(&ts_file, "Point").unwrap().start_byte();
+
+    assert!(remove_field_declaration(&mut ts_file, class_byte, "x").is_none());
+    assert!(remove_field_declaration(&mut ts_file, class_byte, "missing").is_none());
+    assert_eq!(ts_file.source_code, "public class Point {\n  private int x, y;\n}\n");
+  }
 }
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod file_header_tests {
+  use std::fs;
+
+  use syntaxpresso_core::commands::java::services::{
+    create_java_file_service, create_jpa_entity_service,
+  };
+  use syntaxpresso_core::commands::java::treesitter::types::java_file_type::JavaFileType;
+  use syntaxpresso_core::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+  use syntaxpresso_core::commands::java::treesitter::types::reserved_word_check::ReservedWordCheck;
+  use syntaxpresso_core::common::utils::file_header::{self, FileHeader};
+  use tempfile::TempDir;
+
+  #[test]
+  fn test_header_appears_once_above_package_statement() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let header_path = temp_dir.path().join("header.txt");
+    fs::write(&header_path, "/*\n * Copyright {year} Acme\n * File: {filename}\n */\n").unwrap();
+    file_header::set_header(FileHeader::from_file(&header_path.display().to_string()).unwrap());
+
+    let class_response = create_java_file_service::run(
+      temp_dir.path(),
+      "com.example",
+      "order_service",
+      &JavaFileType::Class,
+      &JavaSourceDirectoryType::Main,
+      None,
+      None,
+    );
+    let entity_response = create_jpa_entity_service::run(
+      temp_dir.path(),
+      "com.example",
+      "Customer",
+      None,
+      None,
+      true,
+      &ReservedWordCheck::default(),
+      false,
+      false,
+    );
+    file_header::reset_header();
+
+    let source = fs::read_to_string(class_response.unwrap().file_path).unwrap();
+    let year = &source["/*\n * Copyright ".len().."/*\n * Copyright ".len() + 4];
+    assert!(year.chars().all(|c| c.is_ascii_digit()), "{}", source);
+    assert!(
+      source.starts_with(&format!(
+        "/*\n * Copyright {} Acme\n * File: OrderService.java\n */\n\npackage com.example;",
+        year
+      )),
+      "{}",
+      source
+    );
+    assert_eq!(source.matches("Copyright").count(), 1);
+    let entity_source = fs::read_to_string(entity_response.unwrap().file.file_path).unwrap();
+    assert_eq!(entity_source.matches("Copyright").count(), 1);
+    assert!(
+      entity_source.find("Copyright").unwrap() < entity_source.find("package").unwrap(),
+      "{}",
+      entity_source
+    );
+  }
+
+  #[test]
+  fn test_plain_text_header_is_wrapped_in_comment() {
+    let header = FileHeader::new("Licensed under MIT\n\nSee LICENSE").unwrap();
+
+    assert_eq!(header.render("Order.java"), "/*\n * Licensed under MIT\n *\n * See LICENSE\n */\n");
+    assert!(FileHeader::new("  \n").is_err());
+    assert!(FileHeader::from_file("/nonexistent/header.txt").is_err());
+  }
+
+  #[test]
+  fn test_files_have_no_header_by_default() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+    let response = create_java_file_service::run(
+      temp_dir.path(),
+      "com.example",
+      "Order",
+      &JavaFileType::Class,
+      &JavaSourceDirectoryType::Main,
+      None,
+      None,
+    );
+
+    let source = fs::read_to_string(response.unwrap().file_path).unwrap();
+    assert!(source.starts_with("package com.example;"), "{}", source);
+  }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod generate_audit_entity_service_tests {
+  use std::fs;
+
+  use syntaxpresso_core::commands::java::services::{
+    create_jpa_entity_service, generate_audit_entity_service::run,
+  };
+  use syntaxpresso_core::commands::java::treesitter::types::reserved_word_check::ReservedWordCheck;
+  use tempfile::TempDir;
+
+  #[test]
+  fn test_generates_auditing_mapped_superclass() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+    let response =
+      run(temp_dir.path(), "com.example.common", "Auditable", "Instant", "String", None).unwrap();
+
+    let source = fs::read_to_string(&response.file.file_path).unwrap();
+    assert!(
+      source.contains(
+        "@MappedSuperclass\n@EntityListeners(AuditingEntityListener.class)\npublic abstract class Auditable {\n  @CreatedDate\n  @Column(name = \"created_at\", updatable = false)\n  private Instant createdAt;\n\n  @LastModifiedDate\n  @Column(name = \"updated_at\")\n  private Instant updatedAt;\n"
+      ),
+      "{}",
+      source
+    );
+    assert!(source.contains("  private String createdBy;\n"), "{}", source);
+    assert!(source.contains("  public String getUpdatedBy() {\n    return updatedBy;\n  }\n}"));
+    for import in [
+      "import jakarta.persistence.MappedSuperclass;",
+      "import java.time.Instant;",
+      "import org.springframework.data.annotation.LastModifiedBy;",
+      "import org.springframework.data.jpa.domain.support.AuditingEntityListener;",
+    ] {
+      assert_eq!(source.matches(import).count(), 1, "{}", source);
+    }
+    let annotations: Vec<&str> =
+      response.fields.iter().map(|field| field.annotation.as_str()).collect();
+    assert_eq!(annotations, vec!["CreatedDate", "LastModifiedDate", "CreatedBy", "LastModifiedBy"]);
+    assert_eq!(response.file.warnings.len(), 2);
+  }
+
+  #[test]
+  fn test_entity_extends_generated_audit_base() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let config_dir = temp_dir.path().join("src/main/java/com/example/config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+      config_dir.join("AuditConfig.java"),
+      "package com.example.config;\n\n@Configuration\n@EnableJpaAuditing\npublic class AuditConfig {\n  @Bean\n  AuditorAware<UUID> auditorAware() {\n    return () -> Optional.empty();\n  }\n}\n",
+    )
+    .unwrap();
+
+    let audit_base = run(
+      temp_dir.path(),
+      "com.example.common",
+      "AuditedEntity",
+      "LocalDateTime",
+      "UUID",
+      Some("java.util"),
+    )
+    .unwrap();
+    let entity = create_jpa_entity_service::run(
+      temp_dir.path(),
+      "com.example.domain",
+      "Invoice",
+      Some(&audit_base.file.file_type),
+      Some(&audit_base.file.file_package_name),
+      true,
+      &ReservedWordCheck::default(),
+      false,
+      false,
+    )
+    .unwrap();
+
+    assert!(audit_base.file.warnings.is_empty());
+    let base_source = fs::read_to_string(&audit_base.file.file_path).unwrap();
+    assert!(base_source.contains("import java.util.UUID;"), "{}", base_source);
+    assert!(base_source.contains("  private LocalDateTime updatedAt;"), "{}", base_source);
+    let entity_source = fs::read_to_string(&entity.file.file_path).unwrap();
+    assert!(
+      entity_source.contains("public class Invoice extends AuditedEntity"),
+      "{}",
+      entity_source
+    );
+    assert!(
+      entity_source.contains("import com.example.common.AuditedEntity;"),
+      "{}",
+      entity_source
+    );
+    assert!(entity.file.warnings.is_empty(), "{:?}", entity.file.warnings);
+  }
+
+  #[test]
+  fn test_rejects_unsupported_timestamp_type() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+    let result = run(temp_dir.path(), "com.example.common", "Auditable", "Date", "String", None);
+
+    assert_eq!(
+      result.err().unwrap(),
+      "Type 'Date' can't hold audit timestamps, use one of: Instant, LocalDateTime, OffsetDateTime, ZonedDateTime"
+    );
+    assert!(!temp_dir.path().join("src/main/java/com/example/common/Auditable.java").exists());
+  }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod generate_controller_test_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::services::generate_controller_test_service::run;
+  use tempfile::TempDir;
+
+  const CONTROLLER_SOURCE: &str = "package com.example.web;\n\nimport com.example.service.UserService;\n\n@RestController\n@RequestMapping(\"/users\")\npublic class UserController {\n  private final UserService userService;\n\n  public UserController(UserService userService) {\n    this.userService = userService;\n  }\n\n  @GetMapping(\"/{id}\")\n  public User findById(@PathVariable Long id) {\n    return userService.findById(id);\n  }\n\n  @PostMapping\n  @ResponseStatus(HttpStatus.CREATED)\n  public User create(@RequestBody User user) {\n    return this.userService.save(user);\n  }\n\n  @DeleteMapping(path = \"/{userId}\")\n  @ResponseStatus(code = HttpStatus.NO_CONTENT)\n  public void delete(@PathVariable(\"userId\") UUID id) {\n    userService.deleteById(id);\n  }\n}\n";
+
+  #[test]
+  fn test_controller_test_covers_each_endpoint() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let b64_source = BASE64_STANDARD.encode(CONTROLLER_SOURCE);
+
+    let response = run(temp_dir.path(), &b64_source, None, None).unwrap();
+
+    let endpoints: Vec<(&str, &str, &str)> = response
+      .endpoints
+      .iter()
+      .map(|e| (e.http_method.as_str(), e.path.as_str(), e.test_method.as_str()))
+      .collect();
+    assert_eq!(
+      endpoints,
+      vec![
+        ("GET", "/users/{id}", "findByIdReturnsOk"),
+        ("POST", "/users", "createReturnsCreated"),
+        ("DELETE", "/users/{userId}", "deleteReturnsNoContent"),
+      ]
+    );
+    assert_eq!(response.mocked_services, vec!["UserService"]);
+    assert!(response.warnings.is_empty(), "{:?}", response.warnings);
+    assert!(
+      response.test.file_path.ends_with("src/test/java/com/example/web/UserControllerTest.java")
+    );
+    let test_source = fs::read_to_string(&response.test.file_path).unwrap();
+    assert!(
+      test_source
+        .contains("@WebMvcTest(UserController.class)\npublic class UserControllerTest {\n")
+    );
+    assert!(test_source.contains("import com.example.service.UserService;\n"));
+    assert!(test_source.contains("  @MockBean\n  private UserService userService;\n"));
+    assert!(test_source.contains(
+      "  @Test\n  void findByIdReturnsOk() throws Exception {\n    mockMvc.perform(MockMvcRequestBuilders.get(\"/users/{id}\", 1L))\n        .andExpect(MockMvcResultMatchers.status().isOk());\n    Mockito.verify(userService).findById(ArgumentMatchers.any());\n  }\n"
+    ));
+    assert!(test_source.contains(
+      "            .contentType(MediaType.APPLICATION_JSON)\n            .content(\"{}\"))\n        .andExpect(MockMvcResultMatchers.status().isCreated());\n    Mockito.verify(userService).save(ArgumentMatchers.any());\n"
+    ));
+    assert!(
+      test_source.contains("MockMvcRequestBuilders.delete(\"/users/{userId}\", UUID.randomUUID())")
+    );
+    assert!(test_source.contains("import java.util.UUID;\n"));
+  }
+
+  #[test]
+  fn test_non_controller_class_is_rejected() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let source = CONTROLLER_SOURCE.replace("@RestController\n", "");
+
+    let result = run(temp_dir.path(), &BASE64_STANDARD.encode(source), None, None);
+
+    assert_eq!(
+      result.err(),
+      Some("Class is not annotated with @RestController or @Controller".to_string())
+    );
+    assert!(!temp_dir.path().join("src").exists());
+  }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod generate_entity_diagram_service_tests {
+  use std::fs;
+  use std::path::Path;
+
+  use syntaxpresso_core::commands::java::services::generate_entity_diagram_service::run;
+  use syntaxpresso_core::common::utils::scan_deadline::ScanDeadline;
+  use tempfile::TempDir;
+
+  fn write_file(root: &Path, relative_path: &str, source: &str) {
+    let path = root.join("src/main/java").join(relative_path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, source).unwrap();
+  }
+
+  fn setup_project() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    write_file(
+      temp_dir.path(),
+      "com/example/common/BaseEntity.java",
+      "package com.example.common;\n\n@MappedSuperclass\npublic abstract class BaseEntity {\n  @Id\n  private Long id;\n\n  @Version\n  private Integer version;\n}\n",
+    );
+    write_file(
+      temp_dir.path(),
+      "com/example/library/Author.java",
+      "package com.example.library;\n\n@Entity\npublic class Author extends BaseEntity {\n  @Column(nullable = false)\n  private String name;\n\n  @OneToMany(mappedBy = \"author\")\n  private List<Book> books;\n}\n",
+    );
+    write_file(
+      temp_dir.path(),
+      "com/example/library/Book.java",
+      "package com.example.library;\n\n@Entity\npublic class Book extends BaseEntity {\n  private String title;\n\n  @ManyToOne\n  private Author author;\n\n  @ManyToMany\n  private Set<Tag> tags;\n}\n",
+    );
+    write_file(
+      temp_dir.path(),
+      "com/example/tagging/Tag.java",
+      "package com.example.tagging;\n\n@Entity\npublic class Tag {\n  @Id\n  private Long id;\n\n  private String label;\n}\n",
+    );
+    temp_dir
+  }
+
+  #[test]
+  fn test_renders_entities_with_ids_and_merged_bidirectional_edges() {
+    let temp_dir = setup_project();
+
+    let response = run(temp_dir.path(), None, None, &ScanDeadline::unbounded()).unwrap();
+
+    assert_eq!(response.entities, vec!["Author", "Book", "Tag"]);
+    assert_eq!(response.relationships_count, 2);
+    assert!(
+      response.diagram.contains(
+        "package com.example.common {\n  abstract class BaseEntity <<MappedSuperclass>> {\n    * id : Long <<id>>\n    --\n    version : Integer <<version>>\n  }\n}"
+      ),
+      "{}",
+      response.diagram
+    );
+    assert!(
+      response.diagram.contains("  entity Author {\n    * name : String\n  }"),
+      "{}",
+      response.diagram
+    );
+    assert!(
+      response.diagram.ends_with(
+        "\nBaseEntity <|-- Author\nBaseEntity <|-- Book\nBook \"*\" <--> \"1\" Author : author / books (mappedBy = \"author\")\nBook \"*\" --> \"*\" Tag : tags\n@enduml\n"
+      ),
+      "{}",
+      response.diagram
+    );
+  }
+
+  #[test]
+  fn test_filters_to_package_keeping_inherited_superclass() {
+    let temp_dir = setup_project();
+
+    let response =
+      run(temp_dir.path(), Some("com.example.tagging"), None, &ScanDeadline::unbounded()).unwrap();
+
+    assert_eq!(response.entities, vec!["Tag"]);
+    assert_eq!(response.relationships_count, 0);
+    assert!(!response.diagram.contains("BaseEntity"), "{}", response.diagram);
+
+    let response =
+      run(temp_dir.path(), Some("com.example.library"), None, &ScanDeadline::unbounded()).unwrap();
+
+    assert_eq!(response.entities, vec!["Author", "Book"]);
+    assert!(response.diagram.contains("abstract class BaseEntity"), "{}", response.diagram);
+    // Relationships leaving the package are still drawn
+    assert!(response.diagram.contains("Book \"*\" --> \"*\" Tag : tags"), "{}", response.diagram);
+  }
+
+  #[test]
+  fn test_writes_diagram_within_working_directory_only() {
+    let temp_dir = setup_project();
+    let output_file_path = temp_dir.path().join("docs/entities.puml");
+
+    let response =
+      run(temp_dir.path(), None, Some(&output_file_path), &ScanDeadline::unbounded()).unwrap();
+
+    assert!(response.output_file_path.is_some());
+    assert_eq!(fs::read_to_string(&output_file_path).unwrap(), response.diagram);
+    let outside =
+      run(temp_dir.path(), None, Some(Path::new("../entities.puml")), &ScanDeadline::unbounded());
+    assert!(outside.is_err());
+  }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod generate_entity_listener_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::generate_entity_listener_response::GenerateEntityListenerResponse;
+  use syntaxpresso_core::commands::java::services::generate_entity_listener_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::lifecycle_callback_type::LifecycleCallbackType;
+  use tempfile::TempDir;
+
+  const ENTITY_SOURCE: &str = "package com.example;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Order {\n  @Id\n  private Long id;\n}\n";
+
+  fn generate(
+    temp_dir: &TempDir,
+    source: &str,
+    callbacks: &[LifecycleCallbackType],
+    package_name: Option<&str>,
+    listener_name: Option<&str>,
+    entity_parameter: bool,
+  ) -> (Result<GenerateEntityListenerResponse, String>, String) {
+    let entity_file_path = temp_dir.path().join("Order.java");
+    fs::write(&entity_file_path, source).unwrap();
+    let b64_source = BASE64_STANDARD.encode(source);
+    let response = run(
+      temp_dir.path(),
+      &b64_source,
+      &entity_file_path,
+      callbacks,
+      package_name,
+      listener_name,
+      entity_parameter,
+    );
+    (response, fs::read_to_string(&entity_file_path).unwrap())
+  }
+
+  #[test]
+  fn test_scaffolds_listener_and_registers_it_on_entity() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let callbacks = [
+      LifecycleCallbackType::PrePersist,
+      LifecycleCallbackType::PreUpdate,
+      LifecycleCallbackType::PrePersist,
+    ];
+
+    let (response, entity_source) =
+      generate(&temp_dir, ENTITY_SOURCE, &callbacks, Some("com.example.listener"), None, true);
+
+    let response = response.unwrap();
+    assert!(response.annotation_added);
+    assert_eq!(response.annotation, "@EntityListeners(OrderListener.class)");
+    assert_eq!(response.written_files.files_count, 2);
+    assert_eq!(response.written_files.files[1].file_type, "Order");
+    assert_eq!(response.callbacks.len(), 2);
+    assert_eq!(response.callbacks[1].method_name, "preUpdate");
+    assert_eq!(
+      entity_source,
+      "package com.example;\n\nimport jakarta.persistence.EntityListeners;\nimport jakarta.persistence.Entity;\nimport com.example.listener.OrderListener;\n\n@Entity\n@EntityListeners(OrderListener.class)\npublic class Order {\n  @Id\n  private Long id;\n}\n"
+    );
+    let listener_source = fs::read_to_string(&response.written_files.files[0].file_path).unwrap();
+    assert_eq!(
+      listener_source,
+      "package com.example.listener;\n\nimport jakarta.persistence.PrePersist;\nimport jakarta.persistence.PreUpdate;\nimport com.example.Order;\n\npublic class OrderListener {\n  @PrePersist\n  public void prePersist(Order order) {}\n\n  @PreUpdate\n  public void preUpdate(Order order) {}\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_merges_into_existing_entity_listeners() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let source =
+      ENTITY_SOURCE.replace("@Entity\n", "@Entity\n@EntityListeners(AuditListener.class)\n");
+
+    let (response, entity_source) = generate(
+      &temp_dir,
+      &source,
+      &[LifecycleCallbackType::PostLoad],
+      None,
+      Some("TrackingListener"),
+      false,
+    );
+
+    let response = response.unwrap();
+    assert!(!response.annotation_added);
+    assert_eq!(
+      response.annotation,
+      "@EntityListeners({AuditListener.class, TrackingListener.class})"
+    );
+    assert_eq!(entity_source.matches("@EntityListeners").count(), 1);
+    let listener_source = fs::read_to_string(&response.written_files.files[0].file_path).unwrap();
+    assert!(listener_source.contains("  @PostLoad\n  public void postLoad(Object entity) {}\n"));
+  }
+
+  #[test]
+  fn test_rejects_invalid_listener_names_and_missing_callbacks() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let callbacks = [LifecycleCallbackType::PreRemove];
+
+    let (response, entity_source) =
+      generate(&temp_dir, ENTITY_SOURCE, &callbacks, None, Some("Order"), false);
+    assert_eq!(response.err().unwrap(), "Listener name 'Order' must differ from the entity name");
+    assert_eq!(entity_source, ENTITY_SOURCE);
+
+    let (response, _) = generate(&temp_dir, ENTITY_SOURCE, &[], None, None, false);
+    assert_eq!(response.err().unwrap(), "At least one lifecycle callback must be selected");
+    assert!(!temp_dir.path().join("src").exists());
+  }
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod generate_factory_method_service_tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::generate_factory_method_response::GenerateFactoryMethodResponse;
+  use syntaxpresso_core::commands::java::services::generate_factory_method_service::run;
+  use tempfile::TempDir;
+
+  fn setup_file(file_name: &str, source: &str) -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    let file_path = package_dir.join(file_name);
+    fs::write(&file_path, source).unwrap();
+    (temp_dir, file_path)
+  }
+
+  fn generate(
+    file_name: &str,
+    source: &str,
+    method_name: &str,
+  ) -> (Result<GenerateFactoryMethodResponse, String>, String) {
+    let (temp_dir, file_path) = setup_file(file_name, source);
+    let b64_source = BASE64_STANDARD.encode(source);
+    let result = run(temp_dir.path(), &b64_source, &file_path, method_name);
+    (result, fs::read_to_string(&file_path).unwrap())
+  }
+
+  #[test]
+  fn test_factory_takes_required_fields_and_adds_protected_constructor() {
+    let source = "package com.example;\n\n@Entity\npublic class Book {\n  @Id\n  @GeneratedValue\n  private Long id;\n\n  @Column(name = \"title\", nullable = false)\n  private String title;\n\n  private String subtitle;\n\n  @NotNull\n  @ManyToOne(fetch = FetchType.LAZY)\n  private Author author;\n}\n";
+
+    let (result, written) = generate("Book.java", source, "of");
+
+    let response = result.unwrap();
+    assert_eq!(response.signature, "public static Book of(String title, Author author)");
+    assert!(response.constructor_added);
+    assert_eq!(
+      written,
+      "package com.example;\n\n@Entity\npublic class Book {\n  @Id\n  @GeneratedValue\n  private Long id;\n\n  @Column(name = \"title\", nullable = false)\n  private String title;\n\n  private String subtitle;\n\n  @NotNull\n  @ManyToOne(fetch = FetchType.LAZY)\n  private Author author;\n\n  protected Book() {}\n\n  public static Book of(String title, Author author) {\n    Book book = new Book();\n    book.title = title;\n    book.author = author;\n    return book;\n  }\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_factory_keeps_existing_no_arg_constructor() {
+    let source = "package com.example;\n\npublic class Money {\n  @NonNull\n  private String currency;\n\n  Money() {}\n\n  public Money(String currency) {\n    this.currency = currency;\n  }\n}\n";
+
+    let (result, written) = generate("Money.java", source, "create");
+
+    let response = result.unwrap();
+    assert!(!response.constructor_added);
+    assert_eq!(response.parameters.len(), 1);
+    assert_eq!(response.parameters[0].parameter_name, "currency");
+    assert!(
+      written.ends_with(
+        "  public static Money create(String currency) {\n    Money money = new Money();\n    money.currency = currency;\n    return money;\n  }\n}\n"
+      ),
+      "{}",
+      written
+    );
+  }
+
+  #[test]
+  fn test_factory_rejects_existing_method_and_invalid_name() {
+    let source = "package com.example;\n\npublic class Tag {\n  private String name;\n\n  public static Tag of() {\n    return new Tag();\n  }\n}\n";
+
+    let (existing, written) = generate("Tag.java", source, "of");
+    let (invalid, _) = generate("Tag.java", source, "1of");
+
+    assert_eq!(existing.err().unwrap(), "Class 'Tag' already declares a method 'of'");
+    assert_eq!(written, source);
+    assert_eq!(invalid.err().unwrap(), "'1of' is not a valid method name");
+  }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod generate_liquibase_changelog_service_tests {
+  use std::fs;
+  use std::path::Path;
+
+  use syntaxpresso_core::commands::java::services::generate_liquibase_changelog_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::sql_dialect::SqlDialect;
+  use tempfile::TempDir;
+
+  fn setup_project() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(
+      package_dir.join("Author.java"),
+      "package com.example;\n\n@Entity\npublic class Author {\n  @Id\n  @GeneratedValue(strategy = GenerationType.IDENTITY)\n  private Long id;\n}\n",
+    )
+    .unwrap();
+    fs::write(
+      package_dir.join("Book.java"),
+      "package com.example;\n\n@Entity\n@Table(name = \"books\")\npublic class Book {\n  @Id\n  private Long id;\n\n  @Column(nullable = false, length = 120)\n  private String title;\n\n  @ManyToOne\n  private Author author;\n}\n",
+    )
+    .unwrap();
+    temp_dir
+  }
+
+  fn generate(cwd: &Path, entity: &str, master: Option<&Path>) -> String {
+    let entity_file_path = cwd.join(format!("src/main/java/com/example/{}.java", entity));
+    let response = run(
+      cwd,
+      Some(&entity_file_path),
+      None,
+      &SqlDialect::Postgresql,
+      Path::new("db/changes"),
+      Some("jane"),
+      master,
+    )
+    .unwrap();
+    assert!(response.changeset_id.ends_with(&format!("-create-{}", response.table_name)));
+    fs::read_to_string(&response.changeset_file_path).unwrap()
+  }
+
+  #[test]
+  fn test_changeset_creates_table_with_dialect_types_and_foreign_keys() {
+    let project = setup_project();
+    let cwd = project.path().canonicalize().unwrap();
+
+    let changelog = generate(&cwd, "Book", None);
+
+    assert!(changelog.contains("      author: jane\n"), "{}", changelog);
+    assert!(changelog.contains("            tableName: books\n"), "{}", changelog);
+    assert!(changelog.contains("                  name: title\n                  type: VARCHAR(120)\n                  constraints:\n                    nullable: false\n"), "{}", changelog);
+    assert!(
+      changelog.contains(
+        "        - addForeignKeyConstraint:\n            constraintName: fk_books_author_id\n"
+      ),
+      "{}",
+      changelog
+    );
+    assert!(changelog.contains("            referencedTableName: author\n"), "{}", changelog);
+  }
+
+  #[test]
+  fn test_changeset_honors_foreign_key_annotation() {
+    let project = setup_project();
+    let cwd = project.path().canonicalize().unwrap();
+    let book_path = cwd.join("src/main/java/com/example/Book.java");
+    let book_source = fs::read_to_string(&book_path).unwrap();
+    fs::write(
+      &book_path,
+      book_source.replace(
+        "  @ManyToOne\n",
+        "  @ManyToOne\n  @JoinColumn(foreignKey = @ForeignKey(name = \"fk_book_writer\"))\n",
+      ),
+    )
+    .unwrap();
+
+    let changelog = generate(&cwd, "Book", None);
+    assert!(changelog.contains("            constraintName: fk_book_writer\n"), "{}", changelog);
+    fs::remove_dir_all(cwd.join("db/changes")).unwrap();
+
+    fs::write(
+      &book_path,
+      book_source.replace(
+        "  @ManyToOne\n",
+        "  @ManyToOne\n  @JoinColumn(foreignKey = @ForeignKey(ConstraintMode.NO_CONSTRAINT))\n",
+      ),
+    )
+    .unwrap();
+
+    let changelog = generate(&cwd, "Book", None);
+    assert!(changelog.contains("                  name: author_id\n"), "{}", changelog);
+    assert!(!changelog.contains("addForeignKeyConstraint"), "{}", changelog);
+  }
+
+  #[test]
+  fn test_changeset_is_appended_to_existing_master_changelog() {
+    let project = setup_project();
+    let cwd = project.path().canonicalize().unwrap();
+    fs::create_dir_all(cwd.join("db")).unwrap();
+    let master = cwd.join("db/master.yaml");
+    fs::write(&master, "databaseChangeLog:\n  - include:\n      file: init.yaml\n").unwrap();
+
+    let changelog = generate(&cwd, "Author", Some(Path::new("db/master.yaml")));
+
+    assert!(changelog.contains("                  autoIncrement: true\n"), "{}", changelog);
+    let master_content = fs::read_to_string(&master).unwrap();
+    assert!(
+      master_content.starts_with("databaseChangeLog:\n  - include:\n      file: init.yaml\n")
+    );
+    assert!(master_content.contains("      file: changes/"), "{}", master_content);
+    assert!(master_content.ends_with("-create-author.yaml\n      relativeToChangelogFile: true\n"));
+  }
+}
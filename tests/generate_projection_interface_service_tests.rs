@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod generate_projection_interface_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::generate_projection_interface_response::GenerateProjectionInterfaceResponse;
+  use syntaxpresso_core::commands::java::services::generate_projection_interface_service::run;
+  use tempfile::TempDir;
+
+  const CUSTOMER_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.Entity;
+import jakarta.persistence.Id;
+import java.time.LocalDate;
+
+@Entity
+public class Customer {
+  @Id
+  private Long id;
+
+  private boolean active;
+
+  private Status status;
+
+  private LocalDate birthDate;
+}
+"#;
+
+  fn setup() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let domain_dir = temp_dir.path().join("src/main/java/com/example/domain");
+    fs::create_dir_all(&domain_dir).unwrap();
+    fs::write(domain_dir.join("Customer.java"), CUSTOMER_SOURCE).unwrap();
+    fs::write(
+      domain_dir.join("Status.java"),
+      "package com.example.domain;\n\npublic enum Status {\n  ACTIVE\n}\n",
+    )
+    .unwrap();
+    temp_dir
+  }
+
+  fn generate(
+    temp_dir: &TempDir,
+    field_names: &[&str],
+    package_name: Option<&str>,
+  ) -> Result<GenerateProjectionInterfaceResponse, String> {
+    let field_names: Vec<String> = field_names.iter().map(|name| name.to_string()).collect();
+    run(temp_dir.path(), &BASE64_STANDARD.encode(CUSTOMER_SOURCE), &field_names, package_name, None)
+  }
+
+  #[test]
+  fn test_generates_getters_and_imports_in_another_package() {
+    let temp_dir = setup();
+
+    let response =
+      generate(&temp_dir, &["active", "status", "birthDate"], Some("com.example.projection"))
+        .unwrap();
+
+    let methods: Vec<(&str, &str)> = response
+      .methods
+      .iter()
+      .map(|method| (method.method_name.as_str(), method.return_type.as_str()))
+      .collect();
+    assert_eq!(
+      methods,
+      vec![("isActive", "boolean"), ("getStatus", "Status"), ("getBirthDate", "LocalDate")]
+    );
+    assert_eq!(
+      fs::read_to_string(&response.projection.file_path).unwrap(),
+      "package com.example.projection;\n\nimport com.example.domain.Status;\nimport java.time.LocalDate;\n\npublic interface CustomerProjection {\n  boolean isActive();\n  Status getStatus();\n  LocalDate getBirthDate();\n}"
+    );
+  }
+
+  #[test]
+  fn test_skips_imports_of_types_in_the_entity_package() {
+    let temp_dir = setup();
+
+    let response = generate(&temp_dir, &["status", "active"], None).unwrap();
+
+    assert_eq!(response.projection.file_package_name, "com.example.domain");
+    assert_eq!(
+      fs::read_to_string(&response.projection.file_path).unwrap(),
+      "package com.example.domain;\n\npublic interface CustomerProjection {\n  Status getStatus();\n  boolean isActive();\n}"
+    );
+  }
+
+  #[test]
+  fn test_unknown_field_returns_error_without_creating_file() {
+    let temp_dir = setup();
+
+    let result = generate(&temp_dir, &["active", "nickname"], None);
+
+    assert_eq!(result.err().unwrap(), "Field 'nickname' not found in entity 'Customer'");
+    assert!(
+      !temp_dir.path().join("src/main/java/com/example/domain/CustomerProjection.java").exists()
+    );
+  }
+}
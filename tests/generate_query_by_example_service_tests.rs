@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod generate_query_by_example_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::services::generate_query_by_example_service::run;
+  use tempfile::TempDir;
+
+  const BOOK_SOURCE: &str = r#"package com.example.domain;
+
+import jakarta.persistence.*;
+import java.time.LocalDate;
+import java.util.List;
+import lombok.Setter;
+
+@Entity
+@Setter
+public class Book {
+  @Id
+  @GeneratedValue
+  private Long id;
+
+  private String title;
+
+  private int pages;
+
+  private LocalDate publishedOn;
+
+  @Enumerated(EnumType.STRING)
+  private Genre genre;
+
+  @ManyToOne
+  private Author author;
+
+  @OneToMany(mappedBy = "book")
+  private List<Review> reviews;
+
+  @Version
+  private Long version;
+}
+"#;
+
+  #[test]
+  fn test_generates_example_helper_skipping_id_and_relations() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let b64_source = BASE64_STANDARD.encode(BOOK_SOURCE);
+
+    let response =
+      run(temp_dir.path(), &b64_source, Some("com.example.search"), None, false, false).unwrap();
+
+    let field_names: Vec<&str> =
+      response.probe_fields.iter().map(|field| field.field_name.as_str()).collect();
+    assert_eq!(field_names, vec!["title", "pages", "publishedOn", "genre"]);
+    assert_eq!(response.probe_fields[1].parameter_type, "Integer");
+    assert_eq!(response.method_name, "toExample");
+    assert!(response.warnings.is_empty());
+    assert!(response.helper.file_path.ends_with("com/example/search/BookExampleFactory.java"));
+    let source = fs::read_to_string(&response.helper.file_path).unwrap();
+    assert!(source.contains(&response.method.lines().next().unwrap().to_string()), "{}", source);
+    assert!(
+      source.contains(
+        "  public static Example<Book> toExample(String title, Integer pages, LocalDate publishedOn, Genre genre) {\n    Book probe = new Book();\n    ExampleMatcher matcher = ExampleMatcher.matching().withIgnoreNullValues();\n    probe.setTitle(title);\n    if (pages != null) {\n      probe.setPages(pages);\n    } else {\n      matcher = matcher.withIgnorePaths(\"pages\");\n    }\n"
+      ),
+      "{}",
+      source
+    );
+    assert!(source.contains("    return Example.of(probe, matcher);\n  }\n}"), "{}", source);
+    for import in [
+      "import org.springframework.data.domain.Example;",
+      "import org.springframework.data.domain.ExampleMatcher;",
+      "import com.example.domain.Book;",
+      "import java.time.LocalDate;",
+      "import com.example.domain.Genre;",
+    ] {
+      assert_eq!(source.matches(import).count(), 1, "{}", source);
+    }
+  }
+
+  #[test]
+  fn test_includes_id_and_relations_on_request() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let source = BOOK_SOURCE.replace("@Setter\n", "");
+    let source = source.replacen(
+      "  @Version",
+      "  public void setId(Long id) {\n    this.id = id;\n  }\n\n  public void setAuthor(Author author) {\n    this.author = author;\n  }\n\n  @Version",
+      1,
+    );
+    let b64_source = BASE64_STANDARD.encode(&source);
+
+    let response = run(temp_dir.path(), &b64_source, None, Some("BookProbes"), true, true).unwrap();
+
+    let field_names: Vec<&str> =
+      response.probe_fields.iter().map(|field| field.field_name.as_str()).collect();
+    assert_eq!(field_names, vec!["id", "author"]);
+    assert_eq!(response.warnings.len(), 4);
+    assert_eq!(
+      response.warnings[0],
+      "The entity has no 'setTitle' setter, 'title' is left out of the probe"
+    );
+    assert!(response.helper.file_path.ends_with("com/example/domain/BookProbes.java"));
+  }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod generate_query_dsl_metadata_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::generate_query_dsl_metadata_response::{
+    GenerateQueryDslMetadataResponse, QueryDslCoverage,
+  };
+  use syntaxpresso_core::commands::java::services::generate_query_dsl_metadata_service::run;
+  use tempfile::TempDir;
+
+  fn generate_metadata(source: &str) -> (Result<GenerateQueryDslMetadataResponse, String>, String) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("Country.java");
+    fs::write(&file_path, source).unwrap();
+    let b64_source = BASE64_STANDARD.encode(source);
+    let response = run(temp_dir.path(), &b64_source, &file_path);
+    (response, fs::read_to_string(&file_path).unwrap())
+  }
+
+  #[test]
+  fn test_confirms_jpa_entity_is_covered_by_annotation_processor() {
+    let source = "package com.example;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Country {\n  private Long id;\n}\n";
+
+    let (response, unchanged_source) = generate_metadata(source);
+
+    let response = response.unwrap();
+    assert!(!response.annotation_added);
+    assert_eq!(response.coverage, QueryDslCoverage::JpaAnnotationProcessor);
+    assert_eq!(response.query_type_name, "com.example.QCountry");
+    assert_eq!(unchanged_source, source);
+  }
+
+  #[test]
+  fn test_adds_query_entity_annotation_to_plain_class() {
+    let source = "package com.example;\n\npublic class Country {\n  private Long id;\n}\n";
+
+    let (response, updated_source) = generate_metadata(source);
+
+    let response = response.unwrap();
+    assert!(response.annotation_added);
+    assert_eq!(response.coverage, QueryDslCoverage::QueryDslAnnotation);
+    assert_eq!(
+      updated_source,
+      "package com.example;\n\nimport com.querydsl.core.annotations.QueryEntity;\n\n@QueryEntity\npublic class Country {\n  private Long id;\n}\n"
+    );
+
+    let (second_response, second_source) = generate_metadata(&updated_source);
+    assert!(!second_response.unwrap().annotation_added);
+    assert_eq!(second_source, updated_source);
+  }
+
+  #[test]
+  fn test_keeps_existing_query_supertype_annotation() {
+    let source = "package com.example;\n\nimport com.querydsl.core.annotations.QuerySupertype;\n\n@QuerySupertype\npublic abstract class Country {\n  private Long id;\n}\n";
+
+    let (response, unchanged_source) = generate_metadata(source);
+
+    let response = response.unwrap();
+    assert!(!response.annotation_added);
+    assert_eq!(response.coverage, QueryDslCoverage::QueryDslAnnotation);
+    assert_eq!(unchanged_source, source);
+  }
+}
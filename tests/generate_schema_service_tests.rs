@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod generate_schema_service_tests {
+  use std::fs;
+
+  use syntaxpresso_core::commands::java::services::generate_schema_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::sql_dialect::SqlDialect;
+  use syntaxpresso_core::common::utils::scan_deadline::ScanDeadline;
+  use tempfile::TempDir;
+
+  const AUTHOR_SOURCE: &str = r#"package com.example;
+
+@Entity
+public class Author {
+  @Id
+  private Long id;
+}
+"#;
+
+  fn generate_script(join_column: &str) -> String {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(package_dir.join("Author.java"), AUTHOR_SOURCE).unwrap();
+    fs::write(
+      package_dir.join("Book.java"),
+      format!(
+        "package com.example;\n\n@Entity\npublic class Book {{\n  @Id\n  private Long id;\n\n  @ManyToOne\n  {}\n  private Author author;\n}}\n",
+        join_column
+      ),
+    )
+    .unwrap();
+    run(temp_dir.path(), &SqlDialect::Postgresql, &ScanDeadline::unbounded()).unwrap().script
+  }
+
+  #[test]
+  fn test_uses_default_foreign_key_name() {
+    let script = generate_script("@JoinColumn(name = \"writer_id\")");
+
+    assert!(
+      script.contains(
+        "ALTER TABLE book ADD CONSTRAINT fk_book_writer_id FOREIGN KEY (writer_id) REFERENCES author (id);"
+      ),
+      "{}",
+      script
+    );
+  }
+
+  #[test]
+  fn test_uses_foreign_key_name_from_annotation() {
+    let script = generate_script(
+      "@JoinColumn(name = \"writer_id\", foreignKey = @ForeignKey(name = \"fk_book_writer\"))",
+    );
+
+    assert!(
+      script.contains(
+        "ALTER TABLE book ADD CONSTRAINT fk_book_writer FOREIGN KEY (writer_id) REFERENCES author (id);"
+      ),
+      "{}",
+      script
+    );
+    assert!(!script.contains("fk_book_writer_id"), "{}", script);
+  }
+
+  #[test]
+  fn test_skips_constraint_when_foreign_key_is_disabled() {
+    let script = generate_script(
+      "@JoinColumn(name = \"writer_id\", foreignKey = @ForeignKey(ConstraintMode.NO_CONSTRAINT))",
+    );
+
+    assert!(script.contains("  writer_id BIGINT"), "{}", script);
+    assert!(!script.contains("FOREIGN KEY"), "{}", script);
+  }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod generate_test_data_builder_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::services::generate_test_data_builder_service::run;
+  use tempfile::TempDir;
+
+  const ENTITY_SOURCE: &str = "package com.example;\n\nimport java.util.UUID;\n\n@Entity\npublic class User {\n  @Id\n  @GeneratedValue\n  private Long id;\n\n  @Column(nullable = false, length = 4)\n  private String username;\n\n  @NotNull\n  private UUID externalId;\n\n  private int age;\n\n  private String nickname;\n\n  @ManyToOne(optional = false)\n  private Company company;\n\n  public void setUsername(String username) {\n    this.username = username;\n  }\n\n  public void setExternalId(UUID externalId) {\n    this.externalId = externalId;\n  }\n\n  public void setAge(int age) {\n    this.age = age;\n  }\n\n  public void setCompany(Company company) {\n    this.company = company;\n  }\n}\n";
+
+  #[test]
+  fn test_builder_populates_required_fields_under_test_sources() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let b64_source = BASE64_STANDARD.encode(ENTITY_SOURCE);
+
+    let response = run(temp_dir.path(), &b64_source, None, None, false).unwrap();
+
+    assert_eq!(response.factory_method, "aUser");
+    assert!(response.warnings.is_empty(), "{:?}", response.warnings);
+    let fields: Vec<(&str, &str)> = response
+      .fields
+      .iter()
+      .map(|field| (field.field_name.as_str(), field.default_value.as_str()))
+      .collect();
+    assert_eq!(
+      fields,
+      vec![("username", "\"user\""), ("externalId", "UUID.randomUUID()"), ("age", "1")]
+    );
+    assert!(
+      response.builder.file_path.ends_with("src/test/java/com/example/UserTestDataBuilder.java")
+    );
+    let builder_source = fs::read_to_string(&response.builder.file_path).unwrap();
+    assert!(builder_source.contains("import java.util.UUID;\n"));
+    assert!(builder_source.contains("  private UUID externalId = UUID.randomUUID();\n"));
+    assert!(builder_source.contains(
+      "  public static UserTestDataBuilder aUser() {\n    return new UserTestDataBuilder();\n  }\n"
+    ));
+    assert!(builder_source.contains(
+      "  public UserTestDataBuilder withAge(int age) {\n    this.age = age;\n    return this;\n  }\n"
+    ));
+    assert!(builder_source.contains(
+      "  public User build() {\n    User user = new User();\n    user.setUsername(username);\n    user.setExternalId(externalId);\n    user.setAge(age);\n    return user;\n  }\n}\n"
+    ));
+  }
+
+  #[test]
+  fn test_builder_includes_required_relations_when_requested() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let b64_source = BASE64_STANDARD.encode(ENTITY_SOURCE);
+
+    let response =
+      run(temp_dir.path(), &b64_source, Some("com.example.fixtures"), Some("Users"), true).unwrap();
+
+    let company = response.fields.iter().find(|field| field.field_name == "company").unwrap();
+    assert_eq!(company.default_value, "new Company()");
+    let builder_source = fs::read_to_string(&response.builder.file_path).unwrap();
+    assert!(builder_source.starts_with("package com.example.fixtures;\n"));
+    assert!(builder_source.contains("import com.example.User;\n"));
+    assert!(builder_source.contains("import com.example.Company;\n"));
+    assert!(builder_source.contains("    user.setCompany(company);\n"));
+  }
+
+  #[test]
+  fn test_builder_warns_on_missing_setter_and_unknown_type() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let source = "package com.example;\n\n@Entity\npublic class Order {\n  @Id\n  private String code;\n\n  @NotNull\n  private Money total;\n}\n";
+    let b64_source = BASE64_STANDARD.encode(source);
+
+    let response = run(temp_dir.path(), &b64_source, None, None, false).unwrap();
+
+    assert_eq!(response.factory_method, "anOrder");
+    assert_eq!(response.fields.len(), 1);
+    assert_eq!(
+      response.warnings,
+      vec![
+        "The entity has no 'setCode' setter, add it for the builder to compile".to_string(),
+        "Required field 'total' of type 'Money' has no default value and is left unset".to_string(),
+      ]
+    );
+  }
+}
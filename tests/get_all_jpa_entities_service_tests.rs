@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod get_all_jpa_entities_service_tests {
+  use std::fs;
+
+  use syntaxpresso_core::commands::java::responses::file_response::FileResponse;
+  use syntaxpresso_core::commands::java::services::get_all_jpa_entities_service::run;
+  use syntaxpresso_core::common::utils::scan_deadline::ScanDeadline;
+  use tempfile::TempDir;
+
+  fn setup_project() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    let files = [
+      (
+        "Author",
+        "package com.example;\n\n@Entity\npublic class Author {\n  @Id\n  private Long id;\n}\n",
+      ),
+      (
+        "Draft",
+        "package com.example;\n\n// import jakarta.persistence.Entity;\n\n// @Entity\npublic class Draft {\n  private Long id;\n}\n",
+      ),
+      (
+        "Legacy",
+        "package com.example;\n\n/*\n * @Entity\n * public class Legacy {\n *   @Id\n *   private Long id;\n * }\n */\n",
+      ),
+    ];
+    for (name, source) in files {
+      fs::write(package_dir.join(format!("{}.java", name)), source).unwrap();
+    }
+    temp_dir
+  }
+
+  fn get_file_types(files: &[FileResponse]) -> Vec<&str> {
+    let mut file_types: Vec<&str> = files.iter().map(|file| file.file_type.as_str()).collect();
+    file_types.sort();
+    file_types
+  }
+
+  #[test]
+  fn test_commented_entities_are_ignored_by_default() {
+    let project = setup_project();
+
+    let files = run(project.path(), &ScanDeadline::unbounded(), false).unwrap();
+
+    assert_eq!(get_file_types(&files), vec!["Author"]);
+    assert!(files[0].warnings.is_empty());
+  }
+
+  #[test]
+  fn test_include_commented_reports_commented_entities_with_warning() {
+    let project = setup_project();
+
+    let files = run(project.path(), &ScanDeadline::unbounded(), true).unwrap();
+
+    assert_eq!(get_file_types(&files), vec!["Author", "Draft", "Legacy"]);
+    for file in files.iter().filter(|file| file.file_type != "Author") {
+      assert_eq!(file.warnings, vec!["The @Entity class is commented out".to_string()]);
+      assert_eq!(file.file_package_name, "com.example");
+    }
+  }
+}
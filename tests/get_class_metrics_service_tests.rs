@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod get_class_metrics_service_tests {
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::get_class_metrics_response::GetClassMetricsResponse;
+  use syntaxpresso_core::commands::java::services::get_class_metrics_service;
+  use tempfile::TempDir;
+
+  const ORDER_SOURCE: &str = r#"package com.example;
+
+import jakarta.persistence.*;
+
+/**
+ * An order.
+ */
+@Entity
+public class Order {
+  @Id
+  private Long id;
+
+  // The customer
+  @ManyToOne
+  private Customer customer;
+
+  @OneToMany(mappedBy = "order")
+  private List<OrderLine> lines;
+
+  private int quantity, discount;
+
+  protected Order() {}
+
+  public String describe(int amount) {
+    if (amount > 10 && discount > 0 || quantity == 0) {
+      return "bulk";
+    }
+    for (OrderLine line : lines) {
+      try {
+        line.validate();
+      } catch (IllegalStateException e) {
+        return "invalid";
+      }
+    }
+    switch (quantity) {
+      case 1:
+        return "single";
+      case 2:
+        return "pair";
+      default:
+        return amount > 0 ? "some" : "none";
+    }
+  }
+
+  public Long getId() {
+    return id;
+  }
+
+  static class Key {
+    private Long value;
+
+    boolean isEmpty() {
+      return value == null || value == 0;
+    }
+  }
+}
+"#;
+
+  fn run(source: &str, type_name: Option<&str>) -> Result<GetClassMetricsResponse, String> {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let b64_source = BASE64_STANDARD.encode(source);
+    get_class_metrics_service::run(None, Some(&b64_source), type_name, temp_dir.path())
+  }
+
+  #[test]
+  fn test_counts_own_members_and_relationships() {
+    let response = run(ORDER_SOURCE, None).unwrap();
+
+    assert_eq!(response.type_name, "Order");
+    assert_eq!(response.field_count, 5);
+    assert_eq!(response.method_count, 2);
+    assert_eq!(response.constructor_count, 1);
+    assert_eq!(response.relationship_count, 2);
+    // From @Entity to the closing brace, without the comment and blank lines
+    assert_eq!(response.lines_of_code, 40);
+  }
+
+  #[test]
+  fn test_approximates_complexity_from_branch_nodes() {
+    let response = run(ORDER_SOURCE, None).unwrap();
+
+    // describe: 1 + if + && + || + for + catch + 2 cases + ?: = 9, getId and the constructor: 1
+    assert_eq!(response.max_method_complexity, 9);
+    assert_eq!(response.most_complex_method.as_deref(), Some("describe"));
+    assert_eq!(response.cyclomatic_complexity, 11);
+  }
+
+  #[test]
+  fn test_reports_nested_type_and_rejects_unknown_type() {
+    let response = run(ORDER_SOURCE, Some("Key")).unwrap();
+
+    assert_eq!(response.type_kind, "class");
+    assert_eq!(response.field_count, 1);
+    assert_eq!(response.relationship_count, 0);
+    assert_eq!(response.cyclomatic_complexity, 2);
+    assert_eq!(response.lines_of_code, 6);
+    assert_eq!(
+      run(ORDER_SOURCE, Some("Missing")).err().unwrap(),
+      "Type 'Missing' not found in file"
+    );
+  }
+}
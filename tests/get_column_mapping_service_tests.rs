@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod get_column_mapping_service_tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use syntaxpresso_core::commands::java::services::generate_schema_service;
+  use syntaxpresso_core::commands::java::services::get_column_mapping_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::sql_dialect::SqlDialect;
+  use syntaxpresso_core::common::utils::scan_deadline::ScanDeadline;
+  use tempfile::TempDir;
+
+  const CUSTOMER_SOURCE: &str = r#"package com.example;
+
+import jakarta.persistence.AttributeOverride;
+import jakarta.persistence.Column;
+import jakarta.persistence.Embedded;
+import jakarta.persistence.Entity;
+import jakarta.persistence.Id;
+import jakarta.persistence.JoinColumn;
+import jakarta.persistence.ManyToOne;
+import jakarta.persistence.Table;
+
+@Entity
+@Table(name = "customers")
+public class Customer {
+  @Id
+  private Long id;
+
+  @Column(name = "full_name")
+  private String name;
+
+  private String emailAddress;
+
+  @Embedded
+  @AttributeOverride(name = "zipCode", column = @Column(name = "postal_code"))
+  private Address address;
+
+  @ManyToOne
+  @JoinColumn(name = "company_ref")
+  private Company company;
+}
+"#;
+
+  const ADDRESS_SOURCE: &str = r#"package com.example;
+
+import jakarta.persistence.Embeddable;
+
+@Embeddable
+public class Address {
+  private String street;
+
+  private String zipCode;
+}
+"#;
+
+  const COMPANY_SOURCE: &str = r#"package com.example;
+
+import jakarta.persistence.Entity;
+import jakarta.persistence.Id;
+
+@Entity
+public class Company {
+  @Id
+  private Long id;
+}
+"#;
+
+  fn setup() -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    for (file_name, source) in [
+      ("Customer.java", CUSTOMER_SOURCE),
+      ("Address.java", ADDRESS_SOURCE),
+      ("Company.java", COMPANY_SOURCE),
+    ] {
+      fs::write(package_dir.join(file_name), source).unwrap();
+    }
+    (temp_dir, package_dir.join("Customer.java"))
+  }
+
+  #[test]
+  fn test_maps_explicit_implicit_overridden_and_join_columns() {
+    let (temp_dir, file_path) = setup();
+
+    let response = run(Some(&file_path), None, temp_dir.path()).unwrap();
+
+    assert_eq!(response.entity_type, "Customer");
+    assert_eq!(response.table_name, "customers");
+    assert!(response.has_explicit_table_name);
+    let columns: Vec<(&str, &str, bool, bool)> = response
+      .columns
+      .iter()
+      .map(|column| {
+        (
+          column.field_name.as_str(),
+          column.column_name.as_str(),
+          column.has_explicit_name,
+          column.is_join_column,
+        )
+      })
+      .collect();
+    assert_eq!(
+      columns,
+      vec![
+        ("id", "id", false, false),
+        ("name", "full_name", true, false),
+        ("emailAddress", "email_address", false, false),
+        ("address.street", "street", false, false),
+        ("address.zipCode", "postal_code", true, false),
+        ("company", "company_ref", true, true),
+      ]
+    );
+    assert!(response.columns[0].is_id);
+    assert_eq!(response.columns_count, 6);
+  }
+
+  #[test]
+  fn test_matches_columns_of_generated_schema() {
+    let (temp_dir, file_path) = setup();
+
+    let response = run(Some(&file_path), None, temp_dir.path()).unwrap();
+    let schema = generate_schema_service::run(
+      temp_dir.path(),
+      &SqlDialect::Postgresql,
+      &ScanDeadline::unbounded(),
+    )
+    .unwrap();
+
+    let table_definition =
+      schema.script.split("CREATE TABLE customers (\n").nth(1).unwrap().split(");").next().unwrap();
+    let ddl_column_names: Vec<&str> = table_definition
+      .lines()
+      .map(|line| line.trim())
+      .filter(|line| !line.starts_with("PRIMARY KEY"))
+      .filter_map(|line| line.split_whitespace().next())
+      .collect();
+    let mapped_column_names: Vec<&str> =
+      response.columns.iter().map(|column| column.column_name.as_str()).collect();
+    assert_eq!(mapped_column_names, ddl_column_names);
+  }
+
+  #[test]
+  fn test_rejects_class_that_is_not_an_entity() {
+    let (temp_dir, file_path) = setup();
+
+    let result = run(Some(&file_path.with_file_name("Address.java")), None, temp_dir.path());
+
+    assert_eq!(result.err().unwrap(), "Class is not annotated with @Entity");
+  }
+}
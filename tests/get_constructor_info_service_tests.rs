@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod get_constructor_info_service_tests {
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::get_constructor_info_response::GetConstructorInfoResponse;
+  use syntaxpresso_core::commands::java::services::get_constructor_info_service;
+  use tempfile::TempDir;
+
+  const ORDER_SOURCE: &str = r#"package com.example;
+
+@Entity
+public class Order {
+  @Id
+  private Long id;
+
+  private String reference;
+
+  protected Order() {}
+
+  @Deprecated
+  public Order(Long id, final String... tags) {
+    this.id = id;
+  }
+
+  static class Key {
+    Key(long value) {}
+  }
+}
+"#;
+
+  fn run(source: &str, type_name: Option<&str>) -> Result<GetConstructorInfoResponse, String> {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let b64_source = BASE64_STANDARD.encode(source);
+    get_constructor_info_service::run(None, Some(&b64_source), type_name, temp_dir.path())
+  }
+
+  #[test]
+  fn test_lists_constructors_with_signature_and_parameters() {
+    let response = run(ORDER_SOURCE, None).unwrap();
+
+    assert_eq!(response.type_name, "Order");
+    assert_eq!(response.constructors_count, 2);
+    assert!(response.has_no_arg);
+    assert!(!response.has_implicit_default_constructor);
+    assert_eq!(response.constructors[0].signature, "protected Order()");
+    assert_eq!(response.constructors[0].visibility, "protected");
+    assert_eq!(response.constructors[0].line, 10);
+    let constructor = &response.constructors[1];
+    assert_eq!(constructor.signature, "public Order(Long id, String... tags)");
+    assert_eq!(constructor.modifiers, vec!["public"]);
+    assert_eq!(constructor.parameters.len(), 2);
+    assert_eq!(constructor.parameters[1].parameter_type, "String");
+    assert_eq!(constructor.parameters[1].parameter_name, "tags");
+    assert!(constructor.parameters[1].is_varargs);
+  }
+
+  #[test]
+  fn test_reports_missing_no_arg_constructor_of_nested_class() {
+    let response = run(ORDER_SOURCE, Some("Key")).unwrap();
+
+    assert_eq!(response.constructors_count, 1);
+    assert_eq!(response.constructors[0].signature, "Key(long value)");
+    assert_eq!(response.constructors[0].visibility, "package-private");
+    assert!(!response.has_no_arg);
+  }
+
+  #[test]
+  fn test_implicit_default_constructor_and_non_class_types() {
+    let source = "public class Tag {\n  private String name;\n}\n";
+    let response = run(source, None).unwrap();
+
+    assert!(response.constructors.is_empty());
+    assert!(response.has_no_arg);
+    assert!(response.has_implicit_default_constructor);
+    assert_eq!(
+      run("public enum Status { ACTIVE }\n", None).err().unwrap(),
+      "Type 'Status' is not a class, found enum"
+    );
+  }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod get_effective_column_type_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::get_effective_column_type_response::GetEffectiveColumnTypeResponse;
+  use syntaxpresso_core::commands::java::services::get_effective_column_type_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::sql_dialect::SqlDialect;
+  use tempfile::TempDir;
+
+  const BOOK_SOURCE: &str = r#"package com.example;
+
+@Entity
+public class Book {
+  @Id
+  private Long id;
+
+  @Column(name = "book_title", length = 120)
+  private String title;
+
+  @Lob
+  private String summary;
+
+  @Column(precision = 10, scale = 4)
+  private BigDecimal price;
+
+  @Enumerated(EnumType.STRING)
+  @Column(length = 20)
+  private Status status;
+
+  @Temporal(TemporalType.DATE)
+  private Date publishedOn;
+
+  @ManyToOne
+  @JoinColumn(name = "author_ref")
+  private Author author;
+
+  private Address address;
+
+  private Object metadata;
+}
+"#;
+
+  const AUTHOR_SOURCE: &str =
+    "package com.example;\n\n@Entity\npublic class Author {\n  @Id\n  private UUID id;\n}\n";
+
+  const ADDRESS_SOURCE: &str =
+    "package com.example;\n\n@Embeddable\npublic class Address {\n  private String street;\n}\n";
+
+  fn resolve(
+    field_name: &str,
+    dialect: &SqlDialect,
+  ) -> Result<GetEffectiveColumnTypeResponse, String> {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(package_dir.join("Author.java"), AUTHOR_SOURCE).unwrap();
+    fs::write(package_dir.join("Address.java"), ADDRESS_SOURCE).unwrap();
+    let b64_source = BASE64_STANDARD.encode(BOOK_SOURCE);
+    run(None, Some(&b64_source), field_name, dialect, temp_dir.path())
+  }
+
+  #[test]
+  fn test_resolves_column_type_from_mapping_inputs() {
+    let title = resolve("title", &SqlDialect::Oracle).unwrap();
+    assert_eq!(title.sql_type, "VARCHAR2(120 CHAR)");
+    assert_eq!(title.column_name, "book_title");
+    assert_eq!(title.length, Some(120));
+
+    assert_eq!(resolve("summary", &SqlDialect::Mysql).unwrap().sql_type, "LONGTEXT");
+    let price = resolve("price", &SqlDialect::Postgresql).unwrap();
+    assert_eq!(price.sql_type, "NUMERIC(10,4)");
+    assert_eq!((price.precision, price.scale), (Some(10), Some(4)));
+    let status = resolve("status", &SqlDialect::SqlServer).unwrap();
+    assert_eq!(status.sql_type, "NVARCHAR(20)");
+    assert_eq!(status.enum_type.as_deref(), Some("STRING"));
+    assert_eq!(resolve("publishedOn", &SqlDialect::H2).unwrap().sql_type, "DATE");
+    assert_eq!(resolve("address.street", &SqlDialect::H2).unwrap().sql_type, "VARCHAR(255)");
+  }
+
+  #[test]
+  fn test_join_column_takes_the_referenced_column_type() {
+    let author = resolve("author", &SqlDialect::Mysql).unwrap();
+
+    assert!(author.is_join_column);
+    assert_eq!(author.column_name, "author_ref");
+    assert_eq!(author.sql_type, "BINARY(16)");
+    assert_eq!(author.referenced_entity.as_deref(), Some("Author"));
+    assert_eq!(author.referenced_column.as_deref(), Some("id"));
+  }
+
+  #[test]
+  fn test_reports_unmappable_and_unmapped_fields() {
+    assert_eq!(
+      resolve("metadata", &SqlDialect::Postgresql).err().unwrap(),
+      "Field 'metadata' of type 'Object' can't be mapped to a postgresql column type: 'Object' is not a basic JPA type, it is not an enum of the project, no @Column(columnDefinition) is declared"
+    );
+    assert_eq!(
+      resolve("address", &SqlDialect::Postgresql).err().unwrap(),
+      "Field 'address' is embedded, query one of its columns, e.g. 'address.street'"
+    );
+    assert_eq!(
+      resolve("missing", &SqlDialect::Postgresql).err().unwrap(),
+      "Field 'missing' is not mapped to a column of 'Book'"
+    );
+  }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod get_entity_by_table_name_service_tests {
+  use std::fs;
+  use std::path::Path;
+
+  use syntaxpresso_core::commands::java::services::get_entity_by_table_name_service::run;
+  use tempfile::TempDir;
+
+  fn write_java_file(root: &Path, class_name: &str, source: &str) {
+    let package_dir = root.join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(package_dir.join(format!("{}.java", class_name)), source).unwrap();
+  }
+
+  fn setup_project() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let root = temp_dir.path();
+    write_java_file(
+      root,
+      "OrderItem",
+      "package com.example;\n\n@Entity\npublic class OrderItem {\n  @Id\n  private Long id;\n}\n",
+    );
+    write_java_file(
+      root,
+      "Customer",
+      "package com.example;\n\n@Entity\n@Table(name = \"clients\", schema = \"sales\")\npublic class Customer {\n  @Id\n  private Long id;\n}\n",
+    );
+    write_java_file(
+      root,
+      "Payment",
+      "package com.example;\n\n@Entity\n@Inheritance\npublic class Payment {\n  @Id\n  private Long id;\n}\n",
+    );
+    write_java_file(
+      root,
+      "CardPayment",
+      "package com.example;\n\n@Entity\npublic class CardPayment extends Payment {\n  private String cardNumber;\n}\n",
+    );
+    temp_dir
+  }
+
+  #[test]
+  fn test_finds_entities_by_default_and_explicit_table_names() {
+    let temp_dir = setup_project();
+
+    let response = run(temp_dir.path(), "ORDER_ITEM").unwrap();
+    assert!(response.found);
+    assert_eq!(response.entities.len(), 1);
+    assert_eq!(response.entities[0].entity_type, "OrderItem");
+    assert!(!response.entities[0].has_explicit_table_name);
+    assert!(response.entities[0].entity_path.as_ref().unwrap().ends_with("OrderItem.java"));
+
+    let response = run(temp_dir.path(), "sales.clients").unwrap();
+    assert_eq!(response.entities[0].entity_type, "Customer");
+    assert!(response.entities[0].has_explicit_table_name);
+    assert!(run(temp_dir.path(), "customer").unwrap().entities.is_empty());
+  }
+
+  #[test]
+  fn test_returns_all_entities_sharing_a_single_table() {
+    let temp_dir = setup_project();
+
+    let response = run(temp_dir.path(), "payment").unwrap();
+
+    let entity_types: Vec<&str> =
+      response.entities.iter().map(|entity| entity.entity_type.as_str()).collect();
+    assert_eq!(entity_types, vec!["Payment", "CardPayment"]);
+    assert!(response.entities.iter().all(|entity| entity.table_owner_type == "Payment"));
+    assert!(!run(temp_dir.path(), "card_payment").unwrap().found);
+  }
+
+  #[test]
+  fn test_reports_unknown_table_as_not_found() {
+    let temp_dir = setup_project();
+
+    let response = run(temp_dir.path(), "invoices").unwrap();
+
+    assert!(!response.found);
+    assert!(response.entities.is_empty());
+    assert!(run(temp_dir.path(), "  ").is_err());
+  }
+}
@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod get_entity_graph_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::get_entity_graph_response::GetEntityGraphResponse;
+  use syntaxpresso_core::commands::java::services::get_entity_graph_service::run;
+  use tempfile::TempDir;
+
+  const ORDER_SOURCE: &str = r#"package com.example;
+
+import jakarta.persistence.Entity;
+
+@Entity
+public class Order {
+  @Id
+  private Long id;
+
+  private String reference;
+
+  @ManyToOne
+  private Customer customer;
+
+  @OneToMany(mappedBy = "order")
+  private List<OrderItem> items;
+
+  @ManyToMany(fetch = FetchType.LAZY)
+  private Set<Tag> tags;
+}
+"#;
+
+  fn run_on(
+    source: &str,
+    graph_name: Option<&str>,
+    attribute_nodes: &[&str],
+  ) -> (Result<GetEntityGraphResponse, String>, String) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_file_path = temp_dir.path().join("src/main/java/com/example/Order.java");
+    fs::create_dir_all(entity_file_path.parent().unwrap()).unwrap();
+    fs::write(&entity_file_path, source).unwrap();
+    let attribute_nodes: Vec<String> =
+      attribute_nodes.iter().map(|node| node.to_string()).collect();
+    let result = run(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(source),
+      &entity_file_path,
+      graph_name,
+      &attribute_nodes,
+    );
+    (result, fs::read_to_string(&entity_file_path).unwrap())
+  }
+
+  #[test]
+  fn test_suggests_graphs_for_lazy_associations_without_writing() {
+    let (result, written) = run_on(ORDER_SOURCE, None, &[]);
+
+    let response = result.unwrap();
+    assert!(response.generated_graph.is_none());
+    let suggestions: Vec<(&str, Vec<String>)> = response
+      .suggestions
+      .iter()
+      .map(|graph| (graph.name.as_str(), graph.attribute_nodes.clone()))
+      .collect();
+    assert_eq!(
+      suggestions,
+      vec![
+        ("Order.withItems", vec!["items".to_string()]),
+        ("Order.withTags", vec!["tags".to_string()]),
+        ("Order.withAssociations", vec!["items".to_string(), "tags".to_string()]),
+      ]
+    );
+    assert_eq!(written, ORDER_SOURCE);
+  }
+
+  #[test]
+  fn test_generates_named_entity_graph_next_to_existing_one() {
+    let source = ORDER_SOURCE.replace(
+      "@Entity\n",
+      "@Entity\n@NamedEntityGraph(name = \"Order.withTags\", attributeNodes = @NamedAttributeNode(\"tags\"))\n",
+    );
+
+    let (result, written) = run_on(&source, None, &["items", "customer", "items"]);
+
+    let response = result.unwrap();
+    let graph = response.generated_graph.unwrap();
+    assert_eq!(graph.name, "Order.withItemsAndCustomer");
+    assert_eq!(graph.attribute_nodes, vec!["items", "customer"]);
+    assert!(response.warnings.is_empty(), "{:?}", response.warnings);
+    assert!(
+      written.contains(
+        "@Entity\n@NamedEntityGraphs({\n  @NamedEntityGraph(name = \"Order.withTags\", attributeNodes = @NamedAttributeNode(\"tags\")),\n  @NamedEntityGraph(name = \"Order.withItemsAndCustomer\", attributeNodes = {@NamedAttributeNode(\"items\"), @NamedAttributeNode(\"customer\")})\n})\npublic class Order"
+      ),
+      "{}",
+      written
+    );
+    assert!(written.contains("import jakarta.persistence.NamedEntityGraphs;"), "{}", written);
+    assert!(written.contains("import jakarta.persistence.NamedAttributeNode;"), "{}", written);
+    let suggested: Vec<&str> =
+      response.suggestions.iter().map(|graph| graph.name.as_str()).collect();
+    assert_eq!(suggested, vec!["Order.withItems", "Order.withAssociations"]);
+  }
+
+  #[test]
+  fn test_rejects_unknown_attribute_and_duplicate_graph_name() {
+    let (result, written) = run_on(ORDER_SOURCE, Some("Order.detail"), &["items", "lines"]);
+    assert_eq!(result.err().unwrap(), "Field 'lines' not found in entity 'Order'");
+    assert_eq!(written, ORDER_SOURCE);
+
+    let (result, written) = run_on(ORDER_SOURCE, Some("Order.detail"), &["reference"]);
+    assert_eq!(
+      result.unwrap().warnings,
+      vec![
+        "'reference' is not an association, the graph only changes how it loads when it's lazily fetched"
+      ]
+    );
+    let (result, _) = run_on(&written, Some("Order.detail"), &["items"]);
+    assert_eq!(
+      result.err().unwrap(),
+      "Entity already declares an entity graph named 'Order.detail'"
+    );
+  }
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod get_file_diagnostics_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::services::get_file_diagnostics_service::run;
+  use tempfile::TempDir;
+
+  #[test]
+  fn test_clean_file_has_no_diagnostics() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("User.java");
+    fs::write(&file_path, "package com.example;\n\npublic class User {\n  private Long id;\n}\n")
+      .unwrap();
+
+    let response = run(Some(&file_path), None, temp_dir.path()).unwrap();
+
+    assert!(response.diagnostics.is_empty());
+  }
+
+  #[test]
+  fn test_broken_source_reports_missing_token_with_range() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let source = "public class User {\n  private Long id\n}\n";
+
+    let response = run(None, Some(&BASE64_STANDARD.encode(source)), temp_dir.path()).unwrap();
+
+    assert_eq!(response.diagnostics.len(), 1);
+    let diagnostic = &response.diagnostics[0];
+    assert_eq!(diagnostic.kind, "missing_token");
+    assert_eq!(diagnostic.message, "Missing ';'");
+    assert_eq!((diagnostic.start_line, diagnostic.start_column), (2, 18));
+  }
+
+  #[test]
+  fn test_unparsable_text_is_reported_once() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let source = "public class User {\n  private Long id;\n  ))) ((( @@\n}\n";
+
+    let response = run(None, Some(&BASE64_STANDARD.encode(source)), temp_dir.path()).unwrap();
+
+    let kinds: Vec<&str> = response.diagnostics.iter().map(|d| d.kind.as_str()).collect();
+    assert_eq!(kinds, vec!["syntax_error"]);
+    assert_eq!(response.diagnostics[0].start_line, 3);
+    assert!(response.diagnostics[0].message.starts_with("Unexpected '"));
+  }
+}
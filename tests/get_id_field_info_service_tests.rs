@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod get_id_field_info_service_tests {
+  use std::fs;
+  use std::path::Path;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::get_id_field_info_response::GetIdFieldInfoResponse;
+  use syntaxpresso_core::commands::java::services::get_id_field_info_service::run;
+  use tempfile::TempDir;
+
+  fn write_file(root: &Path, relative_path: &str, source: &str) {
+    let path = root.join("src/main/java").join(relative_path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, source).unwrap();
+  }
+
+  fn run_on_source(cwd: &Path, source: &str) -> Result<GetIdFieldInfoResponse, String> {
+    run(None, Some(&BASE64_STANDARD.encode(source)), cwd)
+  }
+
+  #[test]
+  fn test_reads_generation_strategy_and_class_level_sequence() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let source = r#"package com.example;
+
+@Entity
+@SequenceGenerator(name = "book_gen", sequenceName = "book_seq", allocationSize = 1)
+public class Book {
+  @Id
+  @GeneratedValue(strategy = GenerationType.SEQUENCE, generator = "book_gen")
+  private Long id;
+
+  private String title;
+}
+"#;
+
+    let response = run_on_source(temp_dir.path(), source).unwrap();
+
+    assert_eq!(response.entity_type, "Book");
+    assert!(!response.is_inherited);
+    assert!(!response.is_composite);
+    assert_eq!(response.id_fields.len(), 1);
+    let id_field = &response.id_fields[0];
+    assert_eq!(id_field.field_name, "id");
+    assert_eq!(id_field.field_type, "Long");
+    assert_eq!(id_field.generation_strategy.as_deref(), Some("SEQUENCE"));
+    assert_eq!(id_field.generator_name.as_deref(), Some("book_gen"));
+    assert_eq!(id_field.sequence_name.as_deref(), Some("book_seq"));
+  }
+
+  #[test]
+  fn test_resolves_id_inherited_from_mapped_superclass() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    write_file(
+      temp_dir.path(),
+      "com/example/common/BaseEntity.java",
+      "package com.example.common;\n\n@MappedSuperclass\npublic abstract class BaseEntity {\n  @Id\n  @GeneratedValue\n  private UUID id;\n}\n",
+    );
+    write_file(
+      temp_dir.path(),
+      "com/example/common/AuditedEntity.java",
+      "package com.example.common;\n\n@MappedSuperclass\npublic abstract class AuditedEntity extends BaseEntity {\n  private Instant createdAt;\n}\n",
+    );
+    let source = "package com.example;\n\n@Entity\npublic class Author extends AuditedEntity {\n  private String name;\n}\n";
+
+    let response = run_on_source(temp_dir.path(), source).unwrap();
+
+    assert!(response.is_inherited);
+    assert_eq!(response.declaring_class, "BaseEntity");
+    assert_eq!(response.id_fields[0].field_type, "UUID");
+    assert_eq!(response.id_fields[0].generation_strategy.as_deref(), Some("AUTO"));
+    assert_eq!(response.id_fields[0].sequence_name, None);
+  }
+
+  #[test]
+  fn test_detects_composite_ids_and_missing_id() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let id_class = "package com.example;\n\n@Entity\n@IdClass(OrderLineKey.class)\npublic class OrderLine {\n  @Id\n  private Long orderId;\n\n  @Id\n  private Integer lineNumber;\n}\n";
+    let embedded_id = "package com.example;\n\n@Entity\npublic class Enrollment {\n  @EmbeddedId\n  private EnrollmentKey key;\n}\n";
+    let no_id = "package com.example;\n\n@Entity\npublic class Draft extends Unknown {\n  private String text;\n}\n";
+
+    let id_class_response = run_on_source(temp_dir.path(), id_class).unwrap();
+    let embedded_id_response = run_on_source(temp_dir.path(), embedded_id).unwrap();
+
+    assert!(id_class_response.is_composite);
+    assert!(!id_class_response.is_embedded_id);
+    assert_eq!(id_class_response.id_class_type.as_deref(), Some("OrderLineKey"));
+    assert_eq!(id_class_response.id_fields.len(), 2);
+    assert!(embedded_id_response.is_composite);
+    assert!(embedded_id_response.is_embedded_id);
+    assert_eq!(embedded_id_response.id_fields[0].field_type, "EnrollmentKey");
+    assert_eq!(
+      run_on_source(temp_dir.path(), no_id).err().unwrap(),
+      "NO_ID_FOUND: No @Id or @EmbeddedId field found in 'Draft' or its mapped superclasses"
+    );
+  }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod get_java_files_service_tests {
+  use std::fs::{self, File};
+  use std::path::Path;
+  use std::time::{Duration, UNIX_EPOCH};
+
+  use syntaxpresso_core::commands::java::services::get_java_files_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::java_file_type::JavaFileType;
+  use syntaxpresso_core::common::supported_language::SupportedLanguage;
+  use syntaxpresso_core::common::ts_file::TSFile;
+  use syntaxpresso_core::common::utils::scan_deadline::{ScanDeadline, SkipReason};
+  use tempfile::TempDir;
+
+  fn write_class(root: &Path, class_name: &str, modified_time_ms: u64) {
+    let package_dir = root.join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    let path = package_dir.join(format!("{}.java", class_name));
+    fs::write(&path, format!("package com.example;\n\npublic class {} {{}}\n", class_name))
+      .unwrap();
+    let file = File::options().write(true).open(&path).unwrap();
+    file.set_modified(UNIX_EPOCH + Duration::from_millis(modified_time_ms)).unwrap();
+  }
+
+  fn setup_project() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    write_class(temp_dir.path(), "Order", 1_700_000_000_000);
+    write_class(temp_dir.path(), "Customer", 1_700_000_500_000);
+    write_class(temp_dir.path(), "Invoice", 1_700_001_000_000);
+    temp_dir
+  }
+
+  #[test]
+  fn test_lists_only_files_modified_since_timestamp() {
+    let temp_dir = setup_project();
+    let deadline = ScanDeadline::unbounded();
+
+    let (files, modified_times) =
+      run(temp_dir.path(), &JavaFileType::Class, Some(1_700_000_500_000), &deadline).unwrap();
+
+    let file_types: Vec<&str> = files.iter().map(|file| file.file_type.as_str()).collect();
+    assert_eq!(file_types, vec!["Invoice"]);
+    assert_eq!(modified_times.get(&files[0].file_path), Some(&1_700_001_000_000));
+    assert_eq!(modified_times.len(), 1);
+    // Unchanged files are skipped before parsing
+    assert_eq!(deadline.processed_files_count(), 1);
+  }
+
+  #[test]
+  fn test_lists_all_files_with_their_modification_times() {
+    let temp_dir = setup_project();
+    let deadline = ScanDeadline::unbounded();
+
+    let (files, modified_times) =
+      run(temp_dir.path(), &JavaFileType::Class, None, &deadline).unwrap();
+
+    assert_eq!(files.len(), 3);
+    assert_eq!(deadline.processed_files_count(), 3);
+    let times: Vec<u64> = modified_times.values().copied().collect();
+    assert_eq!(times, vec![1_700_000_500_000, 1_700_001_000_000, 1_700_000_000_000]);
+    let (files, _) = run(temp_dir.path(), &JavaFileType::Enum, None, &deadline).unwrap();
+    assert!(files.is_empty());
+  }
+
+  #[test]
+  fn test_skips_files_with_invalid_utf8() {
+    let temp_dir = setup_project();
+    let invalid_path = temp_dir.path().join("src/main/java/com/example/Legacy.java");
+    fs::write(&invalid_path, b"package com.example;\n\n// Caf\xe9\npublic class Legacy {}\n")
+      .unwrap();
+    let deadline = ScanDeadline::unbounded();
+
+    let (files, _) = run(temp_dir.path(), &JavaFileType::Class, None, &deadline).unwrap();
+
+    let mut file_types: Vec<&str> = files.iter().map(|file| file.file_type.as_str()).collect();
+    file_types.sort();
+    assert_eq!(file_types, vec!["Customer", "Invoice", "Order"]);
+    let skipped_files = deadline.skipped_files();
+    assert_eq!(skipped_files.len(), 1);
+    assert_eq!(skipped_files[0].reason, SkipReason::InvalidEncoding);
+    assert!(skipped_files[0].file_path.ends_with("Legacy.java"));
+    // Editing the file fails instead of rewriting its bytes
+    let error =
+      TSFile::from_file(&invalid_path, temp_dir.path(), SupportedLanguage::Java).err().unwrap();
+    assert!(error.to_string().ends_with("Legacy.java' is not valid UTF-8 (INVALID_ENCODING)"));
+  }
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod get_jpa_entities_info_service_tests {
+  use std::fs;
+  use std::path::{Path, PathBuf};
+
+  use syntaxpresso_core::commands::java::services::get_jpa_entities_info_service::run;
+  use syntaxpresso_core::common::utils::scan_deadline::ScanDeadline;
+  use tempfile::TempDir;
+
+  fn write_file(root: &Path, relative_path: &str, source: &str) -> PathBuf {
+    let path = root.join("src/main/java").join(relative_path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, source).unwrap();
+    path
+  }
+
+  fn setup_project(temp_dir: &TempDir) -> Vec<PathBuf> {
+    vec![
+      write_file(
+        temp_dir.path(),
+        "com/example/Author.java",
+        "package com.example;\n\n@Entity\n@Table(name = \"authors\")\npublic class Author {\n  @Id\n  private Long id;\n}\n",
+      ),
+      write_file(
+        temp_dir.path(),
+        "com/example/Book.java",
+        "package com.example;\n\n@Entity\npublic class Book {\n  @Id\n  private UUID id;\n}\n",
+      ),
+      write_file(
+        temp_dir.path(),
+        "com/example/BookService.java",
+        "package com.example;\n\npublic class BookService {\n}\n",
+      ),
+      // Entity without a package declaration, which the single-file command rejects
+      write_file(temp_dir.path(), "Draft.java", "@Entity\npublic class Draft {\n}\n"),
+    ]
+  }
+
+  #[test]
+  fn test_reports_each_requested_file_in_order() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let paths = setup_project(&temp_dir);
+
+    let response = run(temp_dir.path(), Some(&paths), &ScanDeadline::unbounded()).unwrap();
+
+    assert_eq!(response.entities.len(), 4);
+    assert_eq!(response.succeeded_count, 3);
+    assert_eq!(response.failed_count, 1);
+    let author = response.entities[0].data.as_ref().unwrap();
+    assert_eq!(author.entity_type, "Author");
+    assert_eq!(author.entity_table_name.as_deref(), Some("authors"));
+    assert_eq!(author.id_field_type.as_deref(), Some("Long"));
+    let book = response.entities[1].data.as_ref().unwrap();
+    assert_eq!(book.id_field_package_name.as_deref(), Some("java.util"));
+    // Files that are not entities are still described, like with the single-file command
+    assert!(!response.entities[2].data.as_ref().unwrap().is_jpa_entity);
+    assert!(!response.entities[3].succeed);
+    assert_eq!(
+      response.entities[3].error_reason.as_deref(),
+      Some("Unable to get package declaration node")
+    );
+  }
+
+  #[test]
+  fn test_missing_and_outside_files_do_not_fail_the_batch() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let outside_dir = TempDir::new().expect("Failed to create temp directory");
+    let paths = setup_project(&temp_dir);
+    let outside_path = write_file(
+      outside_dir.path(),
+      "com/example/Other.java",
+      "package com.example;\n\n@Entity\npublic class Other {\n}\n",
+    );
+    let requested = vec![
+      temp_dir.path().join("src/main/java/com/example/Missing.java"),
+      outside_path.clone(),
+      paths[0].clone(),
+    ];
+
+    let response = run(temp_dir.path(), Some(&requested), &ScanDeadline::unbounded()).unwrap();
+
+    assert_eq!(response.succeeded_count, 1);
+    assert_eq!(response.failed_count, 2);
+    assert!(!response.entities[0].succeed);
+    assert!(response.entities[0].data.is_none());
+    assert_eq!(response.entities[1].file_path, outside_path.display().to_string());
+    assert!(
+      response.entities[1]
+        .error_reason
+        .as_deref()
+        .unwrap()
+        .starts_with("Entity file path must be within working directory")
+    );
+    assert_eq!(response.entities[2].data.as_ref().unwrap().entity_type, "Author");
+  }
+
+  #[test]
+  fn test_all_entities_skips_non_entity_files() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    setup_project(&temp_dir);
+
+    let response = run(temp_dir.path(), None, &ScanDeadline::unbounded()).unwrap();
+
+    let mut entity_types: Vec<Option<String>> = response
+      .entities
+      .iter()
+      .map(|entity| entity.data.as_ref().map(|data| data.entity_type.clone()))
+      .collect();
+    entity_types.sort();
+    assert_eq!(entity_types, vec![None, Some("Author".to_string()), Some("Book".to_string())]);
+    assert_eq!(response.failed_count, 1);
+    assert_eq!(response.processed_files_count, 4);
+    assert!(!response.truncated);
+  }
+}
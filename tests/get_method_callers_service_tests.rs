@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod get_method_callers_service_tests {
+  use std::fs;
+  use std::path::{Path, PathBuf};
+
+  use syntaxpresso_core::commands::java::responses::get_method_callers_response::GetMethodCallersResponse;
+  use syntaxpresso_core::commands::java::services::get_method_callers_service::run;
+  use syntaxpresso_core::common::utils::scan_deadline::ScanDeadline;
+  use tempfile::TempDir;
+
+  const PRICING_SOURCE: &str = r#"package com.example;
+
+public class PricingService {
+  public long price(Order order) {
+    return order.total();
+  }
+
+  public long price(Order order, long discount) {
+    return price(order) - discount;
+  }
+}
+"#;
+
+  fn write_file(root: &Path, relative_path: &str, source: &str) -> PathBuf {
+    let path = root.join(relative_path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, source).unwrap();
+    path
+  }
+
+  fn get_callers(cwd: &Path, file_path: &Path, line: usize) -> GetMethodCallersResponse {
+    run(cwd, file_path, line, &ScanDeadline::unbounded()).unwrap()
+  }
+
+  fn positions(response: &GetMethodCallersResponse) -> Vec<(String, usize, bool)> {
+    response
+      .call_sites
+      .iter()
+      .map(|call_site| {
+        let file_name = Path::new(&call_site.file_path).file_name().unwrap();
+        (file_name.to_string_lossy().to_string(), call_site.line, call_site.receiver_resolved)
+      })
+      .collect()
+  }
+
+  #[test]
+  fn test_finds_calls_by_receiver_type_and_arguments_count() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let pricing_path =
+      write_file(temp_dir.path(), "src/main/java/com/example/PricingService.java", PRICING_SOURCE);
+    write_file(
+      temp_dir.path(),
+      "src/main/java/com/example/Checkout.java",
+      r#"package com.example;
+
+public class Checkout {
+  private final PricingService pricingService;
+  private final TaxService taxService;
+
+  public long total(Order order) {
+    long net = pricingService.price(order);
+    long tax = taxService.price(order);
+    return net + this.pricingService.price(order, 10L);
+  }
+}
+"#,
+    );
+
+    let response = get_callers(temp_dir.path(), &pricing_path, 4);
+
+    assert_eq!(response.method_name, "price");
+    assert_eq!(response.declaring_type, "PricingService");
+    // The two-argument overload and the unrelated TaxService call are left out
+    assert_eq!(
+      positions(&response),
+      vec![("Checkout.java".to_string(), 8, true), ("PricingService.java".to_string(), 9, true)]
+    );
+    let call_site = &response.call_sites[0];
+    assert_eq!(call_site.column, 31);
+    assert_eq!(call_site.enclosing_method.as_deref(), Some("total"));
+    assert_eq!(call_site.enclosing_type.as_deref(), Some("Checkout"));
+    assert_eq!(call_site.receiver.as_deref(), Some("pricingService"));
+  }
+
+  #[test]
+  fn test_includes_unqualified_subtype_and_test_calls() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let pricing_path =
+      write_file(temp_dir.path(), "src/main/java/com/example/PricingService.java", PRICING_SOURCE);
+    write_file(
+      temp_dir.path(),
+      "src/main/java/com/example/SalePricingService.java",
+      "package com.example;\n\npublic class SalePricingService extends PricingService {\n  long sale(Order order) {\n    return price(order) / 2;\n  }\n}\n",
+    );
+    write_file(
+      temp_dir.path(),
+      "src/test/java/com/example/PricingServiceTest.java",
+      "package com.example;\n\nclass PricingServiceTest {\n  void prices(Order order) {\n    SalePricingService service = new SalePricingService();\n    service.price(order);\n  }\n}\n",
+    );
+
+    let response = get_callers(temp_dir.path(), &pricing_path, 4);
+
+    assert_eq!(
+      positions(&response),
+      vec![
+        ("PricingService.java".to_string(), 9, true),
+        ("SalePricingService.java".to_string(), 5, true),
+        ("PricingServiceTest.java".to_string(), 6, true),
+      ]
+    );
+    assert_eq!(response.call_sites_count, 3);
+  }
+
+  #[test]
+  fn test_reports_unresolved_receivers_and_rejects_non_method_lines() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let pricing_path =
+      write_file(temp_dir.path(), "src/main/java/com/example/PricingService.java", PRICING_SOURCE);
+    write_file(
+      temp_dir.path(),
+      "src/main/java/com/example/Report.java",
+      "package com.example;\n\npublic class Report {\n  long run(Order order) {\n    var service = lookup();\n    return service.price(order) + lookup().price(order);\n  }\n}\n",
+    );
+
+    let response = get_callers(temp_dir.path(), &pricing_path, 5);
+
+    assert_eq!(
+      positions(&response),
+      vec![
+        ("PricingService.java".to_string(), 9, true),
+        ("Report.java".to_string(), 6, false),
+        ("Report.java".to_string(), 6, false),
+      ]
+    );
+    assert_eq!(
+      run(temp_dir.path(), &pricing_path, 3, &ScanDeadline::unbounded()).err().unwrap(),
+      "No method declaration found at line 3"
+    );
+  }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod get_relationship_inverse_service_tests {
+  use std::fs;
+  use std::path::{Path, PathBuf};
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::services::get_relationship_inverse_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::inverse_resolution::InverseResolution;
+  use tempfile::TempDir;
+
+  const BOOK_SOURCE: &str = "package com.example;\n\n@Entity\npublic class Book {\n  @Id\n  private Long id;\n\n  @ManyToOne\n  private Author author;\n\n  @ManyToOne\n  private Publisher publisher;\n}\n";
+
+  fn write_java_file(root: &Path, class_name: &str, source: &str) -> PathBuf {
+    let package_dir = root.join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    let path = package_dir.join(format!("{}.java", class_name));
+    fs::write(&path, source).unwrap();
+    path
+  }
+
+  #[test]
+  fn test_resolves_inverse_field_through_mapped_by() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let book_path = write_java_file(temp_dir.path(), "Book", BOOK_SOURCE);
+    let author_path = write_java_file(
+      temp_dir.path(),
+      "Author",
+      "package com.example;\n\n@Entity\npublic class Author {\n  @Id\n  private Long id;\n\n  @OneToMany\n  private List<Book> drafts;\n\n  @OneToMany(mappedBy = \"author\")\n  private List<Book> books;\n}\n",
+    );
+
+    let response =
+      run(temp_dir.path(), &BASE64_STANDARD.encode(BOOK_SOURCE), &book_path, "author").unwrap();
+
+    assert!(response.bidirectional);
+    assert_eq!(response.target_type, "Author");
+    let inverse = response.inverse.unwrap();
+    assert_eq!(inverse.field_name, "books");
+    assert_eq!(inverse.field_type, "List<Book>");
+    assert_eq!(inverse.resolved_by, InverseResolution::MappedBy);
+    assert_eq!(inverse.file_path, author_path.display().to_string());
+    assert_eq!((inverse.line, inverse.column), (12, 22));
+    assert!(response.warnings.is_empty());
+  }
+
+  #[test]
+  fn test_falls_back_to_matching_typed_association() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let book_path = write_java_file(temp_dir.path(), "Book", BOOK_SOURCE);
+    write_java_file(
+      temp_dir.path(),
+      "Publisher",
+      "package com.example;\n\n@Entity\npublic class Publisher {\n  @Id\n  private Long id;\n\n  @OneToMany\n  private Set<Book> catalog;\n}\n",
+    );
+
+    let response =
+      run(temp_dir.path(), &BASE64_STANDARD.encode(BOOK_SOURCE), &book_path, "publisher").unwrap();
+
+    let inverse = response.inverse.unwrap();
+    assert_eq!(inverse.field_name, "catalog");
+    assert_eq!(inverse.resolved_by, InverseResolution::FieldType);
+    assert_eq!(
+      response.warnings,
+      vec![
+        "'Publisher.catalog' has no mappedBy = \"publisher\", JPA maps it as a separate relationship"
+      ]
+    );
+  }
+
+  #[test]
+  fn test_reports_unidirectional_relationship() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let book_path = write_java_file(temp_dir.path(), "Book", BOOK_SOURCE);
+    let author_source = "package com.example;\n\n@Entity\npublic class Author {\n  @Id\n  private Long id;\n\n  @OneToMany(mappedBy = \"author\")\n  private List<Book> books;\n}\n";
+    let author_path = write_java_file(temp_dir.path(), "Author", author_source);
+    write_java_file(
+      temp_dir.path(),
+      "Publisher",
+      "package com.example;\n\n@Entity\npublic class Publisher {\n  @Id\n  private Long id;\n}\n",
+    );
+
+    let response =
+      run(temp_dir.path(), &BASE64_STANDARD.encode(BOOK_SOURCE), &book_path, "publisher").unwrap();
+
+    assert!(!response.bidirectional);
+    assert!(response.inverse.is_none());
+    assert!(response.target_file_path.ends_with("Publisher.java"));
+    let error = run(temp_dir.path(), &BASE64_STANDARD.encode(author_source), &author_path, "books")
+      .err()
+      .unwrap();
+    assert_eq!(
+      error,
+      "Field 'books' is the inverse side of the relationship, mapped by 'Book.author'"
+    );
+  }
+}
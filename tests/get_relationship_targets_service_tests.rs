@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod get_relationship_targets_service_tests {
+  use std::fs;
+  use std::path::Path;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::services::get_relationship_targets_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::entity_side::EntitySide;
+  use syntaxpresso_core::commands::java::treesitter::types::relationship_kind::RelationshipKind;
+  use tempfile::TempDir;
+
+  const BOOK_SOURCE: &str = r#"package com.example.library;
+
+import com.example.domain.*;
+import com.example.tags.Tag;
+import jakarta.persistence.*;
+import java.util.List;
+import java.util.Set;
+
+@Entity
+public class Book {
+  @Id
+  private Long id;
+
+  @ManyToOne(fetch = FetchType.LAZY)
+  private Author author;
+
+  @OneToMany(mappedBy = "book")
+  private List<Review> reviews;
+
+  @ManyToMany(targetEntity = Tag.class)
+  private Set<Object> tags;
+
+  @OneToOne
+  private Cover cover;
+
+  private String title;
+}
+"#;
+
+  fn write_java_file(root: &Path, package_path: &str, class_name: &str, source: &str) {
+    let package_dir = root.join("src/main/java").join(package_path);
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(package_dir.join(format!("{}.java", class_name)), source).unwrap();
+  }
+
+  fn setup_project() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let root = temp_dir.path();
+    write_java_file(
+      root,
+      "com/example/domain",
+      "Author",
+      "package com.example.domain;\n\n@Entity\npublic class Author {\n  @Id\n  private Long id;\n}\n",
+    );
+    write_java_file(
+      root,
+      "com/example/library",
+      "Review",
+      "package com.example.library;\n\n@Entity\npublic class Review {\n  @Id\n  private Long id;\n\n  @ManyToOne\n  private Book book;\n}\n",
+    );
+    write_java_file(root, "com/example/library", "Book", BOOK_SOURCE);
+    temp_dir
+  }
+
+  #[test]
+  fn test_lists_relationship_fields_with_kind_side_and_mapped_by() {
+    let temp_dir = setup_project();
+    let b64_source = BASE64_STANDARD.encode(BOOK_SOURCE);
+
+    let response = run(None, Some(&b64_source), temp_dir.path()).unwrap();
+
+    assert_eq!(response.entity_type, "Book");
+    assert_eq!(response.relationships_count, 4);
+    let fields: Vec<(&str, &RelationshipKind, &EntitySide, Option<&str>, bool)> = response
+      .relationships
+      .iter()
+      .map(|relationship| {
+        (
+          relationship.field_name.as_str(),
+          &relationship.relationship_kind,
+          &relationship.side,
+          relationship.mapped_by.as_deref(),
+          relationship.is_collection,
+        )
+      })
+      .collect();
+    assert_eq!(
+      fields,
+      vec![
+        ("author", &RelationshipKind::ManyToOne, &EntitySide::Owning, None, false),
+        ("reviews", &RelationshipKind::OneToMany, &EntitySide::Inverse, Some("book"), true),
+        ("tags", &RelationshipKind::ManyToMany, &EntitySide::Owning, None, true),
+        ("cover", &RelationshipKind::OneToOne, &EntitySide::Owning, None, false),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_resolves_targets_through_imports_and_project_scan() {
+    let temp_dir = setup_project();
+    let b64_source = BASE64_STANDARD.encode(BOOK_SOURCE);
+
+    let response = run(None, Some(&b64_source), temp_dir.path()).unwrap();
+
+    let targets: Vec<(&str, Option<&str>, bool)> = response
+      .relationships
+      .iter()
+      .map(|relationship| {
+        (
+          relationship.target_type.as_str(),
+          relationship.target_fully_qualified_name.as_deref(),
+          relationship.target_file_path.is_some(),
+        )
+      })
+      .collect();
+    assert_eq!(
+      targets,
+      vec![
+        // Behind a wildcard import, found by the project scan
+        ("Author", Some("com.example.domain.Author"), true),
+        // Same package, found by the project scan
+        ("Review", Some("com.example.library.Review"), true),
+        // Single-type import of a class outside the project
+        ("Tag", Some("com.example.tags.Tag"), false),
+        // Unknown and possibly behind a wildcard import
+        ("Cover", None, false),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_rejects_class_that_is_not_mapped() {
+    let temp_dir = setup_project();
+    let b64_source = BASE64_STANDARD.encode("package com.example;\n\npublic class Plain {}\n");
+
+    let response = run(None, Some(&b64_source), temp_dir.path());
+
+    assert!(response.is_err());
+  }
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod get_superclass_fields_service_tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use syntaxpresso_core::commands::java::responses::get_superclass_fields_response::GetSuperclassFieldsResponse;
+  use syntaxpresso_core::commands::java::services::get_superclass_fields_service::run;
+  use tempfile::TempDir;
+
+  const BASE_ENTITY_SOURCE: &str = r#"package com.example;
+
+@MappedSuperclass
+public abstract class BaseEntity {
+  @Id
+  private Long id;
+
+  @Version
+  private Integer version;
+}
+"#;
+
+  const DOCUMENT_SOURCE: &str = r#"package com.example;
+
+@MappedSuperclass
+public abstract class Document extends BaseEntity {
+  private String title;
+
+  @Transient
+  private String preview;
+}
+"#;
+
+  const INVOICE_SOURCE: &str = r#"package com.example;
+
+@Entity
+public class Invoice extends Document {
+  private BigDecimal total;
+}
+"#;
+
+  fn create_project(sources: &[(&str, &str)]) -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    for (file_name, source) in sources {
+      fs::write(package_dir.join(file_name), source).unwrap();
+    }
+    (temp_dir, package_dir.join("Invoice.java"))
+  }
+
+  fn field_names(response: &GetSuperclassFieldsResponse) -> Vec<(&str, &str)> {
+    response
+      .fields
+      .iter()
+      .map(|field| (field.declaring_class.as_str(), field.field_name.as_str()))
+      .collect()
+  }
+
+  #[test]
+  fn test_collects_fields_across_three_level_hierarchy() {
+    let (temp_dir, invoice_path) = create_project(&[
+      ("BaseEntity.java", BASE_ENTITY_SOURCE),
+      ("Document.java", DOCUMENT_SOURCE),
+      ("Invoice.java", INVOICE_SOURCE),
+    ]);
+
+    let response = run(Some(&invoice_path), None, temp_dir.path()).unwrap();
+
+    assert_eq!(response.class_type, "Invoice");
+    assert_eq!(response.superclass_chain, vec!["Document", "BaseEntity"]);
+    assert_eq!(
+      field_names(&response),
+      vec![
+        ("Document", "title"),
+        ("BaseEntity", "id"),
+        ("BaseEntity", "version"),
+        ("Invoice", "total")
+      ]
+    );
+    assert_eq!(response.id_field_type.as_deref(), Some("Long"));
+    assert_eq!(response.fields_count, 4);
+    let inherited: Vec<bool> = response.fields.iter().map(|field| field.is_inherited).collect();
+    assert_eq!(inherited, vec![true, true, true, false]);
+  }
+
+  #[test]
+  fn test_stops_at_superclass_missing_from_project() {
+    let (temp_dir, invoice_path) =
+      create_project(&[("Document.java", DOCUMENT_SOURCE), ("Invoice.java", INVOICE_SOURCE)]);
+
+    let response = run(Some(&invoice_path), None, temp_dir.path()).unwrap();
+
+    assert_eq!(response.superclass_chain, vec!["Document"]);
+    assert_eq!(field_names(&response), vec![("Document", "title"), ("Invoice", "total")]);
+    assert_eq!(response.id_field_type, None);
+  }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod get_type_definition_location_service_tests {
+  use std::fs;
+  use std::path::{Path, PathBuf};
+
+  use syntaxpresso_core::commands::java::responses::get_type_definition_location_response::GetTypeDefinitionLocationResponse;
+  use syntaxpresso_core::commands::java::services::get_type_definition_location_service::run;
+  use tempfile::TempDir;
+
+  const SERVICE_SOURCE: &str = "package com.example.service;\n\nimport com.example.model.Order;\nimport com.example.dto.*;\nimport java.util.List;\n\npublic class OrderService {\n  private Order order;\n  private List<Status> statuses;\n  private Summary summary;\n  private Order.Line line;\n\n  enum Mode { FAST }\n\n  private Mode mode;\n}\n";
+
+  fn write_file(cwd: &Path, relative_path: &str, source: &str) -> PathBuf {
+    let file_path = cwd.join(relative_path);
+    fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+    fs::write(&file_path, source).unwrap();
+    file_path
+  }
+
+  fn setup_project() -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let cwd = temp_dir.path();
+    write_file(
+      cwd,
+      "src/main/java/com/example/model/Order.java",
+      "package com.example.model;\n\npublic class Order {\n  public static class Line {}\n}\n",
+    );
+    write_file(
+      cwd,
+      "src/main/java/com/example/dto/Summary.java",
+      "package com.example.dto;\n\npublic record Summary() {}\n",
+    );
+    write_file(
+      cwd,
+      "src/main/java/com/example/dto/Status.java",
+      "package com.example.dto;\n\npublic enum Status {}\n",
+    );
+    write_file(
+      cwd,
+      "src/main/java/com/example/legacy/Status.java",
+      "package com.example.legacy;\n\npublic enum Status {}\n",
+    );
+    let service_path =
+      write_file(cwd, "src/main/java/com/example/service/OrderService.java", SERVICE_SOURCE);
+    (temp_dir, service_path)
+  }
+
+  /// Resolves the reference starting at the nth occurrence of `needle` in the service source.
+  fn resolve(needle: &str, occurrence: usize) -> GetTypeDefinitionLocationResponse {
+    let (project, service_path) = setup_project();
+    let offset = SERVICE_SOURCE.match_indices(needle).nth(occurrence).unwrap().0;
+    let before = &SERVICE_SOURCE[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = offset - before.rfind('\n').map_or(0, |index| index + 1) + 1;
+    run(project.path(), &service_path, line, column).unwrap()
+  }
+
+  #[test]
+  fn test_resolves_imported_nested_and_wildcard_types() {
+    let order = resolve("Order order", 0);
+    let line = resolve("Line line", 0);
+    let summary = resolve("Summary summary", 0);
+
+    assert_eq!(order.fully_qualified_name.as_deref(), Some("com.example.model.Order"));
+    assert!(!order.is_external);
+    assert!(order.locations[0].file_path.ends_with("com/example/model/Order.java"));
+    assert_eq!((order.locations[0].line, order.locations[0].column), (3, 14));
+    assert_eq!(line.type_name, "Order.Line");
+    assert_eq!(line.fully_qualified_name.as_deref(), Some("com.example.model.Order.Line"));
+    assert_eq!((line.locations[0].line, line.locations[0].column), (4, 23));
+    assert_eq!(summary.fully_qualified_name.as_deref(), Some("com.example.dto.Summary"));
+  }
+
+  #[test]
+  fn test_reports_external_and_local_types() {
+    let list = resolve("List<", 0);
+    let mode = resolve("Mode mode", 0);
+
+    assert!(list.is_external);
+    assert!(list.locations.is_empty());
+    assert_eq!(list.fully_qualified_name.as_deref(), Some("java.util.List"));
+    assert!(!mode.is_external);
+    assert_eq!(mode.fully_qualified_name.as_deref(), Some("com.example.service.OrderService.Mode"));
+    assert_eq!((mode.locations[0].line, mode.locations[0].column), (13, 8));
+  }
+
+  #[test]
+  fn test_returns_all_candidates_of_unresolved_same_named_types() {
+    let (project, _) = setup_project();
+    let file_path = write_file(
+      project.path(),
+      "src/main/java/com/example/web/StatusView.java",
+      "package com.example.web;\n\npublic class StatusView {\n  private Status status;\n}\n",
+    );
+
+    let response = run(project.path(), &file_path, 4, 11).unwrap();
+
+    assert!(response.ambiguous);
+    assert!(!response.is_external);
+    assert_eq!(response.fully_qualified_name, None);
+    let mut names: Vec<&str> =
+      response.locations.iter().map(|location| location.fully_qualified_name.as_str()).collect();
+    names.sort();
+    assert_eq!(names, vec!["com.example.dto.Status", "com.example.legacy.Status"]);
+  }
+}
@@ -555,6 +555,26 @@ public class Test {}"#;
       assert_eq!(imports.len(), 1, "Should still have only one import");
     }
 
+    #[test]
+    fn test_add_import_uses_array_element_type_and_skips_java_lang() {
+      let java_code = "package com.example;\n\npublic class Test {}";
+      let mut ts_file = TSFile::from_source_code(java_code, SupportedLanguage::Java);
+
+      let position = ImportInsertionPosition::AfterPackageDeclaration;
+      assert!(add_import(&mut ts_file, &position, "java.util", "UUID[][]").is_some());
+      assert!(add_import(&mut ts_file, &position, "java.time", "Instant...").is_some());
+      assert!(add_import(&mut ts_file, &position, "java.lang", "String[]").is_none());
+
+      assert!(ts_file.source_code.contains("import java.util.UUID;\n"), "{}", ts_file.source_code);
+      assert!(
+        ts_file.source_code.contains("import java.time.Instant;\n"),
+        "{}",
+        ts_file.source_code
+      );
+      assert!(!ts_file.source_code.contains("java.lang"), "{}", ts_file.source_code);
+      assert_eq!(get_array_element_type("byte [ ]"), "byte");
+    }
+
     #[test]
     fn test_add_import_to_empty_file() {
       let mut ts_file = TSFile::from_source_code("", SupportedLanguage::Java);
@@ -781,4 +801,108 @@ public class UserServiceImpl implements UserService {
       }
     }
   }
+  mod resolve_fully_qualified_type_name_tests {
+    use super::*;
+
+    #[test]
+    fn test_resolves_imported_java_lang_and_same_package_types() {
+      let java_code = r#"
+package com.example.repository;
+
+import com.example.domain.User;
+import java.util.UUID;
+
+public interface UserRepository {}
+      "#;
+      let ts_file = TSFile::from_source_code(java_code, SupportedLanguage::Java);
+      assert_eq!(find_imported_class_package(&ts_file, "UUID").as_deref(), Some("java.util"));
+      assert_eq!(
+        resolve_fully_qualified_type_name(&ts_file, "User").as_deref(),
+        Some("com.example.domain.User")
+      );
+      assert_eq!(
+        resolve_fully_qualified_type_name(&ts_file, "Long").as_deref(),
+        Some("java.lang.Long")
+      );
+      assert_eq!(
+        resolve_fully_qualified_type_name(&ts_file, "Order").as_deref(),
+        Some("com.example.repository.Order")
+      );
+    }
+
+    #[test]
+    fn test_wildcard_import_leaves_unknown_types_unresolved() {
+      let java_code = r#"
+package com.example.repository;
+
+import com.example.domain.*;
+
+public interface UserRepository {}
+      "#;
+      let ts_file = TSFile::from_source_code(java_code, SupportedLanguage::Java);
+      assert!(resolve_fully_qualified_type_name(&ts_file, "User").is_none());
+      assert_eq!(
+        resolve_fully_qualified_type_name(&ts_file, "java.util.UUID").as_deref(),
+        Some("java.util.UUID")
+      );
+    }
+  }
+  mod remove_duplicate_imports_tests {
+    use super::*;
+
+    #[test]
+    fn test_collapses_exact_duplicate_imports() {
+      let java_code = "package com.example;\n\nimport java.util.List;\nimport java.util.Set;\nimport java.util . List ;\nimport static org.junit.Assert.assertEquals;\nimport static org.junit.Assert.assertEquals;\n\npublic class Test {}\n";
+      let mut ts_file = TSFile::from_source_code(java_code, SupportedLanguage::Java);
+
+      let removed_imports = remove_duplicate_imports(&mut ts_file);
+
+      assert_eq!(
+        removed_imports,
+        vec!["import java.util . List ;", "import static org.junit.Assert.assertEquals;"]
+      );
+      assert_eq!(
+        ts_file.source_code,
+        "package com.example;\n\nimport java.util.List;\nimport java.util.Set;\nimport static org.junit.Assert.assertEquals;\n\npublic class Test {}\n"
+      );
+    }
+
+    #[test]
+    fn test_removes_imports_covered_by_wildcard_of_same_package() {
+      let java_code = "package com.example;\n\nimport java.util.List;\nimport java.util.*;\nimport java.util.concurrent.Future;\nimport java.util.Map;\n\npublic class Test {}\n";
+      let mut ts_file = TSFile::from_source_code(java_code, SupportedLanguage::Java);
+
+      let removed_imports = remove_duplicate_imports(&mut ts_file);
+
+      assert_eq!(removed_imports, vec!["import java.util.List;", "import java.util.Map;"]);
+      assert_eq!(
+        ts_file.source_code,
+        "package com.example;\n\nimport java.util.*;\nimport java.util.concurrent.Future;\n\npublic class Test {}\n"
+      );
+    }
+
+    #[test]
+    fn test_keeps_covered_import_resolving_ambiguous_wildcards() {
+      let java_code = "package com.example;\n\nimport java.awt.*;\nimport java.util.*;\nimport java.util.List;\n\npublic class Test {}\n";
+      let mut ts_file = TSFile::from_source_code(java_code, SupportedLanguage::Java);
+
+      let removed_imports = remove_duplicate_imports(&mut ts_file);
+
+      assert!(removed_imports.is_empty(), "{:?}", removed_imports);
+      assert_eq!(ts_file.source_code, java_code);
+    }
+
+    #[test]
+    fn test_add_import_normalizes_existing_duplicates() {
+      let java_code = "package com.example;\n\nimport java.io.File;\nimport java.io.File;\n\npublic class Test {}\n";
+      let mut ts_file = TSFile::from_source_code(java_code, SupportedLanguage::Java);
+
+      add_import(&mut ts_file, &ImportInsertionPosition::AfterLastImport, "java.util", "List");
+
+      assert_eq!(
+        ts_file.source_code,
+        "package com.example;\n\nimport java.io.File;\nimport java.util.List;\n\npublic class Test {}\n"
+      );
+    }
+  }
 }
@@ -325,4 +325,16 @@ public interface Calculator {
       }
     }
   }
+  #[test]
+  fn test_get_interface_extended_type_nodes() {
+    let java_code = r#"
+public interface UserRepository extends JpaRepository<User, Long>, JpaSpecificationExecutor<User> {}
+"#;
+    let ts_file = create_ts_file(java_code);
+    let interface_node = get_first_public_interface_node(&ts_file).unwrap();
+    let extended_types = get_interface_extended_type_nodes(&ts_file, interface_node);
+    let extended_names: Vec<&str> =
+      extended_types.iter().filter_map(|node| ts_file.get_text_from_node(node)).collect();
+    assert_eq!(extended_names, vec!["JpaRepository<User, Long>", "JpaSpecificationExecutor<User>"]);
+  }
 }
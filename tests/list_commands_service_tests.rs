@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod list_commands_service_tests {
+  use std::thread;
+
+  use clap::{Arg, ArgAction, Command, Subcommand};
+  use syntaxpresso_core::commands::java::JavaCommands;
+  use syntaxpresso_core::commands::java::services::list_commands_service::run;
+
+  /// The derived definition of every command needs more stack than a test thread has in debug
+  /// builds.
+  fn build_java_command() -> Command {
+    thread::Builder::new()
+      .stack_size(16 * 1024 * 1024)
+      .spawn(|| JavaCommands::augment_subcommands(Command::new("java")))
+      .unwrap()
+      .join()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_describes_java_commands_from_clap_definitions() {
+    let java_command = build_java_command();
+
+    let response = run(&java_command).unwrap();
+
+    assert_eq!(
+      response.commands_count,
+      java_command.get_subcommands().filter(|subcommand| !subcommand.is_hide_set()).count()
+    );
+    let create_java_file =
+      response.commands.iter().find(|command| command.name == "create-java-file").unwrap();
+    assert!(!create_java_file.requires_ui_feature);
+    assert_eq!(
+      create_java_file.description.as_deref(),
+      Some("Create a class, interface, enum, record or annotation file")
+    );
+    assert!(response.commands.iter().all(|command| command.description.is_some()));
+    let arguments: Vec<(&str, &str, bool)> = create_java_file
+      .arguments
+      .iter()
+      .map(|argument| (argument.name.as_str(), argument.value_type.as_str(), argument.required))
+      .collect();
+    assert_eq!(
+      arguments,
+      vec![
+        ("cwd", "path", true),
+        ("package-name", "string", true),
+        ("file-name", "string", true),
+        ("file-type", "enum", true),
+        ("source-directory", "enum", false),
+        ("target-java-version", "integer", false),
+        ("template", "enum", false),
+      ]
+    );
+    let source_directory = &create_java_file.arguments[4];
+    assert_eq!(source_directory.default_value.as_deref(), Some("main"));
+    assert_eq!(source_directory.possible_values, vec!["main", "test", "all"]);
+    assert!(response.commands.iter().any(|command| command.name == "list-commands"));
+  }
+
+  #[test]
+  fn test_flags_lists_and_ui_commands() {
+    let command = Command::new("java")
+      .subcommand(
+        Command::new("create-java-file-ui")
+          .about("Opens the file form")
+          .arg(Arg::new("cwd").long("cwd").required(true)),
+      )
+      .subcommand(
+        Command::new("add-secondary-table")
+          .arg(Arg::new("apply").long("apply").action(ArgAction::SetTrue))
+          .arg(Arg::new("field_names").long("field-names").value_delimiter(','))
+          .arg(Arg::new("internal").long("internal").hide(true)),
+      );
+
+    let response = run(&command).unwrap();
+
+    let ui_command = &response.commands[0];
+    assert!(ui_command.requires_ui_feature);
+    assert_eq!(ui_command.description.as_deref(), Some("Opens the file form"));
+    let arguments = &response.commands[1].arguments;
+    assert_eq!(arguments.len(), 2);
+    assert_eq!(arguments[0].value_type, "boolean");
+    assert!(arguments[0].possible_values.is_empty() && arguments[0].default_value.is_none());
+    assert!(arguments[1].multiple);
+  }
+}
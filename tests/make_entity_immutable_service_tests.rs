@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod make_entity_immutable_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::make_entity_immutable_response::MakeEntityImmutableResponse;
+  use syntaxpresso_core::commands::java::services::make_entity_immutable_service::run;
+  use tempfile::TempDir;
+
+  const ENTITY_SOURCE: &str = "package com.example;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Country {\n  private Long id;\n\n  private String name;\n\n  public Long getId() {\n    return id;\n  }\n\n  public void setId(Long id) {\n    this.id = id;\n  }\n\n  public void setName(String value) {\n    name = value;\n  }\n\n  public void setCode(String code) {\n    this.name = code.trim();\n  }\n}\n";
+
+  fn make_immutable(
+    source: &str,
+    remove_setters: bool,
+  ) -> (Result<MakeEntityImmutableResponse, String>, String) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_file_path = temp_dir.path().join("Country.java");
+    fs::write(&entity_file_path, source).unwrap();
+    let b64_source = BASE64_STANDARD.encode(source);
+    let response = run(temp_dir.path(), &b64_source, &entity_file_path, remove_setters);
+    (response, fs::read_to_string(&entity_file_path).unwrap())
+  }
+
+  #[test]
+  fn test_adds_immutable_annotation_and_import() {
+    let (response, source) = make_immutable(ENTITY_SOURCE, false);
+
+    let response = response.unwrap();
+    assert!(response.annotation_added);
+    assert!(response.removed_setters.is_empty());
+    assert_eq!(response.warnings.len(), 1);
+    assert!(source.contains("@Entity\n@Immutable\npublic class Country {"), "{}", source);
+    assert!(source.contains("import org.hibernate.annotations.Immutable;"), "{}", source);
+    assert!(source.contains("public void setId(Long id)"), "{}", source);
+
+    let (second_response, second_source) = make_immutable(&source, false);
+    assert!(!second_response.unwrap().annotation_added);
+    assert_eq!(second_source, source);
+  }
+
+  #[test]
+  fn test_removes_generated_setters_only() {
+    let (response, source) = make_immutable(ENTITY_SOURCE, true);
+
+    let response = response.unwrap();
+    assert_eq!(response.removed_setters, vec!["setId".to_string(), "setName".to_string()]);
+    assert_eq!(
+      source,
+      "package com.example;\n\nimport org.hibernate.annotations.Immutable;\nimport jakarta.persistence.Entity;\n\n@Entity\n@Immutable\npublic class Country {\n  private Long id;\n\n  private String name;\n\n  public Long getId() {\n    return id;\n  }\n\n  public void setCode(String code) {\n    this.name = code.trim();\n  }\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_rejects_class_without_entity_annotation() {
+    let source = "package com.example;\n\npublic class Country {\n  private Long id;\n}\n";
+
+    let (response, unchanged_source) = make_immutable(source, true);
+
+    assert!(response.is_err());
+    assert_eq!(unchanged_source, source);
+  }
+}
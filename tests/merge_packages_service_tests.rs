@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod merge_packages_service_tests {
+  use std::fs;
+  use std::path::Path;
+
+  use syntaxpresso_core::commands::java::services::merge_packages_service::run;
+  use syntaxpresso_core::common::utils::scan_deadline::SkipReason;
+  use tempfile::TempDir;
+
+  fn write_file(cwd: &Path, relative_path: &str, source: &str) {
+    let file_path = cwd.join(relative_path);
+    fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+    fs::write(file_path, source).unwrap();
+  }
+
+  fn setup_project() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let cwd = temp_dir.path();
+    write_file(
+      cwd,
+      "src/main/java/com/example/dto/UserDto.java",
+      "package com.example.dto;\n\npublic class UserDto {\n  private AddressDto address;\n}\n",
+    );
+    write_file(
+      cwd,
+      "src/main/java/com/example/dto/AddressDto.java",
+      "package com.example.dto;\n\npublic class AddressDto {}\n",
+    );
+    write_file(
+      cwd,
+      "src/main/java/com/example/model/User.java",
+      "package com.example.model;\n\npublic class User {}\n",
+    );
+    write_file(
+      cwd,
+      "src/main/java/com/example/web/UserController.java",
+      "package com.example.web;\n\nimport com.example.dto.UserDto;\nimport com.example.dto.*;\nimport com.example.dtos.Other;\n\npublic class UserController {\n  private com.example.dto.AddressDto address;\n  private UserDto user;\n}\n",
+    );
+    temp_dir
+  }
+
+  #[test]
+  fn test_merge_moves_files_and_rewrites_imports() {
+    let project = setup_project();
+    let cwd = project.path();
+
+    let response = run(cwd, "com.example.dto", "com.example.model").unwrap();
+
+    assert_eq!(response.moved_files_count, 2);
+    assert_eq!(response.updated_imports_count, 2);
+    assert_eq!(response.updated_files_count, 1);
+    assert!(!cwd.join("src/main/java/com/example/dto").exists());
+    assert_eq!(
+      fs::read_to_string(cwd.join("src/main/java/com/example/model/UserDto.java")).unwrap(),
+      "package com.example.model;\n\npublic class UserDto {\n  private AddressDto address;\n}\n"
+    );
+    assert_eq!(
+      fs::read_to_string(cwd.join("src/main/java/com/example/web/UserController.java")).unwrap(),
+      "package com.example.web;\n\nimport com.example.model.UserDto;\nimport com.example.model.*;\nimport com.example.dtos.Other;\n\npublic class UserController {\n  private com.example.model.AddressDto address;\n  private UserDto user;\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_merge_refuses_conflicting_types_without_changes() {
+    let project = setup_project();
+    let cwd = project.path();
+    write_file(
+      cwd,
+      "src/main/java/com/example/model/UserDto.java",
+      "package com.example.model;\n\npublic class UserDto {}\n",
+    );
+    let controller_path = cwd.join("src/main/java/com/example/web/UserController.java");
+    let controller_source = fs::read_to_string(&controller_path).unwrap();
+
+    let result = run(cwd, "com.example.dto", "com.example.model");
+
+    assert_eq!(
+      result.err().unwrap(),
+      "Cannot merge 'com.example.dto' into 'com.example.model', both packages declare: UserDto"
+    );
+    assert!(cwd.join("src/main/java/com/example/dto/AddressDto.java").exists());
+    assert_eq!(fs::read_to_string(&controller_path).unwrap(), controller_source);
+  }
+
+  #[test]
+  fn test_merge_rejects_same_or_empty_package() {
+    let project = setup_project();
+
+    let same = run(project.path(), "com.example.dto", "com.example.dto");
+    let empty = run(project.path(), "com.example.missing", "com.example.model");
+
+    assert_eq!(same.err().unwrap(), "Source and destination packages must differ");
+    assert_eq!(empty.err().unwrap(), "Package 'com.example.missing' has no Java files to merge");
+  }
+
+  #[test]
+  fn test_merge_skips_undecodable_files_not_referencing_the_source_package() {
+    let project = setup_project();
+    let cwd = project.path();
+    let legacy_path = cwd.join("src/main/java/com/example/cc/Legacy.java");
+    fs::create_dir_all(legacy_path.parent().unwrap()).unwrap();
+    fs::write(&legacy_path, b"package com.example.cc;\n// caf\xe9\npublic class Legacy {}\n")
+      .unwrap();
+
+    let response = run(cwd, "com.example.dto", "com.example.model").unwrap();
+
+    assert_eq!(response.moved_files_count, 2);
+    assert_eq!(response.updated_files_count, 1);
+    assert_eq!(response.skipped_files.len(), 1);
+    assert_eq!(response.skipped_files[0].file_path, legacy_path.display().to_string());
+    assert_eq!(response.skipped_files[0].reason, SkipReason::InvalidEncoding);
+    assert!(!cwd.join("src/main/java/com/example/dto").exists());
+    assert!(cwd.join("src/main/java/com/example/model/UserDto.java").exists());
+  }
+
+  #[test]
+  fn test_merge_writes_nothing_when_an_undecodable_file_references_the_source_package() {
+    let project = setup_project();
+    let cwd = project.path();
+    let legacy_path = cwd.join("src/main/java/com/example/cc/Legacy.java");
+    fs::create_dir_all(legacy_path.parent().unwrap()).unwrap();
+    fs::write(
+      &legacy_path,
+      b"package com.example.cc;\n\nimport com.example.dto.UserDto;\n// caf\xe9\npublic class Legacy {}\n",
+    )
+    .unwrap();
+    let controller_path = cwd.join("src/main/java/com/example/web/UserController.java");
+    let controller_source = fs::read_to_string(&controller_path).unwrap();
+
+    let result = run(cwd, "com.example.dto", "com.example.model");
+
+    let error = result.err().unwrap();
+    assert!(error.contains("Legacy.java"), "{}", error);
+    assert!(
+      error.ends_with("is not valid UTF-8 and references 'com.example.dto', no file was modified")
+    );
+    assert!(cwd.join("src/main/java/com/example/dto/UserDto.java").exists());
+    assert!(!cwd.join("src/main/java/com/example/model/UserDto.java").exists());
+    assert_eq!(fs::read_to_string(&controller_path).unwrap(), controller_source);
+  }
+}
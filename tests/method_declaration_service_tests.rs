@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod method_declaration_service_tests {
+  use syntaxpresso_core::commands::java::treesitter::services::class_declaration_service::get_public_class_node;
+  use syntaxpresso_core::commands::java::treesitter::services::field_declaration_service::{
+    AddFieldDeclarationParams, add_field_declaration,
+  };
+  use syntaxpresso_core::commands::java::treesitter::services::method_declaration_service::*;
+  use syntaxpresso_core::commands::java::treesitter::types::java_basic_types::FieldInsertionPosition;
+  use syntaxpresso_core::commands::java::treesitter::types::java_visibility_modifier::JavaVisibilityModifier;
+  use syntaxpresso_core::commands::java::treesitter::types::member_access_config::MemberAccessConfig;
+  use syntaxpresso_core::common::supported_language::SupportedLanguage;
+  use syntaxpresso_core::common::ts_file::TSFile;
+
+  fn create_ts_file_from_content(content: &str) -> TSFile {
+    TSFile::from_source_code(content, SupportedLanguage::Java)
+  }
+
+  #[test]
+  fn test_get_accessor_names() {
+    assert_eq!(get_getter_name("firstName", "String"), "getFirstName");
+    assert_eq!(get_getter_name("active", "boolean"), "isActive");
+    assert_eq!(get_getter_name("active", "Boolean"), "getActive");
+    assert_eq!(get_setter_name("firstName"), "setFirstName");
+  }
+
+  #[test]
+  fn test_find_method_declaration_node_by_name_ignores_nested_classes() {
+    let content = r#"
+public class User {
+  public String getName() {
+    return name;
+  }
+
+  static class Builder {
+    void build() {}
+  }
+}
+"#;
+    let ts_file = create_ts_file_from_content(content);
+    let class_node = get_public_class_node(&ts_file).unwrap();
+    assert!(find_method_declaration_node_by_name(&ts_file, class_node, "getName").is_some());
+    assert!(find_method_declaration_node_by_name(&ts_file, class_node, "build").is_none());
+  }
+
+  #[test]
+  fn test_protected_field_with_public_getter() {
+    let mut ts_file = create_ts_file_from_content("public class User {\n}\n");
+    let class_start_byte = get_public_class_node(&ts_file).unwrap().start_byte();
+    let params = AddFieldDeclarationParams {
+      insertion_position: FieldInsertionPosition::EndOfClassBody,
+      visibility_modifier: JavaVisibilityModifier::Protected,
+      field_modifiers: vec![],
+      field_type: "String",
+      field_name: "name",
+      field_initialization: None,
+    };
+    add_field_declaration(&mut ts_file, class_start_byte, params, |_| ()).unwrap();
+    let added = add_accessor_methods(
+      &mut ts_file,
+      class_start_byte,
+      "String",
+      "name",
+      JavaVisibilityModifier::Public,
+    )
+    .unwrap();
+    assert_eq!(added, vec!["getName".to_string(), "setName".to_string()]);
+    assert!(ts_file.source_code.contains("  protected String name;"));
+    assert!(ts_file.source_code.contains(
+      "  public String getName() {\n    return name;\n  }\n\n  public void setName(String name) {\n    this.name = name;\n  }\n}"
+    ));
+  }
+
+  #[test]
+  fn test_add_accessor_methods_skips_existing_accessors() {
+    let content = "public class User {\n  private boolean active;\n\n  public boolean isActive() {\n    return active;\n  }\n}\n";
+    let mut ts_file = create_ts_file_from_content(content);
+    let class_start_byte = get_public_class_node(&ts_file).unwrap().start_byte();
+    let added = add_accessor_methods(
+      &mut ts_file,
+      class_start_byte,
+      "boolean",
+      "active",
+      JavaVisibilityModifier::Protected,
+    )
+    .unwrap();
+    assert_eq!(added, vec!["setActive".to_string()]);
+    assert_eq!(ts_file.source_code.matches("isActive").count(), 1);
+    assert!(ts_file.source_code.contains("  }\n\n  protected void setActive(boolean active) {"));
+  }
+
+  #[test]
+  fn test_regenerate_accessor_methods_replaces_stale_accessors_in_place() {
+    let content = "public class User {\n    private boolean active;\n\n    @JsonIgnore\n    public Boolean getActive() {\n        return this.active;\n    }\n\n    public void setActive(Boolean value) {\n        this.active = value;\n    }\n\n    public String toString() {\n        return \"User\";\n    }\n}\n";
+    let mut ts_file = create_ts_file_from_content(content);
+    let class_start_byte = get_public_class_node(&ts_file).unwrap().start_byte();
+    let regenerations = regenerate_accessor_methods(
+      &mut ts_file,
+      class_start_byte,
+      "boolean",
+      "active",
+      true,
+      JavaVisibilityModifier::Public,
+    )
+    .unwrap();
+    assert_eq!(
+      regenerations,
+      vec![
+        ("isActive".to_string(), AccessorRegeneration::Replaced),
+        ("setActive".to_string(), AccessorRegeneration::Replaced),
+      ]
+    );
+    assert_eq!(
+      ts_file.source_code,
+      "public class User {\n    private boolean active;\n\n    @JsonIgnore\n    public boolean isActive() {\n        return active;\n    }\n\n    public void setActive(boolean active) {\n        this.active = active;\n    }\n\n    public String toString() {\n        return \"User\";\n    }\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_regenerate_accessor_methods_keeps_custom_bodies() {
+    let content = "public class User {\n  private String name;\n\n  public void setName(Object value) {\n    // custom: trim input\n    this.name = value.toString().trim();\n  }\n}\n";
+    let mut ts_file = create_ts_file_from_content(content);
+    let class_start_byte = get_public_class_node(&ts_file).unwrap().start_byte();
+    let regenerations = regenerate_accessor_methods(
+      &mut ts_file,
+      class_start_byte,
+      "String",
+      "name",
+      true,
+      JavaVisibilityModifier::Public,
+    )
+    .unwrap();
+    assert_eq!(
+      regenerations,
+      vec![
+        ("setName".to_string(), AccessorRegeneration::SignatureReplaced),
+        ("getName".to_string(), AccessorRegeneration::Created),
+      ]
+    );
+    assert!(ts_file.source_code.contains(
+      "  public void setName(String value) {\n    // custom: trim input\n    this.name = value.toString().trim();\n  }"
+    ));
+    assert!(ts_file.source_code.contains("  public String getName() {\n    return name;\n  }\n}"));
+  }
+
+  #[test]
+  fn test_regenerate_accessor_methods_without_setter() {
+    let content = "public class User {\n    private final Long id;\n}\n";
+    let mut ts_file = create_ts_file_from_content(content);
+    let class_start_byte = get_public_class_node(&ts_file).unwrap().start_byte();
+    let regenerations = regenerate_accessor_methods(
+      &mut ts_file,
+      class_start_byte,
+      "Long",
+      "id",
+      false,
+      JavaVisibilityModifier::Public,
+    )
+    .unwrap();
+    assert_eq!(regenerations, vec![("getId".to_string(), AccessorRegeneration::Created)]);
+    assert_eq!(
+      ts_file.source_code,
+      "public class User {\n    private final Long id;\n\n    public Long getId() {\n        return id;\n    }\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_member_access_config_validation() {
+    let protected_field_public_accessors = MemberAccessConfig {
+      field_visibility: JavaVisibilityModifier::Protected,
+      generate_accessors: true,
+      accessor_visibility: JavaVisibilityModifier::Public,
+    };
+    assert!(protected_field_public_accessors.validate().is_ok());
+    let private_accessors = MemberAccessConfig {
+      accessor_visibility: JavaVisibilityModifier::Private,
+      ..protected_field_public_accessors.clone()
+    };
+    assert!(private_accessors.validate().is_err());
+    let narrower_accessors = MemberAccessConfig {
+      field_visibility: JavaVisibilityModifier::Public,
+      accessor_visibility: JavaVisibilityModifier::Protected,
+      generate_accessors: true,
+    };
+    assert!(narrower_accessors.validate().is_err());
+    assert!(MemberAccessConfig::default().validate().is_ok());
+  }
+}
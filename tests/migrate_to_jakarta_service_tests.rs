@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod migrate_to_jakarta_service_tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use syntaxpresso_core::commands::java::services::migrate_to_jakarta_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+  use syntaxpresso_core::commands::java::treesitter::types::namespace_mapping::NamespaceMapping;
+  use syntaxpresso_core::common::utils::scan_deadline::SkipReason;
+  use tempfile::TempDir;
+
+  fn write_java_file(temp_dir: &TempDir, file_name: &str, source: &str) -> PathBuf {
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    let file_path = package_dir.join(file_name);
+    fs::write(&file_path, source).unwrap();
+    file_path
+  }
+
+  #[test]
+  fn test_rewrites_imports_and_qualified_references() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let file_path = write_java_file(
+      &temp_dir,
+      "User.java",
+      "package com.example;\n\nimport javax.persistence.*;\nimport static javax.persistence.FetchType.LAZY;\nimport javax.transaction.xa.XAResource;\n\n@javax.persistence.Entity\npublic class User {\n  private javax.persistence.EntityManager entityManager;\n  private String note = \"javax.persistence\";\n\n  void validate() {\n    javax.validation.Validation.buildDefaultValidatorFactory();\n  }\n}\n",
+    );
+    let cwd = temp_dir.path().canonicalize().unwrap();
+
+    let response = run(&cwd, &JavaSourceDirectoryType::All, &[]).unwrap();
+
+    assert_eq!(response.migrated_files_count, 1);
+    assert_eq!(response.migrated_files[0].rewritten_references_count, 5);
+    assert_eq!(
+      fs::read_to_string(&file_path).unwrap(),
+      "package com.example;\n\nimport jakarta.persistence.*;\nimport static jakarta.persistence.FetchType.LAZY;\nimport javax.transaction.xa.XAResource;\n\n@jakarta.persistence.Entity\npublic class User {\n  private jakarta.persistence.EntityManager entityManager;\n  private String note = \"javax.persistence\";\n\n  void validate() {\n    jakarta.validation.Validation.buildDefaultValidatorFactory();\n  }\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_skips_files_already_on_jakarta() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let source = "package com.example;\n\nimport jakarta.persistence.Entity;\nimport javax.swing.JPanel;\n\n@Entity\npublic class User {}\n";
+    let file_path = write_java_file(&temp_dir, "User.java", source);
+    let cwd = temp_dir.path().canonicalize().unwrap();
+
+    let response = run(&cwd, &JavaSourceDirectoryType::All, &[]).unwrap();
+
+    assert_eq!(response.migrated_files_count, 0);
+    assert_eq!(response.skipped_files_count, 1);
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), source);
+  }
+
+  #[test]
+  fn test_additional_mappings_extend_and_override_defaults() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let file_path = write_java_file(
+      &temp_dir,
+      "User.java",
+      "package com.example;\n\nimport javax.xml.bind.annotation.XmlRootElement;\nimport javax.annotation.PostConstruct;\n\npublic class User {}\n",
+    );
+    let cwd = temp_dir.path().canonicalize().unwrap();
+    let mappings = vec![
+      NamespaceMapping::parse("javax.xml.bind=jakarta.xml.bind").unwrap(),
+      NamespaceMapping::parse("javax.annotation=javax.annotation").unwrap(),
+    ];
+
+    let response = run(&cwd, &JavaSourceDirectoryType::All, &mappings).unwrap();
+
+    assert_eq!(response.rewritten_references_count, 1);
+    assert_eq!(
+      fs::read_to_string(&file_path).unwrap(),
+      "package com.example;\n\nimport jakarta.xml.bind.annotation.XmlRootElement;\nimport javax.annotation.PostConstruct;\n\npublic class User {}\n"
+    );
+  }
+
+  #[test]
+  fn test_reports_files_that_are_not_valid_utf8() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let source =
+      "package com.example;\n\nimport javax.persistence.Entity;\n\n@Entity\npublic class A {}\n";
+    let file_path = write_java_file(&temp_dir, "A.java", source);
+    let unreadable_path = write_java_file(&temp_dir, "Z.java", "");
+    let unreadable_source =
+      b"package com.example;\n\nimport javax.persistence.Id;\n// \xff\xfe\nclass Z {}\n";
+    fs::write(&unreadable_path, unreadable_source).unwrap();
+    let cwd = temp_dir.path().canonicalize().unwrap();
+
+    let response = run(&cwd, &JavaSourceDirectoryType::All, &[]).unwrap();
+
+    assert_eq!(response.migrated_files_count, 1);
+    assert_eq!(response.skipped_files_count, 0);
+    assert_eq!(response.skipped_files.len(), 1);
+    assert!(response.skipped_files[0].file_path.ends_with("Z.java"));
+    assert_eq!(response.skipped_files[0].reason, SkipReason::InvalidEncoding);
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), source.replace("javax", "jakarta"));
+    assert_eq!(fs::read(&unreadable_path).unwrap(), unreadable_source);
+  }
+
+  #[test]
+  fn test_namespace_mapping_parse_rejects_invalid_values() {
+    assert!(NamespaceMapping::parse("javax.xml.bind").is_err());
+    assert!(NamespaceMapping::parse("javax..bind=jakarta.xml.bind").is_err());
+  }
+}
@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod move_method_service_tests {
+  use std::fs;
+  use std::path::{Path, PathBuf};
+
+  use syntaxpresso_core::commands::java::services::move_method_service::run;
+  use syntaxpresso_core::common::utils::scan_deadline::ScanDeadline;
+  use tempfile::TempDir;
+
+  const STRINGS_SOURCE: &str = r#"package com.example.util;
+
+import java.math.BigDecimal;
+import java.math.RoundingMode;
+
+public class Strings {
+  private int width;
+
+  /**
+   * Formats the amount with two decimals.
+   */
+  public static String formatAmount(BigDecimal amount) {
+    return pad(amount.setScale(2, RoundingMode.HALF_UP).toPlainString());
+  }
+
+  private static String pad(String text) {
+    return text.trim();
+  }
+
+  public String label() {
+    return formatAmount(BigDecimal.ONE);
+  }
+
+  public String describe(String name) {
+    return this.label() + name + width;
+  }
+}
+"#;
+
+  const MONEY_SOURCE: &str = r#"package com.example.money;
+
+public class Money {
+  private long cents;
+}
+"#;
+
+  const INVOICE_SOURCE: &str = r#"package com.example.billing;
+
+import com.example.util.Strings;
+import java.math.BigDecimal;
+
+public class Invoice {
+  public String total(BigDecimal amount) {
+    return Strings.formatAmount(amount);
+  }
+}
+"#;
+
+  fn write(temp_dir: &TempDir, relative_path: &str, source: &str) -> PathBuf {
+    let file_path = temp_dir.path().join(relative_path);
+    fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+    fs::write(&file_path, source).unwrap();
+    file_path
+  }
+
+  fn setup() -> (TempDir, PathBuf, PathBuf, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let source = write(&temp_dir, "src/main/java/com/example/util/Strings.java", STRINGS_SOURCE);
+    let target = write(&temp_dir, "src/main/java/com/example/money/Money.java", MONEY_SOURCE);
+    let invoice =
+      write(&temp_dir, "src/main/java/com/example/billing/Invoice.java", INVOICE_SOURCE);
+    (temp_dir, source, target, invoice)
+  }
+
+  fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap()
+  }
+
+  #[test]
+  fn test_moves_static_method_with_helper_and_updates_call_sites() {
+    let (temp_dir, source, target, invoice) = setup();
+
+    let response =
+      run(temp_dir.path(), &source, &target, "formatAmount", true, &ScanDeadline::unbounded())
+        .unwrap();
+
+    assert!(response.is_static);
+    assert_eq!(response.moved_helpers, vec!["pad"]);
+    assert_eq!(response.updated_call_sites_count, 2);
+    assert_eq!(response.written_files.files_count, 3);
+    assert!(response.warnings.is_empty(), "{:?}", response.warnings);
+    let target_text = read(&target);
+    assert!(
+      target_text.contains(
+        "  private long cents;\n\n  /**\n   * Formats the amount with two decimals.\n   */\n  public static String formatAmount(BigDecimal amount) {\n    return pad(amount.setScale(2, RoundingMode.HALF_UP).toPlainString());\n  }\n\n  private static String pad(String text) {\n    return text.trim();\n  }\n}\n"
+      ),
+      "{}",
+      target_text
+    );
+    assert!(target_text.contains("import java.math.BigDecimal;"), "{}", target_text);
+    assert!(target_text.contains("import java.math.RoundingMode;"), "{}", target_text);
+    let source_text = read(&source);
+    assert!(!source_text.contains("formatAmount(BigDecimal amount)"), "{}", source_text);
+    assert!(!source_text.contains("pad("), "{}", source_text);
+    assert!(!source_text.contains("RoundingMode"), "{}", source_text);
+    assert!(source_text.contains("return Money.formatAmount(BigDecimal.ONE);"), "{}", source_text);
+    assert!(source_text.contains("import com.example.money.Money;"), "{}", source_text);
+    let invoice_text = read(&invoice);
+    assert!(invoice_text.contains("return Money.formatAmount(amount);"), "{}", invoice_text);
+    assert!(invoice_text.contains("import com.example.money.Money;"), "{}", invoice_text);
+    assert!(!invoice_text.contains("import com.example.util.Strings;"), "{}", invoice_text);
+  }
+
+  #[test]
+  fn test_warns_about_instance_method_dependencies() {
+    let (temp_dir, source, target, invoice) = setup();
+
+    let response =
+      run(temp_dir.path(), &source, &target, "describe", false, &ScanDeadline::unbounded())
+        .unwrap();
+
+    assert!(!response.is_static);
+    assert_eq!(response.updated_call_sites_count, 0);
+    assert_eq!(
+      response.warnings,
+      vec![
+        "Method 'describe' uses 'this', which refers to 'Money' once moved",
+        "Method 'describe' references field 'width' of 'Strings', which stays behind",
+        "Method 'describe' calls 'label' of 'Strings', which stays behind",
+        "Call sites of instance method 'describe' were left unchanged, they need a 'Money' receiver",
+      ]
+    );
+    assert!(read(&target).contains("  public String describe(String name) {"));
+    assert_eq!(read(&invoice), INVOICE_SOURCE);
+  }
+
+  #[test]
+  fn test_rejects_missing_and_colliding_methods() {
+    let (temp_dir, source, target, _) = setup();
+
+    let result =
+      run(temp_dir.path(), &source, &target, "missing", false, &ScanDeadline::unbounded());
+    assert_eq!(result.err().unwrap(), "Method 'missing' not found in class 'Strings'");
+
+    fs::write(&target, MONEY_SOURCE.replace("  private long cents;\n", "  void label() {}\n"))
+      .unwrap();
+    let result = run(temp_dir.path(), &source, &target, "label", false, &ScanDeadline::unbounded());
+    assert_eq!(result.err().unwrap(), "Class 'Money' already declares a method named 'label'");
+    assert_eq!(read(&source), STRINGS_SOURCE);
+  }
+
+  #[test]
+  fn test_rewrites_static_imports_of_moved_method() {
+    let (temp_dir, source, target, _) = setup();
+    let report = write(
+      &temp_dir,
+      "src/main/java/com/example/billing/Report.java",
+      "package com.example.billing;\n\nimport static com.example.util.Strings.formatAmount;\n\nimport java.math.BigDecimal;\n\npublic class Report {\n  public String line(BigDecimal amount) {\n    return formatAmount(amount) + formatAmount(BigDecimal.TEN);\n  }\n}\n",
+    );
+    let summary = write(
+      &temp_dir,
+      "src/main/java/com/example/billing/Summary.java",
+      "package com.example.billing;\n\nimport static com.example.util.Strings.*;\n\npublic class Summary {\n  public String zero() {\n    return formatAmount(java.math.BigDecimal.ZERO);\n  }\n}\n",
+    );
+    let strings_source = STRINGS_SOURCE.replace("  private int width;\n\n", "");
+    fs::write(&source, &strings_source).unwrap();
+
+    let response =
+      run(temp_dir.path(), &source, &target, "formatAmount", true, &ScanDeadline::unbounded())
+        .unwrap();
+
+    assert_eq!(response.updated_call_sites_count, 5);
+    assert!(
+      read(&report).contains("import static com.example.money.Money.formatAmount;\n"),
+      "{}",
+      read(&report)
+    );
+    assert!(!read(&report).contains("com.example.util.Strings"), "{}", read(&report));
+    assert!(
+      read(&summary).contains(
+        "import static com.example.util.Strings.*;\nimport static com.example.money.Money.formatAmount;\n"
+      ),
+      "{}",
+      read(&summary)
+    );
+    assert!(read(&source).contains("public class Strings {\n  public String label() {"));
+  }
+
+  #[test]
+  fn test_removes_blank_line_left_by_last_source_import() {
+    let (temp_dir, _, target, _) = setup();
+    let source = write(
+      &temp_dir,
+      "src/main/java/com/example/util/Util.java",
+      "package com.example.util;\n\nimport java.math.BigDecimal;\n\npublic class Util {\n  public static BigDecimal half(BigDecimal amount) {\n    return amount.divide(BigDecimal.TWO);\n  }\n\n  public static int one() {\n    return 1;\n  }\n}\n",
+    );
+
+    run(temp_dir.path(), &source, &target, "half", true, &ScanDeadline::unbounded()).unwrap();
+
+    assert_eq!(
+      read(&source),
+      "package com.example.util;\n\npublic class Util {\n  public static int one() {\n    return 1;\n  }\n}\n"
+    );
+    assert!(read(&target).contains("import java.math.BigDecimal;"), "{}", read(&target));
+  }
+
+  #[test]
+  fn test_aborts_without_writing_when_call_site_scan_times_out() {
+    let (temp_dir, source, target, invoice) = setup();
+
+    let deadline = ScanDeadline::new(Some(0));
+    let result = run(temp_dir.path(), &source, &target, "formatAmount", true, &deadline);
+
+    assert_eq!(
+      result.err().unwrap(),
+      "Timed out scanning the call sites of 'formatAmount', no file was modified"
+    );
+    assert_eq!(read(&source), STRINGS_SOURCE);
+    assert_eq!(read(&target), MONEY_SOURCE);
+    assert_eq!(read(&invoice), INVOICE_SOURCE);
+  }
+}
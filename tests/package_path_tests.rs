@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod package_path_tests {
+  use std::path::PathBuf;
+
+  use syntaxpresso_core::commands::java::services::create_java_file_service;
+  use syntaxpresso_core::commands::java::treesitter::types::java_file_type::JavaFileType;
+  use syntaxpresso_core::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+  use syntaxpresso_core::common::utils::package_path::{
+    self, MappedPackagePathStrategy, PackagePathStrategy, StandardPackagePathStrategy,
+  };
+  use tempfile::TempDir;
+
+  #[test]
+  fn test_standard_strategy_uses_one_directory_per_segment() {
+    assert_eq!(
+      StandardPackagePathStrategy.to_relative_path("com.example.model"),
+      PathBuf::from("com/example/model")
+    );
+    assert_eq!(package_path::to_relative_path("com.example"), PathBuf::from("com/example"));
+  }
+
+  #[test]
+  fn test_mapped_strategy_places_created_files_under_custom_directory() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let mappings = ["com.example=app", "com.example.legacy=legacy"]
+      .iter()
+      .map(|mapping| MappedPackagePathStrategy::parse_mapping(mapping).unwrap())
+      .collect();
+    package_path::set_strategy(MappedPackagePathStrategy::new(mappings));
+
+    let legacy_file = create_java_file_service::run(
+      temp_dir.path(),
+      "com.example.legacy.model",
+      "Order",
+      &JavaFileType::Class,
+      &JavaSourceDirectoryType::Main,
+      None,
+      None,
+    );
+    let other_path = package_path::to_relative_path("com.examples");
+    package_path::reset_strategy();
+
+    let legacy_path = PathBuf::from(legacy_file.unwrap().file_path);
+    assert!(legacy_path.ends_with("src/main/java/legacy/model/Order.java"));
+    assert!(legacy_path.exists());
+    assert_eq!(other_path, PathBuf::from("com/examples"));
+  }
+
+  #[test]
+  fn test_mapping_outside_source_root_is_rejected() {
+    for mapping in ["com.example=../outside", "com.example=/tmp/outside", "com.example"] {
+      assert!(MappedPackagePathStrategy::parse_mapping(mapping).is_err(), "{}", mapping);
+    }
+  }
+}
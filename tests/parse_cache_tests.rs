@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod parse_cache_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::services::create_jpa_entity_basic_field_service;
+  use syntaxpresso_core::commands::java::treesitter::types::basic_field_config::BasicFieldConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::member_access_config::MemberAccessConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::reserved_word_check::ReservedWordCheck;
+  use syntaxpresso_core::common::supported_language::SupportedLanguage;
+  use syntaxpresso_core::common::ts_file::TSFile;
+  use syntaxpresso_core::common::utils::parse_cache;
+  use tempfile::TempDir;
+  use tree_sitter::Point;
+
+  const ENTITY_SOURCE: &str =
+    "package com.example;\n\n@Entity\npublic class Article {\n  @Id\n  private Long id;\n}\n";
+
+  fn string_field_config(field_name: &str) -> BasicFieldConfig {
+    BasicFieldConfig {
+      field_name: field_name.to_string(),
+      field_type: "String".to_string(),
+      field_type_package_name: Some("java.lang".to_string()),
+      field_length: None,
+      field_precision: None,
+      field_scale: None,
+      money_precision: None,
+      money_scale: None,
+      field_temporal: None,
+      field_timezone_storage: None,
+      field_unique: false,
+      field_nullable: true,
+      field_large_object: false,
+      field_optional: true,
+      field_fetch_type: None,
+      sync_length: false,
+      explicit_string_length: false,
+      access_config: MemberAccessConfig::default(),
+      reserved_word_check: ReservedWordCheck::default(),
+    }
+  }
+
+  #[test]
+  fn test_input_edit_spans_the_changed_bytes() {
+    let edit = parse_cache::compute_input_edit("a\nbc\nd", "a\nbXYc\nd");
+
+    assert_eq!((edit.start_byte, edit.old_end_byte, edit.new_end_byte), (3, 3, 5));
+    assert_eq!(edit.start_position, Point::new(1, 1));
+    assert_eq!(edit.old_end_position, Point::new(1, 1));
+    assert_eq!(edit.new_end_position, Point::new(1, 3));
+  }
+
+  #[test]
+  fn test_consecutive_insertions_reuse_cached_tree() {
+    parse_cache::enable();
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_file_path = temp_dir.path().join("Article.java");
+    fs::write(&entity_file_path, ENTITY_SOURCE).unwrap();
+
+    for field_name in ["title", "summary"] {
+      let b64_source = BASE64_STANDARD.encode(fs::read_to_string(&entity_file_path).unwrap());
+      create_jpa_entity_basic_field_service::run(
+        &b64_source,
+        &entity_file_path,
+        &string_field_config(field_name),
+        temp_dir.path(),
+      )
+      .unwrap();
+    }
+
+    let source = fs::read_to_string(&entity_file_path).unwrap();
+    assert!(source.contains("private String title;"), "{}", source);
+    assert!(source.contains("private String summary;"), "{}", source);
+    assert_eq!(parse_cache::get_reuses_count(&entity_file_path), Some(1));
+    let edited_source = source.replace("summary", "subtitle");
+    let reparsed = parse_cache::parse_base64_source_code(
+      &BASE64_STANDARD.encode(&edited_source),
+      &entity_file_path,
+      SupportedLanguage::Java,
+    );
+    let fresh = TSFile::from_source_code(&edited_source, SupportedLanguage::Java);
+    assert_eq!(parse_cache::get_reuses_count(&entity_file_path), Some(2));
+    assert_eq!(
+      reparsed.tree.unwrap().root_node().to_sexp(),
+      fresh.tree.unwrap().root_node().to_sexp()
+    );
+  }
+
+  #[test]
+  fn test_invalidate_drops_cached_parse() {
+    parse_cache::enable();
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_file_path = temp_dir.path().join("Article.java");
+    fs::write(&entity_file_path, ENTITY_SOURCE).unwrap();
+
+    create_jpa_entity_basic_field_service::run(
+      &BASE64_STANDARD.encode(ENTITY_SOURCE),
+      &entity_file_path,
+      &string_field_config("title"),
+      temp_dir.path(),
+    )
+    .unwrap();
+
+    assert_eq!(parse_cache::get_reuses_count(&entity_file_path), Some(0));
+    assert_eq!(parse_cache::invalidate(Some(&entity_file_path)), 1);
+    assert_eq!(parse_cache::get_reuses_count(&entity_file_path), None);
+  }
+}
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod progress_tests {
+  use std::cell::RefCell;
+  use std::fs;
+  use std::rc::Rc;
+
+  use syntaxpresso_core::commands::java::services::migrate_to_jakarta_service;
+  use syntaxpresso_core::commands::java::treesitter::types::java_source_directory_type::JavaSourceDirectoryType;
+  use syntaxpresso_core::common::utils::progress::{self, ProgressEvent};
+  use tempfile::TempDir;
+
+  #[test]
+  fn test_migration_reports_progress_once_per_file() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(
+      package_dir.join("A.java"),
+      "package com.example;\n\nimport javax.persistence.Entity;\n\n@Entity\npublic class A {}\n",
+    )
+    .unwrap();
+    fs::write(package_dir.join("B.java"), "package com.example;\n\npublic class B {}\n").unwrap();
+    fs::write(package_dir.join("C.java"), "package com.example;\n\npublic class C {}\n").unwrap();
+    let events: Rc<RefCell<Vec<ProgressEvent>>> = Rc::new(RefCell::new(Vec::new()));
+    let sink_events = Rc::clone(&events);
+    progress::set_sink(move |event| sink_events.borrow_mut().push(event.clone()));
+
+    let response =
+      migrate_to_jakarta_service::run(temp_dir.path(), &JavaSourceDirectoryType::Main, &[]);
+    progress::clear_sink();
+
+    assert_eq!(response.unwrap().migrated_files_count, 1);
+    let events = events.borrow();
+    assert_eq!(events.len(), 3);
+    for (index, event) in events.iter().enumerate() {
+      assert_eq!(event.phase, "migrating");
+      assert_eq!(event.current, index + 1);
+      assert_eq!(event.total, 3);
+    }
+  }
+}
@@ -0,0 +1,234 @@
+#[cfg(test)]
+mod relationship_field_config_tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::services::{
+    create_jpa_many_to_one_relationship_service, create_jpa_one_to_one_relationship_service,
+  };
+  use syntaxpresso_core::commands::java::treesitter::types::cascade_type::CascadeType;
+  use syntaxpresso_core::commands::java::treesitter::types::collection_type::CollectionType;
+  use syntaxpresso_core::commands::java::treesitter::types::fetch_type::FetchType;
+  use syntaxpresso_core::commands::java::treesitter::types::many_to_one_field_config::ManyToOneFieldConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::mapping_type::MappingType;
+  use syntaxpresso_core::commands::java::treesitter::types::one_to_one_field_config::OneToOneFieldConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::other_type::OtherType;
+  use tempfile::TempDir;
+
+  const BOOK_SOURCE: &str =
+    "package com.example;\n\n@Entity\npublic class Book {\n  @Id\n  private Long id;\n}\n";
+
+  fn setup_project() -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    let book_path = package_dir.join("Book.java");
+    fs::write(&book_path, BOOK_SOURCE).unwrap();
+    fs::write(
+      package_dir.join("Author.java"),
+      "package com.example;\n\n@Entity\npublic class Author {\n  @Id\n  private Long id;\n}\n",
+    )
+    .unwrap();
+    (temp_dir, book_path)
+  }
+
+  #[test]
+  fn test_one_to_one_config_from_complete_json() {
+    let json = r#"{
+      "inverseFieldType": "Profile",
+      "mappingType": "bidirectional_join_column",
+      "owningSideCascades": ["persist", "merge"],
+      "inverseSideCascades": ["all"],
+      "owningSideOther": ["mandatory", "unique"],
+      "inverseSideOther": ["orphan_removal"]
+    }"#;
+
+    let config = OneToOneFieldConfig::from_json(json).unwrap();
+
+    assert_eq!(config.inverse_field_type, "Profile");
+    assert_eq!(config.mapping_type, Some(MappingType::BidirectionalJoinColumn));
+    assert_eq!(config.owning_side_cascades, vec![CascadeType::Persist, CascadeType::Merge]);
+    assert_eq!(config.inverse_side_cascades, vec![CascadeType::All]);
+    assert_eq!(config.owning_side_other, vec![OtherType::Mandatory, OtherType::Unique]);
+    assert_eq!(config.inverse_side_other, vec![OtherType::OrphanRemoval]);
+  }
+
+  #[test]
+  fn test_one_to_one_config_defaults_optional_lists() {
+    let config = OneToOneFieldConfig::from_json(r#"{"inverseFieldType": "Profile"}"#).unwrap();
+
+    assert_eq!(config.mapping_type, None);
+    assert!(config.owning_side_cascades.is_empty());
+    assert!(config.inverse_side_cascades.is_empty());
+    assert!(config.owning_side_other.is_empty());
+    assert!(config.inverse_side_other.is_empty());
+  }
+
+  #[test]
+  fn test_many_to_one_config_from_complete_json() {
+    let json = r#"{
+      "inverseFieldType": "Author",
+      "fetchType": "lazy",
+      "collectionType": "list",
+      "mappingType": "unidirectional_join_column",
+      "owningSideCascades": ["refresh", "detach"],
+      "inverseSideCascades": ["remove"],
+      "owningSideOther": ["mandatory"],
+      "inverseSideOther": ["equals_hashcode", "large_object"]
+    }"#;
+
+    let config = ManyToOneFieldConfig::from_json(json).unwrap();
+
+    assert_eq!(config.inverse_field_type, "Author");
+    assert_eq!(config.fetch_type, FetchType::Lazy);
+    assert_eq!(config.collection_type, CollectionType::List);
+    assert_eq!(config.mapping_type, Some(MappingType::UnidirectionalJoinColumn));
+    assert_eq!(config.owning_side_cascades, vec![CascadeType::Refresh, CascadeType::Detach]);
+    assert_eq!(config.inverse_side_cascades, vec![CascadeType::Remove]);
+    assert_eq!(config.owning_side_other, vec![OtherType::Mandatory]);
+    assert_eq!(config.inverse_side_other, vec![OtherType::EqualsHashcode, OtherType::LargeObject]);
+  }
+
+  #[test]
+  fn test_many_to_one_config_requires_fetch_and_collection_type() {
+    let result = ManyToOneFieldConfig::from_json(r#"{"inverseFieldType": "Author"}"#);
+
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn test_config_json_rejects_unknown_values() {
+    let unknown_cascade =
+      OneToOneFieldConfig::from_json(r#"{"inverseFieldType": "A", "owningSideCascades": ["x"]}"#);
+    let unknown_field = OneToOneFieldConfig::from_json(r#"{"inverseFieldType": "A", "typo": 1}"#);
+
+    assert!(unknown_cascade.is_err());
+    assert!(unknown_field.is_err());
+  }
+
+  #[test]
+  fn test_many_to_one_renders_named_foreign_key() {
+    let (project, book_path) = setup_project();
+    let config = ManyToOneFieldConfig::from_json(
+      r#"{"inverseFieldType": "Author", "fetchType": "lazy", "collectionType": "list",
+          "mappingType": "unidirectional_join_column", "foreignKeyName": "fk_book_author"}"#,
+    )
+    .unwrap();
+
+    create_jpa_many_to_one_relationship_service::run(
+      project.path(),
+      &BASE64_STANDARD.encode(BOOK_SOURCE),
+      &book_path,
+      "author",
+      "books",
+      &config,
+    )
+    .unwrap();
+
+    let book = fs::read_to_string(&book_path).unwrap();
+    assert!(
+      book.contains(
+        "@JoinColumn(name = \"author_id\", nullable = true, foreignKey = @ForeignKey(name = \"fk_book_author\"))"
+      ),
+      "{}",
+      book
+    );
+    assert!(book.contains("import jakarta.persistence.ForeignKey;"), "{}", book);
+    assert!(!book.contains("ConstraintMode"), "{}", book);
+  }
+
+  #[test]
+  fn test_one_to_one_renders_no_constraint_foreign_key() {
+    let (project, book_path) = setup_project();
+    let config = OneToOneFieldConfig::from_json(
+      r#"{"inverseFieldType": "Author", "mappingType": "unidirectional_join_column",
+          "noForeignKeyConstraint": true}"#,
+    )
+    .unwrap();
+
+    create_jpa_one_to_one_relationship_service::run(
+      project.path(),
+      &BASE64_STANDARD.encode(BOOK_SOURCE),
+      &book_path,
+      "author",
+      "book",
+      &config,
+    )
+    .unwrap();
+
+    let book = fs::read_to_string(&book_path).unwrap();
+    assert!(book.contains("foreignKey = @ForeignKey(ConstraintMode.NO_CONSTRAINT))"), "{}", book);
+    assert!(book.contains("import jakarta.persistence.ConstraintMode;"), "{}", book);
+    assert!(book.contains("import jakarta.persistence.ForeignKey;"), "{}", book);
+  }
+
+  #[test]
+  fn test_required_many_to_one_renders_optional_and_nullable_together() {
+    let (project, book_path) = setup_project();
+    let config = ManyToOneFieldConfig::from_json(
+      r#"{"inverseFieldType": "Author", "fetchType": "lazy", "collectionType": "list",
+          "mappingType": "unidirectional_join_column", "required": true}"#,
+    )
+    .unwrap();
+
+    create_jpa_many_to_one_relationship_service::run(
+      project.path(),
+      &BASE64_STANDARD.encode(BOOK_SOURCE),
+      &book_path,
+      "author",
+      "books",
+      &config,
+    )
+    .unwrap();
+
+    let book = fs::read_to_string(&book_path).unwrap();
+    assert!(book.contains("@ManyToOne(fetch = FetchType.LAZY, optional = false)"), "{}", book);
+    assert!(book.contains("@JoinColumn(name = \"author_id\", nullable = false)"), "{}", book);
+  }
+
+  #[test]
+  fn test_required_one_to_one_keeps_mandatory_option_once() {
+    let (project, book_path) = setup_project();
+    let config = OneToOneFieldConfig::from_json(
+      r#"{"inverseFieldType": "Author", "mappingType": "unidirectional_join_column",
+          "owningSideOther": ["mandatory"], "required": true}"#,
+    )
+    .unwrap();
+
+    create_jpa_one_to_one_relationship_service::run(
+      project.path(),
+      &BASE64_STANDARD.encode(BOOK_SOURCE),
+      &book_path,
+      "author",
+      "book",
+      &config,
+    )
+    .unwrap();
+
+    let book = fs::read_to_string(&book_path).unwrap();
+    assert_eq!(config.get_owning_side_other(), vec![OtherType::Mandatory]);
+    assert!(book.contains("@OneToOne(optional = false)"), "{}", book);
+    assert!(book.contains("@JoinColumn(name = \"author_id\", nullable = false)"), "{}", book);
+  }
+
+  #[test]
+  fn test_foreign_key_name_conflicts_with_no_constraint() {
+    let config = OneToOneFieldConfig::from_json(
+      r#"{"inverseFieldType": "Author", "foreignKeyName": "fk_a", "noForeignKeyConstraint": true}"#,
+    )
+    .unwrap();
+    let invalid_name =
+      OneToOneFieldConfig::from_json(r#"{"inverseFieldType": "Author", "foreignKeyName": "1fk"}"#)
+        .unwrap();
+
+    assert!(config.get_foreign_key().is_err());
+    assert!(invalid_name.get_foreign_key().is_err());
+    assert_eq!(
+      OneToOneFieldConfig::from_json(r#"{"inverseFieldType": "Author"}"#)
+        .unwrap()
+        .get_foreign_key(),
+      Ok(None)
+    );
+  }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod relationship_kind_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::services::create_jpa_many_to_one_relationship_service;
+  use syntaxpresso_core::commands::java::treesitter::types::cascade_type::CascadeType;
+  use syntaxpresso_core::commands::java::treesitter::types::collection_type::CollectionType;
+  use syntaxpresso_core::commands::java::treesitter::types::entity_side::EntitySide;
+  use syntaxpresso_core::commands::java::treesitter::types::fetch_type::FetchType;
+  use syntaxpresso_core::commands::java::treesitter::types::many_to_one_field_config::ManyToOneFieldConfig;
+  use syntaxpresso_core::commands::java::treesitter::types::other_type::OtherType;
+  use syntaxpresso_core::commands::java::treesitter::types::relationship_kind::RelationshipKind;
+  use tempfile::TempDir;
+
+  #[test]
+  fn test_many_to_one_rejects_cascade_remove_and_orphan_removal() {
+    let kind = RelationshipKind::ManyToOne;
+
+    let remove = kind.validate_options(&EntitySide::Owning, &[CascadeType::Remove], &[]);
+    let all = kind.validate_options(&EntitySide::Owning, &[CascadeType::All], &[]);
+    let orphan_removal =
+      kind.validate_options(&EntitySide::Owning, &[], &[OtherType::OrphanRemoval]);
+
+    assert_eq!(remove.unwrap_err(), "Cascade type 'REMOVE' is not allowed on @ManyToOne");
+    assert!(all.is_err());
+    assert_eq!(
+      orphan_removal.unwrap_err(),
+      "Option 'orphan_removal' is not allowed on the owning side of @ManyToOne"
+    );
+    assert!(
+      kind
+        .validate_options(
+          &EntitySide::Owning,
+          &[CascadeType::Persist, CascadeType::Merge],
+          &[OtherType::Mandatory, OtherType::Unique],
+        )
+        .is_ok()
+    );
+  }
+
+  #[test]
+  fn test_one_to_one_allows_unique_on_owning_side_only() {
+    let kind = RelationshipKind::OneToOne;
+    let others = [OtherType::Unique, OtherType::OrphanRemoval];
+
+    assert!(kind.validate_options(&EntitySide::Owning, &[CascadeType::All], &others).is_ok());
+    assert!(kind.validate_options(&EntitySide::Inverse, &[CascadeType::All], &others).is_err());
+  }
+
+  #[test]
+  fn test_many_to_one_service_rejects_illegal_cascade_before_editing() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let source = "package com.example;\n\n@Entity\npublic class Book {\n  private Long id;\n}\n";
+    let entity_file_path = temp_dir.path().join("Book.java");
+    fs::write(&entity_file_path, source).unwrap();
+    let field_config = ManyToOneFieldConfig {
+      inverse_field_type: "Author".to_string(),
+      fetch_type: FetchType::Lazy,
+      collection_type: CollectionType::List,
+      mapping_type: None,
+      owning_side_cascades: vec![CascadeType::Persist, CascadeType::Remove],
+      inverse_side_cascades: Vec::new(),
+      owning_side_other: Vec::new(),
+      inverse_side_other: Vec::new(),
+      foreign_key_name: None,
+      no_foreign_key_constraint: false,
+      required: false,
+      insertable: None,
+      updatable: None,
+      map_key_type: None,
+      map_key_column: None,
+    };
+
+    let result = create_jpa_many_to_one_relationship_service::run(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(source),
+      &entity_file_path,
+      "author",
+      "books",
+      &field_config,
+    );
+
+    assert_eq!(result.err().unwrap(), "Cascade type 'REMOVE' is not allowed on @ManyToOne");
+    assert_eq!(fs::read_to_string(&entity_file_path).unwrap(), source);
+  }
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod remove_annotation_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::remove_annotation_response::RemoveAnnotationResponse;
+  use syntaxpresso_core::commands::java::services::remove_annotation_service::run;
+  use tempfile::TempDir;
+
+  const ENTITY_SOURCE: &str = r#"package com.example;
+
+import jakarta.persistence.Column;
+import jakarta.persistence.Entity;
+import jakarta.persistence.Transient;
+
+@Deprecated
+@Entity
+public class Article {
+  @Column(name = "title")
+  @Transient
+  private String title;
+
+  @Transient
+  private String preview;
+
+  @Column(
+      name = "body",
+      length = 4000)
+  @Deprecated private String body;
+}
+"#;
+
+  fn remove(
+    field_name: Option<&str>,
+    annotation_name: &str,
+    remove_import: bool,
+  ) -> (Result<RemoveAnnotationResponse, String>, String) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("Article.java");
+    fs::write(&file_path, ENTITY_SOURCE).unwrap();
+    let b64_source = BASE64_STANDARD.encode(ENTITY_SOURCE);
+    let response = run(
+      temp_dir.path(),
+      &b64_source,
+      &file_path,
+      None,
+      field_name,
+      annotation_name,
+      remove_import,
+    );
+    (response, fs::read_to_string(&file_path).unwrap())
+  }
+
+  #[test]
+  fn test_removes_class_and_multi_line_field_annotations_without_blank_lines() {
+    let (class_response, class_source) = remove(None, "@Deprecated", false);
+    let (field_response, field_source) = remove(Some("body"), "Column", false);
+
+    assert_eq!(class_response.unwrap().removed_annotation, "@Deprecated");
+    assert!(
+      class_source
+        .contains("import jakarta.persistence.Transient;\n\n@Entity\npublic class Article {"),
+      "{}",
+      class_source
+    );
+    let field_response = field_response.unwrap();
+    assert!(field_response.annotation_removed);
+    assert_eq!(field_response.target_name, "body");
+    assert!(
+      field_source.contains("  private String preview;\n\n  @Deprecated private String body;\n}"),
+      "{}",
+      field_source
+    );
+  }
+
+  #[test]
+  fn test_removes_import_only_once_annotation_is_unused() {
+    let (title_response, title_source) = remove(Some("title"), "Transient", true);
+    let title_response = title_response.unwrap();
+
+    // `preview` still uses @Transient, so the import stays
+    assert!(!title_response.import_removed);
+    assert!(
+      title_source.contains("  @Column(name = \"title\")\n  private String title;"),
+      "{}",
+      title_source
+    );
+    assert!(title_source.contains("import jakarta.persistence.Transient;"));
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("Article.java");
+    fs::write(&file_path, &title_source).unwrap();
+    let response = run(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(&title_source),
+      &file_path,
+      None,
+      Some("preview"),
+      "Transient",
+      true,
+    )
+    .unwrap();
+    let source = fs::read_to_string(&file_path).unwrap();
+
+    assert!(response.import_removed);
+    assert_eq!(response.removed_import.as_deref(), Some("import jakarta.persistence.Transient;"));
+    assert!(source.contains("import jakarta.persistence.Entity;\n\n@Deprecated"), "{}", source);
+  }
+
+  #[test]
+  fn test_errors_when_annotation_is_missing() {
+    let (response, source) = remove(Some("preview"), "Column", true);
+
+    assert_eq!(response.err().unwrap(), "'preview' is not annotated with @Column");
+    assert_eq!(source, ENTITY_SOURCE);
+  }
+}
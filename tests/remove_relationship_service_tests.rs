@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod remove_relationship_service_tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::services::remove_relationship_service::run;
+  use tempfile::TempDir;
+
+  const BOOK_SOURCE: &str = "package com.example;\n\nimport jakarta.persistence.Entity;\nimport jakarta.persistence.FetchType;\nimport jakarta.persistence.JoinColumn;\nimport jakarta.persistence.ManyToOne;\n\n@Entity\npublic class Book {\n  @Id\n  private Long id;\n\n  @ManyToOne(fetch = FetchType.LAZY)\n  @JoinColumn(name = \"writer_id\")\n  private Author author;\n}\n";
+
+  const AUTHOR_SOURCE: &str = "package com.example;\n\nimport jakarta.persistence.Entity;\nimport jakarta.persistence.OneToMany;\nimport java.util.List;\n\n@Entity\npublic class Author {\n  @Id\n  private Long id;\n\n  @OneToMany(mappedBy = \"author\")\n  private List<Book> books;\n}\n";
+
+  fn create_project() -> (TempDir, PathBuf, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    let book_path = package_dir.join("Book.java");
+    let author_path = package_dir.join("Author.java");
+    fs::write(&book_path, BOOK_SOURCE).unwrap();
+    fs::write(&author_path, AUTHOR_SOURCE).unwrap();
+    (temp_dir, book_path, author_path)
+  }
+
+  #[test]
+  fn test_removing_owning_field_removes_inverse_field_and_imports() {
+    let (temp_dir, book_path, author_path) = create_project();
+
+    let files =
+      run(temp_dir.path(), &BASE64_STANDARD.encode(BOOK_SOURCE), &book_path, "author", false)
+        .unwrap();
+
+    assert_eq!(files.len(), 2);
+    assert_eq!(
+      files[0].warnings,
+      vec![
+        "Removing 'author' drops the mapping of the 'writer_id' foreign key column, which stays \
+         in the schema until a migration drops it"
+          .to_string()
+      ]
+    );
+    assert_eq!(PathBuf::from(&files[1].file_path), author_path);
+    assert_eq!(
+      fs::read_to_string(&book_path).unwrap(),
+      "package com.example;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Book {\n  @Id\n  private Long id;\n}\n"
+    );
+    assert_eq!(
+      fs::read_to_string(&author_path).unwrap(),
+      "package com.example;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Author {\n  @Id\n  private Long id;\n}\n"
+    );
+  }
+
+  #[test]
+  fn test_owning_only_leaves_inverse_field_in_place() {
+    let (temp_dir, book_path, author_path) = create_project();
+
+    let files =
+      run(temp_dir.path(), &BASE64_STANDARD.encode(BOOK_SOURCE), &book_path, "author", true)
+        .unwrap();
+
+    assert_eq!(files.len(), 1);
+    assert!(!fs::read_to_string(&book_path).unwrap().contains("author"));
+    assert_eq!(fs::read_to_string(&author_path).unwrap(), AUTHOR_SOURCE);
+  }
+
+  #[test]
+  fn test_removing_inverse_field_warns_on_owning_entity() {
+    let (temp_dir, book_path, author_path) = create_project();
+
+    let files =
+      run(temp_dir.path(), &BASE64_STANDARD.encode(AUTHOR_SOURCE), &author_path, "books", false)
+        .unwrap();
+
+    assert!(files[0].warnings.is_empty());
+    assert_eq!(PathBuf::from(&files[1].file_path), book_path);
+    assert_eq!(files[1].warnings.len(), 1);
+    assert!(!fs::read_to_string(&book_path).unwrap().contains("ManyToOne"));
+    let owning_only_error =
+      run(temp_dir.path(), &BASE64_STANDARD.encode(AUTHOR_SOURCE), &author_path, "books", true);
+    assert_eq!(
+      owning_only_error.err(),
+      Some(
+        "Field 'books' is the inverse side of the relationship, mapped by 'Book.author'"
+          .to_string()
+      )
+    );
+  }
+
+  #[test]
+  fn test_warns_about_leftover_references_to_removed_inverse_field() {
+    let (temp_dir, book_path, author_path) = create_project();
+    let author_source = AUTHOR_SOURCE.replace(
+      "  private List<Book> books;\n",
+      "  private List<Book> books;\n\n  @Override\n  public String toString() {\n    List<Book> others = null;\n    return \"Author\" + books + this.books.size() + others;\n  }\n",
+    );
+    fs::write(&author_path, &author_source).unwrap();
+
+    let files =
+      run(temp_dir.path(), &BASE64_STANDARD.encode(BOOK_SOURCE), &book_path, "author", false)
+        .unwrap();
+
+    assert_eq!(PathBuf::from(&files[1].file_path), author_path);
+    assert_eq!(
+      files[1].warnings,
+      vec![
+        "Field 'books' was removed but is still referenced at line 14, update them before compiling"
+          .to_string()
+      ]
+    );
+  }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod rename_column_service_tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::rename_column_response::RenameColumnResponse;
+  use syntaxpresso_core::commands::java::services::rename_column_service::run;
+  use tempfile::TempDir;
+
+  const ENTITY_SOURCE: &str = "package com.example;\n\nimport jakarta.persistence.Entity;\n\n@Entity\n@Table(name = \"customers\")\npublic class Customer {\n  @Id\n  private Long id;\n\n  @Column(name = \"full_name\", length = 80)\n  private String fullName;\n\n  @ManyToOne\n  private Region region;\n\n  @OneToMany(mappedBy = \"customer\")\n  private List<Order> orders;\n\n  @Embedded\n  private Address address;\n}\n";
+
+  fn setup_entity() -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    let entity_file_path = package_dir.join("Customer.java");
+    fs::write(&entity_file_path, ENTITY_SOURCE).unwrap();
+    (temp_dir, entity_file_path)
+  }
+
+  fn rename_column(
+    field_name: &str,
+    column_name: &str,
+  ) -> (Result<RenameColumnResponse, String>, String) {
+    let (temp_dir, entity_file_path) = setup_entity();
+    let b64_source = BASE64_STANDARD.encode(ENTITY_SOURCE);
+    let result = run(temp_dir.path(), &b64_source, &entity_file_path, field_name, column_name);
+    (result, fs::read_to_string(&entity_file_path).unwrap())
+  }
+
+  #[test]
+  fn test_renames_column_and_warns_about_migration() {
+    let (result, written) = rename_column("fullName", "cust_name");
+
+    let response = result.unwrap();
+    assert_eq!(response.old_column_name, "full_name");
+    assert_eq!(response.new_column_name, "cust_name");
+    assert!(!response.is_join_column);
+    assert!(response.migration_required);
+    assert_eq!(
+      response.warnings,
+      vec![
+        "Column 'full_name' of table 'customers' must be renamed to 'cust_name' by a database migration"
+      ]
+    );
+    assert!(
+      written.contains("  @Column(name = \"cust_name\", length = 80)\n  private String fullName;"),
+      "{}",
+      written
+    );
+
+    let (unchanged, unchanged_source) = rename_column("fullName", "full_name");
+    let unchanged = unchanged.unwrap();
+    assert!(!unchanged.migration_required);
+    assert!(unchanged.warnings.is_empty());
+    assert_eq!(unchanged_source, ENTITY_SOURCE);
+  }
+
+  #[test]
+  fn test_renames_join_column_of_relationship() {
+    let (result, written) = rename_column("region", "region_code");
+
+    let response = result.unwrap();
+    assert!(response.is_join_column);
+    assert!(response.annotation_added);
+    assert_eq!(response.old_column_name, "region_id");
+    assert!(
+      written
+        .contains("  @ManyToOne\n  @JoinColumn(name = \"region_code\")\n  private Region region;"),
+      "{}",
+      written
+    );
+    assert!(written.contains("import jakarta.persistence.JoinColumn;"), "{}", written);
+  }
+
+  #[test]
+  fn test_rejects_fields_without_a_column_of_their_own() {
+    assert_eq!(
+      rename_column("orders", "order_ids").0.err().unwrap(),
+      "Field 'orders' is a relationship without a join column, its columns belong to another table"
+    );
+    assert_eq!(
+      rename_column("address", "addr").0.err().unwrap(),
+      "Field 'address' is an embedded value, rename its columns with @AttributeOverride instead"
+    );
+  }
+}
@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod rename_java_class_service_tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use syntaxpresso_core::commands::java::services::rename_java_class_service::run;
+  use syntaxpresso_core::common::utils::scan_deadline::ScanDeadline;
+  use tempfile::TempDir;
+
+  const CUSTOMER_SOURCE: &str = r#"package com.example.domain;
+
+/** A Customer of the shop. */
+public class Customer {
+  private final String name;
+
+  public Customer(String name) {
+    this.name = name;
+  }
+
+  public static Customer of(String name) {
+    return new Customer(name);
+  }
+}
+"#;
+
+  const SERVICE_SOURCE: &str = r#"package com.example.service;
+
+import com.example.domain.Customer;
+import java.util.List;
+import java.util.function.Function;
+
+public class CustomerService {
+  // Loads every Customer
+  public List<Customer> findAll() {
+    Function<String, Customer> factory = Customer::of;
+    Customer customer = Customer.of("Customer");
+    com.example.domain.Customer other = new com.example.domain.Customer("other");
+    return List.of(customer, other, factory.apply("x"));
+  }
+}
+"#;
+
+  const OTHER_CUSTOMER_SOURCE: &str = r#"package com.example.legacy;
+
+public class Report {
+  private Customer customer;
+
+  static class Customer {}
+}
+"#;
+
+  fn setup() -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let java_dir = temp_dir.path().join("src/main/java/com/example");
+    for (relative_path, source) in [
+      ("domain/Customer.java", CUSTOMER_SOURCE),
+      ("service/CustomerService.java", SERVICE_SOURCE),
+      ("legacy/Report.java", OTHER_CUSTOMER_SOURCE),
+    ] {
+      let file_path = java_dir.join(relative_path);
+      fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+      fs::write(&file_path, source).unwrap();
+    }
+    let declaration_file_path = java_dir.join("domain/Customer.java");
+    (temp_dir, declaration_file_path)
+  }
+
+  #[test]
+  fn test_renames_declaration_file_and_references() {
+    let (temp_dir, file_path) = setup();
+
+    let response = run(temp_dir.path(), &file_path, "Client", &ScanDeadline::unbounded()).unwrap();
+
+    assert_eq!(response.files_count, 2);
+    assert_eq!(response.edits_count, 12);
+    let edits: Vec<usize> = response.files.iter().map(|file| file.edits_count).collect();
+    assert_eq!(edits, vec![4, 8]);
+    let new_file_path = file_path.with_file_name("Client.java");
+    assert_eq!(response.files[0].file_path, new_file_path.display().to_string());
+    assert!(!file_path.exists());
+    assert_eq!(
+      fs::read_to_string(&new_file_path).unwrap(),
+      CUSTOMER_SOURCE
+        .replace("public class Customer", "public class Client")
+        .replace("public Customer(String name)", "public Client(String name)")
+        .replace("static Customer of", "static Client of")
+        .replace("new Customer(name)", "new Client(name)")
+    );
+    let service_path =
+      temp_dir.path().join("src/main/java/com/example/service/CustomerService.java");
+    let service_text = fs::read_to_string(service_path).unwrap();
+    assert!(service_text.contains("import com.example.domain.Client;"), "{}", service_text);
+    assert!(service_text.contains("// Loads every Customer\n"), "{}", service_text);
+    assert!(service_text.contains("Function<String, Client> factory = Client::of;"));
+    assert!(service_text.contains("Client customer = Client.of(\"Customer\");"));
+    assert!(
+      service_text
+        .contains("com.example.domain.Client other = new com.example.domain.Client(\"other\");")
+    );
+    assert!(service_text.contains("public class CustomerService"));
+    let report_path = temp_dir.path().join("src/main/java/com/example/legacy/Report.java");
+    assert_eq!(fs::read_to_string(report_path).unwrap(), OTHER_CUSTOMER_SOURCE);
+  }
+
+  #[test]
+  fn test_rejects_name_taken_in_the_same_package() {
+    let (temp_dir, file_path) = setup();
+    fs::write(
+      file_path.with_file_name("Clients.java"),
+      "package com.example.domain;\n\nclass Client {}\n",
+    )
+    .unwrap();
+
+    let result = run(temp_dir.path(), &file_path, "Client", &ScanDeadline::unbounded());
+
+    assert_eq!(
+      result.err().unwrap(),
+      "Type 'Client' already exists in package 'com.example.domain'"
+    );
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), CUSTOMER_SOURCE);
+  }
+
+  #[test]
+  fn test_keeps_new_name_as_given_and_rejects_invalid_names() {
+    let (temp_dir, file_path) = setup();
+
+    let result = run(temp_dir.path(), &file_path, "customer-dto", &ScanDeadline::unbounded());
+    assert_eq!(result.err().unwrap(), "'customer-dto' is not a valid Java identifier");
+
+    let response =
+      run(temp_dir.path(), &file_path, "CustomerDTO", &ScanDeadline::unbounded()).unwrap();
+    assert_eq!(response.new_name, "CustomerDTO");
+    assert!(file_path.with_file_name("CustomerDTO.java").exists());
+  }
+
+  #[test]
+  fn test_aborts_without_writing_when_scan_times_out() {
+    let (temp_dir, file_path) = setup();
+
+    let result = run(temp_dir.path(), &file_path, "Client", &ScanDeadline::new(Some(0)));
+
+    assert_eq!(
+      result.err().unwrap(),
+      "Timed out scanning the project for references to 'Customer', no file was modified"
+    );
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), CUSTOMER_SOURCE);
+    assert!(!file_path.with_file_name("Client.java").exists());
+  }
+}
@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod rename_java_field_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::rename_java_field_response::RenameJavaFieldResponse;
+  use syntaxpresso_core::commands::java::services::rename_java_field_service::run;
+  use syntaxpresso_core::common::utils::scan_deadline::ScanDeadline;
+  use tempfile::TempDir;
+
+  const CUSTOMER_SOURCE: &str = r#"package com.example;
+
+@Entity
+public class Customer {
+  @Column(name = "full_name")
+  private String name;
+
+  private boolean active;
+
+  public String getName() {
+    return name;
+  }
+
+  public void setName(String name) {
+    this.name = name;
+  }
+
+  public boolean isActive() {
+    return active;
+  }
+
+  public String describe(String name) {
+    return this.name + " " + name + " " + getName().length();
+  }
+
+  public boolean sameAs(Customer other) {
+    return name.equals(other.name) && other.getName() != null;
+  }
+}
+"#;
+
+  fn rename(
+    source: &str,
+    line: usize,
+    column: usize,
+    new_name: &str,
+  ) -> (Result<RenameJavaFieldResponse, String>, String) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("Customer.java");
+    fs::write(&file_path, source).unwrap();
+    let result = run(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(source),
+      &file_path,
+      line,
+      column,
+      new_name,
+      &ScanDeadline::unbounded(),
+    );
+    (result, fs::read_to_string(&file_path).unwrap())
+  }
+
+  #[test]
+  fn test_renames_field_accessors_and_references() {
+    let (result, written) = rename(CUSTOMER_SOURCE, 6, 18, "fullName");
+
+    let response = result.unwrap();
+    assert_eq!(response.old_name, "name");
+    assert!(response.getter_renamed && response.setter_renamed);
+    let accessors: Vec<(&str, &str)> = response
+      .renamed_accessors
+      .iter()
+      .map(|accessor| (accessor.old_name.as_str(), accessor.new_name.as_str()))
+      .collect();
+    assert_eq!(accessors, vec![("getName", "getFullName"), ("setName", "setFullName")]);
+    assert_eq!(
+      written,
+      CUSTOMER_SOURCE
+        .replace("private String name;", "private String fullName;")
+        .replace(
+          "String getName() {\n    return name;",
+          "String getFullName() {\n    return fullName;"
+        )
+        .replace(
+          "setName(String name) {\n    this.name = name;",
+          "setFullName(String fullName) {\n    this.fullName = fullName;"
+        )
+        .replace(
+          "return this.name + \" \" + name + \" \" + getName()",
+          "return this.fullName + \" \" + name + \" \" + getFullName()"
+        )
+        .replace(
+          "return name.equals(other.name) && other.getName()",
+          "return fullName.equals(other.fullName) && other.getFullName()"
+        )
+    );
+    assert!(written.contains("@Column(name = \"full_name\")"));
+    assert_eq!(response.edits_count, 12);
+  }
+
+  #[test]
+  fn test_renames_boolean_getter_and_reports_missing_setter() {
+    let (result, written) = rename(CUSTOMER_SOURCE, 8, 3, "enabled");
+
+    let response = result.unwrap();
+    assert!(response.getter_renamed);
+    assert!(!response.setter_renamed);
+    assert!(
+      written.contains("public boolean isEnabled() {\n    return enabled;\n  }"),
+      "{}",
+      written
+    );
+    assert!(written.contains("private boolean enabled;"));
+  }
+
+  #[test]
+  fn test_rejects_position_without_field_and_taken_name() {
+    let (result, written) = rename(CUSTOMER_SOURCE, 10, 5, "title");
+    assert_eq!(result.err().unwrap(), "No field declaration found at line 10, column 5");
+    assert_eq!(written, CUSTOMER_SOURCE);
+
+    let (result, _) = rename(CUSTOMER_SOURCE, 6, 18, "active");
+    assert_eq!(result.err().unwrap(), "Field 'active' already exists in class 'Customer'");
+  }
+
+  #[test]
+  fn test_renames_accessor_calls_in_other_files() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let java_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(java_dir.join("legacy")).unwrap();
+    let file_path = java_dir.join("Customer.java");
+    fs::write(&file_path, CUSTOMER_SOURCE).unwrap();
+    let service_path = java_dir.join("CustomerService.java");
+    let service_source = "package com.example;\n\npublic class CustomerService {\n  public boolean check(Customer a, Order order) {\n    return a.isActive() && order.isActive() && find().isActive();\n  }\n}\n";
+    fs::write(&service_path, service_source).unwrap();
+    let legacy_path = java_dir.join("legacy/Report.java");
+    let legacy_source = "package com.example.legacy;\n\nimport com.example.legacy.model.Customer;\n\npublic class Report {\n  boolean check(Customer c) {\n    return c.isActive();\n  }\n}\n";
+    fs::write(&legacy_path, legacy_source).unwrap();
+
+    let response = run(
+      temp_dir.path(),
+      &BASE64_STANDARD.encode(CUSTOMER_SOURCE),
+      &file_path,
+      8,
+      3,
+      "enabled",
+      &ScanDeadline::unbounded(),
+    )
+    .unwrap();
+
+    assert_eq!(
+      fs::read_to_string(&service_path).unwrap(),
+      service_source.replace("a.isActive()", "a.isEnabled()")
+    );
+    assert_eq!(fs::read_to_string(&legacy_path).unwrap(), legacy_source);
+    assert_eq!(response.updated_files.len(), 1);
+    assert_eq!(response.updated_files[0].file_path, service_path.display().to_string());
+    assert_eq!(response.updated_files[0].edits_count, 1);
+    assert_eq!(
+      response.warnings,
+      vec![
+        "Call to 'isActive' in src/main/java/com/example/CustomerService.java at line 5 was left unchanged, its receiver type couldn't be resolved"
+      ]
+    );
+  }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod replace_annotation_argument_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::replace_annotation_argument_response::ReplaceAnnotationArgumentResponse;
+  use syntaxpresso_core::commands::java::services::replace_annotation_argument_service::run;
+  use tempfile::TempDir;
+
+  const ENTITY_SOURCE: &str = "package com.example;\n\n@Entity\n@Table(name = \"articles\")\npublic class Article {\n  @Column(name = \"title\", length = 255, nullable = false)\n  private String title;\n\n  private String summary;\n}\n";
+
+  fn replace_argument(
+    field_name: Option<&str>,
+    annotation_name: &str,
+    key: &str,
+    value: &str,
+  ) -> (Result<ReplaceAnnotationArgumentResponse, String>, String) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("Article.java");
+    fs::write(&file_path, ENTITY_SOURCE).unwrap();
+    let b64_source = BASE64_STANDARD.encode(ENTITY_SOURCE);
+    let response =
+      run(temp_dir.path(), &b64_source, &file_path, None, field_name, annotation_name, key, value);
+    (response, fs::read_to_string(&file_path).unwrap())
+  }
+
+  #[test]
+  fn test_replaces_existing_field_annotation_argument() {
+    let (response, source) = replace_argument(Some("title"), "Column", "length", "500");
+
+    let response = response.unwrap();
+    assert!(!response.argument_added);
+    assert_eq!(response.target_name, "title");
+    assert_eq!(
+      response.annotation_before,
+      "@Column(name = \"title\", length = 255, nullable = false)"
+    );
+    assert_eq!(
+      response.annotation_after,
+      "@Column(name = \"title\", length = 500, nullable = false)"
+    );
+    assert!(source.contains("  @Column(name = \"title\", length = 500, nullable = false)\n"));
+  }
+
+  #[test]
+  fn test_adds_missing_class_annotation_argument() {
+    let (response, source) = replace_argument(None, "@Table", "schema", "\"blog\"");
+
+    let response = response.unwrap();
+    assert!(response.argument_added);
+    assert_eq!(response.target_name, "Article");
+    assert_eq!(response.annotation_after, "@Table(name = \"articles\", schema = \"blog\")");
+    assert!(
+      source.contains("@Entity\n@Table(name = \"articles\", schema = \"blog\")\npublic class")
+    );
+  }
+
+  #[test]
+  fn test_errors_when_annotation_is_absent() {
+    let (response, source) = replace_argument(Some("summary"), "Column", "length", "500");
+
+    assert_eq!(response.err().unwrap(), "'summary' is not annotated with @Column");
+    assert_eq!(source, ENTITY_SOURCE);
+  }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod resolve_import_for_type_service_tests {
+  use std::fs;
+
+  use syntaxpresso_core::commands::java::responses::resolve_import_for_type_response::{
+    ImportCandidateSource, ResolveImportForTypeResponse,
+  };
+  use syntaxpresso_core::commands::java::services::resolve_import_for_type_service::run;
+  use syntaxpresso_core::common::utils::scan_deadline::ScanDeadline;
+  use tempfile::TempDir;
+
+  fn resolve(files: &[(&str, &str)], type_name: &str) -> ResolveImportForTypeResponse {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    for (relative_path, source) in files {
+      let file_path = temp_dir.path().join(relative_path);
+      fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+      fs::write(file_path, source).unwrap();
+    }
+    run(temp_dir.path(), type_name, &ScanDeadline::unbounded()).unwrap()
+  }
+
+  fn imports(
+    response: &ResolveImportForTypeResponse,
+  ) -> Vec<(Option<&str>, ImportCandidateSource)> {
+    response
+      .candidates
+      .iter()
+      .map(|candidate| (candidate.import_statement.as_deref(), candidate.source))
+      .collect()
+  }
+
+  #[test]
+  fn test_ranks_project_types_before_jdk_types() {
+    let response = resolve(
+      &[
+        (
+          "src/main/java/com/example/order/Order.java",
+          "package com.example.order;\n\npublic class Order {\n  public enum Status { OPEN }\n}\n",
+        ),
+        (
+          "src/test/java/com/example/support/Status.java",
+          "package com.example.support;\n\npublic class Status {}\n",
+        ),
+      ],
+      "Status",
+    );
+
+    assert!(response.is_ambiguous);
+    assert_eq!(
+      imports(&response),
+      vec![
+        (Some("import com.example.order.Order.Status;"), ImportCandidateSource::Project),
+        (Some("import com.example.support.Status;"), ImportCandidateSource::Project),
+      ]
+    );
+    assert!(response.candidates[0].file_path.as_deref().unwrap().ends_with("Order.java"));
+  }
+
+  #[test]
+  fn test_resolves_jdk_and_library_types() {
+    let response = resolve(&[], "LocalDate");
+    assert!(!response.is_ambiguous);
+    assert_eq!(
+      imports(&response),
+      vec![(Some("import java.time.LocalDate;"), ImportCandidateSource::Jdk)]
+    );
+
+    let response = resolve(&[], "Date");
+    assert_eq!(
+      imports(&response),
+      vec![
+        (Some("import java.util.Date;"), ImportCandidateSource::Jdk),
+        (Some("import java.sql.Date;"), ImportCandidateSource::Jdk),
+      ]
+    );
+
+    let legacy_entity =
+      "package com.example;\n\nimport javax.persistence.Id;\n\npublic class Legacy {}\n";
+    let response = resolve(&[("src/main/java/com/example/Legacy.java", legacy_entity)], "Entity");
+    assert_eq!(
+      imports(&response),
+      vec![
+        (Some("import javax.persistence.Entity;"), ImportCandidateSource::Library),
+        (Some("import jakarta.persistence.Entity;"), ImportCandidateSource::Library),
+      ]
+    );
+    assert_eq!(imports(&resolve(&[], "String")), vec![(None, ImportCandidateSource::Jdk)]);
+  }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod scan_cache_tests {
+  use std::fs;
+  use std::path::{Path, PathBuf};
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  use syntaxpresso_core::commands::java::services::generate_schema_service::{
+    clear_project_mappings_cache, load_project_mappings,
+  };
+  use syntaxpresso_core::common::utils::scan_cache::ScanCache;
+  use syntaxpresso_core::common::utils::scan_deadline::ScanDeadline;
+  use tempfile::TempDir;
+
+  fn write_entity(package_dir: &Path, class_name: &str, fields: &str) -> PathBuf {
+    let file_path = package_dir.join(format!("{}.java", class_name));
+    let source = format!(
+      "package com.example;\n\n@Entity\npublic class {} {{\n  @Id\n  private Long id;\n{}}}\n",
+      class_name, fields
+    );
+    fs::write(&file_path, source).unwrap();
+    file_path
+  }
+
+  fn setup_project() -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    (temp_dir, package_dir)
+  }
+
+  #[test]
+  fn test_scan_does_not_reparse_unchanged_files() {
+    let (project, package_dir) = setup_project();
+    let paths =
+      vec![write_entity(&package_dir, "Author", ""), write_entity(&package_dir, "Book", "")];
+    let cache: ScanCache<String> = ScanCache::new();
+    let parse_count = AtomicUsize::new(0);
+    let parse = |path: &Path| {
+      parse_count.fetch_add(1, Ordering::Relaxed);
+      fs::read_to_string(path).ok()
+    };
+
+    cache.scan(project.path(), &paths, &ScanDeadline::unbounded(), parse);
+    cache.scan(project.path(), &paths, &ScanDeadline::unbounded(), parse);
+    assert_eq!(parse_count.load(Ordering::Relaxed), 2);
+
+    write_entity(&package_dir, "Book", "  private String title;\n");
+    let results = cache.scan(project.path(), &paths, &ScanDeadline::unbounded(), parse);
+
+    assert_eq!(parse_count.load(Ordering::Relaxed), 3);
+    assert!(results[1].contains("private String title;"));
+    assert_eq!(cache.get_cached_files_count(project.path()), 2);
+  }
+
+  #[test]
+  fn test_load_project_mappings_reflects_changed_and_deleted_files() {
+    let (project, package_dir) = setup_project();
+    write_entity(&package_dir, "Author", "");
+    let book_path = write_entity(&package_dir, "Book", "");
+    let first = load_project_mappings(project.path(), &ScanDeadline::unbounded());
+    assert_eq!(first.classes["Author"].columns.len(), 1);
+
+    write_entity(&package_dir, "Author", "  private String name;\n");
+    fs::remove_file(book_path).unwrap();
+    let second = load_project_mappings(project.path(), &ScanDeadline::unbounded());
+
+    assert_eq!(second.classes["Author"].columns.len(), 2);
+    assert!(!second.classes.contains_key("Book"));
+  }
+
+  #[test]
+  fn test_clear_project_mappings_cache_drops_cached_files() {
+    let (project, package_dir) = setup_project();
+    write_entity(&package_dir, "Author", "");
+    write_entity(&package_dir, "Book", "");
+    load_project_mappings(project.path(), &ScanDeadline::unbounded());
+
+    assert_eq!(clear_project_mappings_cache(Some(project.path())), 2);
+    assert_eq!(clear_project_mappings_cache(Some(project.path())), 0);
+  }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod scan_deadline_tests {
+  use std::fs;
+
+  use syntaxpresso_core::commands::java::get_all_jpa_entities_command;
+  use syntaxpresso_core::commands::java::services::generate_schema_service;
+  use syntaxpresso_core::commands::java::treesitter::types::sql_dialect::SqlDialect;
+  use syntaxpresso_core::common::utils::scan_deadline::ScanDeadline;
+  use tempfile::TempDir;
+
+  fn setup_project() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    for entity in ["Author", "Book", "Publisher"] {
+      let source = format!(
+        "package com.example;\n\n@Entity\npublic class {} {{\n  @Id\n  private Long id;\n}}\n",
+        entity
+      );
+      fs::write(package_dir.join(format!("{}.java", entity)), source).unwrap();
+    }
+    temp_dir
+  }
+
+  #[test]
+  fn test_unbounded_deadline_never_expires() {
+    let deadline = ScanDeadline::unbounded();
+
+    assert!(!deadline.is_expired());
+    assert!(!deadline.is_truncated());
+  }
+
+  #[test]
+  fn test_scan_without_timeout_is_complete() {
+    let project = setup_project();
+
+    let response = get_all_jpa_entities_command::execute(project.path(), None, false);
+
+    let data = response.data.expect("Scan should succeed");
+    assert!(!data.truncated);
+    assert_eq!(data.files_count, 3);
+    assert_eq!(data.processed_files_count, Some(3));
+  }
+
+  #[test]
+  fn test_scan_with_tiny_timeout_is_truncated() {
+    let project = setup_project();
+
+    let response = get_all_jpa_entities_command::execute(project.path(), Some(0), false);
+
+    let data = response.data.expect("A timed out scan still succeeds with partial results");
+    assert!(data.truncated);
+    assert_eq!(data.processed_files_count, Some(0));
+    assert!(data.files.is_empty());
+  }
+
+  #[test]
+  fn test_parallel_scan_with_tiny_timeout_is_truncated() {
+    let project = setup_project();
+
+    let response = generate_schema_service::run(
+      project.path(),
+      &SqlDialect::Postgresql,
+      &ScanDeadline::new(Some(0)),
+    )
+    .unwrap();
+
+    assert!(response.truncated);
+    assert_eq!(response.processed_files_count, 0);
+    assert!(response.tables.is_empty());
+  }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod serve_tests {
+  use std::fs;
+  use std::io::Write;
+  use std::process::{Command, Stdio};
+
+  use serde_json::{Value, json};
+  use tempfile::TempDir;
+
+  /// Pipes the request lines to `serve --stdin-json` and returns the parsed response lines.
+  fn serve(request_lines: &[String]) -> Vec<Value> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_syntaxpresso-core"))
+      .args(["serve", "--stdin-json"])
+      .stdin(Stdio::piped())
+      .stdout(Stdio::piped())
+      .spawn()
+      .expect("Failed to start serve mode");
+    let mut stdin = child.stdin.take().unwrap();
+    for line in request_lines {
+      writeln!(stdin, "{}", line).unwrap();
+    }
+    drop(stdin);
+    let output = child.wait_with_output().unwrap();
+    String::from_utf8(output.stdout)
+      .unwrap()
+      .lines()
+      .map(|line| serde_json::from_str(line).expect("Response line should be JSON"))
+      .collect()
+  }
+
+  #[test]
+  fn test_serve_echoes_request_ids_in_order() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let cwd = temp_dir.path().display().to_string();
+    let requests = [
+      json!({"id": 1, "args": ["java", "clear-scan-cache", "--cwd", cwd]}).to_string(),
+      json!({"id": "second", "args": ["java", "get-java-files", "--cwd", cwd, "--file-type", "class"]})
+        .to_string(),
+    ];
+
+    let responses = serve(&requests);
+
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0]["id"], json!(1));
+    assert_eq!(responses[0]["command"], "clear-scan-cache");
+    assert_eq!(responses[0]["succeed"], true);
+    assert_eq!(responses[1]["id"], json!("second"));
+    assert_eq!(responses[1]["command"], "get-java-files");
+  }
+
+  #[test]
+  fn test_malformed_requests_get_error_responses_with_parseable_ids() {
+    let requests = [
+      r#"{"id": 3, "args": ["java", "nope"]}"#.to_string(),
+      r#"{"id": {"seq": 4}}"#.to_string(),
+      r#"{"id": 5, "#.to_string(),
+      r#"{"id": 6, "args": ["serve"]}"#.to_string(),
+    ];
+
+    let responses = serve(&requests);
+
+    assert_eq!(responses.len(), 4);
+    assert_eq!(responses[0]["id"], json!(3));
+    assert_eq!(responses[0]["error"], "invalid_request");
+    assert_eq!(responses[1]["id"], json!({"seq": 4}));
+    assert_eq!(responses[1]["message"], "Request has no args array");
+    assert_eq!(responses[2]["id"], Value::Null);
+    assert_eq!(responses[2]["error"], "invalid_request");
+    assert_eq!(responses[3]["message"], "Serve mode can't be started from a serve request");
+  }
+
+  #[test]
+  fn test_serve_writes_progress_lines_before_response() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    fs::write(package_dir.join("A.java"), "package com.example;\n\npublic class A {}\n").unwrap();
+    fs::write(package_dir.join("B.java"), "package com.example;\n\npublic class B {}\n").unwrap();
+    let cwd = temp_dir.path().display().to_string();
+    let requests = [
+      json!({"id": 3, "args": ["java", "migrate-to-jakarta", "--cwd", cwd, "--progress"]})
+        .to_string(),
+      json!({"id": 4, "args": ["java", "migrate-to-jakarta", "--cwd", cwd]}).to_string(),
+    ];
+
+    let responses = serve(&requests);
+
+    assert_eq!(responses.len(), 4);
+    assert_eq!(
+      responses[0],
+      json!({"id": 3, "event": "progress", "phase": "migrating", "current": 1, "total": 2})
+    );
+    assert_eq!(responses[1]["current"], 2);
+    assert_eq!(responses[2]["id"], 3);
+    assert_eq!(responses[2]["command"], "migrate-to-jakarta");
+    assert_eq!(responses[3]["id"], 4);
+    assert!(responses[3].get("event").is_none());
+  }
+}
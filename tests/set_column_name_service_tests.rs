@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod set_column_name_service_tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::set_column_name_response::SetColumnNameResponse;
+  use syntaxpresso_core::commands::java::services::set_column_name_service::run;
+  use tempfile::TempDir;
+
+  const ENTITY_SOURCE: &str = "package com.example;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Customer {\n  @Id\n  private Long id;\n\n  @Column(length = 80, nullable = false)\n  private String fullName;\n\n  @NotNull\n  private String email;\n\n  @ManyToOne\n  private Region region;\n}\n";
+
+  fn setup_entity() -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    let entity_file_path = package_dir.join("Customer.java");
+    fs::write(&entity_file_path, ENTITY_SOURCE).unwrap();
+    (temp_dir, entity_file_path)
+  }
+
+  fn set_column_name(
+    field_name: &str,
+    column_name: &str,
+    quote_mixed_case: bool,
+  ) -> (Result<SetColumnNameResponse, String>, String) {
+    let (temp_dir, entity_file_path) = setup_entity();
+    let b64_source = BASE64_STANDARD.encode(ENTITY_SOURCE);
+    let result = run(
+      temp_dir.path(),
+      &b64_source,
+      &entity_file_path,
+      field_name,
+      column_name,
+      quote_mixed_case,
+    );
+    (result, fs::read_to_string(&entity_file_path).unwrap())
+  }
+
+  #[test]
+  fn test_sets_name_keeping_other_column_attributes() {
+    let (result, written) = set_column_name("fullName", "cust_name", false);
+
+    let response = result.unwrap();
+    assert_eq!(response.previous_column_name, "full_name");
+    assert_eq!(response.column_name, "cust_name");
+    assert!(!response.column_annotation_added);
+    assert!(
+      written.contains(
+        "  @Column(length = 80, nullable = false, name = \"cust_name\")\n  private String fullName;"
+      ),
+      "{}",
+      written
+    );
+  }
+
+  #[test]
+  fn test_adds_column_annotation_and_import_when_absent() {
+    let (result, written) = set_column_name("email", "\"E-Mail\"", false);
+
+    let response = result.unwrap();
+    assert_eq!(response.previous_column_name, "email");
+    assert!(response.column_annotation_added);
+    assert!(
+      written.contains("  @NotNull\n  @Column(name = \"\\\"E-Mail\\\"\")\n  private String email;"),
+      "{}",
+      written
+    );
+    assert!(written.contains("import jakarta.persistence.Column;"), "{}", written);
+  }
+
+  #[test]
+  fn test_rejects_relationship_fields_and_invalid_names() {
+    let (result, written) = set_column_name("region", "region_code", false);
+    assert_eq!(
+      result.err().unwrap(),
+      "Field 'region' is a relationship, set its column with @JoinColumn instead"
+    );
+    assert_eq!(written, ENTITY_SOURCE);
+
+    let (result, _) = set_column_name("email", "e-mail", false);
+    assert!(result.err().unwrap().contains("is not a valid SQL identifier"));
+  }
+
+  #[test]
+  fn test_escapes_backslashes_in_quoted_names() {
+    let (result, written) = set_column_name("email", r#""mail\box""#, false);
+
+    assert!(result.is_ok());
+    assert!(written.contains(r#"@Column(name = "\"mail\\box\"")"#), "{}", written);
+  }
+
+  #[test]
+  fn test_mixed_case_column_name_is_quoted_on_request() {
+    let (result, written) = set_column_name("fullName", "FullName", true);
+
+    let response = result.unwrap();
+    assert_eq!(response.column_name, "\"FullName\"");
+    assert!(
+      written.contains("@Column(length = 80, nullable = false, name = \"\\\"FullName\\\"\")"),
+      "{}",
+      written
+    );
+    assert_eq!(
+      response.warnings,
+      vec![
+        "MIXED_CASE_COLUMN_NAME: Column name 'FullName' was quoted, native queries and migrations \
+         have to quote it as \"FullName\" too"
+          .to_string()
+      ]
+    );
+  }
+
+  #[test]
+  fn test_mixed_case_column_name_is_kept_unquoted_with_a_warning() {
+    let (result, written) = set_column_name("fullName", "FullName", false);
+
+    let response = result.unwrap();
+    assert_eq!(response.column_name, "FullName");
+    assert!(written.contains("name = \"FullName\")"), "{}", written);
+    assert_eq!(response.warnings.len(), 1);
+    assert!(response.warnings[0].contains("use --quote-mixed-case to quote it"));
+  }
+}
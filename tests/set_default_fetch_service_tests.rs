@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod set_default_fetch_service_tests {
+  use std::fs;
+
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::set_default_fetch_response::SetDefaultFetchResponse;
+  use syntaxpresso_core::commands::java::services::set_default_fetch_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::fetch_type::FetchType;
+  use syntaxpresso_core::commands::java::treesitter::types::relationship_kind::RelationshipKind;
+  use tempfile::TempDir;
+
+  const ENTITY_SOURCE: &str = "package com.example;\n\nimport jakarta.persistence.Entity;\n\n@Entity\npublic class Book {\n  @ManyToOne\n  private Author author;\n\n  @OneToOne(fetch = FetchType.EAGER, optional = false)\n  private Cover cover;\n\n  @OneToMany(mappedBy = \"book\")\n  private List<Review> reviews;\n}\n";
+
+  fn set_fetch(
+    fetch_type: FetchType,
+    relationship_kinds: &[RelationshipKind],
+  ) -> (SetDefaultFetchResponse, String) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let entity_file_path = temp_dir.path().join("Book.java");
+    fs::write(&entity_file_path, ENTITY_SOURCE).unwrap();
+    let b64_source = BASE64_STANDARD.encode(ENTITY_SOURCE);
+    let response =
+      run(temp_dir.path(), &b64_source, &entity_file_path, &fetch_type, relationship_kinds)
+        .unwrap();
+    (response, fs::read_to_string(&entity_file_path).unwrap())
+  }
+
+  #[test]
+  fn test_sets_every_relationship_to_lazy() {
+    let (response, source) = set_fetch(FetchType::Lazy, &[]);
+
+    assert_eq!(response.changed_fields, vec!["author", "cover"]);
+    assert_eq!(response.changed_fields_count, 2);
+    assert!(response.warnings.is_empty());
+    assert!(source.contains("  @ManyToOne(fetch = FetchType.LAZY)\n  private Author author;"));
+    assert!(source.contains("  @OneToOne(fetch = FetchType.LAZY, optional = false)"), "{}", source);
+    assert!(source.contains("  @OneToMany(mappedBy = \"book\")\n"), "{}", source);
+    assert!(source.contains("import jakarta.persistence.FetchType;"), "{}", source);
+  }
+
+  #[test]
+  fn test_eager_one_to_many_warns() {
+    let (response, source) = set_fetch(FetchType::Eager, &[RelationshipKind::OneToMany]);
+
+    assert_eq!(response.changed_fields, vec!["reviews"]);
+    assert_eq!(response.warnings.len(), 1);
+    assert!(response.warnings[0].starts_with("Field 'reviews' is a @OneToMany"));
+    assert!(
+      source.contains("  @OneToMany(mappedBy = \"book\", fetch = FetchType.EAGER)"),
+      "{}",
+      source
+    );
+    assert!(source.contains("  @ManyToOne\n"), "{}", source);
+  }
+
+  #[test]
+  fn test_relationships_already_at_target_are_left_untouched() {
+    let (response, source) = set_fetch(FetchType::Eager, &[RelationshipKind::ManyToOne]);
+
+    assert_eq!(response.changed_fields_count, 0);
+    assert_eq!(response.fetch_type, "EAGER");
+    assert_eq!(source, ENTITY_SOURCE);
+  }
+}
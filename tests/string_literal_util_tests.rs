@@ -0,0 +1,21 @@
+#[cfg(test)]
+mod string_literal_util_tests {
+  use syntaxpresso_core::common::utils::string_literal_util::{
+    from_java_string_literal, to_java_string_literal,
+  };
+
+  #[test]
+  fn test_escapes_backslashes_and_quotes() {
+    assert_eq!(to_java_string_literal("user_id"), r#""user_id""#);
+    assert_eq!(to_java_string_literal(r#""Order""#), r#""\"Order\"""#);
+    assert_eq!(to_java_string_literal(r"dbo\users"), r#""dbo\\users""#);
+  }
+
+  #[test]
+  fn test_reads_back_escaped_literals() {
+    for value in ["user_id", r#""Order""#, r"dbo\users", r#"a\"b\\"#] {
+      assert_eq!(from_java_string_literal(&to_java_string_literal(value)), value);
+    }
+    assert_eq!(from_java_string_literal("  plain  "), "plain");
+  }
+}
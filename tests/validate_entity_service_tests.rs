@@ -0,0 +1,227 @@
+#[cfg(test)]
+mod validate_entity_service_tests {
+  use base64::prelude::*;
+  use syntaxpresso_core::commands::java::responses::validate_entity_response::ValidateEntityResponse;
+  use syntaxpresso_core::commands::java::services::validate_entity_service::run;
+  use syntaxpresso_core::commands::java::treesitter::types::sql_dialect::SqlDialect;
+  use tempfile::TempDir;
+
+  fn validate(source: &str) -> ValidateEntityResponse {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    run(None, Some(&BASE64_STANDARD.encode(source)), None, None, temp_dir.path()).unwrap()
+  }
+
+  fn lazy_findings(response: &ValidateEntityResponse) -> Vec<(&str, &str)> {
+    response
+      .findings
+      .iter()
+      .filter(|finding| finding.code == "LAZY_FIELD_IN_OBJECT_METHOD")
+      .map(|finding| (finding.field_name.as_deref().unwrap(), finding.message.as_str()))
+      .collect()
+  }
+
+  #[test]
+  fn test_flags_to_string_touching_lazy_collection() {
+    let response = validate(
+      r#"package com.example;
+
+@Entity
+public class Purchase {
+  @Id
+  private Long id;
+
+  @OneToMany(mappedBy = "purchase")
+  private List<PurchaseLine> lines;
+
+  @Override
+  public String toString() {
+    return "Purchase{id=" + id + ", lines=" + lines + "}";
+  }
+}
+"#,
+    );
+
+    let findings = lazy_findings(&response);
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0, "lines");
+    assert!(findings[0].1.starts_with("toString() reads LAZY relationship 'lines'"));
+    let finding = response.findings.iter().find(|f| f.code == "LAZY_FIELD_IN_OBJECT_METHOD");
+    assert_eq!(
+      finding.unwrap().suggestion.as_deref(),
+      Some("Leave 'lines' out of toString() or print only its id")
+    );
+    assert_eq!(finding.unwrap().severity, "warning");
+  }
+
+  #[test]
+  fn test_flags_equals_and_hash_code_reading_lazy_many_to_one_through_getter() {
+    let response = validate(
+      r#"package com.example;
+
+@Entity
+public class PurchaseLine {
+  @Id
+  private Long id;
+
+  @ManyToOne(fetch = FetchType.LAZY)
+  private Purchase purchase;
+
+  @Override
+  public boolean equals(Object o) {
+    return o instanceof PurchaseLine other && Objects.equals(purchase, other.getPurchase());
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(getPurchase());
+  }
+}
+"#,
+    );
+
+    let methods: Vec<&str> = lazy_findings(&response)
+      .iter()
+      .map(|(_, message)| message.split('(').next().unwrap())
+      .collect();
+    assert_eq!(methods, vec!["equals", "hashCode"]);
+  }
+
+  #[test]
+  fn test_ignores_eager_relationships_and_other_methods() {
+    let response = validate(
+      r#"package com.example;
+
+@Entity
+public class PurchaseLine {
+  @Id
+  private Long id;
+
+  @ManyToOne
+  private Purchase purchase;
+
+  @ManyToMany
+  private Set<Tag> tags;
+
+  @Override
+  public String toString() {
+    return "PurchaseLine{purchase=" + purchase + "}";
+  }
+
+  public int tagCount() {
+    return tags.size();
+  }
+}
+"#,
+    );
+
+    assert!(lazy_findings(&response).is_empty(), "{:?}", lazy_findings(&response));
+  }
+
+  const WIDE_ENTITY_SOURCE: &str = r#"package com.example;
+
+@Entity
+public class Profile {
+  @Id
+  private Long id;
+
+  @Column(nullable = false)
+  private String email;
+
+  private String nickname;
+
+  private String bio;
+
+  @ManyToOne
+  private Country country;
+}
+"#;
+
+  fn dynamic_update_findings(source: &str, threshold: Option<usize>) -> Vec<(String, String)> {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let response =
+      run(None, Some(&BASE64_STANDARD.encode(source)), None, threshold, temp_dir.path()).unwrap();
+    response
+      .findings
+      .into_iter()
+      .filter(|finding| finding.code == "DYNAMIC_UPDATE_SUGGESTED")
+      .map(|finding| (finding.severity, finding.message))
+      .collect()
+  }
+
+  #[test]
+  fn test_suggests_dynamic_update_above_configured_threshold() {
+    assert_eq!(
+      dynamic_update_findings(WIDE_ENTITY_SOURCE, Some(4)),
+      vec![(
+        "info".to_string(),
+        "Entity 'Profile' maps 4 columns, 3 of them nullable, and every update writes all of them"
+          .to_string()
+      )]
+    );
+    assert!(dynamic_update_findings(WIDE_ENTITY_SOURCE, None).is_empty());
+    assert!(dynamic_update_findings(WIDE_ENTITY_SOURCE, Some(5)).is_empty());
+  }
+
+  #[test]
+  fn test_skips_dynamic_update_when_annotated_or_mostly_not_null() {
+    let annotated = WIDE_ENTITY_SOURCE.replace("@Entity\n", "@Entity\n@DynamicUpdate\n");
+    assert!(dynamic_update_findings(&annotated, Some(4)).is_empty());
+
+    let not_null = WIDE_ENTITY_SOURCE.replace(
+      "  private String nickname;",
+      "  @Column(nullable = false)\n  private String nickname;",
+    );
+    assert!(dynamic_update_findings(&not_null, Some(4)).is_empty());
+  }
+
+  const MIXED_CASE_SOURCE: &str = r#"package com.example;
+
+@Entity
+public class Invoice {
+  @Id
+  private Long id;
+
+  @Column(name = "InvoiceNumber")
+  private String number;
+
+  @Column(name = "\"DueDate\"")
+  private LocalDate dueDate;
+
+  @Column(name = "TOTAL")
+  private BigDecimal total;
+}
+"#;
+
+  fn mixed_case_findings(dialect: Option<SqlDialect>) -> Vec<(String, String)> {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let response =
+      run(None, Some(&BASE64_STANDARD.encode(MIXED_CASE_SOURCE)), dialect, None, temp_dir.path())
+        .unwrap();
+    response
+      .findings
+      .into_iter()
+      .filter(|finding| finding.code == "MIXED_CASE_COLUMN_NAME")
+      .map(|finding| (finding.field_name.unwrap(), finding.message))
+      .collect()
+  }
+
+  #[test]
+  fn test_flags_unquoted_mixed_case_column_names() {
+    assert_eq!(
+      mixed_case_findings(Some(SqlDialect::Postgresql)),
+      vec![(
+        "number".to_string(),
+        "Column name 'InvoiceNumber' is mixed case, postgresql folds unquoted names so it doesn't \
+         keep its case"
+          .to_string()
+      )]
+    );
+    assert_eq!(mixed_case_findings(None).len(), 1);
+  }
+
+  #[test]
+  fn test_skips_mixed_case_column_names_for_case_preserving_dialects() {
+    assert!(mixed_case_findings(Some(SqlDialect::Mysql)).is_empty());
+    assert!(mixed_case_findings(Some(SqlDialect::SqlServer)).is_empty());
+  }
+}
@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod validate_project_service_tests {
+  use std::fs;
+  use std::path::Path;
+
+  use syntaxpresso_core::commands::java::responses::validate_project_response::ValidateProjectResponse;
+  use syntaxpresso_core::commands::java::services::validate_project_service;
+  use syntaxpresso_core::commands::java::treesitter::types::finding_severity::FindingSeverity;
+  use tempfile::TempDir;
+
+  const AUTHOR_SOURCE: &str = r#"package com.example;
+
+@Entity
+public class Author {
+  @Id
+  private Long id;
+
+  @OneToMany(mappedBy = "writer")
+  private List<Book> books;
+
+  @OneToMany(mappedBy = "author")
+  private List<Review> reviews;
+}
+"#;
+
+  const BOOK_SOURCE: &str = r#"package com.example;
+
+@Entity
+public class Book {
+  @Id
+  private Long id;
+
+  @ManyToOne
+  private Author author;
+
+  private BigDecimal price;
+}
+"#;
+
+  const REVIEW_SOURCE: &str = r#"package com.example;
+
+@Entity
+public class Review {
+  @Id
+  private Long id;
+
+  @OneToOne
+  private Author author;
+}
+"#;
+
+  const BOOK_REPOSITORY_SOURCE: &str = r#"package com.example;
+
+public interface BookRepository extends JpaRepository<Book, String> {}
+"#;
+
+  fn create_project(files: &[(&str, &str)]) -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let package_dir = temp_dir.path().join("src/main/java/com/example");
+    fs::create_dir_all(&package_dir).unwrap();
+    for (file_name, source) in files {
+      fs::write(package_dir.join(file_name), source).unwrap();
+    }
+    temp_dir
+  }
+
+  fn find_codes(response: &ValidateProjectResponse, type_name: &str) -> Vec<String> {
+    response
+      .files
+      .iter()
+      .find(|file| file.type_name == type_name)
+      .map(|file| file.findings.iter().map(|finding| finding.code.clone()).collect())
+      .unwrap_or_default()
+  }
+
+  #[test]
+  fn test_groups_entity_and_repository_findings_by_file() {
+    let temp_dir = create_project(&[
+      ("Book.java", BOOK_SOURCE),
+      ("BookRepository.java", BOOK_REPOSITORY_SOURCE),
+    ]);
+
+    let response = validate_project_service::run(temp_dir.path(), None, None).unwrap();
+
+    assert_eq!(response.entities_count, 1);
+    assert_eq!(response.repositories_count, 1);
+    assert_eq!(response.files.len(), 2);
+    assert!(Path::new(&response.files[0].file_path).ends_with("Book.java"));
+    assert_eq!(find_codes(&response, "Book"), vec!["DIMENSIONLESS_BIG_DECIMAL"]);
+    assert_eq!(find_codes(&response, "BookRepository"), vec!["ID_TYPE_MISMATCH"]);
+    assert_eq!(response.errors_count, 1);
+    assert_eq!(response.warnings_count, 1);
+    assert!(response.passed);
+  }
+
+  #[test]
+  fn test_reports_inconsistent_mapped_by() {
+    let temp_dir = create_project(&[
+      ("Author.java", AUTHOR_SOURCE),
+      ("Book.java", BOOK_SOURCE),
+      ("Review.java", REVIEW_SOURCE),
+    ]);
+
+    let response = validate_project_service::run(temp_dir.path(), None, None).unwrap();
+
+    let author_file = response.files.iter().find(|file| file.type_name == "Author").unwrap();
+    assert_eq!(author_file.findings.len(), 2);
+    assert_eq!(author_file.findings[0].code, "MAPPED_BY_FIELD_NOT_FOUND");
+    assert_eq!(author_file.findings[0].field_name.as_deref(), Some("books"));
+    assert_eq!(author_file.findings[1].code, "MAPPED_BY_KIND_MISMATCH");
+    assert_eq!(
+      author_file.findings[1].message,
+      "@OneToMany 'Author.reviews' is mapped by @OneToOne 'Review.author', expected @ManyToOne"
+    );
+    assert!(find_codes(&response, "Review").is_empty());
+  }
+
+  #[test]
+  fn test_fail_on_threshold_decides_passed() {
+    let temp_dir = create_project(&[("Book.java", BOOK_SOURCE)]);
+
+    let on_error =
+      validate_project_service::run(temp_dir.path(), None, Some(FindingSeverity::Error)).unwrap();
+    let on_warning =
+      validate_project_service::run(temp_dir.path(), None, Some(FindingSeverity::Warning)).unwrap();
+
+    assert!(on_error.passed);
+    assert_eq!(on_error.fail_on.as_deref(), Some("error"));
+    assert!(!on_warning.passed);
+  }
+}